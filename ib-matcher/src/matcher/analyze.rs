@@ -1,15 +1,45 @@
 use std::cmp::min;
+#[cfg(feature = "pinyin")]
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, OnceLock},
+};
 
 use bon::{bon, Builder};
+#[cfg(feature = "pinyin")]
+use itertools::Itertools;
 
 #[cfg(feature = "romaji")]
 use crate::matcher::RomajiMatchConfig;
 #[cfg(feature = "pinyin")]
 use crate::{
     matcher::{PinyinAnalyzeResult, PinyinMatchConfig},
-    pinyin::PinyinNotation,
+    pinyin::{is_combining_mark, PinyinNotation, PinyinString, UNICODE_DECOMPOSED_MAX_LEN},
 };
 
+/// Caches of [`PatternAnalyzer`] in [`PinyinData`](crate::pinyin::PinyinData), which only depend on the data and the config.
+///
+/// [`PinyinNotation::DiletterCustom`] can't be re-inited with a different scheme, so the cached pinyins never go stale.
+#[cfg(feature = "pinyin")]
+#[derive(Default)]
+pub(crate) struct PinyinAnalyzeCache {
+    /// Every prefix of every pinyin in [`PinyinNotation::Ascii`].
+    syllable_prefixes: OnceLock<HashSet<PinyinString>>,
+    fuzzy_pinyins: Mutex<HashMap<FuzzyPinyinsKey, Arc<[String]>>>,
+}
+
+/// The bits of `(notation, fuzzy, umlaut)`, `optional_tone` and `combining_marks`.
+#[cfg(feature = "pinyin")]
+type FuzzyPinyinsKey = (u32, u32, u32, bool, bool);
+
+#[cfg(feature = "pinyin")]
+impl Clone for PinyinAnalyzeCache {
+    /// Caches are not cloned, as they can be rebuilt.
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
 #[derive(Builder)]
 pub struct PatternAnalyzeConfig {
     /// For better [`PatternAnalyzer::used_notations`] and [`PatternAnalyzer::min_haystack_len`].
@@ -38,6 +68,9 @@ pub(crate) struct PatternAnalyzer<'a> {
     pinyin: Option<&'a PinyinMatchConfig<'a>>,
     #[cfg(feature = "pinyin")]
     pinyin_result: PinyinAnalyzeResult,
+    /// Fuzzy variants, "ü" spellings and optional-tone variants of each notation that are not exact pinyins, for traversal.
+    #[cfg(feature = "pinyin")]
+    pinyin_fuzzy: Vec<(PinyinNotation, Arc<[String]>)>,
 
     #[cfg(feature = "romaji")]
     romaji: Option<&'a RomajiMatchConfig<'a>>,
@@ -75,6 +108,8 @@ impl<'a> PatternAnalyzer<'a> {
             pinyin,
             #[cfg(feature = "pinyin")]
             pinyin_result: Default::default(),
+            #[cfg(feature = "pinyin")]
+            pinyin_fuzzy: Vec::new(),
             #[cfg(feature = "romaji")]
             romaji,
             traversal_count: 0,
@@ -99,6 +134,8 @@ impl<'a> PatternAnalyzer<'a> {
             // TODO: Non-partial pattern
             self.pinyin_result.partial_pattern =
                 self.is_pattern_partial && pinyin.allow_partial_pattern;
+            self.pinyin_result.combining_marks = pinyin.notations.contains(PinyinNotation::Unicode)
+                && self.pattern.chars().any(is_combining_mark);
         }
 
         #[cfg(test)]
@@ -116,9 +153,20 @@ impl<'a> PatternAnalyzer<'a> {
         }
 
         if config.traversal {
+            // Traversal explodes as every prefix of every syllable can match
+            #[cfg(feature = "pinyin")]
+            if let Some(pinyin) = self.pinyin.filter(|pinyin| pinyin.syllable_prefix) {
+                self.analyze_syllable_prefix(pinyin);
+                return;
+            }
+
             #[cfg(feature = "pinyin")]
             {
                 self.pinyin_result.used_notations = PinyinNotation::empty();
+                self.pinyin_fuzzy = self
+                    .pinyin
+                    .map(|pinyin| Self::fuzzy_pinyins(pinyin, self.pinyin_result.combining_marks))
+                    .unwrap_or_default();
             }
 
             self.traversal_count = 0;
@@ -141,8 +189,21 @@ impl<'a> PatternAnalyzer<'a> {
             }
 
             // Traversal can give a better lower bound
+            // Fuzzy variants are no longer than `max_len` either
             #[cfg(feature = "pinyin")]
-            let max_len = self.pinyin_result.used_notations.max_len();
+            let max_len = match self.pinyin {
+                // Syllable prefixes are no longer than Ascii pinyins
+                Some(pinyin) if pinyin.syllable_prefix => {
+                    (self.pinyin_result.used_notations | PinyinNotation::Ascii).max_len()
+                }
+                _ => self.pinyin_result.used_notations.max_len(),
+            };
+            // Decomposed Unicode pinyins are longer, e.g. "zhua\u{300}ng"
+            #[cfg(feature = "pinyin")]
+            let max_len = match self.pinyin_result.combining_marks {
+                true => max_len.map(|len| len.max(UNICODE_DECOMPOSED_MAX_LEN)),
+                false => max_len,
+            };
             #[cfg(not(feature = "pinyin"))]
             let max_len = None;
             let min_haystack_chars = {
@@ -194,45 +255,34 @@ impl<'a> PatternAnalyzer<'a> {
                     pattern,
                     self.pinyin_result.partial_pattern,
                 ) {
-                    let matched = if partial { pattern } else { py };
-
-                    let mut matched_single_char = false;
-                    if matched.len() == 1 {
-                        matched_single_char = true;
-
-                        if notation == PinyinNotation::Ascii
-                            && pinyin.notations.contains(PinyinNotation::AsciiFirstLetter)
-                        {
-                            // Only let AsciiFirstLetter analyze to prune the tree
-                            continue;
-                        }
-                    } else if pinyin.notations.contains(PinyinNotation::Unicode)
-                        && matched.chars().nth(1).is_none()
-                    {
-                        matched_single_char = true;
+                    any_matched_single_char |=
+                        self.sub_analyze_pinyin(pattern, depth, min_len, notation, py, partial);
+                    if self.traversal_count > Self::TRAVERSAL_LIMIT {
+                        return;
                     }
-                    any_matched_single_char |= matched_single_char;
-
-                    self.pinyin_result.used_notations |= notation;
-
-                    // `MAX_RANGE` starts from 0x3007, at least 3 bytes
-                    // `c.len_utf8()` for pruning the tree with `any_matched_single_char`
-                    let min_len = min_len
-                        + if matched_single_char {
-                            min(3, c.len_utf8())
-                        } else {
-                            3
-                        };
-
-                    #[cfg(test)]
-                    println!(
-                        "{}{py} {:X} min_len={min_len} single={matched_single_char}{}",
-                        " ".repeat(depth),
-                        notation.bits(),
-                        if partial { " partial" } else { "" }
-                    );
-
-                    self.sub_analyze(&pattern[matched.len()..], depth + 1, min_len);
+                }
+            }
+
+            if !self.pinyin_fuzzy.is_empty() {
+                let partial_pattern = self.pinyin_result.partial_pattern;
+                let matches: Vec<(PinyinNotation, String, bool)> = self
+                    .pinyin_fuzzy
+                    .iter()
+                    .flat_map(|(notation, pinyins)| {
+                        pinyins.iter().filter_map(move |py| {
+                            if pattern.starts_with(py.as_str()) {
+                                Some((*notation, py.clone(), false))
+                            } else if partial_pattern && py.starts_with(pattern) {
+                                Some((*notation, py.clone(), true))
+                            } else {
+                                None
+                            }
+                        })
+                    })
+                    .collect();
+                for (notation, py, partial) in matches {
+                    any_matched_single_char |=
+                        self.sub_analyze_pinyin(pattern, depth, min_len, notation, &py, partial);
                     if self.traversal_count > Self::TRAVERSAL_LIMIT {
                         return;
                     }
@@ -247,10 +297,234 @@ impl<'a> PatternAnalyzer<'a> {
             #[cfg(test)]
             println!("{}{matched}", " ".repeat(depth));
             let len = matched.len_utf8();
-            self.sub_analyze(&pattern[len..], depth + 1, min_len + len);
+            // A homophone of a 4-byte hanzi may only have 3 bytes
+            #[cfg(feature = "pinyin")]
+            let matched_len = match self.pinyin {
+                Some(pinyin) if pinyin.homophone.is_some() => min(3, len),
+                _ => len,
+            };
+            #[cfg(not(feature = "pinyin"))]
+            let matched_len = len;
+            self.sub_analyze(&pattern[len..], depth + 1, min_len + matched_len);
         }
     }
 
+    /// ## Returns
+    /// Whether `py` matched a single char of the pattern.
+    #[cfg(feature = "pinyin")]
+    fn sub_analyze_pinyin(
+        &mut self,
+        pattern: &str,
+        depth: usize,
+        min_len: usize,
+        notation: PinyinNotation,
+        py: &str,
+        partial: bool,
+    ) -> bool {
+        let pinyin = self.pinyin.unwrap();
+        let c = pattern.chars().next().unwrap();
+        let matched = if partial { pattern } else { py };
+
+        let mut matched_single_char = false;
+        if matched.len() == 1 {
+            matched_single_char = true;
+
+            if matches!(
+                notation,
+                PinyinNotation::Ascii | PinyinNotation::AsciiInitial
+            ) && pinyin.notations.contains(PinyinNotation::AsciiFirstLetter)
+            {
+                // Only let AsciiFirstLetter analyze to prune the tree
+                return false;
+            }
+        } else if pinyin.notations.contains(PinyinNotation::Unicode)
+            && matched.chars().nth(1).is_none()
+        {
+            matched_single_char = true;
+        }
+
+        self.pinyin_result.used_notations |= notation;
+
+        // `MAX_RANGE` starts from 0x3007, at least 3 bytes
+        // `c.len_utf8()` for pruning the tree with `any_matched_single_char`
+        let min_len = min_len
+            + if matched_single_char {
+                min(3, c.len_utf8())
+            } else {
+                3
+            };
+
+        #[cfg(test)]
+        println!(
+            "{}{py} {:X} min_len={min_len} single={matched_single_char}{}",
+            " ".repeat(depth),
+            notation.bits(),
+            if partial { " partial" } else { "" }
+        );
+
+        // An apostrophe after a pinyin can be skipped as a syllable boundary, which is never longer
+        let pattern_next = &pattern[matched.len()..];
+        self.sub_analyze(
+            pattern_next.strip_prefix('\'').unwrap_or(pattern_next),
+            depth + 1,
+            min_len,
+        );
+        matched_single_char
+    }
+
+    /// Dynamic programming instead of traversal for [`PinyinMatchConfig::syllable_prefix`], i.e. the lower bounds of every suffix of the pattern are only computed once.
+    ///
+    /// All notations are considered used.
+    #[cfg(feature = "pinyin")]
+    fn analyze_syllable_prefix(&mut self, pinyin: &PinyinMatchConfig) {
+        self.pinyin_result.used_notations = pinyin.notations;
+        let fuzzy = Self::fuzzy_pinyins(pinyin, self.pinyin_result.combining_marks);
+        let prefixes = pinyin
+            .data
+            .analyze_cache()
+            .syllable_prefixes
+            .get_or_init(|| {
+                pinyin
+                    .data
+                    .iter()
+                    .filter_map(|py| py.notation(PinyinNotation::Ascii))
+                    .flat_map(|py| (1..=py.len()).map(move |len| &py[..len]))
+                    .map(|prefix| PinyinString::try_from_str(prefix).unwrap())
+                    .collect()
+            });
+
+        // (min_haystack_chars, min_haystack_len) of `pattern[i..]`
+        let pattern = self.pattern;
+        let mut bounds = vec![(usize::MAX, usize::MAX); pattern.len() + 1];
+        bounds[pattern.len()] = (0, 0);
+        for (i, c) in pattern.char_indices().rev() {
+            let rest = &pattern[i..];
+            let mut ends = Vec::new();
+            for notation in pinyin.notations.iter() {
+                for (py, partial) in pinyin.data.match_pinyin_partial(
+                    notation,
+                    rest,
+                    self.pinyin_result.partial_pattern,
+                ) {
+                    ends.push(if partial { rest.len() } else { py.len() });
+                }
+            }
+            for py in fuzzy.iter().flat_map(|(_, pinyins)| pinyins.iter()) {
+                if rest.starts_with(py.as_str()) {
+                    ends.push(py.len());
+                } else if self.pinyin_result.partial_pattern && py.starts_with(rest) {
+                    ends.push(rest.len());
+                }
+            }
+            ends.extend((1..=rest.len()).take_while(|&len| {
+                rest.get(..len)
+                    .and_then(|s| PinyinString::try_from_str(s).ok())
+                    .is_some_and(|s| prefixes.contains(&s))
+            }));
+
+            // A plain char, or a pinyin of a single char, see `sub_analyze_pinyin()`
+            let (chars, len) = bounds[i + c.len_utf8()];
+            let mut bound = (chars + 1, len + min(3, c.len_utf8()));
+            for end in ends {
+                // An apostrophe after a pinyin can be skipped as a syllable boundary
+                let end = match pattern[i + end..].starts_with('\'') {
+                    true => end + 1,
+                    false => end,
+                };
+                let (chars, len) = bounds[i + end];
+                bound = (min(bound.0, chars + 1), min(bound.1, len + 3));
+            }
+            bounds[i] = bound;
+        }
+
+        let (chars, len) = bounds[0];
+        self.set_min_haystack_chars(chars);
+        self.set_min_haystack_len(len);
+    }
+
+    /// Fuzzy variants, "ü" spellings and optional-tone variants of all pinyins in each notation, excluding exact pinyins.
+    ///
+    /// Cached in [`PinyinAnalyzeCache`].
+    #[cfg(feature = "pinyin")]
+    fn fuzzy_pinyins(
+        pinyin: &PinyinMatchConfig,
+        combining_marks: bool,
+    ) -> Vec<(PinyinNotation, Arc<[String]>)> {
+        if pinyin.fuzzy.is_empty()
+            && pinyin.umlaut.is_empty()
+            && !pinyin.optional_tone
+            && !combining_marks
+        {
+            return Vec::new();
+        }
+        let cache = &pinyin.data.analyze_cache().fuzzy_pinyins;
+        pinyin
+            .notations
+            .iter()
+            .map(|notation| {
+                let key = (
+                    notation.bits(),
+                    pinyin.fuzzy.bits(),
+                    pinyin.umlaut.bits(),
+                    pinyin.optional_tone,
+                    combining_marks,
+                );
+                if let Some(variants) = cache.lock().unwrap().get(&key) {
+                    return (notation, variants.clone());
+                }
+                // Not locked during the build, racing builds are all the same anyway
+                let variants = Self::build_fuzzy_pinyins(pinyin, notation, combining_marks);
+                cache.lock().unwrap().insert(key, variants.clone());
+                (notation, variants)
+            })
+            .filter(|(_, variants)| !variants.is_empty())
+            .collect()
+    }
+
+    #[cfg(feature = "pinyin")]
+    fn build_fuzzy_pinyins(
+        pinyin: &PinyinMatchConfig,
+        notation: PinyinNotation,
+        combining_marks: bool,
+    ) -> Arc<[String]> {
+        let exact: Vec<&str> = pinyin
+            .data
+            .iter()
+            .filter_map(|py| py.notation(notation))
+            .sorted()
+            .dedup()
+            .collect();
+        let mut variants = Vec::new();
+        let mut push = |variant: &str| {
+            if exact.binary_search(&variant).is_err() {
+                variants.push(variant.to_owned());
+            }
+            None::<()>
+        };
+        for py in pinyin.data.iter() {
+            py.fuzzy_notation_and_try_for_each(notation, pinyin.fuzzy, &mut push);
+            py.umlaut_notation_and_try_for_each(notation, pinyin.umlaut, &mut push);
+            if notation == PinyinNotation::AsciiTone && pinyin.optional_tone {
+                let ascii_tone = py.notation(notation).unwrap();
+                let ascii = &ascii_tone[..ascii_tone.len() - 1];
+                push(ascii);
+                if ascii_tone.ends_with('5') {
+                    push(&format!("{ascii}0"));
+                }
+            }
+            if notation == PinyinNotation::Unicode {
+                py.unicode_variants_and_try_for_each(
+                    pinyin.optional_tone,
+                    combining_marks,
+                    &mut push,
+                );
+            }
+        }
+        variants.sort_unstable();
+        variants.dedup();
+        variants.into()
+    }
+
     #[cfg(feature = "pinyin")]
     pub fn pinyin(&self) -> &PinyinAnalyzeResult {
         &self.pinyin_result
@@ -300,7 +574,7 @@ impl<'a> PatternAnalyzer<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::pinyin::PinyinData;
+    use crate::pinyin::{FuzzyPinyin, PinyinData, UmlautSpelling};
 
     use super::*;
 
@@ -340,6 +614,27 @@ mod tests {
             analyzer.pinyin().used_notations,
             PinyinNotation::AsciiFirstLetter
         );
+
+        let pinyin = PinyinMatchConfig::builder(
+            PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial,
+        )
+        .data(&pinyin_data)
+        .build();
+
+        let mut analyzer = PatternAnalyzer::builder("zhg").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(
+            analyzer.pinyin().used_notations,
+            PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial
+        );
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+
+        let mut analyzer = PatternAnalyzer::builder("pyss").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(
+            analyzer.pinyin().used_notations,
+            PinyinNotation::AsciiFirstLetter
+        );
     }
 
     #[test]
@@ -385,6 +680,19 @@ mod tests {
         assert_eq!(analyzer.min_haystack_len(), 7);
     }
 
+    #[test]
+    fn min_haystack_len_apostrophe() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::Ascii)
+            .data(&pinyin_data)
+            .build();
+
+        let mut analyzer = PatternAnalyzer::builder("xi'an").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        assert_eq!(analyzer.min_haystack_len(), 5);
+    }
+
     #[test]
     fn min_haystack_len_partial_pattern() {
         let pinyin_data = PinyinData::new(PinyinNotation::all());
@@ -459,6 +767,151 @@ mod tests {
         assert_eq!(analyzer.min_haystack_len(), 7);
     }
 
+    #[test]
+    fn min_haystack_len_fuzzy() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::Ascii)
+            .data(&pinyin_data)
+            .build();
+        let fuzzy = PinyinMatchConfig::builder(PinyinNotation::Ascii)
+            .fuzzy(FuzzyPinyin::FH)
+            .data(&pinyin_data)
+            .build();
+
+        // "fong" is not a pinyin, "fo" is
+        let mut analyzer = PatternAnalyzer::builder("fong").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        assert_eq!(analyzer.min_haystack_len(), 4);
+
+        // 红 (hong)
+        let mut analyzer = PatternAnalyzer::builder("fong").pinyin(&fuzzy).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 1);
+        assert_eq!(analyzer.min_haystack_len(), 3);
+        assert_eq!(analyzer.pinyin().used_notations, PinyinNotation::Ascii);
+
+        // Cached in the data
+        let variants = PatternAnalyzer::fuzzy_pinyins(&fuzzy, false);
+        assert!(Arc::ptr_eq(
+            &variants[0].1,
+            &PatternAnalyzer::fuzzy_pinyins(&fuzzy, false)[0].1
+        ));
+        assert!(variants[0].1.iter().any(|py| py == "fong"));
+    }
+
+    #[test]
+    fn min_haystack_len_umlaut() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin =
+            PinyinMatchConfig::builder(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter)
+                .umlaut(UmlautSpelling::Yu)
+                .data(&pinyin_data)
+                .build();
+
+        // 女 (nǚ)
+        let mut analyzer = PatternAnalyzer::builder("nyu").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 1);
+        assert_eq!(analyzer.min_haystack_len(), 3);
+        assert_eq!(
+            analyzer.pinyin().used_notations,
+            PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter
+        );
+    }
+
+    #[test]
+    fn min_haystack_len_optional_tone() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::AsciiTone)
+            .optional_tone(true)
+            .data(&pinyin_data)
+            .build();
+
+        // 世界
+        let mut analyzer = PatternAnalyzer::builder("shi4jie").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        assert_eq!(analyzer.min_haystack_len(), 6);
+        assert_eq!(analyzer.pinyin().used_notations, PinyinNotation::AsciiTone);
+
+        // 的
+        let mut analyzer = PatternAnalyzer::builder("de0").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 1);
+        assert_eq!(analyzer.min_haystack_len(), 3);
+    }
+
+    #[test]
+    fn min_haystack_len_combining_marks() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::Unicode)
+            .data(&pinyin_data)
+            .build();
+
+        // 状 (zhuàng)
+        let mut analyzer = PatternAnalyzer::builder("zhua\u{300}ng")
+            .pinyin(&pinyin)
+            .build();
+        analyzer.analyze_default();
+        assert!(analyzer.pinyin().combining_marks);
+        assert_eq!(analyzer.min_haystack_chars(), 1);
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 1);
+        assert_eq!(analyzer.min_haystack_len(), 3);
+        assert_eq!(analyzer.pinyin().used_notations, PinyinNotation::Unicode);
+
+        let mut analyzer = PatternAnalyzer::builder("zhuàng").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert!(!analyzer.pinyin().combining_marks);
+    }
+
+    #[test]
+    fn min_haystack_len_syllable_prefix() {
+        let pinyin_data = PinyinData::new(PinyinNotation::all());
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::Ascii)
+            .syllable_prefix(true)
+            .data(&pinyin_data)
+            .build();
+
+        // 拼音搜
+        let mut analyzer = PatternAnalyzer::builder("pinyso").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 3);
+        assert_eq!(analyzer.min_haystack_len(), 6);
+        assert_eq!(analyzer.pinyin().used_notations, PinyinNotation::Ascii);
+
+        // 中国
+        let mut analyzer = PatternAnalyzer::builder("zhonguo").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        // "zhong" "u" "o"
+        assert_eq!(analyzer.min_haystack_len(), 5);
+
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::AsciiFirstLetter)
+            .syllable_prefix(true)
+            .data(&pinyin_data)
+            .build();
+        let mut analyzer = PatternAnalyzer::builder("zhonguo").pinyin(&pinyin).build();
+        analyzer.analyze_default();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        assert_eq!(analyzer.min_haystack_len(), 5);
+
+        let mut analyzer = PatternAnalyzer::builder("xi'an").pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 2);
+        assert_eq!(analyzer.min_haystack_len(), 5);
+
+        // Long patterns are not limited by `TRAVERSAL_LIMIT`
+        let pattern = "zhongguo".repeat(8);
+        let mut analyzer = PatternAnalyzer::builder(&pattern).pinyin(&pinyin).build();
+        analyzer.analyze_std();
+        assert_eq!(analyzer.min_haystack_chars(), 16);
+        assert_eq!(analyzer.min_haystack_len(), 48);
+    }
+
     #[test]
     fn min_haystack_len_romaji() {
         let romanizer = Default::default();
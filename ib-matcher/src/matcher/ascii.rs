@@ -1,6 +1,8 @@
 use aho_corasick::{Anchored, Input, StartKind};
 use bon::{bon, Builder};
 
+#[cfg(any(feature = "pinyin", feature = "romaji"))]
+use crate::matcher::transliterate::Transliterator;
 use crate::matcher::Match;
 
 /// Note [`PlainMatchConfigBuilder::case_insensitive`] is `true` by default, unlike [`PinyinMatchConfigBuilder`] and [`RomajiMatchConfigBuilder`].
@@ -28,6 +30,9 @@ pub enum AsciiMatcher<const CHAR_LEN: usize = 1> {
     /// - build -60%, `build_analyze` -25%
     /// - Build size -837.5 KiB
     Ac(AcMatcher),
+    /// Non-ASCII pattern with [`IbMatcherBuilder::transliterate_pattern`](super::IbMatcherBuilder::transliterate_pattern)
+    #[cfg(any(feature = "pinyin", feature = "romaji"))]
+    Transliterate(Transliterator),
     #[cfg(feature = "regex")]
     #[allow(unused)]
     Regex(regex::bytes::Regex),
@@ -40,9 +45,31 @@ pub struct AcMatcher {
     /// `ac` also has `start_kind`, but here has free space so anyway
     starts_with: bool,
     ends_with: bool,
+    /// For [`AcSetMatcher`].
+    case_insensitive: bool,
+    /// For [`AcSetMatcher`].
+    pattern: Box<[u8]>,
 }
 
 impl AcMatcher {
+    fn new(pattern: &[u8], case_insensitive: bool, starts_with: bool, ends_with: bool) -> Self {
+        AcMatcher {
+            ac: aho_corasick::AhoCorasick::builder()
+                .ascii_case_insensitive(case_insensitive)
+                .start_kind(if starts_with {
+                    StartKind::Anchored
+                } else {
+                    StartKind::Unanchored
+                })
+                .build([pattern])
+                .unwrap(),
+            starts_with,
+            ends_with,
+            case_insensitive,
+            pattern: pattern.into(),
+        }
+    }
+
     #[inline]
     pub fn input<'h>(&self, haystack: &'h [u8]) -> Input<'h> {
         Input::new(haystack).anchored(if self.starts_with {
@@ -55,10 +82,28 @@ impl AcMatcher {
 
 #[bon]
 impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
+    pub fn ac(&self) -> Option<&AcMatcher> {
+        match self {
+            Ac(ac) => Some(ac),
+            _ => None,
+        }
+    }
+
+    #[cfg(any(feature = "pinyin", feature = "romaji"))]
+    pub fn transliterator(&self) -> Option<&Transliterator> {
+        match self {
+            Transliterate(transliterator) => Some(transliterator),
+            _ => None,
+        }
+    }
+
     #[builder]
     pub fn new(
         #[builder(start_fn)] pattern: &[u8],
         plain: Option<&PlainMatchConfig>,
+        /// Matches a non-ASCII `pattern` instead.
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        transliterator: Option<Transliterator>,
         #[builder(default = false)] starts_with: bool,
         #[builder(default = false)] ends_with: bool,
     ) -> Self {
@@ -69,21 +114,18 @@ impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
                 //     .case_insensitive(case_insensitive)
                 //     .build()
                 //     .unwrap(),
-                Ac(AcMatcher {
-                    ac: aho_corasick::AhoCorasick::builder()
-                        .ascii_case_insensitive(plain.case_insensitive)
-                        .start_kind(if starts_with {
-                            StartKind::Anchored
-                        } else {
-                            StartKind::Unanchored
-                        })
-                        .build(&[pattern])
-                        .unwrap(),
+                Ac(AcMatcher::new(
+                    pattern,
+                    plain.case_insensitive,
                     starts_with,
                     ends_with,
-                })
+                ))
             }
-            None => Fail,
+            #[cfg(any(feature = "pinyin", feature = "romaji"))]
+            None if !pattern.is_ascii() && transliterator.is_some() => {
+                Transliterate(transliterator.unwrap())
+            }
+            _ => Fail,
         }
     }
 
@@ -104,6 +146,7 @@ impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
                             start: start + m.start() / CHAR_LEN,
                             end: start + m.end() / CHAR_LEN,
                             is_pattern_partial: false,
+                            char_spans: None,
                         })
                         .next()
                 } else {
@@ -111,14 +154,18 @@ impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
                         start: m.start() / CHAR_LEN,
                         end: m.end() / CHAR_LEN,
                         is_pattern_partial: false,
+                        char_spans: None,
                     })
                 }
             }
+            #[cfg(any(feature = "pinyin", feature = "romaji"))]
+            Transliterate(transliterator) => transliterator.find(haystack),
             #[cfg(feature = "regex")]
             Regex(regex) => regex.find(haystack).map(|m| Match {
                 start: m.start() / CHAR_LEN,
                 end: m.end() / CHAR_LEN,
                 is_pattern_partial: false,
+                char_spans: None,
             }),
         }
     }
@@ -133,6 +180,8 @@ impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
                     ac.ac.is_match(ac.input(haystack))
                 }
             }
+            #[cfg(any(feature = "pinyin", feature = "romaji"))]
+            Transliterate(transliterator) => transliterator.is_match(haystack),
             #[cfg(feature = "regex")]
             Regex(regex) => regex.is_match(haystack),
         }
@@ -152,29 +201,106 @@ impl<const CHAR_LEN: usize> AsciiMatcher<CHAR_LEN> {
                             start: 0,
                             end: m.end() / CHAR_LEN,
                             is_pattern_partial: false,
+                            char_spans: None,
                         })
                 } else {
                     ac.ac.find(input).filter(|m| m.start() == 0).map(|m| Match {
                         start: 0,
                         end: m.end() / CHAR_LEN,
                         is_pattern_partial: false,
+                        char_spans: None,
                     })
                 }
             }
+            #[cfg(any(feature = "pinyin", feature = "romaji"))]
+            Transliterate(transliterator) => transliterator.test(haystack),
             // TODO: Use regex-automata's anchored searches?
             #[cfg(feature = "regex")]
             Regex(regex) => regex
-                .find(haystack.as_bytes())
+                .find(haystack)
                 .filter(|m| m.start() == 0)
                 .map(|m| Match {
                     start: 0,
                     end: m.end() / CHAR_LEN,
                     is_pattern_partial: false,
+                    char_spans: None,
                 }),
         }
     }
 }
 
+/// Multi-pattern [`AcMatcher`] for [`IbMatcherSet`](super::IbMatcherSet).
+pub struct AcSetMatcher {
+    /// Grouped by case insensitivity.
+    groups: Vec<AcSetGroup>,
+}
+
+struct AcSetGroup {
+    ac: aho_corasick::AhoCorasick,
+    /// Indexed by pattern ID: `(id, starts_with, ends_with)`
+    patterns: Vec<(usize, bool, bool)>,
+}
+
+impl AcSetMatcher {
+    pub fn new<'m>(matchers: impl IntoIterator<Item = (usize, &'m AcMatcher)>) -> Self {
+        let mut groups: [Vec<(usize, &AcMatcher)>; 2] = Default::default();
+        for (id, ac) in matchers {
+            groups[ac.case_insensitive as usize].push((id, ac));
+        }
+        Self {
+            groups: groups
+                .into_iter()
+                .enumerate()
+                .filter(|(_, matchers)| !matchers.is_empty())
+                .map(|(case_insensitive, matchers)| AcSetGroup {
+                    ac: aho_corasick::AhoCorasick::builder()
+                        .ascii_case_insensitive(case_insensitive != 0)
+                        .build(matchers.iter().map(|(_, ac)| &ac.pattern))
+                        .unwrap(),
+                    patterns: matchers
+                        .iter()
+                        .map(|&(id, ac)| (id, ac.starts_with, ac.ends_with))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Calls `f` with the ID and the first match of each matched pattern, in no particular order, until `f` returns `true`.
+    ///
+    /// ## Returns
+    /// Whether `f` returned `true`.
+    pub fn find_each(&self, haystack: &[u8], mut f: impl FnMut(usize, Match) -> bool) -> bool {
+        for group in &self.groups {
+            let mut found = vec![false; group.patterns.len()];
+            // Overlapping matches are reported in the order of their ends, so the first valid one of each pattern is also the leftmost one
+            for m in group.ac.find_overlapping_iter(haystack) {
+                let i = m.pattern().as_usize();
+                let (id, starts_with, ends_with) = group.patterns[i];
+                if found[i]
+                    || starts_with && m.start() != 0
+                    || ends_with && m.end() != haystack.len()
+                {
+                    continue;
+                }
+                found[i] = true;
+                if f(
+                    id,
+                    Match {
+                        start: m.start(),
+                        end: m.end(),
+                        is_pattern_partial: false,
+                        char_spans: None,
+                    },
+                ) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assert_match;
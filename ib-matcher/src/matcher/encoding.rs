@@ -1,4 +1,7 @@
-use std::{ops::RangeFrom, slice::SliceIndex};
+use std::{
+    ops::{Range, RangeFrom},
+    slice::SliceIndex,
+};
 
 /// ## Performance
 /// Although multiple encodings are supported, UTF-8 (`str`) is most optimized.
@@ -15,6 +18,7 @@ pub trait EncodedStr: Sealed {
     fn is_ascii(&self) -> bool;
     fn as_bytes(&self) -> &[u8];
 
+    fn get(&self, range: Range<usize>) -> Option<&Self>;
     unsafe fn get_unchecked<I: SliceIndex<Self::SLICE, Output = Self::SLICE>>(&self, i: I)
         -> &Self;
     unsafe fn get_unchecked_from(&self, range: RangeFrom<usize>) -> &Self;
@@ -49,6 +53,10 @@ impl EncodedStr for str {
         self.as_bytes()
     }
 
+    fn get(&self, range: Range<usize>) -> Option<&Self> {
+        self.get(range)
+    }
+
     unsafe fn get_unchecked<I: SliceIndex<Self::SLICE, Output = Self::SLICE>>(
         &self,
         i: I,
@@ -95,6 +103,10 @@ impl EncodedStr for widestring::U16Str {
         }
     }
 
+    fn get(&self, range: Range<usize>) -> Option<&Self> {
+        self.get(range)
+    }
+
     unsafe fn get_unchecked<I: SliceIndex<Self::SLICE, Output = Self::SLICE>>(
         &self,
         i: I,
@@ -137,6 +149,10 @@ impl EncodedStr for widestring::U32Str {
         }
     }
 
+    fn get(&self, range: Range<usize>) -> Option<&Self> {
+        self.get(range)
+    }
+
     unsafe fn get_unchecked<I: SliceIndex<Self::SLICE, Output = Self::SLICE>>(
         &self,
         i: I,
@@ -1,10 +1,12 @@
 //! ## Performance
 //! With default `release` profile, using `Input` instead of `&HaystackStr` is 3~5% slower (without using Bon), while with `lto = "fat"` and `codegen-units = 1` using `Input` is 3~5% faster, well...
+use std::ops::Range;
+
 use bon::Builder;
 
 use crate::matcher::encoding::EncodedStr;
 
-#[derive(Builder, Clone)]
+#[derive(Builder)]
 pub struct Input<'h, HaystackStr = str>
 where
     HaystackStr: EncodedStr + ?Sized,
@@ -13,9 +15,54 @@ where
     pub(crate) haystack: &'h HaystackStr,
     // #[builder(default = haystack.is_ascii())]
     // pub(crate) is_ascii: bool,
+    /// The span of the haystack to search, in code units of `HaystackStr` (e.g. bytes for `str`). Defaults to the whole haystack.
+    ///
+    /// Matches can only start and end inside the span, and [`IbMatcherBuilder::starts_with`](super::IbMatcherBuilder::starts_with)/[`IbMatcherBuilder::ends_with`](super::IbMatcherBuilder::ends_with) are evaluated against the span edges. Match offsets are still relative to the whole haystack.
+    ///
+    /// Akin to [`regex_automata::Input::range`](https://docs.rs/regex-automata/latest/regex_automata/struct.Input.html#method.range).
+    ///
+    /// ## Panics
+    /// Searching panics if the span is out of bounds or doesn't fall on char boundaries.
+    #[builder(default = 0..haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE)]
+    pub(crate) range: Range<usize>,
     /// The haystack does not include the real start of the haystack. Akin to POSIX `REG_NOTBOL` and PCRE `PCRE_NOTBOL`.
     #[builder(default = false)]
     pub(crate) no_start: bool,
+    /// The haystack does not include the real end of the haystack. Akin to POSIX `REG_NOTEOL` and PCRE `PCRE_NOTEOL`.
+    ///
+    /// Useful for searching chunked haystacks.
+    #[builder(default = false)]
+    pub(crate) no_end: bool,
+}
+
+impl<'h, HaystackStr> Input<'h, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    /// The part of the haystack covered by [`Input::range`].
+    ///
+    /// ## Panics
+    /// If the range is out of bounds or doesn't fall on char boundaries.
+    #[inline]
+    pub(crate) fn span(&self) -> &'h HaystackStr {
+        self.haystack
+            .get(self.range.clone())
+            .expect("input range should be in bounds and on char boundaries")
+    }
+}
+
+impl<'h, HaystackStr> Clone for Input<'h, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            haystack: self.haystack,
+            range: self.range.clone(),
+            no_start: self.no_start,
+            no_end: self.no_end,
+        }
+    }
 }
 
 impl<'h, HaystackStr> From<&'h HaystackStr> for Input<'h, HaystackStr>
@@ -27,7 +74,9 @@ where
         // Input::builder(haystack).build()
         Input {
             haystack,
+            range: 0..haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE,
             no_start: false,
+            no_end: false,
         }
     }
 }
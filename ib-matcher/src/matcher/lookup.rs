@@ -0,0 +1,185 @@
+//! Pinyin/romaji lookups of haystack chars, which can be shared by multiple matchers, see [`IbMatcherSet`](super::IbMatcherSet).
+use std::ops::Range;
+
+use bon::bon;
+#[cfg(feature = "romaji")]
+use ib_romaji::HepburnRomanizer;
+
+use crate::matcher::{encoding::EncodedStr, matches::MatchContext};
+#[cfg(feature = "pinyin")]
+use crate::pinyin::PinyinData;
+#[cfg(feature = "pinyin-words")]
+use crate::pinyin::PinyinWords;
+
+/// ## Arguments
+/// - `haystack_len`: The length of the remaining haystack in bytes, used as the key of the char.
+pub(crate) trait HaystackLookup: Copy {
+    #[cfg(feature = "pinyin")]
+    fn pinyin_index(self, c: char, haystack_len: usize) -> Option<u16>;
+
+    #[cfg(feature = "romaji")]
+    fn romanize_and_try_for_each<T>(
+        self,
+        romanizer: &HepburnRomanizer,
+        haystack: &str,
+        f: impl FnMut(usize, &'static str) -> Option<T>,
+    ) -> Option<T>;
+}
+
+/// No cache.
+impl HaystackLookup for () {
+    #[cfg(feature = "pinyin")]
+    #[inline(always)]
+    fn pinyin_index(self, c: char, _haystack_len: usize) -> Option<u16> {
+        PinyinData::get_pinyin_index(c)
+    }
+
+    #[cfg(feature = "romaji")]
+    #[inline(always)]
+    fn romanize_and_try_for_each<T>(
+        self,
+        romanizer: &HepburnRomanizer,
+        haystack: &str,
+        f: impl FnMut(usize, &'static str) -> Option<T>,
+    ) -> Option<T> {
+        romanizer.romanize_and_try_for_each(haystack, f)
+    }
+}
+
+/// Lookups of all non-ASCII chars of a haystack, computed once.
+#[cfg_attr(not(any(feature = "pinyin", feature = "romaji")), allow(dead_code))]
+pub(crate) struct LookupCache<'r> {
+    /// The end of the searched span in bytes, which the remaining haystack lengths are measured to.
+    len: usize,
+    /// Indexed by byte offset, `u16::MAX` for none.
+    #[cfg(feature = "pinyin")]
+    pinyin_indexes: Vec<u16>,
+    /// Only lookups with this romanizer are cached.
+    #[cfg(feature = "romaji")]
+    romanizer: Option<&'r HepburnRomanizer>,
+    /// Indexed by byte offset.
+    #[cfg(feature = "romaji")]
+    romajis: Vec<Box<[(usize, &'static str)]>>,
+    _romanizer: core::marker::PhantomData<&'r ()>,
+}
+
+#[bon]
+impl<'r> LookupCache<'r> {
+    /// ## Arguments
+    /// - `range`: The span of `haystack` to search, in code units. Chars out of it are only used as the context of `words`. Default: the whole haystack.
+    /// - `pinyin`: Whether to cache pinyins.
+    /// - `romanizer`: The romanizer to cache romajis with, UTF-8 haystacks only.
+    /// - `words`: Override the pinyins of chars in known words, see [`PinyinMatchConfigBuilder::words()`](crate::matcher::PinyinMatchConfigBuilder::words).
+    #[builder]
+    pub fn new<HaystackStr>(
+        #[builder(start_fn)] haystack: &HaystackStr,
+        range: Option<Range<usize>>,
+        #[cfg(feature = "pinyin")]
+        #[builder(default = false)]
+        pinyin: bool,
+        #[cfg(feature = "romaji")] romanizer: Option<&'r HepburnRomanizer>,
+        #[cfg(feature = "pinyin-words")] words: Option<&PinyinWords>,
+    ) -> Self
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        let range = range.map_or(0..haystack.as_bytes().len(), |range| {
+            range.start * HaystackStr::ELEMENT_LEN_BYTE..range.end * HaystackStr::ELEMENT_LEN_BYTE
+        });
+        let len = range.end;
+
+        // The whole haystack is looked up, so words crossing the span are still known
+        #[cfg(feature = "pinyin")]
+        let pinyin_indexes = if pinyin {
+            let mut indexes = vec![u16::MAX; haystack.as_bytes().len()];
+            for (i, c, _) in haystack.char_index_strs() {
+                if let Some(index) = PinyinData::get_pinyin_index(c) {
+                    indexes[i * HaystackStr::ELEMENT_LEN_BYTE] = index;
+                }
+            }
+            #[cfg(feature = "pinyin-words")]
+            if let Some(words) = words {
+                let (offsets, chars): (Vec<usize>, Vec<char>) = haystack
+                    .char_index_strs()
+                    .map(|(i, c, _)| (i * HaystackStr::ELEMENT_LEN_BYTE, c))
+                    .unzip();
+                words.segment_and_for_each(&chars, |i, index| indexes[offsets[i]] = index);
+            }
+            indexes
+        } else {
+            Vec::new()
+        };
+
+        #[cfg(feature = "romaji")]
+        let romanizer = romanizer.filter(|_| HaystackStr::ELEMENT_LEN_BYTE == 1);
+        #[cfg(feature = "romaji")]
+        let romajis = match romanizer {
+            Some(romanizer) => {
+                let haystack = unsafe { std::str::from_utf8_unchecked(haystack.as_bytes()) };
+                let mut romajis = Vec::with_capacity(len);
+                romajis.resize_with(len, Default::default);
+                // Words can't cross the end of the span
+                let span = &haystack[..range.end];
+                for (i, c) in span.char_indices().skip_while(|&(i, _)| i < range.start) {
+                    if c.is_ascii() {
+                        continue;
+                    }
+                    let mut v = Vec::new();
+                    romanizer.romanize_and_try_for_each(&span[i..], |len, romaji| {
+                        v.push((len, romaji));
+                        None::<()>
+                    });
+                    romajis[i] = v.into_boxed_slice();
+                }
+                romajis
+            }
+            None => Vec::new(),
+        };
+
+        Self {
+            len,
+            #[cfg(feature = "pinyin")]
+            pinyin_indexes,
+            #[cfg(feature = "romaji")]
+            romanizer,
+            #[cfg(feature = "romaji")]
+            romajis,
+            _romanizer: core::marker::PhantomData,
+        }
+    }
+}
+
+impl HaystackLookup for &LookupCache<'_> {
+    #[cfg(feature = "pinyin")]
+    #[inline]
+    fn pinyin_index(self, _c: char, haystack_len: usize) -> Option<u16> {
+        match self.pinyin_indexes[self.len - haystack_len] {
+            u16::MAX => None,
+            index => Some(index),
+        }
+    }
+
+    #[cfg(feature = "romaji")]
+    fn romanize_and_try_for_each<T>(
+        self,
+        romanizer: &HepburnRomanizer,
+        haystack: &str,
+        mut f: impl FnMut(usize, &'static str) -> Option<T>,
+    ) -> Option<T> {
+        if !self.romanizer.is_some_and(|r| core::ptr::eq(r, romanizer)) {
+            return romanizer.romanize_and_try_for_each(haystack, f);
+        }
+        self.romajis[self.len - haystack.len()]
+            .iter()
+            .find_map(|&(len, romaji)| f(len, romaji))
+    }
+}
+
+impl MatchContext for &LookupCache<'_> {
+    type Lookup = Self;
+
+    #[inline(always)]
+    fn lookup(&self) -> Self::Lookup {
+        *self
+    }
+}
@@ -1,12 +1,20 @@
 use std::ops::Range;
 
-use crate::Sealed;
+#[cfg(feature = "pinyin-words")]
+use crate::matcher::lookup::LookupCache;
+#[cfg(feature = "pinyin")]
+use crate::pinyin::PinyinNotation;
+use crate::{
+    matcher::{encoding::EncodedStr, input::Input, lookup::HaystackLookup, IbMatcher},
+    Sealed,
+};
 
 #[derive(Clone, Debug)]
 pub struct Match {
     pub(crate) start: usize,
     pub(crate) end: usize,
     pub(crate) is_pattern_partial: bool,
+    pub(crate) char_spans: Option<Box<[CharSpan]>>,
 }
 
 impl Match {
@@ -34,13 +42,279 @@ impl Match {
         self.is_pattern_partial
     }
 
+    /// Which haystack chars are matched by which pattern chars, in the order of the pattern.
+    ///
+    /// Haystack chars inside the match but not covered by any span are skipped by the pattern, e.g. the chars between the words of a romaji match.
+    ///
+    /// Only available on matches returned by [`IbMatcher::find_positions()`].
+    pub fn char_spans(&self) -> Option<&[CharSpan]> {
+        self.char_spans.as_deref()
+    }
+
+    /// The ranges of haystack chars inside the match that are skipped by the pattern, e.g. with [`IbMatcherBuilder::subsequence`](super::IbMatcherBuilder::subsequence).
+    ///
+    /// Only available on matches returned by [`IbMatcher::find_positions()`], empty otherwise.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.char_spans()
+            .unwrap_or_default()
+            .windows(2)
+            .map(|w| w[0].haystack.end..w[1].haystack.start)
+            .filter(|gap| !gap.is_empty())
+    }
+
     /// Mainly used for bytes to char units conversion.
     pub fn div(self, rhs: usize) -> Match {
+        debug_assert!(self.char_spans.is_none());
         Match {
             start: self.start / rhs,
             end: self.end / rhs,
-            is_pattern_partial: self.is_pattern_partial,
+            ..self
+        }
+    }
+
+    /// Shifts the match by `offset`, e.g. from a sub-haystack to the whole haystack.
+    pub(crate) fn offset(self, offset: usize) -> Match {
+        debug_assert!(self.char_spans.is_none());
+        Match {
+            start: self.start + offset,
+            end: self.end + offset,
+            ..self
+        }
+    }
+}
+
+/// A span of the pattern and the haystack chars it matched. See [`Match::char_spans()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharSpan {
+    pub(crate) pattern: Range<usize>,
+    pub(crate) haystack: Range<usize>,
+    pub(crate) kind: MatchKind,
+}
+
+impl CharSpan {
+    /// The range of the pattern, in code units of `HaystackStr` (e.g. bytes for `str`).
+    ///
+    /// Not empty. The pattern may be shorter than the matched pinyin/romaji if [`Match::is_pattern_partial()`].
+    pub fn pattern(&self) -> Range<usize> {
+        self.pattern.clone()
+    }
+
+    /// The range of the haystack, in code units of `HaystackStr`, relative to the whole haystack.
+    ///
+    /// Always one char for [`MatchKind::Plain`], pinyin and homophone matches, but may be multiple chars for romaji matches.
+    pub fn haystack(&self) -> Range<usize> {
+        self.haystack.clone()
+    }
+
+    pub fn kind(&self) -> MatchKind {
+        self.kind
+    }
+}
+
+/// How a [`CharSpan`] is matched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatchKind {
+    /// Matched as plain chars, see [`IbMatcherBuilder::plain`](super::IbMatcherBuilder::plain).
+    Plain,
+    /// Matched by the pinyin of the given notation.
+    #[cfg(feature = "pinyin")]
+    Pinyin(PinyinNotation),
+    /// A hanzi in the pattern matched another hanzi sharing a reading, see [`PinyinMatchConfigBuilder::homophone`](super::PinyinMatchConfigBuilder::homophone).
+    #[cfg(feature = "pinyin")]
+    Homophone,
+    /// Matched by the romaji of a Japanese word.
+    #[cfg(feature = "romaji")]
+    Romaji,
+}
+
+/// The context of matching, to only pay for the used features by monomorphization.
+///
+/// `()` is the default context, which should be optimized out entirely.
+pub(crate) trait MatchContext {
+    type Lookup: HaystackLookup;
+
+    /// Whether to record [`CharSpan`]s.
+    const RECORD: bool = false;
+    /// Whether to match subsequences, see [`IbMatcherBuilder::subsequence`](super::IbMatcherBuilder::subsequence).
+    const SUBSEQUENCE: bool = false;
+
+    /// Only used by pinyin/romaji match.
+    #[cfg_attr(not(any(feature = "pinyin", feature = "romaji")), allow(dead_code))]
+    fn lookup(&self) -> Self::Lookup;
+
+    /// Called on the way back from a successful match, i.e. from the end of the match to the start.
+    #[inline(always)]
+    fn record(&mut self, _span: CharSpan) {}
+
+    /// Marks the state as visited, and returns whether it was already visited.
+    ///
+    /// ## Arguments
+    /// - `pattern_len`: The number of the remaining pattern chars.
+    /// - `haystack_len`: The length of the remaining haystack, in code units.
+    #[inline(always)]
+    fn visit<const LANG: u8>(&mut self, _pattern_len: usize, _haystack_len: usize) -> bool {
+        false
+    }
+
+    /// Called when the pattern is fully matched, and rejects the match if returning `false`, i.e. backtracks to find other matches.
+    ///
+    /// ## Arguments
+    /// - `len`: The length of the match, in code units.
+    #[inline(always)]
+    fn accept(&mut self, _len: usize) -> bool {
+        true
+    }
+}
+
+impl MatchContext for () {
+    type Lookup = ();
+
+    #[inline(always)]
+    fn lookup(&self) -> Self::Lookup {}
+}
+
+/// Records [`CharSpan`]s, with the given lookup.
+pub(crate) struct Record<L> {
+    pub spans: Vec<CharSpan>,
+    #[cfg_attr(not(any(feature = "pinyin", feature = "romaji")), allow(dead_code))]
+    pub lookup: L,
+}
+
+impl<L: HaystackLookup> MatchContext for Record<L> {
+    type Lookup = L;
+
+    const RECORD: bool = true;
+
+    #[inline(always)]
+    fn lookup(&self) -> Self::Lookup {
+        self.lookup
+    }
+
+    fn record(&mut self, span: CharSpan) {
+        self.spans.push(span)
+    }
+}
+
+/// Enumerates the lengths of all matches at a position, until the closure returns `true`.
+///
+/// Memoizes visited states like [`Subsequence`], to avoid enumerating the same match via exponentially many paths.
+#[cfg(feature = "regex")]
+pub(crate) struct Accept<F> {
+    f: F,
+    /// Bitset of visited `(matched_len, LANG, pattern_len)` states, grown on demand since matches are usually much shorter than the haystack.
+    visited: Vec<u64>,
+    pattern_len: usize,
+    haystack_len: usize,
+}
+
+#[cfg(feature = "regex")]
+impl<F: FnMut(usize) -> bool> Accept<F> {
+    pub fn new<HaystackStr>(pattern_len: usize, haystack: &HaystackStr, f: F) -> Self
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        Self {
+            f,
+            visited: Vec::new(),
+            pattern_len: pattern_len + 1,
+            haystack_len: haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE,
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<F: FnMut(usize) -> bool> MatchContext for Accept<F> {
+    type Lookup = ();
+
+    #[inline(always)]
+    fn lookup(&self) -> Self::Lookup {}
+
+    fn accept(&mut self, len: usize) -> bool {
+        (self.f)(len)
+    }
+
+    fn visit<const LANG: u8>(&mut self, pattern_len: usize, haystack_len: usize) -> bool {
+        let matched_len = self.haystack_len - haystack_len;
+        let i = (matched_len * 3 + lang_index::<LANG>()) * self.pattern_len + pattern_len;
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        if word >= self.visited.len() {
+            self.visited.resize(word + 1, 0);
         }
+        let visited = self.visited[word] & bit != 0;
+        self.visited[word] |= bit;
+        visited
+    }
+}
+
+/// 3 langs: any, pinyin, romaji.
+#[inline(always)]
+fn lang_index<const LANG: u8>() -> usize {
+    match LANG {
+        1 => 1,
+        2 => 2,
+        _ => 0,
+    }
+}
+
+/// Subsequence matching context, wrapping another context.
+///
+/// Memoizes visited states to avoid exponential backtracking.
+pub(crate) struct Subsequence<C> {
+    inner: C,
+    /// Bitset of visited `(LANG, pattern_len, haystack_len)` states.
+    visited: Vec<u64>,
+    pattern_len: usize,
+    haystack_len: usize,
+}
+
+impl<C: MatchContext> Subsequence<C> {
+    pub fn new<HaystackStr>(pattern_len: usize, haystack: &HaystackStr, inner: C) -> Self
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        let pattern_len = pattern_len + 1;
+        let haystack_len = haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE + 1;
+        Self {
+            inner,
+            // 3 langs: any, pinyin, romaji
+            visited: vec![0; (3 * pattern_len * haystack_len).div_ceil(64)],
+            pattern_len,
+            haystack_len,
+        }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: MatchContext> MatchContext for Subsequence<C> {
+    type Lookup = C::Lookup;
+
+    const RECORD: bool = C::RECORD;
+    const SUBSEQUENCE: bool = true;
+
+    #[inline(always)]
+    fn lookup(&self) -> Self::Lookup {
+        self.inner.lookup()
+    }
+
+    fn record(&mut self, span: CharSpan) {
+        self.inner.record(span)
+    }
+
+    fn accept(&mut self, len: usize) -> bool {
+        self.inner.accept(len)
+    }
+
+    fn visit<const LANG: u8>(&mut self, pattern_len: usize, haystack_len: usize) -> bool {
+        let i = (lang_index::<LANG>() * self.pattern_len + pattern_len) * self.haystack_len
+            + haystack_len;
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        let visited = self.visited[word] & bit != 0;
+        self.visited[word] |= bit;
+        visited
     }
 }
 
@@ -54,6 +328,94 @@ pub trait OptionMatchExt: Sealed + Into<Option<Match>> + Sized {
 impl Sealed for Option<Match> {}
 impl OptionMatchExt for Option<Match> {}
 
+/// An iterator over all non-overlapping matches in a haystack.
+///
+/// Created by [`IbMatcher::find_iter()`].
+pub struct Matches<'m, 'a, 'h, HaystackStr = str>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    matcher: &'m IbMatcher<'a, HaystackStr>,
+    input: Input<'h, HaystackStr>,
+    is_ascii: bool,
+    /// Built once for all searches, which share the end of `input`.
+    #[cfg(feature = "pinyin-words")]
+    words_cache: Option<LookupCache<'m>>,
+    /// `None` if the iterator is exhausted.
+    pos: Option<usize>,
+}
+
+impl<'m, 'a, 'h, HaystackStr> Matches<'m, 'a, 'h, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    pub(crate) fn new(
+        matcher: &'m IbMatcher<'a, HaystackStr>,
+        input: Input<'h, HaystackStr>,
+    ) -> Self {
+        let is_ascii = input.span().is_ascii();
+        Self {
+            matcher,
+            is_ascii,
+            #[cfg(feature = "pinyin-words")]
+            words_cache: match is_ascii {
+                true => None,
+                false => matcher.words_cache(&input),
+            },
+            pos: Some(input.range.start),
+            input,
+        }
+    }
+}
+
+impl<'m, 'a, 'h, HaystackStr> Iterator for Matches<'m, 'a, 'h, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos?;
+        let input = Input {
+            haystack: self.input.haystack,
+            range: pos..self.input.range.end,
+            no_start: self.input.no_start || pos != self.input.range.start,
+            no_end: self.input.no_end,
+        };
+        if self.matcher.is_anchor_excluded(&input) {
+            self.pos = None;
+            return None;
+        }
+
+        // A sub-span of an ASCII-only span is still ASCII-only.
+        #[cfg(feature = "pinyin-words")]
+        let m = match &self.words_cache {
+            Some(cache) => self.matcher.find_with_lookup(input, self.is_ascii, cache),
+            None => self.matcher.find_with_is_ascii(input, self.is_ascii),
+        };
+        #[cfg(not(feature = "pinyin-words"))]
+        let m = self.matcher.find_with_is_ascii(input, self.is_ascii);
+        let Some(m) = m else {
+            self.pos = None;
+            return None;
+        };
+
+        self.pos = if m.end >= self.input.range.end {
+            None
+        } else if m.is_empty() {
+            // Empty matches are only possible with empty patterns, advance by one char to avoid yielding the same match forever
+            let haystack = unsafe { self.input.haystack.get_unchecked_from(m.end..) };
+            haystack
+                .char_len_next_strs()
+                .next()
+                .map(|(_c, len, _next)| m.end + len)
+        } else {
+            Some(m.end)
+        };
+        Some(m)
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) struct SubMatch {
     pub len: usize,
@@ -1,13 +1,16 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
 
 use bon::bon;
 
+#[cfg(any(feature = "pinyin", feature = "romaji"))]
+use crate::matcher::transliterate::Transliterator;
 use crate::{
     matcher::{
         ascii::AsciiMatcher,
         encoding::EncodedStr,
         input::Input,
-        matches::SubMatch,
+        lookup::HaystackLookup,
+        matches::{MatchContext, Record, SubMatch, Subsequence},
         pattern::{LangOnly, Pattern},
     },
     unicode::{CharToMonoLowercase, StrToMonoLowercase},
@@ -16,10 +19,16 @@ use crate::{
 pub mod analyze;
 pub mod encoding;
 pub mod input;
+mod lookup;
 mod matches;
 pub mod pattern;
 #[cfg(feature = "regex")]
+#[allow(unused)]
 mod regex_utils;
+mod score;
+mod set;
+#[cfg(any(feature = "pinyin", feature = "romaji"))]
+mod transliterate;
 
 mod ascii;
 #[cfg(feature = "pinyin")]
@@ -28,17 +37,24 @@ mod pinyin;
 mod romaji;
 
 pub use ascii::{PlainMatchConfig, PlainMatchConfigBuilder};
-pub use matches::{Match, OptionMatchExt};
+pub use matches::{CharSpan, Match, MatchKind, Matches, OptionMatchExt};
 #[cfg(feature = "pinyin")]
 pub use pinyin::*;
 #[cfg(feature = "romaji")]
 pub use romaji::*;
+pub use score::{ScoreConfig, ScoreConfigBuilder};
+pub use set::{IbMatcherSet, SetMatches};
 
 struct PatternChar<'a> {
     c: char,
     c_lowercase: char,
     s: &'a str,
     s_lowercase: &'a str,
+    /// The range of the char in the original pattern, in code units of `HaystackStr`.
+    range: Range<usize>,
+    /// [`Pinyin::homophone_key()`](crate::pinyin::Pinyin) of each reading, if [`PinyinMatchConfigBuilder::homophone`] is set.
+    #[cfg(feature = "pinyin")]
+    homophones: Box<[u16]>,
 }
 
 /// ## Design
@@ -49,7 +65,7 @@ struct PatternChar<'a> {
 /// - For matching more than 1000 strings, enable [`IbMatcherBuilder::analyze`] to optimize the pattern further. (The analysis costs ~65us, equivalent to about 220~1100 matches.)
 ///
 /// TODO: No-pinyin pattern optimization
-/// TODO: Anchors, `*_at`
+/// TODO: Anchors
 /// TODO: Unicode normalization
 /// TODO: No-hanzi haystack optimization (0.2/0.9%)
 /// TODO: If pattern doesn't contain `.`, only match before `.` in the haystack
@@ -70,9 +86,11 @@ where
     min_haystack_len: usize,
     starts_with: bool,
     ends_with: bool,
+    subsequence: bool,
 
     plain: Option<PlainMatchConfig>,
     mix_lang: bool,
+    score: ScoreConfig,
     #[cfg(feature = "pinyin")]
     pinyin: Option<PinyinMatcher<'a>>,
     #[cfg(feature = "romaji")]
@@ -108,6 +126,16 @@ where
         /// Only matches if the haystack ends with the pattern.
         #[builder(default = false)]
         ends_with: bool,
+        /// Allow the pattern to match non-adjacent chars in the haystack, like [fzf](https://github.com/junegunn/fzf).
+        ///
+        /// For example, pattern "pyeve" can match "拼音搜索Everything" if `subsequence` is `true`. Skipped chars can be got from [`Match::gaps()`] with [`IbMatcher::find_positions()`].
+        ///
+        /// Pinyin/romaji are still matched per hanzi/word, i.e. a skipped char is always skipped entirely.
+        ///
+        /// ## Performance
+        /// ASCII-only haystack optimization is not available, and each match costs at most `O(pattern.len() * haystack.len())`.
+        #[builder(default = false)]
+        subsequence: bool,
 
         /// `None` means not to match characters in the pattern as plain characters, i.e. match them only as pinyin/romaji, even if they are not valid pinyin/romaji characters.
         ///
@@ -119,6 +147,17 @@ where
         /// `true` may lead to unexpected matches, especially if [`PinyinNotation::AsciiFirstLetter`] is enabled, and also lower performance.
         #[builder(default = false)]
         mix_lang: bool,
+        /// Also transliterate the pattern to match romanized text in ASCII-only haystacks, i.e. the reverse direction of pinyin/romaji match. For example, pattern "拼音" can match "pinyin_notes.txt" if `transliterate_pattern` is `true`.
+        ///
+        /// Hanzi are transliterated to their pinyins in [`PinyinNotation::Ascii`](crate::pinyin::PinyinNotation::Ascii) (limited by [`PinyinMatchConfigBuilder::max_readings`]) if [`IbMatcherBuilder::pinyin`] is set, and Japanese words to their romajis if [`IbMatcherBuilder::romaji`] is set. ASCII chars are kept as is. The transliterations are matched case-insensitively. Their number is not limited, e.g. all the 3<sup>6</sup> pinyin transliterations of "行行行行行行" are matched.
+        ///
+        /// Only for UTF-8 haystacks, and not available with [`IbMatcherBuilder::subsequence`].
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        #[builder(default = false)]
+        transliterate_pattern: bool,
+        /// The weights of [`IbMatcher::score()`].
+        #[builder(default)]
+        score: ScoreConfig,
         #[cfg(feature = "pinyin")] mut pinyin: Option<PinyinMatchConfig<'a>>,
         #[cfg(feature = "romaji")] mut romaji: Option<RomajiMatchConfig<'a>>,
     ) -> Self {
@@ -138,7 +177,15 @@ where
 
         let pattern = pattern.pattern;
         let pattern_bytes = pattern.as_bytes().to_owned();
-        let pattern: String = pattern.char_index_strs().map(|(_, c, _)| c).collect();
+        let mut pattern_ranges = Vec::new();
+        let pattern: String = pattern
+            .char_len_next_strs()
+            .scan(0, |offset, (c, len, _)| {
+                pattern_ranges.push(*offset..*offset + len);
+                *offset += len;
+                Some(c)
+            })
+            .collect();
 
         let pattern_string = pattern;
         let pattern_s: &str = pattern_string.as_str();
@@ -151,13 +198,20 @@ where
         let pattern = pattern_string
             .char_indices()
             .zip(pattern_string_lowercase.char_indices())
-            .map(|((i, c), (i_lowercase, c_lowercase))| {
+            .zip(pattern_ranges)
+            .map(|(((i, c), (i_lowercase, c_lowercase)), range)| {
                 debug_assert_eq!(i, i_lowercase);
                 PatternChar {
                     c,
                     c_lowercase,
                     s: &pattern_s[i..],
                     s_lowercase: &pattern_s_lowercase[i..],
+                    range,
+                    #[cfg(feature = "pinyin")]
+                    homophones: pinyin
+                        .as_ref()
+                        .and_then(|pinyin| pinyin.homophone_keys(c))
+                        .unwrap_or_default(),
                 }
             })
             .collect::<Vec<_>>()
@@ -166,10 +220,14 @@ where
         #[cfg(feature = "pinyin")]
         if let Some(pinyin) = &pinyin {
             // TODO: If pattern does not contain any pinyin letter, then pinyin_data is not needed.
-            #[cfg(not(feature = "inmut-data"))]
-            assert!(pinyin.data.inited_notations().contains(pinyin.notations));
+            let notations = PinyinMatchConfig::required_notations(
+                pinyin.notations,
+                pinyin.fuzzy,
+                pinyin.syllable_prefix,
+            );
             #[cfg(feature = "inmut-data")]
-            pinyin.data.init_notations(pinyin.notations);
+            pinyin.data.init_notations(notations);
+            assert!(pinyin.data.inited_notations().contains(notations));
         }
 
         let analyzer = analyze::PatternAnalyzer::builder(pattern_s_lowercase)
@@ -187,6 +245,24 @@ where
             }
         }));
 
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        let transliterator = match transliterate_pattern
+            && HaystackStr::ELEMENT_LEN_BYTE == 1
+            && !pattern_s.is_ascii()
+        {
+            true => Transliterator::new(
+                pattern_s,
+                mix_lang,
+                #[cfg(feature = "pinyin")]
+                pinyin.as_ref(),
+                #[cfg(feature = "romaji")]
+                romaji.as_ref(),
+                starts_with,
+                ends_with,
+            ),
+            false => None,
+        };
+
         let min_haystack_len = match HaystackStr::ELEMENT_LEN_BYTE {
             1 => analyzer.min_haystack_len(),
             _ if pattern.is_empty() => 0,
@@ -202,6 +278,12 @@ where
 
         drop(analyzer);
 
+        // Transliterations can be shorter than the pattern, e.g. "e" of "饿"
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        let min_haystack_len = transliterator
+            .as_ref()
+            .map_or(min_haystack_len, |t| min_haystack_len.min(t.min_len()));
+
         #[cfg(feature = "pinyin")]
         let pinyin = pinyin.map(|config| {
             PinyinMatcher::builder(config)
@@ -213,8 +295,10 @@ where
         let ascii = AsciiMatcher::builder(&pattern_bytes)
             .maybe_plain(plain.as_ref())
             .starts_with(starts_with)
-            .ends_with(ends_with)
-            .build();
+            .ends_with(ends_with);
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        let ascii = ascii.maybe_transliterator(transliterator);
+        let ascii = ascii.build();
 
         Self {
             ascii,
@@ -222,6 +306,7 @@ where
             min_haystack_len,
             starts_with,
             ends_with,
+            subsequence,
 
             pattern,
             _pattern_string: pattern_string,
@@ -230,6 +315,7 @@ where
             plain,
 
             mix_lang,
+            score,
 
             #[cfg(feature = "pinyin")]
             pinyin,
@@ -247,50 +333,242 @@ where
     /// This routine searches for the first match of this pattern in the haystack given, and if found, returns a [`Match`]. The [`Match`] provides access to both the byte offsets of the match and [`Match::is_pattern_partial()`].
     ///
     /// Note that this should only be used if you want to find the entire match. If instead you just want to test the existence of a match, it’s potentially faster to use [`IbMatcher::is_match()`] instead of `IbMatcher::find().is_some()`.
-    pub fn find<'h>(&'a self, input: impl Into<Input<'h, HaystackStr>>) -> Option<Match>
+    pub fn find<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> Option<Match>
     where
         HaystackStr: 'h,
     {
         let input = input.into();
 
-        if self.starts_with && input.no_start {
+        if self.is_anchor_excluded(&input) {
             return None;
         }
 
-        let is_ascii = input.haystack.is_ascii();
+        let is_ascii = input.span().is_ascii();
         self.find_with_is_ascii(input, is_ascii)
     }
 
-    fn find_with_is_ascii<'h>(
+    /// Like [`IbMatcher::find()`], but only searches the haystack from `start`, in code units of `HaystackStr` (e.g. bytes for `str`).
+    ///
+    /// Unlike `find(&haystack[start..])`, [`IbMatcherBuilder::starts_with`] can only match if `start` is 0, and the returned offsets are relative to the whole haystack.
+    ///
+    /// ## Panics
+    /// If `start` is out of bounds or doesn't fall on a char boundary.
+    pub fn find_at(&self, haystack: &HaystackStr, start: usize) -> Option<Match> {
+        self.find(Self::input_at(haystack, start))
+    }
+
+    /// Like [`IbMatcher::find()`], but also reports which haystack chars are matched by which pattern chars, see [`Match::char_spans()`], which is always `Some` on the returned match.
+    ///
+    /// This is mainly useful for highlighting matched chars. It is slower than [`IbMatcher::find()`], so only use it on matches that will actually be displayed.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::{matcher::{IbMatcher, MatchKind, PinyinMatchConfig}, pinyin::PinyinNotation};
+    ///
+    /// let matcher = IbMatcher::builder("pysousuo")
+    ///     .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter))
+    ///     .build();
+    /// let m = matcher.find_positions("拼音搜索").unwrap();
+    /// let spans = m.char_spans().unwrap();
+    /// assert_eq!(spans.len(), 4);
+    /// assert_eq!(spans[0].pattern(), 0..1);
+    /// assert_eq!(spans[0].haystack(), 0..3);
+    /// assert_eq!(spans[0].kind(), MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter));
+    /// assert_eq!(spans[3].pattern(), 5..8);
+    /// assert_eq!(spans[3].haystack(), 9..12);
+    /// assert_eq!(spans[3].kind(), MatchKind::Pinyin(PinyinNotation::Ascii));
+    /// ```
+    pub fn find_positions<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> Option<Match>
+    where
+        HaystackStr: 'h,
+    {
+        let input = input.into();
+
+        #[cfg(feature = "pinyin-words")]
+        if !input.span().is_ascii() {
+            if let Some(cache) = self.words_cache(&input) {
+                return self.find_positions_with_lookup(input, &cache);
+            }
+        }
+
+        self.find_positions_with_lookup(input, ())
+    }
+
+    fn find_positions_with_lookup<L: HaystackLookup + MatchContext>(
+        &self,
+        input: Input<'_, HaystackStr>,
+        lookup: L,
+    ) -> Option<Match> {
+        if self.is_anchor_excluded(&input) {
+            return None;
+        }
+        let (haystack, end) = (input.haystack, input.range.end);
+        let is_ascii = input.span().is_ascii();
+        let mut m = self.find_with_lookup(input, is_ascii, lookup)?;
+
+        if self.pattern.is_empty() {
+            m.char_spans = Some(Default::default());
+            return Some(m);
+        }
+
+        // Matched by `ascii` with a transliteration, which is replayed by the transliterator instead
+        #[cfg(any(feature = "pinyin", feature = "romaji"))]
+        if let Some(transliterator) = self
+            .ascii
+            .transliterator()
+            .filter(|_| is_ascii && !self.subsequence)
+        {
+            let haystack = haystack.get(m.start..end).unwrap().as_bytes();
+            let (len, spans) = transliterator.char_spans(&self.pattern, haystack, m.start)?;
+            debug_assert_eq!(len, m.len());
+            m.char_spans = Some(spans);
+            return Some(m);
+        }
+
+        // Replay the match with recording. This keeps `find()` free of any recording cost.
+        let haystack = haystack.get(m.start..end).unwrap();
+        let mut ctx = Record {
+            spans: Vec::with_capacity(self.pattern.len()),
+            lookup,
+        };
+        let submatch = if self.subsequence {
+            let mut sub_ctx = Subsequence::new(self.pattern.len(), haystack, ctx);
+            let submatch = self.sub_test::<0xFF, _>(&self.pattern, haystack, 0, &mut sub_ctx);
+            ctx = sub_ctx.into_inner();
+            submatch
+        } else {
+            self.sub_test::<0xFF, _>(&self.pattern, haystack, 0, &mut ctx)
+        };
+        // The replay takes the same path as `find()`, so it can't fail. The replayed match is returned to keep the spans consistent with it anyway.
+        let submatch = submatch?;
+        debug_assert_eq!(submatch.len, m.len());
+        let mut spans = ctx.spans;
+        // Spans are recorded on the way back from the recursion
+        spans.reverse();
+        for span in spans.iter_mut() {
+            span.haystack = span.haystack.start + m.start..span.haystack.end + m.start;
+        }
+        Some(Match {
+            start: m.start,
+            end: m.start + submatch.len,
+            is_pattern_partial: submatch.is_pattern_partial,
+            char_spans: Some(spans.into_boxed_slice()),
+        })
+    }
+
+    /// Returns an iterator over all non-overlapping matches in the haystack given.
+    ///
+    /// Like [`regex::Regex::find_iter`](https://docs.rs/regex/latest/regex/struct.Regex.html#method.find_iter), an empty pattern yields an empty match at every char boundary of the haystack, including its end.
+    ///
+    /// All matches except the first one are searched with [`Input::no_start`], so at most one match will be yielded if [`IbMatcherBuilder::starts_with`] is `true`.
+    pub fn find_iter<'m, 'h>(
+        &'m self,
+        input: impl Into<Input<'h, HaystackStr>>,
+    ) -> Matches<'m, 'a, 'h, HaystackStr>
+    where
+        HaystackStr: 'h,
+    {
+        Matches::new(self, input.into())
+    }
+
+    /// ## Arguments
+    /// - `is_ascii`: Whether the span of `input` is ASCII-only.
+    pub(crate) fn find_with_is_ascii<'h>(
+        &self,
+        input: Input<'h, HaystackStr>,
+        is_ascii: bool,
+    ) -> Option<Match> {
+        #[cfg(feature = "pinyin-words")]
+        if !is_ascii {
+            if let Some(cache) = self.words_cache(&input) {
+                return self.find_with_lookup(input, is_ascii, &cache);
+            }
+        }
+
+        self.find_with_lookup(input, is_ascii, ())
+    }
+
+    /// The pinyin dictionary for strict readings, see [`PinyinMatchConfigBuilder::words()`].
+    #[cfg(feature = "pinyin-words")]
+    #[inline]
+    pub(crate) fn words(&self) -> Option<&crate::pinyin::PinyinWords> {
+        self.pinyin.as_ref().and_then(|pinyin| pinyin.config.words)
+    }
+
+    /// A [`LookupCache`](lookup::LookupCache) with the pinyins of chars in known words overridden, if [`IbMatcher::words()`] is set.
+    ///
+    /// The whole haystack is segmented into words, not only the span of `input`, so the cache can be reused by searches in any span ending at the same position.
+    ///
+    /// Callers should skip it for ASCII-only haystacks, as building it costs a pass over the haystack.
+    #[cfg(feature = "pinyin-words")]
+    pub(crate) fn words_cache(
+        &self,
+        input: &Input<'_, HaystackStr>,
+    ) -> Option<lookup::LookupCache<'_>> {
+        let words = self.words()?;
+        let cache = lookup::LookupCache::builder(input.haystack)
+            .range(input.range.clone())
+            .pinyin(true)
+            .words(words);
+        Some(cache.build())
+    }
+
+    /// See [`IbMatcher::find_with_is_ascii()`] and [`HaystackLookup`].
+    pub(crate) fn find_with_lookup<'h, L: HaystackLookup + MatchContext>(
         &self,
         input: Input<'h, HaystackStr>,
         is_ascii: bool,
+        mut lookup: L,
     ) -> Option<Match> {
-        debug_assert!(!(self.starts_with && input.no_start));
+        debug_assert!(!self.is_anchor_excluded(&input));
 
+        let offset = input.range.start;
         if self.pattern.is_empty() {
             return Some(Match {
-                start: 0,
-                end: 0,
+                start: offset,
+                end: offset,
                 is_pattern_partial: false,
+                char_spans: None,
             });
         }
 
-        let haystack = input.haystack;
-        if is_ascii {
-            return self.ascii.find(haystack.as_bytes()).div(HaystackStr::CHAR);
+        let haystack = input.span();
+        if is_ascii && !self.subsequence {
+            return self
+                .ascii
+                .find(haystack.as_bytes())
+                .div(HaystackStr::CHAR)
+                .map(|m| m.offset(offset));
         }
 
+        if self.subsequence {
+            self.find_with_context(
+                haystack,
+                offset,
+                &mut Subsequence::new(self.pattern.len(), haystack, lookup),
+            )
+        } else {
+            self.find_with_context(haystack, offset, &mut lookup)
+        }
+    }
+
+    fn find_with_context<C: MatchContext>(
+        &self,
+        haystack: &HaystackStr,
+        offset: usize,
+        ctx: &mut C,
+    ) -> Option<Match> {
         // TODO: ends_with optimization
         for (i, _c, str) in haystack.char_index_strs() {
             if self.is_haystack_too_short(str) {
                 break;
             }
-            if let Some(submatch) = self.sub_test::<0xFF>(&self.pattern, str, 0) {
+            if let Some(submatch) = self.sub_test::<0xFF, C>(&self.pattern, str, 0, ctx) {
                 return Some(Match {
-                    start: i,
-                    end: i + submatch.len,
+                    start: offset + i,
+                    end: offset + i + submatch.len,
                     is_pattern_partial: submatch.is_pattern_partial,
+                    char_spans: None,
                 });
             }
             if self.starts_with {
@@ -310,51 +588,132 @@ where
     {
         let input = input.into();
 
-        if self.starts_with && input.no_start {
+        if self.is_anchor_excluded(&input) {
             return false;
         }
 
-        let haystack = input.haystack;
-        if haystack.is_ascii() {
+        let haystack = input.span();
+        let is_ascii = haystack.is_ascii();
+        if is_ascii && !self.subsequence {
             return self.ascii.is_match(haystack.as_bytes());
         }
 
-        self.find_with_is_ascii(input, false).is_some()
+        self.find_with_is_ascii(input, is_ascii).is_some()
+    }
+
+    /// Like [`IbMatcher::is_match()`], but only searches the haystack from `start`. See [`IbMatcher::find_at()`] for details.
+    ///
+    /// ## Panics
+    /// If `start` is out of bounds or doesn't fall on a char boundary.
+    pub fn is_match_at(&self, haystack: &HaystackStr, start: usize) -> bool {
+        self.is_match(Self::input_at(haystack, start))
+    }
+
+    fn input_at<'h>(haystack: &'h HaystackStr, start: usize) -> Input<'h, HaystackStr> {
+        Input::builder(haystack)
+            .range(start..haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE)
+            .no_start(start != 0)
+            .build()
+    }
+
+    /// Whether [`IbMatcherBuilder::starts_with`]/[`IbMatcherBuilder::ends_with`] can never be satisfied because of [`Input::no_start`]/[`Input::no_end`].
+    #[inline]
+    pub(crate) fn is_anchor_excluded(&self, input: &Input<'_, HaystackStr>) -> bool {
+        self.starts_with && input.no_start || self.ends_with && input.no_end
     }
 
     /// This routine tests if this pattern matches the haystack at the start, and if found, returns a [`Match`]. The [`Match`] provides access to both the byte offsets of the match and [`Match::is_pattern_partial()`].
     ///
     /// ## Returns
-    /// - `Match.start()` is guaranteed to be 0.
+    /// - `Match.start()` is guaranteed to be the start of [`Input::range`] (0 by default).
     /// - If there are multiple possible matches, the longer ones are preferred. But the result is not guaranteed to be the longest one.
     pub fn test<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> Option<Match>
     where
         HaystackStr: 'h,
     {
         let input = input.into();
-        let haystack = input.haystack;
-        if self.is_haystack_too_short(haystack) || self.starts_with && input.no_start {
+        let haystack = input.span();
+        let offset = input.range.start;
+        if self.is_haystack_too_short(haystack) || self.is_anchor_excluded(&input) {
             return None;
         } else {
             if self.pattern.is_empty() {
                 return Some(Match {
-                    start: 0,
-                    end: 0,
+                    start: offset,
+                    end: offset,
                     is_pattern_partial: false,
+                    char_spans: None,
                 });
             }
         }
 
-        if haystack.is_ascii() {
-            return self.ascii.test(haystack.as_bytes()).div(HaystackStr::CHAR);
+        let is_ascii = haystack.is_ascii();
+        if is_ascii && !self.subsequence {
+            return self
+                .ascii
+                .test(haystack.as_bytes())
+                .div(HaystackStr::CHAR)
+                .map(|m| m.offset(offset));
         }
 
-        self.sub_test::<0xFF>(&self.pattern, haystack, 0)
-            .map(|submatch| Match {
-                start: 0,
-                end: submatch.len,
-                is_pattern_partial: submatch.is_pattern_partial,
-            })
+        // ASCII-only haystacks have no hanzi to look up
+        #[cfg(feature = "pinyin-words")]
+        if !is_ascii {
+            if let Some(cache) = self.words_cache(&input) {
+                return self.test_with_lookup(haystack, offset, &cache);
+            }
+        }
+
+        self.test_with_lookup(haystack, offset, ())
+    }
+
+    fn test_with_lookup<L: HaystackLookup + MatchContext>(
+        &self,
+        haystack: &HaystackStr,
+        offset: usize,
+        mut lookup: L,
+    ) -> Option<Match> {
+        if self.subsequence {
+            self.sub_test::<0xFF, _>(
+                &self.pattern,
+                haystack,
+                0,
+                &mut Subsequence::new(self.pattern.len(), haystack, lookup),
+            )
+        } else {
+            self.sub_test::<0xFF, _>(&self.pattern, haystack, 0, &mut lookup)
+        }
+        .map(|submatch| Match {
+            start: offset,
+            end: offset + submatch.len,
+            is_pattern_partial: submatch.is_pattern_partial,
+            char_spans: None,
+        })
+    }
+
+    /// Like [`IbMatcher::test()`], but calls `f` with the length of each possible match, in the same preference order, until `f` returns `true`.
+    ///
+    /// [`IbMatcherBuilder::starts_with`] is implied, and the ASCII-only haystack optimization is not used.
+    #[cfg(feature = "regex")]
+    pub(crate) fn test_each(&self, haystack: &HaystackStr, f: impl FnMut(usize) -> bool) -> bool {
+        if self.is_haystack_too_short(haystack) {
+            return false;
+        }
+        let mut ctx = matches::Accept::new(self.pattern.len(), haystack, f);
+        if self.pattern.is_empty() {
+            return ctx.accept(0);
+        }
+        if self.subsequence {
+            self.sub_test::<0xFF, _>(
+                &self.pattern,
+                haystack,
+                0,
+                &mut Subsequence::new(self.pattern.len(), haystack, ctx),
+            )
+        } else {
+            self.sub_test::<0xFF, _>(&self.pattern, haystack, 0, &mut ctx)
+        }
+        .is_some()
     }
 
     /// ## Arguments
@@ -362,11 +721,59 @@ where
     /// - `pattern`: Not empty.
     /// - `haystack`
     /// - `matched_len`: For tail-call optimization.
-    fn sub_test<const LANG: u8>(
+    /// - `ctx`: `&mut ()` for the default, or see [`MatchContext`].
+    ///
+    /// With [`IbMatcherBuilder::subsequence`], chars that can't be matched are skipped, except the first one, whose position is iterated by the caller.
+    #[inline]
+    fn sub_test<const LANG: u8, C: MatchContext>(
+        &self,
+        pattern: &[PatternChar],
+        mut haystack: &HaystackStr,
+        mut matched_len: usize,
+        ctx: &mut C,
+    ) -> Option<SubMatch> {
+        if !C::SUBSEQUENCE {
+            // Only memoized when enumerating all matches, where every state fails until the search ends
+            if ctx.visit::<LANG>(
+                pattern.len(),
+                haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE,
+            ) {
+                return None;
+            }
+            return self.sub_test_char::<LANG, C>(pattern, haystack, matched_len, ctx);
+        }
+
+        loop {
+            // Successes always end the whole search, so a visited state must have failed or be failing
+            if ctx.visit::<LANG>(
+                pattern.len(),
+                haystack.as_bytes().len() / HaystackStr::ELEMENT_LEN_BYTE,
+            ) {
+                return None;
+            }
+            if let Some(submatch) =
+                self.sub_test_char::<LANG, C>(pattern, haystack, matched_len, ctx)
+            {
+                return Some(submatch);
+            }
+            if matched_len == 0 {
+                return None;
+            }
+            let (_c, len, next) = haystack.char_len_next_strs().next()?;
+            haystack = next;
+            matched_len += len;
+        }
+    }
+
+    /// Tests if the pattern matches the haystack starting from its first char.
+    ///
+    /// See [`IbMatcher::sub_test()`] for the arguments.
+    fn sub_test_char<const LANG: u8, C: MatchContext>(
         &self,
         pattern: &[PatternChar],
         haystack: &HaystackStr,
         matched_len: usize,
+        ctx: &mut C,
     ) -> Option<SubMatch> {
         debug_assert!(!pattern.is_empty());
 
@@ -395,12 +802,21 @@ where
                 false => haystack_c == pattern_c.c,
             } {
                 // If haystack_c == pattern_c, then it is impossible that pattern_c is a pinyin letter and haystack_c is a hanzi.
-                return if pattern_next.is_empty() {
-                    Some(SubMatch::new(matched_len_next, false))
-                        .filter(|_| !self.ends_with || haystack_next.as_bytes().is_empty())
+                let submatch = if pattern_next.is_empty() {
+                    self.end_match(ctx, haystack_next, matched_len_next, false)
                 } else {
-                    self.sub_test::<0xFF>(pattern_next, haystack_next, matched_len_next)
+                    self.sub_test::<0xFF, C>(pattern_next, haystack_next, matched_len_next, ctx)
                 };
+                if submatch.is_some() {
+                    Self::record(
+                        ctx,
+                        pattern,
+                        None,
+                        matched_len..matched_len_next,
+                        MatchKind::Plain,
+                    );
+                }
+                return submatch;
             }
         }
 
@@ -423,17 +839,28 @@ where
                 1,
                 "non-UTF-8 romaji match is not yet supported"
             );
-            if let Some(m) = romaji.config.romanizer.romanize_and_try_for_each(
+            if let Some(m) = ctx.lookup().romanize_and_try_for_each(
+                &romaji.config.romanizer,
                 unsafe { str::from_utf8_unchecked(haystack.as_bytes()) },
                 |len, romaji| {
                     let match_len_next = matched_len + len;
-                    match self.sub_test_pinyin::<2>(
+                    match self.sub_test_pinyin::<2, C>(
                         pattern,
                         unsafe { haystack.get_unchecked_from(len..) },
                         match_len_next,
                         romaji,
+                        ctx,
                     ) {
-                        (true, Some(submatch)) => return Some(submatch),
+                        (true, Some(submatch)) => {
+                            Self::record(
+                                ctx,
+                                pattern,
+                                Some(romaji),
+                                matched_len..match_len_next,
+                                MatchKind::Romaji,
+                            );
+                            return Some(submatch);
+                        }
                         (true, None) => (),
                         (false, None) => (),
                         (false, Some(_)) => unreachable!(),
@@ -469,43 +896,227 @@ where
             // }
             // None
 
-            // Reduce total time by 45~65% compared to using `get_pinyins()`
-            if let Some(m) =
-                matcher
+            let haystack_index = ctx
+                .lookup()
+                .pinyin_index(haystack_c, haystack.as_bytes().len());
+
+            if let Some(homophone) = matcher
+                .config
+                .homophone
+                .filter(|_| !pattern_c.homophones.is_empty())
+            {
+                let tone = homophone == Homophone::Tone;
+                if matcher
                     .config
                     .data
-                    .get_pinyins_and_try_for_each(haystack_c, |pinyin| {
-                        for &notation in matcher.notations_prefix_group.iter() {
-                            let pinyin = pinyin.notation(notation).unwrap();
-                            match self.sub_test_pinyin::<1>(
+                    .get_pinyins_by_index_and_try_for_each(
+                        haystack_index,
+                        matcher.config.max_readings.get(),
+                        |pinyin| {
+                            pattern_c
+                                .homophones
+                                .contains(&pinyin.homophone_key(tone))
+                                .then_some(())
+                        },
+                    )
+                    .is_some()
+                {
+                    let submatch = if pattern_next.is_empty() {
+                        self.end_match(ctx, haystack_next, matched_len_next, false)
+                    } else {
+                        self.sub_test::<0xFF, C>(pattern_next, haystack_next, matched_len_next, ctx)
+                    };
+                    if submatch.is_some() {
+                        Self::record(
+                            ctx,
+                            pattern,
+                            None,
+                            matched_len..matched_len_next,
+                            MatchKind::Homophone,
+                        );
+                    }
+                    // A hanzi can't be matched by pinyin
+                    return submatch;
+                }
+            }
+
+            // Reduce total time by 45~65% compared to using `get_pinyins()`
+            if let Some(m) = matcher.config.data.get_pinyins_by_index_and_try_for_each(
+                haystack_index,
+                matcher.config.max_readings.get(),
+                |pinyin| {
+                    let mut prev = "";
+                    for &notation in matcher.notations_prefix_group.iter() {
+                        let pinyin = pinyin.notation(notation).unwrap();
+                        // e.g. AsciiInitial is mostly the same as AsciiFirstLetter
+                        if pinyin == prev {
+                            continue;
+                        }
+                        prev = pinyin;
+                        match self.sub_test_pinyin::<1, C>(
+                            pattern,
+                            haystack_next,
+                            matched_len_next,
+                            pinyin,
+                            ctx,
+                        ) {
+                            (true, Some(submatch)) => {
+                                Self::record(
+                                    ctx,
+                                    pattern,
+                                    Some(pinyin),
+                                    matched_len..matched_len_next,
+                                    MatchKind::Pinyin(notation),
+                                );
+                                return Some(submatch);
+                            }
+                            (true, None) => (),
+                            (false, None) => break,
+                            (false, Some(_)) => unreachable!(),
+                        }
+                    }
+                    for &notation in matcher.notations.iter() {
+                        let pinyin = pinyin.notation(notation).unwrap();
+                        match self.sub_test_pinyin::<1, C>(
+                            pattern,
+                            haystack_next,
+                            matched_len_next,
+                            pinyin,
+                            ctx,
+                        ) {
+                            (true, Some(submatch)) => {
+                                Self::record(
+                                    ctx,
+                                    pattern,
+                                    Some(pinyin),
+                                    matched_len..matched_len_next,
+                                    MatchKind::Pinyin(notation),
+                                );
+                                return Some(submatch);
+                            }
+                            (true, None) => (),
+                            (false, None) => (),
+                            (false, Some(_)) => unreachable!(),
+                        }
+                    }
+                    // Variants of this reading are only tried after its exact notations, not after the exact notations of all readings
+                    let mut test_variant = |notation, variant: &str| match self
+                        .sub_test_pinyin::<1, C>(
+                            pattern,
+                            haystack_next,
+                            matched_len_next,
+                            variant,
+                            ctx,
+                        ) {
+                        (true, Some(submatch)) => {
+                            Self::record(
+                                ctx,
                                 pattern,
-                                haystack_next,
-                                matched_len_next,
-                                pinyin,
+                                Some(variant),
+                                matched_len..matched_len_next,
+                                MatchKind::Pinyin(notation),
+                            );
+                            Some(submatch)
+                        }
+                        _ => None,
+                    };
+                    use crate::pinyin::PinyinNotation;
+                    let is_used = |notation| {
+                        matcher.notations_prefix_group.contains(&notation)
+                            || matcher.notations.contains(&notation)
+                    };
+                    if matcher.config.optional_tone && is_used(PinyinNotation::AsciiTone) {
+                        let ascii_tone = pinyin.notation(PinyinNotation::AsciiTone).unwrap();
+                        let (ascii, tone) = ascii_tone.split_at(ascii_tone.len() - 1);
+                        let pattern_s = pattern[0].s_lowercase;
+                        // A tone digit can be omitted, but a mismatched one can't be skipped as a plain char
+                        if !pattern_s
+                            .strip_prefix(ascii)
+                            .is_some_and(|s| s.starts_with(|c: char| c.is_ascii_digit()))
+                        {
+                            if let Some(submatch) = test_variant(PinyinNotation::AsciiTone, ascii) {
+                                return Some(submatch);
+                            }
+                        }
+                        // "0" for the neutral tone
+                        if tone == "5"
+                            && pattern_s
+                                .strip_prefix(ascii)
+                                .is_some_and(|s| s.starts_with('0'))
+                        {
+                            if let Some(submatch) =
+                                test_variant(PinyinNotation::AsciiTone, &format!("{ascii}0"))
+                            {
+                                return Some(submatch);
+                            }
+                        }
+                    }
+                    if (matcher.config.optional_tone || matcher.combining_marks)
+                        && is_used(PinyinNotation::Unicode)
+                    {
+                        if let Some(submatch) = pinyin.unicode_variants_and_try_for_each(
+                            matcher.config.optional_tone,
+                            matcher.combining_marks,
+                            |variant| test_variant(PinyinNotation::Unicode, variant),
+                        ) {
+                            return Some(submatch);
+                        }
+                    }
+                    if matcher.config.syllable_prefix {
+                        let ascii = pinyin.notation(PinyinNotation::Ascii).unwrap();
+                        // Only prefixes that the pattern starts with, the longest first
+                        let common_len = ascii
+                            .bytes()
+                            .zip(pattern[0].s_lowercase.bytes())
+                            .take_while(|(a, b)| a == b)
+                            .count();
+                        for len in (1..=common_len).rev() {
+                            let notation = match len {
+                                1 => PinyinNotation::AsciiFirstLetter,
+                                _ => PinyinNotation::Ascii,
+                            };
+                            // Already tested
+                            if (len == 1 || len == ascii.len()) && is_used(notation) {
+                                continue;
+                            }
+                            if let Some(submatch) = test_variant(notation, &ascii[..len]) {
+                                return Some(submatch);
+                            }
+                        }
+                    }
+                    if !matcher.config.umlaut.is_empty() {
+                        for &notation in matcher
+                            .notations_prefix_group
+                            .iter()
+                            .chain(matcher.notations.iter())
+                        {
+                            if let Some(submatch) = pinyin.umlaut_notation_and_try_for_each(
+                                notation,
+                                matcher.config.umlaut,
+                                |variant| test_variant(notation, variant),
                             ) {
-                                (true, Some(submatch)) => return Some(submatch),
-                                (true, None) => (),
-                                (false, None) => break,
-                                (false, Some(_)) => unreachable!(),
+                                return Some(submatch);
                             }
                         }
-                        for &notation in matcher.notations.iter() {
-                            let pinyin = pinyin.notation(notation).unwrap();
-                            match self.sub_test_pinyin::<1>(
-                                pattern,
-                                haystack_next,
-                                matched_len_next,
-                                pinyin,
+                    }
+                    if !matcher.config.fuzzy.is_empty() {
+                        for &notation in matcher
+                            .notations_prefix_group
+                            .iter()
+                            .chain(matcher.notations.iter())
+                        {
+                            if let Some(submatch) = pinyin.fuzzy_notation_and_try_for_each(
+                                notation,
+                                matcher.config.fuzzy,
+                                |variant| test_variant(notation, variant),
                             ) {
-                                (true, Some(submatch)) => return Some(submatch),
-                                (true, None) => (),
-                                (false, None) => (),
-                                (false, Some(_)) => unreachable!(),
+                                return Some(submatch);
                             }
                         }
-                        None
-                    })
-            {
+                    }
+                    None
+                },
+            ) {
                 return Some(m);
             }
         }
@@ -518,15 +1129,17 @@ where
     /// - `pattern`: Not empty.
     /// - `haystack`
     /// - `matched_len`: For tail-call optimization.
+    /// - `ctx`: See [`IbMatcher::sub_test()`]. The caller records the span of `pinyin` itself.
     ///
     /// ## Returns
     /// (pinyin_matched, submatch)
-    fn sub_test_pinyin<const LANG: u8>(
+    fn sub_test_pinyin<const LANG: u8, C: MatchContext>(
         &self,
         pattern: &[PatternChar],
         haystack_next: &HaystackStr,
         matched_len_next: usize,
         pinyin: &str,
+        ctx: &mut C,
     ) -> (bool, Option<SubMatch>) {
         debug_assert!(!pattern.is_empty());
         debug_assert_eq!(pinyin, pinyin.to_lowercase());
@@ -561,29 +1174,39 @@ where
             {
                 return (
                     true,
-                    Some(SubMatch::new(matched_len_next, true))
-                        .filter(|_| !self.ends_with || haystack_next.as_bytes().is_empty()),
+                    self.end_match(ctx, haystack_next, matched_len_next, true),
                 );
             }
         } else if pattern_s.starts_with(pinyin) {
             if pattern_s.len() == pinyin.len() {
                 return (
                     true,
-                    Some(SubMatch::new(matched_len_next, false))
-                        .filter(|_| !self.ends_with || haystack_next.as_bytes().is_empty()),
+                    self.end_match(ctx, haystack_next, matched_len_next, false),
                 );
             }
 
-            if let Some(submatch) = if self.mix_lang {
-                Self::sub_test::<0xFF>
+            let sub_test = if self.mix_lang {
+                Self::sub_test::<0xFF, C>
             } else {
-                Self::sub_test::<LANG>
-            }(
-                self,
-                &pattern[pinyin.chars().count()..],
-                haystack_next,
-                matched_len_next,
-            ) {
+                Self::sub_test::<LANG, C>
+            };
+            let pattern_next = &pattern[pinyin.chars().count()..];
+
+            // `'` after a pinyin is an explicit syllable boundary, e.g. "xi'an" matches "西安" but not "先"
+            if LANG == 1 && pattern_next[0].c == '\'' {
+                let pattern_next = &pattern_next[1..];
+                if let Some(submatch) = if pattern_next.is_empty() {
+                    self.end_match(ctx, haystack_next, matched_len_next, false)
+                } else {
+                    sub_test(self, pattern_next, haystack_next, matched_len_next, ctx)
+                } {
+                    return (true, Some(submatch));
+                }
+            }
+
+            if let Some(submatch) =
+                sub_test(self, pattern_next, haystack_next, matched_len_next, ctx)
+            {
                 return (true, Some(submatch));
             }
 
@@ -593,6 +1216,44 @@ where
         (false, None)
     }
 
+    /// Called when the whole pattern is matched.
+    #[inline(always)]
+    fn end_match<C: MatchContext>(
+        &self,
+        ctx: &mut C,
+        haystack_next: &HaystackStr,
+        matched_len_next: usize,
+        is_pattern_partial: bool,
+    ) -> Option<SubMatch> {
+        if self.ends_with && !haystack_next.as_bytes().is_empty() {
+            return None;
+        }
+        Some(SubMatch::new(matched_len_next, is_pattern_partial))
+            .filter(|_| ctx.accept(matched_len_next))
+    }
+
+    /// ## Arguments
+    /// - `pattern`: The remaining pattern when the span is matched.
+    /// - `pinyin`: The pinyin/romaji that matched the span, `None` for plain match.
+    #[inline(always)]
+    fn record<C: MatchContext>(
+        ctx: &mut C,
+        pattern: &[PatternChar],
+        pinyin: Option<&str>,
+        haystack: Range<usize>,
+        kind: MatchKind,
+    ) {
+        if C::RECORD {
+            // The pinyin may be longer than the pattern if the pattern is partial
+            let len = pinyin.map_or(1, |pinyin| pinyin.chars().count().min(pattern.len()));
+            ctx.record(CharSpan {
+                pattern: pattern[0].range.start..pattern[len - 1].range.end,
+                haystack,
+                kind,
+            });
+        }
+    }
+
     // /// Reduce ~10% miss case time at the cost of some hit case time.
     // fn is_haystack_too_short_with_pattern(
     //     _pattern: &[PatternChar],
@@ -706,6 +1367,45 @@ mod test {
         assert_match(matcher.test("柯尔"), Some((0, 6)));
     }
 
+    #[test]
+    fn apostrophe() {
+        let matcher = IbMatcher::builder("xian")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_match(matcher.test("先"), Some((0, 3)));
+        assert_match(matcher.test("西安"), Some((0, 6)));
+
+        let matcher = IbMatcher::builder("xi'an")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .analyze(true)
+            .build();
+        assert_match(matcher.test("先"), None);
+        assert_match(matcher.test("西安"), Some((0, 6)));
+        assert_match(matcher.test("xi'an"), Some((0, 5)));
+        assert_match(matcher.test("西'安"), Some((0, 7)));
+        assert_match(matcher.find("古都西安市"), Some((6, 6)));
+        assert_eq!(
+            char_spans(matcher.find_positions("西安")),
+            [
+                (0..2, 0..3, MatchKind::Pinyin(PinyinNotation::Ascii)),
+                (3..5, 3..6, MatchKind::Pinyin(PinyinNotation::Ascii))
+            ]
+        );
+
+        let matcher = IbMatcher::builder("xi'")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .ends_with(true)
+            .build();
+        assert_match(matcher.test("西"), Some((0, 3)));
+        assert_match(matcher.test("西安"), None);
+
+        // Only after a pinyin
+        let matcher = IbMatcher::builder("'an")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_match(matcher.test("安"), None);
+    }
+
     #[cfg(feature = "encoding")]
     #[test]
     fn test_u16() {
@@ -923,6 +1623,407 @@ mod test {
         assert_match(matcher.find("abc"), Some((0, 0)));
     }
 
+    fn find_iter_ranges<'h, HaystackStr>(
+        matcher: &IbMatcher<HaystackStr>,
+        input: impl Into<Input<'h, HaystackStr>>,
+    ) -> Vec<(usize, usize)>
+    where
+        HaystackStr: EncodedStr + ?Sized + 'h,
+    {
+        matcher
+            .find_iter(input)
+            .map(|m| (m.start(), m.len()))
+            .collect()
+    }
+
+    #[test]
+    fn find_iter() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(find_iter_ranges(&matcher, ""), []);
+        assert_eq!(
+            find_iter_ranges(&matcher, "pypy.py"),
+            [(0, 2), (2, 2), (5, 2)]
+        );
+        assert_eq!(
+            find_iter_ranges(&matcher, "拼音py拼音"),
+            [(0, 6), (6, 2), (8, 6)]
+        );
+        assert_eq!(find_iter_ranges(&matcher, "拼拼音音"), [(3, 6)]);
+
+        let matcher = IbMatcher::builder("pinyi")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .is_pattern_partial(true)
+            .build();
+        let matches = matcher.find_iter("拼音 拼音").collect::<Vec<_>>();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].range(), 7..13);
+        assert!(matches.iter().all(|m| m.is_pattern_partial()));
+
+        let matcher = IbMatcher::builder("")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_eq!(find_iter_ranges(&matcher, ""), [(0, 0)]);
+        assert_eq!(find_iter_ranges(&matcher, "ab"), [(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(find_iter_ranges(&matcher, "a拼"), [(0, 0), (1, 0), (4, 0)]);
+    }
+
+    #[test]
+    fn find_iter_starts_ends_with() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .starts_with(true)
+            .build();
+        assert_eq!(find_iter_ranges(&matcher, "pypy"), [(0, 2)]);
+        assert_eq!(find_iter_ranges(&matcher, "拼音拼音"), [(0, 6)]);
+        assert_eq!(
+            find_iter_ranges(&matcher, Input::builder("拼音拼音").no_start(true).build()),
+            []
+        );
+
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .ends_with(true)
+            .build();
+        assert_eq!(find_iter_ranges(&matcher, "pypy"), [(2, 2)]);
+        assert_eq!(find_iter_ranges(&matcher, "拼音拼音"), [(6, 6)]);
+
+        let matcher = IbMatcher::builder("")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .starts_with(true)
+            .build();
+        assert_eq!(find_iter_ranges(&matcher, "ab"), [(0, 0)]);
+    }
+
+    #[test]
+    fn input_range() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        let path = "拼音/py/拼音.txt";
+        let input = |range| Input::builder(path).range(range).build();
+        assert_eq!(matcher.find(input(3..13)).unwrap().range(), 7..9);
+        assert_eq!(matcher.find(input(10..path.len())).unwrap().range(), 10..16);
+        assert!(matcher.find(input(8..13)).is_none());
+        assert!(!matcher.is_match(input(3..8)));
+        assert!(matcher.is_match(input(3..9)));
+        assert_eq!(matcher.test(input(7..9)).unwrap().range(), 7..9);
+        assert!(matcher.test(input(6..9)).is_none());
+        assert_eq!(
+            find_iter_ranges(&matcher, input(3..path.len())),
+            [(7, 2), (10, 6)]
+        );
+
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .starts_with(true)
+            .ends_with(true)
+            .build();
+        assert!(matcher.is_match(input(10..16)));
+        assert!(matcher.is_match(input(7..9)));
+        assert!(!matcher.is_match(input(6..9)));
+        assert!(!matcher.is_match(input(10..17)));
+        assert_eq!(matcher.find(input(10..16)).unwrap().range(), 10..16);
+        assert_eq!(find_iter_ranges(&matcher, input(10..16)), [(10, 6)]);
+
+        let matcher = IbMatcher::builder("").build();
+        assert_eq!(
+            find_iter_ranges(&matcher, input(0..6)),
+            [(0, 0), (3, 0), (6, 0)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn input_range_not_char_boundary() {
+        let matcher = IbMatcher::builder("py").build();
+        matcher.find(Input::builder("拼音").range(1..6).build());
+    }
+
+    #[test]
+    fn input_no_end() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .ends_with(true)
+            .build();
+        assert!(matcher.is_match("拼音"));
+        assert!(!matcher.is_match(Input::builder("拼音").no_end(true).build()));
+        assert!(matcher
+            .find(Input::builder("py").no_end(true).build())
+            .is_none());
+        assert!(matcher
+            .test(Input::builder("py").no_end(true).build())
+            .is_none());
+
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert!(matcher.is_match(Input::builder("拼音").no_end(true).build()));
+    }
+
+    fn char_spans(m: Option<Match>) -> Vec<(Range<usize>, Range<usize>, MatchKind)> {
+        m.unwrap()
+            .char_spans()
+            .unwrap()
+            .iter()
+            .map(|span| (span.pattern(), span.haystack(), span.kind()))
+            .collect()
+    }
+
+    #[test]
+    fn find_positions() {
+        let matcher = IbMatcher::builder("pysousuo")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(
+            char_spans(matcher.find_positions("a拼音搜索")),
+            [
+                (
+                    0..1,
+                    1..4,
+                    MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter)
+                ),
+                (
+                    1..2,
+                    4..7,
+                    MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter)
+                ),
+                (2..5, 7..10, MatchKind::Pinyin(PinyinNotation::Ascii)),
+                (5..8, 10..13, MatchKind::Pinyin(PinyinNotation::Ascii)),
+            ]
+        );
+        assert!(matcher.find_positions("拼音").is_none());
+        assert!(matcher.find("拼音搜索").unwrap().char_spans().is_none());
+
+        let matcher = IbMatcher::builder("a拼y")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .is_pattern_partial(true)
+            .build();
+        assert_eq!(
+            char_spans(matcher.find_positions("bA拼音")),
+            [
+                (0..1, 1..2, MatchKind::Plain),
+                (1..4, 2..5, MatchKind::Plain),
+                (4..5, 5..8, MatchKind::Pinyin(PinyinNotation::Ascii)),
+            ]
+        );
+        assert_eq!(
+            char_spans(matcher.find_positions(Input::builder("bA拼音A拼音").range(2..15).build())),
+            [
+                (0..1, 8..9, MatchKind::Plain),
+                (1..4, 9..12, MatchKind::Plain),
+                (4..5, 12..15, MatchKind::Pinyin(PinyinNotation::Ascii)),
+            ]
+        );
+
+        let matcher = IbMatcher::builder("ab").build();
+        assert_eq!(
+            char_spans(matcher.find_positions("cab")),
+            [
+                (0..1, 1..2, MatchKind::Plain),
+                (1..2, 2..3, MatchKind::Plain)
+            ]
+        );
+
+        let matcher = IbMatcher::builder("").build();
+        assert_eq!(char_spans(matcher.find_positions("ab")), []);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn find_positions_u16() {
+        use widestring::u16str;
+
+        let matcher = IbMatcher::builder(u16str!("a拼yin"))
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_eq!(
+            char_spans(matcher.find_positions(u16str!("bA拼音"))),
+            [
+                (0..1, 1..2, MatchKind::Plain),
+                (1..2, 2..3, MatchKind::Plain),
+                (2..5, 3..4, MatchKind::Pinyin(PinyinNotation::Ascii)),
+            ]
+        );
+    }
+
+    #[test]
+    fn subsequence() {
+        let matcher = IbMatcher::builder("pyeve")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_match!(matcher.find("拼音搜索Everything"), None);
+
+        let matcher = IbMatcher::builder("pyeve")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .subsequence(true)
+            .build();
+        assert_match!(matcher.find("拼音搜索Everything"), Some((0, 15)));
+        assert_match!(matcher.find("a拼b音c搜索Eve"), Some((1, 17)));
+        assert_match!(matcher.test("a拼b音c搜索Eve"), None);
+        assert_match!(matcher.find("Eve拼音"), None);
+        let m = matcher.find_positions("拼音搜索Everything").unwrap();
+        assert!(m.gaps().eq(std::iter::once(6..12)));
+        assert_eq!(
+            char_spans(Some(m)),
+            [
+                (
+                    0..1,
+                    0..3,
+                    MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter)
+                ),
+                (
+                    1..2,
+                    3..6,
+                    MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter)
+                ),
+                (2..3, 12..13, MatchKind::Plain),
+                (3..4, 13..14, MatchKind::Plain),
+                (4..5, 14..15, MatchKind::Plain),
+            ]
+        );
+
+        // ASCII-only haystack
+        let matcher = IbMatcher::builder("abc").subsequence(true).build();
+        assert_match!(matcher.find("xa_b_c"), Some((1, 5)));
+        assert!(matcher.is_match("a-b-c"));
+        assert!(!matcher.is_match("a-c-b"));
+        assert_match!(matcher.test("a-b-c"), Some((0, 5)));
+        assert!(matcher
+            .find_positions("a-bc")
+            .unwrap()
+            .gaps()
+            .eq(std::iter::once(1..2)));
+
+        let matcher = IbMatcher::builder("abc")
+            .subsequence(true)
+            .starts_with(true)
+            .ends_with(true)
+            .build();
+        assert!(matcher.is_match("a-b-c"));
+        assert!(!matcher.is_match("a-b-cd"));
+        assert!(!matcher.is_match("_a-b-c"));
+    }
+
+    #[test]
+    fn subsequence_backtracking() {
+        // Would take forever without memoization
+        let matcher = IbMatcher::builder(&*("a".repeat(30) + "b"))
+            .subsequence(true)
+            .build();
+        let haystack = "a".repeat(200) + "拼";
+        assert!(!matcher.is_match(haystack.as_str()));
+        assert_match!(matcher.find((haystack + "b").as_str()), Some((0, 204)));
+    }
+
+    #[test]
+    fn find_at() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(matcher.find_at("拼音拼音", 0).unwrap().range(), 0..6);
+        assert_eq!(matcher.find_at("拼音拼音", 3).unwrap().range(), 6..12);
+        assert!(matcher.find_at("拼音拼音", 9).is_none());
+        assert!(matcher.is_match_at("pypy", 2));
+        assert!(!matcher.is_match_at("pypy", 3));
+
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .starts_with(true)
+            .build();
+        assert!(matcher.is_match_at("pypy", 0));
+        assert!(!matcher.is_match_at("pypy", 2));
+        assert!(matcher.find_at("拼音拼音", 6).is_none());
+    }
+
+    #[test]
+    fn borrow() {
+        // The matcher only needs to be borrowed for the call, not for the pattern lifetime
+        fn find<'a>(matcher: &IbMatcher<'a>) -> Option<Range<usize>> {
+            matcher
+                .find("py")
+                .or(matcher.find_at("py", 0))
+                .map(|m| m.range())
+        }
+        fn find_positions<'a>(matcher: &IbMatcher<'a>) -> Option<i32> {
+            matcher.find_positions("py")?;
+            matcher.score("py")
+        }
+
+        let matcher = IbMatcher::builder("py").build();
+        assert_eq!(find(&matcher), Some(0..2));
+        assert!(find_positions(&matcher).is_some());
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn find_iter_u16() {
+        use widestring::u16str;
+
+        let matcher = IbMatcher::builder(u16str!("py"))
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(
+            find_iter_ranges(&matcher, u16str!("pypy")),
+            [(0, 2), (2, 2)]
+        );
+        assert_eq!(
+            find_iter_ranges(&matcher, u16str!("拼音py拼音")),
+            [(0, 2), (2, 2), (4, 2)]
+        );
+
+        let matcher = IbMatcher::builder(u16str!(""))
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_eq!(
+            find_iter_ranges(&matcher, u16str!("a拼")),
+            [(0, 0), (1, 0), (2, 0)]
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn find_iter_u32() {
+        use widestring::u32str;
+
+        let matcher = IbMatcher::builder(u32str!("py"))
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(
+            find_iter_ranges(&matcher, u32str!("拼音py拼音")),
+            [(0, 2), (2, 2), (4, 2)]
+        );
+    }
+
     #[test]
     fn ends_with() {
         let matcher = IbMatcher::builder("xing")
@@ -1,9 +1,11 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, num::NonZeroUsize};
 
 use bon::{bon, builder, Builder};
 
-use crate::pinyin::{PinyinData, PinyinNotation};
+use crate::pinyin::{FuzzyPinyin, PinyinData, PinyinNotation, UmlautSpelling};
 
+/// An apostrophe (`'`, 隔音符号) after a pinyin in the pattern is an explicit syllable boundary, as in IMEs, e.g. "xi'an" matches "西安" but not "先" (xiān). See also [`PinyinData::segment()`].
+///
 /// ## Performance
 /// To avoid initialization cost, you should share one `data` across all configs by either passing `&data`:
 /// ```
@@ -25,10 +27,42 @@ pub struct PinyinMatchConfig<'a> {
     #[builder(start_fn)]
     pub(crate) notations: PinyinNotation,
 
+    /// Fuzzy pinyin (模糊音) equivalences, e.g. [`FuzzyPinyin::ZZh`] lets "zongguo" match "中国".
+    ///
+    /// [`PinyinNotation::Unicode`] is not supported. If not empty, `data` also requires [`PinyinNotation::Ascii`].
+    #[builder(default = FuzzyPinyin::empty())]
+    pub(crate) fuzzy: FuzzyPinyin,
+
+    /// Additional spellings of "ü" that [`PinyinNotation::Ascii`] and [`PinyinNotation::AsciiTone`] accept, e.g. [`UmlautSpelling::U`] lets "nu" match "女" (nǚ) as well as "nv".
+    #[builder(default = UmlautSpelling::empty())]
+    pub(crate) umlaut: UmlautSpelling,
+
+    /// Let the tones of [`PinyinNotation::AsciiTone`] and [`PinyinNotation::Unicode`] be optional per syllable, but still constrain the match if present, e.g. "pin1yin" and "shi4jie" match "拼音" and "世界", but "shi2jie" doesn't match "世界".
+    ///
+    /// - [`PinyinNotation::AsciiTone`]: "0" is also accepted for the neutral tone (轻声) besides "5", e.g. "de0". Unlike also enabling [`PinyinNotation::Ascii`], a mismatched tone digit can't be matched as a plain char instead.
+    /// - [`PinyinNotation::Unicode`]: Tone marks can be omitted, e.g. "pīnyin" and "lü" match "拼音" and "绿" (lǜ).
+    ///
+    /// Regardless of this option, tone marks of [`PinyinNotation::Unicode`] can also be combining marks (NFD) as produced by some IMEs, e.g. "pi\u{304}n".
+    #[builder(default = false)]
+    pub(crate) optional_tone: bool,
+
+    /// Let each syllable be matched by any prefix of its [`PinyinNotation::Ascii`] pinyin, not only the first letter or the whole pinyin, e.g. "pinyso" and "zhguo" match "拼音搜索" and "中国".
+    ///
+    /// Prefixes of fuzzy variants and "ü" spellings are not matched. If `true`, `data` also requires [`PinyinNotation::Ascii`].
+    ///
+    /// This can lead to much more false positives, and also lower performance.
+    #[builder(default = false)]
+    pub(crate) syllable_prefix: bool,
+
+    /// Let each hanzi in the pattern also match other hanzi sharing a reading with it (同音字), e.g. "因乐" matches "音乐", for when the exact hanzi is misremembered or mistyped.
+    ///
+    /// Readings of both hanzi are limited by [`PinyinMatchConfigBuilder::max_readings`]. Homophones are only matched if the hanzi itself doesn't match, and are scored lower than plain chars, see [`ScoreConfigBuilder::homophone`](super::ScoreConfigBuilder::homophone).
+    pub(crate) homophone: Option<Homophone>,
+
     /// Default: `new()` on [`PinyinMatchConfigBuilder::build()`]
     ///
     /// Must be inited with required notations if `inmut-data` feature is not enabled.
-    #[builder(default = Cow::Owned(PinyinData::new(notations)))]
+    #[builder(default = Cow::Owned(PinyinData::new(PinyinMatchConfig::required_notations(notations, fuzzy, syllable_prefix))))]
     #[builder(with = |data: &'a PinyinData| Cow::Borrowed(data))]
     pub(crate) data: Cow<'a, PinyinData>,
 
@@ -38,6 +72,20 @@ pub struct PinyinMatchConfig<'a> {
 
     #[builder(default = true)]
     pub(crate) allow_partial_pattern: bool,
+
+    /// Only match the first N readings of each hanzi, in the order of [`PinyinData::get_pinyins()`], i.e. the primary reading first.
+    ///
+    /// This cuts false positives of heavily polyphonic chars (多音字), e.g. with `1`, "hang" no longer matches "行" (xíng). But primary readings are not frequency based and not always the most common ones (see [`PinyinData::get_primary_pinyin()`]), so common readings can also be cut, e.g. with `1`, "ditu" doesn't match "地图" (de).
+    ///
+    /// Default: all readings
+    #[builder(default = NonZeroUsize::MAX)]
+    pub(crate) max_readings: NonZeroUsize,
+
+    /// Strict readings: hanzi in known words can only match their readings in the words, e.g. "zhongqing" no longer matches "重庆" (chóng qìng).
+    ///
+    /// Only for [`IbMatcher`](super::IbMatcher), `regex` is not affected.
+    #[cfg(feature = "pinyin-words")]
+    pub(crate) words: Option<&'a crate::pinyin::PinyinWords>,
 }
 
 impl<'a> PinyinMatchConfig<'a> {
@@ -46,22 +94,72 @@ impl<'a> PinyinMatchConfig<'a> {
         Self::builder(notations).build()
     }
 
+    /// The notations `data` must be inited with.
+    pub(crate) fn required_notations(
+        notations: PinyinNotation,
+        fuzzy: FuzzyPinyin,
+        syllable_prefix: bool,
+    ) -> PinyinNotation {
+        if fuzzy.is_empty() && !syllable_prefix {
+            notations
+        } else {
+            notations | PinyinNotation::Ascii
+        }
+    }
+
+    /// [`Pinyin::homophone_key()`](crate::pinyin::Pinyin) of each reading of `c`, deduplicated. `None` if [`PinyinMatchConfigBuilder::homophone`] is not set.
+    pub(crate) fn homophone_keys(&self, c: char) -> Option<Box<[u16]>> {
+        let tone = self.homophone? == Homophone::Tone;
+        let mut keys = Vec::new();
+        self.data.get_pinyins_by_index_and_try_for_each(
+            PinyinData::get_pinyin_index(c),
+            self.max_readings.get(),
+            |pinyin| {
+                let key = pinyin.homophone_key(tone);
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+                None::<()>
+            },
+        );
+        Some(keys.into())
+    }
+
     /// See [`PinyinMatchConfig`].
     pub fn shallow_clone(&'a self) -> Self {
         Self {
             notations: self.notations,
+            fuzzy: self.fuzzy,
+            umlaut: self.umlaut,
+            optional_tone: self.optional_tone,
+            syllable_prefix: self.syllable_prefix,
+            homophone: self.homophone,
             data: Cow::Borrowed(self.data.as_ref()),
             case_insensitive: self.case_insensitive,
             allow_partial_pattern: self.allow_partial_pattern,
+            max_readings: self.max_readings,
+            #[cfg(feature = "pinyin-words")]
+            words: self.words,
         }
     }
 }
 
+/// See [`PinyinMatchConfigBuilder::homophone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Homophone {
+    /// The tones must be the same, e.g. "音" (yīn) matches "因" (yīn) but not "银" (yín).
+    Tone,
+    /// The tones are ignored, e.g. "音" (yīn) matches both "因" (yīn) and "银" (yín).
+    Toneless,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct PinyinAnalyzeResult {
-    /// - If [`PinyinNotation::Ascii`] and [`PinyinNotation::AsciiFirstLetter`] are both enabled, [`PinyinNotation::Ascii`] is only considered used if the pattern uses any non-single-letter pinyin from [`PinyinNotation::Ascii`].
+    /// - If [`PinyinNotation::Ascii`] or [`PinyinNotation::AsciiInitial`] and [`PinyinNotation::AsciiFirstLetter`] are both enabled, the former is only considered used if the pattern uses any non-single-letter pinyin from it.
     pub used_notations: PinyinNotation,
     pub partial_pattern: bool,
+    /// Whether [`PinyinNotation::Unicode`] is enabled and the pattern contains combining marks, i.e. decomposed pinyins need to be matched.
+    pub combining_marks: bool,
 }
 
 impl Default for PinyinAnalyzeResult {
@@ -69,6 +167,7 @@ impl Default for PinyinAnalyzeResult {
         Self {
             used_notations: PinyinNotation::empty(),
             partial_pattern: false,
+            combining_marks: false,
         }
     }
 }
@@ -78,12 +177,14 @@ pub(crate) struct PinyinMatcher<'a> {
     pub notations_prefix_group: Box<[PinyinNotation]>,
     pub notations: Box<[PinyinNotation]>,
     pub partial_pattern: bool,
+    pub combining_marks: bool,
 }
 
 #[bon]
 impl<'a> PinyinMatcher<'a> {
-    pub const ORDERED_PINYIN_NOTATIONS: [PinyinNotation; 10] = [
+    pub const ORDERED_PINYIN_NOTATIONS: [PinyinNotation; 15] = [
         PinyinNotation::AsciiFirstLetter,
+        PinyinNotation::AsciiInitial,
         PinyinNotation::Ascii,
         PinyinNotation::AsciiTone,
         PinyinNotation::Unicode,
@@ -93,6 +194,18 @@ impl<'a> PinyinMatcher<'a> {
         PinyinNotation::DiletterThunisoft,
         PinyinNotation::DiletterXiaohe,
         PinyinNotation::DiletterZrm,
+        PinyinNotation::DiletterCustom,
+        PinyinNotation::Zhuyin,
+        PinyinNotation::ZhuyinTone,
+        PinyinNotation::ZhuyinDaqian,
+    ];
+
+    /// Each notation is a prefix of the next one, so if one doesn't match, the next ones can't either.
+    const PREFIX_GROUP_NOTATIONS: [PinyinNotation; 4] = [
+        PinyinNotation::AsciiFirstLetter,
+        PinyinNotation::AsciiInitial,
+        PinyinNotation::Ascii,
+        PinyinNotation::AsciiTone,
     ];
 
     #[builder]
@@ -102,35 +215,19 @@ impl<'a> PinyinMatcher<'a> {
     ) -> Self {
         let used_notations = analyze.used_notations;
 
+        let prefix_group = PinyinNotation::from_iter(Self::PREFIX_GROUP_NOTATIONS);
         let (notations_prefix_group, unprefixable_notations) = match used_notations
-            .intersection(
-                PinyinNotation::AsciiFirstLetter
-                    | PinyinNotation::Ascii
-                    | PinyinNotation::AsciiTone,
-            )
+            .intersection(prefix_group)
             .bits()
             .count_ones()
         {
-            count if count > 1 => {
-                let mut notations = Vec::with_capacity(count as usize);
-                if used_notations.contains(PinyinNotation::AsciiFirstLetter) {
-                    notations.push(PinyinNotation::AsciiFirstLetter);
-                }
-                if used_notations.contains(PinyinNotation::Ascii) {
-                    notations.push(PinyinNotation::Ascii);
-                }
-                if used_notations.contains(PinyinNotation::AsciiTone) {
-                    notations.push(PinyinNotation::AsciiTone);
-                }
-                (
-                    notations,
-                    used_notations.difference(
-                        PinyinNotation::AsciiFirstLetter
-                            | PinyinNotation::Ascii
-                            | PinyinNotation::AsciiTone,
-                    ),
-                )
-            }
+            count if count > 1 => (
+                Self::PREFIX_GROUP_NOTATIONS
+                    .into_iter()
+                    .filter(|&notation| used_notations.contains(notation))
+                    .collect(),
+                used_notations.difference(prefix_group),
+            ),
             _ => (Vec::new(), used_notations),
         };
         let mut notations = Vec::with_capacity(unprefixable_notations.bits().count_ones() as usize);
@@ -142,6 +239,7 @@ impl<'a> PinyinMatcher<'a> {
 
         Self {
             partial_pattern: analyze.partial_pattern,
+            combining_marks: analyze.combining_marks,
             notations_prefix_group: notations_prefix_group.into_boxed_slice(),
             notations: notations.into_boxed_slice(),
             config,
@@ -151,7 +249,11 @@ impl<'a> PinyinMatcher<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{assert_match, matcher::IbMatcher};
+    use crate::{
+        assert_match,
+        matcher::{IbMatcher, MatchKind},
+        pinyin::{FuzzyPinyin, UmlautSpelling},
+    };
 
     use super::*;
 
@@ -163,6 +265,564 @@ mod tests {
         )
     }
 
+    #[test]
+    fn fuzzy() {
+        let m = IbMatcher::builder("zongguo")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_match!(m.find("中国"), None);
+
+        let fuzzy = FuzzyPinyin::ZZh | FuzzyPinyin::NL | FuzzyPinyin::FH | FuzzyPinyin::AnAng;
+        for analyze in [false, true] {
+            let pinyin = PinyinMatchConfig::builder(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            )
+            .fuzzy(fuzzy)
+            .build();
+            let m = IbMatcher::builder("zongguo")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("中国"), Some((0, 6)));
+            assert_match!(m.find("中国"), Some((0, 6)));
+
+            let m = IbMatcher::builder("lan")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("南"), Some((0, 3)));
+            assert_match!(m.find("囊"), Some((0, 3)));
+            assert_match!(m.find("农"), None);
+
+            let m = IbMatcher::builder("fong")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("红"), Some((0, 3)));
+
+            // First letter
+            let m = IbMatcher::builder("lg")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("南瓜"), Some((0, 6)));
+        }
+
+        let m = IbMatcher::builder("zong1")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::AsciiTone)
+                    .fuzzy(fuzzy)
+                    .build(),
+            )
+            .build();
+        assert_match!(m.find("中"), Some((0, 3)));
+
+        // zong: zs
+        let m = IbMatcher::builder("zsgo")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::DiletterXiaohe)
+                    .fuzzy(fuzzy)
+                    .build(),
+            )
+            .analyze(true)
+            .build();
+        assert_match!(m.find("中国"), Some((0, 6)));
+        assert_eq!(
+            m.find_positions("中国").unwrap().char_spans().unwrap()[0].kind(),
+            MatchKind::Pinyin(PinyinNotation::DiletterXiaohe)
+        );
+    }
+
+    #[test]
+    fn umlaut() {
+        let m = IbMatcher::builder("nu")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert_match!(m.find("女"), None);
+        assert_match!(m.find("努"), Some((0, 3)));
+
+        for analyze in [false, true] {
+            let pinyin = PinyinMatchConfig::builder(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            )
+            .umlaut(UmlautSpelling::all())
+            .build();
+            for pattern in [
+                "nv", "nu", "nyu", "nu:", "nvhai", "nuhai", "nyuhai", "nu:hai",
+            ] {
+                let m = IbMatcher::builder(pattern)
+                    .pinyin(pinyin.shallow_clone())
+                    .analyze(analyze)
+                    .build();
+                assert!(m.is_match("女孩"), "{pattern}");
+            }
+            let m = IbMatcher::builder("lyue")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("战略"), Some((3, 3)));
+            let m = IbMatcher::builder("nu")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("努"), Some((0, 3)));
+        }
+
+        let m = IbMatcher::builder("nyu3")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::AsciiTone)
+                    .umlaut(UmlautSpelling::Yu)
+                    .build(),
+            )
+            .build();
+        assert_match!(m.find("女"), Some((0, 3)));
+        assert_eq!(
+            m.find_positions("女").unwrap().char_spans().unwrap()[0].kind(),
+            MatchKind::Pinyin(PinyinNotation::AsciiTone)
+        );
+    }
+
+    #[test]
+    fn optional_tone() {
+        let m = IbMatcher::builder("pin1yin")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::AsciiTone))
+            .build();
+        assert!(!m.is_match("拼音"));
+
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::AsciiTone)
+            .optional_tone(true)
+            .build();
+        for analyze in [false, true] {
+            let m = |pattern| {
+                IbMatcher::builder(pattern)
+                    .pinyin(pinyin.shallow_clone())
+                    .analyze(analyze)
+                    .build()
+            };
+            for pattern in ["pin1yin1", "pin1yin", "pinyin1", "pinyin"] {
+                assert!(m(pattern).is_match("拼音"), "{pattern}");
+            }
+            assert_match!(m("shi4jie").find("世界"), Some((0, 6)));
+            assert_match!(m("shijie4").find("世界"), Some((0, 6)));
+            assert_match!(m("shi2jie").find("世界"), None);
+            assert_match!(m("shi2jie").find("世2界"), None);
+            assert_match!(m("de0").find("的"), Some((0, 3)));
+            assert_match!(m("de5").find("的"), Some((0, 3)));
+            assert_match!(m("hao0").find("好"), None);
+            assert_match!(m("hao3").find("好"), Some((0, 3)));
+
+            let m = IbMatcher::builder("pin1yi")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .is_pattern_partial(true)
+                .build();
+            assert_match!(m.find("拼音"), Some((0, 6)));
+        }
+    }
+
+    #[test]
+    fn unicode_optional_tone() {
+        let m = IbMatcher::builder("pīnyin")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Unicode))
+            .build();
+        assert!(!m.is_match("拼音"));
+
+        let pinyin = PinyinMatchConfig::builder(PinyinNotation::Unicode)
+            .optional_tone(true)
+            .build();
+        for analyze in [false, true] {
+            let m = |pattern| {
+                IbMatcher::builder(pattern)
+                    .pinyin(pinyin.shallow_clone())
+                    .analyze(analyze)
+                    .build()
+            };
+            for pattern in ["pīnyīn", "pīnyin", "pinyīn", "pinyin", "pi\u{304}nyin"] {
+                assert!(m(pattern).is_match("拼音"), "{pattern}");
+            }
+            assert!(!m("pínyin").is_match("拼音"));
+            assert!(!m("pi\u{301}nyin").is_match("拼音"));
+            // 女 (nǚ)
+            assert_match!(m("nü").find("女"), Some((0, 3)));
+            assert_match!(m("nu\u{308}").find("女"), Some((0, 3)));
+            assert_match!(m("nǚ").find("女"), Some((0, 3)));
+            assert_match!(m("nǜ").find("女"), None);
+        }
+    }
+
+    #[test]
+    fn unicode_combining_marks() {
+        for analyze in [false, true] {
+            let m = |pattern| {
+                IbMatcher::builder(pattern)
+                    .pinyin(PinyinMatchConfig::notations(PinyinNotation::Unicode))
+                    .analyze(analyze)
+                    .build()
+            };
+            assert_match!(m("pi\u{304}nyi\u{304}n").find("拼音"), Some((0, 6)));
+            assert_match!(m("pi\u{304}nyīn").find("拼音"), Some((0, 6)));
+            assert_match!(m("pi\u{301}nyi\u{304}n").find("拼音"), None);
+            assert_match!(m("nu\u{308}\u{30C}").find("女"), Some((0, 3)));
+            assert_match!(m("zhua\u{300}ng").find("状"), Some((0, 3)));
+            assert_match!(m("pinyin").find("拼音"), None);
+        }
+
+        let m = IbMatcher::builder("pi\u{304}nyi\u{304}n")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Unicode))
+            .build();
+        let spans: Vec<_> = m
+            .find_positions("拼音")
+            .unwrap()
+            .char_spans()
+            .unwrap()
+            .iter()
+            .map(|span| (span.pattern(), span.kind()))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                (0..5, MatchKind::Pinyin(PinyinNotation::Unicode)),
+                (5..10, MatchKind::Pinyin(PinyinNotation::Unicode))
+            ]
+        );
+    }
+
+    #[test]
+    fn ascii_initial() {
+        let m = IbMatcher::builder("zhg")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert!(!m.is_match("中国"));
+
+        for notations in [
+            PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial,
+            PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial,
+            PinyinNotation::AsciiInitial,
+        ] {
+            let pinyin = PinyinMatchConfig::notations(notations);
+            for analyze in [false, true] {
+                let m = |pattern| {
+                    IbMatcher::builder(pattern)
+                        .pinyin(pinyin.shallow_clone())
+                        .analyze(analyze)
+                        .build()
+                };
+                assert_match!(m("zhg").find("中国"), Some((0, 6)));
+                assert_match!(m("shsh").find("诗书"), Some((0, 6)));
+                assert_match!(m("a").find("安"), Some((0, 3)));
+                assert_match!(m("zho").find("中国"), None);
+                assert_eq!(
+                    m("zg").is_match("中国"),
+                    notations.contains(PinyinNotation::AsciiFirstLetter)
+                );
+                assert_eq!(
+                    m("zhongg").is_match("中国"),
+                    notations.contains(PinyinNotation::Ascii)
+                );
+            }
+        }
+
+        let m = IbMatcher::builder("zhg")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii
+                    | PinyinNotation::AsciiFirstLetter
+                    | PinyinNotation::AsciiInitial,
+            ))
+            .analyze(true)
+            .build();
+        let spans: Vec<MatchKind> = m
+            .find_positions("中国")
+            .unwrap()
+            .char_spans()
+            .unwrap()
+            .iter()
+            .map(|span| span.kind())
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                MatchKind::Pinyin(PinyinNotation::AsciiInitial),
+                MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter)
+            ]
+        );
+    }
+
+    #[test]
+    fn syllable_prefix() {
+        let m = IbMatcher::builder("pinyso")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert!(!m.is_match("拼音搜索"));
+
+        for notations in [
+            PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            PinyinNotation::Ascii,
+            PinyinNotation::AsciiFirstLetter,
+        ] {
+            let pinyin = PinyinMatchConfig::builder(notations)
+                .syllable_prefix(true)
+                .build();
+            for analyze in [false, true] {
+                let m = |pattern| {
+                    IbMatcher::builder(pattern)
+                        .pinyin(pinyin.shallow_clone())
+                        .analyze(analyze)
+                        .build()
+                };
+                for pattern in ["pinyso", "pysousuo", "piyisosu", "pinyinsousuo", "pyss"] {
+                    assert!(m(pattern).is_match("拼音搜索"), "{pattern}");
+                }
+                assert_match!(m("zhguo").find("中国"), Some((0, 6)));
+                assert_match!(m("zhonguo").find("中国"), Some((0, 6)));
+                assert_match!(m("xi'a").find("西安"), Some((0, 6)));
+                assert_match!(m("zhguo").find("中"), None);
+                assert_match!(m("pinyinx").find("拼音搜索"), None);
+            }
+        }
+
+        let m = IbMatcher::builder("zhguo")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::AsciiFirstLetter)
+                    .syllable_prefix(true)
+                    .build(),
+            )
+            .build();
+        let spans: Vec<MatchKind> = m
+            .find_positions("中国")
+            .unwrap()
+            .char_spans()
+            .unwrap()
+            .iter()
+            .map(|span| span.kind())
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                MatchKind::Pinyin(PinyinNotation::Ascii),
+                MatchKind::Pinyin(PinyinNotation::Ascii)
+            ]
+        );
+    }
+
+    #[test]
+    fn homophone() {
+        let m = IbMatcher::builder("因乐")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert!(!m.is_match("音乐"));
+
+        for analyze in [false, true] {
+            let m = |pattern, homophone| {
+                IbMatcher::builder(pattern)
+                    .pinyin(
+                        PinyinMatchConfig::builder(PinyinNotation::Ascii)
+                            .homophone(homophone)
+                            .build(),
+                    )
+                    .analyze(analyze)
+                    .build()
+            };
+            for homophone in [Homophone::Tone, Homophone::Toneless] {
+                assert_match!(m("因乐", homophone).find("音乐"), Some((0, 6)));
+                assert_match!(m("因yue", homophone).find("音乐"), Some((0, 6)));
+                assert_match!(m("因", homophone).find("英"), None);
+                assert_match!(m("a因", homophone).find("a音"), Some((0, 4)));
+            }
+            assert_match!(m("银乐", Homophone::Tone).find("音乐"), None);
+            assert_match!(m("银乐", Homophone::Toneless).find("音乐"), Some((0, 6)));
+        }
+
+        // Polyphonic chars (多音字)
+        let m = |max_readings| {
+            IbMatcher::builder("型")
+                .pinyin(
+                    PinyinMatchConfig::builder(PinyinNotation::Ascii)
+                        .homophone(Homophone::Tone)
+                        .max_readings(max_readings)
+                        .build(),
+                )
+                .build()
+        };
+        assert_match!(m(NonZeroUsize::MAX).find("行"), Some((0, 3)));
+        assert_match!(m(NonZeroUsize::MIN).find("行"), Some((0, 3)));
+        assert_match!(m(NonZeroUsize::MAX).find("航"), None);
+
+        let m = IbMatcher::builder("音因")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::Ascii)
+                    .homophone(Homophone::Tone)
+                    .build(),
+            )
+            .build();
+        let spans: Vec<MatchKind> = m
+            .find_positions("乐音音")
+            .unwrap()
+            .char_spans()
+            .unwrap()
+            .iter()
+            .map(|span| span.kind())
+            .collect();
+        assert_eq!(spans, [MatchKind::Plain, MatchKind::Homophone]);
+    }
+
+    #[test]
+    fn zhuyin() {
+        let pinyin =
+            PinyinMatchConfig::notations(PinyinNotation::Zhuyin | PinyinNotation::ZhuyinTone);
+        for analyze in [false, true] {
+            for (pattern, len) in [("ㄆㄧㄣㄧㄣ", 6), ("ㄆㄧㄣ", 3), ("ㄆㄧ", 3)] {
+                let m = IbMatcher::builder(pattern)
+                    .pinyin(pinyin.shallow_clone())
+                    .is_pattern_partial(true)
+                    .analyze(analyze)
+                    .build();
+                assert_match!(m.find("拼音"), Some((0, len)));
+            }
+
+            // Tone marks are optional
+            let m = IbMatcher::builder("ㄓㄨㄥˋㄧㄠˋ")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("重要"), Some((0, 6)));
+            let m = IbMatcher::builder("ㄓㄨㄥˇㄧㄠˋ")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("重要"), None);
+            let m = IbMatcher::builder("ㄓㄨㄥㄧㄠˋ")
+                .pinyin(pinyin.shallow_clone())
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("重要"), Some((0, 6)));
+        }
+
+        let m = IbMatcher::builder("qupup")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::ZhuyinDaqian))
+            .build();
+        assert_match!(m.find("拼音"), Some((0, 6)));
+        assert_eq!(
+            m.find_positions("拼音").unwrap().char_spans().unwrap()[0].kind(),
+            MatchKind::Pinyin(PinyinNotation::ZhuyinDaqian)
+        );
+    }
+
+    #[test]
+    fn max_readings() {
+        let pinyin = |max_readings| {
+            PinyinMatchConfig::builder(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter)
+                .max_readings(NonZeroUsize::new(max_readings).unwrap())
+                .build()
+        };
+
+        for (pattern, max_readings, matched) in [
+            ("xing", 1, true),
+            ("hang", 1, false),
+            ("hang", 2, true),
+            // háng, hàng, héng
+            ("heng", 3, false),
+            ("heng", 4, true),
+            ("h", 1, false),
+            ("x", 1, true),
+        ] {
+            let m = IbMatcher::builder(pattern)
+                .pinyin(pinyin(max_readings))
+                .build();
+            assert_eq!(m.is_match("行"), matched, "{pattern} {max_readings}");
+        }
+
+        // Not frequency based
+        let m = IbMatcher::builder("ditu").pinyin(pinyin(1)).build();
+        assert_match!(m.find("地图"), None);
+
+        // Monophonic chars are not affected
+        let m = IbMatcher::builder("pinyin").pinyin(pinyin(1)).build();
+        assert_match!(m.find("拼音"), Some((0, 6)));
+    }
+
+    #[cfg(feature = "pinyin-words")]
+    #[test]
+    fn words() {
+        use crate::{
+            matcher::{input::Input, IbMatcherSet},
+            pinyin::PinyinWords,
+        };
+
+        let data = PinyinData::new(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter);
+        let words = PinyinWords::new();
+        let pinyin = |words| {
+            PinyinMatchConfig::builder(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter)
+                .data(&data)
+                .maybe_words(words)
+                .build()
+        };
+
+        let m = IbMatcher::builder("zhongqing").pinyin(pinyin(None)).build();
+        assert_match!(m.find("重庆"), Some((0, 6)));
+        let m = IbMatcher::builder("zhongqing")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        assert_match!(m.find("重庆"), None);
+        assert_match!(m.test("重庆"), None);
+        assert_match!(m.find("a重庆b"), None);
+
+        let m = IbMatcher::builder("cqyh")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        assert_match!(m.find("去重庆银行"), Some((3, 12)));
+        assert_match!(m.test("重庆银行"), Some((0, 12)));
+        let spans = m.find_positions("去重庆银行").unwrap();
+        assert_eq!(spans.char_spans().unwrap().len(), 4);
+
+        // Chars not in known words are not affected
+        let m = IbMatcher::builder("zhongxing")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        assert_match!(m.find("重行"), Some((0, 6)));
+
+        // Words crossing the searched span are still known
+        let m = IbMatcher::builder("chang")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        assert_match!(m.find("行长"), None);
+        assert_match!(m.find(Input::builder("行长").range(3..6).build()), None);
+        let m = IbMatcher::builder("zhang")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        assert_match!(
+            m.find(Input::builder("行长").range(3..6).build()),
+            Some((3, 3))
+        );
+        assert_eq!(
+            m.find_iter(Input::builder("行长 行长").range(3..13).build())
+                .map(|m| m.range())
+                .collect::<Vec<_>>(),
+            [3..6, 10..13]
+        );
+
+        // The words cache is built once per haystack
+        let m = IbMatcher::builder("zhong")
+            .pinyin(pinyin(Some(&words)))
+            .build();
+        let haystack = "中".repeat(10_000);
+        assert_eq!(m.find_iter(haystack.as_str()).count(), 10_000);
+        assert!(m.score(haystack.as_str()).is_some());
+
+        let set = IbMatcherSet::new([
+            IbMatcher::builder("zhongqing")
+                .pinyin(pinyin(Some(&words)))
+                .build(),
+            IbMatcher::builder("zhongqing").pinyin(pinyin(None)).build(),
+        ]);
+        let matches = set.matches("重庆");
+        assert!(!matches.matched(0));
+        assert!(matches.matched(1));
+    }
+
     #[test]
     fn diletter() {
         // rs tw he ne nt er fo ld er
@@ -59,7 +59,10 @@ pub(crate) struct RomajiMatcher<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{assert_match, matcher::IbMatcher};
+    use crate::{
+        assert_match,
+        matcher::{IbMatcher, MatchKind},
+    };
 
     use super::*;
 
@@ -90,6 +93,25 @@ mod tests {
         assert_match!(matcher.find("この素晴らしい世界に祝福を"), Some((0, 21)));
     }
 
+    #[test]
+    fn find_positions() {
+        let romanizer = Default::default();
+        let romaji = RomajiMatchConfig::builder().romanizer(&romanizer).build();
+
+        let matcher = IbMatcher::builder("ohayojojo").romaji(romaji).build();
+        let m = matcher.find_positions("おはよジョジョ").unwrap();
+        assert_eq!(m.range(), 0..21);
+        let spans = m.char_spans().unwrap();
+        assert!(spans.iter().all(|span| span.kind() == MatchKind::Romaji));
+        assert_eq!(spans.first().unwrap().pattern().start, 0);
+        assert_eq!(spans.last().unwrap().pattern().end, 9);
+        assert_eq!(spans.last().unwrap().haystack().end, 21);
+        assert!(spans
+            .windows(2)
+            .all(|w| w[0].pattern().end == w[1].pattern().start
+                && w[0].haystack().end == w[1].haystack().start));
+    }
+
     #[test]
     fn min_haystack_len() {
         let romanizer = Default::default();
@@ -0,0 +1,302 @@
+//! Match scoring for ranking results, similar to [fzf](https://github.com/junegunn/fzf)/[nucleo](https://github.com/helix-editor/nucleo).
+use std::num::NonZeroUsize;
+
+use bon::Builder;
+
+use crate::matcher::{encoding::EncodedStr, input::Input, IbMatcher, Match, MatchKind};
+#[cfg(feature = "pinyin")]
+use crate::pinyin::PinyinNotation;
+
+/// The weights of [`IbMatcher::score()`].
+///
+/// The score of a match is the sum of:
+/// - The weight of each pattern char, by how it is matched, i.e. [`ScoreConfigBuilder::plain`], [`ScoreConfigBuilder::pinyin`], [`ScoreConfigBuilder::first_letter`], [`ScoreConfigBuilder::homophone`] and [`ScoreConfigBuilder::romaji`].
+/// - [`ScoreConfigBuilder::start`] or [`ScoreConfigBuilder::word_start`] bonus.
+/// - Minus [`ScoreConfigBuilder::partial_pattern`] penalty if [`Match::is_pattern_partial()`].
+/// - Minus [`ScoreConfigBuilder::gap`] penalty for each skipped haystack char inside the match.
+/// - Minus [`ScoreConfigBuilder::unmatched`] penalty for each haystack char outside the match.
+#[derive(Builder, Clone, Debug)]
+pub struct ScoreConfig {
+    /// Per pattern char matched as plain chars.
+    #[builder(default = 16)]
+    pub(crate) plain: i32,
+    /// Per pattern char matched by pinyin, except [`ScoreConfigBuilder::first_letter`].
+    #[cfg(feature = "pinyin")]
+    #[builder(default = 12)]
+    pub(crate) pinyin: i32,
+    /// Per pattern char matched by [`PinyinNotation::AsciiFirstLetter`](crate::pinyin::PinyinNotation::AsciiFirstLetter) or [`PinyinNotation::AsciiInitial`](crate::pinyin::PinyinNotation::AsciiInitial).
+    #[cfg(feature = "pinyin")]
+    #[builder(default = 8)]
+    pub(crate) first_letter: i32,
+    /// Per pattern char matched as a homophone, see [`PinyinMatchConfigBuilder::homophone`](super::PinyinMatchConfigBuilder::homophone).
+    ///
+    /// Negative by default, so that a plain match of the hanzi anywhere in the haystack still outscores a homophone match with the [`ScoreConfigBuilder::start`] bonus.
+    #[cfg(feature = "pinyin")]
+    #[builder(default = -8)]
+    pub(crate) homophone: i32,
+    /// Per pattern char matched by romaji.
+    #[cfg(feature = "romaji")]
+    #[builder(default = 12)]
+    pub(crate) romaji: i32,
+
+    /// Penalty if the end of the pattern only matches a part of a pinyin/romaji.
+    #[builder(default = 8)]
+    pub(crate) partial_pattern: i32,
+
+    /// Bonus if the match is at the start of the haystack.
+    #[builder(default = 16)]
+    pub(crate) start: i32,
+    /// Bonus if the match is at the start of a word, e.g. after a space, a punctuation, a lowercase-to-uppercase transition (`camelCase`) or a script transition (`ASCII中文`).
+    #[builder(default = 8)]
+    pub(crate) word_start: i32,
+
+    /// Penalty per skipped haystack char inside the match.
+    #[builder(default = 3)]
+    pub(crate) gap: i32,
+    /// Penalty per haystack char outside the match, i.e. prefer shorter haystacks.
+    #[builder(default = 1)]
+    pub(crate) unmatched: i32,
+
+    /// The max number of matches to score per haystack. Each match after the first one needs another search, so later matches in long haystacks are not scored.
+    #[builder(default = NonZeroUsize::new(16).unwrap())]
+    pub(crate) max_matches: NonZeroUsize,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl ScoreConfig {
+    fn kind_weight(&self, kind: MatchKind) -> i32 {
+        match kind {
+            MatchKind::Plain => self.plain,
+            #[cfg(feature = "pinyin")]
+            MatchKind::Pinyin(PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial) => {
+                self.first_letter
+            }
+            #[cfg(feature = "pinyin")]
+            MatchKind::Pinyin(_) => self.pinyin,
+            #[cfg(feature = "pinyin")]
+            MatchKind::Homophone => self.homophone,
+            #[cfg(feature = "romaji")]
+            MatchKind::Romaji => self.romaji,
+        }
+    }
+}
+
+fn is_word_start(prev: char, c: char) -> bool {
+    !prev.is_alphanumeric()
+        || prev.is_lowercase() && c.is_uppercase()
+        || prev.is_ascii() != c.is_ascii()
+}
+
+impl<'a, HaystackStr> IbMatcher<'a, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    /// Finds the match at each possible start like [`IbMatcher::find()`], scores them by [`IbMatcherBuilder::score`](super::IbMatcherBuilder::score) and returns the best one. Higher is better.
+    ///
+    /// At most [`ScoreConfigBuilder::max_matches`] matches are scored.
+    ///
+    /// Scores are only comparable between haystacks matched by the same matcher.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::{matcher::{IbMatcher, PinyinMatchConfig}, pinyin::PinyinNotation};
+    ///
+    /// let matcher = IbMatcher::builder("py")
+    ///     .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter))
+    ///     .build();
+    /// let mut haystacks = vec!["拼音", "happy", "py.rs", "tpy"];
+    /// haystacks.sort_by_key(|haystack| std::cmp::Reverse(matcher.score(*haystack)));
+    /// assert_eq!(haystacks, ["py.rs", "拼音", "tpy", "happy"]);
+    /// ```
+    pub fn score<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> Option<i32>
+    where
+        HaystackStr: 'h,
+    {
+        let input = input.into();
+        let (haystack, range, no_start) = (input.haystack, input.range.clone(), input.no_start);
+
+        // Built once for all searches, which share the end of `input`
+        #[cfg(feature = "pinyin-words")]
+        let words_cache = match input.span().is_ascii() {
+            true => None,
+            false => self.words_cache(&input),
+        };
+        let find_positions = |input| {
+            #[cfg(feature = "pinyin-words")]
+            if let Some(cache) = &words_cache {
+                return self.find_positions_with_lookup(input, cache);
+            }
+            self.find_positions_with_lookup(input, ())
+        };
+
+        let mut best = None;
+        let mut search = input;
+        // A later match may score better, e.g. a plain match after a pinyin one
+        for _ in 0..self.score.max_matches.get() {
+            let Some(m) = find_positions(search.clone()) else {
+                break;
+            };
+            let score = self.score_match(&m, haystack, range.clone(), no_start);
+            best = best.max(Some(score));
+
+            let Some((_, len, _)) = haystack
+                .get(m.start..range.end)
+                .unwrap()
+                .char_len_next_strs()
+                .next()
+            else {
+                break;
+            };
+            search.range = m.start + len..range.end;
+            search.no_start = true;
+        }
+        best
+    }
+
+    fn score_match(
+        &self,
+        m: &Match,
+        haystack: &HaystackStr,
+        range: std::ops::Range<usize>,
+        no_start: bool,
+    ) -> i32 {
+        let config = &self.score;
+        let get = |start, end| haystack.get(start..end).unwrap();
+        let mut score = 0;
+
+        // Always available from `find_positions()`
+        let spans = m.char_spans().unwrap();
+        for span in spans {
+            let pattern = span.pattern();
+            let chars = self
+                .pattern
+                .partition_point(|c| c.range.start < pattern.end)
+                - self
+                    .pattern
+                    .partition_point(|c| c.range.start < pattern.start);
+            score += config.kind_weight(span.kind()) * chars as i32;
+        }
+        if m.is_pattern_partial() {
+            score -= config.partial_pattern;
+        }
+
+        let before = get(range.start, m.start);
+        match before.char_index_strs().last() {
+            None if !no_start => score += config.start,
+            Some((_, prev, _)) => {
+                if let Some((_, c, _)) = get(m.start, m.end).char_index_strs().next() {
+                    if is_word_start(prev, c) {
+                        score += config.word_start;
+                    }
+                }
+            }
+            None => (),
+        }
+
+        for w in spans.windows(2) {
+            let gap = get(w[0].haystack().end, w[1].haystack().start).chars_count();
+            score -= config.gap * gap as i32;
+        }
+
+        let unmatched = before.chars_count() + get(m.end, range.end).chars_count();
+        score -= config.unmatched * unmatched as i32;
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        matcher::{Homophone, PinyinMatchConfig},
+        pinyin::PinyinNotation,
+    };
+
+    use super::*;
+
+    #[test]
+    fn score() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert_eq!(matcher.score("ab"), None);
+        // 2 * plain + start
+        assert_eq!(matcher.score("py"), Some(48));
+        // 2 * first_letter + start
+        assert_eq!(matcher.score("拼音"), Some(32));
+        // 2 * plain + word_start - 2 * unmatched
+        assert_eq!(matcher.score("a.py"), Some(38));
+        assert!(matcher.score("a.py") > matcher.score("happy"));
+        assert!(matcher.score("py") > matcher.score("py.rs"));
+        assert!(matcher.score("abPy") > matcher.score("abpy"));
+        // The later plain match: 2 * plain + word_start - 6 * unmatched
+        assert_eq!(matcher.score("拼音 py.rs"), Some(34));
+        assert!(matcher.score("拼音 py.rs") > matcher.score("拼音 .rs"));
+        assert_eq!(
+            matcher.score(Input::builder("py拼音").range(2..8).build()),
+            Some(32)
+        );
+
+        let matcher = IbMatcher::builder("le")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .build();
+        assert!(matcher.score("le") > matcher.score("了"));
+        assert!(matcher.score("了") > matcher.score("老鹅"));
+
+        let matcher = IbMatcher::builder("pinyi")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .is_pattern_partial(true)
+            .build();
+        assert!(matcher.score("拼音") < matcher.score("拼yi"));
+
+        let matcher = IbMatcher::builder("因")
+            .pinyin(
+                PinyinMatchConfig::builder(PinyinNotation::Ascii)
+                    .homophone(Homophone::Tone)
+                    .build(),
+            )
+            .build();
+        assert!(matcher.score("因") > matcher.score("音因"));
+        assert!(matcher.score("音因") > matcher.score("音"));
+        assert!(matcher.score("a因") > matcher.score("音"));
+    }
+
+    #[test]
+    fn score_config() {
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::AsciiFirstLetter,
+            ))
+            .score(ScoreConfig::builder().first_letter(20).unmatched(0).build())
+            .build();
+        assert!(matcher.score("拼音") > matcher.score("py"));
+        assert_eq!(matcher.score("a拼音b"), matcher.score(" 拼音"));
+        assert_eq!(
+            matcher.score(Input::builder("拼音").no_start(true).build()),
+            Some(40)
+        );
+
+        let matcher = IbMatcher::builder("py")
+            .pinyin(PinyinMatchConfig::notations(
+                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter,
+            ))
+            .score(
+                ScoreConfig::builder()
+                    .max_matches(NonZeroUsize::MIN)
+                    .build(),
+            )
+            .build();
+        // The later plain match is not scored
+        assert_eq!(matcher.score("拼音 py"), matcher.score("拼音 pa"));
+        assert!(matcher.score("拼音".repeat(100_000).as_str()).is_some());
+    }
+}
@@ -0,0 +1,319 @@
+use crate::matcher::{
+    ascii::{AcMatcher, AcSetMatcher},
+    encoding::EncodedStr,
+    input::Input,
+    lookup::LookupCache,
+    IbMatcher, Match,
+};
+
+/// A set of [`IbMatcher`]s that are matched against a haystack in one pass, like [`regex::RegexSet`](https://docs.rs/regex/latest/regex/struct.RegexSet.html).
+///
+/// ## Performance
+/// - For ASCII-only haystacks, all plain patterns are matched by one multi-pattern automaton.
+/// - For other haystacks, the pinyin/romaji lookups of each haystack char are done once and shared by all matchers. Romaji lookups are only shared by matchers using the same romanizer, i.e. pass [`RomajiMatchConfigBuilder::romanizer`](super::RomajiMatchConfigBuilder::romanizer) or use `RomajiMatchConfig::shallow_clone()`.
+///
+/// ## Example
+/// ```
+/// use ib_matcher::{
+///     matcher::{IbMatcher, IbMatcherSet, PinyinMatchConfig},
+///     pinyin::{PinyinData, PinyinNotation},
+/// };
+///
+/// let data = PinyinData::new(PinyinNotation::Ascii);
+/// let pinyin = PinyinMatchConfig::builder(PinyinNotation::Ascii).data(&data).build();
+/// let set = IbMatcherSet::new(["pinyin", "sousuo", "py"].map(|pattern| {
+///     IbMatcher::builder(pattern).pinyin(pinyin.shallow_clone()).build()
+/// }));
+/// assert_eq!(set.matches("拼音搜索").iter().collect::<Vec<_>>(), [0, 1]);
+/// assert_eq!(set.matches("happy").iter().collect::<Vec<_>>(), [2]);
+/// ```
+pub struct IbMatcherSet<'a, HaystackStr = str>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    matchers: Box<[IbMatcher<'a, HaystackStr>]>,
+    /// For ASCII-only haystack optimization.
+    ascii: AcSetMatcher,
+    /// Whether the matcher is covered by `ascii`.
+    in_ascii: Box<[bool]>,
+    #[cfg(feature = "pinyin")]
+    pinyin: bool,
+    /// The first matcher with romaji.
+    #[cfg(feature = "romaji")]
+    romaji: Option<usize>,
+}
+
+impl<'a, HaystackStr> IbMatcherSet<'a, HaystackStr>
+where
+    HaystackStr: EncodedStr + ?Sized,
+{
+    /// The index of each matcher is its pattern ID.
+    pub fn new(matchers: impl IntoIterator<Item = IbMatcher<'a, HaystackStr>>) -> Self {
+        let matchers: Box<[_]> = matchers.into_iter().collect();
+
+        // Empty patterns are handled in `find_with_is_ascii()`
+        fn ac<'m, HaystackStr: EncodedStr + ?Sized>(
+            matcher: &'m IbMatcher<'_, HaystackStr>,
+        ) -> Option<&'m AcMatcher> {
+            matcher
+                .ascii
+                .ac()
+                .filter(|_| !matcher.pattern.is_empty() && !matcher.subsequence)
+        }
+        let ascii = AcSetMatcher::new(
+            matchers
+                .iter()
+                .enumerate()
+                .filter_map(|(i, matcher)| ac(matcher).map(|ac| (i, ac))),
+        );
+        let in_ascii = matchers
+            .iter()
+            .map(|matcher| ac(matcher).is_some())
+            .collect();
+
+        Self {
+            ascii,
+            in_ascii,
+            #[cfg(feature = "pinyin")]
+            pinyin: matchers.iter().any(|matcher| matcher.pinyin.is_some()),
+            #[cfg(feature = "romaji")]
+            romaji: matchers.iter().position(|matcher| matcher.romaji.is_some()),
+            matchers,
+        }
+    }
+
+    /// The number of matchers.
+    pub fn len(&self) -> usize {
+        self.matchers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matchers.is_empty()
+    }
+
+    pub fn matchers(&self) -> &[IbMatcher<'a, HaystackStr>] {
+        &self.matchers
+    }
+
+    /// Returns true if and only if any matcher in the set matches the haystack given.
+    pub fn is_match<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> bool
+    where
+        HaystackStr: 'h,
+    {
+        self.find_each(input.into(), |_, _| true)
+    }
+
+    /// Returns which matchers in the set match the haystack given.
+    pub fn matches<'h>(&self, input: impl Into<Input<'h, HaystackStr>>) -> SetMatches
+    where
+        HaystackStr: 'h,
+    {
+        let mut matched = vec![false; self.len()].into_boxed_slice();
+        let mut matched_any = false;
+        self.find_each(input.into(), |i, _| {
+            matched[i] = true;
+            matched_any = true;
+            false
+        });
+        SetMatches {
+            matched,
+            matched_any,
+        }
+    }
+
+    /// Returns the first match of each matcher in the set, like [`IbMatcher::find()`], indexed by pattern ID.
+    pub fn find_per_pattern<'h>(
+        &self,
+        input: impl Into<Input<'h, HaystackStr>>,
+    ) -> Vec<Option<Match>>
+    where
+        HaystackStr: 'h,
+    {
+        let mut matches = vec![None; self.len()];
+        self.find_each(input.into(), |i, m| {
+            matches[i] = Some(m);
+            false
+        });
+        matches
+    }
+
+    /// Calls `f` with the pattern ID and the first match of each matched matcher, until `f` returns `true`.
+    fn find_each<'h>(
+        &self,
+        input: Input<'h, HaystackStr>,
+        mut f: impl FnMut(usize, Match) -> bool,
+    ) -> bool {
+        let haystack = input.span();
+        let offset = input.range.start;
+
+        if haystack.is_ascii() {
+            if self.ascii.find_each(haystack.as_bytes(), |i, m| {
+                !self.matchers[i].is_anchor_excluded(&input)
+                    && f(i, m.div(HaystackStr::CHAR).offset(offset))
+            }) {
+                return true;
+            }
+            return self
+                .matchers
+                .iter()
+                .enumerate()
+                .filter(|&(i, matcher)| !self.in_ascii[i] && !matcher.is_anchor_excluded(&input))
+                .any(|(i, matcher)| {
+                    matcher
+                        .find_with_is_ascii(input.clone(), true)
+                        .is_some_and(|m| f(i, m))
+                });
+        }
+
+        let cache = LookupCache::builder(haystack);
+        #[cfg(feature = "pinyin")]
+        let cache = cache.pinyin(self.pinyin);
+        #[cfg(feature = "romaji")]
+        let cache = cache.maybe_romanizer(
+            self.romaji
+                .and_then(|i| self.matchers[i].romaji.as_ref())
+                .map(|romaji| romaji.config.romanizer.as_ref()),
+        );
+        let cache = cache.build();
+        self.matchers
+            .iter()
+            .enumerate()
+            .filter(|(_, matcher)| !matcher.is_anchor_excluded(&input))
+            .any(|(i, matcher)| {
+                // Strict readings can't share the cache
+                #[cfg(feature = "pinyin-words")]
+                if matcher.words().is_some() {
+                    return matcher
+                        .find_with_is_ascii(input.clone(), false)
+                        .is_some_and(|m| f(i, m));
+                }
+                matcher
+                    .find_with_lookup(input.clone(), false, &cache)
+                    .is_some_and(|m| f(i, m))
+            })
+    }
+}
+
+/// Which matchers in an [`IbMatcherSet`] matched, see [`IbMatcherSet::matches()`].
+#[derive(Clone, Debug)]
+pub struct SetMatches {
+    matched: Box<[bool]>,
+    matched_any: bool,
+}
+
+impl SetMatches {
+    pub fn matched_any(&self) -> bool {
+        self.matched_any
+    }
+
+    /// Whether the matcher with the given pattern ID matched.
+    ///
+    /// ## Panics
+    /// If `id` is out of bounds.
+    pub fn matched(&self, id: usize) -> bool {
+        self.matched[id]
+    }
+
+    /// The number of matchers in the set, not the number of matched ones.
+    pub fn len(&self) -> usize {
+        self.matched.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matched.is_empty()
+    }
+
+    /// Iterates over the pattern IDs of matched matchers, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.matched
+            .iter()
+            .enumerate()
+            .filter(|(_, &matched)| matched)
+            .map(|(i, _)| i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        matcher::{PinyinMatchConfig, RomajiMatchConfig},
+        pinyin::{PinyinData, PinyinNotation},
+    };
+
+    use super::*;
+
+    fn ids(matches: SetMatches) -> Vec<usize> {
+        matches.iter().collect()
+    }
+
+    #[test]
+    fn set() {
+        let data = PinyinData::new(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter);
+        let pinyin =
+            PinyinMatchConfig::builder(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter)
+                .data(&data)
+                .build();
+        let romanizer = Default::default();
+        let romaji = RomajiMatchConfig::builder().romanizer(&romanizer).build();
+        let set = IbMatcherSet::new([
+            IbMatcher::builder("py")
+                .pinyin(pinyin.shallow_clone())
+                .build(),
+            IbMatcher::builder("PY")
+                .case_insensitive(false)
+                .pinyin(pinyin.shallow_clone())
+                .build(),
+            IbMatcher::builder("sousuo")
+                .pinyin(pinyin.shallow_clone())
+                .ends_with(true)
+                .build(),
+            IbMatcher::builder("konosuba")
+                .romaji(romaji.shallow_clone())
+                .is_pattern_partial(true)
+                .build(),
+            IbMatcher::builder("").starts_with(true).build(),
+            IbMatcher::builder("ab").subsequence(true).build(),
+        ]);
+        assert_eq!(set.len(), 6);
+
+        assert_eq!(ids(set.matches("py")), [0, 4]);
+        assert_eq!(ids(set.matches("xPY")), [0, 1, 4]);
+        assert_eq!(ids(set.matches("a_b")), [4, 5]);
+        assert_eq!(ids(set.matches("拼音搜索")), [0, 2, 4]);
+        assert_eq!(ids(set.matches("拼音搜索a")), [0, 4]);
+        assert_eq!(ids(set.matches("この素晴らしい世界に祝福を")), [3, 4]);
+        assert_eq!(
+            ids(set.matches(Input::builder("拼音").no_start(true).build())),
+            [0]
+        );
+        assert!(set.is_match("ab"));
+        assert!(!IbMatcherSet::new([IbMatcher::builder("ab").build()]).is_match("拼音"));
+
+        let matches = set.find_per_pattern(Input::builder("xyPY拼音").range(1..10).build());
+        assert_eq!(
+            matches
+                .iter()
+                .map(|m| m.as_ref().map(|m| m.range()))
+                .collect::<Vec<_>>(),
+            [Some(2..4), Some(2..4), None, None, Some(1..1), None]
+        );
+
+        // Same results as matching one by one
+        for haystack in [
+            "py",
+            "xPY",
+            "拼音搜索",
+            "搜索",
+            "この素晴らしい世界",
+            "a拼b",
+        ] {
+            let matches = set.find_per_pattern(haystack);
+            for (matcher, m) in set.matchers().iter().zip(matches) {
+                assert_eq!(
+                    matcher.find(haystack).map(|m| m.range()),
+                    m.map(|m| m.range())
+                );
+            }
+        }
+    }
+}
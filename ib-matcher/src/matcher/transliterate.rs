@@ -0,0 +1,441 @@
+//! Pattern transliteration for [`IbMatcherBuilder::transliterate_pattern`](super::IbMatcherBuilder::transliterate_pattern).
+#[cfg(feature = "pinyin")]
+use crate::matcher::PinyinMatchConfig;
+#[cfg(feature = "romaji")]
+use crate::matcher::RomajiMatchConfig;
+use crate::matcher::{CharSpan, Match, MatchKind, PatternChar};
+#[cfg(feature = "pinyin")]
+use crate::pinyin::{PinyinData, PinyinNotation};
+
+/// An ASCII-only transliteration of some pattern chars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Alternative {
+    /// The number of pattern chars transliterated, e.g. more than one for a Japanese word.
+    chars: usize,
+    s: Box<str>,
+    kind: MatchKind,
+}
+
+/// The alternatives of each pattern char.
+type Lang = Box<[Box<[Alternative]>]>;
+
+/// Matches the ASCII-only transliterations of a pattern case-insensitively.
+///
+/// The transliterations are not enumerated, as their number grows exponentially with the pattern length, e.g. "行行行行行行" has 3<sup>6</sup> ones. Instead, the alternatives of each pattern char are tried in turn, and each (char, haystack position) pair is searched at most once. So a search takes at most `O(pattern chars * haystack.len())` steps.
+pub(crate) struct Transliterator {
+    /// Unless `mix_lang`, one for each language.
+    langs: Box<[Lang]>,
+    starts_with: bool,
+    ends_with: bool,
+    /// The length of the shortest transliteration.
+    min_len: usize,
+}
+
+impl Transliterator {
+    /// Returns `None` if the pattern has no ASCII-only transliteration.
+    ///
+    /// Unless `mix_lang`, each transliteration only uses one language.
+    pub fn new(
+        pattern: &str,
+        mix_lang: bool,
+        #[cfg(feature = "pinyin")] pinyin: Option<&PinyinMatchConfig>,
+        #[cfg(feature = "romaji")] romaji: Option<&RomajiMatchConfig>,
+        starts_with: bool,
+        ends_with: bool,
+    ) -> Option<Self> {
+        let offsets: Vec<usize> = pattern
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([pattern.len()])
+            .collect();
+
+        #[cfg_attr(not(feature = "romaji"), allow(unused_mut))]
+        let mut langs = vec![lang(
+            pattern,
+            &offsets,
+            #[cfg(feature = "pinyin")]
+            pinyin,
+            #[cfg(feature = "romaji")]
+            romaji.filter(|_| mix_lang),
+        )];
+        #[cfg(feature = "romaji")]
+        if !mix_lang && romaji.is_some() {
+            langs.push(lang(
+                pattern,
+                &offsets,
+                #[cfg(feature = "pinyin")]
+                None,
+                romaji,
+            ));
+        }
+        #[cfg(not(feature = "romaji"))]
+        let _ = mix_lang;
+
+        let langs: Vec<(Lang, usize)> = langs
+            .into_iter()
+            .filter_map(|lang| {
+                let min_len = min_len(&lang)?;
+                Some((lang, min_len))
+            })
+            .collect();
+        let min_len = langs.iter().map(|&(_, min_len)| min_len).min()?;
+        Some(Self {
+            langs: langs.into_iter().map(|(lang, _)| lang).collect(),
+            starts_with,
+            ends_with,
+            min_len,
+        })
+    }
+
+    pub fn min_len(&self) -> usize {
+        self.min_len
+    }
+
+    /// Returns `(start, end, path)` of the leftmost match starting in `starts`.
+    ///
+    /// Longer alternatives of each char are tried first, but the match is not guaranteed to be the longest one.
+    fn search<'t>(
+        &'t self,
+        haystack: &[u8],
+        starts: impl Iterator<Item = usize>,
+    ) -> Option<(usize, usize, Vec<Step<'t>>)> {
+        if haystack.len() < self.min_len {
+            return None;
+        }
+        let mut searches: Vec<Search> = self
+            .langs
+            .iter()
+            .map(|lang| Search {
+                lang,
+                haystack,
+                ends_with: self.ends_with,
+                visited: vec![0; (lang.len() * (haystack.len() + 1)).div_ceil(64)],
+                path: Vec::with_capacity(lang.len()),
+            })
+            .collect();
+        for start in starts {
+            for search in searches.iter_mut() {
+                if let Some(end) = search.search(0, start) {
+                    return Some((start, end, std::mem::take(&mut search.path)));
+                }
+            }
+        }
+        None
+    }
+
+    fn match_(start: usize, end: usize) -> Match {
+        Match {
+            start,
+            end,
+            is_pattern_partial: false,
+            char_spans: None,
+        }
+    }
+
+    pub fn find(&self, haystack: &[u8]) -> Option<Match> {
+        let last_start = match self.starts_with {
+            true => 0,
+            false => haystack.len().saturating_sub(self.min_len),
+        };
+        self.search(haystack, 0..=last_start)
+            .map(|(start, end, _)| Self::match_(start, end))
+    }
+
+    pub fn is_match(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    pub fn test(&self, haystack: &[u8]) -> Option<Match> {
+        self.search(haystack, 0..=0)
+            .map(|(start, end, _)| Self::match_(start, end))
+    }
+
+    /// Returns the spans of [`Transliterator::test()`].
+    ///
+    /// ## Arguments
+    /// - `start`: The start of `haystack` in the whole haystack.
+    pub fn char_spans(
+        &self,
+        pattern: &[PatternChar],
+        haystack: &[u8],
+        start: usize,
+    ) -> Option<(usize, Box<[CharSpan]>)> {
+        let (_, end, path) = self.search(haystack, 0..=0)?;
+        let spans = path
+            .into_iter()
+            .map(|step| CharSpan {
+                pattern: pattern[step.char].range.start
+                    ..pattern[step.char + step.alternative.chars - 1].range.end,
+                haystack: start + step.pos..start + step.pos + step.alternative.s.len(),
+                kind: step.alternative.kind,
+            })
+            .collect();
+        Some((end, spans))
+    }
+}
+
+/// Returns the alternatives of each pattern char.
+fn lang(
+    pattern: &str,
+    offsets: &[usize],
+    #[cfg(feature = "pinyin")] pinyin: Option<&PinyinMatchConfig>,
+    #[cfg(feature = "romaji")] romaji: Option<&RomajiMatchConfig>,
+) -> Lang {
+    (0..offsets.len() - 1)
+        .map(|i| {
+            char_alternatives(
+                pattern,
+                offsets,
+                i,
+                #[cfg(feature = "pinyin")]
+                pinyin,
+                #[cfg(feature = "romaji")]
+                romaji,
+            )
+        })
+        .collect()
+}
+
+/// Returns the alternatives of the pattern char at `i`, longer ones first.
+fn char_alternatives(
+    pattern: &str,
+    offsets: &[usize],
+    i: usize,
+    #[cfg(feature = "pinyin")] pinyin: Option<&PinyinMatchConfig>,
+    #[cfg(feature = "romaji")] romaji: Option<&RomajiMatchConfig>,
+) -> Box<[Alternative]> {
+    let s = &pattern[offsets[i]..];
+    let c = s.chars().next().unwrap();
+    if c.is_ascii() {
+        return [Alternative {
+            chars: 1,
+            s: c.to_string().into(),
+            kind: MatchKind::Plain,
+        }]
+        .into();
+    }
+
+    let mut alternatives: Vec<Alternative> = Vec::new();
+    let mut push = |alternative: Alternative| {
+        // e.g. "hang" of both "háng" and "hàng"
+        if !alternatives.contains(&alternative) {
+            alternatives.push(alternative);
+        }
+    };
+
+    #[cfg(feature = "pinyin")]
+    if let Some(pinyin) = pinyin {
+        pinyin.data.get_pinyins_by_index_and_try_for_each(
+            PinyinData::get_pinyin_index(c),
+            pinyin.max_readings.get(),
+            |py| {
+                push(Alternative {
+                    chars: 1,
+                    s: py.to_ascii().into(),
+                    kind: MatchKind::Pinyin(PinyinNotation::Ascii),
+                });
+                None::<()>
+            },
+        );
+    }
+
+    #[cfg(feature = "romaji")]
+    if let Some(romaji) = romaji {
+        romaji
+            .romanizer
+            .romanize_and_try_for_each(s, |len, romaji| {
+                if romaji.is_ascii() {
+                    push(Alternative {
+                        chars: offsets[i..].partition_point(|&offset| offset < offsets[i] + len),
+                        s: romaji.into(),
+                        kind: MatchKind::Romaji,
+                    });
+                }
+                None::<()>
+            });
+    }
+
+    alternatives.sort_by_key(|alternative| std::cmp::Reverse(alternative.s.len()));
+    alternatives.into()
+}
+
+/// Returns the length of the shortest transliteration, `None` if there is none.
+fn min_len(lang: &[Box<[Alternative]>]) -> Option<usize> {
+    let mut min_lens = vec![None; lang.len() + 1];
+    min_lens[lang.len()] = Some(0);
+    for i in (0..lang.len()).rev() {
+        min_lens[i] = lang[i]
+            .iter()
+            .filter_map(|alternative| Some(alternative.s.len() + min_lens[i + alternative.chars]?))
+            .min();
+    }
+    min_lens[0]
+}
+
+struct Step<'t> {
+    char: usize,
+    pos: usize,
+    alternative: &'t Alternative,
+}
+
+struct Search<'t, 'h> {
+    lang: &'t [Box<[Alternative]>],
+    haystack: &'h [u8],
+    ends_with: bool,
+    /// Bitset of searched `(char, pos)`.
+    visited: Vec<u64>,
+    path: Vec<Step<'t>>,
+}
+
+impl<'t> Search<'t, '_> {
+    /// Returns the end of the first match of the chars from `char`, starting at `pos`.
+    ///
+    /// The recursion depth is bounded by the pattern length.
+    fn search(&mut self, char: usize, pos: usize) -> Option<usize> {
+        if char == self.lang.len() {
+            return (!self.ends_with || pos == self.haystack.len()).then_some(pos);
+        }
+
+        // Successes always end the whole search, so a searched pair must have failed
+        let i = char * (self.haystack.len() + 1) + pos;
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        if self.visited[word] & bit != 0 {
+            return None;
+        }
+        self.visited[word] |= bit;
+
+        let lang = self.lang;
+        for alternative in lang[char].iter() {
+            let end = pos + alternative.s.len();
+            if self
+                .haystack
+                .get(pos..end)
+                .is_some_and(|s| s.eq_ignore_ascii_case(alternative.s.as_bytes()))
+            {
+                self.path.push(Step {
+                    char,
+                    pos,
+                    alternative,
+                });
+                if let Some(end) = self.search(char + alternative.chars, end) {
+                    return Some(end);
+                }
+                self.path.pop();
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_match,
+        matcher::{IbMatcher, IbMatcherSet, PinyinMatchConfig, RomajiMatchConfig},
+    };
+
+    use super::*;
+
+    #[test]
+    fn transliterator() {
+        let pinyin = PinyinMatchConfig::notations(PinyinNotation::Unicode);
+        let t = |pattern| Transliterator::new(pattern, false, Some(&pinyin), None, false, false);
+        let alternatives = |pattern| {
+            t(pattern).unwrap().langs[0]
+                .iter()
+                .map(|alternatives| {
+                    alternatives
+                        .iter()
+                        .map(|alternative| alternative.s.as_ref())
+                        .collect::<Vec<_>>()
+                        .join("|")
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(alternatives("拼音.txt"), ["pin", "yin", ".", "t", "x", "t"]);
+        assert_eq!(alternatives("行"), ["xing|hang|heng"]);
+        assert!(t("a\u{301}").is_none());
+        assert_eq!(t("饿a").unwrap().min_len(), 2);
+
+        let t = t("行行行行行行").unwrap();
+        assert_match!(t.find(b"HangHangHangHangHangHang"), Some((0, 24)));
+        assert_match!(t.find(b"x_xinghangxinghenghenghangx"), Some((2, 24)));
+        assert!(!t.is_match(&b"hang".repeat(5)));
+
+        let romaji = RomajiMatchConfig::default();
+        let t = Transliterator::new("おはよう", false, None, Some(&romaji), false, false).unwrap();
+        assert!(t.is_match(b"ohayou"));
+        let t =
+            Transliterator::new("x", false, Some(&pinyin), Some(&romaji), false, false).unwrap();
+        assert!(t.is_match(b"x"));
+    }
+
+    #[test]
+    fn transliterate_pattern() {
+        let m = IbMatcher::builder("拼音")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .build();
+        assert!(!m.is_match("pinyin_notes.txt"));
+
+        for analyze in [false, true] {
+            let m = IbMatcher::builder("拼音")
+                .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+                .transliterate_pattern(true)
+                .analyze(analyze)
+                .build();
+            assert_match!(m.find("pinyin_notes.txt"), Some((0, 6)));
+            assert_match!(m.find("my_PinYin.txt"), Some((3, 6)));
+            assert_match!(m.test("pinyin_notes.txt"), Some((0, 6)));
+            assert_match!(m.find("拼音.txt"), Some((0, 6)));
+            assert_match!(m.find("pinyi"), None);
+        }
+
+        let m = IbMatcher::builder("東京")
+            .romaji(RomajiMatchConfig::default())
+            .transliterate_pattern(true)
+            .ends_with(true)
+            .build();
+        assert_match!(m.find("trip_toukyou"), Some((5, 7)));
+        assert_match!(m.find("toukyou_trip"), None);
+
+        let m = IbMatcher::builder("饿a")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .transliterate_pattern(true)
+            .analyze(true)
+            .build();
+        assert_match!(m.test("ea"), Some((0, 2)));
+
+        let m = IbMatcher::builder("行行行行行行")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .transliterate_pattern(true)
+            .build();
+        assert_match!(m.find("hanghanghanghanghanghang"), Some((0, 24)));
+
+        let set = IbMatcherSet::new(["行", "拼音"].map(|pattern| {
+            IbMatcher::builder(pattern)
+                .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+                .transliterate_pattern(true)
+                .build()
+        }));
+        assert_eq!(set.matches("HangZhou").iter().collect::<Vec<_>>(), [0]);
+        assert_eq!(set.matches("pinyin").iter().collect::<Vec<_>>(), [1]);
+
+        let m = IbMatcher::builder("拼音.")
+            .pinyin(PinyinMatchConfig::notations(PinyinNotation::Ascii))
+            .transliterate_pattern(true)
+            .build();
+        let m = m.find_positions("a.pinyin.txt").unwrap();
+        assert_eq!(
+            m.char_spans()
+                .unwrap()
+                .iter()
+                .map(|span| (span.pattern(), span.haystack(), span.kind()))
+                .collect::<Vec<_>>(),
+            [
+                (0..3, 2..5, MatchKind::Pinyin(PinyinNotation::Ascii)),
+                (3..6, 5..8, MatchKind::Pinyin(PinyinNotation::Ascii)),
+                (6..7, 8..9, MatchKind::Plain),
+            ]
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use bon::Builder;
+#[cfg(feature = "romaji")]
+use ib_romaji::HepburnRomanizer;
+
+use super::{PinyinData, PinyinNotation};
+use crate::matcher::encoding::EncodedStr;
+
+/// A–Z index (字母索引) of strings, e.g. for the side index of contact lists, where "张三" is under "Z".
+///
+/// The bucket of a string is decided by its first char:
+/// - Hanzi: The first letter of its primary reading (see [`PinyinData::get_primary_pinyin()`]), or its reading in `words` if given. Without `words`, a polyphone (多音字) is always under its primary reading, e.g. "重庆" is under "Z" (zhòng).
+/// - ASCII letters: The upper case letter.
+/// - ASCII digits: `digit`.
+/// - Kana: The first letter of its romaji, if `romanizer` is given.
+/// - Others and empty strings: `other`.
+///
+/// ## Example
+/// ```
+/// use ib_matcher::pinyin::{AlphabetIndex, PinyinData, PinyinNotation};
+///
+/// let data = PinyinData::new(PinyinNotation::empty());
+/// let index = AlphabetIndex::builder(&data).build();
+/// assert_eq!(index.bucket("张三"), 'Z');
+/// assert_eq!(index.bucket("alice"), 'A');
+/// assert_eq!(index.bucket("10086"), '#');
+///
+/// let groups = index.group(["张三", "Bob", "李四", "白居易", "#1"]);
+/// assert_eq!(
+///     groups,
+///     [('B', vec!["Bob", "白居易"]), ('L', vec!["李四"]), ('Z', vec!["张三"]), ('#', vec!["#1"])]
+/// );
+/// ```
+#[derive(Builder, Clone)]
+pub struct AlphabetIndex<'a> {
+    #[builder(start_fn)]
+    data: &'a PinyinData,
+
+    /// The bucket of strings starting with ASCII digits.
+    #[builder(default = '#')]
+    digit: char,
+
+    /// The bucket of strings that don't belong to any letter.
+    #[builder(default = '#')]
+    other: char,
+
+    /// Kana are bucketed by their romajis if given, see [`HepburnRomanizer::romanize_kana_str()`].
+    #[cfg(feature = "romaji")]
+    romanizer: Option<&'a HepburnRomanizer>,
+
+    /// Decide the readings of hanzi in known words by the dictionary, see [`PinyinWords`](super::PinyinWords), e.g. "重庆" (chóng qìng) is under "C" instead of "Z".
+    #[cfg(feature = "pinyin-words")]
+    words: Option<&'a super::PinyinWords>,
+}
+
+impl AlphabetIndex<'_> {
+    /// The bucket of the string, see [`AlphabetIndex`].
+    pub fn bucket<HaystackStr>(&self, s: &HaystackStr) -> char
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        let Some((_, c, _)) = s.char_index_strs().next() else {
+            return self.other;
+        };
+
+        #[cfg(feature = "pinyin-words")]
+        if let Some(words) = self.words {
+            let chars: Vec<char> = s
+                .char_index_strs()
+                .take(words.max_chars())
+                .map(|(_, c, _)| c)
+                .collect();
+            let mut first = None;
+            words.segment_and_for_each(&chars, |i, index| {
+                if i == 0 {
+                    first = Some(index);
+                }
+            });
+            if let Some(index) = first {
+                return self
+                    .pinyin_bucket(self.data.pinyin(index).notation(PinyinNotation::Unicode));
+            }
+        }
+
+        if let Some(pinyin) = self.data.get_primary_pinyin(c) {
+            return self.pinyin_bucket(pinyin.notation(PinyinNotation::Unicode));
+        }
+
+        match c {
+            'a'..='z' | 'A'..='Z' => return c.to_ascii_uppercase(),
+            '0'..='9' => return self.digit,
+            _ => (),
+        }
+
+        #[cfg(feature = "romaji")]
+        if let Some(romanizer) = self.romanizer {
+            if let Some((_, romaji)) = romanizer.romanize_kana_str(c.encode_utf8(&mut [0; 4])) {
+                if let Some(letter) = romaji.chars().next().filter(char::is_ascii_alphabetic) {
+                    return letter.to_ascii_uppercase();
+                }
+            }
+        }
+
+        self.other
+    }
+
+    /// Bucket all strings in one call.
+    ///
+    /// ## Returns
+    /// Non-empty buckets, letters in alphabetical order first, then `digit` and `other`. Strings in a bucket are in their original order.
+    pub fn group<'s, HaystackStr>(
+        &self,
+        strs: impl IntoIterator<Item = &'s HaystackStr>,
+    ) -> Vec<(char, Vec<&'s HaystackStr>)>
+    where
+        HaystackStr: EncodedStr + ?Sized + 's,
+    {
+        let mut groups: Vec<(char, Vec<&'s HaystackStr>)> = Vec::new();
+        for s in strs {
+            let bucket = self.bucket(s);
+            match groups.iter_mut().find(|(b, _)| *b == bucket) {
+                Some((_, group)) => group.push(s),
+                None => groups.push((bucket, vec![s])),
+            }
+        }
+        groups.sort_by_key(|&(bucket, _)| {
+            (!bucket.is_ascii_uppercase(), bucket == self.other, bucket)
+        });
+        groups
+    }
+
+    /// The first letter of a Unicode pinyin, e.g. "ǹg" → 'N'.
+    fn pinyin_bucket(&self, unicode: Option<&str>) -> char {
+        unicode
+            .and_then(|pinyin| super::notation::unicode_to_ascii(pinyin).chars().next())
+            .map_or(self.other, |c| c.to_ascii_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let index = AlphabetIndex::builder(&data).build();
+        assert_eq!(index.bucket("张三"), 'Z');
+        assert_eq!(index.bucket("安妮"), 'A');
+        assert_eq!(index.bucket("欧阳"), 'O');
+        assert_eq!(index.bucket("嗯"), 'N');
+        assert_eq!(index.bucket("zoe"), 'Z');
+        assert_eq!(index.bucket("1"), '#');
+        assert_eq!(index.bucket(""), '#');
+        assert_eq!(index.bucket(" 张三"), '#');
+        assert_eq!(index.bucket("あい"), '#');
+
+        let index = AlphabetIndex::builder(&data).digit('0').other('~').build();
+        assert_eq!(index.bucket("1"), '0');
+        assert_eq!(index.bucket("@"), '~');
+        assert_eq!(
+            index.group(["@", "1", "b", "a", "啊"]),
+            [
+                ('A', vec!["a", "啊"]),
+                ('B', vec!["b"]),
+                ('0', vec!["1"]),
+                ('~', vec!["@"])
+            ]
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn bucket_u16() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let index = AlphabetIndex::builder(&data).build();
+        assert_eq!(index.bucket(widestring::u16str!("张三")), 'Z');
+        assert_eq!(index.bucket(widestring::u16str!("𠮾")), 'N');
+    }
+
+    #[cfg(feature = "romaji")]
+    #[test]
+    fn kana() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let romanizer = HepburnRomanizer::builder().kana(true).build();
+        let index = AlphabetIndex::builder(&data).romanizer(&romanizer).build();
+        assert_eq!(index.bucket("あい"), 'A');
+        assert_eq!(index.bucket("さくら"), 'S');
+        assert_eq!(index.bucket("カタカナ"), 'K');
+        assert_eq!(index.bucket("ー"), '#');
+    }
+
+    #[cfg(feature = "pinyin-words")]
+    #[test]
+    fn words() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let words = super::super::PinyinWords::new();
+        let index = AlphabetIndex::builder(&data).build();
+        assert_eq!(index.bucket("重庆"), 'Z');
+        let index = AlphabetIndex::builder(&data).words(&words).build();
+        assert_eq!(index.bucket("重要"), 'Z');
+        assert_eq!(index.bucket("重庆"), 'C');
+        assert_eq!(index.bucket("银行"), 'Y');
+    }
+}
@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+
+use bon::Builder;
+
+use super::{notation, Pinyin, PinyinData, PinyinNotation};
+use crate::{matcher::encoding::EncodedStr, unicode::CharToMonoLowercase};
+
+/// How tones are ordered, see [`PinyinCollator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CollateTone {
+    /// Tones are not significant, except for breaking ties by the original strings.
+    Ignore,
+    /// Strings are first compared by pinyins without tones, then by tones, e.g. "妈" (mā) < "马" (mǎ) < "骂" (mà) < "麻将" (má jiàng).
+    #[default]
+    Secondary,
+    /// Each pinyin is compared with its tone, e.g. "妈" (mā) < "麻将" (má jiàng) < "马" (mǎ).
+    Primary,
+}
+
+/// Where hanzi are placed relative to non-hanzi chars, see [`PinyinCollator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CollateHanzi {
+    /// Hanzi are sorted with letters by their pinyins, e.g. "Apple" < "安" (an) < "Banana".
+    #[default]
+    Interleaved,
+    /// Hanzi are placed before all non-hanzi chars, e.g. "安" < "0" < "Apple".
+    First,
+    /// Hanzi are placed after all non-hanzi chars, e.g. "0" < "Apple" < "安".
+    Last,
+}
+
+/// Sort strings by pinyin (按拼音排序), mixed with non-hanzi chars.
+///
+/// Each hanzi is sorted by its primary reading (see [`PinyinData::get_primary_pinyin()`]), or its reading in `readings`/`words` if given, and non-hanzi chars are sorted case-insensitively by code point, so digits are before letters. Ties are broken by tones (see [`CollateTone`]) and then the original strings.
+///
+/// The sort keys from [`PinyinCollator::sort_key()`] are byte-comparable, so they can be stored in a database index.
+///
+/// ## Example
+/// ```
+/// use ib_matcher::pinyin::{PinyinCollator, PinyinData, PinyinNotation};
+///
+/// let data = PinyinData::new(PinyinNotation::empty());
+/// let collator = PinyinCollator::builder(&data).build();
+/// let mut names = ["张三", "李四", "Alice", "王五", "bob", "安妮", "10"];
+/// names.sort_by_cached_key(|name| collator.sort_key(*name));
+/// assert_eq!(names, ["10", "Alice", "安妮", "bob", "李四", "王五", "张三"]);
+/// ```
+#[derive(Builder, Clone)]
+pub struct PinyinCollator<'a> {
+    #[builder(start_fn)]
+    data: &'a PinyinData,
+
+    #[builder(default)]
+    tone: CollateTone,
+
+    #[builder(default)]
+    hanzi: CollateHanzi,
+
+    /// Preferred readings of polyphones (多音字) in [`PinyinNotation::Unicode`], e.g. `[('单', "shàn")]` for surnames. Readings that are not readings of the char are ignored.
+    #[builder(default = &[])]
+    readings: &'a [(char, &'a str)],
+
+    /// Decide the readings of hanzi in known words by the dictionary, see [`PinyinWords`](super::PinyinWords). Take precedence over `readings`.
+    #[cfg(feature = "pinyin-words")]
+    words: Option<&'a super::PinyinWords>,
+}
+
+/// Separates the levels of a sort key.
+const LEVEL_SEPARATOR: u8 = 0x00;
+/// Ends the pinyin of a hanzi, so that "西安" (xi an) < "先" (xian).
+const SYLLABLE_END: u8 = 0x01;
+/// Prefixes the pinyin of a hanzi for [`CollateHanzi::First`].
+const HANZI_FIRST: u8 = 0x02;
+/// Prefixes the pinyin of a hanzi for [`CollateHanzi::Last`], never in UTF-8.
+const HANZI_LAST: u8 = 0xFF;
+/// Non-hanzi chars below this are treated as this, to not collide with the above.
+const MIN_NON_HANZI: u8 = 0x03;
+
+impl<'a> PinyinCollator<'a> {
+    /// A byte-comparable sort key of the string.
+    ///
+    /// Keys of different strings are always different. Keys are only comparable with keys from collators with the same options.
+    pub fn sort_key<HaystackStr>(&self, s: &HaystackStr) -> Vec<u8>
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        let chars: Vec<char> = s.char_index_strs().map(|(_, c, _)| c).collect();
+        let pinyins = self.pinyins(&chars);
+
+        let mut key = Vec::with_capacity(chars.len() * 8);
+        let mut tones = Vec::new();
+        for (&c, pinyin) in chars.iter().zip(&pinyins) {
+            match pinyin {
+                Some(pinyin) => {
+                    let unicode = pinyin.notation(PinyinNotation::Unicode).unwrap();
+                    let ascii_tone = notation::unicode_to_ascii_tone(unicode);
+                    let (ascii, tone) = ascii_tone.split_at(ascii_tone.len() as usize - 1);
+                    match self.hanzi {
+                        CollateHanzi::Interleaved => (),
+                        CollateHanzi::First => key.push(HANZI_FIRST),
+                        CollateHanzi::Last => key.push(HANZI_LAST),
+                    }
+                    key.extend_from_slice(ascii.as_bytes());
+                    match self.tone {
+                        CollateTone::Ignore => (),
+                        CollateTone::Secondary => tones.push(tone.as_bytes()[0]),
+                        CollateTone::Primary => key.push(tone.as_bytes()[0]),
+                    }
+                    key.push(SYLLABLE_END);
+                }
+                None => {
+                    let c = c.to_mono_lowercase();
+                    let start = key.len();
+                    key.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                    key[start] = key[start].max(MIN_NON_HANZI);
+                }
+            }
+        }
+
+        key.push(LEVEL_SEPARATOR);
+        key.extend_from_slice(&tones);
+        key.push(LEVEL_SEPARATOR);
+        for c in chars {
+            key.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+        key
+    }
+
+    /// Compare two strings by their sort keys.
+    ///
+    /// If many strings are compared, [`PinyinCollator::sort_key()`] with e.g. [`slice::sort_by_cached_key()`] is faster.
+    pub fn compare<HaystackStr>(&self, a: &HaystackStr, b: &HaystackStr) -> Ordering
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        self.sort_key(a).cmp(&self.sort_key(b))
+    }
+
+    /// The reading of each char, `None` for non-hanzi.
+    fn pinyins(&self, chars: &[char]) -> Vec<Option<Pinyin<'a>>> {
+        #[cfg_attr(not(feature = "pinyin-words"), allow(unused_mut))]
+        let mut pinyins: Vec<_> = chars
+            .iter()
+            .map(|&c| {
+                let preferred = self
+                    .readings
+                    .iter()
+                    .find(|&&(reading_c, _)| reading_c == c)
+                    .and_then(|&(_, reading)| {
+                        self.data.get_pinyins_and_try_for_each(c, |pinyin| {
+                            (pinyin.notation(PinyinNotation::Unicode) == Some(reading))
+                                .then_some(pinyin)
+                        })
+                    });
+                preferred.or_else(|| self.data.get_primary_pinyin(c))
+            })
+            .collect();
+
+        #[cfg(feature = "pinyin-words")]
+        if let Some(words) = self.words {
+            words
+                .segment_and_for_each(chars, |i, index| pinyins[i] = Some(self.data.pinyin(index)));
+        }
+
+        pinyins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<'s>(collator: &PinyinCollator, mut v: Vec<&'s str>) -> Vec<&'s str> {
+        v.sort_by_cached_key(|s| collator.sort_key(*s));
+        v
+    }
+
+    #[test]
+    fn sort_key() {
+        let data = PinyinData::new(PinyinNotation::empty());
+
+        let collator = PinyinCollator::builder(&data).build();
+        assert_eq!(
+            sorted(
+                &collator,
+                vec!["先", "西安", "Xi", "xian", "麻将", "骂", "马", "妈"]
+            ),
+            ["妈", "马", "骂", "麻将", "Xi", "西安", "xian", "先"]
+        );
+        assert_eq!(
+            sorted(&collator, vec!["b", "B", "a", "A", "", "1", "-", "\0"]),
+            ["", "\0", "-", "1", "A", "a", "B", "b"]
+        );
+        // Same readings are sorted by code points
+        assert_eq!(
+            sorted(&collator, vec!["钟", "中", "忠"]),
+            ["中", "忠", "钟"]
+        );
+
+        let collator = PinyinCollator::builder(&data)
+            .tone(CollateTone::Primary)
+            .build();
+        assert_eq!(
+            sorted(&collator, vec!["麻将", "骂", "马", "妈"]),
+            ["妈", "麻将", "马", "骂"]
+        );
+
+        let collator = PinyinCollator::builder(&data)
+            .tone(CollateTone::Ignore)
+            .build();
+        assert_eq!(
+            sorted(&collator, vec!["骂", "马", "妈"]),
+            ["妈", "马", "骂"]
+        );
+
+        let collator = PinyinCollator::builder(&data)
+            .hanzi(CollateHanzi::First)
+            .build();
+        assert_eq!(
+            sorted(&collator, vec!["b", "安", "1", "Z"]),
+            ["安", "1", "b", "Z"]
+        );
+        let collator = PinyinCollator::builder(&data)
+            .hanzi(CollateHanzi::Last)
+            .build();
+        assert_eq!(
+            sorted(&collator, vec!["b", "安", "1", "Z", "é"]),
+            ["1", "b", "Z", "é", "安"]
+        );
+    }
+
+    #[test]
+    fn readings() {
+        let data = PinyinData::new(PinyinNotation::empty());
+
+        let collator = PinyinCollator::builder(&data).build();
+        assert_eq!(
+            sorted(&collator, vec!["单田芳", "沙宝亮"]),
+            ["单田芳", "沙宝亮"]
+        );
+
+        let readings = [('单', "shàn"), ('中', "invalid")];
+        let collator = PinyinCollator::builder(&data).readings(&readings).build();
+        assert_eq!(
+            sorted(&collator, vec!["单田芳", "沙宝亮", "中"]),
+            ["沙宝亮", "单田芳", "中"]
+        );
+        assert_eq!(collator.compare("单", "善"), Ordering::Less);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn sort_key_u16() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let collator = PinyinCollator::builder(&data).build();
+        assert_eq!(
+            collator.sort_key(widestring::u16str!("拼音𠮾A")),
+            collator.sort_key("拼音𠮾A")
+        );
+    }
+
+    #[cfg(feature = "pinyin-words")]
+    #[test]
+    fn words() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let words = super::super::PinyinWords::new();
+        let collator = PinyinCollator::builder(&data).words(&words).build();
+        assert_eq!(sorted(&collator, vec!["中国", "重庆"]), ["重庆", "中国"]);
+    }
+}
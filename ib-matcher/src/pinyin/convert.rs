@@ -0,0 +1,251 @@
+use bon::bon;
+
+use super::{PinyinData, PinyinNotation, PinyinNotationError};
+use crate::matcher::encoding::EncodedStr;
+
+/// How to convert non-hanzi chars, see [`PinyinData::to_pinyin()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NonHanzi {
+    /// Keep consecutive non-hanzi chars as one segment, e.g. "拼音ABC" → "pin yin ABC".
+    #[default]
+    Keep,
+    /// Skip non-hanzi chars, e.g. "拼音ABC" → "pin yin".
+    Skip,
+}
+
+#[bon]
+impl PinyinData {
+    /// Convert a string to pinyin in the given notation.
+    ///
+    /// `notation` must be a single notation, otherwise [`PinyinNotationError::NotSingle`] is returned. It must also be inited, otherwise [`PinyinNotationError::NotInited`] is returned. With the `inmut-data` feature, it is inited on demand (except [`PinyinNotation::DiletterCustom`], which requires [`PinyinData::init_diletter_scheme()`]).
+    ///
+    /// ## Arguments
+    /// - `separator`: Between the pinyins of hanzi and segments of non-hanzi chars.
+    /// - `heteronym`: Whether to output all readings of a hanzi (多音字), instead of the primary reading only. Readings are in the order of [`PinyinData::get_pinyins()`].
+    /// - `heteronym_separator`: Between readings of a hanzi if `heteronym`. Readings that are the same in `notation` are only output once.
+    /// - `words`: Decide the readings of hanzi in known words by the dictionary, see [`PinyinWords`](super::PinyinWords). Only the decided reading is output even if `heteronym`.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::pinyin::{NonHanzi, PinyinData, PinyinNotation};
+    ///
+    /// let data = PinyinData::new(PinyinNotation::Ascii);
+    /// assert_eq!(data.to_pinyin("拼音搜索", PinyinNotation::Ascii).call().unwrap(), "pin yin sou suo");
+    /// assert_eq!(data.to_pinyin("拼音", PinyinNotation::Unicode).separator("").call().unwrap(), "pīnyīn");
+    /// assert_eq!(
+    ///     data.to_pinyin("重要ABC", PinyinNotation::Ascii)
+    ///         .heteronym(true)
+    ///         .non_hanzi(NonHanzi::Skip)
+    ///         .call()
+    ///         .unwrap(),
+    ///     "zhong/chong yao"
+    /// );
+    /// ```
+    #[builder]
+    pub fn to_pinyin<HaystackStr>(
+        &self,
+        #[builder(start_fn)] s: &HaystackStr,
+        #[builder(start_fn)] notation: PinyinNotation,
+        #[builder(default = " ")] separator: &str,
+        #[builder(default = false)] heteronym: bool,
+        #[builder(default = "/")] heteronym_separator: &str,
+        #[builder(default)] non_hanzi: NonHanzi,
+        #[cfg(feature = "pinyin-words")] words: Option<&super::PinyinWords>,
+    ) -> Result<String, PinyinNotationError>
+    where
+        HaystackStr: EncodedStr + ?Sized,
+    {
+        if notation.bits().count_ones() != 1 {
+            return Err(PinyinNotationError::NotSingle(notation));
+        }
+        #[cfg(feature = "inmut-data")]
+        self.init_notations(notation);
+        if !self.inited_notations().contains(notation) {
+            return Err(PinyinNotationError::NotInited(notation));
+        }
+
+        let mut pinyin = String::new();
+        let mut in_non_hanzi = false;
+        let mut readings: Vec<&str> = Vec::new();
+
+        #[cfg(feature = "pinyin-words")]
+        let word_pinyins = words.map(|words| {
+            let chars: Vec<char> = s.char_index_strs().map(|(_, c, _)| c).collect();
+            let mut word_pinyins = vec![None; chars.len()];
+            words.segment_and_for_each(&chars, |i, index| word_pinyins[i] = Some(index));
+            word_pinyins
+        });
+        #[cfg(feature = "pinyin-words")]
+        let word_pinyin = |i: usize| word_pinyins.as_ref().and_then(|pinyins| pinyins[i]);
+        #[cfg(not(feature = "pinyin-words"))]
+        let word_pinyin = |_: usize| None;
+
+        for (i, (_, c, _)) in s.char_index_strs().enumerate() {
+            readings.clear();
+            if let Some(index) = word_pinyin(i) {
+                readings.push(self.pinyin(index).notation(notation).unwrap());
+            } else if heteronym {
+                self.get_pinyins_and_for_each(c, |py| {
+                    let py = py.notation(notation).unwrap();
+                    if !readings.contains(&py) {
+                        readings.push(py);
+                    }
+                });
+            } else if let Some(py) = self.get_primary_pinyin(c) {
+                readings.push(py.notation(notation).unwrap());
+            }
+
+            if readings.is_empty() {
+                match non_hanzi {
+                    NonHanzi::Keep => {
+                        if !in_non_hanzi && !pinyin.is_empty() {
+                            pinyin.push_str(separator);
+                        }
+                        pinyin.push(c);
+                        in_non_hanzi = true;
+                    }
+                    NonHanzi::Skip => (),
+                }
+                continue;
+            }
+
+            if !pinyin.is_empty() {
+                pinyin.push_str(separator);
+            }
+            for (i, py) in readings.iter().enumerate() {
+                if i != 0 {
+                    pinyin.push_str(heteronym_separator);
+                }
+                pinyin.push_str(py);
+            }
+            in_non_hanzi = false;
+        }
+        Ok(pinyin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_pinyin() {
+        let data = PinyinData::new(
+            PinyinNotation::Ascii
+                | PinyinNotation::AsciiFirstLetter
+                | PinyinNotation::DiletterXiaohe,
+        );
+
+        assert_eq!(
+            data.to_pinyin("拼音搜索Everything", PinyinNotation::Ascii)
+                .call()
+                .unwrap(),
+            "pin yin sou suo Everything"
+        );
+        assert_eq!(
+            data.to_pinyin("A拼音 B", PinyinNotation::AsciiFirstLetter)
+                .separator("")
+                .call()
+                .unwrap(),
+            "Apy B"
+        );
+        assert_eq!(
+            data.to_pinyin("1拼2音3", PinyinNotation::Ascii)
+                .separator("'")
+                .non_hanzi(NonHanzi::Skip)
+                .call()
+                .unwrap(),
+            "pin'yin"
+        );
+        assert_eq!(
+            data.to_pinyin("中国", PinyinNotation::Unicode)
+                .heteronym(true)
+                .heteronym_separator(",")
+                .call()
+                .unwrap(),
+            "zhōng,zhòng guó"
+        );
+        assert_eq!(
+            data.to_pinyin("拼音", PinyinNotation::DiletterXiaohe)
+                .call()
+                .unwrap(),
+            "pb yb"
+        );
+        assert_eq!(
+            data.to_pinyin("", PinyinNotation::Ascii).call().unwrap(),
+            ""
+        );
+    }
+
+    #[cfg(feature = "inmut-data")]
+    #[test]
+    fn to_pinyin_init() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(
+            data.to_pinyin("中国", PinyinNotation::Zhuyin)
+                .call()
+                .unwrap(),
+            "ㄓㄨㄥ ㄍㄨㄛ"
+        );
+    }
+
+    #[test]
+    fn to_pinyin_error() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(
+            data.to_pinyin("中国", PinyinNotation::Ascii | PinyinNotation::Unicode)
+                .call(),
+            Err(PinyinNotationError::NotSingle(
+                PinyinNotation::Ascii | PinyinNotation::Unicode
+            ))
+        );
+        assert_eq!(
+            data.to_pinyin("中国", PinyinNotation::DiletterCustom)
+                .call(),
+            Err(PinyinNotationError::NotInited(
+                PinyinNotation::DiletterCustom
+            ))
+        );
+    }
+
+    #[cfg(feature = "pinyin-words")]
+    #[test]
+    fn to_pinyin_words() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        let words = super::super::PinyinWords::new();
+        assert_eq!(
+            data.to_pinyin("重庆银行行长", PinyinNotation::Ascii)
+                .heteronym(true)
+                .call()
+                .unwrap(),
+            "zhong/chong qing yin xing/hang/heng xing/hang/heng zhang/chang"
+        );
+        assert_eq!(
+            data.to_pinyin("重庆银行行长", PinyinNotation::Ascii)
+                .heteronym(true)
+                .words(&words)
+                .call()
+                .unwrap(),
+            "chong qing yin hang hang zhang"
+        );
+        assert_eq!(
+            data.to_pinyin("A重要B", PinyinNotation::Unicode)
+                .words(&words)
+                .call()
+                .unwrap(),
+            "A zhòng yào B"
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn to_pinyin_u16() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(
+            data.to_pinyin(widestring::u16str!("拼音𠮾A"), PinyinNotation::Ascii)
+                .call()
+                .unwrap(),
+            "pin yin n A"
+        );
+    }
+}
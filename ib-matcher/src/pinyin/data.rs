@@ -9,14 +9,14 @@ pub(super) const PINYINS: [&'static str; 1514] = [
 
 pub(super) const PINYIN_COMBINATION_LEN: usize = 10;
 
-pub(super) static PINYIN_COMBINATIONS: [PinyinCombination; 1104] = [
-[0,1,2,3,4,F,F,F,F,F],[0,1,3,4,429,553,F,F,F,F],[0,4,290,F,F,F,F,F,F,F],[0,1317,F,F,F,F,F,F,F,F],[0,1322,F,F,F,F,F,F,F,F],[1,1047,F,F,F,F,F,F,F,F],[5,6,F,F,F,F,F,F,F,F],[5,7,8,F,F,F,F,F,F,F],[5,7,289,292,293,296,298,299,300,301],[5,8,F,F,F,F,F,F,F,F],[5,1257,F,F,F,F,F,F,F,F],[6,222,F,F,F,F,F,F,F,F],[8,600,F,F,F,F,F,F,F,F],[8,835,F,F,F,F,F,F,F,F],[8,1343,F,F,F,F,F,F,F,F],[9,11,F,F,F,F,F,F,F,F],[9,125,F,F,F,F,F,F,F,F],[9,396,F,F,F,F,F,F,F,F],[12,417,F,F,F,F,F,F,F,F],[12,1324,F,F,F,F,F,F,F,F],[17,18,F,F,F,F,F,F,F,F],[17,1219,F,F,F,F,F,F,F,F],[18,1274,F,F,F,F,F,F,F,F],[19,20,824,F,F,F,F,F,F,F],[20,1367,F,F,F,F,F,F,F,F],[21,22,F,F,F,F,F,F,F,F],[21,25,F,F,F,F,F,F,F,F],[21,854,F,F,F,F,F,F,F,F],[23,24,F,F,F,F,F,F,F,F],[23,854,F,F,F,F,F,F,F,F],[24,25,F,F,F,F,F,F,F,F],[24,25,886,F,F,F,F,F,F,F],[24,42,F,F,F,F,F,F,F,F],[24,77,F,F,F,F,F,F,F,F],[24,78,F,F,F,F,F,F,F,F],[24,854,F,F,F,F,F,F,F,F],[26,80,F,F,F,F,F,F,F,F],[27,29,F,F,F,F,F,F,F,F],[28,78,F,F,F,F,F,F,F,F],[28,78,80,F,F,F,F,F,F,F],[29,45,F,F,F,F,F,F,F,F],[31,77,862,F,F,F,F,F,F,F],[31,861,F,F,F,F,F,F,F,F],[36,37,865,866,F,F,F,F,F,F],[36,37,882,F,F,F,F,F,F,F],[36,867,F,F,F,F,F,F,F,F],[37,44,F,F,F,F,F,F,F,F],[37,52,F,F,F,F,F,F,F,F],[37,866,F,F,F,F,F,F,F,F],[37,882,F,F,F,F,F,F,F,F],[38,77,F,F,F,F,F,F,F,F],[38,342,F,F,F,F,F,F,F,F],[38,870,872,F,F,F,F,F,F,F],[39,78,80,F,F,F,F,F,F,F],[40,84,918,F,F,F,F,F,F,F],[41,77,F,F,F,F,F,F,F,F],[41,870,F,F,F,F,F,F,F,F],[41,918,F,F,F,F,F,F,F,F],[42,44,F,F,F,F,F,F,F,F],[42,886,F,F,F,F,F,F,F,F],[42,886,907,F,F,F,F,F,F,F],[44,78,F,F,F,F,F,F,F,F],[44,876,F,F,F,F,F,F,F,F],[45,57,F,F,F,F,F,F,F,F],[46,48,F,F,F,F,F,F,F,F],[46,57,F,F,F,F,F,F,F,F],[46,74,F,F,F,F,F,F,F,F],[48,331,F,F,F,F,F,F,F,F],[48,422,F,F,F,F,F,F,F,F],[49,51,52,F,F,F,F,F,F,F],[56,57,F,F,F,F,F,F,F,F],[56,886,F,F,F,F,F,F,F,F],[56,887,F,F,F,F,F,F,F,F],[57,67,F,F,F,F,F,F,F,F],[57,730,F,F,F,F,F,F,F,F],[57,885,888,F,F,F,F,F,F,F],[57,886,F,F,F,F,F,F,F,F],[58,59,F,F,F,F,F,F,F,F],[58,61,F,F,F,F,F,F,F,F],[59,889,F,F,F,F,F,F,F,F],[60,890,F,F,F,F,F,F,F,F],[63,64,F,F,F,F,F,F,F,F],[63,65,F,F,F,F,F,F,F,F],[63,870,F,F,F,F,F,F,F,F],[63,896,F,F,F,F,F,F,F,F],[63,1061,F,F,F,F,F,F,F,F],[66,68,F,F,F,F,F,F,F,F],[67,69,F,F,F,F,F,F,F,F],[67,943,F,F,F,F,F,F,F,F],[70,74,F,F,F,F,F,F,F,F],[70,328,F,F,F,F,F,F,F,F],[74,76,F,F,F,F,F,F,F,F],[75,76,F,F,F,F,F,F,F,F],[75,905,F,F,F,F,F,F,F,F],[77,81,F,F,F,F,F,F,F,F],[77,315,316,F,F,F,F,F,F,F],[78,81,F,F,F,F,F,F,F,F],[78,342,F,F,F,F,F,F,F,F],[78,907,F,F,F,F,F,F,F,F],[78,910,1218,F,F,F,F,F,F,F],[78,911,F,F,F,F,F,F,F,F],[79,80,F,F,F,F,F,F,F,F],[80,814,F,F,F,F,F,F,F,F],[81,84,F,F,F,F,F,F,F,F],[82,84,F,F,F,F,F,F,F,F],[83,85,F,F,F,F,F,F,F,F],[85,917,F,F,F,F,F,F,F,F],[85,1002,F,F,F,F,F,F,F,F],[86,111,F,F,F,F,F,F,F,F],[86,115,F,F,F,F,F,F,F,F],[89,1110,F,F,F,F,F,F,F,F],[91,92,F,F,F,F,F,F,F,F],[93,106,1069,F,F,F,F,F,F,F],[95,106,1052,F,F,F,F,F,F,F],[95,1387,F,F,F,F,F,F,F,F],[96,119,1054,F,F,F,F,F,F,F],[96,120,F,F,F,F,F,F,F,F],[97,140,F,F,F,F,F,F,F,F],[98,1394,F,F,F,F,F,F,F,F],[104,486,F,F,F,F,F,F,F,F],[104,486,487,F,F,F,F,F,F,F],[104,1113,F,F,F,F,F,F,F,F],[104,1400,1415,F,F,F,F,F,F,F],[107,137,947,F,F,F,F,F,F,F],[108,141,F,F,F,F,F,F,F,F],[109,1407,F,F,F,F,F,F,F,F],[111,112,F,F,F,F,F,F,F,F],[111,112,113,114,F,F,F,F,F,F],[111,114,F,F,F,F,F,F,F,F],[111,114,115,118,187,F,F,F,F,F],[111,1410,F,F,F,F,F,F,F,F],[111,1414,F,F,F,F,F,F,F,F],[112,1410,F,F,F,F,F,F,F,F],[113,114,F,F,F,F,F,F,F,F],[114,1044,F,F,F,F,F,F,F,F],[118,214,F,F,F,F,F,F,F,F],[120,226,1055,F,F,F,F,F,F,F],[120,1055,F,F,F,F,F,F,F,F],[120,1150,1284,F,F,F,F,F,F,F],[121,122,F,F,F,F,F,F,F,F],[121,1149,F,F,F,F,F,F,F,F],[122,1422,F,F,F,F,F,F,F,F],[124,125,F,F,F,F,F,F,F,F],[124,1059,F,F,F,F,F,F,F,F],[124,1155,F,F,F,F,F,F,F,F],[124,1424,F,F,F,F,F,F,F,F],[125,1155,F,F,F,F,F,F,F,F],[126,1327,F,F,F,F,F,F,F,F],[128,130,F,F,F,F,F,F,F,F],[128,186,F,F,F,F,F,F,F,F],[128,501,F,F,F,F,F,F,F,F],[128,1274,F,F,F,F,F,F,F,F],[128,1479,F,F,F,F,F,F,F,F],[129,1427,F,F,F,F,F,F,F,F],[132,523,F,F,F,F,F,F,F,F],[134,147,F,F,F,F,F,F,F,F],[136,648,F,F,F,F,F,F,F,F],[136,1069,F,F,F,F,F,F,F,F],[137,142,F,F,F,F,F,F,F,F],[137,1071,F,F,F,F,F,F,F,F],[137,1441,F,F,F,F,F,F,F,F],[139,141,144,F,F,F,F,F,F,F],[141,144,F,F,F,F,F,F,F,F],[141,229,F,F,F,F,F,F,F,F],[141,1438,F,F,F,F,F,F,F,F],[142,143,F,F,F,F,F,F,F,F],[142,244,F,F,F,F,F,F,F,F],[142,1076,F,F,F,F,F,F,F,F],[146,247,F,F,F,F,F,F,F,F],[146,1081,F,F,F,F,F,F,F,F],[147,1080,F,F,F,F,F,F,F,F],[147,1349,F,F,F,F,F,F,F,F],[148,1448,F,F,F,F,F,F,F,F],[150,153,F,F,F,F,F,F,F,F],[150,1357,F,F,F,F,F,F,F,F],[151,1099,1472,F,F,F,F,F,F,F],[151,1451,1452,F,F,F,F,F,F,F],[151,1452,F,F,F,F,F,F,F,F],[152,1155,F,F,F,F,F,F,F,F],[154,155,F,F,F,F,F,F,F,F],[154,1453,F,F,F,F,F,F,F,F],[155,235,F,F,F,F,F,F,F,F],[155,959,F,F,F,F,F,F,F,F],[156,823,F,F,F,F,F,F,F,F],[156,941,F,F,F,F,F,F,F,F],[157,1300,F,F,F,F,F,F,F,F],[159,525,F,F,F,F,F,F,F,F],[160,161,F,F,F,F,F,F,F,F],[160,161,1458,F,F,F,F,F,F,F],[160,1459,F,F,F,F,F,F,F,F],[161,1170,F,F,F,F,F,F,F,F],[161,1304,F,F,F,F,F,F,F,F],[161,1460,F,F,F,F,F,F,F,F],[163,1301,F,F,F,F,F,F,F,F],[166,168,169,F,F,F,F,F,F,F],[169,186,F,F,F,F,F,F,F,F],[169,1509,F,F,F,F,F,F,F,F],[171,179,F,F,F,F,F,F,F,F],[171,1469,F,F,F,F,F,F,F,F],[172,183,1466,1467,F,F,F,F,F,F],[174,177,F,F,F,F,F,F,F,F],[175,1472,F,F,F,F,F,F,F,F],[179,1473,F,F,F,F,F,F,F,F],[183,280,F,F,F,F,F,F,F,F],[186,1020,F,F,F,F,F,F,F,F],[187,189,F,F,F,F,F,F,F,F],[187,190,F,F,F,F,F,F,F,F],[187,1483,F,F,F,F,F,F,F,F],[188,483,F,F,F,F,F,F,F,F],[188,1483,F,F,F,F,F,F,F,F],[188,1485,F,F,F,F,F,F,F,F],[190,1112,F,F,F,F,F,F,F,F],[191,192,F,F,F,F,F,F,F,F],[191,1488,F,F,F,F,F,F,F,F],[192,1490,F,F,F,F,F,F,F,F],[201,522,F,F,F,F,F,F,F,F],[201,965,F,F,F,F,F,F,F,F],[201,1496,F,F,F,F,F,F,F,F],[201,1512,F,F,F,F,F,F,F,F],[203,1389,F,F,F,F,F,F,F,F],[205,1094,F,F,F,F,F,F,F,F],[210,281,F,F,F,F,F,F,F,F],[212,1349,F,F,F,F,F,F,F,F],[213,1511,F,F,F,F,F,F,F,F],[214,1390,F,F,F,F,F,F,F,F],[216,1480,F,F,F,F,F,F,F,F],[217,218,F,F,F,F,F,F,F,F],[217,218,221,F,F,F,F,F,F,F],[217,1142,F,F,F,F,F,F,F,F],[218,219,F,F,F,F,F,F,F,F],[218,221,F,F,F,F,F,F,F,F],[218,1141,F,F,F,F,F,F,F,F],[218,1142,F,F,F,F,F,F,F,F],[220,224,F,F,F,F,F,F,F,F],[221,227,F,F,F,F,F,F,F,F],[221,1141,F,F,F,F,F,F,F,F],[222,224,F,F,F,F,F,F,F,F],[222,1146,F,F,F,F,F,F,F,F],[223,224,F,F,F,F,F,F,F,F],[224,1145,F,F,F,F,F,F,F,F],[226,227,228,F,F,F,F,F,F,F],[226,228,F,F,F,F,F,F,F,F],[227,228,F,F,F,F,F,F,F,F],[227,1055,F,F,F,F,F,F,F,F],[228,265,F,F,F,F,F,F,F,F],[228,1052,F,F,F,F,F,F,F,F],[228,1078,F,F,F,F,F,F,F,F],[228,1150,F,F,F,F,F,F,F,F],[229,231,F,F,F,F,F,F,F,F],[230,231,F,F,F,F,F,F,F,F],[232,233,1157,F,F,F,F,F,F,F],[234,235,F,F,F,F,F,F,F,F],[235,1157,F,F,F,F,F,F,F,F],[236,239,F,F,F,F,F,F,F,F],[237,238,240,F,F,F,F,F,F,F],[238,245,246,248,F,F,F,F,F,F],[238,247,F,F,F,F,F,F,F,F],[238,248,F,F,F,F,F,F,F,F],[238,1162,F,F,F,F,F,F,F,F],[242,244,F,F,F,F,F,F,F,F],[245,246,F,F,F,F,F,F,F,F],[245,247,F,F,F,F,F,F,F,F],[245,1168,F,F,F,F,F,F,F,F],[246,1416,F,F,F,F,F,F,F,F],[246,1446,F,F,F,F,F,F,F,F],[247,1447,F,F,F,F,F,F,F,F],[248,287,F,F,F,F,F,F,F,F],[249,256,F,F,F,F,F,F,F,F],[250,252,F,F,F,F,F,F,F,F],[251,256,F,F,F,F,F,F,F,F],[252,1173,F,F,F,F,F,F,F,F],[252,1323,F,F,F,F,F,F,F,F],[254,809,F,F,F,F,F,F,F,F],[255,1177,F,F,F,F,F,F,F,F],[255,1332,F,F,F,F,F,F,F,F],[256,257,F,F,F,F,F,F,F,F],[257,1065,F,F,F,F,F,F,F,F],[257,1167,F,F,F,F,F,F,F,F],[257,1338,F,F,F,F,F,F,F,F],[257,1343,F,F,F,F,F,F,F,F],[257,1411,F,F,F,F,F,F,F,F],[261,262,F,F,F,F,F,F,F,F],[261,263,F,F,F,F,F,F,F,F],[261,1185,1187,F,F,F,F,F,F,F],[261,1442,F,F,F,F,F,F,F,F],[263,1187,F,F,F,F,F,F,F,F],[267,656,F,F,F,F,F,F,F,F],[267,1189,F,F,F,F,F,F,F,F],[267,1190,F,F,F,F,F,F,F,F],[267,1190,1191,F,F,F,F,F,F,F],[268,271,F,F,F,F,F,F,F,F],[269,270,F,F,F,F,F,F,F,F],[269,1195,F,F,F,F,F,F,F,F],[270,272,F,F,F,F,F,F,F,F],[271,1065,F,F,F,F,F,F,F,F],[272,283,F,F,F,F,F,F,F,F],[272,1454,F,F,F,F,F,F,F,F],[273,274,F,F,F,F,F,F,F,F],[274,285,F,F,F,F,F,F,F,F],[275,1466,F,F,F,F,F,F,F,F],[278,1502,F,F,F,F,F,F,F,F],[280,281,F,F,F,F,F,F,F,F],[283,1106,F,F,F,F,F,F,F,F],[283,1212,F,F,F,F,F,F,F,F],[283,1469,F,F,F,F,F,F,F,F],[285,287,F,F,F,F,F,F,F,F],[286,287,F,F,F,F,F,F,F,F],[287,468,F,F,F,F,F,F,F,F],[287,1216,F,F,F,F,F,F,F,F],[291,846,848,F,F,F,F,F,F,F],[291,1342,F,F,F,F,F,F,F,F],[294,295,F,F,F,F,F,F,F,F],[294,295,1252,1255,F,F,F,F,F,F],[295,297,F,F,F,F,F,F,F,F],[295,1322,F,F,F,F,F,F,F,F],[295,1338,F,F,F,F,F,F,F,F],[298,299,300,301,F,F,F,F,F,F],[306,309,990,F,F,F,F,F,F,F],[306,772,F,F,F,F,F,F,F,F],[306,799,F,F,F,F,F,F,F,F],[308,772,F,F,F,F,F,F,F,F],[310,313,F,F,F,F,F,F,F,F],[310,907,910,F,F,F,F,F,F,F],[311,312,F,F,F,F,F,F,F,F],[312,313,F,F,F,F,F,F,F,F],[315,316,F,F,F,F,F,F,F,F],[315,861,F,F,F,F,F,F,F,F],[316,318,F,F,F,F,F,F,F,F],[316,908,F,F,F,F,F,F,F,F],[319,320,F,F,F,F,F,F,F,F],[321,866,F,F,F,F,F,F,F,F],[324,326,F,F,F,F,F,F,F,F],[327,342,F,F,F,F,F,F,F,F],[328,329,331,F,F,F,F,F,F,F],[328,344,F,F,F,F,F,F,F,F],[332,334,F,F,F,F,F,F,F,F],[332,335,F,F,F,F,F,F,F,F],[333,335,F,F,F,F,F,F,F,F],[333,905,F,F,F,F,F,F,F,F],[334,335,F,F,F,F,F,F,F,F],[337,342,F,F,F,F,F,F,F,F],[340,887,F,F,F,F,F,F,F,F],[341,342,F,F,F,F,F,F,F,F],[342,344,F,F,F,F,F,F,F,F],[342,730,F,F,F,F,F,F,F,F],[342,895,F,F,F,F,F,F,F,F],[342,1080,F,F,F,F,F,F,F,F],[343,344,F,F,F,F,F,F,F,F],[343,691,1254,F,F,F,F,F,F,F],[343,732,F,F,F,F,F,F,F,F],[343,916,F,F,F,F,F,F,F,F],[346,347,348,F,F,F,F,F,F,F],[346,362,363,F,F,F,F,F,F,F],[346,486,487,F,F,F,F,F,F,F],[346,486,943,F,F,F,F,F,F,F],[346,487,F,F,F,F,F,F,F,F],[346,538,F,F,F,F,F,F,F,F],[346,1261,F,F,F,F,F,F,F,F],[347,1320,1411,F,F,F,F,F,F,F],[352,364,F,F,F,F,F,F,F,F],[352,507,F,F,F,F,F,F,F,F],[352,921,F,F,F,F,F,F,F,F],[353,354,F,F,F,F,F,F,F,F],[353,355,F,F,F,F,F,F,F,F],[353,418,F,F,F,F,F,F,F,F],[353,930,F,F,F,F,F,F,F,F],[354,420,F,F,F,F,F,F,F,F],[356,357,F,F,F,F,F,F,F,F],[356,358,F,F,F,F,F,F,F,F],[356,547,F,F,F,F,F,F,F,F],[358,1472,F,F,F,F,F,F,F,F],[359,361,F,F,F,F,F,F,F,F],[360,428,F,F,F,F,F,F,F,F],[362,363,F,F,F,F,F,F,F,F],[362,430,F,F,F,F,F,F,F,F],[362,539,659,684,F,F,F,F,F,F],[362,616,F,F,F,F,F,F,F,F],[362,1343,F,F,F,F,F,F,F,F],[363,364,F,F,F,F,F,F,F,F],[363,409,F,F,F,F,F,F,F,F],[363,430,F,F,F,F,F,F,F,F],[363,483,F,F,F,F,F,F,F,F],[363,629,F,F,F,F,F,F,F,F],[364,365,F,F,F,F,F,F,F,F],[364,430,F,F,F,F,F,F,F,F],[365,684,F,F,F,F,F,F,F,F],[366,484,F,F,F,F,F,F,F,F],[369,370,F,F,F,F,F,F,F,F],[371,373,F,F,F,F,F,F,F,F],[372,513,F,F,F,F,F,F,F,F],[374,376,F,F,F,F,F,F,F,F],[374,442,F,F,F,F,F,F,F,F],[374,662,F,F,F,F,F,F,F,F],[375,442,F,F,F,F,F,F,F,F],[376,443,F,F,F,F,F,F,F,F],[378,379,525,F,F,F,F,F,F,F],[378,380,F,F,F,F,F,F,F,F],[378,380,526,F,F,F,F,F,F,F],[381,382,383,F,F,F,F,F,F,F],[381,383,F,F,F,F,F,F,F,F],[381,384,F,F,F,F,F,F,F,F],[381,385,387,F,F,F,F,F,F,F],[383,450,F,F,F,F,F,F,F,F],[383,471,F,F,F,F,F,F,F,F],[383,488,F,F,F,F,F,F,F,F],[383,1367,F,F,F,F,F,F,F,F],[385,404,F,F,F,F,F,F,F,F],[385,592,F,F,F,F,F,F,F,F],[388,1219,F,F,F,F,F,F,F,F],[389,405,F,F,F,F,F,F,F,F],[392,393,1229,F,F,F,F,F,F,F],[392,394,F,F,F,F,F,F,F,F],[392,509,947,F,F,F,F,F,F,F],[392,678,F,F,F,F,F,F,F,F],[395,397,F,F,F,F,F,F,F,F],[399,535,958,F,F,F,F,F,F,F],[399,586,F,F,F,F,F,F,F,F],[399,1237,F,F,F,F,F,F,F,F],[399,1280,F,F,F,F,F,F,F,F],[400,520,F,F,F,F,F,F,F,F],[401,471,F,F,F,F,F,F,F,F],[401,517,F,F,F,F,F,F,F,F],[401,525,F,F,F,F,F,F,F,F],[401,587,F,F,F,F,F,F,F,F],[401,972,F,F,F,F,F,F,F,F],[404,407,F,F,F,F,F,F,F,F],[404,1248,F,F,F,F,F,F,F,F],[408,410,411,F,F,F,F,F,F,F],[409,1261,F,F,F,F,F,F,F,F],[410,1146,F,F,F,F,F,F,F,F],[412,432,F,F,F,F,F,F,F,F],[412,554,F,F,F,F,F,F,F,F],[413,461,F,F,F,F,F,F,F,F],[414,628,F,F,F,F,F,F,F,F],[418,420,F,F,F,F,F,F,F,F],[419,420,F,F,F,F,F,F,F,F],[419,545,F,F,F,F,F,F,F,F],[423,424,437,1289,1291,F,F,F,F,F],[423,437,F,F,F,F,F,F,F,F],[423,561,F,F,F,F,F,F,F,F],[424,1273,F,F,F,F,F,F,F,F],[426,428,F,F,F,F,F,F,F,F],[426,430,F,F,F,F,F,F,F,F],[427,428,F,F,F,F,F,F,F,F],[429,431,F,F,F,F,F,F,F,F],[430,431,F,F,F,F,F,F,F,F],[430,431,450,478,480,481,F,F,F,F],[430,450,F,F,F,F,F,F,F,F],[431,450,F,F,F,F,F,F,F,F],[431,1264,F,F,F,F,F,F,F,F],[432,753,F,F,F,F,F,F,F,F],[436,438,F,F,F,F,F,F,F,F],[436,440,F,F,F,F,F,F,F,F],[437,438,F,F,F,F,F,F,F,F],[441,443,444,F,F,F,F,F,F,F],[442,444,F,F,F,F,F,F,F,F],[442,497,F,F,F,F,F,F,F,F],[446,448,F,F,F,F,F,F,F,F],[449,450,452,F,F,F,F,F,F,F],[449,1260,F,F,F,F,F,F,F,F],[451,452,F,F,F,F,F,F,F,F],[451,1264,F,F,F,F,F,F,F,F],[451,1303,F,F,F,F,F,F,F,F],[452,571,F,F,F,F,F,F,F,F],[452,1131,F,F,F,F,F,F,F,F],[452,1181,F,F,F,F,F,F,F,F],[454,455,F,F,F,F,F,F,F,F],[454,455,456,F,F,F,F,F,F,F],[454,456,F,F,F,F,F,F,F,F],[454,480,1301,F,F,F,F,F,F,F],[455,456,459,F,F,F,F,F,F,F],[455,465,F,F,F,F,F,F,F,F],[455,477,480,F,F,F,F,F,F,F],[457,458,F,F,F,F,F,F,F,F],[457,469,F,F,F,F,F,F,F,F],[458,885,F,F,F,F,F,F,F,F],[461,463,F,F,F,F,F,F,F,F],[461,1306,F,F,F,F,F,F,F,F],[461,1315,F,F,F,F,F,F,F,F],[461,1370,F,F,F,F,F,F,F,F],[462,463,F,F,F,F,F,F,F,F],[466,467,F,F,F,F,F,F,F,F],[468,470,F,F,F,F,F,F,F,F],[468,474,F,F,F,F,F,F,F,F],[471,577,F,F,F,F,F,F,F,F],[471,587,F,F,F,F,F,F,F,F],[471,1237,F,F,F,F,F,F,F,F],[471,1238,F,F,F,F,F,F,F,F],[471,1374,F,F,F,F,F,F,F,F],[473,741,F,F,F,F,F,F,F,F],[473,1314,F,F,F,F,F,F,F,F],[474,476,F,F,F,F,F,F,F,F],[477,480,847,F,F,F,F,F,F,F],[482,483,F,F,F,F,F,F,F,F],[482,484,F,F,F,F,F,F,F,F],[482,485,F,F,F,F,F,F,F,F],[482,920,F,F,F,F,F,F,F,F],[482,921,F,F,F,F,F,F,F,F],[482,922,F,F,F,F,F,F,F,F],[483,484,F,F,F,F,F,F,F,F],[483,505,F,F,F,F,F,F,F,F],[483,507,F,F,F,F,F,F,F,F],[483,923,F,F,F,F,F,F,F,F],[483,1257,F,F,F,F,F,F,F,F],[484,485,F,F,F,F,F,F,F,F],[485,505,F,F,F,F,F,F,F,F],[485,921,F,F,F,F,F,F,F,F],[485,921,1415,F,F,F,F,F,F,F],[485,1260,F,F,F,F,F,F,F,F],[485,1418,F,F,F,F,F,F,F,F],[486,490,508,F,F,F,F,F,F,F],[486,943,F,F,F,F,F,F,F,F],[486,1280,F,F,F,F,F,F,F,F],[487,505,925,F,F,F,F,F,F,F],[488,489,F,F,F,F,F,F,F,F],[488,1262,F,F,F,F,F,F,F,F],[489,507,508,F,F,F,F,F,F,F],[489,508,F,F,F,F,F,F,F,F],[491,493,F,F,F,F,F,F,F,F],[491,493,1266,F,F,F,F,F,F,F],[491,930,F,F,F,F,F,F,F,F],[491,931,F,F,F,F,F,F,F,F],[492,493,F,F,F,F,F,F,F,F],[492,773,F,F,F,F,F,F,F,F],[493,544,F,F,F,F,F,F,F,F],[493,1268,F,F,F,F,F,F,F,F],[495,497,F,F,F,F,F,F,F,F],[495,497,934,F,F,F,F,F,F,F],[497,935,936,F,F,F,F,F,F,F],[497,1271,F,F,F,F,F,F,F,F],[499,502,F,F,F,F,F,F,F,F],[499,939,F,F,F,F,F,F,F,F],[500,501,F,F,F,F,F,F,F,F],[500,502,532,F,F,F,F,F,F,F],[501,502,F,F,F,F,F,F,F,F],[501,532,F,F,F,F,F,F,F,F],[501,958,F,F,F,F,F,F,F,F],[501,1332,F,F,F,F,F,F,F,F],[501,1480,F,F,F,F,F,F,F,F],[502,532,F,F,F,F,F,F,F,F],[502,939,F,F,F,F,F,F,F,F],[502,1277,F,F,F,F,F,F,F,F],[504,505,F,F,F,F,F,F,F,F],[504,531,F,F,F,F,F,F,F,F],[504,541,F,F,F,F,F,F,F,F],[505,524,F,F,F,F,F,F,F,F],[505,1280,F,F,F,F,F,F,F,F],[506,507,F,F,F,F,F,F,F,F],[506,507,1282,F,F,F,F,F,F,F],[509,511,F,F,F,F,F,F,F,F],[510,511,F,F,F,F,F,F,F,F],[510,947,F,F,F,F,F,F,F,F],[511,514,F,F,F,F,F,F,F,F],[511,1345,F,F,F,F,F,F,F,F],[512,514,F,F,F,F,F,F,F,F],[514,637,F,F,F,F,F,F,F,F],[516,553,555,F,F,F,F,F,F,F],[523,524,F,F,F,F,F,F,F,F],[523,526,F,F,F,F,F,F,F,F],[523,944,F,F,F,F,F,F,F,F],[523,945,F,F,F,F,F,F,F,F],[523,962,F,F,F,F,F,F,F,F],[523,1495,F,F,F,F,F,F,F,F],[525,526,F,F,F,F,F,F,F,F],[525,1503,F,F,F,F,F,F,F,F],[526,963,F,F,F,F,F,F,F,F],[526,964,F,F,F,F,F,F,F,F],[528,530,967,F,F,F,F,F,F,F],[528,1502,F,F,F,F,F,F,F,F],[529,530,F,F,F,F,F,F,F,F],[530,537,F,F,F,F,F,F,F,F],[531,938,F,F,F,F,F,F,F,F],[532,533,F,F,F,F,F,F,F,F],[532,534,F,F,F,F,F,F,F,F],[532,1311,F,F,F,F,F,F,F,F],[535,537,F,F,F,F,F,F,F,F],[535,976,F,F,F,F,F,F,F,F],[535,1377,F,F,F,F,F,F,F,F],[537,962,F,F,F,F,F,F,F,F],[537,1128,1136,F,F,F,F,F,F,F],[537,1316,F,F,F,F,F,F,F,F],[538,539,F,F,F,F,F,F,F,F],[539,927,F,F,F,F,F,F,F,F],[541,922,F,F,F,F,F,F,F,F],[543,544,F,F,F,F,F,F,F,F],[543,545,F,F,F,F,F,F,F,F],[545,932,F,F,F,F,F,F,F,F],[546,549,F,F,F,F,F,F,F,F],[553,554,F,F,F,F,F,F,F,F],[553,555,F,F,F,F,F,F,F,F],[553,556,F,F,F,F,F,F,F,F],[554,941,F,F,F,F,F,F,F,F],[555,556,F,F,F,F,F,F,F,F],[556,557,F,F,F,F,F,F,F,F],[556,925,F,F,F,F,F,F,F,F],[559,1345,F,F,F,F,F,F,F,F],[563,564,F,F,F,F,F,F,F,F],[563,565,F,F,F,F,F,F,F,F],[580,583,F,F,F,F,F,F,F,F],[584,628,F,F,F,F,F,F,F,F],[585,586,F,F,F,F,F,F,F,F],[585,1237,F,F,F,F,F,F,F,F],[592,1080,F,F,F,F,F,F,F,F],[593,594,595,F,F,F,F,F,F,F],[593,594,595,596,F,F,F,F,F,F],[593,597,F,F,F,F,F,F,F,F],[594,596,F,F,F,F,F,F,F,F],[596,612,681,684,F,F,F,F,F,F],[596,1257,F,F,F,F,F,F,F,F],[596,1413,F,F,F,F,F,F,F,F],[597,601,F,F,F,F,F,F,F,F],[598,600,F,F,F,F,F,F,F,F],[605,607,F,F,F,F,F,F,F,F],[607,635,F,F,F,F,F,F,F,F],[610,612,F,F,F,F,F,F,F,F],[611,640,F,F,F,F,F,F,F,F],[611,640,641,F,F,F,F,F,F,F],[611,760,F,F,F,F,F,F,F,F],[612,684,F,F,F,F,F,F,F,F],[614,620,F,F,F,F,F,F,F,F],[615,617,F,F,F,F,F,F,F,F],[615,1375,F,F,F,F,F,F,F,F],[616,641,642,F,F,F,F,F,F,F],[617,618,620,F,F,F,F,F,F,F],[618,619,F,F,F,F,F,F,F,F],[618,619,620,F,F,F,F,F,F,F],[622,623,651,F,F,F,F,F,F,F],[623,651,F,F,F,F,F,F,F,F],[626,628,630,1349,F,F,F,F,F,F],[627,628,F,F,F,F,F,F,F,F],[627,629,F,F,F,F,F,F,F,F],[627,710,F,F,F,F,F,F,F,F],[627,1257,1259,F,F,F,F,F,F,F],[627,1259,F,F,F,F,F,F,F,F],[628,630,F,F,F,F,F,F,F,F],[628,645,F,F,F,F,F,F,F,F],[629,634,F,F,F,F,F,F,F,F],[629,684,F,F,F,F,F,F,F,F],[629,1375,F,F,F,F,F,F,F,F],[631,636,F,F,F,F,F,F,F,F],[632,929,F,F,F,F,F,F,F,F],[635,637,F,F,F,F,F,F,F,F],[636,1349,F,F,F,F,F,F,F,F],[639,640,642,F,F,F,F,F,F,F],[640,641,F,F,F,F,F,F,F,F],[641,642,F,F,F,F,F,F,F,F],[641,672,F,F,F,F,F,F,F,F],[643,644,646,F,F,F,F,F,F,F],[644,645,F,F,F,F,F,F,F,F],[648,650,F,F,F,F,F,F,F,F],[651,652,653,F,F,F,F,F,F,F],[651,1288,1370,F,F,F,F,F,F,F],[655,658,F,F,F,F,F,F,F,F],[656,658,F,F,F,F,F,F,F,F],[658,672,F,F,F,F,F,F,F,F],[660,661,F,F,F,F,F,F,F,F],[661,662,F,F,F,F,F,F,F,F],[661,728,F,F,F,F,F,F,F,F],[661,1099,F,F,F,F,F,F,F,F],[663,827,F,F,F,F,F,F,F,F],[664,666,F,F,F,F,F,F,F,F],[665,668,F,F,F,F,F,F,F,F],[665,686,F,F,F,F,F,F,F,F],[665,687,F,F,F,F,F,F,F,F],[667,672,F,F,F,F,F,F,F,F],[670,671,F,F,F,F,F,F,F,F],[672,688,F,F,F,F,F,F,F,F],[677,678,F,F,F,F,F,F,F,F],[678,680,F,F,F,F,F,F,F,F],[681,682,F,F,F,F,F,F,F,F],[681,682,685,F,F,F,F,F,F,F],[681,687,F,F,F,F,F,F,F,F],[684,907,F,F,F,F,F,F,F,F],[684,1142,F,F,F,F,F,F,F,F],[688,1096,F,F,F,F,F,F,F,F],[689,690,F,F,F,F,F,F,F,F],[691,692,F,F,F,F,F,F,F,F],[691,1254,F,F,F,F,F,F,F,F],[693,694,F,F,F,F,F,F,F,F],[693,695,696,F,F,F,F,F,F,F],[693,751,F,F,F,F,F,F,F,F],[693,752,753,F,F,F,F,F,F,F],[693,1483,F,F,F,F,F,F,F,F],[694,695,697,F,F,F,F,F,F,F],[695,696,F,F,F,F,F,F,F,F],[696,700,F,F,F,F,F,F,F,F],[697,714,1331,F,F,F,F,F,F,F],[698,702,F,F,F,F,F,F,F,F],[700,753,F,F,F,F,F,F,F,F],[701,704,F,F,F,F,F,F,F,F],[702,704,F,F,F,F,F,F,F,F],[702,704,1230,F,F,F,F,F,F,F],[702,862,F,F,F,F,F,F,F,F],[706,723,F,F,F,F,F,F,F,F],[706,866,F,F,F,F,F,F,F,F],[706,1232,F,F,F,F,F,F,F,F],[709,710,F,F,F,F,F,F,F,F],[712,753,F,F,F,F,F,F,F,F],[714,751,F,F,F,F,F,F,F,F],[714,753,F,F,F,F,F,F,F,F],[715,728,F,F,F,F,F,F,F,F],[715,753,F,F,F,F,F,F,F,F],[717,728,F,F,F,F,F,F,F,F],[718,720,F,F,F,F,F,F,F,F],[719,721,F,F,F,F,F,F,F,F],[719,1237,F,F,F,F,F,F,F,F],[720,922,F,F,F,F,F,F,F,F],[722,723,F,F,F,F,F,F,F,F],[722,723,724,F,F,F,F,F,F,F],[723,744,F,F,F,F,F,F,F,F],[724,732,742,F,F,F,F,F,F,F],[727,728,729,F,F,F,F,F,F,F],[727,729,F,F,F,F,F,F,F,F],[728,729,F,F,F,F,F,F,F,F],[728,800,F,F,F,F,F,F,F,F],[728,1341,F,F,F,F,F,F,F,F],[730,744,F,F,F,F,F,F,F,F],[732,733,F,F,F,F,F,F,F,F],[732,742,F,F,F,F,F,F,F,F],[732,1074,F,F,F,F,F,F,F,F],[732,1174,F,F,F,F,F,F,F,F],[732,1229,F,F,F,F,F,F,F,F],[737,749,756,F,F,F,F,F,F,F],[738,814,F,F,F,F,F,F,F,F],[750,751,F,F,F,F,F,F,F,F],[751,753,F,F,F,F,F,F,F,F],[751,759,F,F,F,F,F,F,F,F],[751,1253,F,F,F,F,F,F,F,F],[753,761,F,F,F,F,F,F,F,F],[753,1230,F,F,F,F,F,F,F,F],[756,761,F,F,F,F,F,F,F,F],[760,1349,F,F,F,F,F,F,F,F],[762,763,764,795,796,797,F,F,F,F],[762,795,1253,F,F,F,F,F,F,F],[763,796,F,F,F,F,F,F,F,F],[764,797,F,F,F,F,F,F,F,F],[765,767,768,771,786,789,790,F,F,F],[765,774,F,F,F,F,F,F,F,F],[767,769,771,785,789,F,F,F,F,F],[768,769,786,787,F,F,F,F,F,F],[768,838,F,F,F,F,F,F,F,F],[771,800,F,F,F,F,F,F,F,F],[774,775,F,F,F,F,F,F,F,F],[774,776,F,F,F,F,F,F,F,F],[777,778,F,F,F,F,F,F,F,F],[778,779,F,F,F,F,F,F,F,F],[782,832,F,F,F,F,F,F,F,F],[787,799,F,F,F,F,F,F,F,F],[791,815,F,F,F,F,F,F,F,F],[799,801,F,F,F,F,F,F,F,F],[801,810,F,F,F,F,F,F,F,F],[803,1419,F,F,F,F,F,F,F,F],[804,1421,F,F,F,F,F,F,F,F],[806,808,F,F,F,F,F,F,F,F],[810,1128,F,F,F,F,F,F,F,F],[815,990,F,F,F,F,F,F,F,F],[818,819,820,F,F,F,F,F,F,F],[818,820,F,F,F,F,F,F,F,F],[827,1000,F,F,F,F,F,F,F,F],[835,1306,F,F,F,F,F,F,F,F],[838,839,F,F,F,F,F,F,F,F],[840,988,F,F,F,F,F,F,F,F],[844,1334,F,F,F,F,F,F,F,F],[845,848,1248,F,F,F,F,F,F,F],[849,851,F,F,F,F,F,F,F,F],[849,851,852,F,F,F,F,F,F,F],[849,852,F,F,F,F,F,F,F,F],[849,962,F,F,F,F,F,F,F,F],[849,1253,F,F,F,F,F,F,F,F],[853,860,F,F,F,F,F,F,F,F],[858,859,F,F,F,F,F,F,F,F],[859,910,F,F,F,F,F,F,F,F],[862,868,F,F,F,F,F,F,F,F],[864,900,F,F,F,F,F,F,F,F],[869,872,F,F,F,F,F,F,F,F],[870,871,F,F,F,F,F,F,F,F],[877,880,F,F,F,F,F,F,F,F],[881,882,F,F,F,F,F,F,F,F],[882,905,F,F,F,F,F,F,F,F],[885,886,F,F,F,F,F,F,F,F],[885,887,F,F,F,F,F,F,F,F],[885,888,F,F,F,F,F,F,F,F],[886,888,F,F,F,F,F,F,F,F],[887,1319,F,F,F,F,F,F,F,F],[889,892,F,F,F,F,F,F,F,F],[890,891,F,F,F,F,F,F,F,F],[893,895,F,F,F,F,F,F,F,F],[893,895,896,F,F,F,F,F,F,F],[893,896,F,F,F,F,F,F,F,F],[894,907,910,917,F,F,F,F,F,F],[897,898,F,F,F,F,F,F,F,F],[901,905,F,F,F,F,F,F,F,F],[907,909,F,F,F,F,F,F,F,F],[909,912,F,F,F,F,F,F,F,F],[913,914,F,F,F,F,F,F,F,F],[915,916,F,F,F,F,F,F,F,F],[915,918,F,F,F,F,F,F,F,F],[920,921,F,F,F,F,F,F,F,F],[920,923,F,F,F,F,F,F,F,F],[920,1257,F,F,F,F,F,F,F,F],[920,1340,F,F,F,F,F,F,F,F],[921,923,F,F,F,F,F,F,F,F],[921,1080,F,F,F,F,F,F,F,F],[921,1112,F,F,F,F,F,F,F,F],[921,1345,F,F,F,F,F,F,F,F],[921,1447,F,F,F,F,F,F,F,F],[922,952,953,F,F,F,F,F,F,F],[923,945,F,F,F,F,F,F,F,F],[923,1039,F,F,F,F,F,F,F,F],[923,1282,F,F,F,F,F,F,F,F],[929,1265,F,F,F,F,F,F,F,F],[929,1323,F,F,F,F,F,F,F,F],[930,1315,F,F,F,F,F,F,F,F],[932,945,F,F,F,F,F,F,F,F],[932,1257,F,F,F,F,F,F,F,F],[932,1265,F,F,F,F,F,F,F,F],[932,1268,F,F,F,F,F,F,F,F],[934,936,F,F,F,F,F,F,F,F],[934,937,F,F,F,F,F,F,F,F],[938,940,F,F,F,F,F,F,F,F],[938,940,974,F,F,F,F,F,F,F],[938,1035,F,F,F,F,F,F,F,F],[939,941,F,F,F,F,F,F,F,F],[941,1060,F,F,F,F,F,F,F,F],[942,945,F,F,F,F,F,F,F,F],[946,953,F,F,F,F,F,F,F,F],[947,1150,F,F,F,F,F,F,F,F],[947,1438,F,F,F,F,F,F,F,F],[950,1442,F,F,F,F,F,F,F,F],[952,953,F,F,F,F,F,F,F,F],[955,1293,F,F,F,F,F,F,F,F],[959,1360,F,F,F,F,F,F,F,F],[962,964,F,F,F,F,F,F,F,F],[962,965,F,F,F,F,F,F,F,F],[966,1301,F,F,F,F,F,F,F,F],[970,1309,F,F,F,F,F,F,F,F],[972,974,F,F,F,F,F,F,F,F],[981,983,F,F,F,F,F,F,F,F],[982,984,F,F,F,F,F,F,F,F],[983,999,F,F,F,F,F,F,F,F],[985,986,F,F,F,F,F,F,F,F],[986,987,F,F,F,F,F,F,F,F],[988,1020,F,F,F,F,F,F,F,F],[990,992,F,F,F,F,F,F,F,F],[992,1072,F,F,F,F,F,F,F,F],[997,998,F,F,F,F,F,F,F,F],[1009,1019,F,F,F,F,F,F,F,F],[1021,1022,F,F,F,F,F,F,F,F],[1021,1044,1134,F,F,F,F,F,F,F],[1022,1139,F,F,F,F,F,F,F,F],[1022,1259,F,F,F,F,F,F,F,F],[1023,1044,F,F,F,F,F,F,F,F],[1025,1027,1039,F,F,F,F,F,F,F],[1025,1110,F,F,F,F,F,F,F,F],[1029,1030,F,F,F,F,F,F,F,F],[1029,1069,F,F,F,F,F,F,F,F],[1029,1268,F,F,F,F,F,F,F,F],[1032,1034,F,F,F,F,F,F,F,F],[1035,1037,F,F,F,F,F,F,F,F],[1036,1037,F,F,F,F,F,F,F,F],[1037,1060,F,F,F,F,F,F,F,F],[1039,1050,F,F,F,F,F,F,F,F],[1044,1047,F,F,F,F,F,F,F,F],[1044,1052,F,F,F,F,F,F,F,F],[1044,1134,F,F,F,F,F,F,F,F],[1045,1047,F,F,F,F,F,F,F,F],[1047,1264,F,F,F,F,F,F,F,F],[1049,1077,F,F,F,F,F,F,F,F],[1052,1055,F,F,F,F,F,F,F,F],[1052,1325,F,F,F,F,F,F,F,F],[1052,1413,F,F,F,F,F,F,F,F],[1055,1324,F,F,F,F,F,F,F,F],[1055,1325,F,F,F,F,F,F,F,F],[1055,1419,F,F,F,F,F,F,F,F],[1056,1153,F,F,F,F,F,F,F,F],[1057,1058,F,F,F,F,F,F,F,F],[1060,1063,F,F,F,F,F,F,F,F],[1060,1274,F,F,F,F,F,F,F,F],[1061,1177,F,F,F,F,F,F,F,F],[1062,1063,F,F,F,F,F,F,F,F],[1063,1430,F,F,F,F,F,F,F,F],[1064,1365,F,F,F,F,F,F,F,F],[1065,1341,F,F,F,F,F,F,F,F],[1065,1432,1433,F,F,F,F,F,F,F],[1066,1067,F,F,F,F,F,F,F,F],[1067,1078,F,F,F,F,F,F,F,F],[1067,1257,F,F,F,F,F,F,F,F],[1068,1102,F,F,F,F,F,F,F,F],[1069,1283,F,F,F,F,F,F,F,F],[1070,1072,F,F,F,F,F,F,F,F],[1070,1078,F,F,F,F,F,F,F,F],[1072,1438,F,F,F,F,F,F,F,F],[1073,1076,F,F,F,F,F,F,F,F],[1075,1290,F,F,F,F,F,F,F,F],[1077,1301,F,F,F,F,F,F,F,F],[1078,1079,F,F,F,F,F,F,F,F],[1078,1080,F,F,F,F,F,F,F,F],[1078,1080,1448,F,F,F,F,F,F,F],[1078,1112,1343,F,F,F,F,F,F,F],[1080,1112,F,F,F,F,F,F,F,F],[1080,1432,F,F,F,F,F,F,F,F],[1080,1445,F,F,F,F,F,F,F,F],[1080,1448,F,F,F,F,F,F,F,F],[1081,1446,F,F,F,F,F,F,F,F],[1083,1088,F,F,F,F,F,F,F,F],[1089,1090,1109,F,F,F,F,F,F,F],[1089,1459,F,F,F,F,F,F,F,F],[1090,1365,F,F,F,F,F,F,F,F],[1090,1458,F,F,F,F,F,F,F,F],[1091,1093,F,F,F,F,F,F,F,F],[1104,1108,1375,F,F,F,F,F,F,F],[1112,1342,F,F,F,F,F,F,F,F],[1114,1450,F,F,F,F,F,F,F,F],[1119,1120,F,F,F,F,F,F,F,F],[1121,1124,F,F,F,F,F,F,F,F],[1124,1134,F,F,F,F,F,F,F,F],[1124,1299,1300,F,F,F,F,F,F,F],[1129,1131,F,F,F,F,F,F,F,F],[1134,1138,F,F,F,F,F,F,F,F],[1136,1137,F,F,F,F,F,F,F,F],[1139,1142,F,F,F,F,F,F,F,F],[1139,1216,F,F,F,F,F,F,F,F],[1142,1218,F,F,F,F,F,F,F,F],[1144,1145,F,F,F,F,F,F,F,F],[1150,1265,F,F,F,F,F,F,F,F],[1153,1154,F,F,F,F,F,F,F,F],[1153,1156,F,F,F,F,F,F,F,F],[1154,1289,F,F,F,F,F,F,F,F],[1157,1158,F,F,F,F,F,F,F,F],[1158,1332,F,F,F,F,F,F,F,F],[1161,1163,1207,F,F,F,F,F,F,F],[1161,1165,F,F,F,F,F,F,F,F],[1167,1169,F,F,F,F,F,F,F,F],[1167,1448,F,F,F,F,F,F,F,F],[1168,1170,F,F,F,F,F,F,F,F],[1168,1341,F,F,F,F,F,F,F,F],[1170,1257,F,F,F,F,F,F,F,F],[1175,1441,F,F,F,F,F,F,F,F],[1176,1178,F,F,F,F,F,F,F,F],[1181,1183,1184,F,F,F,F,F,F,F],[1185,1188,F,F,F,F,F,F,F,F],[1187,1188,F,F,F,F,F,F,F,F],[1189,1192,F,F,F,F,F,F,F,F],[1190,1192,F,F,F,F,F,F,F,F],[1190,1451,1452,F,F,F,F,F,F,F],[1190,1472,F,F,F,F,F,F,F,F],[1194,1197,F,F,F,F,F,F,F,F],[1199,1201,F,F,F,F,F,F,F,F],[1200,1201,F,F,F,F,F,F,F,F],[1210,1214,F,F,F,F,F,F,F,F],[1210,1215,F,F,F,F,F,F,F,F],[1212,1476,F,F,F,F,F,F,F,F],[1215,1367,F,F,F,F,F,F,F,F],[1219,1223,F,F,F,F,F,F,F,F],[1221,1222,F,F,F,F,F,F,F,F],[1225,1235,F,F,F,F,F,F,F,F],[1229,1367,F,F,F,F,F,F,F,F],[1230,1370,F,F,F,F,F,F,F,F],[1231,1360,F,F,F,F,F,F,F,F],[1232,1234,F,F,F,F,F,F,F,F],[1233,1234,F,F,F,F,F,F,F,F],[1235,1237,F,F,F,F,F,F,F,F],[1236,1237,F,F,F,F,F,F,F,F],[1236,1238,F,F,F,F,F,F,F,F],[1236,1301,F,F,F,F,F,F,F,F],[1237,1342,F,F,F,F,F,F,F,F],[1238,1341,F,F,F,F,F,F,F,F],[1238,1367,F,F,F,F,F,F,F,F],[1241,1243,F,F,F,F,F,F,F,F],[1245,1246,F,F,F,F,F,F,F,F],[1248,1461,F,F,F,F,F,F,F,F],[1250,1369,F,F,F,F,F,F,F,F],[1252,1255,F,F,F,F,F,F,F,F],[1252,1364,1365,F,F,F,F,F,F,F],[1253,1254,F,F,F,F,F,F,F,F],[1253,1366,F,F,F,F,F,F,F,F],[1254,1255,F,F,F,F,F,F,F,F],[1257,1259,F,F,F,F,F,F,F,F],[1259,1267,F,F,F,F,F,F,F,F],[1261,1264,F,F,F,F,F,F,F,F],[1265,1267,F,F,F,F,F,F,F,F],[1268,1307,F,F,F,F,F,F,F,F],[1268,1345,F,F,F,F,F,F,F,F],[1270,1273,F,F,F,F,F,F,F,F],[1271,1327,F,F,F,F,F,F,F,F],[1274,1275,F,F,F,F,F,F,F,F],[1274,1277,F,F,F,F,F,F,F,F],[1274,1310,F,F,F,F,F,F,F,F],[1277,1311,F,F,F,F,F,F,F,F],[1277,1332,F,F,F,F,F,F,F,F],[1280,1336,F,F,F,F,F,F,F,F],[1280,1338,F,F,F,F,F,F,F,F],[1281,1282,F,F,F,F,F,F,F,F],[1281,1313,F,F,F,F,F,F,F,F],[1283,1286,F,F,F,F,F,F,F,F],[1284,1315,F,F,F,F,F,F,F,F],[1288,1291,F,F,F,F,F,F,F,F],[1289,1350,F,F,F,F,F,F,F,F],[1301,1303,1304,F,F,F,F,F,F,F],[1301,1364,1367,F,F,F,F,F,F,F],[1304,1460,F,F,F,F,F,F,F,F],[1306,1308,F,F,F,F,F,F,F,F],[1307,1309,F,F,F,F,F,F,F,F],[1314,1316,F,F,F,F,F,F,F,F],[1314,1347,F,F,F,F,F,F,F,F],[1315,1316,F,F,F,F,F,F,F,F],[1317,1319,F,F,F,F,F,F,F,F],[1317,1320,F,F,F,F,F,F,F,F],[1317,1321,F,F,F,F,F,F,F,F],[1318,1336,F,F,F,F,F,F,F,F],[1322,1323,F,F,F,F,F,F,F,F],[1322,1325,F,F,F,F,F,F,F,F],[1322,1325,1338,F,F,F,F,F,F,F],[1322,1344,F,F,F,F,F,F,F,F],[1322,1344,1346,F,F,F,F,F,F,F],[1323,1325,F,F,F,F,F,F,F,F],[1323,1370,F,F,F,F,F,F,F,F],[1326,1329,F,F,F,F,F,F,F,F],[1327,1329,F,F,F,F,F,F,F,F],[1331,1334,F,F,F,F,F,F,F,F],[1331,1373,F,F,F,F,F,F,F,F],[1332,1360,1456,F,F,F,F,F,F,F],[1333,1334,F,F,F,F,F,F,F,F],[1334,1375,F,F,F,F,F,F,F,F],[1335,1336,F,F,F,F,F,F,F,F],[1335,1338,F,F,F,F,F,F,F,F],[1338,1463,1465,F,F,F,F,F,F,F],[1340,1341,1343,F,F,F,F,F,F,F],[1340,1342,F,F,F,F,F,F,F,F],[1340,1343,F,F,F,F,F,F,F,F],[1341,1342,F,F,F,F,F,F,F,F],[1341,1343,F,F,F,F,F,F,F,F],[1344,1347,F,F,F,F,F,F,F,F],[1346,1347,F,F,F,F,F,F,F,F],[1349,1352,F,F,F,F,F,F,F,F],[1353,1354,F,F,F,F,F,F,F,F],[1353,1367,F,F,F,F,F,F,F,F],[1355,1358,F,F,F,F,F,F,F,F],[1356,1365,F,F,F,F,F,F,F,F],[1360,1362,F,F,F,F,F,F,F,F],[1361,1362,F,F,F,F,F,F,F,F],[1361,1511,F,F,F,F,F,F,F,F],[1365,1366,F,F,F,F,F,F,F,F],[1365,1366,1367,F,F,F,F,F,F,F],[1366,1367,F,F,F,F,F,F,F,F],[1367,1379,F,F,F,F,F,F,F,F],[1367,1453,F,F,F,F,F,F,F,F],[1370,1372,F,F,F,F,F,F,F,F],[1370,1377,1379,F,F,F,F,F,F,F],[1376,1379,F,F,F,F,F,F,F,F],[1381,1389,F,F,F,F,F,F,F,F],[1381,1410,F,F,F,F,F,F,F,F],[1381,1410,1411,F,F,F,F,F,F,F],[1382,1388,1391,F,F,F,F,F,F,F],[1383,1399,1410,F,F,F,F,F,F,F],[1384,1483,F,F,F,F,F,F,F,F],[1385,1386,F,F,F,F,F,F,F,F],[1385,1483,1485,F,F,F,F,F,F,F],[1392,1394,F,F,F,F,F,F,F,F],[1394,1471,F,F,F,F,F,F,F,F],[1396,1512,F,F,F,F,F,F,F,F],[1399,1416,F,F,F,F,F,F,F,F],[1399,1510,F,F,F,F,F,F,F,F],[1407,1409,F,F,F,F,F,F,F,F],[1409,1488,F,F,F,F,F,F,F,F],[1410,1411,F,F,F,F,F,F,F,F],[1410,1413,F,F,F,F,F,F,F,F],[1411,1413,F,F,F,F,F,F,F,F],[1413,1512,F,F,F,F,F,F,F,F],[1415,1418,F,F,F,F,F,F,F,F],[1418,1448,F,F,F,F,F,F,F,F],[1419,1420,F,F,F,F,F,F,F,F],[1419,1422,F,F,F,F,F,F,F,F],[1424,1425,F,F,F,F,F,F,F,F],[1427,1428,1436,1480,F,F,F,F,F,F],[1427,1453,F,F,F,F,F,F,F,F],[1429,1462,F,F,F,F,F,F,F,F],[1432,1433,F,F,F,F,F,F,F,F],[1432,1435,F,F,F,F,F,F,F,F],[1435,1437,F,F,F,F,F,F,F,F],[1436,1460,1480,F,F,F,F,F,F,F],[1438,1444,F,F,F,F,F,F,F,F],[1442,1444,F,F,F,F,F,F,F,F],[1442,1447,F,F,F,F,F,F,F,F],[1445,1446,1447,F,F,F,F,F,F,F],[1445,1447,F,F,F,F,F,F,F,F],[1445,1448,F,F,F,F,F,F,F,F],[1445,1483,F,F,F,F,F,F,F,F],[1450,1452,F,F,F,F,F,F,F,F],[1451,1452,F,F,F,F,F,F,F,F],[1454,1456,F,F,F,F,F,F,F,F],[1456,1457,F,F,F,F,F,F,F,F],[1458,1460,F,F,F,F,F,F,F,F],[1464,1468,1469,F,F,F,F,F,F,F],[1469,1500,F,F,F,F,F,F,F,F],[1469,1501,F,F,F,F,F,F,F,F],[1470,1472,F,F,F,F,F,F,F,F],[1479,1480,F,F,F,F,F,F,F,F],[1480,1510,F,F,F,F,F,F,F,F],[1483,1485,F,F,F,F,F,F,F,F],[1483,1503,F,F,F,F,F,F,F,F],[1485,1487,F,F,F,F,F,F,F,F],[1499,1501,F,F,F,F,F,F,F,F],[1509,1510,1512,F,F,F,F,F,F,F]];
+pub(super) static PINYIN_COMBINATIONS: [PinyinCombination; 1168] = [
+[0,1,2,3,4,F,F,F,F,F],[0,1,3,4,429,553,F,F,F,F],[0,4,290,F,F,F,F,F,F,F],[1,1047,F,F,F,F,F,F,F,F],[5,6,F,F,F,F,F,F,F,F],[5,7,8,F,F,F,F,F,F,F],[5,7,289,292,293,296,298,299,300,301],[5,8,F,F,F,F,F,F,F,F],[5,1257,F,F,F,F,F,F,F,F],[8,600,F,F,F,F,F,F,F,F],[8,835,F,F,F,F,F,F,F,F],[8,1343,F,F,F,F,F,F,F,F],[12,417,F,F,F,F,F,F,F,F],[12,1324,F,F,F,F,F,F,F,F],[17,1219,F,F,F,F,F,F,F,F],[19,20,824,F,F,F,F,F,F,F],[20,1367,F,F,F,F,F,F,F,F],[21,22,F,F,F,F,F,F,F,F],[21,25,F,F,F,F,F,F,F,F],[21,854,F,F,F,F,F,F,F,F],[23,24,F,F,F,F,F,F,F,F],[23,854,F,F,F,F,F,F,F,F],[24,42,F,F,F,F,F,F,F,F],[24,77,F,F,F,F,F,F,F,F],[24,78,F,F,F,F,F,F,F,F],[24,854,F,F,F,F,F,F,F,F],[26,80,F,F,F,F,F,F,F,F],[28,78,80,F,F,F,F,F,F,F],[31,77,862,F,F,F,F,F,F,F],[31,861,F,F,F,F,F,F,F,F],[36,37,865,866,F,F,F,F,F,F],[36,37,882,F,F,F,F,F,F,F],[36,867,F,F,F,F,F,F,F,F],[37,44,F,F,F,F,F,F,F,F],[37,52,F,F,F,F,F,F,F,F],[37,866,F,F,F,F,F,F,F,F],[37,882,F,F,F,F,F,F,F,F],[38,342,F,F,F,F,F,F,F,F],[39,78,80,F,F,F,F,F,F,F],[40,84,918,F,F,F,F,F,F,F],[41,77,F,F,F,F,F,F,F,F],[41,918,F,F,F,F,F,F,F,F],[42,886,F,F,F,F,F,F,F,F],[42,886,907,F,F,F,F,F,F,F],[44,78,F,F,F,F,F,F,F,F],[44,876,F,F,F,F,F,F,F,F],[46,48,F,F,F,F,F,F,F,F],[46,57,F,F,F,F,F,F,F,F],[46,74,F,F,F,F,F,F,F,F],[48,331,F,F,F,F,F,F,F,F],[56,57,F,F,F,F,F,F,F,F],[56,887,F,F,F,F,F,F,F,F],[57,886,F,F,F,F,F,F,F,F],[58,61,F,F,F,F,F,F,F,F],[59,889,F,F,F,F,F,F,F,F],[60,890,F,F,F,F,F,F,F,F],[63,64,F,F,F,F,F,F,F,F],[63,65,F,F,F,F,F,F,F,F],[63,896,F,F,F,F,F,F,F,F],[63,1061,F,F,F,F,F,F,F,F],[66,68,F,F,F,F,F,F,F,F],[67,69,F,F,F,F,F,F,F,F],[70,74,F,F,F,F,F,F,F,F],[70,328,F,F,F,F,F,F,F,F],[75,76,F,F,F,F,F,F,F,F],[78,81,F,F,F,F,F,F,F,F],[79,80,F,F,F,F,F,F,F,F],[81,84,F,F,F,F,F,F,F,F],[82,84,F,F,F,F,F,F,F,F],[85,917,F,F,F,F,F,F,F,F],[85,1002,F,F,F,F,F,F,F,F],[86,111,F,F,F,F,F,F,F,F],[89,1110,F,F,F,F,F,F,F,F],[91,92,F,F,F,F,F,F,F,F],[93,106,1069,F,F,F,F,F,F,F],[95,106,1052,F,F,F,F,F,F,F],[95,1387,F,F,F,F,F,F,F,F],[96,119,1054,F,F,F,F,F,F,F],[96,120,F,F,F,F,F,F,F,F],[97,140,F,F,F,F,F,F,F,F],[98,1394,F,F,F,F,F,F,F,F],[104,486,F,F,F,F,F,F,F,F],[104,486,487,F,F,F,F,F,F,F],[104,1113,F,F,F,F,F,F,F,F],[104,1400,1415,F,F,F,F,F,F,F],[107,137,947,F,F,F,F,F,F,F],[108,141,F,F,F,F,F,F,F,F],[109,1407,F,F,F,F,F,F,F,F],[111,112,113,114,F,F,F,F,F,F],[111,114,F,F,F,F,F,F,F,F],[111,1414,F,F,F,F,F,F,F,F],[112,1410,F,F,F,F,F,F,F,F],[113,114,F,F,F,F,F,F,F,F],[118,214,F,F,F,F,F,F,F,F],[120,1055,F,F,F,F,F,F,F,F],[120,1150,1284,F,F,F,F,F,F,F],[121,122,F,F,F,F,F,F,F,F],[121,1149,F,F,F,F,F,F,F,F],[122,1422,F,F,F,F,F,F,F,F],[124,125,F,F,F,F,F,F,F,F],[124,1059,F,F,F,F,F,F,F,F],[125,1155,F,F,F,F,F,F,F,F],[126,1327,F,F,F,F,F,F,F,F],[128,501,F,F,F,F,F,F,F,F],[128,1274,F,F,F,F,F,F,F,F],[128,1479,F,F,F,F,F,F,F,F],[129,1427,F,F,F,F,F,F,F,F],[132,523,F,F,F,F,F,F,F,F],[136,648,F,F,F,F,F,F,F,F],[137,1071,F,F,F,F,F,F,F,F],[137,1441,F,F,F,F,F,F,F,F],[141,144,F,F,F,F,F,F,F,F],[141,1438,F,F,F,F,F,F,F,F],[142,143,F,F,F,F,F,F,F,F],[142,244,F,F,F,F,F,F,F,F],[142,1076,F,F,F,F,F,F,F,F],[146,247,F,F,F,F,F,F,F,F],[147,1349,F,F,F,F,F,F,F,F],[148,1448,F,F,F,F,F,F,F,F],[150,153,F,F,F,F,F,F,F,F],[152,1155,F,F,F,F,F,F,F,F],[154,155,F,F,F,F,F,F,F,F],[154,1453,F,F,F,F,F,F,F,F],[155,235,F,F,F,F,F,F,F,F],[155,959,F,F,F,F,F,F,F,F],[156,823,F,F,F,F,F,F,F,F],[156,941,F,F,F,F,F,F,F,F],[157,1300,F,F,F,F,F,F,F,F],[159,525,F,F,F,F,F,F,F,F],[160,161,F,F,F,F,F,F,F,F],[160,161,1458,F,F,F,F,F,F,F],[160,1459,F,F,F,F,F,F,F,F],[161,1170,F,F,F,F,F,F,F,F],[161,1304,F,F,F,F,F,F,F,F],[161,1460,F,F,F,F,F,F,F,F],[166,168,169,F,F,F,F,F,F,F],[169,186,F,F,F,F,F,F,F,F],[169,1509,F,F,F,F,F,F,F,F],[171,179,F,F,F,F,F,F,F,F],[171,1469,F,F,F,F,F,F,F,F],[172,183,1466,1467,F,F,F,F,F,F],[175,1472,F,F,F,F,F,F,F,F],[179,1473,F,F,F,F,F,F,F,F],[183,280,F,F,F,F,F,F,F,F],[186,1020,F,F,F,F,F,F,F,F],[187,189,F,F,F,F,F,F,F,F],[187,1483,F,F,F,F,F,F,F,F],[188,483,F,F,F,F,F,F,F,F],[188,1485,F,F,F,F,F,F,F,F],[190,1112,F,F,F,F,F,F,F,F],[191,1488,F,F,F,F,F,F,F,F],[192,1490,F,F,F,F,F,F,F,F],[201,522,F,F,F,F,F,F,F,F],[201,1512,F,F,F,F,F,F,F,F],[212,1349,F,F,F,F,F,F,F,F],[213,1511,F,F,F,F,F,F,F,F],[214,1390,F,F,F,F,F,F,F,F],[216,1480,F,F,F,F,F,F,F,F],[217,218,F,F,F,F,F,F,F,F],[217,1142,F,F,F,F,F,F,F,F],[218,219,F,F,F,F,F,F,F,F],[218,221,F,F,F,F,F,F,F,F],[218,1141,F,F,F,F,F,F,F,F],[218,1142,F,F,F,F,F,F,F,F],[220,224,F,F,F,F,F,F,F,F],[222,1146,F,F,F,F,F,F,F,F],[223,224,F,F,F,F,F,F,F,F],[224,1145,F,F,F,F,F,F,F,F],[226,227,228,F,F,F,F,F,F,F],[226,228,F,F,F,F,F,F,F,F],[227,228,F,F,F,F,F,F,F,F],[227,1055,F,F,F,F,F,F,F,F],[228,265,F,F,F,F,F,F,F,F],[228,1150,F,F,F,F,F,F,F,F],[229,231,F,F,F,F,F,F,F,F],[230,231,F,F,F,F,F,F,F,F],[232,233,1157,F,F,F,F,F,F,F],[235,1157,F,F,F,F,F,F,F,F],[236,239,F,F,F,F,F,F,F,F],[237,238,240,F,F,F,F,F,F,F],[238,245,246,248,F,F,F,F,F,F],[238,248,F,F,F,F,F,F,F,F],[238,1162,F,F,F,F,F,F,F,F],[242,244,F,F,F,F,F,F,F,F],[245,246,F,F,F,F,F,F,F,F],[245,247,F,F,F,F,F,F,F,F],[245,1168,F,F,F,F,F,F,F,F],[246,1416,F,F,F,F,F,F,F,F],[246,1446,F,F,F,F,F,F,F,F],[247,1447,F,F,F,F,F,F,F,F],[248,287,F,F,F,F,F,F,F,F],[250,252,F,F,F,F,F,F,F,F],[251,256,F,F,F,F,F,F,F,F],[252,1173,F,F,F,F,F,F,F,F],[252,1323,F,F,F,F,F,F,F,F],[255,1177,F,F,F,F,F,F,F,F],[255,1332,F,F,F,F,F,F,F,F],[256,257,F,F,F,F,F,F,F,F],[257,1065,F,F,F,F,F,F,F,F],[257,1167,F,F,F,F,F,F,F,F],[257,1343,F,F,F,F,F,F,F,F],[257,1411,F,F,F,F,F,F,F,F],[261,262,F,F,F,F,F,F,F,F],[261,263,F,F,F,F,F,F,F,F],[261,1442,F,F,F,F,F,F,F,F],[263,1187,F,F,F,F,F,F,F,F],[267,656,F,F,F,F,F,F,F,F],[267,1189,F,F,F,F,F,F,F,F],[267,1190,F,F,F,F,F,F,F,F],[267,1190,1191,F,F,F,F,F,F,F],[268,271,F,F,F,F,F,F,F,F],[269,270,F,F,F,F,F,F,F,F],[271,1065,F,F,F,F,F,F,F,F],[272,1454,F,F,F,F,F,F,F,F],[274,285,F,F,F,F,F,F,F,F],[275,1466,F,F,F,F,F,F,F,F],[278,1502,F,F,F,F,F,F,F,F],[280,281,F,F,F,F,F,F,F,F],[283,1106,F,F,F,F,F,F,F,F],[283,1212,F,F,F,F,F,F,F,F],[283,1469,F,F,F,F,F,F,F,F],[285,287,F,F,F,F,F,F,F,F],[286,287,F,F,F,F,F,F,F,F],[287,468,F,F,F,F,F,F,F,F],[287,1216,F,F,F,F,F,F,F,F],[291,1342,F,F,F,F,F,F,F,F],[294,295,F,F,F,F,F,F,F,F],[295,297,F,F,F,F,F,F,F,F],[295,1322,F,F,F,F,F,F,F,F],[306,309,990,F,F,F,F,F,F,F],[306,799,F,F,F,F,F,F,F,F],[308,772,F,F,F,F,F,F,F,F],[310,313,F,F,F,F,F,F,F,F],[310,907,910,F,F,F,F,F,F,F],[311,312,F,F,F,F,F,F,F,F],[312,313,F,F,F,F,F,F,F,F],[315,316,F,F,F,F,F,F,F,F],[315,861,F,F,F,F,F,F,F,F],[316,908,F,F,F,F,F,F,F,F],[319,320,F,F,F,F,F,F,F,F],[321,866,F,F,F,F,F,F,F,F],[324,326,F,F,F,F,F,F,F,F],[327,342,F,F,F,F,F,F,F,F],[328,329,331,F,F,F,F,F,F,F],[328,344,F,F,F,F,F,F,F,F],[332,334,F,F,F,F,F,F,F,F],[332,335,F,F,F,F,F,F,F,F],[333,905,F,F,F,F,F,F,F,F],[334,335,F,F,F,F,F,F,F,F],[340,887,F,F,F,F,F,F,F,F],[341,342,F,F,F,F,F,F,F,F],[342,344,F,F,F,F,F,F,F,F],[342,895,F,F,F,F,F,F,F,F],[343,691,1254,F,F,F,F,F,F,F],[343,732,F,F,F,F,F,F,F,F],[346,347,348,F,F,F,F,F,F,F],[346,1261,F,F,F,F,F,F,F,F],[352,364,F,F,F,F,F,F,F,F],[352,921,F,F,F,F,F,F,F,F],[353,354,F,F,F,F,F,F,F,F],[353,930,F,F,F,F,F,F,F,F],[354,420,F,F,F,F,F,F,F,F],[356,357,F,F,F,F,F,F,F,F],[356,358,F,F,F,F,F,F,F,F],[358,1472,F,F,F,F,F,F,F,F],[359,361,F,F,F,F,F,F,F,F],[360,428,F,F,F,F,F,F,F,F],[362,363,F,F,F,F,F,F,F,F],[362,430,F,F,F,F,F,F,F,F],[362,539,659,684,F,F,F,F,F,F],[362,1343,F,F,F,F,F,F,F,F],[363,364,F,F,F,F,F,F,F,F],[363,430,F,F,F,F,F,F,F,F],[363,483,F,F,F,F,F,F,F,F],[363,629,F,F,F,F,F,F,F,F],[364,365,F,F,F,F,F,F,F,F],[365,684,F,F,F,F,F,F,F,F],[366,484,F,F,F,F,F,F,F,F],[371,373,F,F,F,F,F,F,F,F],[374,376,F,F,F,F,F,F,F,F],[374,662,F,F,F,F,F,F,F,F],[376,443,F,F,F,F,F,F,F,F],[378,380,F,F,F,F,F,F,F,F],[381,383,F,F,F,F,F,F,F,F],[381,384,F,F,F,F,F,F,F,F],[381,385,387,F,F,F,F,F,F,F],[383,450,F,F,F,F,F,F,F,F],[383,471,F,F,F,F,F,F,F,F],[383,488,F,F,F,F,F,F,F,F],[383,1367,F,F,F,F,F,F,F,F],[385,404,F,F,F,F,F,F,F,F],[385,592,F,F,F,F,F,F,F,F],[388,1219,F,F,F,F,F,F,F,F],[389,405,F,F,F,F,F,F,F,F],[392,393,1229,F,F,F,F,F,F,F],[392,394,F,F,F,F,F,F,F,F],[395,397,F,F,F,F,F,F,F,F],[399,535,958,F,F,F,F,F,F,F],[399,586,F,F,F,F,F,F,F,F],[399,1237,F,F,F,F,F,F,F,F],[399,1280,F,F,F,F,F,F,F,F],[400,520,F,F,F,F,F,F,F,F],[401,471,F,F,F,F,F,F,F,F],[401,525,F,F,F,F,F,F,F,F],[401,972,F,F,F,F,F,F,F,F],[404,407,F,F,F,F,F,F,F,F],[408,410,411,F,F,F,F,F,F,F],[410,1146,F,F,F,F,F,F,F,F],[412,432,F,F,F,F,F,F,F,F],[412,554,F,F,F,F,F,F,F,F],[413,461,F,F,F,F,F,F,F,F],[419,420,F,F,F,F,F,F,F,F],[419,545,F,F,F,F,F,F,F,F],[423,437,F,F,F,F,F,F,F,F],[426,430,F,F,F,F,F,F,F,F],[427,428,F,F,F,F,F,F,F,F],[429,431,F,F,F,F,F,F,F,F],[430,431,F,F,F,F,F,F,F,F],[430,431,450,478,480,481,F,F,F,F],[430,450,F,F,F,F,F,F,F,F],[432,753,F,F,F,F,F,F,F,F],[436,438,F,F,F,F,F,F,F,F],[436,440,F,F,F,F,F,F,F,F],[437,438,F,F,F,F,F,F,F,F],[441,443,444,F,F,F,F,F,F,F],[442,444,F,F,F,F,F,F,F,F],[442,497,F,F,F,F,F,F,F,F],[446,448,F,F,F,F,F,F,F,F],[449,1260,F,F,F,F,F,F,F,F],[451,452,F,F,F,F,F,F,F,F],[451,1264,F,F,F,F,F,F,F,F],[451,1303,F,F,F,F,F,F,F,F],[452,571,F,F,F,F,F,F,F,F],[452,1131,F,F,F,F,F,F,F,F],[452,1181,F,F,F,F,F,F,F,F],[454,455,F,F,F,F,F,F,F,F],[454,455,456,F,F,F,F,F,F,F],[455,465,F,F,F,F,F,F,F,F],[457,458,F,F,F,F,F,F,F,F],[457,469,F,F,F,F,F,F,F,F],[458,885,F,F,F,F,F,F,F,F],[461,463,F,F,F,F,F,F,F,F],[461,1306,F,F,F,F,F,F,F,F],[461,1315,F,F,F,F,F,F,F,F],[461,1370,F,F,F,F,F,F,F,F],[462,463,F,F,F,F,F,F,F,F],[466,467,F,F,F,F,F,F,F,F],[468,470,F,F,F,F,F,F,F,F],[468,474,F,F,F,F,F,F,F,F],[471,577,F,F,F,F,F,F,F,F],[471,587,F,F,F,F,F,F,F,F],[471,1238,F,F,F,F,F,F,F,F],[471,1374,F,F,F,F,F,F,F,F],[473,1314,F,F,F,F,F,F,F,F],[477,480,847,F,F,F,F,F,F,F],[482,483,F,F,F,F,F,F,F,F],[482,485,F,F,F,F,F,F,F,F],[482,920,F,F,F,F,F,F,F,F],[482,922,F,F,F,F,F,F,F,F],[483,484,F,F,F,F,F,F,F,F],[483,505,F,F,F,F,F,F,F,F],[483,507,F,F,F,F,F,F,F,F],[483,923,F,F,F,F,F,F,F,F],[483,1257,F,F,F,F,F,F,F,F],[485,505,F,F,F,F,F,F,F,F],[485,921,F,F,F,F,F,F,F,F],[485,1418,F,F,F,F,F,F,F,F],[486,490,508,F,F,F,F,F,F,F],[486,943,F,F,F,F,F,F,F,F],[487,505,925,F,F,F,F,F,F,F],[488,489,F,F,F,F,F,F,F,F],[488,1262,F,F,F,F,F,F,F,F],[489,507,508,F,F,F,F,F,F,F],[489,508,F,F,F,F,F,F,F,F],[491,493,F,F,F,F,F,F,F,F],[491,930,F,F,F,F,F,F,F,F],[492,493,F,F,F,F,F,F,F,F],[492,773,F,F,F,F,F,F,F,F],[493,1268,F,F,F,F,F,F,F,F],[495,497,F,F,F,F,F,F,F,F],[495,497,934,F,F,F,F,F,F,F],[497,935,936,F,F,F,F,F,F,F],[497,1271,F,F,F,F,F,F,F,F],[499,939,F,F,F,F,F,F,F,F],[501,502,F,F,F,F,F,F,F,F],[501,532,F,F,F,F,F,F,F,F],[501,958,F,F,F,F,F,F,F,F],[501,1332,F,F,F,F,F,F,F,F],[501,1480,F,F,F,F,F,F,F,F],[502,939,F,F,F,F,F,F,F,F],[504,505,F,F,F,F,F,F,F,F],[504,531,F,F,F,F,F,F,F,F],[505,1280,F,F,F,F,F,F,F,F],[506,507,F,F,F,F,F,F,F,F],[506,507,1282,F,F,F,F,F,F,F],[509,511,F,F,F,F,F,F,F,F],[510,511,F,F,F,F,F,F,F,F],[510,947,F,F,F,F,F,F,F,F],[511,514,F,F,F,F,F,F,F,F],[511,1345,F,F,F,F,F,F,F,F],[512,514,F,F,F,F,F,F,F,F],[514,637,F,F,F,F,F,F,F,F],[523,524,F,F,F,F,F,F,F,F],[523,945,F,F,F,F,F,F,F,F],[523,1495,F,F,F,F,F,F,F,F],[525,526,F,F,F,F,F,F,F,F],[525,1503,F,F,F,F,F,F,F,F],[526,964,F,F,F,F,F,F,F,F],[528,1502,F,F,F,F,F,F,F,F],[529,530,F,F,F,F,F,F,F,F],[530,537,F,F,F,F,F,F,F,F],[531,938,F,F,F,F,F,F,F,F],[532,533,F,F,F,F,F,F,F,F],[532,534,F,F,F,F,F,F,F,F],[532,1311,F,F,F,F,F,F,F,F],[535,537,F,F,F,F,F,F,F,F],[535,976,F,F,F,F,F,F,F,F],[537,962,F,F,F,F,F,F,F,F],[537,1316,F,F,F,F,F,F,F,F],[538,539,F,F,F,F,F,F,F,F],[539,927,F,F,F,F,F,F,F,F],[543,544,F,F,F,F,F,F,F,F],[546,549,F,F,F,F,F,F,F,F],[553,554,F,F,F,F,F,F,F,F],[553,555,F,F,F,F,F,F,F,F],[553,556,F,F,F,F,F,F,F,F],[554,941,F,F,F,F,F,F,F,F],[555,556,F,F,F,F,F,F,F,F],[556,557,F,F,F,F,F,F,F,F],[556,925,F,F,F,F,F,F,F,F],[559,1345,F,F,F,F,F,F,F,F],[563,564,F,F,F,F,F,F,F,F],[563,565,F,F,F,F,F,F,F,F],[580,583,F,F,F,F,F,F,F,F],[584,628,F,F,F,F,F,F,F,F],[585,586,F,F,F,F,F,F,F,F],[585,1237,F,F,F,F,F,F,F,F],[593,594,595,596,F,F,F,F,F,F],[594,596,F,F,F,F,F,F,F,F],[596,1257,F,F,F,F,F,F,F,F],[596,1413,F,F,F,F,F,F,F,F],[598,600,F,F,F,F,F,F,F,F],[605,607,F,F,F,F,F,F,F,F],[607,635,F,F,F,F,F,F,F,F],[610,612,F,F,F,F,F,F,F,F],[611,640,F,F,F,F,F,F,F,F],[611,640,641,F,F,F,F,F,F,F],[611,760,F,F,F,F,F,F,F,F],[612,684,F,F,F,F,F,F,F,F],[614,620,F,F,F,F,F,F,F,F],[615,617,F,F,F,F,F,F,F,F],[615,1375,F,F,F,F,F,F,F,F],[616,641,642,F,F,F,F,F,F,F],[618,619,F,F,F,F,F,F,F,F],[623,651,F,F,F,F,F,F,F,F],[627,628,F,F,F,F,F,F,F,F],[627,1257,1259,F,F,F,F,F,F,F],[627,1259,F,F,F,F,F,F,F,F],[628,630,F,F,F,F,F,F,F,F],[628,645,F,F,F,F,F,F,F,F],[629,684,F,F,F,F,F,F,F,F],[629,1375,F,F,F,F,F,F,F,F],[632,929,F,F,F,F,F,F,F,F],[635,637,F,F,F,F,F,F,F,F],[636,1349,F,F,F,F,F,F,F,F],[639,640,642,F,F,F,F,F,F,F],[640,641,F,F,F,F,F,F,F,F],[641,642,F,F,F,F,F,F,F,F],[641,672,F,F,F,F,F,F,F,F],[648,650,F,F,F,F,F,F,F,F],[651,1288,1370,F,F,F,F,F,F,F],[655,658,F,F,F,F,F,F,F,F],[656,658,F,F,F,F,F,F,F,F],[658,672,F,F,F,F,F,F,F,F],[661,662,F,F,F,F,F,F,F,F],[661,728,F,F,F,F,F,F,F,F],[661,1099,F,F,F,F,F,F,F,F],[665,668,F,F,F,F,F,F,F,F],[665,686,F,F,F,F,F,F,F,F],[665,687,F,F,F,F,F,F,F,F],[670,671,F,F,F,F,F,F,F,F],[672,688,F,F,F,F,F,F,F,F],[678,680,F,F,F,F,F,F,F,F],[681,682,F,F,F,F,F,F,F,F],[681,682,685,F,F,F,F,F,F,F],[684,907,F,F,F,F,F,F,F,F],[684,1142,F,F,F,F,F,F,F,F],[688,1096,F,F,F,F,F,F,F,F],[691,692,F,F,F,F,F,F,F,F],[693,751,F,F,F,F,F,F,F,F],[693,1483,F,F,F,F,F,F,F,F],[695,696,F,F,F,F,F,F,F,F],[696,700,F,F,F,F,F,F,F,F],[698,702,F,F,F,F,F,F,F,F],[700,753,F,F,F,F,F,F,F,F],[701,704,F,F,F,F,F,F,F,F],[702,704,F,F,F,F,F,F,F,F],[706,723,F,F,F,F,F,F,F,F],[706,1232,F,F,F,F,F,F,F,F],[712,753,F,F,F,F,F,F,F,F],[715,753,F,F,F,F,F,F,F,F],[719,1237,F,F,F,F,F,F,F,F],[723,744,F,F,F,F,F,F,F,F],[728,729,F,F,F,F,F,F,F,F],[728,800,F,F,F,F,F,F,F,F],[728,1341,F,F,F,F,F,F,F,F],[732,733,F,F,F,F,F,F,F,F],[732,742,F,F,F,F,F,F,F,F],[732,1074,F,F,F,F,F,F,F,F],[732,1174,F,F,F,F,F,F,F,F],[732,1229,F,F,F,F,F,F,F,F],[738,814,F,F,F,F,F,F,F,F],[751,753,F,F,F,F,F,F,F,F],[751,759,F,F,F,F,F,F,F,F],[753,761,F,F,F,F,F,F,F,F],[756,761,F,F,F,F,F,F,F,F],[760,1349,F,F,F,F,F,F,F,F],[762,763,764,795,796,797,F,F,F,F],[763,796,F,F,F,F,F,F,F,F],[764,797,F,F,F,F,F,F,F,F],[767,769,771,785,789,F,F,F,F,F],[768,769,786,787,F,F,F,F,F,F],[768,838,F,F,F,F,F,F,F,F],[771,800,F,F,F,F,F,F,F,F],[774,776,F,F,F,F,F,F,F,F],[778,779,F,F,F,F,F,F,F,F],[782,832,F,F,F,F,F,F,F,F],[791,815,F,F,F,F,F,F,F,F],[799,801,F,F,F,F,F,F,F,F],[801,810,F,F,F,F,F,F,F,F],[804,1421,F,F,F,F,F,F,F,F],[806,808,F,F,F,F,F,F,F,F],[810,1128,F,F,F,F,F,F,F,F],[815,990,F,F,F,F,F,F,F,F],[818,819,820,F,F,F,F,F,F,F],[818,820,F,F,F,F,F,F,F,F],[827,1000,F,F,F,F,F,F,F,F],[835,1306,F,F,F,F,F,F,F,F],[838,839,F,F,F,F,F,F,F,F],[840,988,F,F,F,F,F,F,F,F],[844,1334,F,F,F,F,F,F,F,F],[845,848,1248,F,F,F,F,F,F,F],[849,851,F,F,F,F,F,F,F,F],[849,852,F,F,F,F,F,F,F,F],[849,962,F,F,F,F,F,F,F,F],[849,1253,F,F,F,F,F,F,F,F],[858,859,F,F,F,F,F,F,F,F],[859,910,F,F,F,F,F,F,F,F],[864,900,F,F,F,F,F,F,F,F],[870,871,F,F,F,F,F,F,F,F],[877,880,F,F,F,F,F,F,F,F],[881,882,F,F,F,F,F,F,F,F],[885,886,F,F,F,F,F,F,F,F],[885,887,F,F,F,F,F,F,F,F],[886,888,F,F,F,F,F,F,F,F],[887,1319,F,F,F,F,F,F,F,F],[893,895,F,F,F,F,F,F,F,F],[893,896,F,F,F,F,F,F,F,F],[897,898,F,F,F,F,F,F,F,F],[907,909,F,F,F,F,F,F,F,F],[913,914,F,F,F,F,F,F,F,F],[915,916,F,F,F,F,F,F,F,F],[920,921,F,F,F,F,F,F,F,F],[920,923,F,F,F,F,F,F,F,F],[920,1257,F,F,F,F,F,F,F,F],[921,923,F,F,F,F,F,F,F,F],[921,1080,F,F,F,F,F,F,F,F],[921,1112,F,F,F,F,F,F,F,F],[921,1345,F,F,F,F,F,F,F,F],[921,1447,F,F,F,F,F,F,F,F],[922,952,953,F,F,F,F,F,F,F],[923,945,F,F,F,F,F,F,F,F],[923,1282,F,F,F,F,F,F,F,F],[929,1265,F,F,F,F,F,F,F,F],[929,1323,F,F,F,F,F,F,F,F],[932,945,F,F,F,F,F,F,F,F],[932,1257,F,F,F,F,F,F,F,F],[934,936,F,F,F,F,F,F,F,F],[934,937,F,F,F,F,F,F,F,F],[938,940,F,F,F,F,F,F,F,F],[938,1035,F,F,F,F,F,F,F,F],[939,941,F,F,F,F,F,F,F,F],[941,1060,F,F,F,F,F,F,F,F],[946,953,F,F,F,F,F,F,F,F],[947,1438,F,F,F,F,F,F,F,F],[950,1442,F,F,F,F,F,F,F,F],[959,1360,F,F,F,F,F,F,F,F],[962,964,F,F,F,F,F,F,F,F],[962,965,F,F,F,F,F,F,F,F],[970,1309,F,F,F,F,F,F,F,F],[972,974,F,F,F,F,F,F,F,F],[982,984,F,F,F,F,F,F,F,F],[983,999,F,F,F,F,F,F,F,F],[985,986,F,F,F,F,F,F,F,F],[986,987,F,F,F,F,F,F,F,F],[992,1072,F,F,F,F,F,F,F,F],[1021,1044,1134,F,F,F,F,F,F,F],[1022,1139,F,F,F,F,F,F,F,F],[1022,1259,F,F,F,F,F,F,F,F],[1023,1044,F,F,F,F,F,F,F,F],[1025,1027,1039,F,F,F,F,F,F,F],[1029,1069,F,F,F,F,F,F,F,F],[1029,1268,F,F,F,F,F,F,F,F],[1032,1034,F,F,F,F,F,F,F,F],[1035,1037,F,F,F,F,F,F,F,F],[1036,1037,F,F,F,F,F,F,F,F],[1039,1050,F,F,F,F,F,F,F,F],[1044,1047,F,F,F,F,F,F,F,F],[1044,1134,F,F,F,F,F,F,F,F],[1047,1264,F,F,F,F,F,F,F,F],[1049,1077,F,F,F,F,F,F,F,F],[1052,1055,F,F,F,F,F,F,F,F],[1052,1413,F,F,F,F,F,F,F,F],[1055,1324,F,F,F,F,F,F,F,F],[1055,1325,F,F,F,F,F,F,F,F],[1060,1063,F,F,F,F,F,F,F,F],[1060,1274,F,F,F,F,F,F,F,F],[1061,1177,F,F,F,F,F,F,F,F],[1064,1365,F,F,F,F,F,F,F,F],[1065,1341,F,F,F,F,F,F,F,F],[1066,1067,F,F,F,F,F,F,F,F],[1067,1257,F,F,F,F,F,F,F,F],[1069,1283,F,F,F,F,F,F,F,F],[1070,1072,F,F,F,F,F,F,F,F],[1070,1078,F,F,F,F,F,F,F,F],[1072,1438,F,F,F,F,F,F,F,F],[1075,1290,F,F,F,F,F,F,F,F],[1078,1079,F,F,F,F,F,F,F,F],[1078,1080,F,F,F,F,F,F,F,F],[1078,1080,1448,F,F,F,F,F,F,F],[1078,1112,1343,F,F,F,F,F,F,F],[1080,1112,F,F,F,F,F,F,F,F],[1080,1432,F,F,F,F,F,F,F,F],[1080,1445,F,F,F,F,F,F,F,F],[1089,1459,F,F,F,F,F,F,F,F],[1090,1458,F,F,F,F,F,F,F,F],[1091,1093,F,F,F,F,F,F,F,F],[1114,1450,F,F,F,F,F,F,F,F],[1119,1120,F,F,F,F,F,F,F,F],[1121,1124,F,F,F,F,F,F,F,F],[1124,1299,1300,F,F,F,F,F,F,F],[1134,1138,F,F,F,F,F,F,F,F],[1139,1216,F,F,F,F,F,F,F,F],[1142,1218,F,F,F,F,F,F,F,F],[1150,1265,F,F,F,F,F,F,F,F],[1153,1154,F,F,F,F,F,F,F,F],[1154,1289,F,F,F,F,F,F,F,F],[1157,1158,F,F,F,F,F,F,F,F],[1158,1332,F,F,F,F,F,F,F,F],[1161,1163,1207,F,F,F,F,F,F,F],[1161,1165,F,F,F,F,F,F,F,F],[1167,1448,F,F,F,F,F,F,F,F],[1168,1170,F,F,F,F,F,F,F,F],[1168,1341,F,F,F,F,F,F,F,F],[1170,1257,F,F,F,F,F,F,F,F],[1176,1178,F,F,F,F,F,F,F,F],[1181,1183,1184,F,F,F,F,F,F,F],[1185,1188,F,F,F,F,F,F,F,F],[1187,1188,F,F,F,F,F,F,F,F],[1189,1192,F,F,F,F,F,F,F,F],[1190,1192,F,F,F,F,F,F,F,F],[1190,1472,F,F,F,F,F,F,F,F],[1194,1197,F,F,F,F,F,F,F,F],[1199,1201,F,F,F,F,F,F,F,F],[1200,1201,F,F,F,F,F,F,F,F],[1210,1214,F,F,F,F,F,F,F,F],[1210,1215,F,F,F,F,F,F,F,F],[1212,1476,F,F,F,F,F,F,F,F],[1221,1222,F,F,F,F,F,F,F,F],[1225,1235,F,F,F,F,F,F,F,F],[1229,1367,F,F,F,F,F,F,F,F],[1230,1370,F,F,F,F,F,F,F,F],[1232,1234,F,F,F,F,F,F,F,F],[1233,1234,F,F,F,F,F,F,F,F],[1235,1237,F,F,F,F,F,F,F,F],[1236,1237,F,F,F,F,F,F,F,F],[1236,1238,F,F,F,F,F,F,F,F],[1236,1301,F,F,F,F,F,F,F,F],[1237,1342,F,F,F,F,F,F,F,F],[1238,1367,F,F,F,F,F,F,F,F],[1241,1243,F,F,F,F,F,F,F,F],[1245,1246,F,F,F,F,F,F,F,F],[1248,1461,F,F,F,F,F,F,F,F],[1250,1369,F,F,F,F,F,F,F,F],[1252,1255,F,F,F,F,F,F,F,F],[1253,1366,F,F,F,F,F,F,F,F],[1257,1259,F,F,F,F,F,F,F,F],[1259,1267,F,F,F,F,F,F,F,F],[1261,1264,F,F,F,F,F,F,F,F],[1265,1267,F,F,F,F,F,F,F,F],[1268,1307,F,F,F,F,F,F,F,F],[1268,1345,F,F,F,F,F,F,F,F],[1270,1273,F,F,F,F,F,F,F,F],[1271,1327,F,F,F,F,F,F,F,F],[1274,1275,F,F,F,F,F,F,F,F],[1274,1277,F,F,F,F,F,F,F,F],[1277,1311,F,F,F,F,F,F,F,F],[1277,1332,F,F,F,F,F,F,F,F],[1280,1336,F,F,F,F,F,F,F,F],[1281,1282,F,F,F,F,F,F,F,F],[1283,1286,F,F,F,F,F,F,F,F],[1289,1350,F,F,F,F,F,F,F,F],[1301,1303,1304,F,F,F,F,F,F,F],[1301,1364,1367,F,F,F,F,F,F,F],[1304,1460,F,F,F,F,F,F,F,F],[1307,1309,F,F,F,F,F,F,F,F],[1314,1316,F,F,F,F,F,F,F,F],[1314,1347,F,F,F,F,F,F,F,F],[1315,1316,F,F,F,F,F,F,F,F],[1317,1320,F,F,F,F,F,F,F,F],[1318,1336,F,F,F,F,F,F,F,F],[1322,1323,F,F,F,F,F,F,F,F],[1322,1344,F,F,F,F,F,F,F,F],[1323,1325,F,F,F,F,F,F,F,F],[1323,1370,F,F,F,F,F,F,F,F],[1326,1329,F,F,F,F,F,F,F,F],[1327,1329,F,F,F,F,F,F,F,F],[1332,1360,1456,F,F,F,F,F,F,F],[1333,1334,F,F,F,F,F,F,F,F],[1334,1375,F,F,F,F,F,F,F,F],[1335,1336,F,F,F,F,F,F,F,F],[1335,1338,F,F,F,F,F,F,F,F],[1340,1341,1343,F,F,F,F,F,F,F],[1340,1343,F,F,F,F,F,F,F,F],[1341,1342,F,F,F,F,F,F,F,F],[1341,1343,F,F,F,F,F,F,F,F],[1344,1347,F,F,F,F,F,F,F,F],[1346,1347,F,F,F,F,F,F,F,F],[1349,1352,F,F,F,F,F,F,F,F],[1353,1354,F,F,F,F,F,F,F,F],[1355,1358,F,F,F,F,F,F,F,F],[1356,1365,F,F,F,F,F,F,F,F],[1361,1362,F,F,F,F,F,F,F,F],[1361,1511,F,F,F,F,F,F,F,F],[1365,1366,1367,F,F,F,F,F,F,F],[1366,1367,F,F,F,F,F,F,F,F],[1370,1377,1379,F,F,F,F,F,F,F],[1376,1379,F,F,F,F,F,F,F,F],[1381,1389,F,F,F,F,F,F,F,F],[1381,1410,F,F,F,F,F,F,F,F],[1382,1388,1391,F,F,F,F,F,F,F],[1383,1399,1410,F,F,F,F,F,F,F],[1384,1483,F,F,F,F,F,F,F,F],[1394,1471,F,F,F,F,F,F,F,F],[1396,1512,F,F,F,F,F,F,F,F],[1399,1416,F,F,F,F,F,F,F,F],[1399,1510,F,F,F,F,F,F,F,F],[1407,1409,F,F,F,F,F,F,F,F],[1410,1411,F,F,F,F,F,F,F,F],[1410,1413,F,F,F,F,F,F,F,F],[1413,1512,F,F,F,F,F,F,F,F],[1415,1418,F,F,F,F,F,F,F,F],[1419,1420,F,F,F,F,F,F,F,F],[1424,1425,F,F,F,F,F,F,F,F],[1427,1453,F,F,F,F,F,F,F,F],[1429,1462,F,F,F,F,F,F,F,F],[1432,1433,F,F,F,F,F,F,F,F],[1432,1435,F,F,F,F,F,F,F,F],[1435,1437,F,F,F,F,F,F,F,F],[1436,1460,1480,F,F,F,F,F,F,F],[1442,1444,F,F,F,F,F,F,F,F],[1442,1447,F,F,F,F,F,F,F,F],[1445,1483,F,F,F,F,F,F,F,F],[1450,1452,F,F,F,F,F,F,F,F],[1451,1452,F,F,F,F,F,F,F,F],[1454,1456,F,F,F,F,F,F,F,F],[1456,1457,F,F,F,F,F,F,F,F],[1458,1460,F,F,F,F,F,F,F,F],[1469,1500,F,F,F,F,F,F,F,F],[1469,1501,F,F,F,F,F,F,F,F],[1470,1472,F,F,F,F,F,F,F,F],[1479,1480,F,F,F,F,F,F,F,F],[1483,1485,F,F,F,F,F,F,F,F],[1483,1503,F,F,F,F,F,F,F,F],[1485,1487,F,F,F,F,F,F,F,F],[1499,1501,F,F,F,F,F,F,F,F],[1230,753,F,F,F,F,F,F,F,F],[1058,1057,F,F,F,F,F,F,F,F],[85,83,F,F,F,F,F,F,F,F],[944,523,F,F,F,F,F,F,F,F],[1238,1236,F,F,F,F,F,F,F,F],[629,627,F,F,F,F,F,F,F,F],[714,697,1331,F,F,F,F,F,F,F],[1366,1365,F,F,F,F,F,F,F,F],[1485,1385,1483,F,F,F,F,F,F,F],[652,651,653,F,F,F,F,F,F,F],[721,719,F,F,F,F,F,F,F,F],[992,990,F,F,F,F,F,F,F,F],[1268,932,F,F,F,F,F,F,F,F],[78,28,F,F,F,F,F,F,F,F],[801,799,F,F,F,F,F,F,F,F],[486,346,943,F,F,F,F,F,F,F],[1169,1167,F,F,F,F,F,F,F,F],[342,337,F,F,F,F,F,F,F,F],[1512,1509,1510,F,F,F,F,F,F,F],[1365,1090,F,F,F,F,F,F,F,F],[636,631,F,F,F,F,F,F,F,F],[526,523,F,F,F,F,F,F,F,F],[365,364,F,F,F,F,F,F,F,F],[235,234,F,F,F,F,F,F,F,F],[1155,124,F,F,F,F,F,F,F,F],[1255,1252,F,F,F,F,F,F,F,F],[1291,1288,F,F,F,F,F,F,F,F],[921,482,F,F,F,F,F,F,F,F],[1483,188,F,F,F,F,F,F,F,F],[484,482,F,F,F,F,F,F,F,F],[945,942,F,F,F,F,F,F,F,F],[456,455,459,F,F,F,F,F,F,F],[177,174,F,F,F,F,F,F,F,F],[870,41,F,F,F,F,F,F,F,F],[1044,114,F,F,F,F,F,F,F,F],[190,187,F,F,F,F,F,F,F,F],[1310,1274,F,F,F,F,F,F,F,F],[912,909,F,F,F,F,F,F,F,F],[77,38,F,F,F,F,F,F,F,F],[501,128,F,F,F,F,F,F,F,F],[456,454,F,F,F,F,F,F,F,F],[1081,146,F,F,F,F,F,F,F,F],[587,401,F,F,F,F,F,F,F,F],[962,849,F,F,F,F,F,F,F,F],[1496,201,F,F,F,F,F,F,F,F],[226,120,1055,F,F,F,F,F,F,F],[774,765,F,F,F,F,F,F,F,F],[1422,1419,F,F,F,F,F,F,F,F],[125,9,F,F,F,F,F,F,F,F],[866,706,F,F,F,F,F,F,F,F],[526,378,380,F,F,F,F,F,F,F],[1447,1445,F,F,F,F,F,F,F,F],[1430,1063,F,F,F,F,F,F,F,F],[1145,1144,F,F,F,F,F,F,F,F],[1338,1280,F,F,F,F,F,F,F,F],[428,426,F,F,F,F,F,F,F,F],[430,364,F,F,F,F,F,F,F,F],[1264,431,F,F,F,F,F,F,F,F],[1317,0,F,F,F,F,F,F,F,F],[697,694,695,F,F,F,F,F,F,F],[561,423,F,F,F,F,F,F,F,F],[130,128,F,F,F,F,F,F,F,F],[1321,1317,F,F,F,F,F,F,F,F],[222,6,F,F,F,F,F,F,F,F],[922,720,F,F,F,F,F,F,F,F],[1254,691,F,F,F,F,F,F,F,F],[851,849,852,F,F,F,F,F,F,F],[45,29,F,F,F,F,F,F,F,F],[799,787,F,F,F,F,F,F,F,F],[67,57,F,F,F,F,F,F,F,F],[538,346,F,F,F,F,F,F,F,F],[644,643,646,F,F,F,F,F,F,F],[729,727,F,F,F,F,F,F,F,F],[1388,1382,1391,F,F,F,F,F,F,F],[1308,1306,F,F,F,F,F,F,F,F],[1325,1322,1338,F,F,F,F,F,F,F],[1223,1219,F,F,F,F,F,F,F,F],[1319,1317,F,F,F,F,F,F,F,F],[846,291,848,F,F,F,F,F,F,F],[628,626,630,1349,F,F,F,F,F,F],[1253,762,795,F,F,F,F,F,F,F],[1047,1045,F,F,F,F,F,F,F,F],[597,593,F,F,F,F,F,F,F,F],[81,77,F,F,F,F,F,F,F,F],[594,593,595,F,F,F,F,F,F,F],[1410,111,F,F,F,F,F,F,F,F],[256,249,F,F,F,F,F,F,F,F],[246,245,F,F,F,F,F,F,F,F],[1301,1077,F,F,F,F,F,F,F,F],[1274,18,F,F,F,F,F,F,F,F],[983,981,F,F,F,F,F,F,F,F],[532,500,502,F,F,F,F,F,F,F],[778,777,F,F,F,F,F,F,F,F],[967,528,530,F,F,F,F,F,F,F],[1367,1215,F,F,F,F,F,F,F,F],[125,124,F,F,F,F,F,F,F,F],[555,516,553,F,F,F,F,F,F,F],[11,9,F,F,F,F,F,F,F,F],[1338,295,F,F,F,F,F,F,F,F],[1141,221,F,F,F,F,F,F,F,F],[1325,1322,F,F,F,F,F,F,F,F],[161,160,F,F,F,F,F,F,F,F],[342,341,F,F,F,F,F,F,F,F],[422,48,F,F,F,F,F,F,F,F],[486,346,487,F,F,F,F,F,F,F],[320,319,F,F,F,F,F,F,F,F],[1237,1235,F,F,F,F,F,F,F,F],[1372,1370,F,F,F,F,F,F,F,F],[730,342,F,F,F,F,F,F,F,F],[1315,1284,F,F,F,F,F,F,F,F],[1063,1062,F,F,F,F,F,F,F,F],[1360,1231,F,F,F,F,F,F,F,F],[147,134,F,F,F,F,F,F,F,F],[905,75,F,F,F,F,F,F,F,F],[1343,362,F,F,F,F,F,F,F,F],[922,541,F,F,F,F,F,F,F,F],[962,523,F,F,F,F,F,F,F,F],[1448,1080,F,F,F,F,F,F,F,F],[1366,1253,F,F,F,F,F,F,F,F],[932,545,F,F,F,F,F,F,F,F],[484,483,F,F,F,F,F,F,F,F],[1419,1055,F,F,F,F,F,F,F,F],[114,111,115,118,187,F,F,F,F,F],[1342,1112,F,F,F,F,F,F,F,F],[1273,424,F,F,F,F,F,F,F,F],[1080,342,F,F,F,F,F,F,F,F],[1444,1438,F,F,F,F,F,F,F,F],[355,353,F,F,F,F,F,F,F,F],[76,74,F,F,F,F,F,F,F,F],[396,9,F,F,F,F,F,F,F,F],[247,238,F,F,F,F,F,F,F,F],[827,663,F,F,F,F,F,F,F,F],[935,497,936,F,F,F,F,F,F,F],[224,222,F,F,F,F,F,F,F,F],[192,191,F,F,F,F,F,F,F,F],[1234,1232,F,F,F,F,F,F,F,F],[1110,1025,F,F,F,F,F,F,F,F],[295,294,1252,1255,F,F,F,F,F,F],[1301,966,F,F,F,F,F,F,F,F],[1260,449,F,F,F,F,F,F,F,F],[487,346,F,F,F,F,F,F,F,F],[1055,1052,F,F,F,F,F,F,F,F],[1410,1381,1411,F,F,F,F,F,F,F],[547,356,F,F,F,F,F,F,F,F],[1433,1065,1432,F,F,F,F,F,F,F],[678,677,F,F,F,F,F,F,F,F],[936,934,F,F,F,F,F,F,F,F],[752,693,753,F,F,F,F,F,F,F],[115,86,F,F,F,F,F,F,F,F],[29,27,F,F,F,F,F,F,F,F],[592,385,F,F,F,F,F,F,F,F],[1463,1338,1465,F,F,F,F,F,F,F],[1078,1067,F,F,F,F,F,F,F,F],[1447,1445,1446,F,F,F,F,F,F,F],[1280,486,F,F,F,F,F,F,F,F],[1019,1009,F,F,F,F,F,F,F,F],[1254,1253,F,F,F,F,F,F,F,F],[687,681,F,F,F,F,F,F,F,F],[690,689,F,F,F,F,F,F,F,F],[1448,1445,F,F,F,F,F,F,F,F],[1168,245,F,F,F,F,F,F,F,F],[666,664,F,F,F,F,F,F,F,F],[751,693,F,F,F,F,F,F,F,F],[751,750,F,F,F,F,F,F,F,F],[1022,1021,F,F,F,F,F,F,F,F],[618,617,620,F,F,F,F,F,F,F],[1389,203,F,F,F,F,F,F,F,F],[502,499,F,F,F,F,F,F,F,F],[1030,1029,F,F,F,F,F,F,F,F],[281,280,F,F,F,F,F,F,F,F],[1090,1089,1109,F,F,F,F,F,F,F],[1365,1252,1364,F,F,F,F,F,F,F],[1253,751,F,F,F,F,F,F,F,F],[1259,1257,F,F,F,F,F,F,F,F],[918,41,F,F,F,F,F,F,F,F],[373,371,F,F,F,F,F,F,F,F],[920,482,F,F,F,F,F,F,F,F],[753,714,F,F,F,F,F,F,F,F],[917,894,907,910,F,F,F,F,F,F],[1052,1044,F,F,F,F,F,F,F,F],[379,378,525,F,F,F,F,F,F,F],[1362,1360,F,F,F,F,F,F,F,F],[1413,1052,F,F,F,F,F,F,F,F],[1277,502,F,F,F,F,F,F,F,F],[363,362,F,F,F,F,F,F,F,F],[524,505,F,F,F,F,F,F,F,F],[911,78,F,F,F,F,F,F,F,F],[1060,1037,F,F,F,F,F,F,F,F],[623,622,651,F,F,F,F,F,F,F],[1069,136,F,F,F,F,F,F,F,F],[1342,1340,F,F,F,F,F,F,F,F],[1410,112,F,F,F,F,F,F,F,F],[1338,257,F,F,F,F,F,F,F,F],[541,504,F,F,F,F,F,F,F,F],[544,493,F,F,F,F,F,F,F,F],[142,137,F,F,F,F,F,F,F,F],[1340,920,F,F,F,F,F,F,F,F],[1301,163,F,F,F,F,F,F,F,F],[1444,1442,F,F,F,F,F,F,F,F],[1446,1081,F,F,F,F,F,F,F,F],[1344,1322,1346,F,F,F,F,F,F,F],[383,381,F,F,F,F,F,F,F,F],[318,316,F,F,F,F,F,F,F,F],[420,418,F,F,F,F,F,F,F,F],[1153,1056,F,F,F,F,F,F,F,F],[483,482,F,F,F,F,F,F,F,F],[907,78,F,F,F,F,F,F,F,F],[730,57,F,F,F,F,F,F,F,F],[872,869,F,F,F,F,F,F,F,F],[1039,923,F,F,F,F,F,F,F,F],[860,853,F,F,F,F,F,F,F,F],[931,491,F,F,F,F,F,F,F,F],[485,484,F,F,F,F,F,F,F,F],[628,414,F,F,F,F,F,F,F,F],[634,629,F,F,F,F,F,F,F,F],[1357,150,F,F,F,F,F,F,F,F],[1248,404,F,F,F,F,F,F,F,F],[905,882,F,F,F,F,F,F,F,F],[476,474,F,F,F,F,F,F,F,F],[493,491,F,F,F,F,F,F,F,F],[587,471,F,F,F,F,F,F,F,F],[1141,218,F,F,F,F,F,F,F,F],[1137,1136,F,F,F,F,F,F,F,F],[896,893,895,F,F,F,F,F,F,F],[1472,151,1099,F,F,F,F,F,F,F],[517,401,F,F,F,F,F,F,F,F],[872,38,870,F,F,F,F,F,F,F],[1413,1411,F,F,F,F,F,F,F,F],[1325,1052,F,F,F,F,F,F,F,F],[1088,1083,F,F,F,F,F,F,F,F],[1379,1367,F,F,F,F,F,F,F,F],[18,17,F,F,F,F,F,F,F,F],[344,343,F,F,F,F,F,F,F,F],[892,889,F,F,F,F,F,F,F,F],[710,627,F,F,F,F,F,F,F,F],[710,709,F,F,F,F,F,F,F,F],[1510,1480,F,F,F,F,F,F,F,F],[1441,1175,F,F,F,F,F,F,F,F],[1185,261,1187,F,F,F,F,F,F,F],[227,221,F,F,F,F,F,F,F,F],[221,217,218,F,F,F,F,F,F,F],[943,67,F,F,F,F,F,F,F,F],[1076,142,F,F,F,F,F,F,F,F],[545,543,F,F,F,F,F,F,F,F],[728,727,729,F,F,F,F,F,F,F],[1436,1427,1428,1480,F,F,F,F,F,F],[963,526,F,F,F,F,F,F,F,F],[723,722,F,F,F,F,F,F,F,F],[509,392,947,F,F,F,F,F,F,F],[501,500,F,F,F,F,F,F,F,F],[1078,228,F,F,F,F,F,F,F,F],[480,454,1301,F,F,F,F,F,F,F],[1237,471,F,F,F,F,F,F,F,F],[442,375,F,F,F,F,F,F,F,F],[741,473,F,F,F,F,F,F,F,F],[672,658,F,F,F,F,F,F,F,F],[112,111,F,F,F,F,F,F,F,F],[511,509,F,F,F,F,F,F,F,F],[1451,151,1452,F,F,F,F,F,F,F],[141,139,144,F,F,F,F,F,F,F],[1451,1190,1452,F,F,F,F,F,F,F],[1460,1458,F,F,F,F,F,F,F,F],[218,217,F,F,F,F,F,F,F,F],[1377,535,F,F,F,F,F,F,F,F],[505,504,F,F,F,F,F,F,F,F],[1419,803,F,F,F,F,F,F,F,F],[1453,1367,F,F,F,F,F,F,F,F],[452,449,450,F,F,F,F,F,F,F],[728,715,F,F,F,F,F,F,F,F],[1260,485,F,F,F,F,F,F,F,F],[620,618,619,F,F,F,F,F,F,F],[678,392,F,F,F,F,F,F,F,F],[51,49,52,F,F,F,F,F,F,F],[987,986,F,F,F,F,F,F,F,F],[442,374,F,F,F,F,F,F,F,F],[1265,932,F,F,F,F,F,F,F,F],[1373,1331,F,F,F,F,F,F,F,F],[684,612,F,F,F,F,F,F,F,F],[186,128,F,F,F,F,F,F,F,F],[1488,1409,F,F,F,F,F,F,F,F],[688,672,F,F,F,F,F,F,F,F],[335,333,F,F,F,F,F,F,F,F],[1134,1124,F,F,F,F,F,F,F,F],[756,737,749,F,F,F,F,F,F,F],[25,24,F,F,F,F,F,F,F,F],[25,24,886,F,F,F,F,F,F,F],[941,939,F,F,F,F,F,F,F,F],[772,306,F,F,F,F,F,F,F,F],[1277,1274,F,F,F,F,F,F,F,F],[274,273,F,F,F,F,F,F,F,F],[1367,1353,F,F,F,F,F,F,F,F],[44,42,F,F,F,F,F,F,F,F],[868,862,F,F,F,F,F,F,F,F],[1076,1073,F,F,F,F,F,F,F,F],[362,346,363,F,F,F,F,F,F,F],[1394,1392,F,F,F,F,F,F,F,F],[916,343,F,F,F,F,F,F,F,F],[1322,0,F,F,F,F,F,F,F,F],[1052,228,F,F,F,F,F,F,F,F],[57,45,F,F,F,F,F,F,F,F],[1366,1365,1367,F,F,F,F,F,F,F],[370,369,F,F,F,F,F,F,F,F],[342,78,F,F,F,F,F,F,F,F],[1293,955,F,F,F,F,F,F,F,F],[886,56,F,F,F,F,F,F,F,F],[507,352,F,F,F,F,F,F,F,F],[1020,988,F,F,F,F,F,F,F,F],[998,997,F,F,F,F,F,F,F,F],[1315,930,F,F,F,F,F,F,F,F],[455,454,456,F,F,F,F,F,F,F],[775,774,F,F,F,F,F,F,F,F],[59,58,F,F,F,F,F,F,F,F],[612,596,681,684,F,F,F,F,F,F],[1128,537,1136,F,F,F,F,F,F,F],[723,722,724,F,F,F,F,F,F,F],[744,730,F,F,F,F,F,F,F,F],[601,597,F,F,F,F,F,F,F,F],[704,702,1230,F,F,F,F,F,F,F],[315,77,316,F,F,F,F,F,F,F],[1367,20,F,F,F,F,F,F,F,F],[753,751,F,F,F,F,F,F,F,F],[905,901,F,F,F,F,F,F,F,F],[814,80,F,F,F,F,F,F,F,F],[418,353,F,F,F,F,F,F,F,F],[1261,409,F,F,F,F,F,F,F,F],[695,693,696,F,F,F,F,F,F,F],[409,363,F,F,F,F,F,F,F,F],[1313,1281,F,F,F,F,F,F,F,F],[1289,423,424,437,1291,F,F,F,F,F],[1094,205,F,F,F,F,F,F,F,F],[645,644,F,F,F,F,F,F,F,F],[1334,1331,F,F,F,F,F,F,F,F],[1150,947,F,F,F,F,F,F,F,F],[532,502,F,F,F,F,F,F,F,F],[965,962,F,F,F,F,F,F,F,F],[299,298,300,301,F,F,F,F,F,F],[1102,1068,F,F,F,F,F,F,F,F],[891,890,F,F,F,F,F,F,F,F],[953,952,F,F,F,F,F,F,F,F],[1080,1078,1448,F,F,F,F,F,F,F],[272,270,F,F,F,F,F,F,F,F],[1303,451,F,F,F,F,F,F,F,F],[680,678,F,F,F,F,F,F,F,F],[1108,1104,1375,F,F,F,F,F,F,F],[728,717,F,F,F,F,F,F,F,F],[477,455,480,F,F,F,F,F,F,F],[1080,147,F,F,F,F,F,F,F,F],[1448,1418,F,F,F,F,F,F,F,F],[488,383,F,F,F,F,F,F,F,F],[1156,1153,F,F,F,F,F,F,F,F],[965,201,F,F,F,F,F,F,F,F],[1142,1139,F,F,F,F,F,F,F,F],[862,702,F,F,F,F,F,F,F,F],[281,210,F,F,F,F,F,F,F,F],[1468,1464,1469,F,F,F,F,F,F,F],[1320,347,1411,F,F,F,F,F,F,F],[1386,1385,F,F,F,F,F,F,F,F],[888,57,885,F,F,F,F,F,F,F],[1255,1254,F,F,F,F,F,F,F,F],[910,859,F,F,F,F,F,F,F,F],[1080,592,F,F,F,F,F,F,F,F],[1131,1129,F,F,F,F,F,F,F,F],[1341,1238,F,F,F,F,F,F,F,F],[767,765,768,771,786,789,790,F,F,F],[1452,151,F,F,F,F,F,F,F,F],[637,635,F,F,F,F,F,F,F,F],[229,141,F,F,F,F,F,F,F,F],[1195,269,F,F,F,F,F,F,F,F],[918,915,F,F,F,F,F,F,F,F],[1424,124,F,F,F,F,F,F,F,F],[1266,491,493,F,F,F,F,F,F,F],[888,885,F,F,F,F,F,F,F,F],[720,718,F,F,F,F,F,F,F,F],[661,660,F,F,F,F,F,F,F,F],[450,431,F,F,F,F,F,F,F,F],[974,938,940,F,F,F,F,F,F,F],[672,667,F,F,F,F,F,F,F,F],[513,372,F,F,F,F,F,F,F,F],[283,272,F,F,F,F,F,F,F,F],[430,363,F,F,F,F,F,F,F,F],[616,362,F,F,F,F,F,F,F,F],[1216,287,F,F,F,F,F,F,F,F],[383,381,382,F,F,F,F,F,F,F],[313,312,F,F,F,F,F,F,F,F],[910,78,1218,F,F,F,F,F,F,F],[809,254,F,F,F,F,F,F,F,F],[870,63,F,F,F,F,F,F,F,F],[694,693,F,F,F,F,F,F,F,F],[751,714,F,F,F,F,F,F,F,F],[732,724,742,F,F,F,F,F,F,F],[921,485,1415,F,F,F,F,F,F,F],[921,485,F,F,F,F,F,F,F,F]];
 
 pub(super) const PINYIN_RANGE_TABLES: [PinyinRangeTable; 7] = [
-PinyinRangeTable::new(0x3400..=0x9FED, &[958,1175,F,F,575,1254,1346,F,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,1280,F,F,F,F,F,155,F,F,F,F,840,F,F,226,F,F,F,1304,1289,F,1293,656,649,1270,1355,1286,1440,224,1255,861,1005,F,695,932,1343,1345,790,144,332,F,F,F,1479,321,19,1254,1512,F,1456,267,1124,1343,956,580,620,783,1460,1087,F,F,F,1303,F,F,1069,507,257,838,1124,1343,663,1352,51,F,F,F,601,735,1343,629,485,1366,682,116,F,F,F,474,1303,471,986,F,1456,F,420,1260,1147,1332,471,537,696,690,1154,1332,1430,1415,1366,1480,308,979,922,148,1254,420,1155,1039,1110,956,618,1023,F,F,586,916,1142,1088,1326,851,1145,F,731,1347,255,1366,740,537,809,1282,1360,F,F,135,332,619,629,F,683,F,485,F,F,F,F,968,F,90,636,383,712,F,387,1131,F,F,712,702,967,1080,627,F,1233,568,274,1441,1185,F,F,76,480,267,376,141,F,946,517,672,1291,F,774,1282,F,57,505,1124,F,374,F,1362,1289,928,886,252,343,684,928,928,1153,26,353,188,1306,606,F,F,1065,253,627,456,1194,889,245,1011,295,945,1343,1479,1015,491,F,148,151,1257,F,690,242,648,532,1124,1277,1390,F,F,1459,1421,491,1494,163,1282,629,F,148,1258,492,F,483,F,327,161,49,505,F,22,636,577,F,1261,66,532,618,1286,29,1328,688,44,295,671,F,F,135,838,1307,437,1366,F,400,1343,1308,376,667,1167,615,1080,F,1133,1334,1265,1494,F,974,1345,1257,1447,487,452,593,1342,556,341,947,8,F,556,159,1281,159,1235,F,F,463,1124,1362,F,537,1429,1304,1079,F,1091,587,1099,430,352,1324,959,1069,456,1257,318,868,227,321,374,17,343,786,1313,1360,455,F,137,405,2248,456,629,311,1274,914,F,1112,F,F,615,650,1343,447,F,1304,963,306,F,F,1315,F,F,F,F,814,1237,1282,1168,442,1213,814,814,1345,1438,F,F,F,F,F,1224,1085,840,1338,921,1196,418,537,266,473,672,523,480,651,F,1174,678,F,F,F,F,F,F,363,1322,1078,1311,877,184,822,286,1399,295,1280,1359,295,1075,1242,569,450,363,1262,704,690,483,446,1448,F,F,1224,F,30,8,1473,929,380,228,42,78,158,629,1277,1300,F,F,F,F,F,442,1170,201,592,610,1448,1279,1257,F,945,1410,1257,F,F,192,483,480,1141,1323,1304,907,1026,F,F,F,404,1338,1272,1310,430,1512,1343,188,F,622,1266,1146,998,1343,1448,1257,1266,526,483,419,F,872,629,F,601,1026,419,1323,962,F,1323,419,543,147,814,480,F,57,1262,1246,1307,1227,1360,947,1304,814,57,428,513,20,20,F,F,1438,1149,524,F,1512,85,505,8,1394,188,311,F,F,F,F,814,658,715,280,35,57,40,F,161,1264,1174,124,F,F,284,1235,344,286,1366,1337,585,1237,577,F,1235,1331,662,1288,84,146,1280,814,606,1340,1488,702,1425,1264,403,1280,F,485,640,1343,483,1345,F,217,1343,1282,428,1357,544,122,1145,1154,1446,41,723,585,120,619,F,1260,F,1257,941,780,1376,F,661,344,1488,F,383,540,253,456,586,F,360,1160,F,1054,599,814,342,360,943,33,486,563,1260,1367,1473,1071,186,1274,484,831,1275,1343,1365,1341,1324,1071,979,428,1023,535,1360,F,1284,873,958,119,F,85,265,1112,307,F,711,1379,482,F,940,1293,870,159,881,839,505,1340,308,287,F,F,F,286,F,F,945,687,959,1119,96,270,1257,332,1343,1134,942,910,1283,1191,1286,1360,44,663,F,F,F,F,1377,627,1142,602,703,936,1454,1325,1257,672,1257,1036,318,F,1237,313,1343,783,141,1152,482,1090,890,9,573,111,F,1266,1448,F,F,332,634,1315,1304,730,471,761,1355,1421,1343,829,1154,1257,1377,1090,342,1343,218,F,632,101,93,526,672,1124,791,20,11,932,F,205,191,F,978,804,698,1284,1375,770,20,1069,696,F,F,603,1257,1375,1448,1246,457,725,809,1229,728,814,963,1390,634,1446,1485,413,1304,428,1306,1448,732,183,380,F,183,674,1460,1084,641,521,1281,263,507,998,706,F,556,1333,818,1341,605,1356,1345,1323,1124,F,648,1317,710,744,1504,1366,1343,380,729,537,1242,F,546,252,661,F,1290,207,939,731,725,948,F,1228,237,8,F,60,828,632,510,1364,179,1511,79,468,1334,1209,485,9,684,484,1237,77,1381,1304,804,1379,F,23,1433,523,1237,1282,923,1341,1282,188,959,271,810,923,484,1207,F,1115,252,610,1421,F,F,1345,107,484,471,1485,601,782,526,949,224,F,505,1303,191,1358,269,146,F,742,465,1131,555,1496,428,142,1313,799,148,632,12,760,1110,1271,1327,455,216,959,610,342,280,706,605,1217,418,707,78,975,921,418,F,663,70,1177,1399,921,1390,728,874,1422,1273,357,F,921,F,672,F,1379,295,275,741,1235,968,1119,741,1198,F,745,1333,532,629,577,357,1370,221,F,610,665,932,18,64,1355,707,234,F,18,F,1258,342,226,521,1018,1190,962,295,920,483,483,455,502,1504,64,723,29,1237,1342,20,1366,426,280,1250,801,203,F,627,670,809,457,629,F,688,332,729,1367,F,526,F,F,1421,881,1342,F,485,56,F,992,464,316,363,572,507,1044,F,1110,1190,1369,1483,57,574,629,464,1315,839,F,1433,1243,1266,928,1336,712,F,F,1090,F,938,1476,588,1255,1349,175,1168,632,54,378,706,1282,335,665,1395,1444,159,704,661,F,1347,900,1444,491,674,812,1343,F,485,483,1416,1366,520,461,1447,593,651,1447,47,1413,523,228,642,1343,1430,1268,148,190,147,1324,605,270,663,120,F,1208,112,7,147,F,1351,1433,1194,F,1208,112,1333,1489,F,861,941,632,947,671,1325,2101,1121,1343,119,517,496,F,514,F,267,F,530,420,248,F,F,442,F,146,253,57,F,1316,670,F,1280,57,F,57,F,1266,1015,69,307,530,F,1441,44,295,1366,963,1390,728,1343,1112,F,F,F,1055,1145,761,514,60,998,110,96,261,F,F,F,F,246,1191,1142,1289,1114,285,1260,1157,F,1168,1055,493,1448,1235,1347,F,F,462,1451,923,1488,F,1282,1282,1399,1236,F,F,1142,1419,820,F,F,1283,1343,991,1090,114,1480,F,732,483,320,876,8,318,19,949,925,1277,328,353,938,362,1190,119,1362,359,48,344,161,1460,F,1456,F,423,815,532,150,114,564,645,629,1367,F,1365,415,629,446,375,556,1372,237,471,F,397,517,1512,344,945,43,135,188,706,417,1260,959,466,F,F,155,1030,1322,1446,237,1161,720,651,1085,1210,94,257,135,882,1340,524,485,598,1174,1372,F,91,920,1367,632,191,F,F,F,1365,483,1238,729,1131,1280,1301,148,959,471,F,1365,945,1107,1104,286,665,F,866,1147,1456,1346,1035,326,136,1370,1341,476,1039,1338,742,330,430,F,1347,104,801,20,333,632,124,121,694,256,449,672,F,1343,455,1410,449,295,480,1133,801,1268,627,1268,1325,661,720,509,482,F,59,1366,480,736,155,698,F,615,505,1238,1343,1306,1260,95,601,1346,1282,1381,683,651,930,480,491,1249,F,F,363,1457,257,1357,484,1327,1007,1258,1101,1367,1341,931,483,965,1173,1082,931,761,509,711,1346,352,907,1308,712,321,1318,356,1116,468,1367,385,391,657,295,1485,1486,57,1221,F,645,F,F,576,F,415,1344,1457,153,1267,1309,F,959,876,400,306,375,956,449,611,629,139,1029,1481,1249,913,561,1214,881,1161,1142,1480,65,384,449,F,75,1448,266,279,1453,790,649,908,484,741,1237,134,380,35,1005,1149,84,1488,584,610,420,1350,1448,505,1290,1280,1315,1054,930,1279,1124,412,730,474,885,F,471,768,1116,48,154,505,467,602,F,452,268,480,402,1332,104,400,493,492,234,511,696,471,732,94,690,888,1329,526,526,974,F,929,1049,F,521,480,1378,218,1306,1274,327,104,1338,F,241,F,947,470,1212,F,935,1258,800,1025,723,1204,602,426,190,1418,17,683,740,F,341,F,1280,78,471,952,1280,F,F,78,930,908,501,532,589,1116,524,295,814,929,257,257,F,920,1445,921,1475,569,1365,947,569,430,342,1902,247,1268,401,430,976,420,1191,78,1054,56,672,1338,799,167,1030,255,672,1195,633,555,1030,1440,168,634,712,F,929,542,1062,1274,57,1410,1347,1257,1055,1124,1023,1015,185,670,651,112,F,463,F,F,487,33,450,269,F,666,523,530,555,1136,684,1433,262,277,1460,1324,866,112,F,F,F,F,1342,F,F,1360,468,1333,1333,1447,375,922,370,F,F,448,730,342,449,397,1151,245,F,1323,F,F,965,F,125,745,1157,41,9,F,F,1267,F,F,F,712,607,775,44,137,F,324,1455,482,504,1090,F,590,257,672,F,F,F,F,1365,1145,122,704,742,463,1240,835,1994,446,514,78,1267,629,511,F,707,896,426,1327,F,1268,1124,1237,135,1257,511,109,431,328,1051,651,F,280,920,918,1375,78,F,471,257,1325,526,502,776,645,1365,1170,1172,1254,443,1275,428,F,1176,1442,F,464,344,F,F,1211,F,994,501,F,1286,F,F,1372,532,455,F,37,756,F,356,1237,F,717,1112,60,670,962,F,F,363,1433,687,860,998,959,645,375,1267,1260,1283,F,809,F,F,F,1280,645,341,214,1480,21,1512,1433,1502,430,483,F,491,F,F,F,1199,1266,1324,1154,1142,247,532,14,418,1275,524,1235,36,1473,814,1175,772,F,F,1361,731,F,F,772,1075,111,1322,370,153,1011,487,947,710,295,629,146,1392,430,505,804,F,394,446,352,F,48,1136,1252,485,1257,956,430,1245,1266,505,474,886,1069,154,1441,F,1419,1109,482,1117,1447,47,F,F,F,606,57,1309,874,224,920,1445,886,121,57,1124,480,433,517,171,496,791,383,321,F,F,1142,207,1257,237,1266,579,1433,1139,450,207,672,530,672,932,872,1441,F,629,101,921,F,F,1170,651,963,633,671,1088,376,1433,869,511,951,F,F,1488,916,510,64,493,402,F,70,1395,645,627,683,1071,731,493,246,44,F,633,F,1266,901,974,661,1504,F,532,1052,1311,F,1282,F,602,921,1341,838,627,1375,F,1342,145,485,422,1282,561,1483,429,1260,965,412,1261,412,399,119,1315,1301,1072,568,1261,1047,1364,1320,914,1496,1361,1486,633,1265,1264,1342,1047,1325,502,1257,147,1080,546,1346,432,1343,1257,1039,511,1338,1359,974,1336,674,588,1444,F,F,F,F,1279,F,207,1297,12,1299,94,172,1411,F,1343,885,569,1073,605,1209,1257,651,920,1250,634,272,720,603,1237,277,577,6,1385,471,1343,753,1486,331,882,F,57,629,670,684,412,1440,350,974,1438,563,142,521,532,485,651,F,1061,974,1015,186,794,1445,665,869,F,F,41,998,1265,620,1274,341,963,F,1044,1447,1150,999,1121,1351,710,772,60,F,1094,1154,420,1037,998,F,242,916,499,1151,F,978,818,645,257,257,1452,F,688,228,1257,400,483,801,1343,805,1366,1233,407,1400,2529,207,1266,501,1195,344,876,F,1359,958,1317,85,60,1080,1411,1343,60,F,280,601,1340,118,150,1309,1304,1365,1297,F,F,F,1142,404,F,F,F,663,1282,135,492,1149,888,1389,1307,1266,810,F,F,F,F,F,730,485,829,449,454,1233,1360,1399,57,729,934,1282,318,1343,1149,620,1357,F,511,1067,1347,484,F,1124,F,F,772,1233,733,1124,1343,1049,2017,483,684,1359,712,1412,1131,1448,60,627,F,F,F,F,F,F,F,941,394,1257,1441,1355,814,537,1282,1333,1282,1445,792,F,1110,662,137,730,974,226,1054,F,F,F,1124,1282,78,262,1496,F,1090,1065,420,1149,360,F,F,F,768,730,1315,720,493,206,532,431,327,1078,134,1072,843,905,704,F,F,F,F,1343,155,F,569,39,618,555,1047,57,1129,363,888,1343,1266,801,1350,1459,183,333,1304,895,1254,640,98,1494,1509,60,1334,461,860,1297,F,619,953,1277,499,405,F,F,1323,1311,1457,437,1350,1257,F,F,632,1267,461,1344,F,634,1054,98,44,492,1090,318,252,F,24,1365,F,F,779,619,1343,224,F,120,130,353,511,791,F,F,F,641,753,1361,F,658,418,F,1358,511,147,992,825,F,F,444,1175,F,5,385,63,78,956,F,1090,180,470,130,344,468,295,1238,329,1150,F,678,431,1357,470,F,1365,1489,1325,959,1430,517,1145,F,F,F,F,F,F,1210,648,517,1412,1288,452,F,1304,F,F,F,207,952,753,F,1395,52,145,F,F,1325,363,753,44,529,257,1430,F,1253,1325,F,532,1265,1145,419,F,251,485,505,552,1500,F,1282,600,316,480,1260,814,728,978,204,1345,730,F,532,962,1190,1230,1432,628,1061,565,1265,1433,1445,1178,1087,44,1338,892,122,452,560,518,9,183,930,44,21,329,553,1216,1216,1510,651,F,400,1322,1080,447,645,1044,1112,F,44,992,272,78,635,931,327,485,1489,468,430,627,1370,1375,1297,121,246,618,510,151,1112,917,1333,495,460,463,1157,1007,1246,1350,985,1345,1080,1345,532,1212,1307,486,1450,945,1460,253,F,1362,F,F,1341,1079,1343,753,F,F,974,1274,1253,371,1351,1186,1079,799,371,1142,1248,524,121,895,1480,449,783,1323,379,1366,446,F,1110,145,452,1329,1245,1268,901,998,665,611,1052,1274,1399,415,316,419,119,1422,F,1141,1460,825,420,1365,1480,1362,629,480,1257,1265,120,632,F,1110,521,916,959,375,1485,1365,F,F,994,823,715,21,519,F,1304,905,60,712,F,F,F,F,1341,1365,F,905,962,40,471,F,F,F,85,706,596,1199,1253,629,651,F,485,537,1492,286,532,224,44,F,F,F,F,F,596,70,1129,1199,1310,F,F,F,F,F,287,F,F,1131,57,1198,1039,96,1199,732,509,687,F,F,1422,56,483,1403,1306,629,F,F,1131,1355,1089,F,F,291,F,F,F,F,956,682,1441,1212,381,1366,619,78,789,890,634,1155,632,1240,229,629,1186,1221,1456,356,1289,16,318,884,78,1216,1087,1341,78,945,1195,375,1190,418,142,505,463,1291,252,115,267,886,1011,645,1075,851,248,1365,171,998,546,1154,192,894,176,672,1190,1444,629,1023,861,1110,F,229,450,1343,1268,1282,682,658,F,1150,355,F,1150,F,F,F,1360,774,F,357,537,148,378,1229,629,656,645,1262,42,11,1367,524,1002,1315,1483,214,96,1408,1355,344,1011,F,1258,1090,501,501,1301,1425,F,F,1104,137,317,483,1445,F,384,1255,F,945,1090,412,1216,272,1485,978,761,344,651,483,1297,1308,770,1317,507,629,218,1005,1369,687,1071,628,637,372,1286,1279,948,945,135,1360,85,581,974,8,946,934,161,876,592,1340,389,1075,889,F,1456,465,468,450,44,F,F,1410,485,383,1257,360,116,696,1460,1209,1475,1265,605,F,F,F,1448,8,1267,404,1258,F,1209,95,1037,1265,507,331,976,F,1334,234,487,619,1323,670,1208,1350,888,684,629,68,F,712,27,467,F,1334,429,184,430,820,155,629,1155,461,57,21,135,1329,218,18,1311,F,1483,217,979,35,214,1229,1142,39,353,1323,1257,1460,1319,318,1362,9,1208,723,1067,511,383,485,939,501,1323,1260,545,732,1309,1052,1250,929,463,992,1441,1172,532,1280,923,14,717,383,F,1157,316,526,122,1107,57,712,1109,383,443,456,684,423,487,968,350,464,84,383,332,761,8,1351,1107,637,505,148,505,154,906,136,1323,273,248,F,637,1268,63,1291,724,1338,730,923,923,1250,1282,1367,928,142,1333,1349,1327,483,1488,1306,741,664,541,1333,1324,1133,401,467,1350,1075,112,632,F,1307,171,135,801,965,735,480,1365,1421,450,109,63,930,1257,496,566,698,707,1421,59,482,532,778,57,1080,1109,753,645,740,753,1257,120,963,502,480,1265,1304,823,1190,446,1367,F,150,78,1500,253,1479,482,928,F,1291,471,1078,569,F,278,1332,1365,37,505,1435,486,1079,247,266,188,344,741,1438,1440,F,1325,940,422,375,938,690,391,596,1015,312,215,1323,374,505,389,405,1136,1249,1444,814,255,599,1142,207,1317,402,F,F,245,F,731,504,741,525,1365,1438,1430,1413,1288,F,31,430,380,442,610,1255,77,561,672,201,632,1340,941,1088,F,1309,509,946,470,1124,175,281,661,F,782,1150,227,1237,354,218,629,86,1268,862,596,1457,809,457,1350,1268,603,751,24,F,400,56,341,480,1343,658,1326,1344,530,478,142,270,291,F,1324,1475,1413,922,1365,970,478,814,465,525,1067,F,F,882,744,101,665,627,174,F,205,1055,226,921,F,600,651,641,994,1365,1343,254,922,1341,803,341,492,1318,319,1015,1265,F,F,57,1078,910,803,1448,1158,1174,1174,1007,1343,645,12,430,956,629,399,1486,1124,1372,1320,112,1229,528,1187,1361,471,492,1013,706,525,1483,523,9,1131,598,476,969,123,287,563,786,95,1168,1303,521,465,923,505,710,1322,F,1447,1208,F,8,866,99,1154,303,476,921,159,1136,1480,830,1199,1069,666,63,627,702,1283,107,1985,716,359,632,235,1421,1483,F,F,1448,24,207,958,F,661,1265,327,405,142,521,295,150,1375,442,1333,1317,1332,1190,1413,1362,1313,1333,556,463,605,1375,137,F,F,1072,F,818,744,441,174,1378,1306,511,1480,1364,1149,546,956,F,142,518,1313,1442,150,861,941,F,963,601,1343,998,1110,929,1112,F,311,F,723,456,F,F,415,941,161,974,280,629,24,507,1301,684,F,1378,1450,452,1346,F,1447,931,F,353,493,1460,1460,571,814,1015,1399,15,1448,376,1343,145,482,1457,611,992,998,1442,768,1633,F,F,1341,532,67,142,537,270,1237,1343,1433,1323,F,1028,678,905,1429,418,1367,224,1430,325,1047,651,1142,962,706,1338,39,401,387,775,363,F,1078,553,1136,188,1456,1145,577,949,1301,273,104,462,191,1026,1444,930,509,1488,1237,F,F,1260,768,916,1118,526,1438,1060,1157,31,1142,932,1245,998,684,450,1119,1450,916,740,509,1060,730,1090,651,619,496,623,1448,254,F,1029,381,318,717,1131,492,1154,1282,569,1253,316,684,93,109,651,1340,192,1377,723,1367,1448,1342,227,480,1236,1150,1039,1282,1119,1116,929,656,1343,F,620,627,327,645,650,1268,1277,849,728,1265,982,2608,1099,1323,60,651,442,921,642,32,57,450,450,F,104,876,956,744,521,85,715,1029,1238,F,F,627,969,F,474,1272,F,1080,1350,F,775,465,521,1322,F,1023,1204,1282,1433,719,1260,702,F,465,1150,1277,1338,57,682,316,629,206,163,235,246,583,159,1265,122,728,932,959,1441,F,F,F,452,353,147,391,761,78,456,372,1332,712,1233,F,F,F,1005,1311,1442,741,496,F,1422,1510,1375,645,F,1456,57,992,1367,F,186,307,1343,729,953,F,1233,485,84,F,66,316,1375,627,316,963,343,306,290,1442,1172,1367,511,922,524,598,134,43,824,1343,1303,756,1315,342,F,815,1185,51,1412,1235,553,1331,852,1274,372,1154,401,471,1139,F,1332,217,923,510,690,730,730,491,672,316,849,728,505,343,69,467,1121,1332,814,509,633,78,491,1169,651,1500,1077,1346,235,155,86,740,1324,602,151,499,1099,967,814,684,F,1077,684,1458,F,1693,530,517,307,1343,1015,91,990,342,601,1131,1365,1360,251,651,1460,1142,905,1417,499,179,85,568,212,F,419,419,757,452,374,245,342,1309,728,715,607,384,1430,1142,1367,1490,627,672,1253,618,484,629,627,F,907,1328,1222,1216,881,F,1430,400,F,1302,770,974,1237,1442,265,1237,78,F,463,1309,1387,629,1324,465,1313,450,40,979,1274,910,642,1453,1343,1304,684,552,161,F,768,418,130,672,1421,1142,341,441,1407,939,1124,900,394,F,473,159,F,306,306,1011,922,1112,524,F,1324,37,1338,1483,786,177,24,100,1170,420,1510,24,1433,1222,371,57,308,1460,1255,1241,1448,1456,672,1241,402,959,596,1385,1119,731,247,923,101,896,632,1077,661,1124,923,1372,333,1301,532,248,892,393,823,992,1441,352,888,1151,130,184,429,1466,753,67,923,1080,56,532,1112,F,385,768,470,1257,308,1297,756,F,1258,1448,1018,524,1791,1435,1063,724,57,420,1365,1268,865,792,94,85,F,922,485,1480,672,537,1268,1257,91,1242,1446,1486,588,191,1174,161,245,184,958,1433,1410,1002,71,483,1257,1457,532,363,482,217,136,1137,1020,1272,466,921,1460,1133,115,1246,553,552,383,350,318,191,101,1448,121,618,1297,1418,1433,1365,401,374,1387,226,480,1118,1152,381,1260,702,285,20,888,1255,7,723,888,723,1328,1448,78,1350,1236,983,601,1322,121,968,1440,916,F,1145,327,1089,F,231,214,1149,1173,147,1142,488,1107,465,641,F,F,136,511,295,378,342,287,F,295,49,1157,248,F,248,85,1229,1430,678,921,761,932,F,1488,1118,F,1360,1453,1142,F,1124,85,1258,496,103,344,1165,135,344,327,1254,1257,1328,746,867,707,1042,723,102,1177,541,29,1276,1286,923,F,F,1062,463,822,1275,137,226,332,1346,14,979,996,702,318,962,1079,430,60,224,753,243,F,F,580,F,114,286,1361,428,F,385,1313,620,510,922,962,1233,1340,640,F,F,1323,1343,1345,921,1433,1260,1343,1336,1253,1445,1448,419,186,341,183,905,576,155,F,1217,956,191,359,573,962,962,1445,725,629,1453,1142,1445,384,636,449,596,251,190,1349,F,F,921,1480,114,712,272,1344,116,1015,434,1011,341,600,1291,491,1343,716,F,706,485,1134,420,F,629,1485,1497,1332,362,627,922,376,629,74,1134,F,F,1124,157,491,1280,44,1303,514,916,651,1271,1512,255,183,952,774,1415,688,1341,1062,1365,455,627,853,F,F,627,F,F,1100,F,1343,820,1110,572,344,1340,242,978,104,F,1168,947,64,1131,1236,281,1039,8,923,1507,579,326,F,1347,F,1036,270,471,1282,1399,1150,1154,1448,1343,342,291,F,537,486,112,1266,704,F,57,651,505,587,487,F,141,607,1288,327,686,1412,430,482,800,1350,1277,1165,611,1399,585,F,930,524,894,316,1194,649,728,1480,1280,452,728,504,1382,192,629,978,1458,1345,420,F,1343,674,1375,978,651,808,1367,844,F,1343,844,1343,930,1262,1701,1345,730,1257,768,544,1497,1262,1323,1199,1167,1252,1136,1345,151,1455,707,1370,843,735,1397,1229,627,962,768,1078,57,1483,37,F,530,1272,585,860,580,1315,1413,1332,588,468,1257,291,1327,1177,1360,532,627,F,627,141,485,451,1422,343,124,393,524,723,123,1152,756,1288,628,1322,1118,1077,1343,76,191,446,1229,248,482,363,418,78,1297,656,94,94,1343,1307,1323,1397,420,1356,1488,F,546,1365,920,1435,694,F,F,1100,511,394,916,650,F,1186,495,596,1343,1355,190,1324,505,1314,1238,1267,818,344,363,F,753,1460,770,1267,1241,629,94,740,491,801,118,1227,1304,843,700,1502,545,538,423,F,F,1367,1238,1458,F,F,1343,F,253,342,56,1459,1485,1090,1262,799,F,501,1315,150,830,998,1448,2371,F,1052,1367,F,509,F,672,417,66,1343,1504,1422,1367,1229,799,393,532,51,94,F,287,923,1331,587,1010,446,1315,1282,F,587,F,1280,78,556,205,1304,28,849,1489,F,1170,160,146,809,394,333,1282,242,1236,532,587,1409,1023,286,651,723,F,406,723,661,F,1352,F,394,201,627,272,F,63,929,1257,F,237,237,1268,632,F,1063,1280,1077,1238,F,F,431,1360,672,600,1351,1075,530,923,492,1379,F,923,F,650,483,698,175,804,70,629,651,356,142,1306,1267,450,54,1496,223,223,476,1025,135,1168,F,840,1448,656,327,501,392,1258,648,1306,994,1159,887,1286,1055,1448,1222,1195,1172,1340,1282,887,1332,1332,843,428,2269,1347,317,774,1331,1230,1371,1262,1456,1371,1080,733,1257,485,1158,327,1313,799,188,730,60,F,766,1367,295,1447,990,1304,690,471,1316,782,420,487,270,456,1198,904,201,1257,1117,728,1286,1255,956,1423,1158,1291,521,526,476,1168,702,1325,482,1085,619,1229,135,96,507,1362,470,1412,1124,363,783,1260,F,278,146,1236,1433,402,128,145,1395,471,674,640,610,1215,468,1255,20,1067,1129,700,1152,1286,513,10,1142,120,1238,1205,485,137,135,1367,1267,1283,F,F,F,783,F,1325,959,495,1116,537,640,524,F,703,645,F,161,147,1271,946,716,1090,117,147,382,1365,1344,F,656,610,1090,1433,1099,468,F,F,295,F,1047,1490,532,537,1203,665,1236,150,1460,645,F,1433,1429,F,1343,158,799,77,1125,1342,428,1320,461,704,704,963,611,426,1450,741,1266,1441,1089,1510,1460,380,1309,1343,1448,1280,511,94,F,85,635,1445,485,1229,394,523,514,8,344,401,448,1325,1011,1448,65,1341,1136,257,401,1076,1316,139,1065,951,F,F,184,442,267,141,1237,1005,1089,89,483,1382,921,1322,344,1367,342,910,1445,1151,1510,134,963,1362,430,448,400,295,497,1378,1196,209,1198,344,1510,450,F,78,1427,533,1153,532,344,465,182,1357,180,1136,146,929,89,1665,702,93,923,493,57,482,1446,1458,963,1421,483,58,F,629,629,1375,968,141,344,114,1156,1080,424,945,921,78,768,1196,159,201,1375,1445,137,161,57,723,22,1173,741,644,334,141,961,1177,342,592,492,F,F,F,1441,959,1512,148,585,645,44,274,1254,F,1480,672,1153,F,159,636,1174,589,124,532,1199,463,327,57,F,1261,1250,485,965,586,450,958,1131,89,F,961,888,866,1222,1332,998,1314,201,257,148,214,725,1308,286,67,1435,159,122,401,277,1494,244,598,1165,1375,968,1458,651,136,1440,344,1067,1178,573,6,F,956,1090,413,1054,1226,1421,662,518,629,F,182,998,1375,532,548,317,921,442,342,670,442,1216,741,1173,530,922,1443,953,375,1173,605,712,1347,672,1369,524,888,F,1280,60,473,1457,998,1033,1252,114,561,1055,882,704,1297,F,191,561,1468,120,1110,150,1131,44,542,F,1448,1238,741,651,1499,814,651,923,1375,F,1343,1259,137,F,999,137,825,1360,485,78,321,F,F,199,247,499,1365,430,1304,1367,962,F,29,371,517,F,1320,1090,1360,1117,1338,99,1332,1090,1323,1096,642,191,1367,78,1129,F,1325,620,648,1167,272,1375,484,F,1377,F,F,523,525,158,137,374,1273,1267,9,400,1366,619,F,1199,137,1289,959,424,F,230,91,247,1324,1483,F,1349,120,F,627,1136,695,695,F,1154,874,665,920,214,1199,295,94,505,1341,483,230,532,56,620,1343,183,183,910,627,1385,1147,910,199,526,1304,318,F,1304,308,478,1457,979,311,528,417,635,1445,730,1364,F,107,715,1344,732,1199,585,F,F,730,998,1367,934,728,524,887,510,1234,485,723,493,1313,41,354,121,629,628,959,283,1352,1378,137,1447,979,F,690,540,400,1375,471,888,112,286,120,1044,1080,1067,1289,1350,1080,148,1338,418,327,1338,1324,1501,1118,509,287,1268,392,1157,945,121,418,725,1375,201,932,510,1055,760,1369,F,881,1444,1448,183,1366,756,1230,497,920,1124,898,1173,579,201,1131,F,504,493,18,501,1338,F,1338,661,1396,39,632,F,461,688,1236,1267,1183,78,1444,1458,42,723,1281,849,1359,F,1276,629,1411,728,F,1336,F,F,907,1281,F,F,F,1055,1479,F,1055,532,485,504,F,809,18,161,1255,393,1282,1187,1313,231,1419,1151,881,1280,1304,1268,1112,575,1444,1253,477,1018,1242,271,461,592,344,169,1266,947,943,601,F,1320,1349,974,422,184,1448,F,1237,1323,1273,1343,800,1444,169,F,1078,261,1485,532,1304,1370,F,F,1303,235,1173,365,1341,442,1340,F,628,569,1267,1128,1260,1309,F,F,245,598,1453,805,142,493,57,1469,651,428,37,1154,145,696,1268,1098,1355,962,F,916,471,1236,1342,1338,F,135,426,70,F,1268,120,476,F,420,188,1445,921,585,1002,F,1349,1294,F,450,206,F,974,246,1255,958,F,1325,640,55,F,70,F,1369,844,39,1351,442,188,928,1168,1367,618,39,F,485,342,1268,107,449,1039,49,950,1366,1219,7,418,228,363,246,480,865,F,1473,651,698,700,632,1274,1312,1441,910,344,828,1260,280,228,1378,1268,1346,1087,280,52,452,326,327,1382,44,324,1265,1080,2234,1421,1421,1419,471,343,1229,752,939,641,F,740,449,442,1365,921,287,14,F,24,248,1309,248,57,1456,870,1182,1341,F,487,1448,1199,1280,228,1177,1282,126,1371,393,636,51,F,672,483,1309,1090,271,1118,450,1379,121,35,998,291,1245,24,333,1364,1435,329,393,84,363,281,465,272,1169,78,932,645,661,1238,1422,601,1128,768,57,1216,1460,256,84,524,910,1262,1237,910,217,315,119,452,1382,F,F,F,F,F,316,1282,442,146,39,1345,F,512,78,1011,156,1349,1340,351,588,1378,1440,1319,523,448,741,26,363,60,1479,428,1440,1075,369,57,286,183,165,1030,142,978,138,712,874,1235,887,343,1479,920,648,1340,719,1253,923,257,138,1262,430,1033,385,446,17,343,938,476,885,1323,1110,1258,744,586,363,F,20,1029,1100,665,1440,471,120,F,650,766,420,272,511,731,316,295,128,442,442,1367,1313,869,54,128,1361,1341,1313,1023,1304,629,629,1372,280,480,1047,623,912,449,405,85,1013,1238,1118,12,1365,1270,437,1327,1274,1332,F,57,F,437,1158,656,F,1460,F,1260,1390,1343,270,1370,521,F,78,1168,1351,F,1341,803,1063,48,378,32,753,350,304,1066,F,1448,1329,493,1372,1104,1168,1237,1316,1448,1343,991,1080,450,786,1335,493,1130,1351,40,450,450,1338,F,1329,632,1257,304,278,1389,1460,1351,1351,510,175,228,F,577,1343,1338,492,304,818,188,931,1313,77,729,1104,751,635,922,922,1084,342,78,52,67,1342,1238,461,316,921,710,344,14,15,1848,921,976,1216,1343,78,890,22,F,1307,F,F,1367,146,670,1341,629,F,809,1260,1253,F,620,915,1479,1502,1480,123,12,306,1367,625,344,1411,474,184,1118,54,57,1411,F,430,629,F,420,1385,382,142,665,753,730,700,20,1433,1458,465,316,244,1190,F,272,1250,1238,485,148,648,63,661,492,814,682,1069,F,385,814,1343,569,1228,1219,928,78,550,651,355,385,413,580,437,584,1399,1185,605,57,463,910,1333,1230,1170,1130,573,280,19,493,751,587,577,12,696,952,938,F,551,428,286,1265,770,1134,507,885,853,1114,124,814,702,1114,190,1265,592,F,246,913,1177,1496,1249,327,92,882,1025,F,1002,921,214,862,78,702,1489,190,587,485,601,F,723,731,862,670,1500,518,656,1342,1241,629,629,1409,1459,474,1070,148,1291,1233,265,480,887,449,717,134,717,128,524,830,F,1343,1005,651,1320,F,923,1483,F,37,374,1399,507,1365,947,44,21,1216,1326,939,1361,1448,507,753,1074,1055,921,1055,729,375,1341,373,373,1195,341,1311,1338,1186,1177,756,656,93,627,1087,672,480,216,858,656,526,1422,524,1442,1496,1268,1448,F,F,596,F,F,596,1301,373,291,759,1452,1168,1370,1419,373,1245,605,1365,1118,1412,413,455,1421,123,665,122,1448,1238,1307,1397,741,399,1121,F,F,1110,287,107,579,1165,789,610,671,1341,1282,1324,951,915,155,1266,393,505,600,723,1338,F,629,1347,182,958,1165,1365,F,F,224,274,442,F,1260,F,921,F,1370,483,1379,321,374,423,1441,974,F,F,507,886,355,1307,1073,1078,940,188,257,78,253,1229,188,1447,27,1254,40,1758,22,1190,F,374,521,401,190,1361,1370,611,524,342,814,291,291,1290,545,1325,1199,914,51,744,1104,1325,921,1370,66,F,1306,446,465,1331,530,585,295,483,753,151,40,1255,1441,1304,1142,148,1257,192,694,568,1325,94,F,431,242,978,1190,1367,1273,782,1107,329,916,651,19,461,1341,461,723,1349,619,1325,40,257,651,1077,499,645,512,524,1167,888,357,1274,1224,169,246,461,1333,629,728,449,1073,486,1345,1235,F,894,672,651,1343,90,1055,449,1088,1215,753,455,1184,75,882,474,341,406,85,627,122,886,214,723,1136,937,1446,583,55,18,723,1268,572,1194,1203,1237,1265,F,1203,611,121,801,801,627,266,526,932,78,1051,1410,1159,932,826,1343,514,354,246,492,717,218,492,1367,1282,1386,706,627,403,1314,1142,1435,1329,1205,1056,1260,938,1238,1352,163,963,1219,F,1445,1187,1917,1056,88,342,1184,1142,1142,1480,418,905,430,1473,1456,78,656,843,1257,872,248,429,1170,1226,1170,921,485,146,24,511,2108,629,526,964,596,383,928,921,1268,492,1078,491,6,455,1410,1399,1333,1419,485,114,1325,491,F,1324,F,499,1190,774,1375,F,146,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2541,1798,551,2311,1058,1264,419,2243,1425,1028,2389,1264,482,1619,2557,732,352,156,156,1466,2073,885,1080,1080,958,75,1338,192,265,1110,142,264,958,636,264,1361,636,1323,76,2371,402,518,1897,1317,935,2602,484,507,332,394,173,121,648,1480,1459,21,1228,226,2477,1459,513,2144,525,898,342,1341,1343,771,1254,520,520,1215,2200,1343,1340,1445,2486,1413,449,311,2135,1345,904,865,939,451,389,142,1681,1342,1346,1321,2237,520,922,1337,1258,1270,352,520,1264,452,1087,269,1079,482,778,486,526,1078,711,449,699,676,1483,1006,1311,1324,343,1044,767,353,1136,1365,208,1434,1879,1448,399,353,676,649,1343,532,2136,697,2556,1442,1080,1080,308,161,1365,584,1365,1377,452,921,1254,513,1112,1131,370,370,1320,1279,1320,921,1320,2016,1194,1232,549,220,499,415,1343,121,436,760,1339,1272,512,1186,637,1272,512,1338,2339,78,1362,1282,1756,632,286,2219,990,990,483,483,1232,1343,2404,990,615,261,1400,2064,2309,1696,21,1424,509,507,74,994,1728,337,1029,678,74,97,2571,1080,1139,1425,344,1265,1265,1215,442,1190,992,929,354,1891,78,224,2164,1342,131,124,1021,124,1341,761,2218,992,317,131,1328,930,1452,887,1250,1254,493,2030,1333,332,97,2357,1232,331,245,321,1450,922,876,1365,255,283,1255,1343,1285,549,1340,483,8,1254,485,342,311,1297,511,885,227,341,1155,1452,1359,479,1998,1366,207,1377,1029,1237,1712,132,1318,2330,1056,123,678,1631,1316,1286,1237,1460,1401,1266,832,1552,1914,800,2263,1282,33,1304,651,1456,1069,962,1725,49,2413,1868,885,1343,1112,1342,1442,1784,417,700,228,1460,85,962,56,1427,189,1238,245,1460,1511,1362,1328,2446,1422,430,57,1216,1065,1365,1343,1854,2617,378,820,1190,800,1265,963,2551,1221,929,1081,539,38,876,469,431,611,1271,363,1327,28,312,745,486,1834,76,483,434,478,400,968,1176,501,190,1343,1079,1289,1069,1215,544,1446,350,598,1341,147,574,395,629,1344,1080,729,1457,1304,1362,9,672,756,306,678,1803,114,145,1316,1904,1453,1340,1005,210,1262,1112,224,687,1143,2051,1438,1636,939,577,116,820,825,510,1254,1971,517,143,1441,1512,156,946,687,524,1090,1187,1072,2464,78,774,1274,1594,1209,1366,1260,201,291,959,1302,397,572,1254,537,1343,343,635,1497,941,629,1357,476,514,932,1030,875,1122,342,1257,628,1863,904,40,2421,2317,1262,1286,1297,1366,248,132,155,1448,1324,2153,629,598,1110,492,1297,343,480,2072,1277,858,493,65,1704,327,335,1320,11,44,1367,1283,1584,451,123,1445,76,521,1332,207,2153,1229,598,97,1490,1897,392,44,1174,1087,1087,721,1765,1150,2087,179,1291,882,1658,448,1342,920,1170,355,2069,507,1128,126,505,321,1446,2110,530,1488,526,932,799,678,1479,1248,683,1114,625,476,265,1486,48,1254,526,771,91,492,1418,1335,1446,1047,950,820,1349,141,930,1324,1011,1452,184,2028,2019,1237,1366,76,1020,1168,1235,889,1325,332,1155,1250,295,1280,134,1075,544,248,1512,111,1186,44,1282,465,1333,1422,1698,1322,1360,493,1303,1410,187,344,54,1448,1489,732,483,1342,1282,1315,1624,275,104,1438,851,1193,1193,44,2567,2177,505,1237,331,124,1930,1119,1448,1124,1261,344,1372,999,629,833,1379,496,696,37,250,1154,428,505,1257,1055,932,532,1631,161,1029,44,1277,1357,1332,1152,1134,1328,311,76,486,223,1386,1155,383,70,160,838,93,619,205,1355,1395,1489,49,1116,20,1712,1366,1418,1496,1056,176,514,148,1046,420,1423,950,1325,248,1282,2177,44,896,2064,634,672,702,929,1265,1152,1350,267,1469,1273,1055,939,517,1209,1507,916,1257,610,125,395,640,920,141,120,1237,482,77,471,172,1183,228,2051,521,1042,331,1268,524,295,499,493,2459,650,78,384,1265,1124,1268,495,742,1338,511,2031,941,888,332,1456,8,1027,1341,537,825,120,1343,231,513,1306,577,492,161,226,501,1046,1386,96,70,10,1005,1145,155,116,601,800,510,932,723,1254,818,956,800,124,645,619,687,582,41,1367,63,1389,1448,1112,1359,426,953,139,629,1165,1237,662,160,120,982,1087,471,629,682,1389,838,1155,1324,618,780,1831,2486,1378,1387,1370,1293,150,1430,1293,1265,395,280,556,280,732,1201,124,306,280,1833,509,1201,1112,1324,1324,1079,F,230,929,268,328,710,1069,268,F,512,628,466,1007,1232,790,968,636,1365,1539,374,2168,1257,421,601,376,1172,392,2510,74,2011,526,251,1723,328,1328,491,1085,485,1343,485,121,516,712,979,790,1370,711,356,979,104,516,104,1386,387,517,712,1456,2211,380,1303,732,730,999,1345,2506,544,535,825,1341,728,1080,1925,723,1451,526,1369,744,568,648,344,1281,730,74,265,1147,356,1852,74,452,1687,532,452,583,1337,624,864,342,742,267,1267,645,928,491,514,1118,716,1199,920,384,1477,1114,514,2155,953,253,651,267,355,492,1344,197,6,629,177,745,1477,205,1110,285,511,649,649,818,1257,272,2008,316,316,316,335,523,1701,1442,332,761,1447,342,332,905,332,541,465,541,353,244,905,931,1293,577,1198,1535,158,482,231,418,418,2574,232,253,232,992,992,174,1847,2338,1343,482,543,932,211,159,1242,482,227,1289,1984,1228,532,627,1375,645,656,1399,356,1714,342,158,965,253,1052,742,651,1450,864,67,505,505,1570,629,1052,1601,1653,513,385,371,235,177,584,569,287,308,1448,2423,2349,1648,1720,556,505,401,190,401,541,287,485,1170,513,665,683,1399,1369,216,2501,2107,2120,930,1044,177,387,493,216,627,1167,327,2307,1653,921,177,1486,356,1227,77,482,284,951,2385,271,493,485,1564,1322,526,477,1076,492,285,275,1252,387,344,1076,493,362,218,541,1714,170,121,1204,672,627,883,1052,2302,566,1664,385,938,532,1984,2579,1480,632,526,2294,656,401,501,401,493,493,1153,477,485,493,1343,493,1448,120,492,751,627,1458,629,1320,970,33,374,486,1255,700,645,511,561,1280,1447,267,1460,832,505,963,1063,1343,1457,753,629,2066,610,610,530,567,1327,1219,1277,756,580,505,645,430,1080,556,2066,361,78,742,148,605,1357,1357,732,556,1314,530,951,672,85,724,148,2134,542,732,267,1304,1304,543,1255,1343,1314,1246,1076,610,761,672,896,1080,482,947,497,1664,970,1273,1343,532,315,528,1190,526,226,1280,700,1314,1314,688,629,135,982,970,38,1061,1377,518,41,1910,1255,1377,1241,1293,352,352,38,191,1343,1293,881,523,1158,363,916,295,870,342,374,218,521,374,56,1982,43,783,1683,319,521,1341,1381,497,549,497,580,449,1262,962,316,400,945,1392,580,326,449,1366,400,1937,471,226,401,632,632,1126,272,521,532,1260,887,2281,1340,553,1324,59,801,2281,1078,1316,929,805,1023,1496,1073,1254,471,33,1080,1260,1230,1981,1280,1230,42,1731,2611,1280,1650,700,2251,226,483,78,1096,1617,583,60,84,2586,2096,670,1361,671,1257,388,1250,1282,505,505,1238,14,956,1445,711,1347,1235,1063,483,974,675,147,2083,1282,1304,510,974,1255,483,295,950,1257,1028,1530,1237,295,1185,629,1433,419,629,1319,2521,1325,1067,247,1412,866,1318,945,1318,1448,1635,2208,1168,627,1067,448,1185,1502,216,327,1370,104,1370,1270,1324,629,532,2380,250,159,521,510,18,400,1325,1110,629,125,601,629,1323,1324,1370,1110,374,648,1002,965,965,307,619,271,1268,1466,1028,1626,1626,93,93,8,224,1362,1641,483,1361,1099,317,1082,391,22,1835,1020,1080,1087,1480,964,1085,60,1304,2029,864,1119,483,1238,1119,257,1015,192,567,383,1911,653,387,1764,568,2599,502,2394,21,261,2106,2437,148,1079,1362,959,909,2505,1955,1110,1152,147,615,253,482,641,441,738,2513,706,145,1897,1306,1331,1485,1898,483,255,1736,2457,744,448,629,2462,1273,2580,1963,1337,687,1517,2197,851,477,1340,535,156,650,1211,1345,327,1586,949,949,507,85,1855,1540,281,328,291,418,1185,1953,1106,922,442,2601,1346,1253,1253,1662,768,1313,1257,178,268,1242,447,441,1253,361,2522,537,687,1827,363,715,1525,2220,142,1253,361,341,502,441,1685,1073,2253,1211,2191,1343,1751,2279,629,1554,2562,404,1244,2332,1252,295,1077,529,879,1242,2261,2190,653,978,1359,247,1453,1080,1456,1184,1260,1343,923,905,1485,1915,1721,1238,1303,1515,2260,1871,873,1343,1274,1069,449,746,218,965,2078,418,1381,1215,284,914,870,1587,342,1326,430,2568,1960,412,521,1357,344,217,1456,1221,2095,381,1870,1513,85,661,265,818,1143,1110,1268,480,923,308,295,395,1413,1260,1341,2161,1483,738,2226,1447,1333,482,1456,1889,1090,2567,1277,1945,468,574,458,1158,1266,295,2515,1297,404,2526,611,1340,5,902,1071,1190,1968,1293,284,2467,1941,1384,1362,259,860,1272,5,368,580,2520,1740,1274,57,2002,803,1980,1292,577,286,328,485,825,755,2549,428,1370,663,914,706,362,1823,145,1063,2142,2252,1496,430,569,2500,1268,610,77,1433,1410,637,21,738,645,1128,342,84,417,1966,372,1108,364,1362,1325,381,383,45,418,1134,183,1343,1523,487,1198,1266,1229,629,1257,1154,1512,959,132,2247,1398,1319,268,922,246,949,2199,753,1908,269,965,2128,2156,1136,1398,463,608,1044,482,1497,1248,334,2067,1975,923,1085,2476,1091,126,306,629,937,11,1399,1353,805,1364,1174,600,1047,1257,1218,449,6,2589,829,559,1480,1480,1056,248,1965,648,1514,91,1270,1211,1254,1243,207,1047,383,922,922,1158,228,228,1338,1485,56,207,1709,430,2520,922,1433,324,2156,1266,886,2379,2119,1399,1349,388,853,1434,1039,1469,814,404,2184,1323,245,968,1654,1608,263,604,1277,524,1154,148,1168,10,518,228,538,2552,1238,774,1055,1367,1433,2117,504,446,419,1794,1453,116,1224,2275,1367,1344,2567,1331,2277,732,450,1378,172,471,463,463,1259,1958,482,587,1451,1236,1047,1303,465,285,814,1306,637,1367,2371,145,939,1325,1650,880,93,627,1353,1644,1235,734,1350,2290,84,585,1258,1367,504,2175,572,1398,452,1168,1332,431,1519,1300,2332,1039,1355,1124,443,1280,1528,1134,697,111,415,2104,1742,1033,136,1007,1118,1219,482,867,1252,931,1080,363,1483,2057,612,1245,1222,1112,145,426,2432,F,1944,1136,947,814,429,1446,1027,2246,364,766,1781,1521,934,1189,57,18,18,632,1502,2592,753,1120,1119,1151,1774,920,502,150,499,541,1152,1052,101,486,6,1277,896,2175,1865,1918,1274,449,471,404,851,1265,1399,124,2408,908,1767,697,696,450,621,271,346,1153,1337,49,1349,1025,502,730,1277,1980,699,978,1710,881,2128,1277,482,1459,1667,587,1503,1274,1110,426,1862,640,939,1257,161,1654,228,1964,1316,294,1507,315,145,468,1389,175,201,228,1367,1211,1638,502,1335,1257,923,426,632,1301,242,468,1345,915,531,947,1315,814,669,1110,1324,1352,1740,1419,845,1456,511,825,2002,1282,923,295,1398,1340,1080,502,1372,1521,1355,2088,577,1366,877,235,347,439,281,229,1283,1025,885,887,1344,1503,818,246,603,1139,2006,1005,425,1963,1338,284,888,155,485,511,426,1170,124,1314,713,1622,1170,671,471,78,1359,814,1345,452,714,441,1433,627,656,416,778,1536,751,1325,629,670,661,751,228,139,901,887,1273,480,751,1260,286,572,1323,120,1349,2351,251,594,1142,1274,2047,186,460,480,1469,814,1274,88,627,121,118,629,1343,2183,2258,1382,1121,1259,1406,491,1382,1459,601,814,777,602,659,1236,469,1344,959,1112,815,2037,469,1286,1344,773,1204,1204,1817,549,1369,517,889,1377,191,450,469,1370,291,405,590,191,1189,1199,1236,678,405,975,996,651,384,405,1144,405,1199,1362,405,1345,476,917,1366,418,1370,678,2081,1366,950,405,1711,1236,1370,967,569,917,1370,1370,1320,1199,1199,1199,1204,690,471,1343,1993,674,674,1200,1320,1200,1187,1076,916,672,577,1317,1386,2478,362,2466,1252,399,887,1341,1771,929,929,1441,1480,231,928,1264,1052,583,1656,2318,814,753,482,487,1447,1447,32,1314,1343,948,715,535,999,1212,1843,1581,48,1149,544,1989,1512,561,57,513,248,512,485,577,247,512,491,1150,629,24,1255,329,1475,907,33,1154,588,962,1151,1445,1216,353,905,252,1922,799,1145,885,516,1328,337,20,672,958,760,2070,380,1313,22,1682,135,651,1460,344,449,1448,179,593,662,662,670,20,224,870,743,1289,1802,485,431,688,188,147,619,350,1344,448,278,1430,342,395,1332,1820,1820,400,112,1327,1345,311,380,1370,257,1280,559,1057,1084,295,76,252,442,1317,574,221,539,231,541,423,783,1529,1288,1268,1372,35,1856,24,1343,1347,420,1304,179,947,372,5,51,320,974,1357,537,486,248,2201,607,529,142,1052,509,1433,645,645,1620,142,454,85,1078,1314,404,516,1337,805,245,1367,85,1317,968,1131,2296,950,1229,526,679,1442,563,1692,265,224,1152,11,92,161,51,544,1446,286,1343,1446,1343,874,482,1477,921,1037,526,799,569,556,1154,588,801,491,1814,510,356,1367,295,882,384,1201,625,323,1318,932,588,12,1069,1821,783,1198,142,1344,474,57,634,404,257,1469,448,1568,40,1365,1776,710,504,1010,1829,373,543,1488,1365,465,295,1332,1325,41,1722,715,1656,273,1216,1347,333,1452,507,509,438,356,182,492,905,619,1273,464,623,277,1227,1306,485,483,577,1350,1139,142,1357,541,1124,1124,1078,730,1749,1246,142,1199,1154,974,1451,629,1451,37,2366,1394,278,1173,1255,1444,1314,363,1441,8,374,1323,544,1173,1370,1240,1282,658,414,606,1656,882,52,137,672,671,849,932,715,753,1466,1100,1088,666,146,704,63,514,104,1090,1448,1425,545,1355,252,138,1446,1260,404,936,511,248,1056,761,205,2525,1141,1407,930,935,635,1238,1475,938,1407,1301,1055,1055,22,916,577,266,316,974,753,281,281,1506,248,1076,287,287,1150,244,759,329,465,1150,221,1338,1460,493,20,935,482,938,559,1343,886,57,252,495,1337,1355,1311,1150,602,526,458,231,983,932,1314,1268,1259,431,8,2521,234,426,1010,511,619,583,670,1323,1150,1237,458,662,662,1015,629,648,983,120,1314,1323,618,24,1227,1080,990,1031,1472,1472,1073,1340,700,2105,1460,1472,450,450,589,1340,450,1304,589,1085,707,1506,1085,1340,1447,383,1701,497,333,44,1415,60,1128,975,651,344,216,1264,1296,1282,782,1264,585,1257,1226,1372,711,1124,284,284,1338,951,1226,380,380,923,725,725,1345,479,138,1747,1400,1172,1147,1856,391,1331,1326,1582,360,1077,1157,1147,2460,1324,56,1341,573,1867,285,456,582,1378,1867,21,302,632,463,245,1324,872,530,2011,772,335,1280,331,251,967,585,1494,463,2323,540,2580,1578,1343,496,1160,2573,47,1257,466,326,253,1316,49,252,20,1064,1246,1943,20,1255,20,496,632,285,1376,496,1080,331,480,57,674,286,842,831,262,771,929,491,1139,520,834,114,1957,1265,318,484,1109,1005,324,1234,442,1470,344,693,226,992,341,514,1323,415,1243,1450,853,274,485,561,1452,1331,511,1377,737,340,145,1375,1470,821,1325,768,1283,329,56,1365,1217,332,2471,1843,1254,1367,399,274,22,798,1454,1480,1427,218,2255,1372,1195,1266,1446,290,717,753,2312,57,1069,945,290,430,1303,311,1442,741,33,760,341,651,1485,1485,1079,979,1052,1326,702,506,381,1112,1291,2475,1483,526,1052,900,992,1332,267,495,1087,483,350,1273,455,528,499,380,2131,491,491,1341,805,1446,482,482,1268,437,395,535,573,1325,745,645,876,295,1362,1323,114,1069,1344,1078,400,968,1483,1114,1235,442,1220,665,1320,2354,499,674,904,1268,1063,628,142,1282,706,341,1134,715,1237,556,186,186,1187,806,1289,774,1365,2254,912,789,528,1069,1448,418,248,1470,291,901,1210,1268,2235,1253,1323,1254,1524,1323,1365,1112,1365,1219,629,1266,523,964,1475,920,1266,1480,265,123,672,7,290,290,665,731,192,914,524,908,91,651,1229,64,1274,1088,922,468,318,1249,1013,1150,324,324,505,1172,799,968,514,473,512,929,252,1291,452,1227,598,57,1344,1694,784,344,514,678,12,601,588,1345,1320,523,629,251,1266,454,456,1349,120,1071,1186,231,1333,1255,776,1718,488,1193,1304,1367,1236,248,1002,716,226,1011,946,468,1250,930,182,735,344,506,275,1341,1452,715,465,731,9,1349,1306,504,1235,717,2561,1442,958,1080,1282,1217,634,712,979,1110,889,1238,1219,201,450,19,505,40,1301,1193,399,159,1332,888,1258,1370,1352,998,1007,145,656,716,862,19,693,380,587,947,489,1036,1438,1370,504,998,744,1349,483,1124,809,1266,1157,866,605,783,39,8,888,901,1343,894,1367,618,1307,2203,1340,1423,546,1355,801,627,246,399,1322,510,1466,124,1399,417,791,612,751,1432,452,452,20,791,935,697,899,381,1254,939,1217,1421,735,1266,1266,753,640,632,456,399,242,1446,1301,1340,456,1257,587,2354,1257,1325,120,499,716,318,315,1265,1343,471,502,344,1080,57,1055,1131,935,633,1991,1283,809,266,1343,93,8,806,818,693,1178,155,511,188,1365,901,998,1005,771,1322,1145,1349,932,809,1375,1350,731,55,2194,1071,1291,801,272,657,1369,602,1325,1099,651,501,806,602,2324,1349,1099,471,968,729,627,674,1323,1458,602,2615,505,532,532,564,1379,2196,1486,210,1132,342,1575,1483,1277,1286,725,1112,1144,38,485,381,831,1311,1362,1468,413,674,1132,781,738,192,929,1088,1630,1317,1483,800,341,1483,627,1311,80,1005,770,814,814,1349,674,731,2271,999,1139,400,1416,956,1366,1084,9,1198,1117,1228,1004,1333,442,1341,513,1476,1858,1459,231,442,1488,392,1456,263,1229,1341,40,1078,1078,152,1071,556,1306,1080,1362,463,1341,1178,1079,1268,374,142,976,374,1274,1385,1413,40,415,1325,1274,2024,1071,137,998,466,730,568,578,70,2430,1625,1389,485,1369,485,1345,730,568,950,431,1438,493,344,818,76,461,717,948,418,1367,1078,818,511,818,1448,1366,40,578,818,948,753,112,526,387,948,449,1255,640,1078,2271,1418,1071,1237,2506,578,471,640,537,461,1343,1341,40,946,152,40,332,212,280,1112,2509,234,688,280,1085,909,332,1466,341,1067,2107,2041,2041,1466,2481,1506,2509,1090,280,234,1276,505,2393,307,307,307,348,491,1087,137,1058,1058,751,347,124,642,1267,1267,591,2472,1231,1360,642,642,1332,2207,1231,1231,1231,349,1332,287,587,1451,521,353,383,353,1208,353,353,1077,1346,1669,550,799,2064,2479,2268,524,888,109,1260,54,523,507,1173,962,1170,507,1252,254,1077,1079,1607,482,1282,1440,1282,799,1421,1257,1237,703,290,667,905,1170,327,2420,1282,1199,687,687,1259,109,687,526,1282,526,531,640,532,2420,1260,135,2465,801,1052,1219,1265,629,295,471,471,661,1891,922,992,1255,420,1069,1366,158,1131,2097,992,1375,32,1333,14,1318,1255,505,295,483,929,329,1228,921,107,930,921,114,507,962,1881,1268,20,601,234,21,1512,1512,1328,526,356,555,379,1311,907,629,1177,2075,1323,342,1300,488,652,1216,886,20,224,583,1375,962,452,910,741,12,1177,651,146,905,265,420,584,1300,711,1190,1311,1343,60,430,21,684,295,344,1315,257,672,303,306,350,967,1802,1341,760,1078,9,1236,461,2416,730,2149,485,1190,1236,1362,927,1262,628,1332,2054,1442,674,499,291,291,1367,1280,82,941,975,332,332,782,628,1360,1268,998,234,1069,142,1199,372,537,361,1262,1345,2489,607,545,610,598,1267,974,563,151,151,1142,648,456,523,598,921,741,588,588,1496,384,205,1318,1318,357,678,678,623,532,284,1442,404,1345,265,418,1442,1237,1275,886,1322,1114,505,49,1496,569,265,1421,384,1345,1483,1400,465,1365,2469,1327,332,959,1327,1168,1342,1448,1080,1385,2536,295,1460,543,688,1324,716,418,482,482,463,1186,1076,715,2100,1255,1365,1488,601,555,1323,1323,1237,1488,112,1131,998,553,946,1365,921,666,1199,278,1257,1246,97,231,998,505,541,656,1255,1114,938,1483,1236,49,250,214,931,1357,814,214,2013,1078,1020,1116,1488,497,640,546,121,257,106,262,1198,666,1425,1421,1421,18,101,962,934,205,1503,234,234,1258,1367,876,661,1273,109,77,946,499,1322,610,1422,648,640,640,509,244,287,1506,2054,401,1332,499,1332,532,2387,1343,1311,782,1338,1338,1341,814,1267,483,1282,555,1257,248,20,1503,1235,1341,998,234,652,505,1366,1375,1346,1008,505,629,399,661,661,250,998,1257,524,120,1351,584,1323,1235,782,968,130,203,674,250,250,814,1323,1323,1324,585,1324,170,577,170,1453,464,512,1315,129,129,645,374,1511,940,526,375,526,1252,919,919,1643,959,959,484,2425,1112,21,1445,1427,1954,1341,510,1316,530,21,1316,509,342,1381,57,1860,85,261,1096,1839,814,1077,328,856,1447,1257,452,228,1236,1425,1155,224,753,876,856,2453,77,632,1448,1455,78,1448,248,753,1343,1343,905,928,2083,1005,1096,224,2595,1104,941,1438,1077,976,1258,35,224,399,1695,905,1425,1030,1227,224,1236,124,1047,921,1399,405,712,273,446,1444,1301,730,1236,1250,342,1343,35,905,257,374,862,466,1157,730,489,1165,468,1450,1052,704,761,63,405,1399,761,35,1425,513,121,342,1448,449,315,1715,57,57,1424,730,938,119,329,723,35,1695,740,159,505,1267,601,1877,905,803,491,1605,76,1291,355,1331,463,1362,1359,2008,1531,887,1185,1400,396,1470,751,953,57,947,283,175,400,1319,29,507,1304,670,1254,1470,572,2548,1770,870,252,1317,737,371,190,343,1190,866,327,1271,1342,1448,1176,1448,1297,1812,1512,1274,1199,400,572,706,1186,1361,82,76,143,598,57,483,9,1090,546,1355,1217,1114,1090,952,1367,1366,737,1118,1635,1270,327,521,295,1931,658,2380,632,605,1118,1448,85,952,521,521,2065,18,592,665,1347,642,224,672,1343,159,120,1199,1110,1283,737,125,1254,327,396,572,577,57,935,1282,649,649,640,670,485,1351,1265,1185,1355,627,1185,1346,1315,1323,1186,246,2285,493,469,771,469,375,805,540,60,1343,923,2173,331,525,1324,1343,1394,57,1343,1340,308,1028,1080,308,1080,1080,374,255,1346,452,342,442,1252,1208,146,497,24,1071,248,1423,532,1157,343,247,728,1266,450,128,832,514,1440,1341,729,967,1227,1060,1020,1306,514,253,1423,497,2042,882,1761,2042,57,57,1067,228,492,380,362,310,57,566,492,69,1274,1761,404,2042,442,728,404,1227,532,485,485,399,1762,672,672,1206,471,1448,471,471,1341,1341,1341,1341,1373,1373,1052,1289,1241,1190,1325,1325,1367,145,91,63,253,70,882,1357,893,1423,1351,145,148,1480,1217,483,1844,1450,1343,1232,135,56,245,651,342,2474,1442,199,1233,514,1750,1257,1316,434,1327,1988,688,448,1233,143,1448,1302,514,1199,192,1449,2125,192,1768,858,1259,265,485,124,1448,1726,1453,2125,1367,1282,507,493,1080,488,60,465,344,1315,1237,866,1332,1235,1257,1442,896,1168,237,1442,2597,67,237,150,135,501,471,2048,468,715,663,1270,41,963,1283,1287,57,1343,615,990,232,263,351,485,991,990,122,1151,1161,2444,353,923,1080,211,1448,2473,706,1257,315,1349,1174,741,1242,1450,150,1255,483,1254,1260,487,1359,1230,191,2426,577,1367,60,1448,921,207,137,1147,1212,930,805,474,1293,823,581,1265,1283,546,449,542,331,457,1147,1116,1254,852,126,177,526,1343,40,128,741,873,1512,1404,1329,526,33,833,782,2596,856,85,1181,452,1979,526,218,632,2367,155,248,224,1341,1198,1360,341,483,881,1291,1372,799,391,342,1260,57,1359,945,1309,191,75,466,1304,161,57,1090,1257,1149,1357,1489,280,754,1447,1343,1080,2262,1315,1080,1260,611,437,580,756,1447,1280,634,1176,466,257,428,564,400,437,1257,501,1090,1110,449,958,1329,471,469,148,487,1341,1293,391,650,468,1486,1304,147,1058,843,435,302,556,1801,1173,374,967,1257,928,1375,881,559,237,471,1826,1278,1192,1322,541,104,783,1379,706,1357,1357,1369,885,589,2333,1375,1367,1199,507,1257,1433,650,1170,420,428,945,1170,85,1343,932,470,1257,44,702,1340,436,1116,967,143,2113,1255,1255,1359,627,637,463,191,1343,1375,629,815,783,295,974,1307,929,1255,742,192,326,42,237,207,126,720,629,485,394,394,1291,235,920,563,1174,678,1257,544,402,801,951,155,281,406,1419,512,1229,1369,509,485,601,1367,480,430,968,1150,1170,1170,814,1233,186,449,473,1257,1660,1283,1236,471,1826,1136,1489,491,1357,252,526,95,142,237,44,945,94,228,394,287,783,1379,1272,1475,257,465,184,956,988,1288,104,59,742,1488,1168,940,155,44,1306,1235,363,929,1237,1367,1365,57,1306,463,742,57,1343,732,1357,542,231,1344,295,137,712,928,556,1365,8,945,1324,840,354,1379,1489,1025,625,331,1349,587,587,974,374,1377,1124,1124,921,1332,1116,467,483,383,526,177,801,1280,541,1443,1357,102,1316,1072,78,542,1372,1260,476,1357,1328,629,1035,1157,1344,188,1304,2327,1147,464,1379,1072,745,377,1067,192,893,761,761,405,148,95,94,94,205,742,1161,1423,1192,20,1100,704,394,974,1398,521,471,541,632,852,1116,947,1347,688,1056,1238,1204,702,929,1067,1355,953,546,248,1446,665,530,920,920,1367,905,640,194,1359,150,1448,1192,141,923,962,882,44,66,956,499,1407,148,632,905,587,471,939,142,1347,1347,1259,2491,228,1150,287,280,280,1124,532,104,1274,315,331,610,612,150,417,923,1266,742,513,641,1254,95,532,201,1268,1151,1074,885,1343,161,1265,782,228,1151,513,1114,420,501,1238,1306,266,947,947,526,102,559,1282,2548,20,712,1343,649,1039,537,457,720,602,8,649,1322,592,1264,148,1366,1347,222,724,8,723,280,921,752,601,720,155,1448,840,840,1322,1328,78,1448,583,582,1361,341,656,740,142,472,122,724,602,457,1307,984,122,485,526,460,1067,1343,634,775,728,1155,532,358,1884,1472,362,1375,1255,491,2348,1090,998,1973,142,1249,507,362,491,934,480,2332,1422,267,920,1869,257,1402,487,484,1445,543,483,585,352,243,1422,2332,362,492,505,1367,492,1324,672,1973,1422,1260,1973,185,224,963,452,452,452,295,1080,1170,711,452,629,320,1136,1593,252,516,1057,1341,1342,2382,452,324,1324,1084,1086,90,2566,959,615,915,1541,1743,993,317,1007,1386,1215,1425,254,1883,1364,569,1880,1069,111,1215,383,568,1255,241,929,1446,992,592,719,2373,1327,823,33,134,986,1257,930,1556,487,1365,342,20,1257,885,1447,1448,295,241,1429,142,485,1324,581,60,128,523,1242,450,1375,532,1542,949,227,1443,1378,1228,786,1343,1087,1461,913,1194,269,549,2397,913,343,869,22,1537,1399,1204,566,2181,2331,1380,452,41,75,1447,881,774,85,885,1145,1333,1440,1410,1326,41,429,800,1338,247,148,885,486,2195,717,136,1317,154,962,742,161,486,342,1412,1459,1754,1623,760,802,2118,343,869,33,857,647,766,390,930,526,2436,22,1215,1215,1537,523,1479,2287,1427,1551,29,247,800,526,592,662,492,926,1355,601,2270,77,2575,929,433,1921,1080,505,1443,816,375,375,968,1097,210,2564,551,1341,1280,104,468,900,2540,2399,766,26,146,388,1448,592,286,286,2598,945,12,827,1441,363,502,575,267,766,2452,645,1410,687,257,1219,532,644,525,1448,674,1320,2484,1142,2026,782,1763,501,2596,484,468,1266,1366,1520,1215,838,216,78,372,1169,1441,142,2362,2362,561,716,827,523,882,492,1343,1187,1052,2360,1229,1280,111,333,501,2488,537,521,1191,589,480,1199,1479,913,2185,21,420,2390,811,528,1400,1090,1336,532,84,1228,85,1508,1338,1415,687,1118,1215,609,1133,35,492,463,234,1237,1230,947,883,1066,645,741,719,343,28,2072,233,1249,6,529,1375,1489,136,179,505,1198,48,768,804,1019,1510,1250,920,1265,142,250,2373,2181,953,356,284,1085,255,2308,247,1424,476,484,2442,925,921,2284,1087,929,651,2539,1320,532,1442,636,388,1343,480,2386,1443,2189,91,1152,135,74,504,1170,565,1207,1324,216,1453,523,1175,930,560,26,854,504,671,1923,747,505,2600,1757,726,1629,1035,394,884,1372,840,492,1442,518,492,1365,1323,585,775,441,1002,888,1235,1025,1494,1306,735,1776,811,111,1080,1489,1441,1340,1315,1356,58,1327,463,1324,1389,11,1301,1320,1250,554,1708,483,1170,594,596,137,540,518,518,1199,504,468,370,153,1274,1790,1279,1370,930,1336,111,1410,42,1332,1235,53,602,1243,949,119,1887,2174,1489,370,501,380,949,998,974,154,166,1421,1133,1132,78,161,998,1563,213,1035,553,1332,234,1445,833,593,491,1118,960,360,1267,1109,1033,511,740,295,179,840,1052,1142,1412,1154,862,31,217,629,1157,450,1448,1219,455,929,1243,2331,1173,1438,295,1280,840,968,112,1413,363,1254,304,1067,547,1067,1087,28,1332,72,1118,1149,2365,121,1134,518,150,174,1923,1606,333,1094,248,923,1118,1415,633,141,145,394,672,684,2174,1489,352,452,1410,176,1156,456,205,770,2194,495,399,1351,1446,18,1448,814,704,122,566,158,1067,1204,501,2238,751,1433,1629,561,1596,497,1332,380,929,642,482,1349,531,897,2304,609,281,1268,1010,401,1389,1343,1266,141,141,2361,782,444,1110,420,397,217,1507,804,649,1443,468,1472,501,484,100,227,1757,135,77,134,531,343,2157,48,343,941,77,1737,1480,1469,1237,915,949,281,804,455,1280,669,501,202,1142,420,941,2484,492,354,1355,2137,779,671,1055,1480,2575,915,186,482,1763,1039,100,951,951,463,504,947,576,1755,1280,538,887,1550,20,526,1338,295,722,2427,728,484,1145,1480,234,1290,602,86,525,1336,1006,1338,1338,800,1250,505,72,2270,362,2600,1448,592,751,493,1280,645,1149,28,2427,671,690,986,2447,861,1328,620,86,1087,1389,804,1267,537,477,629,596,462,1350,670,662,929,929,1733,929,601,1265,1349,715,983,119,1246,202,1280,1067,682,537,728,145,1389,674,1149,1501,629,250,1219,230,501,532,602,629,779,1445,401,400,920,1315,915,915,1082,551,1359,351,1342,374,353,31,322,1444,910,250,568,742,1255,384,430,104,1277,729,161,363,246,1304,502,742,137,521,1069,285,1366,148,18,29,1304,2044,285,633,814,57,125,251,284,1343,354,2368,555,1325,1815,482,1195,2502,284,501,514,1327,1262,742,2419,6,938,6,1443,246,1441,341,2419,640,962,1296,1342,501,1055,501,1480,1343,633,57,627,2502,1277,1241,1311,921,921,1415,70,532,1415,605,326,31,31,601,1366,601,1237,1805,1073,642,488,450,1280,488,1366,1438,502,1250,1178,270,509,148,1345,343,934,1421,963,1480,1421,277,1739,1110,1283,1480,1480,947,648,1480,161,277,1459,319,121,423,2487,1077,876,1360,717,866,921,1419,710,687,876,885,656,341,321,2516,512,512,800,1496,1430,1342,656,1060,493,1365,1342,921,1448,315,893,315,1419,577,1131,1365,2241,485,485,485,480,996,228,521,1447,1397,1280,1176,1315,1304,346,594,355,420,1145,248,1301,121,1078,583,1327,1078,1234,741,741,1211,182,1254,1377,44,14,1400,32,505,588,1073,452,321,428,401,123,1306,744,473,328,948,449,1343,1257,1283,1323,1400,321,1150,1072,526,1327,1389,75,1288,1352,1309,910,1440,651,182,428,717,1510,753,60,1304,473,1427,1490,1080,1080,1367,327,1793,711,801,125,1240,265,7,75,14,1456,661,1267,583,1178,129,1078,1995,466,1308,585,1301,501,511,1448,511,1057,1190,443,1325,350,1272,1051,1276,1338,2563,468,418,420,537,1229,1268,588,1456,1257,1681,1076,82,1433,1433,1255,1229,471,428,137,1229,1174,1480,1504,1455,917,513,1257,1054,800,1257,951,922,512,400,1443,1343,2600,1533,1229,648,637,123,1233,1276,1390,324,1306,373,1341,1262,1376,468,1303,742,585,1335,1352,1089,1237,1089,951,712,774,492,835,12,1327,182,1332,1136,917,744,501,541,1886,1246,126,923,428,1325,629,1527,485,485,720,1390,1282,428,761,753,191,801,1423,471,1571,420,1307,171,640,1265,1151,513,897,648,1211,2491,1343,485,467,224,1338,1338,629,1150,1190,1276,327,1071,1430,428,1343,1272,1288,1069,501,41,514,1325,8,1338,1005,1089,723,1314,1334,1571,629,137,583,257,641,1325,480,670,1257,998,661,779,683,674,1051,1155,1324,1458,1373,1373,2346,1338,1902,1338,449,430,1087,101,101,1073,704,108,1639,1170,1504,95,1304,1998,1346,945,328,886,1375,2554,1011,882,329,1857,651,326,963,1170,843,1178,1109,1441,606,606,1502,744,464,1234,1211,1667,482,2010,1349,1488,1234,1190,606,610,723,661,761,243,1238,2213,47,1411,1090,2422,761,1457,990,21,2303,286,286,232,629,400,482,518,56,1299,142,190,1044,1007,1382,968,929,1365,1876,1252,1642,2377,1315,316,1255,1485,628,1291,90,209,992,1599,1215,1780,1425,706,148,1343,352,374,274,627,922,1090,1882,1177,498,731,1230,598,520,706,1327,696,736,1112,1370,423,327,42,505,265,360,1333,1265,160,182,854,1087,456,1283,1697,1460,156,1114,32,1114,483,1250,511,380,482,710,886,57,1233,16,319,329,1343,342,774,1257,452,1317,269,1284,1440,1331,648,1015,294,715,1430,406,1445,1727,1379,1505,1073,1087,1397,248,629,670,492,142,1114,934,332,1419,1274,1265,569,905,1145,1259,1447,390,1274,489,486,1909,1565,753,1343,1338,1338,1080,814,56,1822,1341,651,75,800,593,430,33,316,1450,224,188,1328,341,1553,757,353,920,979,1002,712,1061,1114,1435,1262,2553,1069,1936,1218,2582,774,818,1357,247,1448,1410,1646,228,381,85,521,17,342,492,21,287,553,772,1460,57,657,116,2384,1112,1706,873,1080,390,1410,1333,141,521,1080,1445,657,715,629,998,2384,1397,63,1422,1448,661,267,670,1073,2152,601,1357,1090,1315,1097,923,1438,2313,629,1341,1271,1441,629,1039,1921,543,1580,991,2055,28,991,76,1483,155,1343,190,1303,1457,493,1504,306,307,1361,311,375,551,611,1419,645,1344,1329,1961,367,1343,1080,1887,1384,674,342,505,437,401,1158,1928,1236,2112,1005,12,9,530,1341,1479,569,1448,956,1190,1032,1032,461,2059,521,1313,287,1475,1365,1389,F,1349,505,657,1422,1317,985,1438,231,920,939,456,1934,496,1470,1315,1134,1044,1438,42,1185,592,514,1614,48,342,1013,1191,532,1257,605,657,332,920,1242,535,354,1124,635,959,2455,1361,715,35,663,881,1470,248,1306,1199,1398,17,384,57,246,418,1485,1445,992,44,372,492,463,1229,838,486,1177,485,1274,687,474,2374,1637,329,1114,725,1253,627,627,270,948,1351,1134,523,1167,1282,589,1480,1087,119,318,1237,514,627,1603,1264,337,1158,1448,598,632,492,1479,651,627,923,75,678,191,932,731,921,921,92,403,120,237,326,858,37,1560,473,1488,142,1397,483,629,882,1367,1367,384,537,267,1154,356,1233,248,216,316,141,1422,922,1369,1324,1367,967,1343,1040,991,179,2140,920,1479,342,553,598,1492,1492,1430,392,328,329,1671,951,799,1229,406,672,426,504,2542,155,525,524,1681,1510,635,934,1446,1716,1317,523,1573,499,1480,1483,70,882,263,160,123,718,454,492,399,1260,272,932,235,401,251,682,1445,971,746,343,371,884,1055,1341,1217,1040,286,1335,344,1237,1235,277,488,1488,491,1341,2405,1258,1325,1324,171,491,182,1366,430,1646,1250,890,54,1331,480,1301,1020,1327,596,1323,47,468,585,507,585,1110,332,1279,1217,1448,493,761,712,160,1977,450,634,623,1186,774,1365,1360,715,1116,1309,1309,1328,1438,890,1792,483,505,1338,160,1816,1365,1494,1235,715,1170,483,505,2058,958,1350,1003,465,665,615,968,1270,902,1079,352,1150,602,1240,1365,139,686,525,1070,162,54,1282,488,1343,1421,342,840,730,605,998,383,493,525,1139,1333,1438,1558,1044,1370,1485,744,1124,489,1332,505,467,355,326,1413,930,696,1133,1370,1282,998,1078,1445,205,1240,1186,656,998,1154,974,1415,1110,1076,1142,553,1257,383,920,360,360,1132,862,1157,363,182,250,830,483,1109,380,179,934,112,931,457,715,1304,358,359,1479,1216,939,1329,250,488,2038,1504,234,661,1603,1457,1032,1258,482,632,471,1355,932,406,352,352,1204,456,2312,1040,205,882,1361,450,496,452,463,401,814,1343,359,546,399,399,101,704,510,1774,1470,2135,606,137,1727,627,1297,951,1100,316,1189,394,1399,1124,619,671,635,730,665,129,1124,553,158,1154,63,672,518,1435,1410,1087,1423,702,2240,809,1329,1177,882,1460,1044,1257,968,1967,491,191,482,1322,935,1312,1349,308,1315,1446,939,1502,192,917,1090,456,587,1438,1506,1375,1055,1257,182,252,311,354,751,1254,938,985,650,656,939,1268,1018,316,1421,1216,2129,1377,1107,281,141,1154,723,524,1672,1124,532,532,252,471,482,839,1273,1217,819,1014,1457,1190,1407,329,956,979,437,930,381,657,612,359,159,1259,1076,1485,1031,483,268,512,671,494,162,1370,1142,1087,495,1150,649,825,1346,1258,471,1052,1504,1307,141,355,524,1504,1343,947,917,1323,618,332,470,231,485,1131,80,905,142,160,1461,1934,483,506,488,951,1416,492,935,235,1342,64,1114,1064,649,629,112,723,1345,1158,1145,731,921,1204,1603,480,485,929,800,818,1340,360,2038,1347,830,952,1324,921,730,1430,401,182,482,585,908,244,159,363,731,1359,1448,466,929,619,618,1023,671,629,203,688,740,471,849,686,1448,359,272,1370,2152,327,1480,1119,632,497,159,953,1457,670,1323,629,1457,139,505,295,1121,457,814,1367,661,600,503,1267,399,525,1274,651,1349,491,1346,1360,1350,1270,825,78,120,601,525,1099,1067,1236,192,968,963,98,521,1367,682,629,203,674,230,532,1323,602,601,1458,618,628,24,778,1367,651,398,932,190,460,1283,1365,1343,929,849,1301,128,161,923,542,1343,532,1260,1304,429,1367,587,605,579,1109,1257,1522,2314,920,1707,147,946,579,544,579,544,172,1047,385,1344,1283,1279,1365,932,1274,1338,362,1252,1152,511,849,449,1170,460,1301,877,1259,1277,1707,2400,1055,417,161,1343,295,1365,186,460,1447,2596,189,85,1254,921,85,85,1224,526,930,146,1039,147,1039,1451,1131,1131,629,1399,1365,629,399,223,295,1111,491,1433,753,753,1331,753,199,1326,1174,1073,224,1056,1304,1316,1087,94,532,895,928,959,1124,951,1378,634,1343,340,2417,1338,94,473,226,483,257,1438,1378,1240,157,72,1170,511,1056,1345,253,521,471,204,1343,226,274,495,634,72,272,491,491,1087,849,277,1460,2528,953,1343,1044,941,554,1275,1316,252,470,470,1913,938,482,1343,849,470,277,1340,1274,1253,394,760,716,716,7,506,272,1367,56,57,57,886,886,57,120,710,426,91,886,644,486,1419,1025,761,1218,1315,307,998,1267,524,759,426,959,270,1044,1151,874,524,284,207,54,1028,1028,712,1025,1087,1087,1218,430,493,1142,1028,686,759,710,1190,999,125,917,673,1419,1037,1419,723,671,963,257,2415,1775,741,532,2207,923,897,771,923,232,1265,170,328,1327,790,73,342,1069,265,950,923,1344,1257,415,1328,9,1320,556,950,1320,265,228,688,951,1328,1376,1376,1103,1105,1443,74,1357,231,1103,615,801,1213,1841,1933,1185,1445,959,70,1400,732,202,471,253,420,114,1480,173,1228,318,220,1257,1215,706,959,923,1055,903,1948,929,1252,1252,1316,1112,1006,375,495,146,1252,1202,520,2388,1445,1447,929,730,383,1231,513,513,1015,535,442,1147,969,2007,60,60,355,1243,1450,319,1293,532,451,822,923,329,1304,1304,949,1341,1250,1377,1370,424,1324,1673,137,228,1360,1818,452,480,920,761,843,715,1746,732,730,150,865,56,2376,1447,876,864,1474,1381,378,656,2215,1399,332,2280,629,678,97,332,1236,452,753,717,1090,2077,1382,1215,1216,1216,430,629,729,1341,310,327,1360,1173,1448,1429,381,1419,2529,1110,583,517,523,1282,959,1343,486,1450,968,1612,471,1588,46,1399,1458,615,1359,381,442,353,312,711,1112,449,905,189,318,1445,1124,820,141,651,2288,77,923,1112,2263,524,1023,1460,1073,620,1309,532,342,864,742,1147,1326,484,1357,394,52,1311,2172,670,228,2186,1282,907,1399,512,1345,862,505,1338,468,469,1386,142,1344,1236,448,493,1327,645,1112,485,306,1289,1857,2364,2322,1447,1347,1253,2492,551,1457,497,684,684,12,1802,1170,756,620,1340,729,968,509,910,1237,1275,1282,442,1304,1124,580,1158,945,526,307,1453,1007,905,1315,1293,1448,395,461,744,478,1219,928,2283,1252,962,656,1343,486,514,2035,2040,499,1438,1077,1480,104,311,1998,2018,656,121,474,1976,825,1315,511,645,959,1237,1435,2094,418,35,706,1480,1360,1257,78,270,463,442,1343,917,1351,602,428,607,419,1947,371,342,1253,2150,183,333,1343,1367,1190,610,414,511,486,150,517,716,1128,141,876,1268,1072,1199,590,904,814,420,512,1274,1067,804,1198,1688,1277,1266,1187,291,1124,1211,528,107,1170,629,1104,1112,620,1104,1157,272,612,598,632,1236,1940,1377,463,246,436,1018,493,2587,1039,342,394,1291,1085,1098,1318,186,1425,1338,563,2485,418,1215,265,430,1248,523,1067,2155,473,1142,1479,252,945,237,530,1483,1257,1275,921,383,406,1322,2163,1155,1453,883,428,123,1087,920,319,1446,672,784,524,1158,192,620,1435,2292,325,1114,1174,888,228,1367,799,1364,672,355,730,514,651,678,1345,207,963,457,1367,804,1069,63,183,449,1369,598,2005,950,1322,2035,1172,736,1447,1346,78,48,1369,1243,1020,324,950,1369,555,485,1067,1369,1039,672,1486,272,1340,2032,2233,860,1257,1365,1369,1071,1072,1002,463,1459,492,835,1365,959,1186,963,274,316,1410,78,1250,1940,248,1235,1240,1005,1282,104,1238,430,357,1324,442,1309,729,555,710,1349,1324,1360,441,736,1075,716,1384,474,772,400,148,295,860,715,634,923,923,715,1173,197,1236,93,1203,732,471,753,2512,483,878,491,492,450,335,1270,1343,1347,1422,1078,504,1678,465,1152,1365,57,742,1077,1198,1073,1357,524,267,1206,2050,501,959,2527,2388,661,480,1370,775,33,1361,968,1470,637,120,1266,183,814,1483,1227,1077,703,1350,596,1999,333,2032,1304,665,1236,352,77,1350,907,511,1325,1154,1370,1136,1370,632,1333,723,1477,142,556,1147,1745,1219,2166,378,1035,744,1413,1078,1343,680,695,917,1235,629,1384,1255,1257,1240,934,1399,1077,1124,6,2341,1118,1377,1300,1344,998,476,1124,2433,2264,1139,1077,1007,5,864,1705,159,865,2483,97,740,363,250,428,467,1260,1483,246,1448,2511,343,505,455,362,1485,1157,1165,1128,57,502,471,402,1345,359,661,1448,1325,1067,703,1350,183,688,603,674,2503,70,1149,1367,1299,452,57,63,1448,497,568,1072,1056,245,730,18,671,451,449,1359,121,318,1355,402,703,952,1365,2301,485,1318,129,920,1259,485,672,665,661,510,405,192,667,1446,352,935,627,1324,101,502,191,183,1204,2280,1165,1337,1258,730,1154,753,1056,420,632,602,1219,146,353,333,1307,1340,704,1486,707,546,2187,881,1090,2587,1423,1689,1304,463,479,493,1322,1100,640,206,1168,1329,495,192,1351,442,1299,1090,394,1350,1274,1491,588,1304,634,1448,1236,888,1367,502,907,231,471,505,1254,854,483,861,1236,1124,930,930,1257,672,1260,1316,283,465,742,1018,1124,2130,1438,192,1343,1435,1227,1055,1150,129,1315,587,1335,1063,1199,1457,1022,432,57,1052,120,120,1089,1190,915,648,1236,1039,1039,142,517,1680,456,499,612,135,354,209,444,1110,1090,2291,418,1377,658,444,342,428,430,1266,493,1052,1260,1368,671,601,820,1365,649,2233,1397,229,463,1399,1282,1367,628,1080,1311,651,1230,1483,1355,1998,96,634,252,1338,20,461,1438,120,704,227,1761,1343,1131,888,526,1142,947,482,1480,632,825,404,511,329,1039,483,1128,471,160,1142,1114,262,1039,1459,600,70,632,729,1077,1090,730,820,1350,1350,723,511,921,57,2018,426,1005,207,1250,1157,1346,1346,280,188,480,953,603,2094,7,916,1480,1236,70,383,930,1350,70,592,327,97,714,493,1237,684,1390,688,629,1359,1329,671,1112,1448,1350,272,1233,468,1282,862,1071,63,120,753,656,491,1571,1039,142,383,70,480,1268,670,949,420,1350,998,629,514,1274,1350,1130,1237,1282,457,1313,1457,2172,600,280,316,450,600,1087,654,1350,728,485,634,493,1350,331,648,1343,491,1375,120,224,2352,492,601,316,1101,1369,1480,332,1067,619,601,192,963,1355,930,312,394,532,1325,428,1350,1022,1390,674,1325,627,729,1055,1149,230,501,121,1350,428,24,1458,602,601,779,1227,674,1315,1267,1325,355,1325,1367,479,63,740,395,242,468,1274,1274,468,441,651,1398,1469,520,1413,1282,148,1480,1384,1384,96,1327,923,1450,329,823,1935,1241,915,1343,670,178,885,542,864,1323,542,868,761,130,642,1938,549,283,395,1283,1448,395,395,1237,937,61,218,1262,1442,1458,555,1430,342,22,1282,1282,653,1479,1309,526,1152,1566,517,870,1145,1145,75,1328,1189,1054,1460,2581,251,2477,1078,634,148,466,1453,449,1109,603,1185,501,1304,437,969,645,463,2532,1297,1297,1267,1345,2486,1453,1332,1080,1235,1190,740,1384,542,441,2132,1262,1458,1308,1442,910,2527,469,395,135,468,551,526,316,1060,1338,471,F,1156,511,989,645,1257,342,517,1282,917,1185,1480,1187,1228,414,881,606,2383,1304,332,148,998,450,1257,1087,431,1314,572,528,1274,1257,1322,420,1472,2092,248,1282,483,1255,1322,687,418,1325,463,720,524,1766,44,329,650,588,476,1211,1257,207,2241,441,130,343,1250,499,191,335,905,956,1020,1257,956,1286,1666,1325,1325,1343,532,1367,358,978,886,1296,358,1073,126,1060,1295,804,371,1239,137,431,586,1451,277,1261,468,335,634,1306,1288,465,501,491,57,1349,1459,1237,1203,1054,1257,2273,835,120,1322,517,517,1367,717,2376,1238,1411,511,956,1002,715,463,1304,1430,1235,316,959,1131,1327,645,1459,504,1398,385,38,450,2563,775,1080,638,58,380,1210,1154,130,1052,302,78,466,1280,1260,1255,1257,1379,430,431,1257,1377,1294,770,1054,956,1334,2517,730,632,1350,1254,998,374,1325,937,655,1257,57,63,191,672,491,2418,1343,665,882,1128,1343,1164,532,1488,2559,452,1341,1448,1534,1238,657,420,849,989,517,704,588,1056,204,1407,491,1257,1257,1257,1343,1277,148,465,121,1338,1150,978,1325,1315,938,537,242,283,1069,499,329,1110,2158,1367,648,1190,1060,329,316,2525,1315,603,716,1156,1343,517,720,515,501,1350,1367,1343,1311,601,1147,1398,96,1131,1257,974,1489,632,470,1458,1282,651,1235,1343,1280,1430,471,218,825,601,1005,1267,431,1314,511,155,1766,1334,431,603,63,998,629,753,41,1020,688,596,17,1314,583,1109,640,629,670,532,641,1325,1257,1282,661,1338,93,983,1375,603,192,532,151,394,527,135,728,1155,603,1458,602,651,204,1367,2590,1429,854,1442,870,141,1370,8,2477,421,532,532,1861,1336,24,256,1336,1332,1497,1100,307,862,175,553,1392,257,934,1355,935,2298,32,864,129,491,858,272,174,1365,1411,58,257,36,78,174,1361,1361,272,1318,1676,822,822,903,518,2244,1139,760,610,992,705,319,710,761,356,1255,1325,362,44,1112,493,383,1362,362,1073,760,247,929,970,968,1486,1161,1257,706,561,929,1254,384,1257,627,627,914,482,356,1446,46,968,183,272,526,486,2034,332,889,553,524,552,159,1260,44,684,507,694,1028,1238,2145,281,1190,939,497,1257,629,272,645,858,893,78,1257,154,1236,585,154,969,969,22,318,959,484,116,1480,1532,362,1472,396,2198,1360,549,78,447,1320,1345,460,1472,1378,581,823,246,581,1452,761,44,885,524,1341,1073,870,1262,1216,450,651,327,886,800,1333,1362,379,1313,523,228,78,571,1267,818,461,434,501,430,1430,483,1316,1052,1142,998,1085,1190,611,272,1262,1077,577,1442,1367,1132,1365,57,706,1257,530,627,1262,1345,1125,605,44,1448,1323,1044,629,420,1267,512,858,324,1274,29,921,799,63,1347,598,645,491,934,588,1325,406,1490,728,123,1340,1448,1442,1318,724,89,201,1064,645,251,682,450,1488,401,1237,332,1248,1370,1288,1457,2210,1238,170,1268,1203,1320,782,1279,486,446,58,1360,1360,715,112,1332,1132,78,744,455,1370,1118,696,1370,222,1367,1077,426,934,1343,1438,97,426,704,514,496,753,1423,120,18,18,426,205,48,532,57,57,465,916,648,1304,1190,1334,640,1109,1274,1085,281,502,363,530,272,471,577,1267,1282,1141,1267,1314,818,58,480,830,724,645,783,396,1085,670,1141,1268,728,982,460,783,682,1267,921,532,1307,737,1483,2188,670,1367,1124,2473,959,348,261,615,21,482,442,248,173,353,520,1365,922,1365,1661,695,442,1254,341,1241,507,1318,1604,60,37,1375,532,719,532,1228,491,715,227,901,1237,461,1268,934,651,224,1343,10,905,252,342,1307,1259,77,189,379,488,1061,910,188,553,979,1073,1069,1341,1497,486,741,1052,657,57,1438,1438,532,313,661,509,502,493,629,395,1265,1453,375,1322,1300,1327,1303,684,1124,1457,947,1345,1315,40,307,1273,1332,1262,1952,399,150,1304,31,876,611,229,1349,1997,1241,291,142,248,1254,1253,142,537,715,44,1187,1268,161,418,1307,1323,959,1309,605,628,1300,342,656,1318,1257,651,627,511,633,1136,1136,332,1228,252,901,1421,1039,741,1367,523,136,598,741,1076,1236,1174,161,2612,51,141,451,921,295,588,123,921,51,1229,672,192,393,1324,253,44,648,947,886,854,974,1480,947,313,509,956,273,507,474,1366,712,715,182,1306,1168,1288,224,1002,741,491,1237,1011,463,1280,170,492,1469,1661,634,968,1262,277,1372,1318,783,450,1349,1365,465,1015,1039,656,1077,998,1136,1332,1240,1254,1438,511,1350,695,1157,656,1154,629,605,399,2451,934,213,532,1429,1332,8,70,1087,124,588,1466,191,510,1340,206,191,921,627,513,1136,959,1307,18,633,719,1423,1345,1338,1349,1238,672,1253,242,1300,1407,1315,963,231,648,640,956,1124,465,399,916,513,316,511,656,482,471,513,8,57,96,963,1397,229,501,403,1151,471,461,1039,1131,1173,160,1365,511,670,70,1088,1243,1503,601,1259,1483,1307,1011,1250,352,618,272,629,1448,1002,627,1390,956,1170,399,1129,596,661,670,629,1390,603,1349,728,1270,956,394,235,1390,461,385,78,257,78,452,1446,894,33,982,629,2468,F,1271,929,32,878,321,227,1247,849,F,F,1223,450,651,1341,905,188,28,528,124,145,F,231,724,85,1475,905,58,1456,1438,F,188,1349,923,1266,666,248,849,723,1466,52,650,1409,1254,888,226,1247,1349,1324,353,224,2403,1173,1173,418,124,1073,951,1069,121,121,1013,1073,1121,1069,1358,1095,672,343,1357,50,335,2271,1173,1360,488,1069,1411,252,342,774,1782,904,1797,456,1187,1441,1384,723,57,57,658,1315,656,126,760,1377,318,342,371,1173,507,507,969,1238,342,1173,760,284,864,495,1219,218,774,656,47,1440,1705,760,760,104,1173,350,57,218,1448,690,921,690,861,1340,1840,456,2395,1365,760,537,1343,656,1064,257,155,456,1762,1475,482,1229,495,142,126,1213,618,482,111,656,257,1205,650,495,495,155,888,257,257,2297,505,228,1087,1087,1448,1341,786,771,261,56,504,640,356,362,521,1455,1264,1055,1301,2276,629,1327,139,1360,21,507,532,921,1261,207,57,1343,629,1490,174,332,1460,872,886,353,553,187,1310,1445,1748,1440,311,1447,1165,523,483,327,523,1052,486,1307,1413,76,814,2596,1355,514,968,1165,1189,1341,504,1237,469,1149,1328,148,1448,433,1319,717,270,514,1274,1192,1198,706,887,1274,1125,341,629,1448,214,285,1255,1044,610,1085,463,1266,1343,49,1425,393,1150,327,694,648,145,485,1174,9,148,57,57,741,384,278,290,1237,1364,207,1319,1458,201,1755,1072,1451,1686,1367,446,332,596,1327,137,1199,1366,404,1241,463,572,488,1344,1343,667,1037,532,148,1257,392,1343,1240,483,174,31,471,656,1649,1085,2276,250,1741,1600,1149,1425,63,1072,201,683,1343,1490,154,1425,1418,1120,1039,973,255,667,667,753,947,1346,1351,465,342,640,661,939,656,610,1266,327,1755,1347,431,6,31,1266,392,401,827,1367,1236,1343,1355,887,619,629,1089,228,649,252,649,600,1600,485,145,1328,1308,504,1442,714,629,480,600,482,250,1308,1351,1346,963,1355,1149,250,683,674,674,77,77,400,22,310,242,310,27,28,1602,483,1398,1398,712,1769,853,504,465,399,189,651,359,753,483,501,883,359,6,291,428,420,57,1229,155,932,1257,6,1276,428,467,428,1399,206,428,1276,1338,908,428,501,8,1288,467,629,895,430,502,886,354,872,1456,535,959,209,974,1410,383,535,535,1456,1410,383,1427,272,742,922,1350,1365,42,1427,1450,878,430,1350,430,1343,77,1229,430,16,1421,1323,2032,430,1364,584,318,1873,235,862,343,959,1681,235,672,1421,2223,627,2064,1304,2032,862,394,9,670,1303,1453,231,9,383,629,761,261,355,1301,706,1234,1446,923,1371,1173,2497,282,1283,1260,864,332,283,741,744,2407,1080,1377,2231,861,321,736,226,715,712,2099,1268,566,1080,1326,1442,1333,1069,480,220,1440,583,523,1072,1341,1075,717,753,1460,1438,1438,731,1080,1369,257,801,1486,1486,130,1412,1309,75,729,661,1128,1190,2225,259,248,786,744,1309,145,583,530,756,1441,1179,1327,1324,753,1452,753,2588,1442,715,1134,1063,420,463,248,143,214,530,291,703,1268,1257,590,600,492,1054,1174,403,1229,625,1080,956,645,1318,512,1442,627,600,1131,530,1104,1128,271,57,888,761,473,801,672,1343,505,91,1455,1365,473,696,1264,1290,468,403,1384,184,491,717,273,446,1306,1175,585,359,1015,712,1304,311,1250,735,156,587,2226,1246,568,231,136,553,1119,1261,956,753,744,702,1104,1399,1425,1343,253,566,753,1107,191,664,145,702,895,141,399,723,1230,1016,897,1257,939,916,1459,244,1071,1107,2159,135,1266,545,1338,1304,1190,756,648,401,493,1338,8,471,1419,492,383,1430,2079,715,156,1037,819,1314,1334,480,2221,731,901,731,619,583,532,1306,731,480,670,723,661,394,703,1259,161,1155,545,1459,710,1926,509,1367,1109,1399,532,1079,1342,1071,1445,446,1071,1351,525,1453,2046,214,276,7,2046,1407,1373,24,1760,263,923,482,1485,353,1255,1433,569,356,1260,316,583,231,695,1044,226,532,629,341,741,294,1983,546,1447,2321,544,507,70,295,1320,885,1433,2529,1131,1466,132,283,1221,1325,509,332,1837,753,1412,523,1367,553,1216,1216,247,1418,1438,295,342,760,1460,594,58,832,904,881,651,872,615,910,77,910,1069,1382,8,629,661,1190,1358,629,583,160,561,968,1457,580,399,295,782,928,672,2000,8,1899,2496,1289,2529,1800,881,1257,611,1907,1109,1262,938,954,2477,939,1343,561,1274,974,122,605,441,1365,1274,1262,707,684,1357,132,135,1250,656,1352,706,974,1325,1044,589,1367,148,454,671,138,492,844,1114,1480,561,882,1322,1475,2111,142,921,1490,953,648,535,77,263,2003,253,491,431,2168,8,1131,974,623,42,1345,280,1254,921,679,1229,251,782,44,923,138,1011,1323,257,263,1810,1216,505,1349,59,556,57,1238,1109,1438,277,1262,231,1168,783,884,492,248,1152,1640,1173,923,283,332,1309,974,974,695,374,804,1124,291,188,656,1110,1154,1562,455,885,1237,1033,619,213,1173,1262,1257,2154,862,1236,1378,278,1433,553,594,1466,1332,402,1466,120,923,18,881,658,671,545,176,138,1346,619,63,923,2239,923,205,1488,953,186,678,482,1055,610,963,1407,244,493,1260,648,263,1150,465,862,1382,938,245,629,493,499,1257,1424,939,281,492,1367,1475,430,556,1399,618,505,160,1338,974,231,1342,495,885,885,1367,900,295,8,553,491,1367,1011,723,872,188,78,1328,696,87,1266,583,618,619,1448,629,629,316,974,872,1349,629,661,661,753,78,1099,394,601,87,1323,1080,1080,628,994,1067,1375,1112,921,1139,696,1282,1331,1265,2319,921,1447,49,280,1452,992,1340,1078,1362,1448,1177,342,344,1588,1497,1445,1127,717,1512,962,452,1460,1070,1131,188,116,2228,687,1366,1271,1253,1176,896,1460,400,1262,1445,2023,361,1438,361,1104,511,1072,350,589,248,234,480,1158,921,384,394,1504,651,672,75,2063,234,1446,672,1651,57,1434,468,1361,1260,1344,1483,480,1438,342,1372,1253,1267,1327,1445,1340,715,1110,248,44,1480,1438,1357,485,361,1154,1110,696,1142,344,1306,921,1367,1259,482,1112,1651,228,401,1131,628,825,2228,234,629,982,1375,1168,1390,620,1002,1366,1365,627,1282,947,430,1198,1300,1110,990,1198,1485,112,354,1343,1265,75,803,958,958,1690,331,428,1377,553,736,1445,512,56,1445,1367,1588,572,33,885,799,629,1360,1495,885,78,651,753,144,803,947,1326,1510,1448,1445,1088,526,1485,478,482,1675,1190,1448,478,430,1344,1483,1448,504,991,274,1341,1457,471,825,344,1257,360,605,341,1316,1104,687,589,354,512,1168,142,1199,2390,1104,1320,679,672,384,1510,991,1478,37,29,482,1445,1448,589,2141,882,553,75,155,1504,1367,1121,690,1270,1340,1260,59,485,342,888,840,504,2603,1488,1303,1675,235,1242,1266,1483,1367,485,1304,1440,1448,235,489,2012,360,360,383,998,1131,1001,485,546,761,95,715,1448,485,672,1121,482,1351,1242,958,1039,431,1343,465,945,484,1131,1274,916,499,1479,2458,1505,687,1131,825,1039,471,982,840,1367,900,485,1208,1242,141,480,583,687,63,1039,982,1479,627,203,1311,1219,518,956,1257,956,2111,1364,1069,513,1334,170,1476,1198,610,945,1417,1333,59,39,1333,75,1219,1458,502,941,255,1252,399,1332,1448,174,1334,1178,502,174,517,1274,142,568,204,1248,228,569,553,1480,1301,1121,392,584,270,1482,2518,1248,1219,1320,1365,526,956,1332,1332,1178,129,1366,1173,255,526,642,1257,1255,584,174,1427,579,579,661,141,207,640,1398,204,941,956,270,1398,662,945,629,161,1078,344,929,161,442,921,426,1073,328,1090,737,964,1422,1460,651,661,76,514,514,1423,28,1112,537,442,1190,1116,514,255,1343,1090,514,964,505,904,275,627,1468,109,242,209,1224,514,544,514,1458,1458,615,882,1365,146,353,706,1458,1228,273,482,501,21,1127,483,948,1430,1133,1318,1475,1370,452,423,1277,107,57,56,492,1342,265,1052,1073,217,246,1458,768,145,381,629,945,742,38,1177,1112,342,104,48,311,218,1485,248,651,2576,831,342,379,316,486,354,318,1079,711,909,1171,491,956,2170,742,58,684,401,962,146,1344,1334,1267,56,956,592,243,1275,509,968,1133,1005,311,580,2606,1191,482,1740,423,104,1452,568,598,57,1049,229,1442,104,341,2091,1199,854,627,605,525,393,492,418,1190,1262,1448,142,1127,1080,1460,1510,1276,1060,1186,1634,1323,361,577,353,155,580,358,1377,2282,929,1276,492,913,598,1492,56,57,57,365,1145,390,1364,491,235,381,146,1442,953,1047,1455,672,78,482,648,1127,537,342,1411,381,563,930,929,537,179,393,1369,104,1496,79,1399,945,1218,682,226,1274,1020,493,1306,58,1133,1270,1267,905,1438,1288,450,1341,1460,1373,182,688,1252,266,1109,483,2056,465,1288,717,318,171,1469,889,332,2606,465,945,446,958,736,932,381,587,1081,666,1377,430,1154,1375,154,359,326,1020,1442,378,814,932,1276,204,662,882,273,629,57,1480,159,1049,146,1460,934,661,601,491,85,627,471,57,246,191,1322,882,1628,2608,886,895,268,1367,740,1204,1399,1049,401,1341,452,121,568,201,905,1398,482,400,1124,666,104,672,804,1134,204,253,1134,615,277,1460,1274,78,730,1049,231,640,226,252,343,492,742,587,224,499,242,465,1133,610,1387,1274,672,1080,1387,924,858,921,858,354,526,672,672,1323,1615,229,1027,1461,378,929,632,85,1456,600,1081,601,587,1365,1375,426,1438,1145,1170,814,155,483,1341,921,1165,2608,1456,315,1119,1456,933,1480,1165,672,670,492,1218,1350,1367,600,2170,945,632,601,929,1375,1450,963,632,58,277,1500,627,1110,682,1350,1375,1480,1367,729,246,316,1069,1433,1069,842,430,620,1265,1485,799,212,1425,929,1415,56,32,1255,1044,546,1002,330,57,207,1348,1433,729,1148,452,21,629,353,526,910,753,198,2265,1456,145,1124,1179,629,1257,1124,442,1190,1483,104,1375,2560,648,1470,28,609,331,306,962,430,635,1268,342,635,96,512,628,1375,672,524,921,207,29,1423,648,1490,512,406,454,2369,1029,1154,59,1002,733,446,1303,1490,1972,493,1387,188,627,1282,341,840,44,383,1299,359,1154,960,486,100,1470,1154,2214,2369,331,1395,546,497,751,1029,1029,840,1257,635,497,577,80,461,1089,1490,1268,840,1204,814,629,1512,246,814,1179,603,730,1110,518,2022,374,1443,518,1362,485,114,1456,1315,1373,442,1364,430,1228,992,1242,1241,959,768,1483,1195,823,339,485,1087,183,885,1441,1044,442,1447,483,328,1377,992,227,509,1124,321,1136,207,520,2565,25,510,341,1448,920,1485,155,442,2565,2139,1260,342,1282,1069,77,1460,962,651,1460,1063,355,1328,342,1216,1440,224,161,1077,1450,1266,1497,516,33,963,753,1090,1504,583,512,992,423,1282,505,1457,155,388,28,532,583,450,190,461,371,1157,2060,572,501,968,351,684,1309,49,1268,342,366,267,998,1179,1344,619,1282,530,1304,350,257,1191,1110,497,1271,471,532,1446,492,530,145,732,1441,687,142,959,1087,36,1191,1274,461,946,372,1299,1168,1196,1280,442,1260,342,1185,1128,280,589,341,2068,452,1445,1323,517,333,485,1304,991,1488,1670,286,629,688,635,155,969,1063,921,921,1477,921,1229,932,1268,1085,1236,2320,1158,1229,356,1233,49,1475,91,406,207,1927,657,922,1422,57,186,651,731,920,945,1173,1488,402,1492,1257,1483,1291,636,510,324,1013,741,1367,1489,316,688,1304,1349,1058,924,1304,1270,491,556,1268,1011,731,482,277,151,248,741,735,1370,1282,40,1110,958,58,462,371,191,732,1238,344,1237,1194,378,736,1280,634,1488,60,1379,1344,1168,385,1448,1379,141,120,224,1262,1370,1489,1301,1074,1235,371,1306,1350,511,1343,1475,801,35,383,862,1456,491,187,968,1100,1379,1262,205,1257,998,1157,344,1377,136,360,1007,450,1386,1165,2495,1124,1440,1490,1157,466,92,57,335,201,627,1134,1324,1259,1490,618,530,932,704,1446,687,761,895,632,728,1309,1489,482,1052,1131,1842,688,1583,1340,1035,756,2535,936,474,1265,485,1048,1300,978,1309,1131,938,1407,1511,1445,1055,1029,648,1367,1839,640,186,1506,493,2355,121,1014,1300,471,456,1500,1257,936,1380,221,1074,471,1260,1039,492,495,461,1397,191,1282,501,57,228,1343,826,1131,1343,1050,1301,485,70,931,601,916,1314,1500,921,882,1334,753,620,1280,1500,583,1359,1304,2138,1265,120,501,670,120,1349,90,983,1265,1502,1500,684,2147,235,602,618,634,1110,518,1364,1905,1456,2329,1888,2534,483,1228,583,2018,992,1237,1377,442,183,885,1044,356,768,992,1490,1927,328,1447,2482,321,1460,1441,823,1087,1268,355,1282,342,634,1497,1069,1260,1445,1450,1456,33,342,161,1063,1343,2068,224,36,998,2056,572,2355,257,423,471,1900,1309,497,2132,532,501,1191,372,1274,530,1300,1260,1129,1157,485,2448,2009,1304,651,1349,1304,922,324,1663,1058,402,1074,1236,731,1085,1583,155,1158,657,969,2578,1422,1229,2180,1475,1483,556,1270,491,732,602,1168,736,2010,2563,471,1110,286,277,1594,1268,378,1475,462,248,687,58,741,1370,511,344,1007,1440,1851,205,360,120,627,1343,491,70,2300,704,618,1349,2429,2236,1035,1280,640,1055,2577,495,931,2335,461,2052,1500,340,1282,356,340,972,340,924,77,905,1273,1431,356,1349,1349,953,1264,394,1506,1150,141,923,1247,1349,618,1150,670,394,1233,1233,356,1233,419,682,2183,342,1069,311,381,1459,523,710,383,741,356,1544,388,1168,530,342,1072,1324,1430,1504,388,1480,1367,1448,11,311,602,1089,1110,886,696,657,1545,311,627,129,1238,57,485,1407,150,657,482,530,730,1430,682,886,482,482,674,1327,729,934,218,716,1327,1361,1361,329,21,359,1329,383,934,1392,359,651,1343,1460,245,1297,936,1341,1268,998,976,976,936,461,1134,1268,1343,1330,934,930,1365,371,505,1153,1370,1257,316,1052,329,1052,633,618,371,828,937,122,1366,376,1343,150,1245,328,442,148,148,207,342,1262,47,1343,593,1343,885,651,658,1448,963,1258,1280,1271,1257,1257,554,2336,471,468,1274,1047,442,495,1777,207,326,235,1047,148,1460,492,1306,148,889,1488,1228,468,446,430,431,420,18,893,1343,632,446,18,648,879,2336,18,315,1343,471,1306,235,1334,611,611,551,712,1434,2316,379,379,379,257,257,306,1092,1011,1832,772,1813,619,1185,1485,371,131,428,1377,1549,885,1341,1112,965,486,526,477,159,612,679,483,1153,851,665,830,496,867,1411,665,482,612,480,1359,753,457,307,1343,261,2538,217,1116,947,1377,147,226,226,442,372,1446,864,814,226,1440,135,651,1442,1361,1222,640,661,1446,818,1176,306,1320,1181,1920,1304,632,428,1076,645,903,512,526,56,247,405,1241,1304,904,191,263,799,1186,525,191,584,632,587,191,632,1246,587,632,632,191,18,1073,1116,1185,587,814,1446,226,818,943,800,1185,2454,661,1367,1367,1430,1112,1124,1343,1124,1112,1430,1430,1004,1343,2133,482,959,559,103,362,78,463,464,147,992,2500,1006,1455,1372,1811,356,998,353,111,1250,124,383,1445,418,341,325,329,873,868,491,320,1476,1360,768,13,559,978,374,2550,1242,1332,921,886,931,1257,1257,327,559,513,1147,1072,1451,1425,1280,1072,1238,1456,257,227,327,22,78,963,1173,1572,385,1144,1485,326,1445,801,905,1486,343,2286,1438,1266,1512,873,488,2406,1445,38,760,962,450,553,147,1347,1301,1326,661,267,539,670,514,832,1322,865,575,1341,395,414,1866,267,145,499,1293,1293,306,12,437,890,792,1486,399,142,1178,1445,207,715,1280,207,1280,2202,2202,2013,1280,817,577,1023,2572,921,783,729,825,674,1230,78,1242,1229,1297,2049,514,1361,436,215,645,1052,1187,715,183,1072,931,1772,2082,201,1297,1286,1215,869,142,789,1864,270,1215,810,783,887,383,682,629,633,1425,207,504,636,1102,886,1595,678,890,619,587,179,228,1174,789,512,770,2122,1338,1518,992,1072,186,343,343,523,325,934,1230,267,886,405,1488,263,1250,715,799,1469,148,197,682,851,248,9,1288,783,1090,1098,775,1379,1451,1002,295,1025,1199,1331,493,1237,2049,1365,486,277,57,124,344,1268,801,732,1222,1165,1209,1557,931,687,1222,1085,1154,1124,1475,363,1343,78,640,483,886,1280,1885,687,72,849,124,672,405,865,167,63,496,341,1154,751,1257,1466,688,499,1352,686,1448,1312,209,650,1190,882,801,169,640,207,399,1274,1164,316,1446,499,1055,449,207,1018,1270,1130,331,1349,1759,1461,227,577,825,1212,632,1577,1355,532,161,1343,529,596,633,2372,1212,383,921,207,72,1314,784,1250,1394,1268,63,1291,578,596,1322,670,480,1381,683,963,1394,674,799,1381,137,929,1250,397,1392,648,396,1486,501,814,1699,485,359,157,731,814,1448,1448,363,493,257,1445,1297,1145,1438,521,1268,1365,111,1333,1365,150,1260,1260,521,1365,2557,2510,525,521,1286,1065,2398,1067,520,1080,1149,1087,1080,1174,1152,918,918,393,456,1175,172,1107,1262,1254,1453,232,171,1052,1342,316,853,1147,316,32,171,423,321,1555,56,670,1450,493,97,651,1458,1399,287,78,1266,364,171,1262,670,956,866,1257,573,342,1398,333,627,1060,1365,605,1187,1367,1237,78,724,805,523,465,1084,556,60,761,257,235,37,111,1343,1118,97,101,665,224,1312,1334,150,242,229,935,671,1342,483,493,480,723,921,671,670,120,1099,1901,635,491,491,2375,1325,1611,904,1325,1325,102,102,1343,615,1185,499,1528,771,1177,499,2056,882,1228,1343,115,731,729,353,929,1367,1367,1061,2344,274,452,922,2209,1486,471,1128,1448,1270,1585,342,1212,1237,1253,1445,923,1052,1241,932,990,342,566,1874,2179,2514,482,947,921,2530,328,21,1015,2508,485,454,454,319,1255,532,379,1447,1377,947,19,159,712,1318,1846,995,423,191,1345,1361,60,1343,942,1237,629,887,295,1268,124,97,1460,1121,1168,1372,979,651,2437,2392,246,735,952,629,1358,553,761,44,38,379,741,1342,1342,2080,898,2356,571,818,800,78,75,2382,1298,1333,1265,47,442,1349,1412,265,523,257,812,353,449,905,715,342,1073,381,57,1238,342,1480,712,318,2025,710,710,22,1724,753,1483,1779,146,485,512,661,191,809,1370,1311,1350,956,363,744,629,998,1347,370,2328,117,137,1367,425,1486,645,1253,485,399,190,492,188,380,395,706,112,499,499,342,1365,1457,1483,495,469,1344,112,311,2359,1005,150,707,1190,1452,929,1458,1315,461,341,968,350,1740,512,1291,172,102,512,306,12,939,146,991,493,2449,464,905,629,509,611,1090,1470,218,487,985,57,104,939,471,2020,231,1486,998,2004,2511,684,1350,2326,511,1132,2546,699,442,1456,1334,274,1237,627,270,341,991,1345,1959,55,85,1378,246,1199,1128,1128,142,137,1253,67,1257,372,629,916,1460,753,629,1470,1510,1215,959,2378,1134,137,882,525,715,723,1291,512,132,2402,535,1323,1186,1360,216,1924,420,1361,216,487,1232,1124,823,1060,1268,2127,1859,291,2242,1243,505,774,761,544,598,632,2410,1248,1201,1265,480,1360,1350,1349,376,183,707,707,190,2470,512,248,963,265,491,1492,381,593,2180,524,1238,2089,814,588,430,916,2569,360,406,342,678,123,155,1114,179,1422,719,92,22,627,2461,77,420,41,949,529,1257,947,247,504,916,231,510,939,1145,371,1981,381,651,1845,947,9,1233,51,1455,1322,2076,491,649,1151,1087,1173,235,451,921,430,207,1158,182,57,124,461,327,598,920,723,905,2475,228,1047,461,1324,1341,1177,921,1229,104,772,1440,1218,518,1181,682,57,1343,861,81,869,263,1350,1350,1350,1274,1023,958,553,1273,1230,1366,1365,344,634,1306,1306,2256,104,1248,184,1274,1365,1591,712,9,295,2121,1350,592,592,495,732,1512,1512,1495,40,1002,1259,1338,9,963,491,342,688,512,878,1850,442,442,446,1325,1198,2594,1483,1270,2358,1892,925,951,729,465,1069,916,352,266,1456,493,1237,78,1235,853,485,450,1394,486,277,1334,2093,191,968,1235,1438,585,1186,473,1259,1077,923,601,1488,1331,1369,715,1376,1090,248,1469,392,979,1310,121,541,587,454,496,665,1237,860,1363,1118,1344,1077,183,2410,1376,1438,607,1005,2222,629,972,1127,1370,629,525,1257,37,159,1302,1199,656,480,251,932,1495,910,214,1369,159,1367,576,862,916,916,768,1109,1258,329,1377,1442,491,483,1020,97,302,728,425,1132,1438,2230,1118,1304,656,1258,1913,605,998,1246,1873,216,1077,1154,683,1007,1134,1306,44,1333,401,57,1489,402,1512,1177,104,876,2124,228,485,627,1069,606,1367,651,1350,753,255,1177,711,1189,161,882,9,632,191,1259,905,958,510,183,505,1236,1207,101,1367,1343,1484,2160,57,671,1305,85,1423,618,935,704,1323,651,485,1598,402,1949,246,1124,672,1067,1056,246,740,1314,2205,1610,248,214,1435,1069,1309,2481,450,18,729,665,201,1450,92,908,496,730,191,809,471,530,1345,493,802,1087,2546,405,137,452,1044,568,932,694,1392,1399,935,268,633,650,568,7,57,627,1237,483,930,1076,1609,723,851,121,251,1316,2045,1014,1014,619,1365,939,159,455,491,699,1377,38,1360,963,672,985,471,295,1168,326,532,1504,313,1005,329,587,1107,1013,1319,1301,344,532,231,1253,266,1110,1274,1260,661,1240,1060,921,491,1379,1132,651,1367,1262,1247,483,1969,1112,825,619,1306,1379,1538,1258,428,1567,425,8,1235,471,471,485,188,1270,1230,740,1343,623,495,96,1069,935,632,553,1370,218,1170,1153,1310,57,1419,1132,1265,316,262,1282,383,1282,1089,493,425,441,1023,1283,1314,1334,29,1119,1089,1314,280,901,1237,818,155,698,1005,894,1145,2020,1397,137,1438,307,800,1350,360,192,1274,921,311,492,1304,585,2015,59,255,730,2124,511,1632,736,956,945,1267,640,851,1266,1124,686,1343,1304,1281,627,1343,595,619,502,246,1447,42,1165,1334,2239,463,63,315,1119,1150,1207,956,939,1238,656,471,849,360,1379,40,629,1089,159,7,650,1397,1306,949,600,480,1218,1255,1014,1014,921,437,2179,1121,1208,723,1379,2305,1366,1314,485,516,1306,751,958,1121,516,882,1616,80,982,1343,1267,1365,524,633,633,1346,935,1349,661,1195,454,1375,651,963,1332,316,715,420,584,601,485,231,704,620,618,468,332,1445,1238,585,1422,457,627,485,728,619,458,682,482,585,672,491,1023,1165,618,969,1274,1343,674,719,66,449,1974,671,844,688,1110,1274,930,1701,449,1301,214,342,1301,1301,671,451,1365,1955,499,526,405,41,1323,1422,1422,584,70,1260,1090,151,959,253,484,959,261,1077,1261,532,1433,1065,1364,1878,1485,1970,1996,723,365,1128,1942,118,1078,1342,2193,1272,319,295,21,147,929,1241,1241,1015,1561,886,1375,1375,535,921,1190,1346,921,94,1370,532,469,947,921,1452,1318,426,761,1232,329,329,423,374,1397,344,978,507,342,145,269,41,1267,799,224,958,1360,1413,905,146,1362,430,417,526,629,344,978,1411,379,886,886,1266,1460,253,67,75,1913,1419,962,2396,1183,651,383,228,383,1350,629,141,962,756,363,190,469,469,706,344,1327,1219,645,1457,1340,1266,592,499,629,1343,905,920,1893,1065,1341,1233,753,956,945,400,956,1448,702,611,1433,487,782,1110,921,1288,507,959,2391,1357,487,1210,132,44,1824,420,1089,1307,332,1072,1072,343,1268,2591,1253,342,627,605,57,159,1369,1361,505,228,1323,1186,252,1210,469,1248,1445,1114,1845,523,730,921,921,1367,537,2123,724,934,1110,1257,678,629,257,1177,1158,588,418,420,1367,37,325,886,1235,281,1343,1369,1137,968,931,1015,799,950,1238,636,406,1227,265,295,32,248,1233,94,1328,1350,404,120,263,596,553,505,1279,1186,712,1301,731,1365,504,1078,1306,465,1324,58,1002,1235,344,1370,717,1238,342,1005,1280,1360,2345,710,1942,1349,1077,151,1153,1457,1488,1168,344,1370,585,723,596,272,450,958,257,629,1248,1376,964,775,665,182,998,1350,495,34,605,866,1110,1257,190,1257,1370,1245,632,1118,31,998,998,483,1252,1300,420,947,1341,54,455,1154,1342,274,772,430,450,399,695,744,1343,1241,1350,2445,1450,97,1035,921,703,1180,1056,2414,101,145,248,18,672,1238,1448,1154,137,893,963,886,1365,493,682,665,948,1450,1346,495,1096,1241,1274,1230,1433,1435,694,694,404,656,710,1257,191,627,703,1274,127,1423,707,1273,753,1502,1110,958,1161,1446,882,882,501,963,66,640,862,400,1259,484,1466,465,325,610,532,532,471,1345,120,499,1055,782,1274,1253,151,1315,1110,159,141,229,628,1282,1055,1342,513,218,120,923,187,1272,1067,683,947,1350,118,629,1402,1306,632,1458,1399,1279,707,1282,921,998,492,724,426,1005,480,1480,505,901,429,740,316,619,505,596,742,2143,184,629,958,814,670,274,1274,1457,661,627,661,332,1335,886,778,383,528,1349,1089,1257,94,963,968,274,94,702,963,505,1458,1479,2507,464,843,873,843,1286,1452,700,308,538,740,1260,1951,1324,545,1372,963,651,1309,1090,1266,1192,1273,504,1266,1318,450,1238,235,1687,1238,235,1476,437,963,2543,1340,84,354,1365,64,1647,1341,1052,139,341,402,328,1734,505,768,1450,227,1343,1452,1450,507,1447,1280,978,1445,992,946,509,535,1370,717,118,19,809,468,978,486,1216,652,224,41,870,1334,1512,57,1063,1151,526,431,1311,1300,1440,1341,856,77,245,1222,344,402,1448,1448,978,864,1343,712,1215,768,378,1309,1433,962,44,1367,1258,728,78,77,342,147,147,572,992,497,2027,493,78,505,306,362,1005,1457,399,1344,90,2162,539,1292,1470,229,1301,588,560,809,1090,487,589,1679,628,528,1069,913,363,1343,1367,1440,656,959,976,485,1343,84,1470,1104,1044,976,628,632,633,572,492,339,119,1590,588,1158,1372,651,147,123,155,284,64,636,1657,874,874,324,1369,683,406,1324,272,2450,1448,523,1342,921,406,388,560,920,1170,1168,344,151,1282,59,257,588,275,1300,1300,431,1372,38,40,344,1365,1206,1324,468,44,1703,687,870,226,1378,1139,378,217,457,998,1372,1007,772,517,1136,31,2463,147,1033,809,1349,507,929,457,572,632,601,627,1434,1077,687,1343,256,1282,1265,1238,64,101,482,936,1040,38,1270,57,342,492,1469,492,207,483,226,1382,316,78,1273,1284,67,985,703,601,19,1399,401,103,1131,825,119,633,57,509,229,1089,1151,57,601,342,1005,1447,280,1089,1222,1080,28,1280,78,139,600,661,1258,1265,601,1434,224,525,1390,1077,492,864,1343,601,1320,1257,1257,2533,334,2340,344,336,344,24,430,482,482,493,392,60,1325,399,532,891,712,730,730,740,1080,1112,119,682,532,730,1179,632,1334,1448,535,1258,1054,1235,1260,1174,1365,602,295,273,2339,867,485,744,1350,380,2347,1422,511,392,242,493,682,965,491,1236,2053,2347,682,602,1071,246,1925,2039,1925,1325,399,730,1080,119,602,2053,485,1258,246,1174,1365,380,511,2347,2049,959,509,198,532,1448,131,483,381,228,2614,247,1056,456,968,363,1080,2062,400,374,161,2061,476,959,1288,1124,799,482,672,1448,1410,57,1288,450,1056,374,1448,1311,161,1257,1341,629,532,1257,1325,1257,1323,1323,263,344,959,959,502,441,485,318,1316,255,444,118,1159,1301,505,1341,992,1316,1345,1055,923,1215,485,1316,1345,291,328,1320,1331,1117,1071,1345,1283,532,1275,786,137,1360,1447,1293,321,1286,128,1067,1323,1022,1478,1303,1343,1343,1124,145,429,1069,430,1304,1440,1460,1444,380,2613,1485,1419,383,344,492,257,651,247,1329,629,782,864,1456,355,1343,526,1334,1413,1341,1341,964,1430,905,57,1296,962,22,218,1497,1157,1459,188,1433,1357,1303,1315,1343,466,430,1080,112,1277,1077,434,114,380,400,968,471,505,456,350,1271,1235,1069,1456,1190,728,1419,746,295,468,1323,1293,388,308,76,1178,1341,619,1457,580,573,1252,1367,1165,485,1448,992,201,606,291,581,1830,1080,1187,228,44,120,1362,561,941,946,1093,9,1366,1277,142,507,1268,1252,1255,361,1117,82,471,514,1108,1441,1108,272,454,126,2401,505,556,962,192,1275,1131,1233,1266,326,145,1142,1343,801,1345,255,887,1480,121,136,1476,485,920,1150,1475,1237,523,952,266,1444,1399,1492,929,1480,637,493,161,426,2182,1071,64,456,890,1365,257,1301,2299,1080,1306,1080,476,456,295,1452,248,1280,342,917,1186,493,922,1367,1483,1466,1259,471,1344,9,1266,774,137,1853,1457,1327,1325,465,1306,363,840,920,756,1338,1238,1288,1165,1453,1055,492,908,587,466,480,362,1350,728,1276,730,1259,934,136,1313,1168,1124,37,146,929,1080,496,1370,1282,431,1157,1332,1332,669,1365,63,194,2343,627,751,751,1056,1433,749,492,1399,504,632,665,96,849,403,1258,1480,18,18,510,1433,1341,449,497,702,129,420,455,121,1301,1407,1039,1257,1410,280,1444,782,601,291,2548,532,482,1507,501,80,471,1469,1253,1405,1411,2411,941,1150,1405,917,1074,1306,1398,1150,230,1131,1267,482,502,513,1422,778,1340,7,1419,888,470,456,1343,1343,1055,984,830,931,280,1142,452,1453,426,8,1349,493,1367,492,471,1807,1433,1309,1390,619,1071,1238,121,629,1341,60,1433,1325,295,155,1238,155,1334,120,984,1346,601,139,1280,814,460,1390,1343,230,2585,1325,272,1323,485,263,344,992,482,505,444,1159,984,1055,923,1215,1316,1343,1316,485,992,496,471,849,526,1320,786,1976,291,2182,1293,1117,1853,1067,321,532,1444,383,429,905,1497,2411,1296,1413,1124,1440,247,1453,188,962,1430,57,1343,1341,580,619,1080,388,1077,2014,468,142,1457,1069,456,228,380,968,400,1315,1343,2596,350,1271,114,476,1303,1453,507,1252,2558,941,1255,361,1362,471,581,2424,1117,299,952,1457,1492,840,1807,1480,326,556,1237,1365,2401,1071,1787,121,637,1476,1131,1150,1071,1343,756,137,257,466,493,1280,1313,1338,1238,295,1367,1306,120,1483,9,1325,248,2216,2299,1301,751,230,1124,1282,1332,37,1080,929,730,510,2204,1433,492,749,1150,1405,2336,601,917,532,1325,931,1419,139,1919,929,442,1261,483,442,417,441,1257,1257,1986,640,419,272,661,270,495,2097,1684,628,242,1227,54,1090,1268,332,1448,1448,1325,1325,1079,161,468,1212,1343,1212,1343,491,21,448,295,159,1273,463,491,559,350,526,341,1257,70,426,1367,1457,486,329,1257,78,1240,461,70,246,1488,329,1343,2584,41,116,12,886,768,885,379,768,1362,253,753,1112,1297,461,588,430,1956,753,12,712,627,799,56,1366,486,1203,2210,886,1257,1343,526,753,158,1150,460,532,44,1438,1370,344,90,376,1161,2545,423,1228,901,480,318,1149,394,1399,1448,308,1460,1080,57,1483,308,401,891,59,699,224,1076,583,327,1181,1341,146,712,431,57,672,650,471,350,890,1483,488,1304,1402,501,350,1392,493,1349,1316,1441,1064,70,70,959,1064,173,1392,1453,600,1390,190,136,1057,1174,874,371,1266,700,493,1131,344,1152,192,192,1448,482,1425,273,511,1293,184,1378,40,1384,600,335,99,482,1076,1343,1469,344,380,1027,1399,640,1343,29,138,1230,1448,1475,63,1376,1409,228,1390,1325,916,1055,1230,1350,511,355,1266,1392,57,272,1088,1325,1057,1309,663,355,1392,44,1438,344,1370,376,90,1399,1266,29,1425,480,1448,318,1149,901,59,380,1460,394,308,493,1579,1080,1181,401,583,224,712,327,431,1341,1402,1448,1918,471,1483,650,672,1392,1483,350,511,959,1441,600,1064,344,273,482,1088,1057,190,57,1453,371,874,227,600,335,1475,344,2609,1027,1399,1325,1390,1376,1409,1055,1350,355,148,1257,1067,775,1190,1260,141,431,141,1434,1262,1154,1493,1493,629,518,344,1430,354,922,1055,956,1346,1267,1483,532,948,146,187,139,139,257,2074,128,245,1260,1419,532,1375,962,483,146,159,385,1313,1483,1177,286,645,354,1134,201,1258,1430,1124,1346,524,493,974,2440,186,206,672,1730,231,958,1483,1168,962,148,465,939,938,502,1398,1170,307,1389,1389,1496,853,1569,572,553,282,532,341,138,492,322,1447,1139,1375,24,2315,1375,2332,1218,1145,1343,804,651,717,22,1789,569,1216,486,1719,2289,927,1460,523,1783,1446,341,862,526,1052,79,799,526,2151,367,1341,482,287,1267,499,287,1457,968,575,1464,401,956,586,1271,148,672,890,1448,487,1179,91,493,1744,938,57,1265,287,482,524,485,1087,1199,161,514,814,1274,85,1311,209,760,1087,2155,1357,501,155,938,756,2434,493,921,1248,1237,185,505,483,814,523,814,678,672,625,457,526,146,1229,968,1167,78,1496,945,1342,201,1488,91,1488,884,1448,1442,251,1446,1365,1819,283,172,1357,1451,248,1412,138,169,493,385,1154,525,342,1496,257,890,1002,840,1168,113,1209,492,234,213,2313,1142,2332,804,250,1168,483,814,2206,655,1390,57,150,672,640,201,1153,224,1124,1259,586,482,1446,2332,1778,2206,1488,632,52,1395,804,67,1208,524,1773,110,1265,316,159,1450,1735,77,1729,201,2086,532,650,1140,938,2085,917,639,281,202,394,1398,218,57,57,1458,526,159,941,282,155,482,1254,1375,804,650,645,1446,2151,1448,120,159,277,1238,661,650,1265,1238,1499,601,1282,982,1022,814,1142,963,483,202,214,1259,585,532,650,1069,374,226,328,962,1169,286,286,374,605,991,683,7,482,524,1155,563,612,1324,716,546,962,665,612,286,1446,1325,1169,235,1349,1367,1668,1320,400,535,1238,1375,1286,224,1306,318,992,1052,581,1087,1212,137,224,295,768,921,710,1011,581,930,1468,441,449,963,583,247,651,224,17,1440,318,580,1328,881,44,381,381,870,1460,999,295,22,1454,1447,1332,553,1343,1448,1080,905,306,375,524,502,395,430,541,968,1453,1386,1448,1064,637,1367,1060,1360,1230,1346,1433,1229,343,950,1453,799,623,1433,1422,637,1483,468,1233,186,406,544,1342,882,932,402,804,905,393,44,678,858,635,1011,1002,483,1327,1266,171,197,182,363,1360,441,1087,344,1483,342,1240,48,1421,1365,1240,1157,383,1438,1262,1370,672,499,129,2607,1238,474,1312,1433,502,1422,83,611,329,315,648,363,1039,544,1990,1342,483,1475,306,1367,493,441,618,876,629,629,670,650,1668,1872,400,1306,224,992,2607,295,678,1011,441,381,2103,670,2604,1447,1343,449,1440,629,1332,950,1080,2570,1448,502,1453,968,672,502,1433,343,637,804,44,468,402,1233,635,186,1483,197,342,483,1240,1087,876,1370,1262,2266,672,1433,648,1283,381,188,188,1589,1504,60,596,596,188,1310,33,60,60,60,1310,60,31,188,60,60,137,1006,825,825,121,186,186,1340,994,1592,58,1078,1364,640,218,119,353,929,1364,1364,923,1316,2544,1939,700,920,1381,1234,1201,1476,1350,218,1379,511,423,1320,317,2490,218,291,1946,2593,218,511,1371,1236,632,146,135,801,1177,1448,2544,517,486,137,224,307,246,2285,1460,257,1399,1158,1090,1216,966,514,469,267,1362,728,52,2009,771,1341,505,1473,645,1316,1210,1117,2116,1158,866,448,801,283,517,1308,1316,82,1359,1274,959,1196,1458,959,248,248,1199,514,1170,270,1342,2593,2456,397,1255,1080,143,1124,1398,975,333,632,1137,469,628,383,598,48,216,532,52,463,1752,672,1360,1453,511,1367,185,585,1235,1170,1343,218,1371,682,54,840,1365,231,1129,283,2431,1324,171,146,1168,1367,1078,1438,1360,1379,295,60,1939,295,1262,465,959,235,218,1236,774,2480,380,1332,157,2167,1316,1142,248,146,1371,1124,2434,931,695,1332,394,1423,18,1080,88,148,1124,1395,1432,283,248,665,146,213,648,1506,987,929,1308,1367,1341,295,640,526,1080,57,1331,700,1282,1131,1946,1419,1165,307,736,58,58,593,627,1370,1332,682,628,1343,1186,244,922,1355,1052,418,1365,706,1005,956,1257,583,341,549,70,319,1289,2250,1283,1071,35,1370,209,479,2504,35,1252,526,1360,418,1145,958,57,885,75,1063,44,1221,247,1492,1338,648,580,399,1457,1077,569,1367,350,430,945,1448,483,1992,448,1289,499,1258,399,838,2126,487,577,1444,605,1379,1323,142,270,1257,687,343,1253,342,361,427,605,487,372,537,1351,78,1260,44,629,1377,85,1275,920,886,950,404,1453,1150,1492,905,598,799,136,1360,85,1270,226,524,1355,938,1340,1804,1324,715,1020,44,295,1087,530,1366,1379,446,585,1270,1270,1118,1154,744,1257,1006,161,1483,1492,1338,1252,1270,1377,428,1355,56,712,129,341,641,1345,1466,452,938,1322,1423,704,938,1303,244,57,1315,57,1407,1236,1444,712,1055,648,908,226,723,1338,103,577,332,723,1492,583,633,1390,120,1359,482,1325,120,1738,651,460,1257,332,1390,629,1361,1795,959,1480,876,1456,1342,353,1365,520,1324,1504,710,1441,1304,270,1438,328,1370,345,1379,1147,1172,927,1216,1732,417,381,1121,1836,155,1386,745,612,186,155,1362,1190,1447,1265,497,142,1347,1199,502,715,572,1125,620,916,1504,414,1325,2381,2267,1236,672,602,1322,1158,873,1421,183,1150,1504,1475,201,588,1168,1266,271,450,1303,1290,1151,959,183,1379,910,553,1118,728,968,156,213,1379,1358,16,1413,414,1154,497,895,138,1367,627,1395,610,1340,497,83,502,1257,1150,1836,825,1343,628,526,1325,1343,808,1005,1314,155,1325,651,728,728,2267,1286,502,2381,728,1325,60,1625,1080,1362,1080,1080,2148,1691,1337,2155,2146,509,509,959,1342,641,232,1427,261,910,959,21,343,1438,1446,21,676,343,771,255,1055,940,568,173,1485,317,455,455,420,356,921,706,996,248,1112,1260,1343,115,1077,1200,1257,842,929,959,493,888,1336,509,23,319,137,1289,269,1375,929,341,885,768,1283,291,532,283,378,1346,930,32,1023,990,128,823,328,1378,1342,947,885,404,442,1345,535,255,1343,1450,1259,352,996,479,1147,549,1370,670,295,947,285,1483,800,1199,1080,741,381,553,651,75,1112,383,78,885,1367,1112,1510,82,1360,1173,488,1438,1079,1080,1446,526,119,1077,1077,1309,1427,41,430,57,1073,1700,1078,78,1460,148,1381,907,1190,930,342,1417,657,929,342,629,1375,885,1326,33,77,505,378,1090,1442,760,1259,1259,248,486,761,1151,461,1342,1110,583,539,43,493,1190,1289,442,501,147,308,684,75,1080,756,486,1345,535,1453,153,1272,1190,753,620,482,1367,1304,990,1508,1448,956,1055,148,1267,1289,968,885,1183,1457,1273,744,574,1332,1265,1266,1297,535,111,611,483,887,1005,729,1340,1344,395,11,264,1361,1039,552,930,674,1110,5,255,420,1015,1080,561,959,1274,1433,1300,1394,1168,216,385,444,1450,1193,687,715,605,1230,1283,1377,44,1255,1124,1367,120,263,78,420,487,442,202,332,119,1229,1448,1110,1306,455,1366,1177,583,1480,690,1289,948,1072,418,690,1336,159,1409,523,1268,1183,706,918,627,864,1015,142,361,628,1161,74,1460,1441,1198,657,1504,526,125,1371,493,1882,255,1158,124,678,406,651,885,672,627,934,913,529,741,1504,882,12,885,1268,1317,1473,620,553,563,1142,588,272,790,179,1483,1442,46,814,1490,1717,1150,263,921,930,1475,482,1367,510,393,710,123,1174,1257,634,1158,384,216,1090,1438,672,724,672,454,64,347,598,559,319,1256,772,1230,1390,451,237,1265,889,477,637,312,719,541,1349,245,634,404,1267,274,1199,1236,1488,344,1002,483,295,535,138,1168,1411,452,1327,277,1262,1365,561,1073,465,1237,344,1427,111,945,1077,441,585,1174,756,938,938,446,1193,191,461,1338,741,493,275,493,1114,585,450,1306,286,505,1438,58,1450,1483,1297,1336,716,860,5,507,933,715,1136,218,37,1262,632,1136,542,656,1332,1338,830,1245,998,1154,1136,934,629,1109,179,78,862,217,54,1033,356,1483,1252,1350,467,1177,656,541,1133,1044,1118,1230,428,1441,1441,605,1343,1370,1155,814,1258,486,362,695,528,1117,1497,1136,1264,332,1240,766,671,1136,849,1496,1204,1297,394,1309,634,1085,20,703,753,682,57,1238,656,246,1029,1489,1341,672,18,561,934,205,920,124,1153,704,1355,121,332,514,63,1090,667,1300,191,661,1390,493,101,627,1264,1257,546,1100,52,1426,933,141,672,455,483,916,471,936,907,648,1039,1300,1029,141,1937,1110,656,782,465,898,1131,316,939,967,1327,1153,1273,532,499,1506,640,945,610,280,1284,1387,482,492,1450,244,1317,1351,278,532,830,1387,917,1183,316,141,262,1055,540,491,327,1131,671,528,471,1367,632,1480,938,493,1480,618,57,1183,461,1338,285,406,1677,526,329,218,44,1343,8,1488,1316,255,1460,437,1475,482,814,430,480,950,70,1349,587,818,1301,493,493,931,113,1448,740,627,618,482,1501,583,1057,882,596,272,1109,186,688,63,41,671,1269,578,661,295,670,1283,493,603,78,491,2537,120,1270,493,1257,394,98,814,619,202,963,864,682,2616,674,2574,814,532,1155,1458,601,509,347,1342,1438,1796,1427,907,2159,1200,929,173,2382,1023,316,255,719,842,1327,115,1289,352,85,1147,526,283,128,1450,768,44,1882,32,930,2537,946,535,1252,378,549,319,479,1806,823,1543,1367,930,1442,930,383,77,553,909,82,78,1375,2616,761,1151,488,1784,1360,1183,78,651,1109,2325,711,41,1080,1309,2435,57,799,2293,285,1289,552,611,307,706,1317,1361,142,487,1336,782,1448,1677,1190,687,255,1344,541,1411,1457,2492,1799,264,1265,455,968,1044,408,1788,365,744,2596,1039,501,1340,121,153,1153,11,1345,1005,1460,610,2310,2489,598,1161,634,561,1274,1136,628,1409,159,404,361,291,1300,216,690,332,1283,657,540,2036,1015,1167,605,948,2071,0,934,1434,840,216,710,46,921,237,556,588,123,1257,384,682,179,1473,510,1448,1265,529,477,874,2438,263,493,2072,724,1483,945,1349,541,934,1110,295,111,938,1450,277,1118,465,461,5,274,716,667,1483,327,715,753,1441,78,363,814,1155,528,814,766,2167,1886,37,1343,486,70,998,63,2439,704,682,52,1355,514,1774,1496,1309,656,1652,532,642,916,671,1815,601,917,202,2331,244,480,618,461,1480,632,1343,113,63,596,120,1270,1659,124,520,19,257,962,641,728,1659,719,696,1097,1054,480,719,1323,57,420,57,1052,540,549,49,442,1018,1030,1266,2033,491,742,1261,1105,270,1411,784,1419,881,1263,651,60,57,1018,8,392,363,1894,311,161,444,399,742,1038,589,607,686,1186,1047,524,1375,1375,121,965,650,123,1051,589,1322,1241,1323,295,473,1367,1241,444,38,444,965,1333,1241,32,12,1236,1344,592,974,601,271,968,332,1173,814,1142,541,430,974,176,392,270,922,584,1154,392,894,545,1260,471,121,2295,231,461,1142,1241,1139,719,1097,1054,1323,420,57,1243,176,1018,1236,1266,442,2032,742,2101,2217,1411,784,399,1241,1142,742,686,541,311,363,430,589,518,1375,2126,1808,1367,1322,123,1260,1241,473,1323,1828,121,601,965,471,592,974,430,1173,1746,2350,1950,461,344,344,615,280,1286,929,1255,352,1448,1344,1327,269,295,1073,32,874,561,1378,1011,1447,886,513,320,1327,1344,1441,504,141,295,962,247,1497,1512,1785,652,1516,1216,1216,1574,75,344,485,2168,662,137,1289,287,667,753,2043,1087,287,1268,306,400,1364,350,1054,537,941,1289,183,344,57,1262,1054,1073,1448,915,269,1372,1441,159,1268,234,814,1378,1267,874,327,1492,1343,280,678,1344,523,179,1674,886,651,2443,1268,2168,1073,1267,1344,1459,1327,994,1262,151,1325,1344,1090,1776,1365,2169,1235,1235,814,280,1129,11,465,504,1129,1346,352,1324,468,363,1378,1255,2115,8,1260,1154,485,1425,234,18,1260,1346,1023,986,648,1208,244,501,1131,1129,1538,1267,329,800,306,482,234,1258,1346,1448,468,662,1257,629,629,629,1473,1962,1445,1133,2084,2257,1343,2334,1325,947,929,1294,2520,483,384,461,1448,380,2084,188,1355,523,159,449,1382,684,1365,155,253,1128,420,1250,1099,394,159,1382,1355,482,1257,155,658,627,2257,1311,1382,483,483,2558,1365,1312,767,340,1039,761,1241,328,865,1377,629,148,1326,651,618,10,39,1255,252,231,452,1255,255,1301,485,761,137,1274,1413,1186,1441,876,715,651,920,1453,480,1047,324,442,1419,1344,799,1460,1212,648,651,267,1349,1255,651,1099,651,1262,442,1344,700,700,1378,658,725,70,1255,1238,592,1345,1258,1343,7,228,1166,2370,1367,2178,661,224,483,865,1327,24,885,1236,332,1260,485,698,723,723,618,629,480,7,327,224,661,651,8,332,629,40,431,431,431,76,950,950,2069,1172,1438,514,141,953,514,514,252,514,1172,324,324,552,2227,733,733,41,1338,1174,471,1338,1895,261,112,930,992,246,274,1255,992,947,511,1310,823,23,1346,2363,768,753,1497,218,33,1343,1334,1158,44,504,442,870,2531,75,1344,363,1158,505,1280,9,9,433,375,927,218,939,1185,702,1352,1128,1177,2337,1309,565,51,1142,1058,75,592,523,597,1282,1002,35,305,958,958,430,941,761,523,491,58,245,491,1240,1157,378,1142,44,1280,862,363,57,592,1153,665,401,939,1310,482,491,495,122,218,452,1267,929,272,1222,491,601,1236,992,342,717,970,363,1237,941,418,126,592,1003,1379,1067,1237,363,29,1157,378,1379,359,57,1237,1131,272,1222,272,1236,992,342,418,1237,1379,1157,520,520,1265,1282,1265,482,1344,1382,1379,1061,615,882,465,1349,1379,882,9,1344,1272,452,1338,262,952,585,1273,1107,417,1301,1341,1301,294,1117,586,921,423,1367,1228,31,283,246,226,864,2306,652,135,513,620,430,938,295,291,1237,1280,592,1071,1341,1341,413,279,1366,904,620,343,487,2460,471,585,487,681,1187,141,1351,1376,450,420,1903,1208,1208,901,600,1208,1483,1483,179,263,600,1150,420,929,553,207,1308,946,1341,1025,1168,291,295,1323,1243,544,1356,1466,1323,1267,1286,1342,1372,1033,250,250,496,584,620,610,895,1226,702,201,1332,428,939,384,1316,1324,471,122,1005,723,70,1267,901,670,602,814,968,1338,262,952,417,1273,1107,1301,1301,1228,384,1809,921,31,1117,423,1367,670,652,2306,1903,2060,487,1187,1894,1351,517,2102,1341,901,471,1208,420,1351,1351,553,1168,1356,295,1466,1323,291,814,701,250,1033,428,620,1655,1005,901,968,1849,63,385,342,1261,1421,63,1023,22,1145,645,385,1309,1060,526,63,1110,1237,1327,1332,1118,541,1118,315,656,1258,658,893,893,656,63,63,63,640,63,1039,332,1297,332,1327,1421,63,1023,526,1110,1118,1332,656,893,63,63,324,315,324,324,2412,1078,93,482,263,1112,1215,1419,1132,1272,1212,992,1367,530,148,1346,318,318,1132,1346,1195,1341,1512,57,506,1157,40,188,1184,1112,40,1080,287,415,992,1174,501,487,75,1332,1190,188,1272,1328,530,307,1325,616,1257,93,77,789,295,85,537,270,1124,1365,1080,1332,474,406,1080,493,1475,75,1268,85,1338,1150,324,1423,1238,393,295,835,1379,450,465,1184,471,491,446,8,2441,328,1238,383,111,1117,1154,78,359,1260,587,658,1118,1158,1338,1240,751,1154,702,57,1367,1297,510,1029,587,1469,1055,148,228,1343,482,985,141,1355,1157,1238,1272,1419,328,415,723,1325,751,120,1272,682,1390,778,1078,263,482,1215,2441,1212,1260,992,1367,148,318,2547,493,1080,40,1112,287,1341,307,985,1272,430,1890,501,1257,75,77,270,295,1365,789,537,406,474,1268,393,1645,587,383,1118,120,1338,751,78,2167,1297,510,702,1029,1469,2259,1084,585,405,1270,329,78,800,57,78,1199,417,324,491,9,8,344,1265,1376,1283,329,900,1283,695,1367,333,420,246,1216,1433,146,1315,1460,1445,876,1286,996,1023,1378,1241,1446,228,686,1360,78,40,532,1216,1343,962,1241,962,516,909,1427,1369,874,1456,526,1460,831,523,885,1393,489,651,1440,1145,344,1328,1079,57,1216,1216,1112,656,696,890,1158,1448,998,1165,267,1314,967,1069,516,307,415,78,1457,1344,684,1453,228,415,656,524,1116,946,706,605,420,1199,1306,1210,537,294,143,1288,6,672,1473,1453,1067,890,588,1158,598,1488,556,921,921,1325,324,1035,1325,363,1333,1255,892,191,892,930,324,465,930,477,1365,1168,968,1262,1488,585,1002,1110,385,1216,399,1118,929,142,1448,656,882,1165,1258,102,272,1325,1370,1492,1035,1055,921,1448,1099,672,1258,682,1423,753,20,93,63,191,962,57,1448,1367,1301,455,77,1124,1274,648,1422,281,656,1216,109,252,499,1183,1325,682,1419,512,1343,1338,1215,900,1456,1325,661,686,1165,1270,485,1099,524,1258,460,627,63,695,1367,1822,2519,146,962,996,78,686,1393,1079,1112,344,523,1492,1460,1216,831,489,1343,1753,1274,696,1344,499,455,684,415,890,63,627,143,1325,1288,946,537,921,921,556,1473,1488,1124,93,892,1448,585,1035,1255,20,656,929,1055,1598,682,191,121,1456,485,1099,1270,1912,1237,1237,1237,1365,355,1343,13,1194,507,41,44,187,1169,247,569,413,938,446,575,363,1209,372,890,57,553,928,1365,1130,665,78,1274,1559,78,187,578,72,751,640,665,1274,272,1392,1130,2446,72,578,670,359,359,941,550,940,610,1037,63,588,588,246,321,1297,978,710,228,588,72,1838,1177,885,1483,1838,978,1170,41,57,710,342,306,998,962,374,1297,592,485,882,1461,1060,1134,1170,629,72,1488,246,882,1114,1442,968,1488,1107,492,1217,450,596,518,921,632,1440,72,882,696,1028,702,702,1042,1301,645,929,929,778,461,592,818,72,645,982,270,270,784,444,1260,270,419,270,270,518,126,1367,1367,1896,1325,343,947,399,1488,658,399,1056,1367,400,717,485,921,349,585,474,22,1613,717,1301,1324,1274,636,1367,1208,920,1233,636,1238,353,145,893,57,751,484,1301,156,1324,1419,1365,232,990,505,21,442,1215,255,484,1304,291,295,1044,423,1212,753,507,1071,32,1370,886,671,1241,450,670,1381,320,329,768,1360,892,751,430,1262,962,418,885,651,1216,77,959,905,342,57,189,1238,523,253,24,1360,402,885,803,1288,1145,41,344,1412,526,381,1078,265,225,1142,505,1087,448,1272,306,12,1236,1430,1457,1347,645,684,1190,1169,1343,76,1237,499,569,399,1265,363,469,611,342,552,1297,285,535,1168,732,1060,1412,1134,946,1365,789,1433,402,372,1121,1253,959,1052,915,463,1177,628,1044,1044,552,723,142,627,1493,1257,1357,1069,1483,921,1442,1272,789,183,485,253,945,384,1455,265,598,327,799,1343,588,672,521,123,512,678,651,1492,627,724,1488,1448,803,451,1365,247,1077,1069,463,1168,446,1288,1457,596,1488,1402,58,58,463,968,1402,1235,1235,1365,182,1002,257,465,634,1324,958,958,492,54,295,1327,344,1025,354,1261,1217,450,1080,1020,1306,1240,932,428,1252,320,1035,656,695,1078,1077,392,1483,1165,1141,1332,291,1356,930,921,1240,1020,1070,632,18,615,468,742,485,1177,962,491,1069,702,1258,959,65,485,485,1458,495,1297,1466,1355,1423,546,1312,66,1367,962,1273,77,501,1315,1124,465,1506,1055,1055,315,401,648,1315,735,1259,1407,1270,331,392,448,577,1402,1035,1419,354,401,1352,628,124,618,1089,8,1005,485,1304,452,1089,629,645,629,740,1438,1272,295,670,394,627,1265,1365,232,484,1360,1212,671,320,21,430,1547,905,803,670,1360,1412,344,1548,41,448,886,1145,1932,505,552,1237,306,1190,1402,448,577,485,499,2494,1412,1272,1315,372,627,632,491,628,1078,1177,402,1044,463,535,485,1357,2342,651,921,1492,324,588,123,384,799,803,253,512,1069,1077,1483,331,257,54,124,1168,1240,1235,1025,295,958,344,465,968,495,58,1035,18,921,1141,392,1332,866,491,615,65,1312,66,702,742,1355,1238,1258,401,1055,648,1506,452,354,628,1419,393,1786,1342,342,629,518,83,1325,343,253,482,335,1007,353,1077,335,744,40,1369,1445,452,947,341,31,1241,491,1077,1367,340,1331,532,532,887,460,1441,40,1325,1317,1444,319,335,1241,849,224,362,1005,651,740,342,1216,741,629,59,1448,362,1369,188,963,1274,145,228,523,1333,381,1450,1367,1326,1367,1317,1183,1367,1173,1349,278,1252,306,385,8,1445,1325,437,1274,487,645,1457,1327,1168,442,684,1005,756,362,990,499,1297,1453,145,684,437,803,294,674,487,485,1199,460,1217,84,1253,528,1367,78,537,537,54,1257,537,524,1198,512,1168,291,291,581,450,1254,1069,600,503,864,672,886,1087,342,9,1480,882,947,929,42,253,672,974,491,524,1201,1317,1369,921,627,1338,1473,563,287,588,1073,921,512,1343,1343,512,1483,598,265,920,183,371,523,532,1343,1506,482,1090,1349,148,735,1002,9,958,1168,450,1168,295,504,710,342,182,1199,1324,430,1370,889,588,715,450,1349,173,1255,524,265,97,321,431,1349,1370,1265,1245,1077,431,159,1154,1262,1020,656,483,382,491,1133,420,188,188,1343,1334,1325,482,629,1173,568,1167,1167,1343,1199,695,1274,359,1173,137,483,1204,1435,18,1333,1340,849,148,1448,658,1355,686,57,1099,1480,1365,1253,532,1345,1168,1110,499,1343,455,57,1349,1124,465,316,499,640,1325,359,521,1266,1266,1199,699,1506,1367,1349,672,1204,1266,1311,1343,888,1702,682,1257,1341,482,1399,1365,1419,1338,1327,888,818,452,728,1349,723,246,1375,1367,619,84,670,431,661,1099,1375,1349,394,963,627,674,1786,518,482,1369,744,1077,849,1317,97,40,1441,381,265,670,1317,1274,1326,651,145,963,1369,1311,1216,1110,1448,306,385,1297,437,1453,362,674,442,1253,78,627,528,1916,291,1367,1266,1168,1254,974,735,9,588,42,882,929,183,371,1369,1124,450,430,295,1916,958,188,715,1255,1343,1334,1245,656,483,1343,491,431,1340,1349,1435,658,640,499,521,1367,672,461,1419,1349,452,723,394,1099,671,509,651,492,1266,214,492,492,1323,214,672,1359,198,484,1597,198,870,1460,2090,1459,491,728,728,1366,656,137,535,648,799,921,672,521,2090,512,2144,1270,1266,486,728,629,1067,1423,648,512,921,651,1323,198,700,700,430,130,341,733,733,341,872,965,962,756,341,1268,598,962,733,149,332,341,962,733,2192,714,2212,468,728,1492,837,329,465,465,509,395,1172,1195,442,456,583,442,1089,627,803,145,432,432,1343,930,227,1260,1211,753,753,930,224,161,1361,251,1340,1262,1324,962,716,1324,951,1375,627,230,272,95,1322,1323,1324,227,12,1440,224,95,1340,715,1421,1324,272,670,1447,330,342,343,2232,2224,1370,201,965,129,1219,1457,1445,723,18,66,1216,57,1370,129,1216,262,730,772,262,1483,383,383,265,329,1158,1369,886,123,359,923,1369,1153,1164,1089,1089,329,327,1241,22,253,1216,1450,963,1073,1078,1362,1078,1186,1253,524,512,474,524,1324,1198,1110,1257,1268,1324,618,55,1334,959,417,1255,1255,445,1282,295,1410,1300,1247,1410,827,780,2021,1415,485,1483,482,482,2020,482,147,139,139,430,1318,1345,1282,38,1399,1282,116,145,1324,525,1177,651,651,158,968,1282,559,814,521,1333,186,1378,1366,160,1342,799,1399,1492,964,1378,1324,850,295,1250,1343,187,1492,250,160,511,1320,147,139,430,1345,525,651,38,1177,1483,2109,1366,186,964,1250,661,866,374,866,1324,661,662,374,543,218,651,218,661,374,543,1929,958,66,1929,1375,178,430,532,1280,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1054,F,F,F,F,F,F,F,F,F,358,1745,700,F,F,F,F,362,226,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,20,1173,800]),
-PinyinRangeTable::new(0x20000..=0x2D016, &[429,920,F,944,F,415,F,F,F,958,100,F,F,1080,F,F,F,F,F,1110,532,F,F,F,F,F,F,1367,F,563,F,F,F,F,1483,F,F,F,1289,F,F,F,F,F,F,F,F,F,F,757,F,F,F,F,F,482,1338,535,F,F,930,672,F,F,F,270,F,F,F,F,F,F,F,158,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,F,F,F,F,F,F,945,F,F,F,F,348,F,F,F,F,F,F,F,921,F,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,460,F,1343,1511,505,F,F,F,F,F,F,F,F,1492,F,F,1485,F,F,F,F,F,F,F,F,1381,F,509,F,F,860,F,278,192,F,1072,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,465,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1346,F,402,F,F,F,F,F,F,F,F,F,518,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1069,F,F,F,F,F,F,F,F,F,F,F,F,F,F,521,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,267,F,F,532,505,F,254,F,532,179,F,F,F,651,F,F,F,1185,F,F,F,F,F,F,F,F,370,F,F,F,F,F,F,F,F,F,F,1320,F,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,1236,F,F,505,F,F,F,F,F,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,F,259,F,F,921,F,F,F,F,F,F,F,F,F,1258,F,F,F,F,F,F,F,38,F,F,F,F,1282,F,F,F,F,F,F,F,1425,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,1304,F,F,F,F,F,F,F,F,259,F,226,F,F,F,1237,F,F,F,387,F,F,F,F,F,318,F,F,F,F,753,F,F,1257,1324,F,F,799,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,227,F,F,F,1157,F,F,374,F,F,F,F,573,161,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,965,F,753,F,1077,F,354,F,1073,F,F,F,F,F,F,F,F,F,1215,F,F,F,1082,F,F,F,F,813,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1379,387,F,F,F,F,F,F,1274,610,F,F,228,1134,F,F,F,707,1341,F,1161,F,57,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,684,F,F,F,F,F,F,F,F,F,F,1259,473,218,F,F,526,F,272,F,F,11,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,717,F,F,978,F,6,1367,F,F,493,F,923,F,F,F,F,F,F,F,F,F,F,742,F,F,F,1456,1448,1451,783,76,F,1469,1090,1316,532,931,F,F,387,F,1198,F,F,F,1352,1448,F,F,F,F,F,F,585,F,F,F,F,F,F,F,139,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,634,1317,F,F,F,F,407,736,1065,1366,F,1112,1119,F,1448,F,F,942,F,344,F,F,524,44,F,57,F,F,1136,F,F,931,745,121,F,F,1035,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,376,956,F,F,F,2272,F,F,F,1119,1119,1332,F,F,F,F,F,F,F,F,F,154,F,F,1096,1432,629,352,1128,1419,F,1472,F,F,F,F,F,F,F,F,344,F,F,F,F,F,482,268,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,471,F,F,492,1324,1448,F,F,F,F,F,F,F,F,F,F,F,716,1334,245,1341,F,F,F,67,F,F,963,1343,F,1329,F,F,F,1411,F,F,F,1047,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,598,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,532,F,920,F,F,1365,F,F,1385,1023,1039,F,F,283,F,F,F,506,553,F,F,1373,F,F,F,492,1332,F,F,F,F,F,F,F,F,F,F,1265,F,1277,938,F,F,F,1367,963,F,F,F,F,F,1265,684,F,396,F,F,141,176,1341,F,1443,F,1490,280,F,1417,F,F,F,F,F,F,F,F,F,F,F,F,F,F,326,1341,723,F,F,F,F,F,F,58,505,1090,640,56,1122,F,F,F,248,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,44,1243,F,F,F,F,723,F,121,F,F,F,F,F,F,F,F,F,F,F,234,F,F,F,F,901,492,650,401,920,441,F,F,F,483,1282,1442,121,F,F,F,F,F,F,F,F,F,1332,121,F,F,F,F,F,F,250,153,788,788,F,F,1418,58,F,121,F,F,F,F,F,F,F,F,1274,F,F,F,F,201,1283,513,929,F,950,F,F,F,F,383,F,F,F,F,F,F,F,F,F,F,1255,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1371,75,F,F,F,F,1228,F,F,F,F,F,F,F,F,F,F,F,F,F,809,F,F,F,F,634,F,F,986,F,F,F,F,F,318,246,F,F,F,F,F,F,F,F,F,F,468,1343,1266,F,F,F,F,F,F,F,F,F,601,344,F,1296,F,F,636,1157,483,F,F,F,507,1411,1077,F,F,F,F,F,921,59,F,602,649,F,F,F,F,F,F,F,1448,57,1076,F,F,F,F,1076,F,947,F,F,63,1257,F,F,F,F,F,530,F,482,F,1257,948,F,F,415,F,F,F,678,F,F,F,F,F,F,F,F,F,F,1375,F,F,F,F,F,F,F,632,F,F,F,F,F,F,31,F,F,437,F,F,F,920,F,F,F,929,1444,711,F,F,F,F,192,F,F,768,F,F,F,F,F,1187,F,1488,F,F,F,F,F,F,F,F,516,1429,F,F,F,F,F,F,F,F,804,142,F,F,928,F,F,1367,501,F,F,F,F,F,1430,F,F,F,F,F,246,518,F,F,F,1130,F,F,1331,F,F,F,1231,F,F,640,F,1190,F,725,F,F,F,F,1361,F,F,F,F,F,F,F,1110,F,F,F,F,F,F,F,667,F,F,F,1344,F,F,F,F,F,152,F,F,F,F,F,354,518,F,F,F,F,F,F,F,F,F,949,517,F,1280,F,F,F,F,F,F,F,F,431,F,F,F,1157,F,959,1280,514,804,514,F,F,483,F,F,F,F,F,F,F,F,1174,F,207,257,F,952,F,F,F,F,F,F,F,906,905,F,257,667,F,F,F,F,F,F,F,F,F,633,418,865,1154,F,F,F,1341,1307,1137,656,1100,1072,F,85,1118,F,F,947,1071,F,F,F,827,1187,495,F,F,F,F,F,F,F,F,1257,1448,F,F,F,F,F,F,600,629,629,F,F,430,502,F,1323,F,1087,F,F,1079,F,F,F,F,F,F,1440,F,1359,F,F,F,F,F,F,1137,1253,F,F,F,F,F,124,192,F,F,F,526,F,F,F,F,F,F,F,1087,F,F,F,F,F,521,1236,F,F,F,F,F,F,F,F,480,F,F,F,F,F,504,F,F,F,F,F,F,F,1397,F,F,F,F,F,F,F,F,F,851,F,F,F,F,F,387,F,F,F,F,F,F,426,628,1448,1268,F,F,82,126,F,F,F,F,F,F,F,F,1376,430,F,F,F,F,F,F,F,1157,F,F,F,63,F,F,F,F,253,F,308,518,F,F,F,F,248,1343,588,F,1433,F,592,1453,526,F,F,F,1055,1047,253,31,482,F,F,1450,F,F,1341,F,566,1252,F,F,F,362,21,F,F,378,F,F,1266,385,657,147,389,170,F,627,201,1091,F,F,F,F,F,F,56,F,F,F,75,629,F,F,520,1176,286,F,1322,967,F,F,645,F,556,F,367,1438,F,329,F,F,F,F,F,F,F,F,1341,F,521,1304,501,F,F,688,520,F,156,F,F,1268,F,577,280,F,F,F,F,681,1257,949,85,F,F,F,F,F,F,F,F,F,F,928,F,F,F,F,F,F,F,F,F,F,F,F,885,1317,49,406,385,F,F,F,524,F,F,925,F,532,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,454,499,F,F,F,F,F,F,928,F,1411,925,F,1433,111,1351,F,F,1322,F,150,F,F,F,147,F,1227,F,1118,F,F,F,F,F,544,1370,F,F,F,F,F,F,F,F,F,155,F,1136,1198,F,F,1433,1167,F,1252,F,217,629,111,F,F,F,F,F,F,F,F,F,F,998,376,974,F,627,F,F,F,F,1157,F,F,F,F,F,629,F,F,728,F,148,F,F,403,665,176,1136,501,511,F,F,F,311,1415,F,F,F,F,F,F,F,511,207,F,F,110,1507,F,1430,F,F,897,1421,1257,1334,343,150,F,F,F,F,F,207,F,F,F,385,F,F,F,F,F,F,F,F,F,F,F,482,F,F,1039,1419,653,1039,1338,F,F,F,F,F,523,F,F,F,F,F,1198,F,F,F,1005,1399,461,F,F,F,F,1267,F,929,1430,F,F,F,F,F,F,94,F,F,592,629,1002,F,F,F,272,F,F,645,F,F,F,F,1349,629,F,F,272,F,651,F,F,F,F,F,F,F,1227,F,F,F,F,257,F,F,F,518,F,629,569,561,F,1440,F,F,F,F,F,F,431,F,57,F,885,F,F,F,F,F,422,F,F,F,F,F,F,1480,279,F,1343,F,F,F,F,F,F,F,556,1343,753,F,F,94,F,372,556,1080,F,F,F,F,F,F,F,651,49,F,F,277,F,F,F,F,528,783,1485,F,F,1490,F,F,F,F,F,F,F,1154,F,F,1262,420,F,F,F,F,690,930,F,F,F,F,F,753,849,426,F,F,F,1411,530,192,F,F,F,F,629,1411,1361,252,532,44,F,F,F,1333,897,F,F,F,F,F,F,511,541,1039,1328,511,F,F,F,556,F,F,F,F,F,F,F,F,F,F,119,F,F,804,F,1230,688,F,F,F,F,F,1377,1331,38,F,F,535,1307,F,1453,F,F,F,F,F,F,F,587,335,F,F,F,F,F,F,F,F,963,1063,1133,F,F,F,271,F,576,872,F,F,F,F,F,F,41,F,F,F,344,521,978,F,F,F,523,F,F,F,F,F,956,F,F,1453,456,40,F,F,F,F,F,1341,F,1341,1341,F,F,F,F,712,F,F,F,F,F,F,F,F,1011,F,F,F,F,188,F,F,418,F,192,F,F,F,1260,F,F,F,F,968,1177,F,255,F,418,F,F,F,F,F,F,F,F,1337,F,F,F,F,F,290,1236,F,97,255,F,F,F,295,248,F,1126,968,F,F,295,849,1307,F,F,F,1254,F,F,F,1343,F,756,F,F,F,F,F,F,F,449,F,F,F,418,F,F,F,F,F,F,F,F,F,F,1078,F,F,F,1023,F,F,F,F,57,F,418,514,1260,F,949,214,190,F,31,F,F,F,F,278,F,F,F,F,1260,F,F,F,F,F,F,F,F,F,F,1445,676,F,449,483,389,F,F,F,F,F,865,F,F,F,F,F,F,F,F,F,F,F,F,F,1457,F,F,F,F,56,F,1365,F,F,F,F,F,F,F,F,F,F,922,F,F,430,160,F,F,1063,148,78,F,F,F,994,1360,F,F,F,771,F,F,F,F,471,1177,32,F,F,F,F,1301,F,F,F,1360,148,F,F,F,F,F,F,F,F,F,437,F,F,F,1226,F,F,1282,F,F,F,532,F,1128,950,1469,F,F,F,F,F,F,485,F,F,57,F,1257,F,F,F,F,F,483,F,537,F,F,640,1359,F,F,F,F,F,F,524,F,F,F,F,1375,F,F,37,F,F,886,F,F,1400,F,F,1343,247,F,F,945,F,1136,F,190,F,1460,1375,F,F,F,F,F,499,F,F,F,F,1078,F,F,1341,1262,F,F,F,F,F,F,F,1370,F,F,F,F,405,F,556,F,F,207,1343,F,F,F,F,F,F,F,F,F,629,F,251,F,F,1257,F,F,F,F,57,F,F,59,715,629,F,F,1119,F,F,F,F,F,F,F,F,656,401,556,F,F,F,F,1341,F,1259,1345,F,F,F,F,556,F,F,F,1067,F,F,F,1249,F,F,F,F,F,F,888,F,F,F,F,F,F,F,1375,442,F,F,629,344,F,F,F,F,F,F,F,532,1265,F,F,F,F,250,F,F,629,F,F,F,F,F,F,1198,F,F,F,F,491,F,F,28,248,1424,F,F,F,F,F,1367,F,F,F,F,280,F,F,F,F,93,1199,F,F,F,F,F,F,F,1149,483,921,1055,803,F,F,F,F,F,F,F,F,F,F,F,394,F,56,F,F,1288,F,F,F,F,F,F,F,1440,F,F,F,F,F,1021,F,753,F,342,F,F,F,F,1157,37,F,F,F,F,F,F,65,F,1257,F,505,F,F,F,F,F,F,F,511,F,F,F,F,F,F,F,929,F,F,F,F,F,F,F,F,F,1112,513,F,147,F,F,F,F,F,F,F,F,F,F,F,513,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,F,F,F,F,F,F,F,F,F,1410,627,F,F,F,1479,F,F,F,F,60,F,F,F,F,F,1212,F,F,F,57,F,F,327,F,F,F,237,F,1458,F,F,F,F,523,F,F,F,F,F,F,F,1342,F,F,1320,F,F,148,387,1447,F,F,F,F,F,F,994,F,F,1359,F,78,F,484,902,F,F,1349,1326,708,F,F,F,F,F,F,F,663,2249,1024,170,F,188,1254,992,F,F,F,224,483,F,1342,F,978,F,F,480,385,F,1433,888,F,F,1381,33,505,F,F,445,F,F,1268,468,F,F,F,F,F,F,F,F,1410,222,362,F,888,F,892,1078,636,1375,452,60,F,F,994,F,994,F,F,F,F,F,F,F,F,F,F,1340,1445,F,509,1245,128,F,958,F,1459,F,1411,909,12,F,430,F,158,1323,F,F,F,1080,452,295,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1078,F,F,F,F,1215,224,1226,907,999,523,F,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1366,268,F,400,1085,F,F,1134,801,1453,663,75,1508,1338,979,F,651,1023,F,F,619,295,F,1452,484,F,F,295,F,F,F,1512,F,F,768,1378,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,1503,1090,264,314,991,F,35,418,442,1340,F,1340,F,F,553,1343,469,1442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,514,F,F,363,F,F,828,945,F,257,F,485,1343,1341,F,342,1109,1109,1357,559,455,444,F,F,F,430,F,F,429,931,928,F,1112,F,35,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,512,556,F,F,F,F,F,5,665,F,1198,F,F,175,F,F,1117,142,F,1235,F,F,832,F,520,F,F,70,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1277,1073,447,F,F,1460,F,392,482,F,485,F,1257,F,1067,851,450,1142,1275,F,1398,F,F,80,923,1219,1215,235,F,768,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1415,F,F,1320,F,F,1254,1439,238,429,F,13,886,1039,330,385,F,F,F,909,F,F,F,1309,417,356,21,1488,725,F,480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,250,1257,F,F,220,780,F,F,F,253,684,556,F,F,F,F,1343,532,430,F,483,F,F,431,814,1017,930,224,1060,556,1458,F,1077,688,486,890,448,482,1142,155,1248,514,907,1415,1283,F,F,60,F,F,1304,F,F,F,F,381,507,F,F,1266,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,291,F,78,893,F,F,F,1383,F,860,1198,F,1349,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1272,F,F,840,362,78,1282,F,F,F,1438,1365,801,F,F,F,F,F,1316,1222,F,16,420,441,226,F,838,F,102,483,793,1357,1274,F,164,1334,F,363,1154,41,121,F,1304,F,F,413,F,155,F,492,1509,F,F,F,1238,217,885,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,F,1257,F,880,655,2245,738,607,1210,31,F,F,362,F,572,F,F,486,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,F,1496,684,F,F,F,F,F,F,627,F,430,751,F,1104,1069,548,148,651,683,F,F,1324,1430,164,383,948,F,1150,331,1199,F,F,F,F,651,F,F,606,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,601,1390,1255,F,F,F,F,627,0,690,1447,155,497,F,491,F,F,F,F,678,1341,F,1056,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,815,F,F,F,471,F,1410,F,F,419,F,1346,57,9,1261,799,F,F,F,245,492,862,F,F,1367,169,1381,F,112,F,1433,1039,F,877,381,1433,F,F,F,F,F,627,268,F,155,F,1503,910,F,F,1064,661,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1090,F,511,651,F,F,546,596,F,1301,511,F,170,F,F,F,1375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,699,1282,518,485,F,1375,F,F,F,491,F,418,F,1023,471,941,F,1039,1503,F,F,671,456,158,1054,1250,483,1480,1266,1340,405,587,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1453,F,F,672,F,77,1078,1352,569,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,1280,F,F,1338,295,688,420,1338,F,F,F,F,684,186,318,1446,1352,1242,1219,8,1365,F,F,454,F,645,512,1382,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1392,280,F,485,F,F,F,1248,F,483,1257,F,1422,1204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1365,F,F,F,F,645,F,F,1448,1077,F,611,600,1237,870,146,1351,270,F,270,F,41,945,1090,F,1446,F,F,F,F,F,645,F,882,F,1432,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,849,F,F,1282,483,600,1350,108,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,614,F,F,F,F,F,F,680,F,F,F,661,1260,F,F,F,650,F,F,399,F,F,F,F,F,F,F,F,F,1288,F,F,F,627,187,F,F,F,F,F,F,F,F,F,F,F,F,F,F,952,F,F,F,F,F,F,F,F,F,491,235,492,953,1282,1352,F,F,F,F,F,F,F,F,409,F,1436,1064,728,461,F,F,F,F,F,F,F,F,F,F,F,F,201,1005,1022,480,1340,F,245,F,676,F,1343,F,F,F,F,F,F,80,866,1150,291,1392,192,F,F,F,F,F,F,F,F,F,F,F,1415,F,1259,707,F,596,1379,F,F,F,F,F,F,F,295,F,F,F,257,F,F,F,F,F,F,F,392,F,F,F,463,F,F,F,1080,492,F,F,1419,483,463,F,F,F,F,F,F,F,F,F,1230,683,F,F,F,F,F,F,F,F,270,F,F,F,F,F,634,F,F,F,F,F,F,F,F,F,F,F,F,F,814,775,521,1375,F,F,1331,174,F,F,F,95,628,283,775,775,F,F,F,F,F,996,F,F,F,F,1375,F,F,1360,F,1344,F,405,F,F,F,231,F,F,F,F,F,F,F,F,1438,728,257,F,F,1438,F,F,F,573,F,418,1117,430,482,1433,F,F,F,75,F,1236,1193,F,1199,F,F,356,665,968,476,1468,974,F,442,F,231,430,1147,389,F,1367,F,1320,F,F,1227,975,F,F,F,F,532,849,F,F,967,1446,F,F,651,1252,1286,218,F,1369,1372,F,F,F,753,F,1360,F,F,F,F,1254,F,1423,F,F,1306,F,F,986,402,1367,F,F,F,F,F,1262,59,1360,F,1344,F,1307,1360,618,F,F,F,F,F,1187,F,F,1438,F,F,F,F,1386,346,594,F,F,974,F,F,F,F,524,F,182,217,1212,5,F,F,F,1485,F,F,465,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,41,146,F,F,996,F,F,F,F,F,F,670,F,F,505,1080,F,1499,F,F,F,F,F,F,1343,F,F,331,331,F,F,F,753,F,F,F,1090,F,F,F,F,F,F,F,F,F,F,F,F,F,18,F,887,905,907,487,1454,F,958,F,F,F,1361,1150,F,F,999,F,730,F,F,F,F,F,F,F,F,1343,F,999,F,F,645,956,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,469,485,F,F,F,F,361,F,F,F,F,F,F,F,1360,111,237,1344,F,1367,44,F,78,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,938,F,F,F,F,F,113,F,1283,F,146,F,F,F,F,1398,585,F,327,F,F,1139,391,F,F,284,F,F,F,F,399,F,1446,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,783,F,F,450,F,1158,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,814,1418,461,F,274,F,923,104,F,F,179,F,F,F,217,F,F,F,1448,373,F,F,F,1247,F,F,F,F,F,F,F,F,F,F,F,F,F,274,F,F,F,146,F,F,F,12,592,F,1250,F,F,F,1349,F,891,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1411,1462,F,1124,F,F,F,F,801,F,F,F,F,F,F,1458,120,F,F,52,799,1446,471,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1264,F,1448,1257,F,F,496,F,F,F,F,F,F,F,F,F,F,278,341,F,F,499,129,29,F,F,F,F,F,645,F,F,F,F,F,F,18,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1395,161,F,F,1217,F,F,426,546,1345,F,1268,F,F,F,F,F,F,1857,66,F,584,F,F,F,945,1023,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,217,1337,F,F,F,1424,F,635,F,279,F,F,F,F,610,1314,F,F,F,F,F,F,F,F,F,1448,F,569,F,F,F,1131,1248,F,F,F,569,F,F,F,F,F,F,F,F,F,F,F,492,F,F,F,F,F,F,496,F,F,F,F,1475,F,1100,1365,F,F,1023,F,1367,602,F,F,F,F,F,1367,F,931,526,F,645,F,F,1088,1268,F,F,352,F,F,F,F,F,F,F,F,F,F,F,1145,F,F,F,F,1174,F,F,F,F,F,F,F,725,F,246,F,731,F,F,F,F,F,F,F,F,F,F,468,F,F,F,F,F,F,F,F,F,F,287,F,F,F,645,F,F,F,F,600,1345,602,F,499,F,480,F,F,F,F,F,F,F,F,F,F,404,F,F,1422,F,F,F,F,F,F,729,F,F,584,F,F,F,F,F,F,287,F,F,F,F,F,F,F,1345,F,F,F,F,F,F,F,620,F,F,F,F,F,F,F,F,F,F,F,F,F,376,F,F,F,F,F,1187,1332,F,1233,F,F,F,F,505,F,F,F,F,1297,F,1090,F,F,F,F,F,F,1238,F,F,1367,F,F,F,F,F,F,F,F,F,F,F,F,1419,F,F,F,F,F,F,F,13,F,F,F,F,F,1033,155,F,575,F,F,F,525,415,F,F,F,F,F,F,F,F,F,F,732,F,F,F,F,424,F,F,155,F,F,F,651,F,1488,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,588,F,F,1450,F,1427,F,258,379,1377,226,2274,F,F,F,F,F,F,75,F,978,119,F,F,F,999,1344,119,F,F,1448,F,F,391,838,1069,F,F,1124,F,F,1249,147,F,F,F,F,F,F,740,1446,F,F,920,F,F,378,F,F,F,F,666,F,1483,F,F,F,F,230,F,1267,F,1003,F,F,F,F,F,883,F,F,F,F,F,F,1257,F,F,F,573,F,401,183,507,F,F,F,F,F,F,F,F,F,F,F,507,1257,F,569,F,381,1413,318,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,461,F,809,1260,F,F,F,F,F,F,F,F,F,F,198,F,402,F,F,F,1257,F,F,F,F,F,926,F,F,705,F,F,1433,F,F,530,F,F,F,66,F,F,F,F,F,F,F,F,F,F,F,66,F,F,F,F,968,F,F,F,F,F,1260,F,F,501,F,968,1447,1172,540,F,F,F,F,1029,F,F,1483,F,F,F,F,F,F,F,505,F,F,F,F,F,F,67,F,270,1502,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,57,F,F,F,576,F,1325,1236,F,460,F,428,F,F,F,F,374,F,F,723,F,F,619,F,248,F,75,460,F,F,1219,532,F,F,F,F,F,F,F,148,F,F,F,F,21,520,F,F,F,F,F,F,F,F,248,F,1425,F,220,1078,428,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,F,F,F,F,F,F,F,57,887,1333,F,F,245,96,901,1375,942,885,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1217,1282,F,F,F,F,F,F,1338,F,F,318,385,452,1006,F,F,F,F,F,979,340,464,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1005,F,F,F,F,F,F,F,711,F,F,278,471,1260,1297,F,F,979,1340,F,F,1433,F,485,361,1362,F,915,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,198,1433,809,F,945,F,F,112,F,809,1128,F,F,F,F,F,112,142,1332,274,1231,F,805,728,F,F,F,F,F,829,1260,F,1331,F,119,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,740,562,F,201,F,1075,864,452,F,556,1268,F,446,956,1488,F,F,342,772,F,801,F,571,F,F,F,F,F,F,F,F,F,F,F,F,F,F,791,F,F,F,F,F,F,F,F,F,F,F,F,F,F,362,F,F,F,446,F,5,F,1077,F,F,F,F,F,F,F,F,1297,191,499,F,1411,1274,634,964,F,F,1054,1282,F,376,740,116,F,302,F,F,F,270,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,1177,1077,F,F,F,1032,F,F,392,F,F,F,428,1448,1329,1189,57,F,751,F,342,F,F,F,F,F,F,935,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,1118,F,F,809,530,F,1329,F,464,F,F,F,49,751,129,F,F,F,687,1060,84,1407,1110,F,1504,1373,1387,675,F,F,F,F,F,F,F,F,F,F,F,F,F,963,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,736,F,F,F,F,F,1469,F,F,F,F,F,F,F,231,F,1369,F,F,F,F,F,F,F,525,F,F,470,923,F,1379,F,704,F,752,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,893,F,511,F,F,F,F,F,1331,F,F,F,F,F,F,148,801,1118,F,F,F,F,F,1090,F,F,893,F,F,F,F,F,F,F,F,420,F,F,F,F,F,F,F,F,F,F,F,1331,F,788,F,F,F,F,F,F,F,1080,F,1369,F,92,505,F,F,F,F,F,F,F,F,F,1282,F,F,F,1323,1274,F,F,F,F,F,F,F,F,F,F,F,F,1282,629,F,318,F,F,F,F,F,F,F,F,1460,F,768,F,1468,F,F,584,F,F,F,682,F,F,F,F,F,F,F,F,925,F,F,F,F,F,F,F,F,F,F,1227,F,F,F,F,F,F,1089,F,144,F,1343,F,F,F,F,427,F,502,F,F,471,F,1277,188,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,F,F,F,F,F,800,F,800,1169,F,F,F,F,F,F,F,F,F,F,F,F,526,F,746,F,F,F,F,627,F,1452,F,1304,F,956,342,F,72,F,F,F,485,F,F,921,1260,F,F,F,F,F,244,306,F,F,F,F,F,1088,1190,2499,F,886,F,F,F,F,F,F,F,F,227,F,483,F,F,F,F,F,F,F,F,1277,F,F,F,192,F,F,F,70,999,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,733,F,F,F,F,733,F,1087,1275,40,1222,F,872,F,F,F,F,F,F,F,F,F,351,F,449,437,F,1458,389,F,F,F,401,F,F,F,F,F,F,F,F,F,F,F,224,F,F,70,466,F,F,112,F,F,F,1264,524,F,1333,F,F,F,F,F,F,F,F,F,F,F,F,F,F,330,1398,F,F,F,332,F,F,F,F,F,F,523,1367,F,F,F,F,F,473,F,F,F,F,F,F,F,F,505,1296,F,772,F,F,F,F,F,829,F,1075,F,1367,F,F,461,372,1229,F,1216,938,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1347,F,486,F,F,F,F,F,F,1136,F,505,1257,1246,F,F,F,706,F,F,F,F,F,F,F,F,F,F,F,F,1327,F,1332,F,F,F,F,706,849,F,F,10,F,F,F,667,F,F,F,F,F,F,F,F,F,F,F,295,1485,F,F,F,F,295,F,12,F,F,F,F,480,F,109,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1296,485,F,1510,F,921,F,F,F,F,1442,F,F,F,F,F,482,920,529,818,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1039,F,F,F,F,F,431,999,948,F,F,F,F,523,F,F,629,F,F,F,F,F,1078,F,F,801,1266,341,F,F,1006,F,F,F,1296,401,F,485,F,724,341,F,1027,1367,502,725,2171,934,F,2229,F,F,1341,F,661,417,801,612,1043,F,F,649,F,1367,F,F,F,F,F,F,838,F,F,F,F,F,1255,F,F,F,59,F,F,59,1306,F,491,F,F,59,F,F,F,F,F,F,F,F,F,237,F,F,F,F,1466,F,F,F,999,F,F,F,F,1098,F,F,F,F,F,F,F,486,F,F,470,F,F,1419,F,F,F,29,645,F,1279,F,F,F,F,F,F,F,492,1084,F,F,F,F,552,F,F,F,392,676,F,F,F,F,F,829,125,F,F,F,F,F,F,F,F,F,F,F,F,F,F,635,F,F,F,F,F,F,F,F,F,F,772,1006,F,F,F,1448,F,F,F,F,F,F,F,101,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,601,F,F,F,119,F,1231,F,F,629,F,F,1255,870,1362,F,353,F,F,F,9,1297,1103,1014,F,F,F,F,F,32,1360,F,F,F,F,F,F,F,F,478,F,F,468,F,F,1512,1274,F,731,F,F,F,F,349,1371,F,80,131,1209,F,80,F,F,F,F,F,349,F,1176,766,F,F,F,F,450,814,F,F,F,F,469,666,F,1168,F,941,939,1451,F,F,F,245,F,F,F,648,F,F,968,1466,F,618,F,1280,F,F,990,F,F,229,F,271,804,F,F,F,1079,F,F,1266,F,F,F,F,F,F,1446,F,F,F,8,187,916,F,1079,F,F,F,962,1089,250,F,1276,1103,F,461,F,F,F,1341,528,F,F,1447,F,F,F,F,F,F,F,1430,F,F,F,F,F,F,1304,F,F,F,F,F,F,F,F,F,F,F,663,F,1460,F,1136,F,F,F,257,F,F,963,F,556,449,523,F,952,F,F,F,F,F,F,F,F,F,F,F,F,74,F,F,F,F,F,F,F,1170,F,532,F,F,959,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,1379,F,F,717,885,F,963,F,F,F,F,F,F,F,F,F,F,F,730,F,F,1170,F,F,542,F,56,F,962,F,F,F,F,F,F,F,F,1176,F,161,F,F,F,F,F,F,524,F,1257,F,F,F,650,F,F,F,F,F,F,F,F,F,F,F,F,F,F,147,482,F,F,F,F,F,670,F,F,F,629,F,F,F,F,F,532,F,F,F,F,F,F,1457,672,F,F,F,F,F,F,F,814,F,F,F,F,F,968,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1320,F,295,F,452,F,F,F,F,F,F,F,F,F,F,F,F,F,F,706,F,F,F,F,F,F,F,F,1255,F,F,111,F,F,F,F,946,505,442,F,226,303,1400,452,16,507,344,1358,F,332,F,F,F,F,F,F,F,F,F,F,F,F,F,761,F,F,F,F,F,F,F,F,F,1039,192,F,F,F,546,F,F,F,F,F,F,1334,8,38,F,909,F,1079,318,F,524,886,F,1238,569,943,353,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,583,1131,49,486,1320,F,F,F,545,814,F,1289,F,1260,F,650,286,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,280,F,495,1366,672,303,F,F,383,F,1237,132,463,67,F,F,420,1208,768,922,F,1194,1369,1232,F,1253,361,F,F,561,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,1274,F,400,1320,1131,1116,F,1480,F,F,1198,1267,F,F,F,F,1400,629,F,F,1460,F,505,F,F,1170,F,F,1280,956,F,1320,523,F,F,1345,1446,F,544,1483,F,553,F,814,935,1229,1399,F,523,F,1486,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1320,F,F,648,F,921,F,F,F,F,469,F,F,F,F,923,F,1327,1131,F,922,399,F,F,F,F,F,F,F,F,949,290,F,1512,F,F,1400,923,483,F,1216,257,F,471,710,F,1303,F,F,446,1324,1271,191,450,F,F,12,F,75,F,F,F,F,F,F,F,F,286,F,F,F,F,F,F,F,F,1459,257,1359,922,1078,1314,1359,543,940,F,F,934,878,F,F,968,F,1350,F,F,F,F,F,1044,F,F,F,1157,F,444,887,1332,F,F,F,F,1199,116,F,1264,921,F,956,F,F,511,F,F,F,F,F,F,F,F,F,F,1438,F,F,F,1457,F,1257,F,1245,1451,F,F,F,1131,F,F,553,592,548,F,F,129,57,753,1460,420,1366,1341,694,F,F,923,403,704,640,648,1496,619,452,176,923,618,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,145,F,908,257,F,F,F,F,F,619,F,F,F,1342,F,F,F,F,252,F,F,281,359,449,F,1274,347,881,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1071,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,280,129,1346,577,569,F,1504,383,F,F,1379,1448,F,F,485,141,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,1503,10,425,F,F,909,F,246,1338,F,F,F,782,F,F,F,F,F,F,F,F,F,505,37,602,98,F,57,F,F,F,F,1421,923,F,F,F,F,F,782,F,F,688,F,583,F,751,F,619,870,F,F,F,F,F,629,109,F,231,619,F,F,295,F,52,532,F,F,F,F,F,F,F,F,1307,814,F,415,F,F,F,F,F,1267,F,492,728,814,F,F,F,F,F,F,F,F,98,1116,1407,1343,F,F,F,151,F,98,F,F,F,F,619,838,629,F,F,627,682,F,F,F,1155,F,F,814,814,F,482,F,619,F,780,F,F,648,651,F,F,1266,1367,F,1384,969,645,F,F,F,F,F,1367,464,F,F,F,F,F,F,F,F,F,783,F,1316,F,524,480,F,1343,F,F,F,F,F,F,F,F,1257,1039,501,1355,F,F,F,F,F,F,F,1077,512,1230,1337,518,F,F,375,F,F,F,F,468,F,F,F,F,F,F,F,F,307,F,F,F,F,F,F,F,F,F,F,420,F,F,F,F,F,F,342,F,F,F,342,1480,482,F,F,F,F,F,F,F,F,F,F,F,F,35,F,F,921,1079,F,254,876,1267,1028,F,F,F,F,124,1373,F,374,F,1252,F,328,F,F,121,F,790,532,F,1429,F,930,F,F,19,F,F,F,F,1233,1450,F,464,F,85,1459,57,128,1442,342,566,F,1510,1309,F,344,F,F,F,1333,F,F,77,F,44,1280,1080,1341,F,442,207,F,1343,1466,F,F,F,F,148,F,F,F,F,F,F,907,F,F,F,1345,F,F,F,F,F,F,F,F,1372,F,F,F,F,516,F,F,712,932,F,1343,F,F,F,1253,F,F,F,F,F,F,F,F,F,F,F,F,42,480,192,563,F,F,F,F,1142,F,420,932,F,F,F,1446,F,F,F,F,F,1039,F,F,929,406,F,F,402,F,F,491,1450,732,400,1080,756,295,23,596,F,F,F,1456,F,483,F,F,F,F,F,1397,F,F,F,1410,1343,F,379,F,F,399,1349,1050,430,37,753,723,F,F,1255,224,F,F,517,F,F,F,F,420,F,F,1189,566,627,1448,471,1389,F,254,201,F,F,F,F,F,F,F,F,F,1448,F,574,F,1273,456,640,207,938,501,F,1301,308,F,1215,1150,1448,F,F,F,F,F,F,783,712,248,109,F,F,501,632,F,1044,228,F,F,1131,632,407,F,F,64,F,190,252,688,800,1324,601,F,F,352,159,F,F,F,57,1496,471,F,599,1266,331,431,F,F,F,F,F,F,F,F,1334,1421,F,788,F,683,F,1370,F,792,F,F,F,F,F,F,991,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,363,F,492,905,F,F,F,69,F,F,493,F,F,76,F,F,F,F,F,730,452,F,F,F,254,F,1359,1331,49,F,137,482,F,1331,F,F,F,F,F,F,F,F,F,392,1325,F,F,F,F,F,F,F,F,F,F,F,F,147,F,1047,1324,1343,1343,135,F,F,420,464,F,F,F,F,1104,1131,990,1150,1447,F,318,334,F,F,F,F,1150,F,728,886,85,768,1173,22,1343,F,F,F,F,F,F,F,F,F,1324,F,1176,F,1332,1071,553,1190,F,1308,F,F,F,F,F,F,F,1362,F,29,F,F,F,1262,687,590,1392,959,F,F,201,1502,666,F,1262,F,F,F,F,F,F,F,F,F,F,1069,F,F,916,F,512,934,1343,F,814,278,F,505,1131,1422,195,F,F,49,392,1066,F,511,248,F,F,F,F,F,F,F,F,F,F,226,F,771,F,828,F,483,1323,F,830,F,274,1238,889,F,F,F,450,F,489,1338,536,601,596,1344,F,F,F,1208,F,F,F,F,F,F,F,783,F,F,F,F,1497,F,F,F,F,696,1110,1448,F,F,468,1475,F,471,F,F,F,F,F,159,F,135,F,F,1297,601,F,191,1072,753,1340,1332,1259,1503,76,F,F,F,F,F,F,F,F,F,F,1365,F,672,F,F,F,F,1208,1237,F,329,1071,F,F,F,F,F,F,F,F,640,F,F,F,F,F,F,1089,227,529,1365,1286,1332,1121,F,F,F,F,F,F,F,F,F,478,F,929,F,F,F,F,F,694,F,F,541,F,F,F,671,F,1359,F,F,F,F,F,F,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,1253,F,1346,1257,F,F,1415,1282,F,F,F,963,F,F,F,627,F,F,F,F,929,F,F,F,F,F,F,651,674,F,F,F,F,119,F,F,F,F,F,F,F,F,F,F,F,1444,F,1323,F,F,F,F,F,F,F,F,F,1347,585,F,F,F,962,F,342,F,1367,F,F,F,F,F,921,F,F,F,F,923,485,1369,F,F,F,F,F,361,530,F,921,F,351,F,970,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,492,F,1112,F,F,1343,929,F,F,F,F,F,F,F,F,F,F,629,F,F,1392,1343,F,90,1343,362,F,257,F,1445,1343,F,1384,224,F,1124,F,F,F,F,F,505,139,963,F,420,1266,F,F,F,F,F,F,968,505,F,F,F,530,F,F,F,F,228,F,F,509,F,F,F,F,F,F,74,450,F,F,F,532,F,1365,F,F,F,F,F,F,F,628,935,1103,569,F,1440,F,F,F,F,342,1069,F,F,F,179,F,F,1190,F,1343,F,1327,F,F,1216,1453,483,F,F,F,F,F,1316,F,1071,1306,F,F,F,F,F,656,1369,450,1444,F,F,881,F,F,F,532,F,F,F,F,F,F,F,F,F,F,1448,889,1372,F,491,F,F,F,866,F,F,F,1469,F,1266,F,49,F,191,F,753,F,F,F,405,F,F,F,142,938,F,F,F,F,F,F,57,F,F,936,F,1453,F,F,F,F,F,F,316,66,F,F,F,F,F,F,F,F,F,F,78,2353,F,F,F,F,F,262,968,521,1332,F,F,F,F,F,F,F,F,F,F,1262,F,F,1397,F,F,F,F,F,F,226,F,1254,1216,F,449,F,F,F,F,1257,F,F,F,F,598,F,324,F,F,F,F,F,F,F,F,F,F,450,F,F,F,F,F,F,F,F,F,F,F,F,1265,F,F,1054,F,F,F,327,F,F,216,F,342,F,161,F,F,F,F,F,F,F,F,264,603,F,F,F,F,F,F,F,F,F,F,1259,F,F,F,F,F,63,1367,1131,1259,F,F,F,F,913,F,1054,F,F,F,F,502,F,1343,F,F,1228,484,F,1228,1210,F,F,F,16,F,1172,146,F,F,F,978,F,1023,1345,885,189,1190,1346,F,F,363,1176,1442,1456,F,1341,575,1114,F,F,F,248,F,F,F,F,1280,F,1274,397,1217,332,1253,F,F,1300,F,F,F,F,F,F,F,F,F,1360,F,651,1325,F,F,265,923,1158,418,F,146,1114,F,F,F,F,F,969,F,F,420,F,F,F,F,F,F,F,F,F,F,1003,923,540,1365,111,144,1367,F,76,F,191,1457,F,1367,F,F,F,F,532,658,1035,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1096,F,F,F,F,F,1372,F,F,1423,F,F,1096,F,160,1423,1029,1265,F,205,724,246,F,F,F,1448,20,F,F,F,F,F,F,1297,F,890,F,502,579,1023,1268,1413,252,F,F,F,F,F,F,F,1341,F,F,471,1055,F,F,F,F,F,F,F,F,151,1341,1282,1448,1179,F,904,1266,F,F,1265,1124,F,203,F,F,F,F,F,1116,F,F,F,432,F,1268,F,1360,F,1367,F,F,1145,F,532,780,F,250,F,1343,57,F,F,F,F,F,F,1301,1343,1007,F,374,F,F,1343,F,F,F,F,1448,1283,F,485,F,1264,F,F,F,1427,786,1282,F,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,343,F,1067,F,1370,317,F,341,1255,1257,443,F,F,F,485,126,F,F,F,F,753,876,F,F,759,959,712,F,218,F,1262,1069,1161,442,57,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,800,F,939,F,F,F,F,F,F,F,1011,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,497,111,729,F,F,1343,F,1134,F,1255,1306,F,F,1258,F,1342,F,F,F,F,F,F,F,F,782,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,545,F,F,663,687,1471,F,F,F,F,F,F,1448,F,1291,F,372,511,1268,485,216,F,610,330,526,F,F,F,F,737,1262,F,F,F,F,1124,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,452,568,F,1136,801,F,F,F,F,F,F,F,F,F,F,F,1164,1460,F,F,F,F,F,218,F,959,1320,F,1266,F,F,790,F,F,F,1447,67,F,F,F,152,601,265,975,1273,F,1275,1229,1007,1234,801,F,26,1320,F,F,F,F,F,1110,1346,F,1367,F,F,F,F,F,627,480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,37,F,F,F,F,F,F,F,F,F,F,F,1257,F,518,F,F,1282,929,840,1290,285,484,1254,759,1325,923,766,148,446,F,1037,F,782,F,F,143,143,2114,F,489,1199,441,272,F,F,1262,1452,480,151,218,F,F,712,1334,F,F,F,F,F,528,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,1345,F,F,F,383,1255,F,F,F,407,1170,F,441,F,F,F,F,F,F,F,F,F,F,F,988,F,1341,F,1213,F,F,F,956,415,F,923,F,F,480,1170,885,F,F,372,F,1282,F,730,361,1139,1272,F,1087,F,F,342,F,F,F,F,F,1466,658,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1360,F,F,F,F,143,278,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,627,1329,627,F,F,671,760,1131,8,F,F,568,F,1433,8,1165,F,688,1208,54,F,F,F,F,F,F,F,F,471,461,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,592,F,1283,F,F,F,1037,F,F,F,F,F,F,F,F,F,1090,974,21,1210,F,F,F,344,66,F,1155,F,1273,F,1110,78,F,698,231,F,401,432,1257,231,1343,F,54,F,381,207,1039,F,F,F,363,1367,767,F,629,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1430,F,F,F,482,1011,F,F,F,153,F,F,F,F,F,F,F,F,505,F,F,F,F,F,F,F,F,F,126,1433,F,F,F,F,2428,1355,F,F,923,1480,F,F,542,F,1338,F,923,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1296,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1340,156,F,F,F,1205,8,900,F,F,645,731,8,F,752,1238,1352,800,F,F,F,78,F,658,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1015,F,F,F,F,F,F,F,686,112,F,F,161,F,1037,627,F,1114,F,629,F,F,F,F,1260,F,1322,213,F,656,F,F,F,F,F,F,F,723,F,1422,F,F,F,F,F,F,F,F,F,1472,F,F,736,F,629,F,525,F,F,F,1282,1282,662,661,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1165,1460,F,F,F,F,F,F,F,120,1267,F,F,1350,876,F,F,F,F,F,F,F,1280,F,502,F,F,F,150,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,429,F,F,F,F,F,F,F,F,F,1213,F,F,F,F,F,F,F,443,F,F,702,F,509,F,963,269,959,1384,F,1073,1384,F,F,1342,F,F,F,F,456,F,F,F,F,543,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,801,1110,F,1249,F,F,F,94,F,491,F,740,1061,F,999,353,F,F,F,F,935,F,1088,1480,F,F,F,F,F,F,1077,F,1170,F,F,F,F,1411,1419,F,F,F,F,F,331,740,F,1400,F,F,F,1448,929,420,363,F,F,F,F,F,F,94,F,405,499,F,1355,18,F,F,F,F,F,F,1411,F,1260,F,F,F,1301,1254,F,F,F,F,F,F,F,F,F,F,F,F,532,2007,F,148,F,1229,F,740,1402,F,F,F,F,505,1078,F,1257,F,295,F,F,F,452,452,F,629,F,F,161,F,F,1340,711,1301,1450,F,1343,F,F,F,F,F,F,640,F,F,F,F,491,492,524,F,F,1460,F,F,F,1254,F,F,F,F,F,F,556,555,629,56,363,F,1301,1044,651,553,F,F,F,F,F,78,58,1097,921,1055,F,F,F,482,F,940,F,F,F,F,F,1343,532,1424,F,1286,F,F,F,F,1215,415,1264,F,1216,1341,F,F,F,F,F,F,201,F,F,F,495,774,F,F,883,F,505,1310,450,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1361,832,1338,F,F,1347,F,564,F,F,F,F,F,F,F,F,F,1274,1270,F,F,F,F,782,F,1425,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,505,F,F,832,1055,F,F,F,F,F,F,F,F,F,F,F,F,F,487,F,F,F,F,1455,999,F,F,672,2365,833,F,78,1433,F,948,F,187,1496,F,1249,1254,F,F,812,F,F,F,1265,442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1188,510,F,F,F,F,505,431,1198,1433,900,511,776,F,F,F,F,283,F,1257,1282,F,1260,610,276,485,111,154,F,356,F,F,F,F,F,1271,234,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,1274,1283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1366,1266,627,931,F,F,716,F,938,1320,F,925,957,F,37,1442,F,F,F,F,F,F,F,F,F,1400,1098,F,F,1037,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,672,F,F,F,1280,F,343,1418,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1400,F,277,F,244,1367,F,688,F,1230,1311,501,1374,1448,1237,F,363,525,F,1324,216,712,F,F,F,F,F,F,F,342,5,F,F,1306,F,356,9,F,F,F,F,483,F,F,F,F,F,886,1447,F,F,838,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,864,F,1341,F,F,505,F,1483,F,489,1225,F,F,489,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,1136,1136,483,1116,F,1167,885,908,F,F,F,F,F,730,F,F,F,F,F,1338,F,949,511,F,F,531,F,F,1369,1010,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,33,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,70,F,F,F,1238,1398,945,1118,671,F,F,F,257,166,57,1458,693,327,897,1347,1309,F,20,1480,1496,F,F,56,F,F,F,F,F,607,F,1170,F,F,F,F,F,1178,491,F,F,F,F,1191,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,284,267,F,F,F,59,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,329,F,F,F,547,1448,1415,57,579,F,33,531,962,F,920,618,1280,1153,F,F,F,F,F,F,F,F,1118,F,44,F,F,F,F,F,F,F,F,1329,492,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1398,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1465,F,F,316,F,1065,F,956,F,910,F,1183,1044,1382,F,F,F,809,391,206,F,F,F,F,F,F,F,F,F,F,F,F,F,941,F,257,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,900,188,F,37,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1347,F,F,F,1267,F,F,1342,736,276,1456,F,563,F,F,F,F,F,F,F,F,1423,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,656,F,1447,121,272,1370,F,F,1137,505,629,375,F,F,F,F,F,F,F,F,F,F,35,F,F,F,F,F,F,F,F,F,F,405,640,1071,F,F,F,F,F,F,F,F,F,809,F,204,1237,F,1215,F,F,1121,F,661,F,F,F,F,F,1274,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,952,F,F,F,F,F,F,F,F,F,1257,F,1365,F,1444,1282,115,331,F,405,F,513,603,1265,F,F,651,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,619,F,F,F,537,1277,F,F,F,F,F,F,F,F,1382,F,F,F,F,F,F,F,392,945,684,1334,674,1142,F,F,F,F,F,F,F,684,F,F,F,F,F,F,F,F,F,F,F,F,23,122,F,1480,F,F,F,F,F,F,F,F,F,1178,F,F,F,1227,651,F,F,F,1367,923,F,921,F,F,F,F,485,78,F,1077,343,F,399,F,F,251,F,425,F,351,F,921,F,F,F,F,F,F,F,142,471,F,F,1262,1078,1448,921,F,415,F,F,501,629,F,641,F,938,F,F,F,1023,F,920,1077,F,F,505,F,F,F,F,F,F,44,58,21,535,885,F,F,227,F,F,1154,586,569,F,567,F,F,F,F,F,1077,1077,482,41,F,F,F,555,580,F,F,F,F,742,F,F,640,295,363,F,F,F,1233,285,F,F,928,455,F,443,F,F,881,F,502,F,F,F,F,962,1486,1456,580,F,1044,F,485,1235,915,1311,F,1060,F,F,F,F,F,605,1447,1187,F,F,220,F,F,F,F,F,F,F,F,F,F,F,F,F,1327,511,1447,F,F,1480,F,1382,120,F,F,F,F,712,F,F,F,563,1453,449,881,F,F,F,521,F,F,F,F,F,F,F,F,F,F,186,742,F,F,F,F,1277,F,273,1236,F,94,1365,274,540,888,F,F,142,F,F,F,184,F,1062,1324,577,F,1373,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,1442,F,556,921,1447,672,F,F,F,F,885,840,871,F,F,F,F,F,F,326,F,F,F,F,1241,F,F,723,F,F,F,F,F,1054,F,F,F,1296,F,287,65,F,F,F,F,F,F,F,F,F,F,1359,F,704,F,641,F,F,1280,676,938,244,F,142,142,F,F,F,F,F,186,F,F,F,F,F,F,F,F,F,F,104,F,F,F,F,F,F,F,618,1421,628,632,976,F,F,F,F,F,F,F,F,137,F,142,381,F,1490,155,173,F,F,F,F,F,F,F,620,1109,688,F,F,F,F,342,F,629,F,1030,F,F,F,1028,F,F,F,1023,F,F,F,814,F,F,1499,628,F,F,F,1089,F,F,342,F,F,F,F,F,F,F,F,F,F,57,F,F,F,235,F,F,F,F,1077,F,F,F,355,1152,F,F,F,F,704,F,F,627,F,F,57,F,F,F,862,F,1359,F,F,F,F,518,F,404,640,F,F,1250,928,269,F,645,F,501,F,645,F,F,F,F,F,1176,F,F,404,F,865,938,F,246,1379,F,F,F,F,F,F,F,615,F,F,F,1110,1283,F,F,F,F,1283,1273,683,F,F,F,F,F,49,1176,F,F,F,F,F,F,1277,F,268,F,F,F,F,231,1186,1469,F,F,F,F,F,849,F,1250,F,F,F,F,F,F,1283,1011,F,F,1480,231,F,F,F,207,F,F,F,1480,F,F,F,F,F,192,121,F,F,F,F,1328,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,1324,F,1441,F,F,F,F,F,F,F,839,1325,F,F,F,F,F,F,321,F,F,F,1324,1365,F,F,1168,344,47,F,1324,F,468,F,F,F,F,F,466,F,F,401,1325,F,450,63,F,F,F,F,F,F,1131,F,F,F,F,F,F,1486,485,294,485,586,F,637,F,F,F,480,F,1236,1479,F,F,F,1187,F,F,F,1385,1362,F,F,F,F,992,F,F,F,733,F,F,F,F,F,F,F,F,F,F,F,F,768,F,F,1198,F,226,F,532,F,F,1301,245,F,F,F,F,F,F,F,F,F,F,1273,F,F,F,F,F,F,1296,F,F,1361,387,F,F,1257,F,F,F,F,F,F,F,F,F,431,F,F,F,F,262,F,F,670,F,1302,F,1456,1268,464,111,1079,355,839,12,F,F,F,1279,F,F,F,F,F,F,F,428,F,F,F,F,F,F,F,F,F,F,946,372,1052,342,F,F,F,F,F,F,F,1400,F,F,F,F,F,F,F,F,F,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,251,1069,F,1497,F,F,F,F,F,F,F,F,66,F,F,F,179,1435,224,F,F,1249,956,F,F,F,648,F,473,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,101,F,F,F,F,761,F,F,257,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,1169,F,F,F,1199,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,373,F,F,F,F,F,F,F,F,F,F,F,F,F,146,197,1169,F,F,F,F,F,F,F,F,F,F,F,480,920,1035,1034,1308,16,772,F,1327,1087,1044,F,F,F,F,1187,F,F,F,F,F,F,F,1320,466,F,F,F,70,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,852,101,F,18,F,712,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,723,F,1172,F,F,F,F,F,F,1034,1304,545,F,F,F,F,F,F,F,606,F,F,F,F,F,F,F,F,F,F,F,F,F,F,66,192,F,F,1266,F,F,F,F,F,F,F,F,F,1211,F,F,F,F,1367,228,1352,F,1427,F,918,F,F,F,F,F,F,F,F,471,F,F,F,F,F,8,752,F,F,512,601,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,895,F,78,956,F,F,57,F,F,F,F,F,1355,F,F,F,F,F,629,F,F,F,F,F,F,F,814,F,237,F,F,F,460,F,F,F,1375,F,F,182,F,629,F,1423,651,183,F,F,F,F,F,F,104,1315,F,F,F,525,472,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1189,F,F,F,F,F,F,F,818,526,F,F,F,F,F,F,F,114,F,F,F,F,F,F,1395,F,F,F,F,1367,F,F,F,559,F,F,F,F,F,F,583,326,F,F,F,F,F,F,F,1379,931,F,F,F,968,F,F,F,910,F,875,F,F,F,F,F,F,F,F,F,373,1343,684,F,F,F,F,F,F,F,F,F,F,578,F,1308,805,F,F,F,F,F,450,524,F,F,F,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,F,1257,F,F,1375,1155,903,282,44,F,F,641,F,F,F,F,F,F,F,1357,F,F,F,F,F,F,F,F,F,F,F,F,F,1317,F,F,501,F,F,590,F,1441,1090,F,F,1078,F,F,F,1360,860,1275,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,920,430,F,F,564,F,F,F,F,F,F,F,1338,148,F,F,F,F,F,F,551,1375,F,F,1221,804,F,188,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,522,F,F,F,F,F,F,1326,627,F,222,151,F,F,F,F,F,1341,F,F,F,F,420,F,F,F,F,1340,F,153,452,1462,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,956,287,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1190,1265,F,F,F,F,F,342,F,F,252,1258,1279,1441,941,1198,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,420,583,1134,F,1085,1177,F,F,F,1438,F,F,790,F,931,1345,F,636,1047,1486,886,359,F,F,511,1360,F,1055,F,730,852,F,449,F,F,F,1362,F,724,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1447,F,F,56,F,F,F,1069,923,1265,862,548,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1097,886,F,1384,1459,F,1118,517,F,F,120,316,1275,1346,446,712,1199,353,485,F,F,F,1341,F,1367,516,872,F,1274,F,379,F,F,378,1133,1267,1468,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,155,F,F,F,F,F,938,1168,1377,F,F,1052,645,F,1447,F,F,F,857,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,524,598,F,F,F,1485,F,963,383,532,1446,16,949,886,1502,F,930,F,214,F,483,1168,1005,F,414,1315,F,44,1446,F,283,F,F,F,F,F,F,F,F,F,230,F,F,F,F,994,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,353,F,F,358,1142,F,1218,1329,569,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,491,801,1069,37,1096,268,F,929,418,925,354,F,F,183,112,57,1340,341,294,F,610,426,627,F,F,F,F,1161,1069,F,1345,F,F,491,F,F,F,112,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,197,F,F,1341,F,F,F,1154,F,F,530,F,F,F,F,F,F,F,F,F,F,F,F,F,148,379,F,F,505,1433,450,706,F,F,F,1492,1112,F,F,327,1483,1483,F,505,1110,F,182,872,F,F,F,1336,245,F,618,1301,1005,F,854,530,1260,1338,9,F,1343,491,F,F,1114,1249,F,1039,1447,54,1469,F,F,F,497,428,F,148,283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,78,483,164,F,684,F,F,1014,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,450,F,F,F,F,F,228,F,F,419,974,1044,1421,1399,171,920,257,F,1413,1196,F,F,187,1023,F,682,F,F,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,683,947,F,F,F,956,530,F,F,F,8,492,F,F,F,F,F,F,F,F,F,F,F,1170,1241,F,F,938,F,F,F,858,474,F,F,8,F,1109,632,280,F,1142,510,57,1324,361,894,1367,1067,F,F,491,F,450,F,F,645,F,60,1124,499,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1475,F,F,F,F,417,F,F,F,F,F,F,F,F,F,283,F,F,F,F,F,F,F,F,1281,723,341,672,1152,F,F,656,1265,1033,F,F,197,1470,F,136,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,634,F,F,F,627,F,F,F,F,F,F,F,F,F,F,F,884,1217,F,F,1218,F,640,1277,181,458,809,929,629,F,F,869,1177,656,1253,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1351,F,1411,F,F,F,F,F,F,F,F,F,1365,F,1267,1307,1097,1257,F,F,715,1040,634,F,518,612,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1274,1492,F,F,F,F,F,F,F,F,F,F,656,F,1430,F,1433,F,619,F,F,F,F,F,F,F,F,F,F,F,F,276,F,F,F,F,F,F,F,F,F,492,1097,1510,945,F,611,F,F,F,F,F,F,F,F,F,F,F,F,1367,1343,800,F,F,107,F,F,F,F,F,F,1325,F,1011,F,F,F,F,F,F,1323,257,731,F,F,F,F,F,F,618,F,1227,F,F,F,F,F,F,767,F,F,F,F,F,1323,F,F,F,619,F,F,1044,449,F,F,1257,1257,F,1361,417,F,412,F,1219,1304,885,1149,1257,1257,70,946,1257,1365,1260,F,190,932,1261,F,F,1220,295,1361,1291,799,418,57,1073,F,F,1419,252,1366,F,851,F,400,1233,929,1341,F,F,1496,F,929,263,561,F,161,1340,F,F,417,579,F,F,F,F,F,F,F,F,F,F,F,F,252,1260,1483,651,1486,F,1367,473,F,1111,544,F,F,F,F,F,F,F,12,F,1361,483,476,925,446,446,F,252,F,F,F,F,F,1279,F,F,F,1067,1047,F,F,F,1280,1332,220,F,1282,145,1361,429,1047,F,F,F,F,1145,F,1458,F,7,F,F,F,974,1399,F,593,667,169,F,1361,F,F,F,F,F,F,F,1170,F,1077,F,F,F,F,F,F,F,F,1277,1260,F,F,F,F,F,480,148,1343,F,F,F,F,1088,1375,120,295,1257,1257,1351,1496,1381,F,F,1381,F,F,F,F,F,F,F,1142,1230,F,F,F,1286,F,F,1234,343,F,F,F,F,671,F,F,F,F,F,F,F,F,F,F,F,F,F,492,F,F,1323,F,57,559,394,F,F,1483,F,F,F,F,F,586,1455,1448,F,F,1199,F,F,F,1142,F,161,141,143,1460,F,220,F,F,F,F,F,F,F,F,57,F,488,F,F,1343,F,F,1375,356,F,F,F,F,F,353,F,F,F,F,F,938,F,F,F,159,159,57,F,F,F,401,F,F,383,75,1347,1475,383,F,629,F,F,F,F,F,295,223,F,F,F,F,F,94,F,F,F,F,F,1170,274,1343,F,F,F,257,F,823,F,1313,786,401,551,F,F,172,F,F,F,1411,1360,F,29,1078,252,853,959,F,F,F,1313,F,753,553,1361,501,78,F,F,F,F,1299,F,F,F,F,F,729,684,F,1313,F,287,F,308,1052,F,587,784,732,629,676,F,257,F,928,620,F,712,F,436,135,1448,F,383,213,F,F,F,F,1255,1160,F,F,1257,1331,1237,F,1496,696,1366,883,1343,949,1375,534,497,1304,49,F,F,F,F,683,1473,F,F,F,F,F,F,274,1273,F,F,471,F,F,F,383,551,F,F,1288,474,58,F,F,F,556,551,F,F,214,F,F,F,F,F,F,672,F,1504,1395,501,394,F,F,F,F,1322,306,F,951,F,F,244,1112,1131,642,F,F,F,F,1055,F,57,1238,1338,F,1418,F,1336,255,8,F,F,497,F,F,1121,F,458,1367,F,F,983,F,F,250,1499,31,F,947,F,F,486,F,886,F,F,1194,F,F,F,155,F,F,F,F,400,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,1313,F,252,F,F,60,1385,1190,F,F,F,F,F,F,1054,F,384,974,F,F,F,F,F,F,383,F,F,F,F,F,F,F,450,576,F,F,380,F,1124,F,155,F,561,F,271,F,F,F,F,1343,F,F,235,934,F,F,F,F,F,661,F,627,F,629,950,F,1235,F,756,F,F,F,F,923,F,496,1280,F,F,F,F,224,F,665,F,F,F,F,F,F,394,F,F,F,874,F,F,886,F,530,F,42,532,530,1080,F,F,F,F,1281,F,F,1013,514,910,1028,F,F,F,F,482,F,F,F,F,F,F,F,F,328,44,507,1021,F,885,F,F,F,F,F,248,710,25,25,1177,651,1073,1440,885,1255,F,1400,41,F,F,F,F,F,F,687,F,F,F,F,F,F,F,F,F,F,F,F,F,F,425,269,342,799,F,F,F,363,F,F,1005,1267,F,F,57,F,F,F,F,F,F,F,F,F,710,F,F,F,999,959,F,F,F,78,F,425,783,1323,F,F,F,F,F,F,F,870,1128,F,1218,F,962,627,237,F,505,505,402,491,57,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1030,35,183,F,F,F,772,36,F,F,998,486,1118,F,F,F,237,F,F,F,F,F,F,F,F,F,F,F,F,F,1265,1419,712,F,F,1483,F,485,921,F,F,F,F,1007,1134,999,1255,998,998,F,F,F,F,F,F,F,F,F,1142,F,1118,F,F,F,F,F,F,F,627,F,F,206,1488,719,1259,F,707,814,F,1128,F,874,F,F,57,248,F,F,963,939,F,328,1124,F,F,F,F,F,F,1301,F,F,F,999,482,F,F,963,645,F,F,F,F,F,F,F,F,1037,F,F,590,F,207,1338,76,F,505,F,963,963,F,F,F,723,978,F,70,129,F,F,272,F,F,F,F,F,F,F,F,F,982,1265,F,F,1158,963,814,F,F,1087,671,F,590,F,F,F,F,F,741,742,F,F,F,228,F,F,1347,F,F,1277,F,F,F,485,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,328,1452,F,F,F,383,F,F,F,F,F,112,F,656,F,F,84,F,F,F,853,1112,232,1440,F,F,1052,F,168,F,520,F,F,F,F,F,556,146,F,F,F,F,F,452,629,1044,F,F,860,1236,1254,F,F,F,1350,F,F,F,F,1044,245,F,F,226,F,F,F,F,F,F,F,F,F,F,F,1198,430,909,F,1447,823,801,F,F,F,F,F,999,391,F,1446,F,F,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,318,F,F,505,414,F,F,F,1422,F,1260,F,F,1483,F,F,1258,896,F,F,46,F,492,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,493,F,F,1382,F,F,F,F,F,F,F,48,712,F,F,1398,1472,F,581,F,F,55,F,860,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,1152,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1213,683,F,F,1149,F,F,F,F,F,F,F,F,F,F,F,F,F,F,10,F,F,F,F,F,418,1458,F,287,287,355,F,F,F,F,F,F,F,F,F,957,F,1233,F,753,1435,1242,1472,F,504,872,F,F,F,F,F,F,F,1124,F,F,F,F,526,F,F,920,96,F,1204,1044,F,1216,F,F,456,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,741,1450,F,F,F,1109,F,F,F,1343,1233,18,F,F,F,F,F,F,F,F,F,F,1123,F,F,F,F,F,F,F,400,1217,470,F,F,1304,1389,F,1485,60,F,218,1344,969,F,F,458,766,1382,F,1168,F,F,F,F,F,1341,1149,1065,1109,F,1289,F,F,1361,F,F,329,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,556,F,F,F,342,F,F,F,F,F,F,742,F,F,F,F,F,F,F,888,F,483,941,1451,355,1369,146,F,F,F,932,F,1510,F,1280,710,F,450,F,888,1316,F,1262,1168,F,F,768,164,F,F,F,F,F,F,F,F,F,1254,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,464,1313,1160,F,941,F,F,499,F,F,F,F,F,F,F,F,230,29,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,231,568,F,523,1044,512,F,F,751,828,F,1109,F,1090,1470,342,F,F,1392,1280,607,1189,F,F,F,F,F,F,1433,F,F,96,F,1375,F,F,1456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1149,F,F,F,1323,672,1324,F,F,F,F,F,1399,1096,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,404,1458,F,1005,1005,F,F,544,485,359,F,F,F,1282,F,F,852,491,F,F,F,1446,1411,F,443,F,579,F,78,F,F,1039,12,493,F,1165,F,F,1114,F,725,1345,1149,404,F,F,1010,1238,F,F,1112,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,923,F,1424,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,266,342,1071,1124,1343,634,F,430,F,1438,F,1399,F,206,206,F,F,F,F,F,F,F,F,F,335,628,568,F,F,F,1277,1361,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,426,F,F,F,F,F,420,559,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1367,F,F,F,F,F,462,1134,F,596,F,270,493,907,59,F,F,F,F,1313,F,60,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,505,29,F,804,1268,1039,F,F,455,163,F,F,852,645,246,92,F,1411,F,686,F,F,F,480,F,F,629,1351,F,1237,57,405,F,888,F,F,63,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,442,F,F,F,650,295,F,1346,603,F,1334,F,F,1309,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,603,651,1257,441,F,501,1480,F,F,F,1446,F,F,78,1164,11,F,F,1315,619,1392,470,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1260,442,F,318,492,192,1382,F,86,F,1359,F,F,280,861,F,F,F,F,F,F,F,F,1142,F,864,F,F,F,315,1257,F,F,F,F,F,F,F,F,F,1334,682,F,F,58,F,511,629,F,F,F,F,F,F,F,F,F,F,F,F,1325,270,F,F,704,F,374,983,96,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,F,F,F,F,F,F,F,F,F,F,F,F,F,383,1098,F,F,F,F,F,1323,57,F,F,F,F,F,F,63,142,587,F,479,F,F,F,F,F,F,F,F,148,F,1250,F,197,1448,F,F,F,F,F,F,1103,F,F,388,915,1304,1110,F,1254,F,F,F,F,F,F,F,F,F,F,F,F,341,F,1080,F,F,471,464,853,F,F,F,F,F,F,1459,F,1341,F,F,F,F,629,1054,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,741,F,362,F,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,302,311,F,F,1304,1341,F,F,F,F,F,F,F,F,F,1350,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,146,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,F,F,246,470,430,F,1412,F,F,F,F,F,F,F,F,F,F,F,F,1377,1265,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1266,612,1063,1080,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,66,520,1248,501,342,F,1270,542,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,783,F,480,483,596,F,F,F,F,338,1054,642,740,135,F,F,751,F,F,F,F,F,F,F,F,F,F,F,F,665,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,287,F,F,783,F,482,F,F,1460,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1124,287,F,F,F,517,F,F,1385,470,1351,450,650,1246,420,F,F,F,774,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1260,F,355,F,F,F,F,431,482,1272,1044,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1210,F,1427,1090,F,1361,491,F,F,F,F,F,1398,F,F,F,989,F,F,F,1423,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1020,F,F,F,F,F,F,1322,F,F,F,F,F,F,207,F,F,F,F,F,F,F,F,F,F,F,483,1056,F,F,F,F,F,F,F,F,F,F,295,610,1151,F,1460,F,F,F,F,F,649,F,1407,F,529,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1071,480,F,F,F,587,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,1456,F,F,F,17,F,1480,F,F,F,F,1288,F,740,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1150,F,F,F,F,57,F,F,F,F,F,F,F,F,F,262,F,F,F,F,F,542,F,63,F,F,F,F,480,645,204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,989,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,1314,F,641,F,F,F,F,F,F,F,F,F,F,F,1044,F,F,1080,F,F,F,1282,F,F,F,F,F,F,F,F,1274,F,F,F,1336,602,1343,F,F,F,F,F,633,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,78,100,F,F,F,F,F,F,F,1334,F,F,F,F,F,F,F,F,634,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,1257,1448,F,F,F,F,1257,F,F,1375,F,F,F,F,F,F,1267,F,1481,F,F,F,F,F,F,F,F,1424,F,F,F,F,F,1497,F,766,F,F,F,F,F,F,235,645,766,F,F,F,F,F,F,F,F,870,F,526,F,F,F,F,F,F,F,F,676,F,683,F,F,1092,1058,F,F,683,F,328,F,F,F,41,F,F,F,F,629,F,F,1296,F,F,F,F,F,F,F,F,F,F,229,F,F,F,F,F,F,F,F,F,144,F,F,F,1424,F,F,1119,F,F,1070,F,F,F,F,F,F,F,364,F,F,F,F,F,1364,F,468,135,F,502,1460,1087,F,F,1275,F,F,F,818,F,F,F,F,F,F,495,F,495,F,F,F,F,F,F,F,255,F,F,F,F,F,935,959,F,332,F,F,F,F,F,1422,553,F,F,F,F,F,F,F,F,F,F,257,1399,F,F,395,1039,331,F,F,496,F,1323,1448,F,F,F,629,F,F,F,651,F,F,F,1341,F,964,862,378,F,488,430,F,884,F,526,F,135,F,F,645,1080,910,1273,F,888,683,201,F,1366,F,F,F,565,1282,F,F,F,F,1229,1324,874,F,F,F,142,F,F,F,F,1168,135,57,634,488,F,1186,F,F,F,1167,F,F,F,F,F,257,F,1090,627,686,2493,F,205,F,F,F,77,1208,916,F,650,331,F,78,122,F,F,229,1146,235,F,F,629,F,1318,1318,1419,F,F,1341,F,920,F,F,F,F,F,F,F,452,F,1185,F,567,F,F,183,1360,331,F,838,1175,511,886,137,888,F,507,400,F,F,F,F,F,F,F,F,F,F,1472,F,F,450,156,1090,1157,886,999,999,F,447,881,F,F,F,F,F,F,29,F,1262,F,F,F,948,800,F,1157,965,F,F,1280,F,1430,454,1283,F,1082,F,F,1199,F,635,57,158,F,1288,F,1283,341,F,F,F,F,507,F,F,F,343,F,F,1161,1067,F,F,128,178,F,F,F,F,F,F,978,447,49,F,91,F,F,F,F,759,F,F,F,1301,257,F,F,121,1365,1452,F,F,F,627,1082,F,F,F,F,F,272,F,709,465,F,1158,F,274,1168,1073,715,F,F,F,1438,947,888,1154,97,1332,F,1300,35,383,F,F,F,85,F,F,F,F,F,F,380,78,F,F,F,1243,F,F,485,F,F,F,F,F,593,F,F,205,742,200,849,1355,F,F,F,F,710,556,705,262,460,286,495,1124,F,F,F,F,109,1142,F,465,532,1315,F,F,1296,F,730,976,610,F,F,1448,1237,F,F,F,F,1039,F,F,F,1392,F,F,F,F,F,11,1238,F,1987,F,F,1422,F,1349,362,471,F,968,F,F,F,F,F,645,524,24,618,F,702,651,F,F,629,484,F,F,F,469,1286,1080,1433,F,F,77,F,F,F,111,F,F,F,111,512,21,44,F,F,1325,F,452,F,1365,F,57,171,F,484,F,F,F,761,F,710,1450,F,1338,268,1337,F,F,F,996,1344,F,428,F,768,1184,344,760,1385,F,450,F,136,1216,F,F,161,342,F,F,F,F,F,F,F,41,F,F,F,F,247,91,672,909,218,1338,F,1342,F,F,F,1271,54,1457,F,1341,F,688,F,580,F,F,1448,F,F,F,F,1220,245,1090,645,1397,1448,782,F,F,F,F,F,F,F,F,F,116,F,F,1274,1394,F,F,1367,270,114,1280,1327,F,1267,40,F,F,F,F,F,F,F,F,2583,F,959,F,450,1386,532,F,417,F,F,F,F,F,F,F,F,12,1398,F,F,1047,F,1268,147,1324,F,12,F,F,F,1433,532,F,F,629,F,615,F,F,91,F,672,F,486,F,F,1264,1277,1322,1301,F,283,1350,468,1168,828,1259,F,F,1199,F,F,F,F,F,F,F,F,F,F,F,F,1224,136,F,F,F,441,F,1168,1306,1382,F,F,F,F,F,F,363,F,F,F,665,116,862,483,F,1142,F,F,1257,F,F,1274,F,1035,486,1124,464,F,213,F,1142,1094,F,F,F,F,F,F,F,F,F,342,629,F,1067,F,1154,F,F,F,F,F,F,250,F,F,F,57,F,380,201,F,929,F,F,618,1124,F,F,1490,425,F,F,F,F,F,F,F,148,101,F,F,1250,1274,645,2524,F,F,F,F,F,F,57,F,463,F,1257,145,1301,782,1323,F,1282,1411,F,1131,F,1260,49,978,1109,31,401,540,136,F,F,1304,F,F,F,F,F,F,F,295,629,1257,463,1124,F,125,F,F,F,F,F,672,1323,F,F,229,227,1326,F,1417,526,F,285,1035,598,1124,F,F,F,F,F,F,1399,F,F,F,57,F,F,1347,F,425,F,645,F,F,426,1327,F,F,F,F,F,1109,1526,956,F,F,619,1280,F,1080,F,F,F,F,F,F,671,F,974,632,F,F,F,F,F,1277,F,1349,F,F,1280,F,F,F,F,F,F,651,1359,F,F,F,F,230,602,1274,F,F,F,F,F,F,F,1343,F,F,F,1252,F,1343,1215,84,F,1286,F,F,1110,509,F,21,312,F,753,1020,F,F,F,F,F,F,F,F,F,F,F,F,F,220,485,F,F,F,F,1124,956,21,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1173,1360,F,1216,F,1226,1362,F,265,F,F,1259,564,F,F,F,956,278,287,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1257,946,1124,F,F,656,F,1228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,132,1457,F,712,F,F,F,F,F,F,968,F,F,F,F,F,1364,F,1343,728,F,F,598,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,799,F,31,F,F,F,265,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,651,1365,191,248,1448,F,F,F,1011,F,F,493,F,F,F,F,F,1230,F,2063,F,866,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,672,963,F,1259,218,F,F,F,F,452,683,F,615,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,375,F,F,F,F,653,F,F,F,F,F,F,610,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1397,428,1273,F,F,428,629,F,F,252,363,F,F,F,F,F,F,F,F,F,F,461,F,F,F,F,F,F,295,F,1262,F,F,F,F,491,921,1262,1361,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1442,F,F,F,F,F,F,F,F,1469,F,F,F,122,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,782,F,F,F,F,F,F,F,485,1173,F,F,F,F,1324,F,F,F,427,1284,651,F,31,51,F,F,F,F,378,651,F,F,592,928,502,F,302,1332,271,F,F,F,F,F,479,273,873,F,F,F,F,F,F,F,F,1370,F,F,665,1289,F,F,632,1332,1257,1332,F,1257,F,F,670,F,1325,F,F,968,F,F,F,F,982,1222,1496,318,1343,274,1131,F,885,F,418,F,1304,F,375,F,248,F,768,F,F,F,F,F,F,287,1219,F,F,814,F,F,F,F,F,255,464,F,F,1168,318,F,F,F,1253,14,F,905,F,F,F,F,418,F,356,627,F,281,344,768,F,105,F,F,F,F,504,F,953,F,1349,1271,F,F,F,F,450,F,F,1124,F,F,F,F,F,F,362,295,1304,F,F,F,F,F,F,F,F,1257,F,F,F,546,405,504,171,618,437,1506,F,F,F,F,899,F,F,242,1257,618,F,1055,F,F,F,F,F,F,F,F,F,F,670,F,280,537,F,F,122,F,1280,1219,1433,F,1466,F,F,F,658,618,F,F,F,224,353,F,F,F,F,F,F,1080,F,F,1324,F,F,F,F,353,F,F,F,1323,F,F,F,F,F,1128,F,F,F,1450,F,1080,F,F,F,F,1076,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,465,1347,F,F,724,F,F,F,F,F,F,982,F,F,1271,F,F,44,F,F,F,171,F,F,F,F,916,F,F,F,F,F,F,F,553,593,F,F,969,F,424,148,706,F,F,F,F,1413,F,F,F,331,F,131,F,F,F,F,F,F,513,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,F,768,768,1190,F,F,F,978,1497,885,1361,F,1087,F,F,F,F,F,F,F,F,F,F,645,1082,1205,F,360,1061,1216,F,774,F,F,F,1217,374,255,F,F,F,F,F,F,F,F,F,F,724,35,F,1280,1112,1187,401,F,F,342,401,F,F,F,F,F,F,F,F,F,F,401,F,F,F,F,F,F,F,1459,F,598,F,679,1174,979,F,F,265,F,F,F,F,F,F,F,F,F,F,F,F,F,530,1323,F,F,1010,227,F,F,712,F,F,F,F,F,674,F,1304,F,1257,F,F,F,F,F,F,F,694,920,F,114,F,F,1056,420,905,F,F,F,482,F,F,F,F,629,F,1367,31,F,1164,F,F,F,F,155,F,F,155,F,F,F,920,1257,44,F,F,F,1338,F,F,396,F,1460,F,F,F,618,618,111,F,F,F,F,F,F,F,F,F,F,396,F,F,F,F,F,F,F,F,F,F,F,F,257,F,F,F,F,F,1319,F,F,F,F,814,1087,F,1448,F,F,F,1448,F,F,1448,887,F,518,518,1343,1362,F,518,F,F,F,F,463,F,274,F,F,F,F,F,F,F,F,F,1158,945,947,1286,119,485,F,949,F,F,F,F,F,F,F,274,1445,F,F,851,F,1255,F,1241,F,F,F,F,F,57,F,F,42,F,760,511,1158,640,F,F,F,F,101,1411,F,F,F,F,F,147,1317,585,1347,F,F,F,F,F,F,F,F,661,928,F,422,1058,415,111,F,501,611,F,F,F,F,F,F,1257,F,F,78,F,F,F,F,F,F,F,1447,F,1214,342,F,449,F,814,1343,1472,F,F,F,112,F,F,F,1125,F,F,1379,F,F,F,F,F,F,274,F,1257,173,1289,501,1069,F,F,F,F,F,F,F,F,F,F,F,1231,42,325,493,968,1343,265,1304,768,483,F,F,1440,921,278,1345,F,521,886,1286,678,91,653,66,235,237,F,F,F,F,F,597,F,1257,526,F,1275,F,512,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1226,F,783,1270,974,945,1198,1303,471,F,F,F,741,1237,F,1360,1208,224,F,F,F,555,768,F,344,1367,1447,F,417,5,344,F,F,F,F,F,F,F,F,F,1326,F,F,1078,F,120,F,F,F,148,1379,1094,F,1124,1033,F,295,1443,6,1136,85,F,976,1343,1324,F,768,1254,F,F,F,F,F,F,F,F,F,F,629,629,F,1257,532,1077,F,1319,F,F,F,F,F,F,F,F,F,F,F,F,137,1350,57,135,F,F,1410,1217,452,1165,1352,56,818,634,1286,1366,F,F,F,F,F,F,F,44,F,751,278,F,234,921,F,F,F,F,F,F,F,1094,F,F,1274,1451,1475,F,60,F,1237,1257,F,242,F,1279,861,814,F,F,67,1067,327,742,923,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1055,1136,F,F,F,F,F,F,F,F,F,F,F,483,F,F,227,530,672,F,20,F,F,F,1343,1089,1131,1238,1228,160,F,F,F,F,1250,F,F,F,F,F,F,F,F,F,57,F,1346,478,F,F,542,818,F,F,F,F,8,F,629,F,1415,F,F,F,F,F,F,F,F,F,F,672,F,F,F,F,60,862,F,F,F,F,F,F,F,401,1121,723,1267,663,F,923,F,F,F,F,F,122,1343,423,F,633,394,F,1237,F,F,F,F,532,618,674,629,F,886,F,F,F,F,F,462,F,F,F,F,F,F,F,F,F,F,F,399,F,F,F,F,524,F,F,242,F,F,F,327,F,F,F,F,F,F,1445,F,717,F,463,F,F,F,853,56,F,907,F,F,F,F,F,F,306,F,463,F,F,F,F,F,F,F,F,F,F,F,F,F,126,F,684,340,F,F,F,F,F,F,F,F,155,F,1496,774,1276,F,F,F,F,F,29,672,F,684,F,F,805,1399,F,F,F,1460,450,F,F,468,1155,155,F,F,F,F,F,F,465,268,F,F,F,F,F,F,F,F,737,F,78,F,F,248,F,243,915,F,1114,155,F,F,F,F,1334,724,661,F,F,F,F,632,F,F,67,F,F,F,F,687,F,F,F,F,1039,1510,F,F,F,210,651,1443,887,39,F,F,974,F,F,885,776,885,79,44,310,F,742,753,1222,1427,1448,198,F,F,F,F,1315,483,401,F,142,F,F,F,420,1277,974,F,186,F,343,F,F,F,F,F,948,672,974,251,929,F,F,F,F,125,1142,42,F,F,274,51,448,F,F,F,F,1412,1412,F,F,F,F,974,694,418,F,F,656,672,F,1483,F,887,1456,F,1395,F,823,F,F,471,F,F,1311,F,596,F,F,F,F,F,828,1324,979,783,F,596,396,272,F,F,670,F,F,F,492,1282,923,F,F,1273,F,F,406,505,708,F,F,1261,584,F,F,F,F,F,F,1358,F,414,730,1334,F,F,1240,F,F,F,F,F,F,F,F,F,629,530,1252,939,F,F,F,F,F,F,F,F,F,F,F,255,161,F,F,1134,F,F,150,F,F,967,1067,F,F,F,F,F,F,F,1323,724,526,F,F,F,F,F,F,F,1199,F,F,F,F,F,F,825,751,F,F,F,F,F,331,F,F,F,F,F,F,F,F,18,404,450,94,281,414,501,F,F,F,F,F,F,F,381,F,F,F,F,509,F,F,1327,F,F,F,F,F,F,F,114,F,F,F,F,F,F,F,F,F,F,F,468,F,F,F,F,F,F,F,963,553,F,F,F,F,F,F,F,F,F,950,1343,F,F,541,501,F,F,154,84,370,499,1445,F,F,1243,F,70,F,F,F,1296,318,F,F,1341,173,1334,F,F,1326,274,1324,F,723,F,F,F,F,F,145,761,499,F,843,F,405,1313,F,F,342,1310,341,876,753,1257,1250,1054,F,F,1257,923,733,F,F,F,F,F,F,F,F,227,F,156,F,F,F,F,F,F,F,F,327,739,F,1313,1304,1110,525,711,41,F,1341,385,801,F,1341,F,1512,F,F,832,F,F,F,F,F,F,F,F,F,F,F,F,252,318,1343,1080,F,F,198,1440,F,F,F,F,F,1080,501,448,306,F,F,F,F,620,1313,373,F,1082,F,528,F,F,F,F,F,F,F,505,1236,F,1084,514,F,1302,153,F,F,F,F,F,F,F,F,F,495,758,F,F,1367,F,F,532,F,F,F,F,1188,F,F,1274,F,268,F,405,706,1231,1304,1234,1134,530,1375,F,418,F,1069,F,1280,656,1016,F,F,F,F,F,F,F,57,F,F,784,F,F,F,1230,521,972,F,F,F,F,F,F,F,F,F,F,F,801,F,728,1134,F,936,F,F,420,1480,728,1304,F,606,505,263,126,1448,324,487,537,480,920,523,1476,F,252,501,1317,F,1421,F,F,F,F,F,F,F,F,F,F,1445,F,700,449,1282,1078,399,F,F,F,F,F,F,F,F,F,F,F,1304,F,F,483,F,177,F,712,1010,1301,463,1047,525,F,F,F,583,F,446,392,385,F,728,257,57,636,596,1054,672,1260,F,F,1119,F,F,F,F,F,F,F,F,F,F,F,F,849,F,623,F,F,F,F,F,F,F,F,569,399,F,F,1257,862,1039,534,444,394,526,F,772,455,363,629,380,1170,F,696,1165,218,F,F,F,920,1367,501,740,372,725,1238,F,1168,921,F,F,137,268,F,862,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,420,F,F,F,730,694,672,920,561,F,257,923,499,546,938,730,1052,F,F,F,F,F,F,491,627,556,1304,F,F,F,F,F,F,702,335,122,470,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,1237,394,483,1508,480,1280,F,F,F,F,1131,F,1011,F,1161,F,F,F,1444,588,1272,731,F,1260,F,F,F,F,F,F,F,F,F,F,1021,F,F,F,F,F,F,F,F,F,F,F,F,295,740,1459,1492,724,F,1257,F,1154,F,489,124,483,F,F,F,F,F,F,F,F,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,431,112,923,731,1440,569,1338,1453,F,491,F,864,F,F,468,F,744,658,F,F,F,F,F,F,F,1104,F,700,627,F,F,1109,1341,F,F,F,F,629,F,F,F,1279,1161,1297,F,F,1309,629,723,1236,723,F,F,F,F,F,F,F,F,1334,601,651,1349,1349,629,492,399,F,F,F,392,1282,F,F,1067,F,1502,F,F,F,F,F,F,F,545,618,F,F,F,F,F,60,F,F,1089,843,1304,F,F,F,428,F,F,F,F,400,F,1418,605,202,1448,333,946,F,1399,768,823,1343,F,F,191,1077,492,1488,1324,1349,F,F,F,1011,F,998,1260,F,392,542,F,1255,F,947,191,F,1399,1282,F,1367,1390,174,628,628,1304,728,1304,1011,F,F,401,999,F,F,710,F,947,204,204,204,F,F,F,F,F,F,F,F,F,1252,F,312,22,F,F,F,F,F,F,928,1448,1179,F,F,F,F,F,F,F,F,F,1448,1446,F,463,155,F,1448,F,F,F,1351,F,F,F,1255,42,F,442,1071,F,532,587,F,F,1342,1320,F,F,54,F,F,F,575,929,F,F,1427,F,541,1056,F,F,12,1433,1448,F,F,F,F,F,F,1448,F,501,F,F,F,F,F,F,1110,916,851,F,F,F,F,F,F,F,1480,F,F,F,F,F,F,1349,F,460,F,1320,F,F,1078,853,917,F,F,F,706,115,F,F,F,F,F,F,F,F,F,1377,F,F,383,F,F,F,F,F,F,F,F,F,F,F,F,227,F,782,F,1433,F,450,F,F,F,F,F,561,F,257,1185,F,F,391,F,F,955,1079,488,20,767,902,487,F,F,F,F,F,F,F,F,F,F,F,F,1435,85,1249,F,113,F,F,F,F,782,2098,F,F,F,272,391,956,F,998,1342,278,619,F,1453,573,290,1265,252,840,295,1355,1255,561,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,1447,1315,F,F,1444,F,F,1327,F,480,483,783,F,F,F,F,1320,672,F,F,341,1029,161,1237,F,343,561,1112,549,F,1343,456,F,F,F,F,F,F,F,1366,F,F,F,F,629,F,F,649,273,295,F,F,F,936,272,F,F,505,186,1268,F,F,F,360,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,235,F,F,F,441,F,F,F,F,F,F,F,F,F,F,1488,F,F,923,1216,441,887,373,F,814,F,F,563,F,F,1447,F,F,F,F,F,F,1276,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1067,1365,495,F,F,F,F,F,922,138,1033,F,1136,930,471,F,1055,295,F,F,F,F,F,F,F,F,958,F,556,F,F,1245,1483,483,F,F,F,F,219,F,216,F,F,F,666,546,592,246,942,F,753,F,F,406,441,129,432,F,F,F,F,F,F,F,F,101,1433,F,F,402,F,F,F,F,F,F,F,F,F,1301,882,532,F,F,354,1110,F,1131,974,F,1253,1323,884,1276,861,F,F,F,F,F,F,F,F,F,F,F,F,F,596,F,F,F,F,F,F,F,F,F,52,1440,483,F,F,510,632,559,F,1454,F,F,F,F,F,F,F,1398,F,615,920,76,F,F,F,F,F,F,F,F,1346,900,F,F,F,F,1119,688,F,246,272,641,1480,F,F,F,F,F,F,F,F,125,F,F,F,F,F,F,F,139,1142,F,F,F,F,F,974,235,F,F,983,F,910,F,F,F,F,F,F,1450,1279,F,F,495,963,619,88,972,F,F,F,F,F,F,1273,620,F,F,F,603,F,F,601,F,595,F,595,F,F,1367,F,F,F,F,F,502,947,482,F,F,354,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,1445,F,F,64,1073,521,F,F,F,F,429,342,F,523,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1511,1341,F,F,F,F,1268,1341,F,1112,F,181,F,F,753,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1419,F,1315,F,F,1005,F,480,F,F,F,1060,F,F,F,1085,F,F,F,F,F,F,F,F,F,F,F,F,F,1362,1367,F,F,537,F,F,F,F,F,F,1483,672,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,147,588,F,F,F,F,1478,F,F,F,F,F,446,F,F,1303,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1488,1352,F,F,1457,F,F,658,F,F,F,F,F,F,F,F,F,F,F,833,F,F,F,F,F,F,57,F,148,F,1497,333,672,917,F,F,F,F,F,1469,F,1433,1077,1366,672,635,F,F,F,532,642,49,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,392,F,F,F,F,F,F,F,19,F,F,401,742,F,1324,601,F,F,78,F,F,1390,1361,F,F,F,F,F,F,F,F,F,F,1343,800,F,F,F,F,F,800,406,537,F,1077,F,1267,F,929,974,585,F,F,F,F,F,F,F,F,F,1065,F,480,F,1230,F,F,F,F,F,327,327,F,1367,F,F,F,1445,388,F,505,706,430,F,1361,F,F,F,F,F,F,274,1110,F,629,F,F,505,823,24,1365,F,F,F,F,F,1445,F,F,F,F,F,F,F,F,F,430,553,F,F,F,F,274,486,F,136,F,181,430,1417,F,F,F,F,717,F,F,430,1485,1458,F,F,1216,F,F,F,F,F,1508,F,1005,287,497,F,F,F,F,F,F,F,F,F,F,437,F,49,753,F,F,F,F,1496,F,F,66,F,572,487,F,F,F,F,1479,F,1297,F,F,F,F,F,F,430,F,938,F,F,F,F,F,F,F,326,1073,F,F,F,1475,579,1400,1265,F,57,1343,F,126,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,F,F,F,F,F,F,F,F,F,F,F,1229,F,F,F,F,F,F,1252,569,1249,1288,553,F,518,275,463,F,F,1448,104,1002,483,F,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,512,1329,F,F,F,F,1489,F,F,F,F,F,F,F,95,F,F,F,F,F,F,F,1110,629,383,126,F,326,656,F,505,1376,F,F,1448,F,F,155,66,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,682,491,F,174,1100,F,688,537,502,F,1170,1410,F,F,1343,F,191,789,486,F,F,F,F,F,485,F,F,F,F,F,F,F,F,8,F,F,F,F,F,F,F,F,F,492,F,F,48,F,316,1978,1508,252,359,360,610,F,1480,F,F,F,F,F,F,F,F,F,F,452,F,F,1208,F,F,F,57,524,F,F,F,F,F,F,455,F,F,F,142,F,F,F,577,229,363,1280,F,505,F,93,F,F,F,F,F,F,F,F,1496,F,916,F,F,1089,84,F,F,F,F,F,F,F,F,F,F,818,1324,1456,F,723,F,59,F,1273,F,F,F,F,672,627,F,F,F,485,F,740,620,F,1448,1359,59,F,F,F,F,F,F,F,761,980,980,F,F,F,F,F,F,F,809,F,F,F,F,F,F,F,968,1433,F,F,F,F,620,F,F,F,F,F,F,230,532,F,F,F,651,F,651,1323,F,F,F,1333,1441,920,8,F,831,707,F,F,F,F,F,F,F,544,F,518,1324,733,F,1345,1228,1334,1219,886,1131,F,F,F,F,F,F,F,F,564,F,F,442,F,745,651,1343,1069,F,1512,F,F,F,F,F,F,F,F,F,F,F,1198,F,1358,F,1222,400,444,F,F,F,1080,F,1296,F,0,F,F,F,F,F,F,F,142,F,561,1343,1329,1186,270,112,658,F,F,F,959,1308,1069,578,1192,F,931,F,157,F,F,F,F,1242,F,663,11,F,F,F,F,F,F,544,F,1333,F,342,F,F,F,52,602,928,252,F,F,502,399,F,1296,F,F,556,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,1251,F,F,F,F,F,F,F,F,F,F,375,F,F,F,851,553,F,F,F,569,F,F,F,F,F,1173,380,695,F,658,F,F,F,1238,1242,F,F,F,F,F,F,376,F,1199,818,F,F,730,F,F,F,605,931,702,1433,F,456,1355,511,F,717,F,F,342,F,F,F,963,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,658,344,228,F,375,F,207,F,F,1290,F,F,F,F,F,F,1198,1085,F,F,F,F,F,F,F,F,F,F,F,F,956,F,F,F,F,F,F,F,F,998,F,F,F,F,F,F,F,629,F,F,F,482,1218,F,F,F,F,F,F,F,F,F,F,F,1190,F,F,F,F,F,1150,F,651,F,1343,1011,F,855,F,F,F,88,F,F,F,1375,974,1460,415,F,F,F,F,F,F,F,F,F,F,F,F,311,415,F,F,F,F,F,F,F,F,F,F,F,F,F,82,904,645,F,F,F,F,F,F,F,586,342,1174,1250,F,523,F,F,F,F,F,F,F,F,1438,F,342,F,F,F,F,F,F,F,661,F,F,F,1260,1173,F,F,F,485,F,F,F,1334,F,201,F,F,868,945,F,F,F,F,F,661,484,F,F,F,F,F,1190,1341,F,123,F,F,F,F,F,374,F,F,267,F,F,F,F,F,F,F,1270,F,F,1187,F,1466,1342,1343,1485,922,F,F,113,F,F,F,F,F,F,F,F,F,283,F,F,150,672,283,F,319,1080,1170,482,958,1103,137,F,F,467,1081,F,F,1377,F,F,F,F,F,661,F,703,378,F,F,F,1265,753,F,1071,F,907,1334,962,979,F,F,F,526,F,F,1346,27,814,F,154,F,F,F,F,F,F,F,F,F,998,172,814,629,495,551,104,153,1461,1485,1327,F,F,F,F,F,F,F,1242,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,485,F,F,F,688,959,283,39,119,F,78,F,145,1435,708,F,485,737,1372,F,1253,1448,904,F,F,150,F,F,F,F,F,728,325,213,723,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1345,707,251,253,F,930,F,F,424,1446,524,805,F,F,F,728,383,F,F,F,F,F,1461,814,1480,F,1338,194,F,1301,F,1260,F,F,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1628,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,510,F,F,525,231,274,F,1336,514,556,684,1237,1198,1360,860,F,886,263,F,1237,135,493,F,1110,1480,1120,F,1011,F,1365,F,F,F,F,295,F,F,571,F,1460,F,F,F,F,F,1262,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,342,1158,1257,154,1370,686,104,1055,656,F,1260,482,1342,1150,F,450,F,F,213,364,F,1080,1035,444,1268,F,F,1262,F,F,F,F,761,1136,F,1418,F,341,1039,831,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,947,953,F,F,F,F,F,F,F,F,F,F,F,F,471,1100,227,849,753,929,148,858,530,F,F,129,645,74,568,228,155,1189,228,703,452,640,1266,F,101,672,173,1253,702,F,F,F,1485,F,274,F,F,1101,344,526,1456,F,255,1234,174,929,1210,F,632,63,627,F,F,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,344,207,271,F,1390,661,1315,956,482,931,F,492,1060,287,1087,85,1301,266,F,978,F,1327,1014,650,492,248,329,252,1504,F,819,F,F,F,F,1127,1174,12,F,F,104,263,1069,274,1168,501,1504,1424,492,228,227,1116,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1421,1186,1448,F,F,1360,858,F,F,F,F,F,F,F,F,F,F,628,F,F,930,F,1131,525,8,363,526,1212,57,928,78,471,F,493,F,F,378,1127,F,F,F,F,188,937,F,F,F,1323,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,252,F,F,740,F,F,F,F,F,F,F,F,F,910,652,505,1460,383,F,F,275,1430,F,1062,948,728,F,905,192,154,F,F,1023,F,F,F,F,F,F,1174,F,F,F,F,F,F,F,F,F,F,F,F,F,F,656,686,671,1492,F,F,F,688,462,F,1177,1208,936,650,42,870,1419,F,629,F,F,F,1168,450,F,F,F,F,F,645,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,470,962,1308,F,514,257,1129,F,1238,F,1323,1322,33,F,496,800,629,450,923,1450,F,F,F,F,F,F,F,F,57,F,F,1365,257,650,629,1480,485,523,F,332,F,1367,F,F,F,F,F,F,F,F,F,645,1382,930,504,392,F,1480,F,F,344,F,F,F,F,F,F,F,1039,F,F,201,F,F,F,F,F,F,470,F,F,F,F,231,661,1343,F,F,F,F,F,F,F,F,F,F,F,F,1022,1375,F,246,F,F,F,F,F,F,354,1387,1055,1367,79,F,263,316,F,1367,F,1069,F,F,F,F,F,374,F,740,1212,F,F,645,F,F,F,F,F,F,F,F,1410,873,F,728,F,744,318,F,768,1112,1341,486,1460,F,F,F,F,F,31,1367,F,909,F,F,F,460,96,499,F,F,F,1150,F,F,F,F,F,F,F,F,1448,F,729,551,F,F,F,F,1331,280,969,85,161,940,656,78,F,546,331,F,F,F,F,F,F,F,F,F,235,F,F,F,270,363,F,F,F,F,F,F,F,F,F,F,F,F,F,F,651,1258,F,801,1453,1453,F,F,F,F,154,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,802,482,F,962,F,F,F,F,F,F,F,F,F,F,F,F,540,F,F,1268,F,430,F,648,F,1483,F,F,F,851,201,F,F,F,F,112,F,F,F,F,F,1452,83,F,F,F,F,F,154,1260,1023,1266,1039,733,F,316,1445,F,207,F,F,F,F,F,1264,F,F,F,F,F,F,F,F,F,840,627,1496,F,205,1399,F,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,F,1479,207,915,F,316,1150,F,F,F,F,F,F,F,F,F,1483,1497,1453,998,648,1150,F,F,F,F,F,F,F,1080,F,F,F,206,1483,341,F,F,F,F,1277,F,F,F,F,F,F,332,F,F,F,F,F,F,1268,493,F,331,F,F,F,F,629,753,F,F,F,F,F,F,1359,F,F,F,F,F,480,F,962,F,F,F,F,808,F,F,F,728,F,F,923,F,F,430,F,634,F,F,F,F,F,F,1512,F,F,651,F,F,1458,F,809,F,F,484,994,505,354,F,F,1343,F,F,1454,F,1255,F,F,F,F,372,201,F,740,F,F,F,1315,F,1445,1275,F,F,342,450,F,F,F,245,F,532,255,F,F,F,F,F,F,F,F,F,1084,F,F,1233,F,F,F,F,F,F,768,245,1080,188,1087,F,1222,135,316,F,381,1369,F,F,392,F,F,F,F,F,F,F,F,945,F,1421,224,1064,F,F,F,F,F,F,F,1453,1272,744,1486,464,728,F,1260,1448,860,286,F,F,F,190,756,F,131,F,1343,378,F,F,F,F,F,F,F,F,F,F,F,F,512,F,F,F,F,F,F,F,F,F,F,F,1407,904,1338,505,F,885,F,F,1044,1472,517,F,F,656,1366,F,523,F,F,F,F,840,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,F,F,F,F,F,F,F,F,F,F,F,136,F,1469,805,565,504,456,F,F,1283,1510,1325,532,F,F,F,F,449,1456,1067,F,1324,F,1282,257,F,F,136,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,492,485,F,F,186,442,F,F,F,F,F,F,F,F,218,F,F,F,540,1288,471,492,1456,1412,344,148,51,840,F,F,F,485,930,F,1230,850,57,1109,F,F,F,F,F,F,F,F,512,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,326,F,F,627,F,F,629,886,F,F,F,F,F,F,1131,656,430,475,1151,1109,1448,78,F,F,F,1260,F,F,F,908,977,F,761,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,F,224,F,F,F,F,F,F,F,922,254,814,1100,F,1060,589,1131,F,268,257,F,F,F,F,F,F,F,374,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1468,405,F,F,F,F,F,F,F,F,F,F,F,1301,963,F,F,1315,F,F,499,1433,F,252,1032,49,F,1136,931,F,F,F,1301,F,1315,F,F,753,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,596,1459,1456,F,629,F,226,524,F,1379,121,682,F,F,1039,F,632,F,1500,F,F,600,1100,945,F,F,F,F,F,F,F,F,F,F,268,F,F,F,F,F,1255,723,F,485,F,F,145,F,800,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1332,F,F,596,F,F,688,F,1131,341,F,F,619,1237,F,F,F,F,F,F,F,F,191,F,F,F,F,F,629,F,901,F,535,525,F,596,F,F,F,F,F,F,F,F,F,F,F,485,F,F,740,F,1334,58,F,F,F,192,1110,F,F,1110,F,F,430,F,F,F,F,F,F,F,F,F,F,780,F,257,F,F,135,1379,F,1299,1087,F,121,741,632,1344,1288,1235,383,1194,1139,326,217,814,201,1511,505,1309,78,509,1346,1301,F,1365,1296,F,923,42,1289,375,F,F,1503,F,F,F,504,F,540,F,F,1289,42,1087,1367,F,1455,1421,F,F,F,F,F,F,1450,F,F,F,112,F,179,658,F,F,F,F,1128,F,1459,F,F,F,F,F,F,F,F,60,F,F,F,1286,F,1320,F,F,651,F,F,F,F,1320,F,F,F,F,1185,F,F,F,F,F,F,F,F,F,F,F,F,246,F,F,F,F,F,F,F,886,452,107,F,F,F,F,F,F,1172,757,529,F,757,F,526,657,F,652,F,F,F,657,452,F,F,F,F,F,F,F,F,F,F,F,F,F,342,450,F,F,295,374,381,F,F,F,F,388,F,F,F,F,F,F,F,690,F,316,688,723,342,656,F,F,F,F,F,1280,381,F,1268,78,F,485,F,F,F,F,F,F,F,967,672,F,F,F,F,F,F,F,F,F,1109,F,F,757,1367,420,F,F,F,F,F,1375,228,F,F,F,F,1365,491,F,F,356,F,F,F,F,F,F,F,F,F,F,F,101,1072,657,F,F,F,F,499,F,F,1124,1124,1452,F,F,F,F,F,F,642,F,1308,672,F,485,F,F,1323,F,F,F,F,672,F,742,1168,F,F,F,463,F,F,1343,1151,F,1254,F,F,F,482,F,F,F,F,F,F,272,588,F,535,F,F,F,F,1077,776,910,F,F,1087,970,F,F,F,F,F,F,992,F,F,329,F,F,1142,1212,F,1327,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,284,187,F,383,329,F,F,1002,F,F,F,359,1271,F,1271,447,F,1157,1055,1327,1486,F,1370,F,F,F,F,F,F,F,1122,F,F,173,1271,F,31,F,703,F,343,595,628,F,505,1359,F,F,F,F,1367,F,148,F,173,1343,1052,F,F,F,483,1322,F,F,1255,183,706,F,F,F,F,342,486,380,382,487,F,F,F,1266,F,511,1486,665,F,F,380,F,F,F,990,F,1052,F,F,532,1190,1361,F,F,F,F,F,F,F,F,F,F,F,F,491,272,F,450,F,F,F,1035,1367,F,F,F,F,F,700,F,1445,1322,359,F,458,F,F,F,F,F,968,F,F,1328,F,1503,F,F,F,1274,1343,1324,442,1365,F,F,F,148,F,146,F,F,423,1039,853,1142,328,145,F,F,442,1313,F,F,F,F,F,F,F,F,1447,F,F,F,F,963,F,F,F,F,1257,342,F,1087,415,F,910,F,189,F,F,F,F,F,F,F,118,F,F,441,F,F,F,F,871,1069,1274,F,F,1306,189,1186,910,F,F,F,F,F,F,1142,111,F,F,1496,480,1304,1325,118,F,1216,F,F,F,F,F,F,1266,1306,446,462,363,152,57,441,441,146,F,112,F,F,F,F,F,F,F,F,F,F,F,1412,F,1416,1142,F,F,910,1142,F,1360,342,187,218,1141,F,656,F,F,187,F,441,F,420,593,F,1077,F,F,F,F,1190,471,430,897,1367,F,F,F,F,F,F,F,F,F,F,1265,419,F,910,F,F,F,F,F,F,596,480,F,F,F,F,F,F,F,F,1147,F,F,F,611,F,1090,F,F,F,235,251,F,F,F,F,F,F,F,F,F,F,F,F,1296,F,F,1234,F,134,772,F,532,F,F,306,306,841,843,F,F,F,F,F,F,1468,F,F,F,F,840,F,645,619,F,21,F,F,F,F,141,F,F,399,968,365,F,375,1063,F,F,F,F,598,1442,1343,402,1235,679,F,F,F,1078,1349,1075,1199,57,F,1399,1452,F,999,921,344,104,F,F,F,F,627,702,F,632,63,F,F,F,175,1343,F,F,F,860,F,F,F,F,1343,577,F,F,63,F,148,963,753,1433,1047,1047,F,F,F,F,F,F,1331,374,772,F,F,1282,F,F,1175,F,F,F,F,F,F,1336,F,F,1044,F,F,F,F,F,1037,F,F,250,1304,F,F,F,F,F,963,F,F,F,F,F,F,441,1076,1188,F,F,F,F,F,F,F,F,F,F,F,F,F,288,F,F,F,F,640,F,444,628,F,1272,F,F,1072,F,F,341,F,F,F,F,F,F,F,1324,1233,920,286,F,456,929,F,1282,F,F,F,F,F,F,F,F,F,F,F,F,190,1073,F,F,F,308,F,1288,F,1210,1325,F,645,F,F,728,F,F,F,F,F,F,F,F,F,F,F,1490,F,1483,F,450,1350,632,217,1173,1175,F,F,F,F,F,F,F,F,F,998,F,8,F,F,8,1433,404,672,1427,728,640,1433,F,F,F,964,191,F,F,1185,F,1150,1421,450,F,897,F,217,998,F,F,F,F,F,783,F,F,F,F,778,229,501,F,F,F,F,F,526,307,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,404,1226,F,F,F,F,814,F,509,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,887,148,F,F,F,F,F,F,F,887,1343,271,1221,1314,F,923,1055,F,F,1301,F,F,429,864,F,873,F,1293,F,147,1149,1504,1500,923,271,F,F,F,F,F,F,F,F,F,F,F,F,F,1103,F,F,767,1257,F,F,F,F,F,F,F,F,F,130,1343,F,F,1442,F,F,524,224,F,1028,F,F,1460,1230,383,F,1028,33,489,700,F,F,F,F,F,F,F,F,F,F,F,F,1218,F,923,F,F,F,F,1470,1216,F,F,905,F,F,F,F,F,F,F,F,F,881,580,1341,F,1282,1373,433,F,446,1442,184,1080,1221,F,1280,F,F,F,F,F,F,F,F,F,F,F,F,373,F,F,F,F,F,F,F,F,F,F,F,F,295,F,F,F,F,F,F,F,F,F,570,768,F,F,523,1309,962,135,687,430,1076,776,F,430,112,1322,372,814,F,405,1323,393,F,F,1448,613,F,F,F,F,F,F,273,923,962,532,F,F,F,F,F,F,F,F,F,F,F,F,F,F,332,F,1304,1210,F,418,569,F,F,1069,1448,F,868,1442,629,1229,F,317,1286,F,F,1320,F,F,F,F,523,1072,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,707,F,1213,1480,1257,1347,512,1212,F,F,373,485,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1468,F,F,1181,F,1445,F,483,F,F,F,1350,1238,F,463,1186,120,F,F,585,928,33,111,1217,775,504,F,1322,F,1199,F,1242,F,191,F,F,1304,1347,F,F,52,F,F,F,F,2176,F,F,F,F,1384,217,F,F,F,814,525,446,F,F,F,373,F,F,F,F,F,F,F,F,446,543,374,F,470,1282,F,F,1260,418,728,F,1246,476,1035,1286,1433,480,F,374,F,F,1027,509,1219,F,F,F,279,145,F,F,F,F,F,F,F,F,F,F,1257,F,F,F,F,728,1392,1033,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1212,1448,1242,F,F,1345,1213,F,150,1399,F,1274,751,201,F,F,59,1297,F,F,1341,F,F,F,F,F,F,466,F,1410,1134,474,526,F,F,F,F,F,F,F,F,F,F,F,F,F,201,F,F,482,1315,1133,109,F,1343,F,F,F,F,63,F,F,F,532,629,F,F,872,F,F,F,F,1381,1338,F,57,1435,1435,F,521,1433,F,F,1090,F,F,F,1257,F,F,F,F,F,F,F,F,F,F,F,F,1303,771,1266,402,1238,F,F,483,1023,F,F,266,838,274,1442,569,F,F,744,F,F,F,F,F,F,F,F,F,F,F,39,471,F,F,1488,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1030,F,1164,1341,F,1367,F,F,F,1334,819,F,155,476,F,280,F,923,1351,76,818,465,F,F,F,F,F,F,F,F,1351,F,F,F,39,F,F,F,397,619,1507,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,492,F,F,F,723,F,1277,F,F,F,F,F,1286,F,627,F,F,F,F,F,F,F,F,940,F,F,F,F,1237,768,F,865,F,618,F,F,682,F,F,F,674,F,371,F,674,F,F,963,F,F,F,682,F,778,F,F,682,1375,F,F,F,F,F,1104,F,F,730,1232,104,491,1233,F,F,F,F,F,486,F,F,F,F,461,F,F,F,634,1486,27,1084,F,F,1229,F,F,F,1087,F,F,F,F,399,1257,F,1005,1334,F,F,359,F,F,F,F,F,F,1375,F,F,1355,1222,78,F,F,F,F,1286,F,F,888,78,F,F,415,1418,1250,F,1338,57,415,F,F,F,F,F,F,F,F,F,F,F,148,F,F,1448,F,799,F,F,F,1253,7,F,F,F,F,F,7,1366,148,F,F,514,1448,1448,1448,524,F,F,F,F,418,F,F,F,904,F,F,1333,F,F,F,F,F,1360,904,F,753,F,F,F,F,F,1512,910,F,1311,581,1343,910,F,F,F,F,F,F,F,F,1475,F,F,F,F,F,F,F,799,960,197,F,F,F,F,F,F,1333,F,F,F,F,329,F,F,F,1262,F,495,111,F,F,1277,111,F,F,F,F,F,142,207,F,F,F,956,F,1367,F,1365,F,F,F,1243,F,111,1366,F,F,F,F,F,F,1510,234,F,F,530,234,1349,F,334,F,F,F,1247,F,F,511,923,F,949,F,592,F,1149,1265,F,1172,F,592,F,1175,F,450,1457,1419,1142,F,1172,1142,1142,455,1324,1184,F,1184,1142,F,F,F,F,F,F,458,F,487,923,F,1142,F,F,1149,456,F,F,1469,454,F,F,601,F,F,F,F,F,F,F,F,F,1506,1343,342,1255,F,342,F,261,1142,F,F,F,F,F,F,F,131,F,F,996,969,F,362,F,F,F,F,342,248,253,1357,F,489,F,F,661,F,F,1357,886,F,478,956,F,316,1253,1190,423,F,F,1149,F,F,F,F,F,436,F,F,F,F,F,1176,F,F,F,1453,F,F,29,1282,232,F,509,F,F,F,F,F,449,42,F,263,F,F,F,838,1238,1365,F,1288,342,1268,923,1198,F,F,483,F,1352,F,244,1235,1257,F,858,F,1074,1361,F,6,493,F,378,1020,F,F,F,376,F,F,1047,1154,F,F,F,F,F,F,672,18,F,923,1297,F,222,F,F,F,311,1238,F,283,640,315,465,532,1142,1508,985,93,1165,F,F,456,1301,F,1419,F,F,F,354,F,F,882,93,1279,218,F,F,F,485,F,F,F,F,628,F,F,862,F,F,F,661,629,1258,1165,F,F,651,F,F,F,F,628,978,651,F,F,F,402,F,F,F,F,F,907,753,857,F,F,24,F,F,F,F,F,F,F,921,F,F,1323,F,F,F,F,F,1222,15,F,746,742,1316,723,F,F,390,F,F,499,F,F,F,F,351,F,F,F,F,F,90,1255,1433,991,566,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1429,1450,959,404,374,915,452,732,F,F,1172,F,F,F,F,1233,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1458,218,1296,766,F,F,528,F,F,804,F,F,F,F,F,F,452,1044,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,F,1139,F,1110,F,F,F,1343,F,F,F,F,F,F,F,956,1448,687,1005,F,921,1366,1453,1327,1267,756,155,468,518,521,895,F,F,F,F,502,F,389,F,753,F,F,F,F,F,F,F,F,F,F,1257,916,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,F,F,F,F,1242,44,1342,342,1110,528,485,F,801,F,48,F,F,F,F,1304,F,F,948,78,F,F,1232,1435,F,1250,1061,1398,1328,F,F,1117,814,F,F,F,F,57,F,F,F,F,F,F,F,199,934,F,F,F,F,F,1277,1445,1065,F,F,1448,881,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,255,F,F,F,F,F,F,1250,F,1447,57,F,329,F,F,F,F,F,2254,F,F,F,35,F,F,F,F,959,800,78,283,F,1079,1301,124,1301,1336,728,F,F,F,1283,1480,344,F,F,887,1313,F,1367,1266,1367,1365,F,523,1139,563,F,F,1442,723,356,F,F,F,F,F,761,1259,57,F,344,F,F,F,F,F,1277,F,F,F,518,F,F,379,F,F,F,F,F,F,F,F,F,F,F,F,146,518,518,F,F,1044,F,324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,342,F,F,F,1230,1301,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,428,F,1280,890,1366,F,1173,886,F,1079,576,482,F,F,1410,772,757,F,342,274,F,F,1075,112,F,146,400,741,1153,29,934,F,1480,1238,1315,F,736,1384,1360,F,1362,F,1052,430,687,1446,F,F,F,514,1438,F,F,723,1360,F,1250,22,F,F,530,1005,197,1445,F,F,F,F,F,F,F,F,450,1326,F,537,1065,568,F,F,930,F,F,723,F,F,F,F,F,1177,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,F,F,F,F,F,F,F,F,F,F,F,F,F,F,146,F,1293,F,476,F,F,246,605,F,1395,104,1136,1498,1128,F,1262,F,1282,F,F,505,1360,F,380,372,F,F,F,537,466,483,912,1252,F,1343,F,F,771,F,999,774,F,905,1055,253,483,454,280,564,1142,F,444,F,1087,F,F,F,437,330,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,803,F,F,F,159,F,F,F,F,F,F,F,F,937,F,F,F,F,F,F,F,F,F,F,F,1260,450,1117,1250,F,415,1005,723,F,1029,F,1253,F,1360,F,1149,1069,F,F,F,922,F,405,928,1265,F,F,F,F,1128,672,F,F,920,253,F,F,921,487,1360,1258,129,F,F,F,F,F,730,667,56,F,F,F,F,F,F,874,F,F,F,1438,1069,121,344,F,F,F,F,962,1110,F,F,1502,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,886,F,F,197,F,F,F,F,F,359,272,F,341,392,1036,1119,492,913,F,94,52,758,1427,1275,F,524,1087,492,627,F,173,612,F,431,450,381,1424,505,1273,F,271,418,487,1273,483,1089,607,482,1052,F,F,1157,1483,1098,F,483,161,485,1069,650,640,F,1029,F,11,1011,F,1168,228,F,461,F,1023,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1013,1252,526,461,623,672,F,F,1149,1407,F,F,F,930,F,F,F,1257,F,F,F,F,F,F,F,F,F,189,1065,F,F,F,F,1023,F,F,712,963,F,78,354,F,945,530,229,124,1327,430,F,482,F,75,F,715,F,F,F,281,19,512,672,733,252,431,F,491,F,F,455,378,F,F,672,342,470,F,1402,F,511,1110,975,F,F,F,F,F,228,F,1230,59,F,F,F,F,487,F,F,F,F,F,F,227,518,1266,78,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1262,F,63,F,F,F,910,F,F,1036,44,1047,1237,F,97,672,F,F,F,F,F,F,F,F,F,F,228,F,383,1381,36,355,F,F,128,485,643,F,956,493,672,275,1125,1332,1346,F,1142,1332,512,159,342,1370,1062,F,76,231,1080,F,F,670,945,682,910,F,723,505,F,F,482,F,F,672,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,126,740,723,492,F,F,91,F,1124,F,F,F,F,F,F,F,431,1023,F,1483,561,372,1110,F,F,F,F,F,1168,1422,1282,1102,147,1359,671,725,645,1112,F,F,1257,316,341,1071,1168,118,1375,F,341,493,248,F,F,1435,1280,226,F,F,F,1446,F,F,F,1304,F,F,F,F,814,318,723,742,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,665,272,F,1422,493,420,228,1040,493,1150,501,908,F,905,F,1469,F,640,1486,F,1480,F,452,F,F,F,F,1260,F,723,526,740,1266,F,587,723,491,F,F,F,828,F,248,1035,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,1446,930,687,F,1480,F,F,F,1512,418,1130,380,F,156,485,1343,1365,F,F,F,F,F,F,F,828,800,1020,F,F,F,648,F,F,818,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,939,1332,344,1099,587,963,266,1089,F,F,F,F,F,627,524,1014,F,F,F,1411,F,F,F,1274,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,1078,250,629,244,1390,F,682,94,F,F,17,F,F,492,F,255,F,F,1350,F,F,F,F,F,F,F,F,F,F,1343,230,828,F,1375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,628,627,452,F,1362,F,F,F,F,F,F,F,780,F,F,F,F,F,F,F,139,F,F,F,F,F,F,332,66,F,F,F,F,703,355,480,F,198,F,1361,F,F,1362,F,F,F,1301,F,F,F,F,1304,451,670,F,1262,1343,F,F,F,F,F,F,F,451,452,1485,F,F,F,F,F,F,374,1207,1252,651,381,1450,F,F,F,F,F,F,F,670,F,F,F,1498,F,F,F,1190,1261,430,F,F,F,F,1375,F,F,F,F,F,774,78,449,923,1088,934,1453,1334,381,F,F,F,31,544,F,F,F,F,F,F,F,430,485,450,1323,F,F,F,F,182,262,958,446,F,F,428,F,F,1498,F,1266,F,F,1264,1260,F,F,1039,F,F,F,363,1260,F,F,F,363,F,F,687,F,363,556,F,1085,1460,F,1165,1320,801,F,F,F,F,F,F,F,684,1128,F,F,121,F,F,1255,F,1364,F,F,F,F,F,F,F,F,F,1397,F,1343,1257,442,968,1233,147,1260,1174,1378,F,1340,483,468,339,F,343,F,F,483,1307,F,F,1147,F,274,F,F,F,1370,F,F,F,248,F,F,1459,1144,F,999,1311,1367,318,43,F,964,F,85,486,1411,F,832,1065,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,400,390,F,224,F,F,F,F,F,F,F,350,F,F,190,F,1324,1114,1080,F,572,1447,1190,963,295,F,1289,1005,1365,F,F,1343,1343,1304,340,363,F,F,F,F,430,1344,F,444,F,286,F,F,F,F,F,F,F,F,F,F,F,1289,316,F,F,F,F,F,F,F,F,F,F,920,1044,F,274,248,627,1343,1258,372,1190,552,444,590,814,146,1168,F,1190,F,F,F,F,F,627,768,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1419,43,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1177,F,1381,295,1085,563,882,344,672,1282,1282,1297,672,1174,1142,190,962,F,344,1445,F,1282,1493,327,741,1288,F,F,1190,921,F,893,F,1131,307,F,F,F,451,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1114,F,66,261,32,1077,1282,1275,326,F,F,F,F,F,F,F,F,F,F,F,F,F,F,172,1096,1331,532,1075,F,1359,318,F,F,585,248,F,710,505,F,1323,F,F,1235,F,F,1032,505,1365,1238,295,968,517,333,661,257,890,F,634,450,688,F,F,F,F,F,F,F,F,252,F,F,F,207,756,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1232,528,553,1323,501,F,F,F,F,F,F,F,374,F,998,1132,1055,F,F,146,F,921,1136,F,1338,1397,972,1421,21,1496,1136,1433,1260,F,160,501,1504,362,1255,F,F,690,483,F,F,1280,1280,F,F,269,F,F,F,F,958,F,F,F,F,F,905,F,656,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,505,F,471,F,F,F,1047,F,F,F,F,F,F,F,F,F,F,F,F,1446,8,1304,57,F,1335,801,1458,F,1124,F,1280,1367,962,F,F,1496,1445,1423,690,1237,150,730,F,482,F,1124,1337,1258,1204,632,1307,F,1255,F,F,F,F,F,710,F,F,F,F,F,F,F,F,F,F,F,F,442,F,F,690,272,192,120,672,1124,F,F,F,F,F,F,F,F,F,F,F,690,F,F,F,F,F,1450,627,327,F,513,587,1343,455,207,F,1367,51,1211,1089,224,1252,190,820,231,1496,418,F,886,173,F,F,274,854,F,F,1457,F,1280,1433,945,1306,F,1037,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,F,344,F,F,F,F,F,629,F,F,F,F,F,291,F,1335,1089,F,1039,F,920,407,1039,F,344,710,F,618,1419,F,F,F,F,F,F,F,F,F,F,118,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,618,F,1402,1349,8,1279,F,57,F,F,F,F,F,F,120,F,F,886,192,645,921,F,485,512,265,325,1341,1204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,724,94,1318,F,F,F,F,F,F,F,1328,F,1186,F,F,F,1446,F,1282,688,F,629,F,710,F,F,1262,F,F,1120,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1121,1313,F,F,F,F,F,629,1370,F,F,1421,F,1142,1307,1238,1338,866,710,1168,901,F,274,959,1342,F,F,F,F,1216,118,F,F,511,F,F,F,F,291,F,F,F,F,F,F,120,1349,651,F,1267,F,920,F,1375,690,1350,963,F,F,F,326,1483,F,F,F,F,F,950,F,F,F,818,1238,1099,F,344,F,F,753,753,1216,118,1394,F,F,F,F,F,627,627,F,1262,529,F,774,730,F,F,465,F,1099,F,1303,F,F,326,F,1282,F,F,F,F,1142,1357,F,1421,F,F,F,F,F,F,F,935,778,F,650,F,F,F,674,1267,342,F,651,F,F,F,1035,F,471,F,F,F,F,F,1186,F,951,F,464,F,12,F,F,F,F,F,F,703,F,801,F,F,F,405,851,F,F,1273,F,509,F,F,F,F,1442,F,831,F,F,1030,452,F,1496,470,F,F,482,F,F,F,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1289,F,F,596,1367,532,F,F,F,F,F,1090,1442,F,1357,F,362,F,493,1286,F,F,468,F,F,1096,F,F,150,423,F,F,F,F,641,F,F,F,F,495,F,374,F,1480,F,F,F,F,F,922,F,F,F,F,929,F,269,907,F,F,452,F,F,823,F,923,253,253,F,629,F,F,1293,F,F,F,F,F,F,F,F,F,F,F,F,F,F,766,F,1442,596,1448,F,294,77,907,1301,1358,188,629,F,F,870,F,F,1300,F,F,F,F,F,F,F,F,F,F,F,918,F,133,923,F,F,1343,F,1168,286,661,F,493,F,F,F,F,F,1422,1372,F,F,F,F,F,F,F,1365,F,371,F,448,F,F,F,922,F,761,463,663,1260,291,606,327,1229,F,209,F,882,F,F,F,216,1245,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,360,F,F,F,207,F,F,923,627,945,932,563,51,F,1085,F,F,F,F,F,F,F,1235,F,F,F,F,F,F,F,F,F,F,F,F,1052,F,F,F,F,F,F,F,F,F,F,1483,F,F,1170,929,272,F,F,1199,F,F,1235,F,F,F,450,1288,F,1052,1447,F,F,F,F,147,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1456,1245,146,1136,1282,F,556,F,F,1051,1077,1085,F,507,F,F,F,360,687,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,1447,F,F,F,702,F,1096,556,F,254,1340,F,1124,174,F,F,F,F,F,F,F,F,F,207,1218,F,F,1282,F,F,F,F,F,F,F,1307,F,F,F,F,431,532,F,F,1170,327,F,1447,1080,1208,F,F,150,F,1170,1422,437,F,963,1236,F,281,41,F,F,F,640,F,F,F,F,F,F,F,1110,F,F,F,F,F,64,1282,67,F,193,F,F,F,526,430,F,F,F,587,1355,F,F,F,F,F,F,F,1090,F,F,F,F,F,F,F,F,F,F,F,F,814,F,1365,1480,723,450,F,F,645,F,F,F,F,F,F,F,504,1294,F,F,F,F,1324,F,F,F,F,F,505,596,1090,505,618,F,F,1496,F,1080,F,F,F,F,F,1236,271,1124,F,F,F,F,F,F,F,F,1280,982,F,F,F,F,F,F,F,684,F,F,F,F,929,F,F,F,F,F,F,780,651,F,F,485,F,F,F,746,F,F,383,F,F,F,F,1307,F,F,F,1301,F,F,F,F,78,F,F,F,F,F,F,F,F,F,F,1235,F,F,F,F,F,569,F,F,F,1229,F,114,F,712,556,F,F,190,F,F,F,1268,753,F,F,F,F,F,F,473,122,1077,1440,295,728,F,1077,962,1087,F,187,1324,F,F,449,920,1448,464,F,F,F,F,F,F,F,1447,F,1361,F,F,F,F,F,361,1333,912,F,F,F,F,F,F,F,F,1341,144,485,F,7,F,267,F,1131,F,521,F,F,F,F,F,F,923,632,1308,F,641,F,F,F,F,1379,1308,196,889,F,585,F,1168,462,226,401,136,F,1057,485,F,F,F,F,634,543,1076,F,268,1360,921,F,1276,F,F,F,F,F,1343,665,F,F,174,F,F,F,F,612,359,F,F,F,1407,F,1236,F,F,F,491,F,F,F,F,1349,316,629,929,F,F,F,1334,F,F,F,584,1236,F,974,F,F,1276,974,F,F,449,F,F,F,F,284,161,F,F,1069,F,F,1480,291,485,F,F,1150,F,853,F,F,F,F,F,F,F,507,941,F,F,F,F,930,526,F,F,959,1216,F,F,F,840,1112,F,F,F,1341,F,383,476,854,1483,F,499,F,F,1259,1062,F,1341,1448,F,F,F,F,F,F,F,680,F,1453,532,1150,840,526,450,F,1448,F,F,F,F,54,F,F,F,F,F,F,F,F,F,148,1306,483,387,524,1250,1216,F,959,1235,275,F,1085,F,1440,786,F,F,1260,1433,1448,F,766,F,F,F,F,491,F,F,F,F,F,1332,405,F,F,247,F,480,512,F,F,F,F,F,F,532,1375,F,F,F,F,F,F,483,F,1124,F,491,F,588,1250,583,63,532,F,F,57,F,120,F,1483,627,F,F,F,337,931,1324,F,1152,753,F,F,F,568,1257,F,F,F,F,F,F,F,F,F,452,452,F,342,F,F,1329,407,F,990,1347,332,537,F,1377,F,F,1316,F,1260,F,F,F,F,F,F,F,F,F,F,F,F,1261,F,F,423,F,F,F,F,F,F,F,F,452,F,F,449,918,315,F,F,F,F,486,F,F,1341,F,F,F,F,F,1215,766,F,F,F,F,F,F,F,F,F,1345,1347,F,F,F,F,F,F,F,F,F,485,1234,1080,278,287,F,1216,1219,629,F,F,F,989,F,F,190,1304,1453,1486,F,F,F,F,F,F,1233,1319,F,485,130,F,F,F,F,F,F,F,F,483,F,F,F,F,F,F,F,F,F,F,F,1054,1199,F,67,1260,885,1413,F,F,471,F,1134,F,431,F,1373,F,1252,F,651,F,1413,455,F,F,F,F,F,F,F,F,F,F,F,120,F,F,F,F,F,F,F,295,F,137,F,F,F,F,F,1131,F,1174,F,F,F,F,F,F,1448,1170,17,1480,1486,553,F,1039,1173,672,F,F,F,F,1053,1412,F,F,F,150,F,1325,F,F,F,F,F,F,F,F,F,F,F,F,760,449,F,F,F,F,F,F,145,F,F,1124,F,F,F,F,F,783,F,F,483,285,448,F,194,1410,1345,F,1276,F,60,52,596,F,145,F,928,F,9,1077,F,F,148,F,F,F,F,F,F,F,F,833,F,485,F,F,F,F,F,F,F,F,F,F,F,851,F,1261,F,F,118,F,6,F,F,1076,430,F,483,145,1260,1442,F,F,1139,F,696,F,F,885,F,F,1301,931,F,F,F,F,F,F,F,F,F,1264,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1367,F,F,F,F,F,F,505,1264,671,F,945,F,114,F,F,F,1329,485,1046,667,F,482,1448,1234,F,57,9,1340,9,F,F,F,F,627,F,F,F,F,F,F,F,F,F,F,F,F,1265,F,F,F,F,521,1151,F,428,431,F,F,1410,1421,1343,1260,F,1260,313,1323,F,F,760,F,F,F,F,F,381,F,F,F,F,F,F,F,F,1377,F,F,F,F,F,1452,F,121,175,471,1382,403,492,1318,F,F,F,1273,431,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,F,F,F,731,818,F,723,F,645,1456,915,1144,F,F,F,1350,1165,405,F,F,F,F,935,F,688,1023,645,146,1281,F,F,405,41,684,530,F,F,F,295,F,F,F,F,F,F,F,F,430,F,717,F,F,1282,901,F,417,139,1055,471,F,F,F,F,F,F,F,1349,F,492,F,F,F,F,9,F,F,F,1142,1340,1208,F,F,F,656,F,1510,F,627,F,901,1313,F,791,270,F,F,602,F,F,F,F,F,1419,532,1438,483,929,F,417,329,F,417,442,430,446,F,F,F,1422,155,1147,932,F,1067,1349,F,F,946,F,F,480,F,1260,431,1260,1261,425,612,F,629,F,F,141,F,F,F,537,1257,419,F,F,F,F,F,270,F,268,1227,F,F,268,1386,530,F,666,161,F,1442,F,F,F,921,545,480,598,F,F,F,F,F,F,F,350,F,1085,F,265,F,F,F,F,665,1203,F,F,1365,1255,F,1173,F,F,F,F,F,F,F,405,F,F,F,F,F,1150,921,F,F,F,F,F,F,645,629,F,1314,F,F,F,F,373,1185,420,161,F,1212,F,1294,1360,753,147,F,451,271,F,760,F,768,F,651,F,F,F,8,1265,F,F,F,544,1112,1028,F,F,F,1343,F,F,F,F,1343,1277,F,1445,270,F,F,F,F,700,F,F,F,678,532,F,F,F,934,651,F,F,F,F,F,F,890,197,287,1366,F,F,F,1479,F,1260,458,744,1154,F,F,F,915,F,730,702,F,389,F,929,F,648,742,1237,109,F,452,1129,F,F,526,1047,723,F,F,F,F,F,F,F,F,F,1236,1257,653,F,F,57,1238,F,F,F,629,1433,F,1356,450,1228,21,491,F,F,F,F,1511,1421,77,958,1326,F,F,265,963,F,F,F,F,886,1417,F,F,1052,380,65,1341,342,F,1286,1080,1189,F,F,261,F,F,1198,1274,1253,874,468,F,F,F,F,598,F,F,F,1112,206,1047,1455,1430,1236,598,57,F,F,266,F,F,783,1279,986,1206,1238,1360,715,1370,1452,F,F,F,F,F,F,F,1118,F,382,1063,F,1429,886,F,F,1189,F,145,882,120,1355,1100,F,1254,F,886,463,F,342,F,65,F,F,F,F,782,F,65,1238,1355,F,783,391,F,F,F,F,F,629,F,1286,1323,910,874,F,F,F,F,1136,F,992,1054,F,F,F,F,1136,F,F,F,F,F,226,F,720,F,F,F,F,F,F,F,F,1084,F,F,F,F,380,F,417,1080,1328,F,383,F,F,F,F,F,F,F,F,F,F,F,F,553,F,F,523,F,860,104,38,1293,90,F,F,649,8,F,F,F,730,599,F,F,F,1274,F,1065,F,F,F,F,F,F,F,478,801,F,F,F,F,F,F,F,1444,F,650,1411,F,F,1377,F,F,1304,F,F,F,F,F,F,142,1249,1257,F,F,44,F,F,1056,F,F,F,1367,730,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,276,F,F,114,318,1399,144,F,1186,F,F,F,F,F,F,F,F,F,F,1341,F,F,F,F,F,1331,F,F,569,F,329,1280,144,F,F,F,F,F,F,F,F,587,F,F,F,70,F,665,F,F,F,1343,730,1282,F,F,F,F,F,F,F,F,F,399,F,682,F,F,1055,F,F,F,F,F,F,F,524,271,F,F,1265,F,F,1447,F,F,72,F,F,F,F,F,F,F,F,F,F,F,F,1447,1469,1311,634,1131,F,F,F,F,F,F,F,F,F,F,F,F,603,526,731,1316,1422,403,F,F,F,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,1238,969,118,F,F,F,F,F,F,F,F,994,F,1375,F,1483,F,F,F,684,401,F,142,F,523,1174,1230,F,F,F,1445,F,F,775,F,F,F,F,417,F,F,F,F,1257,648,F,F,1322,1304,F,F,F,F,452,355,1304,F,1260,F,F,F,207,F,F,1260,450,F,F,F,F,F,F,1322,F,F,F,F,F,F,F,F,1343,146,532,F,1496,F,F,F,F,F,F,F,F,F,502,1343,F,1151,148,22,1196,1488,959,F,F,148,1259,F,F,F,F,F,F,F,801,F,198,F,1254,F,161,1121,1356,525,22,F,189,248,863,148,F,960,F,1323,F,F,F,F,F,F,F,F,F,1417,F,F,F,F,1268,52,580,923,1453,524,945,753,1370,F,401,1502,F,F,F,F,F,F,F,F,F,945,F,F,F,F,F,F,F,F,450,959,413,344,607,1047,1257,82,1080,1357,395,F,814,F,F,447,F,F,F,F,F,F,F,F,F,F,730,F,F,F,295,1266,1378,1304,948,265,623,923,601,342,922,152,F,F,201,F,F,753,42,F,F,F,235,F,F,F,505,153,148,1367,205,1124,1170,1090,1411,342,F,135,337,446,1411,F,F,F,F,F,F,F,F,F,F,F,F,F,505,1411,1419,F,F,1324,413,1254,455,250,1332,1118,929,483,1296,923,535,F,413,F,F,F,F,F,F,F,1324,505,205,F,1204,1423,893,672,1445,161,730,934,F,634,F,F,F,F,F,F,629,F,F,F,291,1124,532,F,F,524,1150,640,1028,267,F,1382,1446,F,F,F,1309,651,F,F,242,F,F,1419,1306,948,502,888,F,F,419,F,F,F,F,F,1365,405,F,1315,F,F,1315,120,505,524,1324,272,F,444,1268,1315,F,F,F,F,651,505,1343,963,353,332,F,532,962,F,F,F,F,521,F,485,484,F,F,F,F,F,F,1258,865,F,583,572,F,572,1413,F,F,24,F,F,138,452,833,291,1293,282,1073,1228,328,F,F,1257,1483,F,452,F,F,F,F,67,F,1218,32,363,F,553,F,F,F,F,F,F,1475,342,753,487,1216,1367,F,760,532,524,385,909,F,F,800,F,F,F,1221,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,156,580,415,F,1271,1257,F,210,1189,1020,F,285,135,F,F,F,620,1483,F,1443,1511,F,F,546,1386,F,1369,956,F,F,F,311,1315,F,485,F,111,F,F,F,F,F,F,F,1087,1309,1280,1167,420,1265,1052,1214,423,589,107,268,838,1325,142,915,923,1375,341,F,F,F,F,1187,F,F,F,F,F,F,F,1249,1073,1217,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1151,F,1319,1448,672,1324,523,F,F,237,F,161,1497,295,1446,882,F,66,F,247,F,F,F,F,F,F,F,F,598,F,1338,F,F,F,F,F,F,F,F,F,426,862,1152,546,1301,1494,483,1255,F,F,173,F,F,910,1324,1218,F,272,F,890,148,476,904,F,191,1412,F,F,F,F,1227,F,F,F,F,1225,F,F,F,295,1238,26,F,495,F,F,F,F,F,F,F,F,F,F,F,112,F,161,575,1165,1492,629,1142,1023,F,F,862,862,F,F,F,1037,938,F,F,F,F,F,F,F,F,1496,F,1448,1324,F,505,792,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,674,962,F,244,635,121,945,667,257,205,F,F,484,F,F,129,1098,1496,F,546,F,F,934,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1094,1367,1423,619,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,908,F,F,F,F,1433,1277,F,1151,207,601,F,1301,1090,1412,94,F,F,56,884,F,F,F,F,142,F,F,F,F,F,939,482,F,F,F,F,F,1415,F,601,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1174,1023,509,1460,287,F,114,530,1154,52,F,316,645,1402,1131,F,F,F,F,F,F,F,F,F,1039,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,1208,F,950,F,186,F,F,F,1142,76,1242,F,F,909,F,F,F,F,F,F,F,751,86,F,F,583,F,214,986,41,600,F,F,F,F,F,F,804,627,F,F,F,F,F,F,501,670,629,661,401,F,F,F,121,F,F,F,F,F,F,1265,F,122,F,1282,1422,F,F,F,F,F,1099,F,F,F,F,F,F,F,F,F,F,F,729,674,684,F,F,250,F,F,F,F,F,F,F,257,F,1227,1375,674,F,674,F,F,F,F,623,F,1225,260,791,1062,1282,886,F,F,F,F,F,F,F,F,F,F,710,F,1346,F,78,F,1460,F,F,150,F,F,F,F,F,F,F,760,1216,F,1191,1336,F,F,F,F,F,F,467,F,992,F,1338,F,F,F,F,F,1215,F,F,F,F,F,F,F,F,F,F,1499,1367,F,F,0,F,1453,1227,F,F,F,286,1452,408,465,733,F,F,F,182,945,374,1186,715,F,F,F,1156,F,F,998,F,F,998,921,405,F,F,F,1273,1173,F,F,F,F,F,F,1274,F,F,1419,207,F,F,F,F,F,F,F,F,F,F,601,F,F,F,1069,F,619,629,F,119,814,674,F,1185,471,F,F,F,F,374,F,F,F,F,F,F,F,F,923,1365,F,1283,F,F,F,F,1375,21,224,482,1309,F,F,532,823,F,F,F,F,F,F,F,274,483,F,F,F,F,F,F,853,375,48,F,561,1329,657,799,1413,1347,804,872,F,374,85,430,999,401,F,F,F,57,1257,524,474,57,F,1176,1443,F,441,1343,190,F,76,F,F,F,F,374,F,F,311,F,F,1327,1303,F,F,441,F,F,1394,116,442,F,1173,F,F,F,1445,1288,1302,F,F,1441,F,F,1229,F,F,F,537,F,F,F,F,1250,F,F,672,F,1442,999,142,342,F,295,1157,1154,F,528,131,1142,247,F,1488,F,F,561,1207,F,561,F,F,F,F,F,F,F,F,F,F,F,F,F,F,999,1376,430,1489,191,958,F,F,F,761,285,1303,561,1268,F,F,F,F,F,F,F,F,272,544,F,1349,F,F,F,1483,F,F,F,F,465,F,882,F,629,F,78,363,524,553,F,450,1332,1154,F,956,999,657,471,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,1154,1447,546,F,F,F,F,F,F,1329,F,1155,441,F,F,F,635,F,101,F,F,F,771,1489,F,244,F,499,882,F,395,306,493,502,838,1397,F,F,F,F,882,229,F,963,632,761,602,F,F,F,F,329,F,F,F,474,F,F,F,580,F,1346,1098,493,F,F,F,F,F,F,F,684,F,672,F,F,F,F,F,363,983,F,F,901,F,661,F,F,F,1440,1268,F,F,650,632,1052,78,629,F,F,F,F,F,F,1280,363,742,632,F,F,532,1453,F,F,F,F,553,F,257,F,1433,F,1087,482,661,395,1397,1268,929,F,1069,F,F,1346,507,F,F,1069,1069,1022,F,F,F,F,1260,F,F,F,F,F,572,F,963,F,363,33,F,57,929,F,F,F,F,F,F,70,33,F,1512,888,F,480,F,F,F,F,F,F,F,33,F,F,F,F,F,F,F,F,F,F,F,825,F,137,F,881,F,F,343,1199,F,F,F,F,F,F,F,F,F,887,910,F,F,147,F,F,1313,923,1255,F,F,1448,248,191,1360,F,F,F,F,F,F,F,F,F,F,F,F,F,191,F,F,248,1480,F,1493,192,F,F,864,1324,923,999,487,F,1448,959,1375,F,1080,F,F,F,426,F,F,F,F,F,F,F,1215,F,F,67,F,545,F,F,F,186,F,189,F,1346,1080,415,1011,F,1327,145,F,187,F,F,374,728,F,484,F,F,F,F,F,F,F,370,1398,F,F,F,51,F,F,F,F,F,1285,592,F,257,F,F,1186,F,F,F,F,F,F,F,F,F,F,F,F,1104,F,F,F,224,F,F,F,F,F,F,F,628,F,1357,499,F,F,1140,964,1345,1369,505,F,929,1331,1320,F,F,950,F,F,F,F,F,F,F,874,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,486,F,1196,F,1167,F,F,F,F,F,283,121,486,148,491,1090,F,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,1370,F,F,449,F,645,F,F,F,1399,F,161,F,F,F,961,49,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,461,573,1073,F,505,F,1233,F,F,F,449,F,F,F,F,F,F,1399,1389,1329,F,147,521,F,F,F,F,F,F,F,F,F,F,640,1364,F,F,F,F,59,F,581,F,F,F,F,F,F,F,F,F,157,1318,1480,F,945,1268,F,1369,1254,501,1273,1047,F,1448,F,F,153,F,58,1235,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,235,F,F,F,F,F,F,F,F,F,1367,1208,F,F,131,F,F,F,471,931,F,1237,F,F,F,F,F,F,F,1360,F,F,F,F,F,F,F,F,F,F,F,248,F,220,F,F,1360,521,1208,1389,F,F,471,F,1047,F,F,480,F,F,F,F,F,F,F,1332,F,F,F,F,1268,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,F,F,F,248,F,521,F,F,F,471,F,552,1360,F,F,629,F,F,F,171,F,146,F,480,F,1360,F,1375,F,F,F,F,F,F,F,F,F,1142,1390,F,F,F,814,1460,F,F,F,F,F,F,F,F,F,F,F,F,1267,F,F,F,F,F,F,F,1078,F,567,922,1200,316,209,F,F,1212,111,90,1273,876,513,921,1062,823,768,F,947,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,F,F,F,F,F,57,38,60,1483,768,1238,426,F,F,F,F,F,F,F,510,F,1444,F,F,F,943,F,F,F,F,F,F,428,1190,1397,1076,210,464,1005,1386,803,F,F,F,F,F,F,F,1265,F,F,F,F,F,F,F,F,F,968,485,1345,628,706,1063,420,216,537,485,85,663,340,1360,577,F,F,F,F,F,1273,F,F,F,F,1377,F,947,469,916,F,F,F,F,F,627,874,1087,523,1341,1442,151,F,1258,F,451,1002,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,941,1445,1350,1259,938,485,1442,465,F,1365,1492,715,F,F,F,1075,F,F,F,F,F,F,F,F,F,F,F,F,968,F,F,F,F,F,F,495,430,F,1190,430,1240,1343,866,F,F,1245,930,629,1341,177,1304,1237,F,F,F,F,F,362,F,1366,F,F,1418,353,929,546,627,1069,394,F,894,F,F,627,F,451,F,F,1199,1107,F,452,627,F,F,667,F,F,F,231,F,1512,1052,F,1067,F,333,526,1190,501,939,359,1483,465,1052,F,F,1150,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1215,F,653,F,142,1247,1510,1367,F,1458,F,976,1259,963,F,363,F,F,F,F,F,F,920,1301,F,F,F,F,352,974,155,723,F,F,F,F,F,F,1069,963,F,F,938,94,F,F,629,F,1230,618,1288,605,F,F,1080,1442,316,F,F,F,F,F,1448,F,F,F,F,1345,F,629,F,F,F,F,751,1237,F,1349,982,F,F,F,F,F,967,F,F,F,F,683,F,F,F,F,F,F,F,F,F,F,F,F,224,F,1347,56,362,F,1243,1324,732,F,357,959,1445,F,F,F,F,F,F,F,F,F,F,F,F,381,1190,F,651,1168,188,1341,318,907,57,F,41,F,F,F,F,F,F,F,F,881,F,1125,F,F,1114,1236,1275,F,F,F,F,F,428,1324,F,F,F,F,F,F,F,F,1341,1395,1351,775,F,F,F,F,F,1381,F,1174,1257,502,1323,F,F,F,F,F,F,F,788,1151,1325,1174,1448,154,1158,F,F,F,F,1413,F,F,F,F,F,F,732,F,F,1254,1346,1325,611,F,F,F,F,907,F,476,414,759,191,F,F,572,154,F,1361,F,F,F,1480,F,F,1118,F,F,F,F,F,F,1347,F,F,1504,1032,658,420,1238,723,450,629,F,730,F,35,492,F,F,F,F,F,F,F,F,F,F,974,F,F,F,723,F,759,443,452,728,1051,F,F,1056,131,F,1480,F,1445,805,F,F,F,F,F,485,F,F,553,1442,F,F,F,F,F,226,641,1421,375,610,454,169,F,492,587,F,F,F,F,1064,F,F,F,F,F,F,138,1151,F,450,723,872,1421,124,F,354,F,F,1343,F,1131,F,F,F,1304,485,603,F,F,F,1341,F,F,730,F,740,F,F,F,203,F,F,602,F,F,1322,F,F,728,F,F,F,1357,98,492,F,F,1118,F,F,F,F,F,F,1323,F,F,530,F,F,F,295,F,F,331,F,331,F,F,F,F,F,F,397,698,F,644,F,F,F,F,150,F,627,F,F,F,F,F,1446,F,F,1282,F,F,155,F,483,F,F,F,885,F,F,F,F,505,F,F,F,F,1455,F,F,F,F,F,1293,F,F,F,583,F,F,F,F,F,F,F,513,F,452,F,F,930,F,F,F,F,107,F,F,921,1229,710,F,269,F,F,F,F,F,F,F,F,F,567,F,224,F,782,F,442,F,F,F,F,F,F,F,599,286,929,F,1345,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,667,468,F,F,F,344,710,F,1453,F,F,1356,F,F,F,F,F,F,F,F,F,F,F,610,483,1343,656,191,F,775,F,F,F,F,F,F,F,F,F,F,F,F,274,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1211,1273,F,F,F,60,175,1255,F,523,F,F,F,F,F,F,F,F,F,F,F,1279,885,1480,1015,F,1037,1486,F,1444,F,F,1496,962,F,148,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,77,F,F,F,F,F,F,F,970,929,1317,131,430,1006,F,F,F,523,1255,F,F,F,F,F,F,F,F,F,F,148,583,F,197,1012,592,146,1496,499,F,1365,1199,723,217,1109,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,332,379,265,113,712,121,58,1367,F,F,1228,1496,F,1483,F,170,1229,1219,F,967,F,F,1229,F,1264,F,F,F,F,F,F,1352,493,F,F,1237,1168,1035,F,921,1044,1367,483,270,121,1204,F,F,656,F,1475,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1012,F,F,1325,383,F,629,111,F,F,F,248,656,1421,907,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,667,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,632,F,F,F,683,F,F,F,F,F,F,F,287,F,F,532,629,601,F,1012,381,120,1301,F,F,1447,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1313,77,141,F,1460,432,F,F,31,F,F,F,F,1259,F,F,F,F,257,F,F,1421,405,F,F,63,596,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,509,F,F,F,F,F,F,F,351,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,725,F,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1259,F,893,1110,F,F,F,F,F,F,244,F,F,F,185,246,482,120,F,F,F,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,F,F,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1194,F,F,F,F,F,F,F,F,F,F,627,F,F,F,F,932,F,F,F,185,F,F,F,F,F,F,F,F,1142,F,255,F,492,F,F,F,F,F,F,F,1447,532,F,751,F,682,F,F,F,F,F,40,F,F,F,F,F,F,1500,F,F,F,F,F,F,F,1432,F,F,1365,F,F,40,F,F,695,1260,452,1343,291,381,1199,1438,F,959,1124,637,962,651,394,605,1193,217,667,465,1085,499,1506,351,1236,F,F,588,277,1114,921,1328,F,F,F,1080,F,351,F,F,235,1333,F,F,F,930,F,1060,124,748,F,751,F,F,F,783,F,F,191,F,814,1427,107,F,F,1114,814,190,F,F,537,F,1060,F,1458,286,12,54,F,F,1170,F,887,1262,959,1075,F,F,F,1153,F,F,F,702,889,F,1170,998,F,F,F,F,F,F,F,191,F,F,482,333,1255,502,610,1407,882,95,F,825,F,121,F,F,F,F,F,F,F,F,702,401,810,150,122,F,F,F,780,F,F,1261,518,484,1441,F,F,F,F,1187,F,F,719,1375,F,1450,1212,1015,1282,1257,F,1187,823,F,1233,491,F,328,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,F,F,F,F,1341,F,F,257,482,354,F,F,491,516,F,F,F,F,F,540,F,F,F,974,F,774,756,1304,1116,1072,580,974,1236,F,F,F,257,774,F,1020,374,270,F,804,F,F,128,430,1325,F,F,F,F,F,1199,84,F,450,1357,F,1079,161,F,F,F,F,F,F,F,F,1274,719,628,1168,F,491,F,F,F,1447,385,393,F,923,F,324,1366,1433,1237,294,119,1257,F,383,F,F,F,F,F,F,974,471,F,1280,1349,F,1142,1224,342,507,888,F,F,F,1075,1365,573,F,888,1280,844,1268,493,1304,F,57,F,F,F,774,F,635,F,890,F,F,F,514,F,F,F,1141,1325,8,F,F,1274,934,1254,1154,F,537,F,F,F,F,F,592,F,F,F,F,F,F,607,F,793,F,F,270,1088,F,501,814,F,1365,F,F,F,F,F,104,F,501,F,456,1241,1335,291,395,454,499,F,F,F,F,F,F,F,620,F,1056,1358,F,242,392,822,F,1131,1273,F,1023,123,F,F,F,F,F,F,1018,F,1376,F,328,493,1304,F,F,F,1260,1088,F,F,F,F,F,F,F,F,F,F,F,1280,629,F,F,1194,F,F,729,120,477,F,F,1468,1375,F,F,F,F,F,F,F,F,601,F,1323,231,1273,1375,1187,49,1030,1268,257,888,890,F,1141,F,499,1335,F,1375,F,994,940,921,253,921,F,F,420,1370,1360,483,352,412,1080,F,962,F,F,F,F,F,F,F,F,F,1243,F,F,1441,907,1323,381,524,1175,F,F,F,F,F,295,F,F,1317,650,57,F,F,F,1485,442,F,286,F,280,1309,F,1054,F,1054,1332,979,F,F,F,F,F,F,F,1216,F,F,74,1304,1211,142,F,270,1343,F,F,F,135,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,529,482,F,1430,49,F,1174,F,F,F,F,881,F,F,F,F,344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1217,F,1266,801,661,F,F,1480,F,1442,1106,1488,332,277,888,1324,1119,959,295,930,F,931,F,86,1316,F,F,F,F,F,F,1475,F,F,711,501,F,F,F,F,F,1421,886,1257,1325,327,814,F,1448,F,1136,F,1343,F,619,1304,F,1343,F,F,1235,F,F,482,136,257,F,F,F,F,F,F,F,F,F,F,F,1370,F,1258,F,656,1136,F,F,F,F,F,F,F,F,49,1264,1325,F,205,F,546,F,F,950,665,54,F,F,F,F,F,F,F,F,F,F,F,1422,204,1253,1301,136,426,532,F,139,112,121,1446,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,363,137,1338,F,F,F,F,160,963,1282,F,1422,559,F,532,F,F,F,F,F,F,F,F,F,F,F,963,F,723,1338,1492,916,F,1080,F,F,F,F,1089,120,F,F,272,F,404,672,1322,F,F,F,F,809,70,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,801,460,930,F,F,F,F,F,F,1264,F,F,651,F,F,F,F,632,F,1343,F,629,1112,F,F,224,F,F,1238,F,F,190,F,F,F,520,442,F,1365,F,585,F,F,F,423,362,322,F,F,585,F,F,399,147,F,F,520,F,F,1128,F,F,257,F,F,F,F,F,F,F,1130,F,F,F,947,F,F,F,399,F,F,F,F,F,F,1473,F,F,1179,F,F,1375,F,F,F,F,F,1503,F,F,F,F,F,F,F,1253,206,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,F,1104,F,265,F,F,F,F,F,F,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,152,F,F,F,F,F,F,F,F,F,F,F,1016,F,F,F,F,F,F,F,F,F,F,483,F,F,F,F,F,253,F,97,F,568,F,F,1236,F,F,F,94,F,F,694,852,F,F,F,F,F,F,1029,F,F,F,1236,F,F,F,F,F,1029,F,F,509,F,F,F,F,F,F,F,F,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,F,F,F,1376,F,F,141,F,F,1052,F,F,F,F,F,F,F,449,1051,1212,F,340,F,949,1301,F,F,F,170,344,F,F,F,1343,265,342,342,1399,918,F,651,F,F,F,1051,872,F,F,F,1345,684,456,1347,52,1364,1067,F,1282,160,F,F,F,F,F,F,F,F,1067,252,F,F,F,1343,F,135,372,661,905,1378,1325,753,F,1128,F,F,F,F,F,F,F,514,F,1117,866,F,1318,1039,286,F,F,175,1282,F,1204,374,1309,F,593,F,651,F,224,1411,F,F,F,F,F,F,F,F,F,F,1344,1114,F,1366,1216,1216,F,F,24,979,78,224,F,1411,446,F,F,F,470,F,F,F,F,F,F,670,F,F,F,F,653,1005,F,F,F,F,F,F,F,F,F,228,723,1264,1246,418,1483,1441,1039,214,629,F,250,632,380,F,F,F,882,F,F,F,1349,F,448,F,280,1255,F,F,F,F,F,F,F,896,431,F,661,753,326,687,1399,78,252,707,F,1472,672,865,280,85,F,F,F,F,136,704,F,F,F,F,F,F,F,F,1257,F,F,F,F,F,F,11,1450,F,776,1218,430,F,F,280,1227,1450,107,629,1099,F,F,F,107,F,1110,F,280,F,473,F,F,F,F,F,F,F,491,825,228,344,480,471,188,F,1357,1023,1186,F,F,F,F,F,F,F,658,F,F,1125,651,702,252,F,F,F,869,F,651,F,F,629,F,828,F,F,F,645,1054,F,327,F,F,F,F,1054,F,F,651,1422,F,70,627,F,F,1110,982,491,1480,F,F,651,651,725,F,1099,F,F,F,F,651,F,F,476,F,F,F,F,F,F,651,491,963,F,F,F,825,514,136,F,F,F,F,F,1438,953,F,953,295,F,F,1039,F,F,F,F,F,44,F,324,F,F,327,325,F,F,F,F,319,571,F,F,F,F,1382,471,F,325,F,F,F,280,F,F,F,F,853,823,868,228,226,8,F,1174,130,19,717,775,F,F,80,1367,1265,700,F,F,904,F,278,F,235,F,F,1291,801,417,161,1092,703,F,F,F,F,F,1230,1343,255,1322,F,1250,1127,F,11,601,775,F,960,733,839,94,95,F,F,F,603,1174,1338,F,804,F,1092,F,F,F,F,188,F,492,F,F,355,F,F,F,493,405,F,1419,F,683,F,F,482,401,F,F,F,487,484,F,F,1309,F,332,F,F,F,57,921,F,F,1370,16,245,F,F,295,329,F,F,526,800,1216,F,1069,342,1262,963,910,1229,651,696,1456,41,F,1367,F,F,F,F,51,700,F,486,F,1328,F,574,502,F,75,F,F,F,684,400,287,1448,F,F,F,1441,295,1457,22,F,F,F,1441,332,270,804,85,280,1044,1039,57,F,F,F,1448,1433,85,F,F,F,532,1316,F,F,F,1260,F,1480,29,1332,156,1142,929,F,784,1367,295,491,1343,1274,F,814,F,F,74,F,F,F,F,406,1280,255,F,F,523,1136,257,342,732,1080,1309,1168,1367,F,F,1280,342,1448,800,1309,1327,F,334,1490,1456,1306,F,F,F,1457,F,597,F,1352,361,592,F,291,1236,715,F,F,F,457,156,F,1136,1142,1136,1142,1313,F,375,488,F,78,1142,1371,F,F,F,F,F,F,1142,F,F,F,F,179,F,F,1293,430,1134,F,F,F,F,753,151,1128,1399,672,1423,684,1304,491,1052,F,1304,F,F,F,F,F,F,F,F,F,F,F,496,F,F,F,41,698,F,1190,1260,F,F,998,F,1074,1456,F,491,344,244,F,F,1355,523,F,1343,35,F,1039,1131,F,285,1282,F,F,F,461,F,F,F,1006,800,1456,401,F,684,F,F,F,F,F,F,F,1445,1304,F,1445,F,532,523,F,F,1370,670,F,F,78,F,F,998,1282,F,F,F,F,F,1259,682,F,F,F,363,F,F,1499,420,F,499,1022,947,975,870,1375,135,342,873,F,F,717,F,F,1157,F,558,1260,F,F,F,F,F,287,F,1343,F,F,1131,F,1262,528,F,1236,F,1343,F,1367,F,29,1216,1142,870,F,F,F,75,F,F,1379,1379,277,1011,1236,F,F,F,F,F,1236,401,F,218,1262,F,F,476,529,1128,F,1131,F,F,665,29,1367,1444,401,F,584,359,226,F,F,F,1267,1416,1039,F,553,84,78,F,F,1131,F,1367,84,518,518,F,530,532,F,768,1416,1157,1237,1262,1282,F,F,F,1023,482,F,F,1282,F,F,280,1485,F,F,F,F,F,F,F,F,F,F,1371,949,342,882,870,F,1347,F,441,1496,F,374,267,429,1250,F,865,F,F,1124,544,814,426,335,295,1338,F,F,1186,267,1433,1032,F,F,F,753,1124,F,615,F,917,291,1480,1338,F,F,F,1270,397,991,651,F,F,20,F,F,115,F,285,956,569,1301,461,1331,1441,1187,51,F,F,14,F,543,F,569,874,1362,19,719,753,F,F,F,F,F,343,950,596,269,1151,F,F,931,1334,1238,450,753,429,1309,F,57,907,F,245,F,1440,F,1077,544,104,F,F,1301,1440,F,1459,F,F,F,F,471,147,F,F,443,828,814,1325,F,152,343,395,920,F,369,1187,F,F,F,1151,931,F,F,521,1301,922,F,1441,F,F,F,959,F,294,F,F,471,444,952,F,132,F,F,344,F,441,1257,1253,706,F,F,1167,F,F,441,F,F,F,F,F,F,F,F,F,F,78,F,948,369,F,F,342,586,F,F,F,F,F,67,514,544,399,F,360,1301,12,1375,1255,1341,512,F,672,968,1208,F,485,F,F,F,F,F,F,F,F,F,F,F,517,532,897,588,F,F,1226,471,283,1371,505,F,401,360,910,719,1469,424,F,F,F,F,F,F,F,F,1356,959,F,620,14,887,1245,F,F,949,F,948,740,268,728,1419,F,952,1341,F,F,F,F,F,F,F,31,F,F,528,F,1399,1304,601,694,694,849,42,F,913,1304,F,F,F,20,F,F,F,F,F,443,F,F,443,1421,F,1041,360,F,908,642,F,F,F,F,1226,1306,F,F,F,F,F,585,F,F,295,420,1039,F,F,228,F,F,F,F,F,1306,F,295,352,F,232,F,724,1340,819,F,901,F,F,F,97,F,F,F,F,1372,F,295,814,F,F,1346,F,F,938,F,441,651,F,119,1351,F,F,F,F,392,F,809,1301,1150,511,F,F,F,882,F,640,F,F,44,F,F,1284,1212,128,353,F,449,1233,F,F,342,876,F,782,1315,1313,F,F,657,651,1313,962,426,1341,420,F,342,22,1341,F,78,F,F,F,441,629,F,F,F,1023,1257,F,F,F,1080,893,456,1341,78,78,789,959,F,F,1237,135,1360,F,1238,470,1023,F,F,F,444,1118,420,870,F,320,F,656,1456,886,F,629,F,F,179,1257,1442,F,52,1442,1131,1324,F,F,F,F,950,1255,636,F,1430,635,F,F,F,504,F,441,1359,F,596,448,F,1372,442,1338,F,1351,1308,1360,F,F,F,F,968,F,F,F,1154,1136,F,629,1118,629,F,F,1367,F,F,1343,F,F,F,F,F,1297,18,1204,1124,1096,F,1367,F,332,F,F,F,1124,1208,1367,1442,1442,F,1158,F,F,F,F,656,F,142,1129,1035,F,F,F,F,F,F,383,332,645,893,F,F,F,629,F,661,158,1274,441,1282,1067,F,F,661,445,1307,332,F,22,78,1158,1124,1430,63,1118,1208,1136,1274,437,1035,F,324,F,F,F,F,824,707,F,F,F,F,461,1445,F,F,F,1343,F,1367,F,F,1341,1373,146,F,F,F,F,F,F,F,F,F,F,F,1346,824,999,F,F,F,768,F,F,F,F,F,F,F,1173,F,21,F,F,F,F,307,1442,295,913,482,799,F,517,487,F,F,353,F,F,F,651,F,1504,F,F,44,F,F,F,F,F,F,1087,1342,857,F,F,F,783,1080,F,703,1080,F,1168,F,F,F,F,F,F,374,F,F,F,F,620,40,1369,1509,605,1297,F,F,1386,144,491,712,487,1367,F,F,1367,1341,F,F,F,705,1386,F,1475,1168,F,F,1260,524,1390,672,1158,F,F,1475,651,F,526,F,F,482,529,F,F,1483,F,1373,265,F,F,F,F,779,F,F,F,151,F,F,F,F,F,F,F,F,16,F,F,F,371,F,77,263,1237,F,F,F,F,968,553,F,F,888,544,342,1357,F,1204,1195,1362,1331,F,1335,F,F,1325,F,F,F,F,F,F,F,F,F,F,1266,F,1168,F,1131,F,F,F,188,F,F,F,1303,1255,93,1367,F,F,121,1262,F,552,97,111,960,F,F,217,F,1124,F,F,454,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1252,1369,F,F,F,F,497,1272,1415,1029,751,F,1057,101,1128,175,728,1458,151,485,151,F,F,F,F,F,F,F,F,F,F,F,F,F,632,F,F,F,F,415,F,F,F,F,F,281,1272,141,1057,629,465,F,F,244,F,F,635,F,F,F,F,F,F,1381,F,F,F,480,648,F,F,272,420,1355,1372,407,651,F,633,F,20,229,1343,825,1055,F,1286,F,F,218,1367,93,1250,112,78,F,492,F,F,F,F,F,F,723,1237,751,F,F,F,F,1104,505,1109,480,F,F,186,F,661,458,F,1215,F,F,1365,F,F,122,1355,480,F,602,F,F,F,F,768,21,353,1342,487,F,218,263,1316,991,529,1204,1303,1117,F,101,141,F,262,F,F,F,F,F,F,F,F,413,F,F,F,F,1254,F,F,F,F,F,F,922,F,482,F,F,F,F,F,585,1236,F,F,F,F,F,F,1084,342,F,1204,F,67,F,1150,422,897,F,F,F,1365,1150,F,F,F,F,F,F,F,1270,F,1297,F,F,F,F,1246,415,882,F,F,F,F,F,F,F,1150,F,67,1270,F,F,1342,F,F,894,461,760,21,F,318,F,F,F,261,F,F,F,F,F,F,F,328,F,F,507,F,F,F,1135,F,F,F,F,F,1230,362,F,F,328,F,1216,F,1241,385,284,F,1433,189,1333,F,33,85,753,F,909,F,F,363,F,F,656,F,F,979,F,F,F,F,F,F,353,F,450,756,F,F,1297,464,342,469,F,963,505,1215,1365,753,1453,521,F,1088,580,956,645,344,F,F,F,F,F,F,F,F,F,F,1304,F,F,F,F,F,F,F,F,F,F,F,650,F,814,F,885,F,344,85,1343,F,F,78,F,291,F,F,F,F,F,1433,F,629,F,F,1201,218,F,672,1322,265,945,1229,745,1502,344,962,46,19,934,F,F,F,F,975,F,F,F,F,F,F,974,455,1268,590,F,F,F,207,F,F,1341,F,F,F,145,1490,783,142,275,1356,1434,F,1152,1327,1280,1306,F,277,1092,1266,1266,F,F,291,F,F,F,F,F,F,F,F,593,F,F,F,F,F,1238,1359,1365,F,F,1167,F,509,F,1154,921,F,250,1157,688,1422,1240,485,17,852,928,F,F,F,1077,1141,F,F,753,F,F,F,1360,F,F,F,F,F,F,F,1411,F,F,1332,F,F,F,F,F,F,F,150,627,1365,121,1340,F,F,148,F,627,F,F,F,F,F,F,F,F,1199,F,1496,F,F,1266,F,F,F,F,1260,F,67,418,921,1032,F,324,F,1055,F,F,F,F,F,F,F,460,F,F,F,F,F,F,F,37,1365,1365,F,483,F,F,F,F,F,F,F,F,F,F,F,F,576,1488,F,F,F,F,F,F,1268,723,F,F,F,F,F,F,F,F,629,1448,316,645,92,272,395,1296,627,923,F,F,532,1215,F,526,1274,F,F,F,F,963,F,F,F,1468,F,F,F,F,532,F,F,F,F,507,F,1456,1268,661,1328,979,1343,645,77,474,485,267,1453,967,505,F,F,F,F,524,F,46,F,F,54,363,184,F,930,1118,1238,142,665,1365,593,930,250,1141,1422,F,316,645,1185,482,929,450,F,F,1365,923,1365,1219,F,24,921,1022,938,1320,1267,F,F,F,F,F,F,187,318,F,589,402,972,295,956,F,F,696,569,1333,F,F,972,158,488,F,1459,F,278,1220,F,783,F,F,F,1323,1190,F,F,F,F,F,1289,402,904,F,F,F,1366,431,F,1480,F,F,1064,1366,F,F,485,F,934,1104,186,1496,623,799,F,1219,1411,F,228,F,F,F,F,F,F,274,60,504,928,430,153,1323,F,1325,F,F,F,1115,1165,1333,F,550,F,1473,401,6,415,F,F,F,F,1136,1304,63,F,335,962,707,F,405,F,F,F,F,F,57,532,175,F,F,916,F,F,F,1343,F,F,929,1343,295,651,F,57,F,F,F,F,F,480,752,F,F,1314,F,F,1325,F,F,F,629,F,1150,F,F,F,674,F,542,712,1274,F,F,F,F,7,F,F,1141,F,F,717,F,404,F,F,F,360,782,426,F,F,F,F,F,F,F,F,F,F,972,F,F,F,F,101,1037,F,F,F,F,886,F,F,F,F,F,F,1279,1274,524,F,F,F,F,142,783,F,F,F,F,F,790,F,F,F,F,F,F,F,F,F,F,F,F,760,F,1060,F,250,F,F,651,F,1440,1333,F,344,930,956,524,76,710,1413,1144,F,F,F,150,F,F,F,F,F,F,1417,F,1077,1358,F,956,235,1170,1474,F,1347,F,783,77,580,1447,286,191,40,F,F,F,F,F,F,F,F,F,627,F,F,524,1241,645,F,F,1249,1079,809,706,518,F,F,F,F,1297,F,F,F,F,1249,F,235,F,1257,12,218,1489,420,179,54,F,267,F,1424,F,F,F,1317,F,F,246,477,F,F,F,741,F,F,344,F,40,556,710,989,1488,928,1261,1118,1297,768,F,F,F,702,F,F,F,F,1410,120,1067,1249,F,F,F,F,6,37,425,F,1035,1136,1170,1320,F,76,998,F,F,F,F,F,F,F,F,F,F,1044,1246,F,F,18,F,1470,F,896,1128,1340,1118,268,1118,682,F,F,F,F,F,F,F,F,F,327,1508,F,784,242,1446,213,640,484,77,192,142,84,F,1028,1390,F,F,F,F,F,502,F,F,1334,671,F,96,F,F,F,F,F,F,F,F,F,800,F,F,F,F,F,F,F,505,916,1472,1390,F,F,F,F,F,F,629,F,F,596,F,F,150,F,F,1422,F,F,F,F,F,F,F,F,F,60,1245,F,F,F,F,444,F,F,F,900,F,1039,F,F,F,F,800,328,1303,F,1079,F,526,F,F,F,532,F,1367,F,404,404,F,450,F,F,629,1280,306,1370,413,F,F,514,F,556,F,1488,327,F,881,371,F,491,799,F,1266,629,130,F,306,371,1367,450,327,18,F,F,F,307,F,F,F,F,556,572,78,F,F,1338,502,F,F,F,F,F,F,F,130,371,1007,F,1375,F,648,F,F,F,F,1367,1375,1415,1274,F,F,740,F,F,F,400,518,F,1218,F,F,1258,1237,1480,1238,585,F,F,717,F,428,422,319,822,1362,456,F,F,607,F,F,F,F,F,F,F,F,F,F,F,F,F,1458,400,57,488,1177,F,688,564,1503,651,921,F,1458,F,F,F,F,383,1498,F,1326,1121,F,585,F,123,F,1332,F,F,1367,F,F,F,F,F,F,1087,600,1343,268,F,F,F,1253,1351,342,1469,343,F,1124,628,1334,1210,F,F,F,F,F,F,F,401,F,F,F,688,1325,921,607,1458,F,400,449,F,F,F,F,F,F,512,F,F,147,F,F,524,1411,F,735,F,F,F,F,F,F,F,1457,353,1293,483,F,F,F,1051,717,1379,F,F,F,1085,F,F,688,1362,497,838,F,F,F,F,526,1362,F,F,1343,1165,1236,134,650,384,F,629,642,F,F,499,1327,63,921,F,F,F,1343,F,F,70,723,114,F,353,F,F,F,963,246,618,F,F,F,F,651,F,F,F,460,963,F,682,F,585,F,F,F,959,1366,456,F,F,F,620,F,992,1276,1112,F,F,274,66,F,F,F,F,822,F,431,873,F,327,761,F,F,341,F,F,450,1232,1044,F,499,1254,F,F,F,F,F,F,F,344,F,F,F,F,F,F,F,75,1460,F,1458,145,F,1071,449,82,F,F,F,F,979,F,F,F,F,F,F,F,761,F,629,F,F,486,F,F,696,F,F,723,756,1453,1267,470,391,521,F,761,F,1007,F,1253,F,1005,F,1413,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,839,1280,F,497,F,F,F,F,F,F,628,1087,1343,246,951,524,F,F,1448,F,605,85,581,1343,F,78,F,F,F,F,F,F,F,F,F,F,F,F,148,F,F,F,F,F,497,F,1250,1316,F,F,F,F,1211,706,F,320,1480,F,928,F,1141,921,F,884,66,331,1201,456,F,F,295,F,F,F,295,F,F,263,F,1005,F,F,F,F,F,295,F,F,F,F,F,F,F,1325,1112,F,F,F,F,F,1350,799,799,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,728,F,F,F,F,1336,907,197,F,1238,F,415,1349,F,1186,1448,324,1360,F,F,585,12,24,F,420,F,F,F,F,F,F,F,F,F,F,F,F,774,772,F,F,512,F,F,1235,F,F,F,F,F,F,F,F,F,F,F,161,F,1136,1157,921,1154,1237,354,F,363,F,420,F,768,363,F,F,F,F,1442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1141,F,F,F,1110,F,801,1033,F,F,F,F,F,F,F,F,F,F,F,F,1280,F,F,F,1496,1365,801,920,F,F,1069,F,F,F,F,F,F,82,F,F,F,F,F,F,F,F,F,F,F,F,F,F,588,627,F,385,F,F,F,F,F,F,F,1324,85,493,F,1253,107,648,1469,F,468,F,1190,1412,F,432,F,F,406,F,F,F,F,F,F,F,F,F,513,F,F,F,257,F,1350,F,F,F,F,1448,F,F,F,F,F,1237,F,485,999,F,F,20,229,682,1338,1235,F,F,F,F,F,935,F,F,F,F,F,F,363,485,F,F,F,F,F,F,F,F,F,F,F,1494,F,1341,F,F,1412,F,645,F,F,F,F,F,F,1338,F,F,F,F,F,F,F,1419,F,F,F,155,63,F,F,F,F,1304,1359,F,F,F,F,F,1282,1236,629,F,F,F,F,F,F,F,F,F,F,F,78,492,120,588,F,F,951,F,F,F,F,F,1099,1257,963,F,F,F,F,F,F,682,F,F,230,803,628,F,24,F,295,341,344,475,1413,9,F,F,959,155,732,1316,1201,799,453,1087,F,1301,1452,546,F,F,F,F,F,1274,1274,190,148,F,253,1343,F,261,F,F,420,1228,F,1342,41,1343,F,F,F,F,1316,F,F,F,F,1271,F,F,F,F,F,F,55,F,F,505,362,1400,F,1441,450,1257,1283,1274,344,1452,F,712,1283,934,F,F,F,329,31,460,F,F,F,F,F,F,499,F,41,1317,1332,F,F,F,F,F,1260,F,526,F,965,1375,1145,1195,753,1411,963,F,341,F,963,148,F,1360,F,F,F,F,F,F,F,F,F,F,1168,F,F,1219,F,F,1216,F,159,F,362,F,F,F,F,F,F,1369,362,963,F,F,F,F,526,F,F,257,1341,1077,1343,F,400,497,F,1114,956,F,295,464,469,1315,F,F,524,F,1416,148,611,F,921,1297,F,468,1190,F,F,F,F,F,F,F,F,F,F,F,F,344,F,F,1315,505,729,1367,F,F,F,F,F,F,F,2610,499,1448,142,F,505,1274,137,627,1375,F,1448,610,1250,963,F,1231,F,1340,1343,605,F,1194,9,532,1325,F,F,F,526,F,1441,F,1448,707,F,F,F,1300,F,F,175,158,F,F,F,F,F,934,324,124,F,731,1124,19,F,343,F,F,F,1238,1445,741,123,1323,1367,F,344,1142,484,F,327,F,F,450,523,F,1366,F,F,F,F,F,921,715,F,F,66,406,F,F,F,746,F,1229,1229,F,F,F,F,F,F,F,F,F,F,F,F,512,1367,1266,F,F,182,483,F,1270,878,344,F,F,F,656,F,1025,1310,1494,F,505,F,F,1419,F,1365,1365,715,736,712,285,344,F,F,F,F,F,F,F,493,F,F,F,F,F,F,F,F,F,F,735,F,17,F,F,F,F,556,F,F,F,F,F,F,F,F,446,F,F,F,380,F,1257,F,998,362,862,1370,1264,F,F,1044,885,F,951,1355,963,F,376,F,363,1265,F,1124,F,F,F,31,921,448,F,F,F,1257,F,1252,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,920,452,399,F,246,1056,700,742,485,1258,1265,483,124,568,150,F,F,F,F,F,F,1423,895,1124,690,627,725,150,1172,F,651,F,148,F,F,F,F,F,F,F,F,150,F,F,148,F,F,F,809,F,1356,F,F,F,F,F,F,F,F,F,F,F,F,F,F,730,F,1087,F,1260,F,295,1483,F,F,505,482,445,1076,629,F,920,F,1453,1110,963,F,F,F,F,F,F,F,F,1280,F,F,F,F,F,F,F,F,F,F,F,1110,F,F,F,1301,F,F,F,F,344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,825,1320,656,488,399,585,148,96,159,F,404,F,227,F,F,F,493,F,229,448,F,568,F,161,929,8,F,888,F,F,F,F,F,F,1316,512,725,F,70,601,383,155,F,F,1355,386,1365,1456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,F,656,84,684,505,1438,740,396,F,487,F,596,F,F,F,F,F,F,1085,F,F,404,F,F,725,930,600,F,430,1204,F,F,F,F,F,468,F,F,F,F,F,F,441,F,F,F,687,F,F,487,F,F,F,F,F,399,F,F,1340,460,F,F,F,F,F,F,682,F,F,F,532,F,F,F,394,F,F,968,809,F,F,702,F,F,1379,1241,148,148,1445,F,188,1472,455,505,963,1198,741,715,1365,18,31,F,885,1438,671,148,1194,F,504,F,1419,F,F,F,F,F,509,671,F,F,493,1152,123,F,190,F,F,1224,197,545,F,60,F,F,F,F,F,F,1240,F,F,929,F,F,F,355,F,F,471,F,354,F,485,355,F,457,F,F,F,1112,F,F,341,F,F,F,F,886,F,86,F,F,F,F,48,F,F,F,F,F,2409,F,F,461,F,399,F,F,851,F,F,F,F,F,F,F,F,870,F,1351,1187,1277,F,1460,F,1365,F,F,F,F,F,493,F,F,964,1229,588,1473,F,1367,406,905,1503,1496,F,1457,836,1457,F,F,F,F,893,728,F,F,F,F,57,1124,F,F,F,916,728,F,F,F,F,F,F,F,F,1338,1366,F,1367,F,1459,F,F,651,F,F,F,F,F,F,830,F,F,F,651,F,641,F,1215,F,56,768,963,F,886,269,814,1212,F,482,F,651,F,F,F,572,1124,F,F,F,1195,F,F,F,F,F,770,1399,F,F,1191,363,278,F,F,505,F,1173,1179,146,962,F,1044,F,78,627,F,684,F,640,1090,243,F,145,740,F,1158,474,F,812,F,537,452,F,672,1338,F,753,131,F,F,F,1137,F,553,344,F,130,F,F,1136,F,F,958,F,F,F,1124,F,1379,F,1136,569,78,F,666,753,F,633,1309,1136,702,57,F,F,F,F,F,F,F,1170,F,632,1150,1055,F,963,272,461,1037,F,F,F,583,F,F,F,814,F,814,682,1510,1343,1268,130,1184,F,F,F,F,F,F,1109,F,729,F,F,728,F,F,F,1229,F,48,934,F,752,F,F,656,1250,F,716,F,1194,F,F,760,F,715,F,F,F,F,1512,F,1212,549,1212,F,F,F,135,1444,F,150,1172,F,1448,120,120,F,F,950,1211,470,974,1419,491,120,F,465,F,468,146,F,465,437,1378,F,1203,58,F,465,1378,F,F,F,F,753,374,F,374,F,401,F,120,F,974,1015,583,896,F,F,1006,F,F,F,823,452,510,801,41,F,800,F,57,450,627,F,F,1457,767,F,969,334,56,627,67,803,266,F,F,632,801,632,694,1433,F,F,F,486,1341,F,662,F,1343,F,F,F,F,224,274,F,F,F,F,1342,F,1147,422,1090,F,F,F,F,1228,F,1124,1333,308,F,1441,F,F,F,F,F,F,F,270,491,F,F,F,865,468,F,114,1052,670,F,1367,F,1325,1229,941,681,1367,F,F,1199,1238,F,1214,F,F,475,46,945,F,509,598,F,1447,1365,F,190,F,F,F,F,F,F,1338,257,114,252,702,F,244,1235,804,620,74,1252,F,1440,F,F,1002,1226,730,507,F,446,F,1418,1006,1483,862,F,753,F,730,F,920,753,F,F,1445,31,F,740,F,672,F,920,150,F,627,1343,F,F,244,213,F,280,696,1324,F,1409,1324,280,915,F,F,1375,F,F,F,480,700,492,825,947,F,947,F,F,1338,F,1145,F,F,F,F,491,F,F,112,F,228,1165,629,F,F,807,120,1392,F,F,F,F,1367,F,1504,58,F,F,160,F,F,F,F,F,F,F,978,F,978,1326,79,F,F,F,F,201,F,F,F,F,F,F,F,F,F,F,728,F,555,F,201,F,F,F,F,F,F,1258,F,694,F,1077,250,F,F,1077,F,F,262,516,F,1370,353,F,F,F,471,482,F,882,F,242,F,52,F,F,865,1142,F,1369,359,1369,F,F,F,F,F,486,F,F,F,563,F,F,267,F,F,1266,923,F,1032,F,F,F,1347,F,F,661,F,F,1164,661,F,F,992,F,F,1347,905,915,1370,999,F,319,F,F,F,422,728,450,1483,F,651,516,999,F,F,F,905,395,307,F,F,F,F,F,F,F,F,201,537,F,F,F,F,F,F,F,1299,F,306,1170,F,1327,F,8,450,1258,F,450,F,1110,628,F,1343,383,F,1154,F,F,F,F,F,F,974,1488,627,F,502,F,F,316,916,1110,F,505,670,629,120,F,1334,F,F,F,F,468,F,F,F,445,250,961,532,F,888,F,F,F,584,F,F,1259,1167,F,F,1304,F,F,F,F,F,59,F,F,429,632,F,F,1124,642,F,F,F,F,509,F,F,F,F,629,120,F,F,921,921,F,F,1483,F,1483,F,921,F,921,1483,F,1415,1415,856,F,523,F,F,1324,F,F,423,768,F,F,F,F,F,F,1324,F,1422,1079,1446,F,F,F,F,1410,F,F,F,F,F,F,999,1410,F,1343,744,1318,F,1448,F,592,1262,F,890,1142,F,1342,F,F,1297,1415,F,286,295,F,F,F,1345,F,295,1125,9,214,F,1216,F,1216,1262,F,F,186,F,1125,F,F,F,F,F,F,F,485,931,1496,1415,1378,1422,F,1341,F,F,F,F,F,1318,1373,F,F,F,F,F,430,928,F,F,F,111,F,F,F,F,850,F,F,F,F,450,F,1325,F,945,78,934,507,F,F,F,F,F,F,F,F,F,F,F,801,F,F,121,948,F,1395,F,F,1346,1282,F,921,493,F,F,1301,F,1409,F,295,F,F,F,1495,1342,F,F,F,F,1446,629,F,F,629,1345,F,F,632,F,120,F,532,F,1382,F,F,F,F,F,F,1415,890,F,661,F,661,F,F,F,F,661,F,F,F,F,661,F,F,661,F,707,F,F,1433,F,F,F,F,F,F,355,378,978,201,499,F,F,F,F,F,F,79,F,1460,958,1326,F,F,F,F,1277,F,469,962,F,F,F,F,651,F,1345,F,F,F,888,F,F,F,632,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,285,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2009,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,27,F,F,F,F,F,F,1419,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,674,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,665,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,642,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1115,F,F,F,F,F,F,F,F,F,F,F,F,531,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,962,F,F,F,F,F,1064,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,832,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,191,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,327,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1124,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1323,145,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,1314,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1237,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,928,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,682,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,F,F,1341,F,F,782,1190,F,F,F,F,F,F,F,F,F,F,F,1266,1259,F,1276,F,F,F,F,F,F,F,F,F,F,1306,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2085,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,577,F,F,651,F,F,F,F,F,F,799,F,F,1621,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,723,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,418,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,341,F,191,F,F,F,F,F,482,F,F,F,F,F,F,1306,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,316,532,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1342,343,F,F,F,1376,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1423,F,F,F,F,F,85,1124,F,F,F,F,F,F,465,F,F,F,F,F,1419,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1241,532,F,F,F,F,F,420,6,F,1158,672,1168,F,1370,F,1258,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1304,442,F,F,F,F,F,F,F,F,342,F,469,1077,189,F,F,915,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,F,532,F,F,F,818,F,F,F,F,F,F,F,F,F,F,581,F,F,F,F,F,F,F,F,148,F,1168,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1085,456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,635,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,442,F,F,218,F,F,F,F,F,F,F,F,F,586,F,F,F,1307,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,799,F,F,F,F,F,F,F,F,667,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1825,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2281,F,F,679,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,610,F,F,F,F,F,F,1055,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1289,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,257,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,566,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,255,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,230,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1237,1268,F,F,F,F,F,F,F,F,F,F,F,F,1056,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,486,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,783,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1230,F,F,F,1576,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,405,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2278,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,231,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,414,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1452,F,F,F,F,F,F,F,F,F,F,F,F,156,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,974,F,F,F,F,F,F,F,F,F,F,F,372,F,F,601,1286,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1906,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,227,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,1185,F,461,932,1670,F,1477,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,753,1270,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,811,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,704,F,F,636,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,901,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,265,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1301,F,F,F,F,1459,F,F,493,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,434,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,1080,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,471,F,F,F,F,F,F,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,F,F,F,F,F,F,F,F,F,F,F,1360,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,825,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,678,125,F,F,509,F,F,F,F,F,F,1090,1070,F,670,F,F,F,1427,F,760,F,F,F,F,F,F,F,F,274,F,F,F,442,F,F,F,F,F,F,F,F,F,F,F,183,77,F,F,F,F,F,F,F,F,446,F,F,F,F,1245,F,F,2001,F,F,898,F,F,F,1259,F,F,432,F,648,F,F,F,1131,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,F,F,F,F,1875,482,F,F,F,F,F,F,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,761,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,246,F,F,F,F,F,F,F,F,1237,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,141,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,F,885,516,F,F,F,F,F,F,1069,F,1199,F,F,324,477,F,F,F,F,F,F,648,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,523,F,1216,F,1236,1430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,596,634,F,F,F,F,F,F,F,485,F,F,F,F,485,1259,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1618,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,1375,F,F,F,F,F,F,F,F,1265,F,F,1480,F,F,F,316,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,1342,F,F,F,F,F,F,F,F,F,F,160,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,627]),
-PinyinRangeTable::new(0x3007..=0x3007, &[2165]),
-PinyinRangeTable::new(0xE815..=0xE864, &[1338,2555,F,402,F,2605,2605,F,2056,F,1224,419,419,F,F,1456,1456,1084,356,576,1116,1116,332,376,356,1999,1142,491,302,1276,F,2176,1627,1458,1693,1233,F,2498,1384,1546,1546,F,1713,956,1999,1999,529,1283,1325,951,951,F,1055,2523,907,1055,1479,1055,532,169,1444,169,1444,1480,1350,1365,1347,182,958,1365,1165,1077,499,645,512,524,1167,888,1324,674]),
+PinyinRangeTable::new(0x3400..=0x9FED, &[958,1175,F,F,575,1254,1346,F,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,1280,F,F,F,F,F,155,F,F,F,F,840,F,F,226,F,F,F,1304,1289,F,1293,656,649,1270,1355,1286,1440,224,1255,861,1005,F,695,932,1343,1345,790,144,332,F,F,F,1479,321,19,1254,1512,F,1456,267,1124,1343,956,580,620,783,1460,1087,F,F,F,1303,F,F,1069,507,257,838,1124,1343,663,1352,51,F,F,F,601,735,1343,629,485,1366,682,116,F,F,F,474,1303,471,986,F,1456,F,420,1260,1147,1332,471,537,696,690,1154,1332,1430,1415,1366,1480,308,979,922,148,1254,420,1155,1039,1110,956,618,1023,F,F,586,916,1142,1088,1326,851,1145,F,731,1347,255,1366,740,537,809,1282,1360,F,F,135,332,619,629,F,683,F,485,F,F,F,F,968,F,90,636,383,712,F,387,1131,F,F,712,702,967,1080,627,F,1233,568,274,1441,1185,F,F,76,480,267,376,141,F,946,517,672,1291,F,774,1282,F,57,505,1124,F,374,F,1362,1289,928,886,252,343,684,928,928,1153,26,353,188,1306,606,F,F,1065,253,627,456,1194,889,245,1011,295,945,1343,1479,1015,491,F,148,151,1257,F,690,242,648,532,1124,1277,1390,F,F,1459,1421,491,1494,163,1282,629,F,148,1258,492,F,483,F,327,161,49,505,F,22,636,577,F,1261,66,532,618,1286,29,1328,688,44,295,671,F,F,135,838,1307,437,1366,F,400,1343,1308,376,667,1167,615,1080,F,1133,1334,1265,1494,F,974,1345,1257,1447,487,452,593,1342,556,341,947,8,F,556,159,1281,159,1235,F,F,463,1124,1362,F,537,1429,1304,1079,F,1091,587,1099,430,352,1324,959,1069,456,1257,318,868,227,321,374,17,343,786,1313,1360,455,F,137,405,2032,456,629,311,1274,914,F,1112,F,F,615,650,1343,447,F,1304,963,306,F,F,1315,F,F,F,F,814,1237,1282,1168,442,1213,814,814,1345,1438,F,F,F,F,F,1224,1085,840,1338,921,1196,418,537,266,473,672,523,480,651,F,1174,678,F,F,F,F,F,F,363,1322,1078,1311,877,184,822,286,1399,295,1280,1359,295,1075,1242,569,450,363,1262,704,690,483,446,1448,F,F,1224,F,30,8,1473,929,380,228,42,78,158,629,1277,1300,F,F,F,F,F,442,1170,201,592,610,1448,1279,1257,F,945,1410,1257,F,F,192,483,480,1141,1323,1304,907,1026,F,F,F,404,1338,1272,1310,430,1512,1343,188,F,622,1266,1146,998,1343,1448,1257,1266,526,483,419,F,872,629,F,601,1026,419,1323,962,F,1323,419,543,147,814,480,F,57,1262,1246,1307,1227,1360,947,1304,814,57,428,513,20,20,F,F,1438,1149,524,F,1512,85,505,8,1394,188,311,F,F,F,F,814,658,715,280,35,57,40,F,161,1264,1174,124,F,F,284,1235,344,286,1366,1337,585,1237,577,F,1235,1331,662,1288,84,146,1280,814,606,1340,1488,702,1425,1264,403,1280,F,485,640,1343,483,1345,F,217,1343,1282,428,1357,544,122,1145,1154,1446,41,723,585,120,619,F,1260,F,1257,941,780,1376,F,661,344,1488,F,383,540,253,456,586,F,360,1160,F,1054,599,814,342,360,943,33,486,563,1260,1367,1473,1071,186,1274,484,831,1275,1343,1365,1341,1324,1071,979,428,1023,535,1360,F,1284,873,958,119,F,85,265,1112,307,F,711,1379,482,F,940,1293,870,159,881,839,505,1340,308,287,F,F,F,286,F,F,945,687,959,1119,96,270,1257,332,1343,1134,942,910,1283,1191,1286,1360,44,663,F,F,F,F,1377,627,1142,602,703,936,1454,1325,1257,672,1257,1036,318,F,1237,313,1343,783,141,1152,482,1090,890,9,573,111,F,1266,1448,F,F,332,634,1315,1304,730,471,761,1355,1421,1343,829,1154,1257,1377,1090,342,1343,218,F,632,101,93,526,672,1124,791,20,11,932,F,205,191,F,978,804,698,1284,1375,770,20,1069,696,F,F,603,1257,1375,1448,1246,457,725,809,1229,728,814,963,1390,634,1446,1485,413,1304,428,1306,1448,732,183,380,F,183,674,1460,1084,641,521,1281,263,507,998,706,F,556,1333,818,1341,605,1356,1345,1323,1124,F,648,1317,710,744,1504,1366,1343,380,729,537,1242,F,546,252,661,F,1290,207,939,731,725,948,F,1228,237,8,F,60,828,632,510,1364,179,1511,79,468,1334,1209,485,9,684,484,1237,77,1381,1304,804,1379,F,23,1433,523,1237,1282,923,1341,1282,188,959,271,810,923,484,1207,F,1115,252,610,1421,F,F,1345,107,484,471,1485,601,782,526,949,224,F,505,1303,191,1358,269,146,F,742,465,1131,555,1496,428,142,1313,799,148,632,12,760,1110,1271,1327,455,216,959,610,342,280,706,605,1217,418,707,78,975,921,418,F,663,70,1177,1399,921,1390,728,874,1422,1273,357,F,921,F,672,F,1379,295,275,741,1235,968,1119,741,1198,F,745,1333,532,629,577,357,1370,221,F,610,665,932,18,64,1355,707,234,F,18,F,1258,342,226,521,1018,1190,962,295,920,483,483,455,502,1504,64,723,29,1237,1342,20,1366,426,280,1250,801,203,F,627,670,809,457,629,F,688,332,729,1367,F,526,F,F,1421,881,1342,F,485,56,F,992,464,316,363,572,507,1044,F,1110,1190,1369,1483,57,574,629,464,1315,839,F,1433,1243,1266,928,1336,712,F,F,1090,F,938,1476,588,1255,1349,175,1168,632,54,378,706,1282,335,665,1395,1444,159,704,661,F,1347,900,1444,491,674,812,1343,F,485,483,1416,1366,520,461,1447,593,651,1447,47,1413,523,228,642,1343,1430,1268,148,190,147,1324,605,270,663,120,F,1208,112,7,147,F,1351,1433,1194,F,1208,112,1333,1489,F,861,941,632,947,671,1325,1936,1121,1343,119,517,496,F,514,F,267,F,530,420,248,F,F,442,F,146,253,57,F,1316,670,F,1280,57,F,57,F,1266,1015,69,307,530,F,1441,44,295,1366,963,1390,728,1343,1112,F,F,F,1055,1145,761,514,60,998,110,96,261,F,F,F,F,246,1191,1142,1289,1114,285,1260,1157,F,1168,1055,493,1448,1235,1347,F,F,462,1451,923,1488,F,1282,1282,1399,1236,F,F,1142,1419,820,F,F,1283,1343,991,1090,114,1480,F,732,483,320,876,8,318,19,949,925,1277,328,353,938,362,1190,119,1362,359,48,344,161,1460,F,1456,F,423,815,532,150,114,564,645,629,1367,F,1365,415,629,446,375,556,1372,237,471,F,397,517,1512,344,945,43,135,188,706,417,1260,959,466,F,F,155,1030,1322,1446,237,1161,720,651,1085,1210,94,257,135,882,1340,524,485,598,1174,1372,F,91,920,1367,632,191,F,F,F,1365,483,1238,729,1131,1280,1301,148,959,471,F,1365,945,1107,1104,286,665,F,866,1147,1456,1346,1035,326,136,1370,1341,476,1039,1338,742,330,430,F,1347,104,801,20,333,632,124,121,694,256,449,672,F,1343,455,1410,449,295,480,1133,801,1268,627,1268,1325,661,720,509,482,F,59,1366,480,736,155,698,F,615,505,1238,1343,1306,1260,95,601,1346,1282,1381,683,651,930,480,491,1249,F,F,363,1457,257,1357,484,1327,1007,1258,1101,1367,1341,931,483,965,1173,1082,931,761,509,711,1346,352,907,1308,712,321,1318,356,1116,468,1367,385,391,657,295,1485,1486,57,1221,F,645,F,F,576,F,415,1344,1457,153,1267,1309,F,959,876,400,306,375,956,449,611,629,139,1029,1481,1249,913,561,1214,881,1161,1142,1480,65,384,449,F,75,1448,266,279,1453,790,649,908,484,741,1237,134,380,35,1005,1149,84,1488,584,610,420,1350,1448,505,1290,1280,1315,1054,930,1279,1124,412,730,474,885,F,471,768,1116,48,154,505,467,602,F,452,268,480,402,1332,104,400,493,492,234,511,696,471,732,94,690,888,1329,526,526,974,F,929,1049,F,521,480,1378,218,1306,1274,327,104,1338,F,241,F,947,470,1212,F,935,1258,800,1025,723,1204,602,426,190,1418,17,683,740,F,341,F,1280,78,471,952,1280,F,F,78,930,908,501,532,589,1116,524,295,814,929,257,257,F,920,1445,921,1475,569,1365,947,569,430,342,1792,247,1268,401,430,976,420,1191,78,1054,56,672,1338,799,167,1030,255,672,1195,633,555,1030,1440,168,634,712,F,929,542,1062,1274,57,1410,1347,1257,1055,1124,1023,1015,185,670,651,112,F,463,F,F,487,33,450,269,F,666,523,530,555,1136,684,1433,262,277,1460,1324,866,112,F,F,F,F,1342,F,F,1360,468,1333,1333,1447,375,922,370,F,F,448,730,342,449,397,1151,245,F,1323,F,F,965,F,125,745,1157,41,9,F,F,1267,F,F,F,712,607,775,44,137,F,324,1455,482,504,1090,F,590,257,672,F,F,F,F,1365,1145,122,704,742,463,1240,835,1859,446,514,78,1267,629,511,F,707,896,426,1327,F,1268,1124,1237,135,1257,511,109,431,328,1051,651,F,280,920,918,1375,78,F,471,257,1325,526,502,776,645,1365,1170,1172,1254,443,1275,428,F,1176,1442,F,464,344,F,F,1211,F,994,501,F,1286,F,F,1372,532,455,F,37,756,F,356,1237,F,717,1112,60,670,962,F,F,363,1433,687,860,998,959,645,375,1267,1260,1283,F,809,F,F,F,1280,645,341,214,1480,21,1512,1433,1502,430,483,F,491,F,F,F,1199,1266,1324,1154,1142,247,532,14,418,1275,524,1235,36,1473,814,1175,772,F,F,1361,731,F,F,772,1075,111,1322,370,153,1011,487,947,710,295,629,146,1392,430,505,804,F,394,446,352,F,48,1136,1252,485,1257,956,430,1245,1266,505,474,886,1069,154,1441,F,1419,1109,482,1117,1447,47,F,F,F,606,57,1309,874,224,920,1445,886,121,57,1124,480,433,517,171,496,791,383,321,F,F,1142,207,1257,237,1266,579,1433,1139,450,207,672,530,672,932,872,1441,F,629,101,921,F,F,1170,651,963,633,671,1088,376,1433,869,511,951,F,F,1488,916,510,64,493,402,F,70,1395,645,627,683,1071,731,493,246,44,F,633,F,1266,901,974,661,1504,F,532,1052,1311,F,1282,F,602,921,1341,838,627,1375,F,1342,145,485,422,1282,561,1483,429,1260,965,412,1261,412,399,119,1315,1301,1072,568,1261,1047,1364,1320,914,1496,1361,1486,633,1265,1264,1342,1047,1325,502,1257,147,1080,546,1346,432,1343,1257,1039,511,1338,1359,974,1336,674,588,1444,F,F,F,F,1279,F,207,1297,12,1299,94,172,1411,F,1343,885,569,1073,605,1209,1257,651,920,1250,634,272,720,603,1237,277,577,6,1385,471,1343,753,1486,331,882,F,57,629,670,684,412,1440,350,974,1438,563,142,521,532,485,651,F,1061,974,1015,186,794,1445,665,869,F,F,41,998,1265,620,1274,341,963,F,1044,1447,1150,999,1121,1351,710,772,60,F,1094,1154,420,1037,998,F,242,916,499,1151,F,978,818,645,257,257,1452,F,688,228,1257,400,483,801,1343,805,1366,1233,407,1400,2227,207,1266,501,1195,344,876,F,1359,958,1317,85,60,1080,1411,1343,60,F,280,601,1340,118,150,1309,1304,1365,1297,F,F,F,1142,404,F,F,F,663,1282,135,492,1149,888,1389,1307,1266,810,F,F,F,F,F,730,485,829,449,454,1233,1360,1399,57,729,934,1282,318,1343,1149,620,1357,F,511,1067,1347,484,F,1124,F,F,772,1233,733,1124,1343,1049,1877,483,684,1359,712,1412,1131,1448,60,627,F,F,F,F,F,F,F,941,394,1257,1441,1355,814,537,1282,1333,1282,1445,792,F,1110,662,137,730,974,226,1054,F,F,F,1124,1282,78,262,1496,F,1090,1065,420,1149,360,F,F,F,768,730,1315,720,493,206,532,431,327,1078,134,1072,843,905,704,F,F,F,F,1343,155,F,569,39,618,555,1047,57,1129,363,888,1343,1266,801,1350,1459,183,333,1304,895,1254,640,98,1494,1509,60,1334,461,860,1297,F,619,953,1277,499,405,F,F,1323,1311,1457,437,1350,1257,F,F,632,1267,461,1344,F,634,1054,98,44,492,1090,318,252,F,24,1365,F,F,779,619,1343,224,F,120,130,353,511,791,F,F,F,641,753,1361,F,658,418,F,1358,511,147,992,825,F,F,444,1175,F,5,385,63,78,956,F,1090,180,470,130,344,468,295,1238,329,1150,F,678,431,1357,470,F,1365,1489,1325,959,1430,517,1145,F,F,F,F,F,F,1210,648,517,1412,1288,452,F,1304,F,F,F,207,952,753,F,1395,52,145,F,F,1325,363,753,44,529,257,1430,F,1253,1325,F,532,1265,1145,419,F,251,485,505,552,1500,F,1282,600,316,480,1260,814,728,978,204,1345,730,F,532,962,1190,1230,1432,628,1061,565,1265,1433,1445,1178,1087,44,1338,892,122,452,560,518,9,183,930,44,21,329,553,1216,1216,1510,651,F,400,1322,1080,447,645,1044,1112,F,44,992,272,78,635,931,327,485,1489,468,430,627,1370,1375,1297,121,246,618,510,151,1112,917,1333,495,460,463,1157,1007,1246,1350,985,1345,1080,1345,532,1212,1307,486,1450,945,1460,253,F,1362,F,F,1341,1079,1343,753,F,F,974,1274,1253,371,1351,1186,1079,799,371,1142,1248,524,121,895,1480,449,783,1323,379,1366,446,F,1110,145,452,1329,1245,1268,901,998,665,611,1052,1274,1399,415,316,419,119,1422,F,1141,1460,825,420,1365,1480,1362,629,480,1257,1265,120,632,F,1110,521,916,959,375,1485,1365,F,F,994,823,715,21,519,F,1304,905,60,712,F,F,F,F,1341,1365,F,905,962,40,471,F,F,F,85,706,596,1199,1253,629,651,F,485,537,1492,286,532,224,44,F,F,F,F,F,596,70,1129,1199,1310,F,F,F,F,F,287,F,F,1131,57,1198,1039,96,1199,732,509,687,F,F,1422,56,483,1403,1306,629,F,F,1131,1355,1089,F,F,291,F,F,F,F,956,682,1441,1212,381,1366,619,78,789,890,634,1155,632,1240,229,629,1186,1221,1456,356,1289,16,318,884,78,1216,1087,1341,78,945,1195,375,1190,418,142,505,463,1291,252,115,267,886,1011,645,1075,851,248,1365,171,998,546,1154,192,894,176,672,1190,1444,629,1023,861,1110,F,229,450,1343,1268,1282,682,658,F,1150,355,F,1150,F,F,F,1360,774,F,357,537,148,378,1229,629,656,645,1262,42,11,1367,524,1002,1315,1483,214,96,1408,1355,344,1011,F,1258,1090,501,501,1301,1425,F,F,1104,137,317,483,1445,F,384,1255,F,945,1090,412,1216,272,1485,978,761,344,651,483,1297,1308,770,1317,507,629,218,1005,1369,687,1071,628,637,372,1286,1279,948,945,135,1360,85,581,974,8,946,934,161,876,592,1340,389,1075,889,F,1456,465,468,450,44,F,F,1410,485,383,1257,360,116,696,1460,1209,1475,1265,605,F,F,F,1448,8,1267,404,1258,F,1209,95,1037,1265,507,331,976,F,1334,234,487,619,1323,670,1208,1350,888,684,629,68,F,712,27,467,F,1334,429,184,430,820,155,629,1155,461,57,21,135,1329,218,18,1311,F,1483,217,979,35,214,1229,1142,39,353,1323,1257,1460,1319,318,1362,9,1208,723,1067,511,383,485,939,501,1323,1260,545,732,1309,1052,1250,929,463,992,1441,1172,532,1280,923,14,717,383,F,1157,316,526,122,1107,57,712,1109,383,443,456,684,423,487,968,350,464,84,383,332,761,8,1351,1107,637,505,148,505,154,906,136,1323,273,248,F,637,1268,63,1291,724,1338,730,923,923,1250,1282,1367,928,142,1333,1349,1327,483,1488,1306,741,664,541,1333,1324,1133,401,467,1350,1075,112,632,F,1307,171,135,801,965,735,480,1365,1421,450,109,63,930,1257,496,566,698,707,1421,59,482,532,778,57,1080,1109,753,645,740,753,1257,120,963,502,480,1265,1304,823,1190,446,1367,F,150,78,1500,253,1479,482,928,F,1291,471,1078,569,F,278,1332,1365,37,505,1435,486,1079,247,266,188,344,741,1438,1440,F,1325,940,422,375,938,690,391,596,1015,312,215,1323,374,505,389,405,1136,1249,1444,814,255,599,1142,207,1317,402,F,F,245,F,731,504,741,525,1365,1438,1430,1413,1288,F,31,430,380,442,610,1255,77,561,672,201,632,1340,941,1088,F,1309,509,946,470,1124,175,281,661,F,782,1150,227,1237,354,218,629,86,1268,862,596,1457,809,457,1350,1268,603,751,24,F,400,56,341,480,1343,658,1326,1344,530,478,142,270,291,F,1324,1475,1413,922,1365,970,478,814,465,525,1067,F,F,882,744,101,665,627,174,F,205,1055,226,921,F,600,651,641,994,1365,1343,254,922,1341,803,341,492,1318,319,1015,1265,F,F,57,1078,910,803,1448,1158,1174,1174,1007,1343,645,12,430,956,629,399,1486,1124,1372,1320,112,1229,528,1187,1361,471,492,1013,706,525,1483,523,9,1131,598,476,969,123,287,563,786,95,1168,1303,521,465,923,505,710,1322,F,1447,1208,F,8,866,99,1154,303,476,921,159,1136,1480,830,1199,1069,666,63,627,702,1283,107,1851,716,359,632,235,1421,1483,F,F,1448,24,207,958,F,661,1265,327,405,142,521,295,150,1375,442,1333,1317,1332,1190,1413,1362,1313,1333,556,463,605,1375,137,F,F,1072,F,818,744,441,174,1378,1306,511,1480,1364,1149,546,956,F,142,518,1313,1442,150,861,941,F,963,601,1343,998,1110,929,1112,F,311,F,723,456,F,F,415,941,161,974,280,629,24,507,1301,684,F,1378,1450,452,1346,F,1447,931,F,353,493,1460,1460,571,814,1015,1399,15,1448,376,1343,145,482,1457,611,992,998,1442,768,1595,F,F,1341,532,67,142,537,270,1237,1343,1433,1323,F,1028,678,905,1429,418,1367,224,1430,325,1047,651,1142,962,706,1338,39,401,387,775,363,F,1078,553,1136,188,1456,1145,577,949,1301,273,104,462,191,1026,1444,930,509,1488,1237,F,F,1260,768,916,1118,526,1438,1060,1157,31,1142,932,1245,998,684,450,1119,1450,916,740,509,1060,730,1090,651,619,496,623,1448,254,F,1029,381,318,717,1131,492,1154,1282,569,1253,316,684,93,109,651,1340,192,1377,723,1367,1448,1342,227,480,1236,1150,1039,1282,1119,1116,929,656,1343,F,620,627,327,645,650,1268,1277,849,728,1265,982,2282,1099,1323,60,651,442,921,642,32,57,450,450,F,104,876,956,744,521,85,715,1029,1238,F,F,627,969,F,474,1272,F,1080,1350,F,775,465,521,1322,F,1023,1204,1282,1433,719,1260,702,F,465,1150,1277,1338,57,682,316,629,206,163,235,246,583,159,1265,122,728,932,959,1441,F,F,F,452,353,147,391,761,78,456,372,1332,712,1233,F,F,F,1005,1311,1442,741,496,F,1422,1510,1375,645,F,1456,57,992,1367,F,186,307,1343,729,953,F,1233,485,84,F,66,316,1375,627,316,963,343,306,290,1442,1172,1367,511,922,524,598,134,43,824,1343,1303,756,1315,342,F,815,1185,51,1412,1235,553,1331,852,1274,372,1154,401,471,1139,F,1332,217,923,510,690,730,730,491,672,316,849,728,505,343,69,467,1121,1332,814,509,633,78,491,1169,651,1500,1077,1346,235,155,86,740,1324,602,151,499,1099,967,814,684,F,1077,684,1458,F,1635,530,517,307,1343,1015,91,990,342,601,1131,1365,1360,251,651,1460,1142,905,1417,499,179,85,568,212,F,419,419,757,452,374,245,342,1309,728,715,607,384,1430,1142,1367,1490,627,672,1253,618,484,629,627,F,907,1328,1222,1216,881,F,1430,400,F,1302,770,974,1237,1442,265,1237,78,F,463,1309,1387,629,1324,465,1313,450,40,979,1274,910,642,1453,1343,1304,684,552,161,F,768,418,130,672,1421,1142,341,441,1407,939,1124,900,394,F,473,159,F,306,306,1011,922,1112,524,F,1324,37,1338,1483,786,177,24,100,1170,420,1510,24,1433,1222,371,57,308,1460,1255,1241,1448,1456,672,1241,402,959,596,1385,1119,731,247,923,101,896,632,1077,661,1124,923,1372,333,1301,532,248,892,393,823,992,1441,352,888,1151,130,184,429,1466,753,67,923,1080,56,532,1112,F,385,768,470,1257,308,1297,756,F,1258,1448,1018,524,1713,1435,1063,724,57,420,1365,1268,865,792,94,85,F,922,485,1480,672,537,1268,1257,91,1242,1446,1486,588,191,1174,161,245,184,958,1433,1410,1002,71,483,1257,1457,532,363,482,217,136,1137,1020,1272,466,921,1460,1133,115,1246,553,552,383,350,318,191,101,1448,121,618,1297,1418,1433,1365,401,374,1387,226,480,1118,1152,381,1260,702,285,20,888,1255,7,723,888,723,1328,1448,78,1350,1236,983,601,1322,121,968,1440,916,F,1145,327,1089,F,231,214,1149,1173,147,1142,488,1107,465,641,F,F,136,511,295,378,342,287,F,295,49,1157,248,F,248,85,1229,1430,678,921,761,932,F,1488,1118,F,1360,1453,1142,F,1124,85,1258,496,103,344,1165,135,344,327,1254,1257,1328,746,867,707,1042,723,102,1177,541,29,1276,1286,923,F,F,1062,463,822,1275,137,226,332,1346,14,979,996,702,318,962,1079,430,60,224,753,243,F,F,580,F,114,286,1361,428,F,385,1313,620,510,922,962,1233,1340,640,F,F,1323,1343,1345,921,1433,1260,1343,1336,1253,1445,1448,419,186,341,183,905,576,155,F,1217,956,191,359,573,962,962,1445,725,629,1453,1142,1445,384,636,449,596,251,190,1349,F,F,921,1480,114,712,272,1344,116,1015,434,1011,341,600,1291,491,1343,716,F,706,485,1134,420,F,629,1485,1497,1332,362,627,922,376,629,74,1134,F,F,1124,157,491,1280,44,1303,514,916,651,1271,1512,255,183,952,774,1415,688,1341,1062,1365,455,627,853,F,F,627,F,F,1100,F,1343,820,1110,572,344,1340,242,978,104,F,1168,947,64,1131,1236,281,1039,8,923,1507,579,326,F,1347,F,1036,270,471,1282,1399,1150,1154,1448,1343,342,291,F,537,486,112,1266,704,F,57,651,505,587,487,F,141,607,1288,327,686,1412,430,482,800,1350,1277,1165,611,1399,585,F,930,524,894,316,1194,649,728,1480,1280,452,728,504,1382,192,629,978,1458,1345,420,F,1343,674,1375,978,651,808,1367,844,F,1343,844,1343,930,1262,1643,1345,730,1257,768,544,1497,1262,1323,1199,1167,1252,1136,1345,151,1455,707,1370,843,735,1397,1229,627,962,768,1078,57,1483,37,F,530,1272,585,860,580,1315,1413,1332,588,468,1257,291,1327,1177,1360,532,627,F,627,141,485,451,1422,343,124,393,524,723,123,1152,756,1288,628,1322,1118,1077,1343,76,191,446,1229,248,482,363,418,78,1297,656,94,94,1343,1307,1323,1397,420,1356,1488,F,546,1365,920,1435,694,F,F,1100,511,394,916,650,F,1186,495,596,1343,1355,190,1324,505,1314,1238,1267,818,344,363,F,753,1460,770,1267,1241,629,94,740,491,801,118,1227,1304,843,700,1502,545,538,423,F,F,1367,1238,1458,F,F,1343,F,253,342,56,1459,1485,1090,1262,799,F,501,1315,150,830,998,1448,2117,F,1052,1367,F,509,F,672,417,66,1343,1504,1422,1367,1229,799,393,532,51,94,F,287,923,1331,587,1010,446,1315,1282,F,587,F,1280,78,556,205,1304,28,849,1489,F,1170,160,146,809,394,333,1282,242,1236,532,587,1409,1023,286,651,723,F,406,723,661,F,1352,F,394,201,627,272,F,63,929,1257,F,237,237,1268,632,F,1063,1280,1077,1238,F,F,431,1360,672,600,1351,1075,530,923,492,1379,F,923,F,650,483,698,175,804,70,629,651,356,142,1306,1267,450,54,1496,223,223,476,1025,135,1168,F,840,1448,656,327,501,392,1258,648,1306,994,1159,887,1286,1055,1448,1222,1195,1172,1340,1282,887,1332,1332,843,428,2047,1347,317,774,1331,1230,1371,1262,1456,1371,1080,733,1257,485,1158,327,1313,799,188,730,60,F,766,1367,295,1447,990,1304,690,471,1316,782,420,487,270,456,1198,904,201,1257,1117,728,1286,1255,956,1423,1158,1291,521,526,476,1168,702,1325,482,1085,619,1229,135,96,507,1362,470,1412,1124,363,783,1260,F,278,146,1236,1433,402,128,145,1395,471,674,640,610,1215,468,1255,20,1067,1129,700,1152,1286,513,10,1142,120,1238,1205,485,137,135,1367,1267,1283,F,F,F,783,F,1325,959,495,1116,537,640,524,F,703,645,F,161,147,1271,946,716,1090,117,147,382,1365,1344,F,656,610,1090,1433,1099,468,F,F,295,F,1047,1490,532,537,1203,665,1236,150,1460,645,F,1433,1429,F,1343,158,799,77,1125,1342,428,1320,461,704,704,963,611,426,1450,741,1266,1441,1089,1510,1460,380,1309,1343,1448,1280,511,94,F,85,635,1445,485,1229,394,523,514,8,344,401,448,1325,1011,1448,65,1341,1136,257,401,1076,1316,139,1065,951,F,F,184,442,267,141,1237,1005,1089,89,483,1382,921,1322,344,1367,342,910,1445,1151,1510,134,963,1362,430,448,400,295,497,1378,1196,209,1198,344,1510,450,F,78,1427,533,1153,532,344,465,182,1357,180,1136,146,929,89,1618,702,93,923,493,57,482,1446,1458,963,1421,483,58,F,629,629,1375,968,141,344,114,1156,1080,424,945,921,78,768,1196,159,201,1375,1445,137,161,57,723,22,1173,741,644,334,141,961,1177,342,592,492,F,F,F,1441,959,1512,148,585,645,44,274,1254,F,1480,672,1153,F,159,636,1174,589,124,532,1199,463,327,57,F,1261,1250,485,965,586,450,958,1131,89,F,961,888,866,1222,1332,998,1314,201,257,148,214,725,1308,286,67,1435,159,122,401,277,1494,244,598,1165,1375,968,1458,651,136,1440,344,1067,1178,573,6,F,956,1090,413,1054,1226,1421,662,518,629,F,182,998,1375,532,548,317,921,442,342,670,442,1216,741,1173,530,922,1443,953,375,1173,605,712,1347,672,1369,524,888,F,1280,60,473,1457,998,1033,1252,114,561,1055,882,704,1297,F,191,561,1468,120,1110,150,1131,44,542,F,1448,1238,741,651,1499,814,651,923,1375,F,1343,1259,137,F,999,137,825,1360,485,78,321,F,F,199,247,499,1365,430,1304,1367,962,F,29,371,517,F,1320,1090,1360,1117,1338,99,1332,1090,1323,1096,642,191,1367,78,1129,F,1325,620,648,1167,272,1375,484,F,1377,F,F,523,525,158,137,374,1273,1267,9,400,1366,619,F,1199,137,1289,959,424,F,230,91,247,1324,1483,F,1349,120,F,627,1136,695,695,F,1154,874,665,920,214,1199,295,94,505,1341,483,230,532,56,620,1343,183,183,910,627,1385,1147,910,199,526,1304,318,F,1304,308,478,1457,979,311,528,417,635,1445,730,1364,F,107,715,1344,732,1199,585,F,F,730,998,1367,934,728,524,887,510,1234,485,723,493,1313,41,354,121,629,628,959,283,1352,1378,137,1447,979,F,690,540,400,1375,471,888,112,286,120,1044,1080,1067,1289,1350,1080,148,1338,418,327,1338,1324,1501,1118,509,287,1268,392,1157,945,121,418,725,1375,201,932,510,1055,760,1369,F,881,1444,1448,183,1366,756,1230,497,920,1124,898,1173,579,201,1131,F,504,493,18,501,1338,F,1338,661,1396,39,632,F,461,688,1236,1267,1183,78,1444,1458,42,723,1281,849,1359,F,1276,629,1411,728,F,1336,F,F,907,1281,F,F,F,1055,1479,F,1055,532,485,504,F,809,18,161,1255,393,1282,1187,1313,231,1419,1151,881,1280,1304,1268,1112,575,1444,1253,477,1018,1242,271,461,592,344,169,1266,947,943,601,F,1320,1349,974,422,184,1448,F,1237,1323,1273,1343,800,1444,169,F,1078,261,1485,532,1304,1370,F,F,1303,235,1173,365,1341,442,1340,F,628,569,1267,1128,1260,1309,F,F,245,598,1453,805,142,493,57,1469,651,428,37,1154,145,696,1268,1098,1355,962,F,916,471,1236,1342,1338,F,135,426,70,F,1268,120,476,F,420,188,1445,921,585,1002,F,1349,1294,F,450,206,F,974,246,1255,958,F,1325,640,55,F,70,F,1369,844,39,1351,442,188,928,1168,1367,618,39,F,485,342,1268,107,449,1039,49,950,1366,1219,7,418,228,363,246,480,865,F,1473,651,698,700,632,1274,1312,1441,910,344,828,1260,280,228,1378,1268,1346,1087,280,52,452,326,327,1382,44,324,1265,1080,2023,1421,1421,1419,471,343,1229,752,939,641,F,740,449,442,1365,921,287,14,F,24,248,1309,248,57,1456,870,1182,1341,F,487,1448,1199,1280,228,1177,1282,126,1371,393,636,51,F,672,483,1309,1090,271,1118,450,1379,121,35,998,291,1245,24,333,1364,1435,329,393,84,363,281,465,272,1169,78,932,645,661,1238,1422,601,1128,768,57,1216,1460,256,84,524,910,1262,1237,910,217,315,119,452,1382,F,F,F,F,F,316,1282,442,146,39,1345,F,512,78,1011,156,1349,1340,351,588,1378,1440,1319,523,448,741,26,363,60,1479,428,1440,1075,369,57,286,183,165,1030,142,978,138,712,874,1235,887,343,1479,920,648,1340,719,1253,923,257,138,1262,430,1033,385,446,17,343,938,476,885,1323,1110,1258,744,586,363,F,20,1029,1100,665,1440,471,120,F,650,766,420,272,511,731,316,295,128,442,442,1367,1313,869,54,128,1361,1341,1313,1023,1304,629,629,1372,280,480,1047,623,912,449,405,85,1013,1238,1118,12,1365,1270,437,1327,1274,1332,F,57,F,437,1158,656,F,1460,F,1260,1390,1343,270,1370,521,F,78,1168,1351,F,1341,803,1063,48,378,32,753,350,304,1066,F,1448,1329,493,1372,1104,1168,1237,1316,1448,1343,991,1080,450,786,1335,493,1130,1351,40,450,450,1338,F,1329,632,1257,304,278,1389,1460,1351,1351,510,175,228,F,577,1343,1338,492,304,818,188,931,1313,77,729,1104,751,635,922,922,1084,342,78,52,67,1342,1238,461,316,921,710,344,14,15,1758,921,976,1216,1343,78,890,22,F,1307,F,F,1367,146,670,1341,629,F,809,1260,1253,F,620,915,1479,1502,1480,123,12,306,1367,625,344,1411,474,184,1118,54,57,1411,F,430,629,F,420,1385,382,142,665,753,730,700,20,1433,1458,465,316,244,1190,F,272,1250,1238,485,148,648,63,661,492,814,682,1069,F,385,814,1343,569,1228,1219,928,78,550,651,355,385,413,580,437,584,1399,1185,605,57,463,910,1333,1230,1170,1130,573,280,19,493,751,587,577,12,696,952,938,F,551,428,286,1265,770,1134,507,885,853,1114,124,814,702,1114,190,1265,592,F,246,913,1177,1496,1249,327,92,882,1025,F,1002,921,214,862,78,702,1489,190,587,485,601,F,723,731,862,670,1500,518,656,1342,1241,629,629,1409,1459,474,1070,148,1291,1233,265,480,887,449,717,134,717,128,524,830,F,1343,1005,651,1320,F,923,1483,F,37,374,1399,507,1365,947,44,21,1216,1326,939,1361,1448,507,753,1074,1055,921,1055,729,375,1341,373,373,1195,341,1311,1338,1186,1177,756,656,93,627,1087,672,480,216,858,656,526,1422,524,1442,1496,1268,1448,F,F,596,F,F,596,1301,373,291,759,1452,1168,1370,1419,373,1245,605,1365,1118,1412,413,455,1421,123,665,122,1448,1238,1307,1397,741,399,1121,F,F,1110,287,107,579,1165,789,610,671,1341,1282,1324,951,915,155,1266,393,505,600,723,1338,F,629,1347,182,958,1165,1365,F,F,224,274,442,F,1260,F,921,F,1370,483,1379,321,374,423,1441,974,F,F,507,886,355,1307,1073,1078,940,188,257,78,253,1229,188,1447,27,1254,40,1686,22,1190,F,374,521,401,190,1361,1370,611,524,342,814,291,291,1290,545,1325,1199,914,51,744,1104,1325,921,1370,66,F,1306,446,465,1331,530,585,295,483,753,151,40,1255,1441,1304,1142,148,1257,192,694,568,1325,94,F,431,242,978,1190,1367,1273,782,1107,329,916,651,19,461,1341,461,723,1349,619,1325,40,257,651,1077,499,645,512,524,1167,888,357,1274,1224,169,246,461,1333,629,728,449,1073,486,1345,1235,F,894,672,651,1343,90,1055,449,1088,1215,753,455,1184,75,882,474,341,406,85,627,122,886,214,723,1136,937,1446,583,55,18,723,1268,572,1194,1203,1237,1265,F,1203,611,121,801,801,627,266,526,932,78,1051,1410,1159,932,826,1343,514,354,246,492,717,218,492,1367,1282,1386,706,627,403,1314,1142,1435,1329,1205,1056,1260,938,1238,1352,163,963,1219,F,1445,1187,1801,1056,88,342,1184,1142,1142,1480,418,905,430,1473,1456,78,656,843,1257,872,248,429,1170,1226,1170,921,485,146,24,511,1943,629,526,964,596,383,928,921,1268,492,1078,491,6,455,1410,1399,1333,1419,485,114,1325,491,F,1324,F,499,1190,774,1375,F,146,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2236,1718,551,2076,1058,1264,419,2290,1425,1028,2291,1264,482,2292,2248,732,352,156,156,1466,2293,885,1080,1080,958,75,1338,192,265,1110,142,264,958,636,264,1361,636,1323,76,2117,402,518,1789,1317,935,2277,484,507,332,394,173,121,648,1480,1459,21,1228,226,2294,1459,513,2295,525,898,342,1341,1343,771,1254,520,520,1215,2296,1343,1340,1445,2198,1413,449,311,1965,1345,904,865,939,451,389,142,1629,1342,1346,1321,2025,520,922,1337,1258,1270,352,520,1264,452,1087,269,1079,482,778,486,526,1078,711,449,699,676,1483,1006,1311,1324,343,1044,767,353,1136,1365,208,1434,1774,1448,399,353,676,649,1343,532,1966,697,2297,1442,1080,1080,308,161,1365,584,1365,1377,452,921,1254,513,1112,1131,370,370,1320,1279,1320,921,1320,1876,1194,1232,549,220,499,415,1343,121,436,760,1339,1272,512,1186,637,1272,512,1338,2097,78,1362,1282,1684,632,286,2015,990,990,483,483,1232,1343,2138,990,615,261,1400,1910,2075,1638,21,1424,509,507,74,994,1665,337,1029,678,74,97,2298,1080,1139,1425,344,1265,1265,1215,442,1190,992,929,354,1784,78,224,2299,1342,131,124,1021,124,1341,761,2300,992,317,131,1328,930,1452,887,1250,1254,493,1886,1333,332,97,2301,1232,331,245,321,1450,922,876,1365,255,283,1255,1343,1285,549,1340,483,8,1254,485,342,311,1297,511,885,227,341,1155,1452,1359,479,1863,1366,207,1377,1029,1237,1653,132,1318,2302,1056,123,678,1593,1316,1286,1237,1460,1401,1266,832,2303,1798,800,2304,1282,33,1304,651,1456,1069,962,1663,49,2145,2305,885,1343,1112,1342,1442,1707,417,700,228,1460,85,962,56,1427,189,1238,245,1460,1511,1362,1328,2306,1422,430,57,1216,1065,1365,1343,2307,2308,378,820,1190,800,1265,963,2244,1221,929,1081,539,38,876,469,431,611,1271,363,1327,28,312,745,486,1745,76,483,434,478,400,968,1176,501,190,1343,1079,1289,1069,1215,544,1446,350,598,1341,147,574,395,629,1344,1080,729,1457,1304,1362,9,672,756,306,678,1723,114,145,1316,1793,1453,1340,1005,210,1262,1112,224,687,1143,1901,1438,1598,939,577,116,820,825,510,1254,1841,517,143,1441,1512,156,946,687,524,1090,1187,1072,2180,78,774,1274,1569,1209,1366,1260,201,291,959,1302,397,572,1254,537,1343,343,635,1497,941,629,1357,476,514,932,1030,875,1122,342,1257,628,1768,904,40,2309,2081,1262,1286,1297,1366,248,132,155,1448,1324,2310,629,598,1110,492,1297,343,480,2311,1277,858,493,65,1646,327,335,1320,11,44,1367,1283,1564,451,123,1445,76,521,1332,207,2310,1229,598,97,1490,2312,392,44,1174,1087,1087,721,2313,1150,1927,179,1291,882,2314,448,1342,920,1170,355,1915,507,1128,126,505,321,1446,1945,530,1488,526,932,799,678,1479,1248,683,1114,625,476,265,1486,48,1254,526,771,91,492,1418,1335,1446,1047,950,820,1349,141,930,1324,1011,1452,184,1884,1878,1237,1366,76,1020,1168,1235,889,1325,332,1155,1250,295,1280,134,1075,544,248,1512,111,1186,44,1282,465,1333,1422,1640,1322,1360,493,1303,1410,187,344,54,1448,1489,732,483,1342,1282,1315,1586,275,104,1438,851,1193,1193,44,2254,1993,505,1237,331,124,1812,1119,1448,1124,1261,344,1372,999,629,833,1379,496,696,37,250,1154,428,505,1257,1055,932,532,1593,161,1029,44,1277,1357,1332,1152,1134,1328,311,76,486,223,1386,1155,383,70,160,838,93,619,205,1355,1395,1489,49,1116,20,1653,1366,1418,1496,1056,176,514,148,1046,420,1423,950,1325,248,1282,1993,44,896,1910,634,672,702,929,1265,1152,1350,267,1469,1273,1055,939,517,1209,1507,916,1257,610,125,395,640,920,141,120,1237,482,77,471,172,1183,228,1901,521,1042,331,1268,524,295,499,493,2175,650,78,384,1265,1124,1268,495,742,1338,511,1887,941,888,332,1456,8,1027,1341,537,825,120,1343,231,513,1306,577,492,161,226,501,1046,1386,96,70,10,1005,1145,155,116,601,800,510,932,723,1254,818,956,800,124,645,619,687,582,41,1367,63,1389,1448,1112,1359,426,953,139,629,1165,1237,662,160,120,982,1087,471,629,682,1389,838,1155,1324,618,780,1743,2315,1378,1387,1370,1293,150,1430,1293,1265,395,280,556,280,732,1201,124,306,280,1744,509,1201,1112,1324,1324,1079,F,230,929,268,328,710,1069,268,F,512,628,466,1007,1232,790,968,636,1365,1531,374,1987,1257,421,601,376,1172,392,2316,74,2317,526,251,2318,328,1328,491,1085,485,1343,485,121,516,712,979,790,1370,711,356,979,104,516,104,1386,387,517,712,1456,2013,380,1303,732,730,999,1345,2213,544,535,825,1341,728,1080,1809,723,1451,526,1369,744,568,648,344,1281,730,74,265,1147,356,1761,74,452,1633,532,452,583,1337,624,864,342,742,267,1267,645,928,491,514,1118,716,1199,920,384,1477,1114,514,1977,953,253,651,267,355,492,1344,197,6,629,177,745,1477,205,1110,285,511,649,649,818,1257,272,2319,316,316,316,335,523,1643,1442,332,761,1447,342,332,905,332,541,465,541,353,244,905,931,1293,577,1198,1528,158,482,231,418,418,2258,232,253,232,992,992,174,1757,2320,1343,482,543,932,211,159,1242,482,227,1289,2321,1228,532,627,1375,645,656,1399,356,2322,342,158,965,253,1052,742,651,1450,864,67,505,505,2323,629,1052,1575,1610,513,385,371,235,177,584,569,287,308,1448,2150,2103,2324,2325,556,505,401,190,401,541,287,485,1170,513,665,683,1399,1369,216,2326,1942,1952,930,1044,177,387,493,216,627,1167,327,2327,1610,921,177,1486,356,1227,77,482,284,951,2127,271,493,485,2328,1322,526,477,1076,492,285,275,1252,387,344,1076,493,362,218,541,2322,170,121,1204,672,627,883,1052,2071,566,2329,385,938,532,2321,2262,1480,632,526,2067,656,401,501,401,493,493,1153,477,485,493,1343,493,1448,120,492,751,627,1458,629,1320,970,33,374,486,1255,700,645,511,561,1280,1447,267,1460,832,505,963,1063,1343,1457,753,629,1912,610,610,530,567,1327,1219,1277,756,580,505,645,430,1080,556,1912,361,78,742,148,605,1357,1357,732,556,1314,530,951,672,85,724,148,1964,542,732,267,1304,1304,543,1255,1343,1314,1246,1076,610,761,672,896,1080,482,947,497,1617,970,1273,1343,532,315,528,1190,526,226,1280,700,1314,1314,688,629,135,982,970,38,1061,1377,518,41,1796,1255,1377,1241,1293,352,352,38,191,1343,1293,881,523,1158,363,916,295,870,342,374,218,521,374,56,2330,43,783,2331,319,521,1341,1381,497,549,497,580,449,1262,962,316,400,945,1392,580,326,449,1366,400,2332,471,226,401,632,632,1126,272,521,532,1260,887,2333,1340,553,1324,59,801,2333,1078,1316,929,805,1023,1496,1073,1254,471,33,1080,1260,1230,1850,1280,1230,42,2334,2285,1280,2335,700,2336,226,483,78,1096,1581,583,60,84,2337,1934,670,1361,671,1257,388,1250,1282,505,505,1238,14,956,1445,711,1347,1235,1063,483,974,675,147,1923,1282,1304,510,974,1255,483,295,950,1257,1028,2338,1237,295,1185,629,1433,419,629,1319,2223,1325,1067,247,1412,866,1318,945,1318,1448,1597,2339,1168,627,1067,448,1185,1502,216,327,1370,104,1370,1270,1324,629,532,2123,250,159,521,510,18,400,1325,1110,629,125,601,629,1323,1324,1370,1110,374,648,1002,965,965,307,619,271,1268,1466,1028,1588,1588,93,93,8,224,1362,1602,483,1361,1099,317,1082,391,22,1746,1020,1080,1087,1480,964,1085,60,1304,1885,864,1119,483,1238,1119,257,1015,192,567,383,2340,653,387,1690,568,2341,502,2342,21,261,1941,2343,148,1079,1362,959,909,2344,2345,1110,1152,147,615,253,482,641,441,738,2217,706,145,2312,1306,1331,1485,2346,483,255,1668,2174,744,448,629,2178,1273,2263,2347,1337,687,2348,2349,851,477,1340,535,156,650,1211,1345,327,1565,949,949,507,85,1763,1532,281,328,291,418,1185,2350,1106,922,442,2276,1346,1253,1253,2351,768,1313,1257,178,268,1242,447,441,1253,361,2352,537,687,1741,363,715,2353,2354,142,1253,361,341,502,441,1631,1073,2035,1211,2355,1343,1679,2356,629,2357,2250,404,1244,2092,1252,295,1077,529,879,1242,2358,2002,653,978,1359,247,1453,1080,1456,1184,1260,1343,923,905,1485,1799,1660,1238,1303,1515,2040,1770,873,1343,1274,1069,449,746,218,965,1920,418,1381,1215,284,914,870,2359,342,1326,430,2255,1832,412,521,1357,344,217,1456,1221,1933,381,2360,1513,85,661,265,818,1143,1110,1268,480,923,308,295,395,1413,1260,1341,2361,1483,738,2362,1447,1333,482,1456,1783,1090,2363,1277,1823,468,574,458,1158,1266,295,2364,1297,404,2365,611,1340,5,902,1071,1190,1838,1293,284,2366,1820,1384,1362,259,860,1272,5,368,580,2367,1672,1274,57,1866,803,1849,1292,577,286,328,485,825,755,2243,428,1370,663,914,706,362,2368,145,1063,2369,2034,1496,430,569,2209,1268,610,77,1433,1410,637,21,738,645,1128,342,84,417,1836,372,1108,364,1362,1325,381,383,45,418,1134,183,1343,1521,487,1198,1266,1229,629,1257,1154,1512,959,132,2370,1398,1319,268,922,246,949,2006,753,1795,269,965,1958,1978,1136,1398,463,608,1044,482,1497,1248,334,1913,1844,923,1085,2189,1091,126,306,629,937,11,1399,1353,805,1364,1174,600,1047,1257,1218,449,6,2268,829,559,1480,1480,1056,248,1835,648,1514,91,1270,1211,1254,1243,207,1047,383,922,922,1158,228,228,1338,1485,56,207,1650,430,2367,922,1433,324,1978,1266,886,2371,2372,1399,1349,388,853,1434,1039,1469,814,404,1998,1323,245,968,1611,2373,263,604,1277,524,1154,148,1168,10,518,228,538,2245,1238,774,1055,1367,1433,2374,504,446,419,1715,1453,116,1224,2053,1367,1344,2254,1331,2055,732,450,1378,172,471,463,463,1259,1830,482,587,1451,1236,1047,1303,465,285,814,1306,637,1367,2117,145,939,1325,2335,880,93,627,1353,2375,1235,734,1350,2064,84,585,1258,1367,504,1991,572,1398,452,1168,1332,431,1517,1300,2092,1039,1355,1124,443,1280,1525,1134,697,111,415,1939,1673,1033,136,1007,1118,1219,482,867,1252,931,1080,363,1483,1905,612,1245,1222,1112,145,426,2155,F,1822,1136,947,814,429,1446,1027,2031,364,766,2376,1519,934,1189,57,18,18,632,1502,2271,753,1120,1119,1151,2377,920,502,150,499,541,1152,1052,101,486,6,1277,896,1991,1769,1802,1274,449,471,404,851,1265,1399,124,2378,908,1692,697,696,450,621,271,346,1153,1337,49,1349,1025,502,730,1277,1849,699,978,1651,881,1958,1277,482,1459,1620,587,1503,1274,1110,426,1767,640,939,1257,161,1611,228,1834,1316,294,1507,315,145,468,1389,175,201,228,1367,1211,1600,502,1335,1257,923,426,632,1301,242,468,1345,915,531,947,1315,814,669,1110,1324,1352,1672,1419,845,1456,511,825,1866,1282,923,295,1398,1340,1080,502,1372,1519,1355,1928,577,1366,877,235,347,439,281,229,1283,1025,885,887,1344,1503,818,246,603,1139,1868,1005,425,2347,1338,284,888,155,485,511,426,1170,124,1314,713,1585,1170,671,471,78,1359,814,1345,452,714,441,1433,627,656,416,778,2379,751,1325,629,670,661,751,228,139,901,887,1273,480,751,1260,286,572,1323,120,1349,2380,251,594,1142,1274,2381,186,460,480,1469,814,1274,88,627,121,118,629,1343,1997,2382,1382,1121,1259,1406,491,1382,1459,601,814,777,602,659,1236,469,1344,959,1112,815,1891,469,1286,1344,773,1204,1204,1733,549,1369,517,889,1377,191,450,469,1370,291,405,590,191,1189,1199,1236,678,405,975,996,651,384,405,1144,405,1199,1362,405,1345,476,917,1366,418,1370,678,2383,1366,950,405,1652,1236,1370,967,569,917,1370,1370,1320,1199,1199,1199,1204,690,471,1343,1858,674,674,1200,1320,1200,1187,1076,916,672,577,1317,1386,2191,362,2384,1252,399,887,1341,1695,929,929,1441,1480,231,928,1264,1052,583,2385,2082,814,753,482,487,1447,1447,32,1314,1343,948,715,535,999,1212,1753,1563,48,1149,544,1854,1512,561,57,513,248,512,485,577,247,512,491,1150,629,24,1255,329,1475,907,33,1154,588,962,1151,1445,1216,353,905,252,1806,799,1145,885,516,1328,337,20,672,958,760,2386,380,1313,22,1630,135,651,1460,344,449,1448,179,593,662,662,670,20,224,870,743,1289,1722,485,431,688,188,147,619,350,1344,448,278,1430,342,395,1332,1736,1736,400,112,1327,1345,311,380,1370,257,1280,559,1057,1084,295,76,252,442,1317,574,221,539,231,541,423,783,2387,1288,1268,1372,35,1764,24,1343,1347,420,1304,179,947,372,5,51,320,974,1357,537,486,248,2007,607,529,142,1052,509,1433,645,645,1583,142,454,85,1078,1314,404,516,1337,805,245,1367,85,1317,968,1131,2068,950,1229,526,679,1442,563,1634,265,224,1152,11,92,161,51,544,1446,286,1343,1446,1343,874,482,1477,921,1037,526,799,569,556,1154,588,801,491,1730,510,356,1367,295,882,384,1201,625,323,1318,932,588,12,1069,1737,783,1198,142,1344,474,57,634,404,257,1469,448,1553,40,1365,1700,710,504,1010,2388,373,543,1488,1365,465,295,1332,1325,41,1661,715,2385,273,1216,1347,333,1452,507,509,438,356,182,492,905,619,1273,464,623,277,1227,1306,485,483,577,1350,1139,142,1357,541,1124,1124,1078,730,2389,1246,142,1199,1154,974,1451,629,1451,37,2114,1394,278,1173,1255,1444,1314,363,1441,8,374,1323,544,1173,1370,1240,1282,658,414,606,1613,882,52,137,672,671,849,932,715,753,1466,1100,1088,666,146,704,63,514,104,1090,1448,1425,545,1355,252,138,1446,1260,404,936,511,248,1056,761,205,2390,1141,1407,930,935,635,1238,1475,938,1407,1301,1055,1055,22,916,577,266,316,974,753,281,281,1506,248,1076,287,287,1150,244,759,329,465,1150,221,1338,1460,493,20,935,482,938,559,1343,886,57,252,495,1337,1355,1311,1150,602,526,458,231,983,932,1314,1268,1259,431,8,2223,234,426,1010,511,619,583,670,1323,1150,1237,458,662,662,1015,629,648,983,120,1314,1323,618,24,1227,1080,990,1031,1472,1472,1073,1340,700,1940,1460,1472,450,450,589,1340,450,1304,589,1085,707,1506,1085,1340,1447,383,2391,497,333,44,1415,60,1128,975,651,344,216,1264,1296,1282,782,1264,585,1257,1226,1372,711,1124,284,284,1338,951,1226,380,380,923,725,725,1345,479,138,1678,1400,1172,1147,2392,391,1331,1326,2393,360,1077,1157,1147,2176,1324,56,1341,573,2394,285,456,582,1378,2394,21,302,632,463,245,1324,872,530,2317,772,335,1280,331,251,967,585,1494,463,2086,540,2263,1560,1343,496,1160,2257,47,1257,466,326,253,1316,49,252,20,1064,1246,1821,20,1255,20,496,632,285,1376,496,1080,331,480,57,674,286,842,831,262,771,929,491,1139,520,834,114,1829,1265,318,484,1109,1005,324,1234,442,1470,344,693,226,992,341,514,1323,415,1243,1450,853,274,485,561,1452,1331,511,1377,737,340,145,1375,1470,821,1325,768,1283,329,56,1365,1217,332,2185,2395,1254,1367,399,274,22,798,1454,1480,1427,218,2037,1372,1195,1266,1446,290,717,753,2077,57,1069,945,290,430,1303,311,1442,741,33,760,341,651,1485,1485,1079,979,1052,1326,702,506,381,1112,1291,2396,1483,526,1052,900,992,1332,267,495,1087,483,350,1273,455,528,499,380,1961,491,491,1341,805,1446,482,482,1268,437,395,535,573,1325,745,645,876,295,1362,1323,114,1069,1344,1078,400,968,1483,1114,1235,442,1220,665,1320,2107,499,674,904,1268,1063,628,142,1282,706,341,1134,715,1237,556,186,186,1187,806,1289,774,1365,2036,912,789,528,1069,1448,418,248,1470,291,901,1210,1268,2024,1253,1323,1254,1522,1323,1365,1112,1365,1219,629,1266,523,964,1475,920,1266,1480,265,123,672,7,290,290,665,731,192,914,524,908,91,651,1229,64,1274,1088,922,468,318,1249,1013,1150,324,324,505,1172,799,968,514,473,512,929,252,1291,452,1227,598,57,1344,1636,784,344,514,678,12,601,588,1345,1320,523,629,251,1266,454,456,1349,120,1071,1186,231,1333,1255,776,1658,488,1193,1304,1367,1236,248,1002,716,226,1011,946,468,1250,930,182,735,344,506,275,1341,1452,715,465,731,9,1349,1306,504,1235,717,2397,1442,958,1080,1282,1217,634,712,979,1110,889,1238,1219,201,450,19,505,40,1301,1193,399,159,1332,888,1258,1370,1352,998,1007,145,656,716,862,19,693,380,587,947,489,1036,1438,1370,504,998,744,1349,483,1124,809,1266,1157,866,605,783,39,8,888,901,1343,894,1367,618,1307,2009,1340,1423,546,1355,801,627,246,399,1322,510,1466,124,1399,417,791,612,751,1432,452,452,20,791,935,697,899,381,1254,939,1217,1421,735,1266,1266,753,640,632,456,399,242,1446,1301,1340,456,1257,587,2107,1257,1325,120,499,716,318,315,1265,1343,471,502,344,1080,57,1055,1131,935,633,1856,1283,809,266,1343,93,8,806,818,693,1178,155,511,188,1365,901,998,1005,771,1322,1145,1349,932,809,1375,1350,731,55,2003,1071,1291,801,272,657,1369,602,1325,1099,651,501,806,602,2087,1349,1099,471,968,729,627,674,1323,1458,602,2288,505,532,532,564,1379,2004,1486,210,1132,342,1558,1483,1277,1286,725,1112,1144,38,485,381,831,1311,1362,1468,413,674,1132,781,738,192,929,1088,1592,1317,1483,800,341,1483,627,1311,80,1005,770,814,814,1349,674,731,2049,999,1139,400,1416,956,1366,1084,9,1198,1117,1228,1004,1333,442,1341,513,1476,2398,1459,231,442,1488,392,1456,263,1229,1341,40,1078,1078,152,1071,556,1306,1080,1362,463,1341,1178,1079,1268,374,142,976,374,1274,1385,1413,40,415,1325,1274,1881,1071,137,998,466,730,568,578,70,2154,1587,1389,485,1369,485,1345,730,568,950,431,1438,493,344,818,76,461,717,948,418,1367,1078,818,511,818,1448,1366,40,578,818,948,753,112,526,387,948,449,1255,640,1078,2049,1418,1071,1237,2213,578,471,640,537,461,1343,1341,40,946,152,40,332,212,280,1112,2399,234,688,280,1085,909,332,1466,341,1067,1942,1894,1894,1466,2193,1506,2399,1090,280,234,1276,505,2400,307,307,307,348,491,1087,137,1058,1058,751,347,124,642,1267,1267,591,2401,1231,1360,642,642,1332,2011,1231,1231,1231,349,1332,287,587,1451,521,353,383,353,1208,353,353,1077,1346,2402,550,799,1910,2192,2046,524,888,109,1260,54,523,507,1173,962,1170,507,1252,254,1077,1079,2403,482,1282,1440,1282,799,1421,1257,1237,703,290,667,905,1170,327,2148,1282,1199,687,687,1259,109,687,526,1282,526,531,640,532,2148,1260,135,2181,801,1052,1219,1265,629,295,471,471,661,2404,922,992,1255,420,1069,1366,158,1131,2405,992,1375,32,1333,14,1318,1255,505,295,483,929,329,1228,921,107,930,921,114,507,962,1776,1268,20,601,234,21,1512,1512,1328,526,356,555,379,1311,907,629,1177,2406,1323,342,1300,488,652,1216,886,20,224,583,1375,962,452,910,741,12,1177,651,146,905,265,420,584,1300,711,1190,1311,1343,60,430,21,684,295,344,1315,257,672,303,306,350,967,1722,1341,760,1078,9,1236,461,2407,730,1973,485,1190,1236,1362,927,1262,628,1332,1903,1442,674,499,291,291,1367,1280,82,941,975,332,332,782,628,1360,1268,998,234,1069,142,1199,372,537,361,1262,1345,2408,607,545,610,598,1267,974,563,151,151,1142,648,456,523,598,921,741,588,588,1496,384,205,1318,1318,357,678,678,623,532,284,1442,404,1345,265,418,1442,1237,1275,886,1322,1114,505,49,1496,569,265,1421,384,1345,1483,1400,465,1365,2183,1327,332,959,1327,1168,1342,1448,1080,1385,2232,295,1460,543,688,1324,716,418,482,482,463,1186,1076,715,2409,1255,1365,1488,601,555,1323,1323,1237,1488,112,1131,998,553,946,1365,921,666,1199,278,1257,1246,97,231,998,505,541,656,1255,1114,938,1483,1236,49,250,214,931,1357,814,214,2410,1078,1020,1116,1488,497,640,546,121,257,106,262,1198,666,1425,1421,1421,18,101,962,934,205,1503,234,234,1258,1367,876,661,1273,109,77,946,499,1322,610,1422,648,640,640,509,244,287,1506,1903,401,1332,499,1332,532,2411,1343,1311,782,1338,1338,1341,814,1267,483,1282,555,1257,248,20,1503,1235,1341,998,234,652,505,1366,1375,1346,1008,505,629,399,661,661,250,998,1257,524,120,1351,584,1323,1235,782,968,130,203,674,250,250,814,1323,1323,1324,585,1324,170,577,170,1453,464,512,1315,129,129,645,374,1511,940,526,375,526,1252,919,919,2412,959,959,484,2413,1112,21,1445,1427,2414,1341,510,1316,530,21,1316,509,342,1381,57,2415,85,261,1096,1750,814,1077,328,856,1447,1257,452,228,1236,1425,1155,224,753,876,856,2170,77,632,1448,1455,78,1448,248,753,1343,1343,905,928,1923,1005,1096,224,2416,1104,941,1438,1077,976,1258,35,224,399,1637,905,1425,1030,1227,224,1236,124,1047,921,1399,405,712,273,446,1444,1301,730,1236,1250,342,1343,35,905,257,374,862,466,1157,730,489,1165,468,1450,1052,704,761,63,405,1399,761,35,1425,513,121,342,1448,449,315,1655,57,57,1424,730,938,119,329,723,35,1637,740,159,505,1267,601,2417,905,803,491,2418,76,1291,355,1331,463,1362,1359,2319,2419,887,1185,1400,396,1470,751,953,57,947,283,175,400,1319,29,507,1304,670,1254,1470,572,2242,2420,870,252,1317,737,371,190,343,1190,866,327,1271,1342,1448,1176,1448,1297,1728,1512,1274,1199,400,572,706,1186,1361,82,76,143,598,57,483,9,1090,546,1355,1217,1114,1090,952,1367,1366,737,1118,1597,1270,327,521,295,1813,658,2123,632,605,1118,1448,85,952,521,521,1911,18,592,665,1347,642,224,672,1343,159,120,1199,1110,1283,737,125,1254,327,396,572,577,57,935,1282,649,649,640,670,485,1351,1265,1185,1355,627,1185,1346,1315,1323,1186,246,2061,493,469,771,469,375,805,540,60,1343,923,2421,331,525,1324,1343,1394,57,1343,1340,308,1028,1080,308,1080,1080,374,255,1346,452,342,442,1252,1208,146,497,24,1071,248,1423,532,1157,343,247,728,1266,450,128,832,514,1440,1341,729,967,1227,1060,1020,1306,514,253,1423,497,2422,882,1687,2422,57,57,1067,228,492,380,362,310,57,566,492,69,1274,1687,404,1895,442,728,404,1227,532,485,485,399,1688,672,672,1206,471,1448,471,471,1341,1341,1341,1341,1373,1373,1052,1289,1241,1190,1325,1325,1367,145,91,63,253,70,882,1357,893,1423,1351,145,148,1480,1217,483,1754,1450,1343,1232,135,56,245,651,342,2187,1442,199,1233,514,2423,1257,1316,434,1327,1853,688,448,1233,143,1448,1302,514,1199,192,1449,1955,192,1693,858,1259,265,485,124,1448,2424,1453,1955,1367,1282,507,493,1080,488,60,465,344,1315,1237,866,1332,1235,1257,1442,896,1168,237,1442,2275,67,237,150,135,501,471,1898,468,715,663,1270,41,963,1283,1287,57,1343,615,990,232,263,351,485,991,990,122,1151,1161,2163,353,923,1080,211,1448,2425,706,1257,315,1349,1174,741,1242,1450,150,1255,483,1254,1260,487,1359,1230,191,2151,577,1367,60,1448,921,207,137,1147,1212,930,805,474,1293,823,581,1265,1283,546,449,542,331,457,1147,1116,1254,852,126,177,526,1343,40,128,741,873,1512,1404,1329,526,33,833,782,2274,856,85,1181,452,1848,526,218,632,2426,155,248,224,1341,1198,1360,341,483,881,1291,1372,799,391,342,1260,57,1359,945,1309,191,75,466,1304,161,57,1090,1257,1149,1357,1489,280,754,1447,1343,1080,2041,1315,1080,1260,611,437,580,756,1447,1280,634,1176,466,257,428,564,400,437,1257,501,1090,1110,449,958,1329,471,469,148,487,1341,1293,391,650,468,1486,1304,147,1058,843,435,302,556,1721,1173,374,967,1257,928,1375,881,559,237,471,2427,1278,1192,1322,541,104,783,1379,706,1357,1357,1369,885,589,2093,1375,1367,1199,507,1257,1433,650,1170,420,428,945,1170,85,1343,932,470,1257,44,702,1340,436,1116,967,143,1948,1255,1255,1359,627,637,463,191,1343,1375,629,815,783,295,974,1307,929,1255,742,192,326,42,237,207,126,720,629,485,394,394,1291,235,920,563,1174,678,1257,544,402,801,951,155,281,406,1419,512,1229,1369,509,485,601,1367,480,430,968,1150,1170,1170,814,1233,186,449,473,1257,1615,1283,1236,471,2427,1136,1489,491,1357,252,526,95,142,237,44,945,94,228,394,287,783,1379,1272,1475,257,465,184,956,988,1288,104,59,742,1488,1168,940,155,44,1306,1235,363,929,1237,1367,1365,57,1306,463,742,57,1343,732,1357,542,231,1344,295,137,712,928,556,1365,8,945,1324,840,354,1379,1489,1025,625,331,1349,587,587,974,374,1377,1124,1124,921,1332,1116,467,483,383,526,177,801,1280,541,1443,1357,102,1316,1072,78,542,1372,1260,476,1357,1328,629,1035,1157,1344,188,1304,2089,1147,464,1379,1072,745,377,1067,192,893,761,761,405,148,95,94,94,205,742,1161,1423,1192,20,1100,704,394,974,1398,521,471,541,632,852,1116,947,1347,688,1056,1238,1204,702,929,1067,1355,953,546,248,1446,665,530,920,920,1367,905,640,194,1359,150,1448,1192,141,923,962,882,44,66,956,499,1407,148,632,905,587,471,939,142,1347,1347,1259,2200,228,1150,287,280,280,1124,532,104,1274,315,331,610,612,150,417,923,1266,742,513,641,1254,95,532,201,1268,1151,1074,885,1343,161,1265,782,228,1151,513,1114,420,501,1238,1306,266,947,947,526,102,559,1282,2242,20,712,1343,649,1039,537,457,720,602,8,649,1322,592,1264,148,1366,1347,222,724,8,723,280,921,752,601,720,155,1448,840,840,1322,1328,78,1448,583,582,1361,341,656,740,142,472,122,724,602,457,1307,984,122,485,526,460,1067,1343,634,775,728,1155,532,358,1778,1472,362,1375,1255,491,2428,1090,998,2429,142,1249,507,362,491,934,480,2092,1422,267,920,2430,257,1402,487,484,1445,543,483,585,352,243,1422,2092,362,492,505,1367,492,1324,672,1842,1422,1260,2429,185,224,963,452,452,452,295,1080,1170,711,452,629,320,1136,1568,252,516,1057,1341,1342,2431,452,324,1324,1084,1086,90,2432,959,615,915,1533,1674,993,317,1007,1386,1215,1425,254,2433,1364,569,1775,1069,111,1215,383,568,1255,241,929,1446,992,592,719,2119,1327,823,33,134,986,1257,930,1543,487,1365,342,20,1257,885,1447,1448,295,241,1429,142,485,1324,581,60,128,523,1242,450,1375,532,1534,949,227,1443,1378,1228,786,1343,1087,1461,913,1194,269,549,2434,913,343,869,22,1529,1399,1204,566,2435,2436,1380,452,41,75,1447,881,774,85,885,1145,1333,1440,1410,1326,41,429,800,1338,247,148,885,486,2437,717,136,1317,154,962,742,161,486,342,1412,1459,1682,2438,760,802,1951,343,869,33,857,647,766,390,930,526,2157,22,1215,1215,1529,523,1479,2062,1427,2439,29,247,800,526,592,662,492,926,1355,601,2048,77,2259,929,433,2440,1080,505,1443,816,375,375,968,1097,210,2252,551,1341,1280,104,468,900,2441,2442,766,26,146,388,1448,592,286,286,2443,945,12,827,1441,363,502,575,267,766,2169,645,1410,687,257,1219,532,644,525,1448,674,1320,2196,1142,2444,782,1689,501,2274,484,468,1266,1366,1518,1215,838,216,78,372,1169,1441,142,2110,2110,561,716,827,523,882,492,1343,1187,1052,2445,1229,1280,111,333,501,2446,537,521,1191,589,480,1199,1479,913,2447,21,420,2129,811,528,1400,1090,1336,532,84,1228,85,1508,1338,1415,687,1118,1215,609,1133,35,492,463,234,1237,1230,947,883,1066,645,741,719,343,28,2311,233,1249,6,529,1375,1489,136,179,505,1198,48,768,804,1019,1510,1250,920,1265,142,250,2119,2435,953,356,284,1085,255,2074,247,1424,476,484,2161,925,921,2060,1087,929,651,2235,1320,532,1442,636,388,1343,480,2128,1443,2448,91,1152,135,74,504,1170,565,1207,1324,216,1453,523,1175,930,560,26,854,504,671,1807,747,505,2449,1685,726,1591,1035,394,884,1372,840,492,1442,518,492,1365,1323,585,775,441,1002,888,1235,1025,1494,1306,735,2450,811,111,1080,1489,1441,1340,1315,1356,58,1327,463,1324,1389,11,1301,1320,1250,554,1649,483,1170,594,596,137,540,518,518,1199,504,468,370,153,1274,1712,1279,1370,930,1336,111,1410,42,1332,1235,53,602,1243,949,119,1781,2451,1489,370,501,380,949,998,974,154,166,1421,1133,1132,78,161,998,1550,213,1035,553,1332,234,1445,833,593,491,1118,960,360,1267,1109,1033,511,740,295,179,840,1052,1142,1412,1154,862,31,217,629,1157,450,1448,1219,455,929,1243,2436,1173,1438,295,1280,840,968,112,1413,363,1254,304,1067,547,1067,1087,28,1332,72,1118,1149,2113,121,1134,518,150,174,1807,1578,333,1094,248,923,1118,1415,633,141,145,394,672,684,2451,1489,352,452,1410,176,1156,456,205,770,2452,495,399,1351,1446,18,1448,814,704,122,566,158,1067,1204,501,2453,751,1433,1591,561,1571,497,1332,380,929,642,482,1349,531,897,2072,609,281,1268,1010,401,1389,1343,1266,141,141,2454,782,444,1110,420,397,217,1507,804,649,1443,468,1472,501,484,100,227,1685,135,77,134,531,343,1979,48,343,941,77,1669,1480,1469,1237,915,949,281,804,455,1280,669,501,202,1142,420,941,2196,492,354,1355,2455,779,671,1055,1480,2259,915,186,482,1689,1039,100,951,951,463,504,947,576,1683,1280,538,887,1540,20,526,1338,295,722,2152,728,484,1145,1480,234,1290,602,86,525,1336,1006,1338,1338,800,1250,505,72,2048,362,2449,1448,592,751,493,1280,645,1149,28,2152,671,690,986,2165,861,1328,620,86,1087,1389,804,1267,537,477,629,596,462,1350,670,662,929,929,2456,929,601,1265,1349,715,983,119,1246,202,1280,1067,682,537,728,145,1389,674,1149,1501,629,250,1219,230,501,532,602,629,779,1445,401,400,920,1315,915,915,1082,551,1359,351,1342,374,353,31,322,1444,910,250,568,742,1255,384,430,104,1277,729,161,363,246,1304,502,742,137,521,1069,285,1366,148,18,29,1304,2457,285,633,814,57,125,251,284,1343,354,2458,555,1325,2459,482,1195,2210,284,501,514,1327,1262,742,2460,6,938,6,1443,246,1441,341,2460,640,962,1296,1342,501,1055,501,1480,1343,633,57,627,2210,1277,1241,1311,921,921,1415,70,532,1415,605,326,31,31,601,1366,601,1237,1725,1073,642,488,450,1280,488,1366,1438,502,1250,1178,270,509,148,1345,343,934,1421,963,1480,1421,277,1671,1110,1283,1480,1480,947,648,1480,161,277,1459,319,121,423,2461,1077,876,1360,717,866,921,1419,710,687,876,885,656,341,321,2219,512,512,800,1496,1430,1342,656,1060,493,1365,1342,921,1448,315,893,315,1419,577,1131,1365,2462,485,485,485,480,996,228,521,1447,1397,1280,1176,1315,1304,346,594,355,420,1145,248,1301,121,1078,583,1327,1078,1234,741,741,1211,182,1254,1377,44,14,1400,32,505,588,1073,452,321,428,401,123,1306,744,473,328,948,449,1343,1257,1283,1323,1400,321,1150,1072,526,1327,1389,75,1288,1352,1309,910,1440,651,182,428,717,1510,753,60,1304,473,1427,1490,1080,1080,1367,327,1714,711,801,125,1240,265,7,75,14,1456,661,1267,583,1178,129,1078,1860,466,1308,585,1301,501,511,1448,511,1057,1190,443,1325,350,1272,1051,1276,1338,2251,468,418,420,537,1229,1268,588,1456,1257,1629,1076,82,1433,1433,1255,1229,471,428,137,1229,1174,1480,1504,1455,917,513,1257,1054,800,1257,951,922,512,400,1443,1343,2449,1527,1229,648,637,123,1233,1276,1390,324,1306,373,1341,1262,1376,468,1303,742,585,1335,1352,1089,1237,1089,951,712,774,492,835,12,1327,182,1332,1136,917,744,501,541,1780,1246,126,923,428,1325,629,1524,485,485,720,1390,1282,428,761,753,191,801,1423,471,1555,420,1307,171,640,1265,1151,513,897,648,1211,2463,1343,485,467,224,1338,1338,629,1150,1190,1276,327,1071,1430,428,1343,1272,1288,1069,501,41,514,1325,8,1338,1005,1089,723,1314,1334,2464,629,137,583,257,641,1325,480,670,1257,998,661,779,683,674,1051,1155,1324,1458,1373,1373,2101,1338,2465,1338,449,430,1087,101,101,1073,704,108,1601,1170,1504,95,1304,1863,1346,945,328,886,1375,2246,1011,882,329,1765,651,326,963,1170,843,1178,1109,1441,606,606,1502,744,464,1234,1211,1620,482,2466,1349,1488,1234,1190,606,610,723,661,761,243,1238,2467,47,1411,1090,2149,761,1457,990,21,2468,286,286,232,629,400,482,518,56,1299,142,190,1044,1007,1382,968,929,1365,1773,1252,1603,2469,1315,316,1255,1485,628,1291,90,209,992,1573,1215,1704,1425,706,148,1343,352,374,274,627,922,1090,1777,1177,498,731,1230,598,520,706,1327,696,736,1112,1370,423,327,42,505,265,360,1333,1265,160,182,854,1087,456,1283,1639,1460,156,1114,32,1114,483,1250,511,380,482,710,886,57,1233,16,319,329,1343,342,774,1257,452,1317,269,1284,1440,1331,648,1015,294,715,1430,406,1445,1664,1379,1505,1073,1087,1397,248,629,670,492,142,1114,934,332,1419,1274,1265,569,905,1145,1259,1447,390,1274,489,486,2470,1551,753,1343,1338,1338,1080,814,56,1738,1341,651,75,800,593,430,33,316,1450,224,188,1328,341,1541,757,353,920,979,1002,712,1061,1114,1435,1262,2471,1069,1817,1218,2264,774,818,1357,247,1448,1410,1605,228,381,85,521,17,342,492,21,287,553,772,1460,57,657,116,2126,1112,1648,873,1080,390,1410,1333,141,521,1080,1445,657,715,629,998,2472,1397,63,1422,1448,661,267,670,1073,1975,601,1357,1090,1315,1097,923,1438,2078,629,1341,1271,1441,629,1039,1805,543,1562,991,2473,28,991,76,1483,155,1343,190,1303,1457,493,1504,306,307,1361,311,375,551,611,1419,645,1344,1329,1833,367,1343,1080,2474,1384,674,342,505,437,401,1158,1810,1236,1947,1005,12,9,530,1341,1479,569,1448,956,1190,1032,1032,461,2475,521,1313,287,1475,1365,1389,F,1349,505,657,1422,1317,985,1438,231,920,939,456,1816,496,1470,1315,1134,1044,1438,42,1185,592,514,2476,48,342,1013,1191,532,1257,605,657,332,920,1242,535,354,1124,635,959,2172,1361,715,35,663,881,1470,248,1306,1199,1398,17,384,57,246,418,1485,1445,992,44,372,492,463,1229,838,486,1177,485,1274,687,474,2477,1599,329,1114,725,1253,627,627,270,948,1351,1134,523,1167,1282,589,1480,1087,119,318,1237,514,627,1576,1264,337,1158,1448,598,632,492,1479,651,627,923,75,678,191,932,731,921,921,92,403,120,237,326,858,37,1547,473,1488,142,1397,483,629,882,1367,1367,384,537,267,1154,356,1233,248,216,316,141,1422,922,1369,1324,1367,967,1343,1040,991,179,2478,920,1479,342,553,598,1492,1492,1430,392,328,329,2479,951,799,1229,406,672,426,504,2480,155,525,524,1629,1510,635,934,1446,1656,1317,523,1556,499,1480,1483,70,882,263,160,123,718,454,492,399,1260,272,932,235,401,251,682,1445,971,746,343,371,884,1055,1341,1217,1040,286,1335,344,1237,1235,277,488,1488,491,1341,2139,1258,1325,1324,171,491,182,1366,430,2481,1250,890,54,1331,480,1301,1020,1327,596,1323,47,468,585,507,585,1110,332,1279,1217,1448,493,761,712,160,1846,450,634,623,1186,774,1365,1360,715,1116,1309,1309,1328,1438,890,2482,483,505,1338,160,1732,1365,1494,1235,715,1170,483,505,2483,958,1350,1003,465,665,615,968,1270,902,1079,352,1150,602,1240,1365,139,686,525,1070,162,54,1282,488,1343,1421,342,840,730,605,998,383,493,525,1139,1333,1438,1545,1044,1370,1485,744,1124,489,1332,505,467,355,326,1413,930,696,1133,1370,1282,998,1078,1445,205,1240,1186,656,998,1154,974,1415,1110,1076,1142,553,1257,383,920,360,360,1132,862,1157,363,182,250,830,483,1109,380,179,934,112,931,457,715,1304,358,359,1479,1216,939,1329,250,488,2484,1504,234,661,1576,1457,1032,1258,482,632,471,1355,932,406,352,352,1204,456,2077,1040,205,882,1361,450,496,452,463,401,814,1343,359,546,399,399,101,704,510,1698,1470,1965,606,137,1664,627,1297,951,1100,316,1189,394,1399,1124,619,671,635,730,665,129,1124,553,158,1154,63,672,518,1435,1410,1087,1423,702,2027,809,1329,1177,882,1460,1044,1257,968,1837,491,191,482,1322,935,1312,1349,308,1315,1446,939,1502,192,917,1090,456,587,1438,1506,1375,1055,1257,182,252,311,354,751,1254,938,985,650,656,939,1268,1018,316,1421,1216,1959,1377,1107,281,141,1154,723,524,2485,1124,532,532,252,471,482,839,1273,1217,819,1014,1457,1190,1407,329,956,979,437,930,381,657,612,359,159,1259,1076,1485,1031,483,268,512,671,494,162,1370,1142,1087,495,1150,649,825,1346,1258,471,1052,1504,1307,141,355,524,1504,1343,947,917,1323,618,332,470,231,485,1131,80,905,142,160,1461,1816,483,506,488,951,1416,492,935,235,1342,64,1114,1064,649,629,112,723,1345,1158,1145,731,921,1204,1576,480,485,929,800,818,1340,360,2484,1347,830,952,1324,921,730,1430,401,182,482,585,908,244,159,363,731,1359,1448,466,929,619,618,1023,671,629,203,688,740,471,849,686,1448,359,272,1370,1975,327,1480,1119,632,497,159,953,1457,670,1323,629,1457,139,505,295,1121,457,814,1367,661,600,503,1267,399,525,1274,651,1349,491,1346,1360,1350,1270,825,78,120,601,525,1099,1067,1236,192,968,963,98,521,1367,682,629,203,674,230,532,1323,602,601,1458,618,628,24,778,1367,651,398,932,190,460,1283,1365,1343,929,849,1301,128,161,923,542,1343,532,1260,1304,429,1367,587,605,579,1109,1257,1520,2486,920,2487,147,946,579,544,579,544,172,1047,385,1344,1283,1279,1365,932,1274,1338,362,1252,1152,511,849,449,1170,460,1301,877,1259,1277,2487,2135,1055,417,161,1343,295,1365,186,460,1447,2488,189,85,1254,921,85,85,1224,526,930,146,1039,147,1039,1451,1131,1131,629,1399,1365,629,399,223,295,1111,491,1433,753,753,1331,753,199,1326,1174,1073,224,1056,1304,1316,1087,94,532,895,928,959,1124,951,1378,634,1343,340,2489,1338,94,473,226,483,257,1438,1378,1240,157,72,1170,511,1056,1345,253,521,471,204,1343,226,274,495,634,72,272,491,491,1087,849,277,1460,2490,953,1343,1044,941,554,1275,1316,252,470,470,2491,938,482,1343,849,470,277,1340,1274,1253,394,760,716,716,7,506,272,1367,56,57,57,886,886,57,120,710,426,91,886,644,486,1419,1025,761,1218,1315,307,998,1267,524,759,426,959,270,1044,1151,874,524,284,207,54,1028,1028,712,1025,1087,1087,1218,430,493,1142,1028,686,759,710,1190,999,125,917,673,1419,1037,1419,723,671,963,257,2147,1699,741,532,2011,923,897,771,923,232,1265,170,328,1327,790,73,342,1069,265,950,923,1344,1257,415,1328,9,1320,556,950,1320,265,228,688,951,1328,1376,1376,1103,1105,1443,74,1357,231,1103,615,801,1213,2492,1815,1185,1445,959,70,1400,732,202,471,253,420,114,1480,173,1228,318,220,1257,1215,706,959,923,1055,903,2493,929,1252,1252,1316,1112,1006,375,495,146,1252,1202,520,2494,1445,1447,929,730,383,1231,513,513,1015,535,442,1147,969,2495,60,60,355,1243,1450,319,1293,532,451,822,923,329,1304,1304,949,1341,1250,1377,1370,424,1324,1623,137,228,1360,1734,452,480,920,761,843,715,1677,732,730,150,865,56,2121,1447,876,864,1474,1381,378,656,2014,1399,332,2057,629,678,97,332,1236,452,753,717,1090,1919,1382,1215,1216,1216,430,629,729,1341,310,327,1360,1173,1448,1429,381,1419,2227,1110,583,517,523,1282,959,1343,486,1450,968,2496,471,2497,46,1399,1458,615,1359,381,442,353,312,711,1112,449,905,189,318,1445,1124,820,141,651,2498,77,923,1112,2042,524,1023,1460,1073,620,1309,532,342,864,742,1147,1326,484,1357,394,52,1311,1990,670,228,1999,1282,907,1399,512,1345,862,505,1338,468,469,1386,142,1344,1236,448,493,1327,645,1112,485,306,1289,1765,2112,2499,1447,1347,1253,2201,551,1457,497,684,684,12,1722,1170,756,620,1340,729,968,509,910,1237,1275,1282,442,1304,1124,580,1158,945,526,307,1453,1007,905,1315,1293,1448,395,461,744,478,1219,928,2500,1252,962,656,1343,486,514,2501,1893,499,1438,1077,1480,104,311,1863,2502,656,121,474,1845,825,1315,511,645,959,1237,1435,1932,418,35,706,1480,1360,1257,78,270,463,442,1343,917,1351,602,428,607,419,2503,371,342,1253,2504,183,333,1343,1367,1190,610,414,511,486,150,517,716,1128,141,876,1268,1072,1199,590,904,814,420,512,1274,1067,804,1198,2505,1277,1266,1187,291,1124,1211,528,107,1170,629,1104,1112,620,1104,1157,272,612,598,632,1236,2506,1377,463,246,436,1018,493,2267,1039,342,394,1291,1085,1098,1318,186,1425,1338,563,2197,418,1215,265,430,1248,523,1067,1977,473,1142,1479,252,945,237,530,1483,1257,1275,921,383,406,1322,1983,1155,1453,883,428,123,1087,920,319,1446,672,784,524,1158,192,620,1435,2507,325,1114,1174,888,228,1367,799,1364,672,355,730,514,651,678,1345,207,963,457,1367,804,1069,63,183,449,1369,598,2508,950,1322,2501,1172,736,1447,1346,78,48,1369,1243,1020,324,950,1369,555,485,1067,1369,1039,672,1486,272,1340,2509,2022,860,1257,1365,1369,1071,1072,1002,463,1459,492,835,1365,959,1186,963,274,316,1410,78,1250,2506,248,1235,1240,1005,1282,104,1238,430,357,1324,442,1309,729,555,710,1349,1324,1360,441,736,1075,716,1384,474,772,400,148,295,860,715,634,923,923,715,1173,197,1236,93,1203,732,471,753,2216,483,878,491,492,450,335,1270,1343,1347,1422,1078,504,1626,465,1152,1365,57,742,1077,1198,1073,1357,524,267,1206,1900,501,959,2226,2494,661,480,1370,775,33,1361,968,1470,637,120,1266,183,814,1483,1227,1077,703,1350,596,2510,333,2509,1304,665,1236,352,77,1350,907,511,1325,1154,1370,1136,1370,632,1333,723,1477,142,556,1147,2511,1219,1985,378,1035,744,1413,1078,1343,680,695,917,1235,629,1384,1255,1257,1240,934,1399,1077,1124,6,2098,1118,1377,1300,1344,998,476,1124,2512,2043,1139,1077,1007,5,864,1647,159,865,2195,97,740,363,250,428,467,1260,1483,246,1448,2215,343,505,455,362,1485,1157,1165,1128,57,502,471,402,1345,359,661,1448,1325,1067,703,1350,183,688,603,674,2211,70,1149,1367,1299,452,57,63,1448,497,568,1072,1056,245,730,18,671,451,449,1359,121,318,1355,402,703,952,1365,2513,485,1318,129,920,1259,485,672,665,661,510,405,192,667,1446,352,935,627,1324,101,502,191,183,1204,2057,1165,1337,1258,730,1154,753,1056,420,632,602,1219,146,353,333,1307,1340,704,1486,707,546,2000,881,1090,2267,1423,2514,1304,463,479,493,1322,1100,640,206,1168,1329,495,192,1351,442,1299,1090,394,1350,1274,1491,588,1304,634,1448,1236,888,1367,502,907,231,471,505,1254,854,483,861,1236,1124,930,930,1257,672,1260,1316,283,465,742,1018,1124,1960,1438,192,1343,1435,1227,1055,1150,129,1315,587,1335,1063,1199,1457,1022,432,57,1052,120,120,1089,1190,915,648,1236,1039,1039,142,517,1628,456,499,612,135,354,209,444,1110,1090,2065,418,1377,658,444,342,428,430,1266,493,1052,1260,1368,671,601,820,1365,649,2022,1397,229,463,1399,1282,1367,628,1080,1311,651,1230,1483,1355,1863,96,634,252,1338,20,461,1438,120,704,227,1687,1343,1131,888,526,1142,947,482,1480,632,825,404,511,329,1039,483,1128,471,160,1142,1114,262,1039,1459,600,70,632,729,1077,1090,730,820,1350,1350,723,511,921,57,2502,426,1005,207,1250,1157,1346,1346,280,188,480,953,603,1932,7,916,1480,1236,70,383,930,1350,70,592,327,97,714,493,1237,684,1390,688,629,1359,1329,671,1112,1448,1350,272,1233,468,1282,862,1071,63,120,753,656,491,2464,1039,142,383,70,480,1268,670,949,420,1350,998,629,514,1274,1350,1130,1237,1282,457,1313,1457,1990,600,280,316,450,600,1087,654,1350,728,485,634,493,1350,331,648,1343,491,1375,120,224,2105,492,601,316,1101,1369,1480,332,1067,619,601,192,963,1355,930,312,394,532,1325,428,1350,1022,1390,674,1325,627,729,1055,1149,230,501,121,1350,428,24,1458,602,601,779,1227,674,1315,1267,1325,355,1325,1367,479,63,740,395,242,468,1274,1274,468,441,651,1398,1469,520,1413,1282,148,1480,1384,1384,96,1327,923,1450,329,823,2515,1241,915,1343,670,178,885,542,864,1323,542,868,761,130,642,1818,549,283,395,1283,1448,395,395,1237,937,61,218,1262,1442,1458,555,1430,342,22,1282,1282,653,1479,1309,526,1152,2516,517,870,1145,1145,75,1328,1189,1054,1460,2517,251,2294,1078,634,148,466,1453,449,1109,603,1185,501,1304,437,969,645,463,2230,1297,1297,1267,1345,2198,1453,1332,1080,1235,1190,740,1384,542,441,1962,1262,1458,1308,1442,910,2226,469,395,135,468,551,526,316,1060,1338,471,F,1156,511,989,645,1257,342,517,1282,917,1185,1480,1187,1228,414,881,606,2518,1304,332,148,998,450,1257,1087,431,1314,572,528,1274,1257,1322,420,1472,1931,248,1282,483,1255,1322,687,418,1325,463,720,524,1691,44,329,650,588,476,1211,1257,207,2462,441,130,343,1250,499,191,335,905,956,1020,1257,956,1286,1619,1325,1325,1343,532,1367,358,978,886,1296,358,1073,126,1060,1295,804,371,1239,137,431,586,1451,277,1261,468,335,634,1306,1288,465,501,491,57,1349,1459,1237,1203,1054,1257,2051,835,120,1322,517,517,1367,717,2121,1238,1411,511,956,1002,715,463,1304,1430,1235,316,959,1131,1327,645,1459,504,1398,385,38,450,2251,775,1080,638,58,380,1210,1154,130,1052,302,78,466,1280,1260,1255,1257,1379,430,431,1257,1377,1294,770,1054,956,1334,2220,730,632,1350,1254,998,374,1325,937,655,1257,57,63,191,672,491,2519,1343,665,882,1128,1343,1164,532,1488,2520,452,1341,1448,2521,1238,657,420,849,989,517,704,588,1056,204,1407,491,1257,1257,1257,1343,1277,148,465,121,1338,1150,978,1325,1315,938,537,242,283,1069,499,329,1110,1980,1367,648,1190,1060,329,316,2390,1315,603,716,1156,1343,517,720,515,501,1350,1367,1343,1311,601,1147,1398,96,1131,1257,974,1489,632,470,1458,1282,651,1235,1343,1280,1430,471,218,825,601,1005,1267,431,1314,511,155,1691,1334,431,603,63,998,629,753,41,1020,688,596,17,1314,583,1109,640,629,670,532,641,1325,1257,1282,661,1338,93,983,1375,603,192,532,151,394,527,135,728,1155,603,1458,602,651,204,1367,2269,1429,854,1442,870,141,1370,8,2294,421,532,532,2522,1336,24,256,1336,1332,1497,1100,307,862,175,553,1392,257,934,1355,935,2523,32,864,129,491,858,272,174,1365,1411,58,257,36,78,174,1361,1361,272,1318,1625,822,822,903,518,2029,1139,760,610,992,705,319,710,761,356,1255,1325,362,44,1112,493,383,1362,362,1073,760,247,929,970,968,1486,1161,1257,706,561,929,1254,384,1257,627,627,914,482,356,1446,46,968,183,272,526,486,1889,332,889,553,524,552,159,1260,44,684,507,694,1028,1238,2524,281,1190,939,497,1257,629,272,645,858,893,78,1257,154,1236,585,154,969,969,22,318,959,484,116,1480,1526,362,1472,396,2005,1360,549,78,447,1320,1345,460,1472,1378,581,823,246,581,1452,761,44,885,524,1341,1073,870,1262,1216,450,651,327,886,800,1333,1362,379,1313,523,228,78,571,1267,818,461,434,501,430,1430,483,1316,1052,1142,998,1085,1190,611,272,1262,1077,577,1442,1367,1132,1365,57,706,1257,530,627,1262,1345,1125,605,44,1448,1323,1044,629,420,1267,512,858,324,1274,29,921,799,63,1347,598,645,491,934,588,1325,406,1490,728,123,1340,1448,1442,1318,724,89,201,1064,645,251,682,450,1488,401,1237,332,1248,1370,1288,1457,2525,1238,170,1268,1203,1320,782,1279,486,446,58,1360,1360,715,112,1332,1132,78,744,455,1370,1118,696,1370,222,1367,1077,426,934,1343,1438,97,426,704,514,496,753,1423,120,18,18,426,205,48,532,57,57,465,916,648,1304,1190,1334,640,1109,1274,1085,281,502,363,530,272,471,577,1267,1282,1141,1267,1314,818,58,480,830,724,645,783,396,1085,670,1141,1268,728,982,460,783,682,1267,921,532,1307,737,1483,2001,670,1367,1124,2186,959,348,261,615,21,482,442,248,173,353,520,1365,922,1365,1616,695,442,1254,341,1241,507,1318,1577,60,37,1375,532,719,532,1228,491,715,227,901,1237,461,1268,934,651,224,1343,10,905,252,342,1307,1259,77,189,379,488,1061,910,188,553,979,1073,1069,1341,1497,486,741,1052,657,57,1438,1438,532,313,661,509,502,493,629,395,1265,1453,375,1322,1300,1327,1303,684,1124,1457,947,1345,1315,40,307,1273,1332,1262,1827,399,150,1304,31,876,611,229,1349,1862,1241,291,142,248,1254,1253,142,537,715,44,1187,1268,161,418,1307,1323,959,1309,605,628,1300,342,656,1318,1257,651,627,511,633,1136,1136,332,1228,252,901,1421,1039,741,1367,523,136,598,741,1076,1236,1174,161,2526,51,141,451,921,295,588,123,921,51,1229,672,192,393,1324,253,44,648,947,886,854,974,1480,947,313,509,956,273,507,474,1366,712,715,182,1306,1168,1288,224,1002,741,491,1237,1011,463,1280,170,492,1469,1616,634,968,1262,277,1372,1318,783,450,1349,1365,465,1015,1039,656,1077,998,1136,1332,1240,1254,1438,511,1350,695,1157,656,1154,629,605,399,2527,934,213,532,1429,1332,8,70,1087,124,588,1466,191,510,1340,206,191,921,627,513,1136,959,1307,18,633,719,1423,1345,1338,1349,1238,672,1253,242,1300,1407,1315,963,231,648,640,956,1124,465,399,916,513,316,511,656,482,471,513,8,57,96,963,1397,229,501,403,1151,471,461,1039,1131,1173,160,1365,511,670,70,1088,1243,1503,601,1259,1483,1307,1011,1250,352,618,272,629,1448,1002,627,1390,956,1170,399,1129,596,661,670,629,1390,603,1349,728,1270,956,394,235,1390,461,385,78,257,78,452,1446,894,33,982,629,2182,F,1271,929,32,878,321,227,1247,849,F,F,1223,450,651,1341,905,188,28,528,124,145,F,231,724,85,1475,905,58,1456,1438,F,188,1349,923,1266,666,248,849,723,1466,52,650,1409,1254,888,226,1247,1349,1324,353,224,2137,1173,1173,418,124,1073,951,1069,121,121,1013,1073,1121,1069,1358,1095,672,343,1357,50,335,2049,1173,1360,488,1069,1411,252,342,774,1705,904,2528,456,1187,1441,1384,723,57,57,658,1315,656,126,760,1377,318,342,371,1173,507,507,969,1238,342,1173,760,284,864,495,1219,218,774,656,47,1440,1647,760,760,104,1173,350,57,218,1448,690,921,690,861,1340,1751,456,2132,1365,760,537,1343,656,1064,257,155,456,1688,1475,482,1229,495,142,126,1213,618,482,111,656,257,1205,650,495,495,155,888,257,257,2069,505,228,1087,1087,1448,1341,786,771,261,56,504,640,356,362,521,1455,1264,1055,1301,2054,629,1327,139,1360,21,507,532,921,1261,207,57,1343,629,1490,174,332,1460,872,886,353,553,187,1310,1445,2529,1440,311,1447,1165,523,483,327,523,1052,486,1307,1413,76,814,2488,1355,514,968,1165,1189,1341,504,1237,469,1149,1328,148,1448,433,1319,717,270,514,1274,1192,1198,706,887,1274,1125,341,629,1448,214,285,1255,1044,610,1085,463,1266,1343,49,1425,393,1150,327,694,648,145,485,1174,9,148,57,57,741,384,278,290,1237,1364,207,1319,1458,201,1683,1072,1451,1632,1367,446,332,596,1327,137,1199,1366,404,1241,463,572,488,1344,1343,667,1037,532,148,1257,392,1343,1240,483,174,31,471,656,1607,1085,2054,250,2530,1574,1149,1425,63,1072,201,683,1343,1490,154,1425,1418,1120,1039,973,255,667,667,753,947,1346,1351,465,342,640,661,939,656,610,1266,327,1683,1347,431,6,31,1266,392,401,827,1367,1236,1343,1355,887,619,629,1089,228,649,252,649,600,1574,485,145,1328,1308,504,1442,714,629,480,600,482,250,1308,1351,1346,963,1355,1149,250,683,674,674,77,77,400,22,310,242,310,27,28,2531,483,1398,1398,712,1694,853,504,465,399,189,651,359,753,483,501,883,359,6,291,428,420,57,1229,155,932,1257,6,1276,428,467,428,1399,206,428,1276,1338,908,428,501,8,1288,467,629,895,430,502,886,354,872,1456,535,959,209,974,1410,383,535,535,1456,1410,383,1427,272,742,922,1350,1365,42,1427,1450,878,430,1350,430,1343,77,1229,430,16,1421,1323,1888,430,1364,584,318,1771,235,862,343,959,2532,235,672,1421,2016,627,1910,1304,1888,862,394,9,670,1303,1453,231,9,383,629,761,261,355,1301,706,1234,1446,923,1371,1173,2206,282,1283,1260,864,332,283,741,744,2140,1080,1377,2020,861,321,736,226,715,712,2533,1268,566,1080,1326,1442,1333,1069,480,220,1440,583,523,1072,1341,1075,717,753,1460,1438,1438,731,1080,1369,257,801,1486,1486,130,1412,1309,75,729,661,1128,1190,2534,259,248,786,744,1309,145,583,530,756,1441,1179,1327,1324,753,1452,753,2535,1442,715,1134,1063,420,463,248,143,214,530,291,703,1268,1257,590,600,492,1054,1174,403,1229,625,1080,956,645,1318,512,1442,627,600,1131,530,1104,1128,271,57,888,761,473,801,672,1343,505,91,1455,1365,473,696,1264,1290,468,403,1384,184,491,717,273,446,1306,1175,585,359,1015,712,1304,311,1250,735,156,587,2362,1246,568,231,136,553,1119,1261,956,753,744,702,1104,1399,1425,1343,253,566,753,1107,191,664,145,702,895,141,399,723,1230,1016,897,1257,939,916,1459,244,1071,1107,1981,135,1266,545,1338,1304,1190,756,648,401,493,1338,8,471,1419,492,383,1430,2536,715,156,1037,819,1314,1334,480,2537,731,901,731,619,583,532,1306,731,480,670,723,661,394,703,1259,161,1155,545,1459,710,2538,509,1367,1109,1399,532,1079,1342,1071,1445,446,1071,1351,525,1453,2539,214,276,7,2539,1407,1373,24,2540,263,923,482,1485,353,1255,1433,569,356,1260,316,583,231,695,1044,226,532,629,341,741,294,2541,546,1447,2085,544,507,70,295,1320,885,1433,2227,1131,1466,132,283,1221,1325,509,332,1748,753,1412,523,1367,553,1216,1216,247,1418,1438,295,342,760,1460,594,58,832,904,881,651,872,615,910,77,910,1069,1382,8,629,661,1190,1358,629,583,160,561,968,1457,580,399,295,782,928,672,2542,8,1790,2205,1289,2227,1720,881,1257,611,2543,1109,1262,938,954,2190,939,1343,561,1274,974,122,605,441,1365,1274,1262,707,684,1357,132,135,1250,656,1352,706,974,1325,1044,589,1367,148,454,671,138,492,844,1114,1480,561,882,1322,1475,1946,142,921,1490,953,648,535,77,263,2544,253,491,431,2545,8,1131,974,623,42,1345,280,1254,921,679,1229,251,782,44,923,138,1011,1323,257,263,1727,1216,505,1349,59,556,57,1238,1109,1438,277,1262,231,1168,783,884,492,248,1152,2546,1173,923,283,332,1309,974,974,695,374,804,1124,291,188,656,1110,1154,1549,455,885,1237,1033,619,213,1173,1262,1257,1976,862,1236,1378,278,1433,553,594,1466,1332,402,1466,120,923,18,881,658,671,545,176,138,1346,619,63,923,2026,923,205,1488,953,186,678,482,1055,610,963,1407,244,493,1260,648,263,1150,465,862,1382,938,245,629,493,499,1257,1424,939,281,492,1367,1475,430,556,1399,618,505,160,1338,974,231,1342,495,885,885,1367,900,295,8,553,491,1367,1011,723,872,188,78,1328,696,87,1266,583,618,619,1448,629,629,316,974,872,1349,629,661,661,753,78,1099,394,601,87,1323,1080,1080,628,994,1067,1375,1112,921,1139,696,1282,1331,1265,2083,921,1447,49,280,1452,992,1340,1078,1362,1448,1177,342,344,2497,1497,1445,1127,717,1512,962,452,1460,1070,1131,188,116,2018,687,1366,1271,1253,1176,896,1460,400,1262,1445,1880,361,1438,361,1104,511,1072,350,589,248,234,480,1158,921,384,394,1504,651,672,75,2547,234,1446,672,1608,57,1434,468,1361,1260,1344,1483,480,1438,342,1372,1253,1267,1327,1445,1340,715,1110,248,44,1480,1438,1357,485,361,1154,1110,696,1142,344,1306,921,1367,1259,482,1112,1608,228,401,1131,628,825,2018,234,629,982,1375,1168,1390,620,1002,1366,1365,627,1282,947,430,1198,1300,1110,990,1198,1485,112,354,1343,1265,75,803,958,958,2548,331,428,1377,553,736,1445,512,56,1445,1367,2497,572,33,885,799,629,1360,1495,885,78,651,753,144,803,947,1326,1510,1448,1445,1088,526,1485,478,482,2549,1190,1448,478,430,1344,1483,1448,504,991,274,1341,1457,471,825,344,1257,360,605,341,1316,1104,687,589,354,512,1168,142,1199,2129,1104,1320,679,672,384,1510,991,1478,37,29,482,1445,1448,589,1968,882,553,75,155,1504,1367,1121,690,1270,1340,1260,59,485,342,888,840,504,2278,1488,1303,2549,235,1242,1266,1483,1367,485,1304,1440,1448,235,489,1872,360,360,383,998,1131,1001,485,546,761,95,715,1448,485,672,1121,482,1351,1242,958,1039,431,1343,465,945,484,1131,1274,916,499,1479,2550,1505,687,1131,825,1039,471,982,840,1367,900,485,1208,1242,141,480,583,687,63,1039,982,1479,627,203,1311,1219,518,956,1257,956,1946,1364,1069,513,1334,170,1476,1198,610,945,1417,1333,59,39,1333,75,1219,1458,502,941,255,1252,399,1332,1448,174,1334,1178,502,174,517,1274,142,568,204,1248,228,569,553,1480,1301,1121,392,584,270,1482,2221,1248,1219,1320,1365,526,956,1332,1332,1178,129,1366,1173,255,526,642,1257,1255,584,174,1427,579,579,661,141,207,640,1398,204,941,956,270,1398,662,945,629,161,1078,344,929,161,442,921,426,1073,328,1090,737,964,1422,1460,651,661,76,514,514,1423,28,1112,537,442,1190,1116,514,255,1343,1090,514,964,505,904,275,627,1468,109,242,209,1224,514,544,514,1458,1458,615,882,1365,146,353,706,1458,1228,273,482,501,21,1127,483,948,1430,1133,1318,1475,1370,452,423,1277,107,57,56,492,1342,265,1052,1073,217,246,1458,768,145,381,629,945,742,38,1177,1112,342,104,48,311,218,1485,248,651,2260,831,342,379,316,486,354,318,1079,711,909,1171,491,956,1988,742,58,684,401,962,146,1344,1334,1267,56,956,592,243,1275,509,968,1133,1005,311,580,2551,1191,482,2552,423,104,1452,568,598,57,1049,229,1442,104,341,2553,1199,854,627,605,525,393,492,418,1190,1262,1448,142,1127,1080,1460,1510,1276,1060,1186,1596,1323,361,577,353,155,580,358,1377,2059,929,1276,492,913,598,1492,56,57,57,365,1145,390,1364,491,235,381,146,1442,953,1047,1455,672,78,482,648,1127,537,342,1411,381,563,930,929,537,179,393,1369,104,1496,79,1399,945,1218,682,226,1274,1020,493,1306,58,1133,1270,1267,905,1438,1288,450,1341,1460,1373,182,688,1252,266,1109,483,2554,465,1288,717,318,171,1469,889,332,2281,465,945,446,958,736,932,381,587,1081,666,1377,430,1154,1375,154,359,326,1020,1442,378,814,932,1276,204,662,882,273,629,57,1480,159,1049,146,1460,934,661,601,491,85,627,471,57,246,191,1322,882,1590,2282,886,895,268,1367,740,1204,1399,1049,401,1341,452,121,568,201,905,1398,482,400,1124,666,104,672,804,1134,204,253,1134,615,277,1460,1274,78,730,1049,231,640,226,252,343,492,742,587,224,499,242,465,1133,610,1387,1274,672,1080,1387,924,858,921,858,354,526,672,672,1323,1580,229,1027,1461,378,929,632,85,1456,600,1081,601,587,1365,1375,426,1438,1145,1170,814,155,483,1341,921,1165,2282,1456,315,1119,1456,933,1480,1165,672,670,492,1218,1350,1367,600,1988,945,632,601,929,1375,1450,963,632,58,277,1500,627,1110,682,1350,1375,1480,1367,729,246,316,1069,1433,1069,842,430,620,1265,1485,799,212,1425,929,1415,56,32,1255,1044,546,1002,330,57,207,1348,1433,729,1148,452,21,629,353,526,910,753,198,2555,1456,145,1124,1179,629,1257,1124,442,1190,1483,104,1375,2556,648,1470,28,609,331,306,962,430,635,1268,342,635,96,512,628,1375,672,524,921,207,29,1423,648,1490,512,406,454,2115,1029,1154,59,1002,733,446,1303,1490,2557,493,1387,188,627,1282,341,840,44,383,1299,359,1154,960,486,100,1470,1154,2558,2115,331,1395,546,497,751,1029,1029,840,1257,635,497,577,80,461,1089,1490,1268,840,1204,814,629,1512,246,814,1179,603,730,1110,518,2559,374,1443,518,1362,485,114,1456,1315,1373,442,1364,430,1228,992,1242,1241,959,768,1483,1195,823,339,485,1087,183,885,1441,1044,442,1447,483,328,1377,992,227,509,1124,321,1136,207,520,2253,25,510,341,1448,920,1485,155,442,2253,2560,1260,342,1282,1069,77,1460,962,651,1460,1063,355,1328,342,1216,1440,224,161,1077,1450,1266,1497,516,33,963,753,1090,1504,583,512,992,423,1282,505,1457,155,388,28,532,583,450,190,461,371,1157,1906,572,501,968,351,684,1309,49,1268,342,366,267,998,1179,1344,619,1282,530,1304,350,257,1191,1110,497,1271,471,532,1446,492,530,145,732,1441,687,142,959,1087,36,1191,1274,461,946,372,1299,1168,1196,1280,442,1260,342,1185,1128,280,589,341,1914,452,1445,1323,517,333,485,1304,991,1488,1622,286,629,688,635,155,969,1063,921,921,1477,921,1229,932,1268,1085,1236,2084,1158,1229,356,1233,49,1475,91,406,207,2561,657,922,1422,57,186,651,731,920,945,1173,1488,402,1492,1257,1483,1291,636,510,324,1013,741,1367,1489,316,688,1304,1349,1058,924,1304,1270,491,556,1268,1011,731,482,277,151,248,741,735,1370,1282,40,1110,958,58,462,371,191,732,1238,344,1237,1194,378,736,1280,634,1488,60,1379,1344,1168,385,1448,1379,141,120,224,1262,1370,1489,1301,1074,1235,371,1306,1350,511,1343,1475,801,35,383,862,1456,491,187,968,1100,1379,1262,205,1257,998,1157,344,1377,136,360,1007,450,1386,1165,2204,1124,1440,1490,1157,466,92,57,335,201,627,1134,1324,1259,1490,618,530,932,704,1446,687,761,895,632,728,1309,1489,482,1052,1131,1752,688,2562,1340,1035,756,2231,936,474,1265,485,1048,1300,978,1309,1131,938,1407,1511,1445,1055,1029,648,1367,1750,640,186,1506,493,2563,121,1014,1300,471,456,1500,1257,936,1380,221,1074,471,1260,1039,492,495,461,1397,191,1282,501,57,228,1343,826,1131,1343,1050,1301,485,70,931,601,916,1314,1500,921,882,1334,753,620,1280,1500,583,1359,1304,1967,1265,120,501,670,120,1349,90,983,1265,1502,1500,684,1971,235,602,618,634,1110,518,1364,2564,1456,2565,1782,2566,483,1228,583,2502,992,1237,1377,442,183,885,1044,356,768,992,1490,2561,328,1447,2194,321,1460,1441,823,1087,1268,355,1282,342,634,1497,1069,1260,1445,1450,1456,33,342,161,1063,1343,1914,224,36,998,2554,572,2108,257,423,471,1791,1309,497,2567,532,501,1191,372,1274,530,1300,1260,1129,1157,485,2166,1870,1304,651,1349,1304,922,324,2568,1058,402,1074,1236,731,1085,2562,155,1158,657,969,2569,1422,1229,2570,1475,1483,556,1270,491,732,602,1168,736,1871,2251,471,1110,286,277,1569,1268,378,1475,462,248,687,58,741,1370,511,344,1007,1440,2571,205,360,120,627,1343,491,70,2070,704,618,1349,2572,2573,1035,1280,640,1055,2261,495,931,2094,461,1902,1500,340,1282,356,340,972,340,924,77,905,1273,1431,356,1349,1349,953,1264,394,1506,1150,141,923,1247,1349,618,1150,670,394,1233,1233,356,1233,419,682,1997,342,1069,311,381,1459,523,710,383,741,356,2574,388,1168,530,342,1072,1324,1430,1504,388,1480,1367,1448,11,311,602,1089,1110,886,696,657,2575,311,627,129,1238,57,485,1407,150,657,482,530,730,1430,682,886,482,482,674,1327,729,934,218,716,1327,1361,1361,329,21,359,1329,383,934,1392,359,651,1343,1460,245,1297,936,1341,1268,998,976,976,936,461,1134,1268,1343,1330,934,930,1365,371,505,1153,1370,1257,316,1052,329,1052,633,618,371,828,937,122,1366,376,1343,150,1245,328,442,148,148,207,342,1262,47,1343,593,1343,885,651,658,1448,963,1258,1280,1271,1257,1257,554,2576,471,468,1274,1047,442,495,1701,207,326,235,1047,148,1460,492,1306,148,889,1488,1228,468,446,430,431,420,18,893,1343,632,446,18,648,879,2576,18,315,1343,471,1306,235,1334,611,611,551,712,1434,2080,379,379,379,257,257,306,1092,1011,2577,772,1729,619,1185,1485,371,131,428,1377,1539,885,1341,1112,965,486,526,477,159,612,679,483,1153,851,665,830,496,867,1411,665,482,612,480,1359,753,457,307,1343,261,2234,217,1116,947,1377,147,226,226,442,372,1446,864,814,226,1440,135,651,1442,1361,1222,640,661,1446,818,1176,306,1320,1181,1804,1304,632,428,1076,645,903,512,526,56,247,405,1241,1304,904,191,263,799,1186,525,191,584,632,587,191,632,1246,587,632,632,191,18,1073,1116,1185,587,814,1446,226,818,943,800,1185,2171,661,1367,1367,1430,1112,1124,1343,1124,1112,1430,1430,1004,1343,1963,482,959,559,103,362,78,463,464,147,992,2578,1006,1455,1372,2579,356,998,353,111,1250,124,383,1445,418,341,325,329,873,868,491,320,1476,1360,768,13,559,978,374,2580,1242,1332,921,886,931,1257,1257,327,559,513,1147,1072,1451,1425,1280,1072,1238,1456,257,227,327,22,78,963,1173,2581,385,1144,1485,326,1445,801,905,1486,343,2582,1438,1266,1512,873,488,2583,1445,38,760,962,450,553,147,1347,1301,1326,661,267,539,670,514,832,1322,865,575,1341,395,414,2584,267,145,499,1293,1293,306,12,437,890,792,1486,399,142,1178,1445,207,715,1280,207,1280,2008,2008,1873,1280,817,577,1023,2585,921,783,729,825,674,1230,78,1242,1229,1297,1899,514,1361,436,215,645,1052,1187,715,183,1072,931,1696,1922,201,1297,1286,1215,869,142,789,2586,270,1215,810,783,887,383,682,629,633,1425,207,504,636,1102,886,1570,678,890,619,587,179,228,1174,789,512,770,1953,1338,2587,992,1072,186,343,343,523,325,934,1230,267,886,405,1488,263,1250,715,799,1469,148,197,682,851,248,9,1288,783,1090,1098,775,1379,1451,1002,295,1025,1199,1331,493,1237,1899,1365,486,277,57,124,344,1268,801,732,1222,1165,1209,1544,931,687,1222,1085,1154,1124,1475,363,1343,78,640,483,886,1280,1779,687,72,849,124,672,405,865,167,63,496,341,1154,751,1257,1466,688,499,1352,686,1448,1312,209,650,1190,882,801,169,640,207,399,1274,1164,316,1446,499,1055,449,207,1018,1270,1130,331,1349,2588,1461,227,577,825,1212,632,2589,1355,532,161,1343,529,596,633,2118,1212,383,921,207,72,1314,784,1250,1394,1268,63,1291,578,596,1322,670,480,1381,683,963,1394,674,799,1381,137,929,1250,397,1392,648,396,1486,501,814,1641,485,359,157,731,814,1448,1448,363,493,257,1445,1297,1145,1438,521,1268,1365,111,1333,1365,150,1260,1260,521,1365,2590,2316,525,521,1286,1065,2134,1067,520,1080,1149,1087,1080,1174,1152,918,918,393,456,1175,172,1107,1262,1254,1453,232,171,1052,1342,316,853,1147,316,32,171,423,321,1542,56,670,1450,493,97,651,1458,1399,287,78,1266,364,171,1262,670,956,866,1257,573,342,1398,333,627,1060,1365,605,1187,1367,1237,78,724,805,523,465,1084,556,60,761,257,235,37,111,1343,1118,97,101,665,224,1312,1334,150,242,229,935,671,1342,483,493,480,723,921,671,670,120,1099,2591,635,491,491,2120,1325,2592,904,1325,1325,102,102,1343,615,1185,499,1525,771,1177,499,2554,882,1228,1343,115,731,729,353,929,1367,1367,1061,2593,274,452,922,2012,1486,471,1128,1448,1270,2594,342,1212,1237,1253,1445,923,1052,1241,932,990,342,566,2595,1994,2218,482,947,921,2228,328,21,1015,2214,485,454,454,319,1255,532,379,1447,1377,947,19,159,712,1318,1756,995,423,191,1345,1361,60,1343,942,1237,629,887,295,1268,124,97,1460,1121,1168,1372,979,651,2343,2131,246,735,952,629,1358,553,761,44,38,379,741,1342,1342,1921,898,2596,571,818,800,78,75,2125,1298,1333,1265,47,442,1349,1412,265,523,257,812,353,449,905,715,342,1073,381,57,1238,342,1480,712,318,1882,710,710,22,1662,753,1483,1703,146,485,512,661,191,809,1370,1311,1350,956,363,744,629,998,1347,370,2090,117,137,1367,425,1486,645,1253,485,399,190,492,188,380,395,706,112,499,499,342,1365,1457,1483,495,469,1344,112,311,2597,1005,150,707,1190,1452,929,1458,1315,461,341,968,350,1672,512,1291,172,102,512,306,12,939,146,991,493,2167,464,905,629,509,611,1090,1470,218,487,985,57,104,939,471,1879,231,1486,998,1867,2215,684,1350,2598,511,1132,2240,699,442,1456,1334,274,1237,627,270,341,991,1345,1831,55,85,1378,246,1199,1128,1128,142,137,1253,67,1257,372,629,916,1460,753,629,1470,1510,1215,959,2122,1134,137,882,525,715,723,1291,512,132,2136,535,1323,1186,1360,216,1808,420,1361,216,487,1232,1124,823,1060,1268,1957,1766,291,2028,1243,505,774,761,544,598,632,2142,1248,1201,1265,480,1360,1350,1349,376,183,707,707,190,2184,512,248,963,265,491,1492,381,593,1995,524,1238,1929,814,588,430,916,2256,360,406,342,678,123,155,1114,179,1422,719,92,22,627,2177,77,420,41,949,529,1257,947,247,504,916,231,510,939,1145,371,2599,381,651,1755,947,9,1233,51,1455,1322,1918,491,649,1151,1087,1173,235,451,921,430,207,1158,182,57,124,461,327,598,920,723,905,2188,228,1047,461,1324,1341,1177,921,1229,104,772,1440,1218,518,1181,682,57,1343,861,81,869,263,1350,1350,1350,1274,1023,958,553,1273,1230,1366,1365,344,634,1306,1306,2600,104,1248,184,1274,1365,2601,712,9,295,2602,1350,592,592,495,732,1512,1512,1495,40,1002,1259,1338,9,963,491,342,688,512,878,1760,442,442,446,1325,1198,2273,1483,1270,2109,1785,925,951,729,465,1069,916,352,266,1456,493,1237,78,1235,853,485,450,1394,486,277,1334,2603,191,968,1235,1438,585,1186,473,1259,1077,923,601,1488,1331,1369,715,1376,1090,248,1469,392,979,1310,121,541,587,454,496,665,1237,860,1363,1118,1344,1077,183,2142,1376,1438,607,1005,2604,629,972,1127,1370,629,525,1257,37,159,1302,1199,656,480,251,932,1495,910,214,1369,159,1367,576,862,916,916,768,1109,1258,329,1377,1442,491,483,1020,97,302,728,425,1132,1438,2605,1118,1304,656,1258,2491,605,998,1246,1771,216,1077,1154,683,1007,1134,1306,44,1333,401,57,1489,402,1512,1177,104,876,2606,228,485,627,1069,606,1367,651,1350,753,255,1177,711,1189,161,882,9,632,191,1259,905,958,510,183,505,1236,1207,101,1367,1343,1484,1982,57,671,1305,85,1423,618,935,704,1323,651,485,1572,402,1825,246,1124,672,1067,1056,246,740,1314,2607,1579,248,214,1435,1069,1309,2193,450,18,729,665,201,1450,92,908,496,730,191,809,471,530,1345,493,802,1087,2240,405,137,452,1044,568,932,694,1392,1399,935,268,633,650,568,7,57,627,1237,483,930,1076,2608,723,851,121,251,1316,1897,1014,1014,619,1365,939,159,455,491,699,1377,38,1360,963,672,985,471,295,1168,326,532,1504,313,1005,329,587,1107,1013,1319,1301,344,532,231,1253,266,1110,1274,1260,661,1240,1060,921,491,1379,1132,651,1367,1262,1247,483,1839,1112,825,619,1306,1379,2609,1258,428,1552,425,8,1235,471,471,485,188,1270,1230,740,1343,623,495,96,1069,935,632,553,1370,218,1170,1153,1310,57,1419,1132,1265,316,262,1282,383,1282,1089,493,425,441,1023,1283,1314,1334,29,1119,1089,1314,280,901,1237,818,155,698,1005,894,1145,1879,1397,137,1438,307,800,1350,360,192,1274,921,311,492,1304,585,1875,59,255,730,2606,511,1594,736,956,945,1267,640,851,1266,1124,686,1343,1304,1281,627,1343,595,619,502,246,1447,42,1165,1334,2610,463,63,315,1119,1150,1207,956,939,1238,656,471,849,360,1379,40,629,1089,159,7,650,1397,1306,949,600,480,1218,1255,1014,1014,921,437,1994,1121,1208,723,1379,2611,1366,1314,485,516,1306,751,958,1121,516,882,2612,80,982,1343,1267,1365,524,633,633,1346,935,1349,661,1195,454,1375,651,963,1332,316,715,420,584,601,485,231,704,620,618,468,332,1445,1238,585,1422,457,627,485,728,619,458,682,482,585,672,491,1023,1165,618,969,1274,1343,674,719,66,449,1843,671,844,688,1110,1274,930,2391,449,1301,214,342,1301,1301,671,451,1365,2345,499,526,405,41,1323,1422,1422,584,70,1260,1090,151,959,253,484,959,261,1077,1261,532,1433,1065,1364,2613,1485,1840,1861,723,365,1128,2614,118,1078,1342,2615,1272,319,295,21,147,929,1241,1241,1015,1548,886,1375,1375,535,921,1190,1346,921,94,1370,532,469,947,921,1452,1318,426,761,1232,329,329,423,374,1397,344,978,507,342,145,269,41,1267,799,224,958,1360,1413,905,146,1362,430,417,526,629,344,978,1411,379,886,886,1266,1460,253,67,75,1797,1419,962,2133,1183,651,383,228,383,1350,629,141,962,756,363,190,469,469,706,344,1327,1219,645,1457,1340,1266,592,499,629,1343,905,920,2616,1065,1341,1233,753,956,945,400,956,1448,702,611,1433,487,782,1110,921,1288,507,959,2130,1357,487,1210,132,44,1739,420,1089,1307,332,1072,1072,343,1268,2270,1253,342,627,605,57,159,1369,1361,505,228,1323,1186,252,1210,469,1248,1445,1114,1755,523,730,921,921,1367,537,1954,724,934,1110,1257,678,629,257,1177,1158,588,418,420,1367,37,325,886,1235,281,1343,1369,1137,968,931,1015,799,950,1238,636,406,1227,265,295,32,248,1233,94,1328,1350,404,120,263,596,553,505,1279,1186,712,1301,731,1365,504,1078,1306,465,1324,58,1002,1235,344,1370,717,1238,342,1005,1280,1360,2100,710,2614,1349,1077,151,1153,1457,1488,1168,344,1370,585,723,596,272,450,958,257,629,1248,1376,964,775,665,182,998,1350,495,34,605,866,1110,1257,190,1257,1370,1245,632,1118,31,998,998,483,1252,1300,420,947,1341,54,455,1154,1342,274,772,430,450,399,695,744,1343,1241,1350,2164,1450,97,1035,921,703,1180,1056,2146,101,145,248,18,672,1238,1448,1154,137,893,963,886,1365,493,682,665,948,1450,1346,495,1096,1241,1274,1230,1433,1435,694,694,404,656,710,1257,191,627,703,1274,127,1423,707,1273,753,1502,1110,958,1161,1446,882,882,501,963,66,640,862,400,1259,484,1466,465,325,610,532,532,471,1345,120,499,1055,782,1274,1253,151,1315,1110,159,141,229,628,1282,1055,1342,513,218,120,923,187,1272,1067,683,947,1350,118,629,1402,1306,632,1458,1399,1279,707,1282,921,998,492,724,426,1005,480,1480,505,901,429,740,316,619,505,596,742,1969,184,629,958,814,670,274,1274,1457,661,627,661,332,1335,886,778,383,528,1349,1089,1257,94,963,968,274,94,702,963,505,1458,1479,2617,464,843,873,843,1286,1452,700,308,538,740,1260,2618,1324,545,1372,963,651,1309,1090,1266,1192,1273,504,1266,1318,450,1238,235,1633,1238,235,1476,437,963,2237,1340,84,354,1365,64,1606,1341,1052,139,341,402,328,2619,505,768,1450,227,1343,1452,1450,507,1447,1280,978,1445,992,946,509,535,1370,717,118,19,809,468,978,486,1216,652,224,41,870,1334,1512,57,1063,1151,526,431,1311,1300,1440,1341,856,77,245,1222,344,402,1448,1448,978,864,1343,712,1215,768,378,1309,1433,962,44,1367,1258,728,78,77,342,147,147,572,992,497,1883,493,78,505,306,362,1005,1457,399,1344,90,2620,539,1292,1470,229,1301,588,560,809,1090,487,589,1627,628,528,1069,913,363,1343,1367,1440,656,959,976,485,1343,84,1470,1104,1044,976,628,632,633,572,492,339,119,1566,588,1158,1372,651,147,123,155,284,64,636,1614,874,874,324,1369,683,406,1324,272,2168,1448,523,1342,921,406,388,560,920,1170,1168,344,151,1282,59,257,588,275,1300,1300,431,1372,38,40,344,1365,1206,1324,468,44,1645,687,870,226,1378,1139,378,217,457,998,1372,1007,772,517,1136,31,2179,147,1033,809,1349,507,929,457,572,632,601,627,1434,1077,687,1343,256,1282,1265,1238,64,101,482,936,1040,38,1270,57,342,492,1469,492,207,483,226,1382,316,78,1273,1284,67,985,703,601,19,1399,401,103,1131,825,119,633,57,509,229,1089,1151,57,601,342,1005,1447,280,1089,1222,1080,28,1280,78,139,600,661,1258,1265,601,1434,224,525,1390,1077,492,864,1343,601,1320,1257,1257,2621,334,2622,344,336,344,24,430,482,482,493,392,60,1325,399,532,891,712,730,730,740,1080,1112,119,682,532,730,1179,632,1334,1448,535,1258,1054,1235,1260,1174,1365,602,295,273,2097,867,485,744,1350,380,2102,1422,511,392,242,493,682,965,491,1236,2623,2102,682,602,1071,246,1809,1892,1809,1325,399,730,1080,119,602,2623,485,1258,246,1174,1365,380,511,2624,1899,959,509,198,532,1448,131,483,381,228,2287,247,1056,456,968,363,1080,1908,400,374,161,1907,476,959,1288,1124,799,482,672,1448,1410,57,1288,450,1056,374,1448,1311,161,1257,1341,629,532,1257,1325,1257,1323,1323,263,344,959,959,502,441,485,318,1316,255,444,118,1159,1301,505,1341,992,1316,1345,1055,923,1215,485,1316,1345,291,328,1320,1331,1117,1071,1345,1283,532,1275,786,137,1360,1447,1293,321,1286,128,1067,1323,1022,1478,1303,1343,1343,1124,145,429,1069,430,1304,1440,1460,1444,380,2286,1485,1419,383,344,492,257,651,247,1329,629,782,864,1456,355,1343,526,1334,1413,1341,1341,964,1430,905,57,1296,962,22,218,1497,1157,1459,188,1433,1357,1303,1315,1343,466,430,1080,112,1277,1077,434,114,380,400,968,471,505,456,350,1271,1235,1069,1456,1190,728,1419,746,295,468,1323,1293,388,308,76,1178,1341,619,1457,580,573,1252,1367,1165,485,1448,992,201,606,291,581,2625,1080,1187,228,44,120,1362,561,941,946,1093,9,1366,1277,142,507,1268,1252,1255,361,1117,82,471,514,1108,1441,1108,272,454,126,2626,505,556,962,192,1275,1131,1233,1266,326,145,1142,1343,801,1345,255,887,1480,121,136,1476,485,920,1150,1475,1237,523,952,266,1444,1399,1492,929,1480,637,493,161,426,1996,1071,64,456,890,1365,257,1301,2627,1080,1306,1080,476,456,295,1452,248,1280,342,917,1186,493,922,1367,1483,1466,1259,471,1344,9,1266,774,137,1762,1457,1327,1325,465,1306,363,840,920,756,1338,1238,1288,1165,1453,1055,492,908,587,466,480,362,1350,728,1276,730,1259,934,136,1313,1168,1124,37,146,929,1080,496,1370,1282,431,1157,1332,1332,669,1365,63,194,2628,627,751,751,1056,1433,749,492,1399,504,632,665,96,849,403,1258,1480,18,18,510,1433,1341,449,497,702,129,420,455,121,1301,1407,1039,1257,1410,280,1444,782,601,291,2242,532,482,1507,501,80,471,1469,1253,1405,1411,2629,941,1150,1405,917,1074,1306,1398,1150,230,1131,1267,482,502,513,1422,778,1340,7,1419,888,470,456,1343,1343,1055,984,830,931,280,1142,452,1453,426,8,1349,493,1367,492,471,2630,1433,1309,1390,619,1071,1238,121,629,1341,60,1433,1325,295,155,1238,155,1334,120,984,1346,601,139,1280,814,460,1390,1343,230,2266,1325,272,1323,485,263,344,992,482,505,444,1159,984,1055,923,1215,1316,1343,1316,485,992,496,471,849,526,1320,786,2631,291,2632,1293,1117,1762,1067,321,532,1444,383,429,905,1497,2143,1296,1413,1124,1440,247,1453,188,962,1430,57,1343,1341,580,619,1080,388,1077,1874,468,142,1457,1069,456,228,380,968,400,1315,1343,2274,350,1271,114,476,1303,1453,507,1252,2249,941,1255,361,1362,471,581,2633,1117,299,952,1457,1492,840,2630,1480,326,556,1237,1365,2626,1071,1709,121,637,1476,1131,1150,1071,1343,756,137,257,466,493,1280,1313,1338,1238,295,1367,1306,120,1483,9,1325,248,2634,2627,1301,751,230,1124,1282,1332,37,1080,929,730,510,2010,1433,492,749,1150,1405,2095,601,917,532,1325,931,1419,139,1803,929,442,1261,483,442,417,441,1257,1257,2635,640,419,272,661,270,495,2405,2636,628,242,1227,54,1090,1268,332,1448,1448,1325,1325,1079,161,468,1212,1343,1212,1343,491,21,448,295,159,1273,463,491,559,350,526,341,1257,70,426,1367,1457,486,329,1257,78,1240,461,70,246,1488,329,1343,2637,41,116,12,886,768,885,379,768,1362,253,753,1112,1297,461,588,430,1828,753,12,712,627,799,56,1366,486,1203,2525,886,1257,1343,526,753,158,1150,460,532,44,1438,1370,344,90,376,1161,2239,423,1228,901,480,318,1149,394,1399,1448,308,1460,1080,57,1483,308,401,891,59,699,224,1076,583,327,1181,1341,146,712,431,57,672,650,471,350,890,1483,488,1304,1402,501,350,1392,493,1349,1316,1441,1064,70,70,959,1064,173,1392,1453,600,1390,190,136,1057,1174,874,371,1266,700,493,1131,344,1152,192,192,1448,482,1425,273,511,1293,184,1378,40,1384,600,335,99,482,1076,1343,1469,344,380,1027,1399,640,1343,29,138,1230,1448,1475,63,1376,1409,228,1390,1325,916,1055,1230,1350,511,355,1266,1392,57,272,1088,1325,1057,1309,663,355,1392,44,1438,344,1370,376,90,1399,1266,29,1425,480,1448,318,1149,901,59,380,1460,394,308,493,1561,1080,1181,401,583,224,712,327,431,1341,1402,1448,2638,471,1483,650,672,1392,1483,350,511,959,1441,600,1064,344,273,482,1088,1057,190,57,1453,371,874,227,600,335,1475,344,2283,1027,1399,1325,1390,1376,1409,1055,1350,355,148,1257,1067,775,1190,1260,141,431,141,1434,1262,1154,1493,1493,629,518,344,1430,354,922,1055,956,1346,1267,1483,532,948,146,187,139,139,257,1917,128,245,1260,1419,532,1375,962,483,146,159,385,1313,1483,1177,286,645,354,1134,201,1258,1430,1124,1346,524,493,974,2639,186,206,672,2640,231,958,1483,1168,962,148,465,939,938,502,1398,1170,307,1389,1389,1496,853,1554,572,553,282,532,341,138,492,322,1447,1139,1375,24,2079,1375,2092,1218,1145,1343,804,651,717,22,1711,569,1216,486,1659,2063,927,1460,523,1706,1446,341,862,526,1052,79,799,526,1974,367,1341,482,287,1267,499,287,1457,968,575,1464,401,956,586,1271,148,672,890,1448,487,1179,91,493,1675,938,57,1265,287,482,524,485,1087,1199,161,514,814,1274,85,1311,209,760,1087,1977,1357,501,155,938,756,2641,493,921,1248,1237,185,505,483,814,523,814,678,672,625,457,526,146,1229,968,1167,78,1496,945,1342,201,1488,91,1488,884,1448,1442,251,1446,1365,1735,283,172,1357,1451,248,1412,138,169,493,385,1154,525,342,1496,257,890,1002,840,1168,113,1209,492,234,213,2078,1142,2092,804,250,1168,483,814,2642,655,1390,57,150,672,640,201,1153,224,1124,1259,586,482,1446,2092,1702,2642,1488,632,52,1395,804,67,1208,524,1697,110,1265,316,159,1450,2643,77,1666,201,1926,532,650,1140,938,1925,917,639,281,202,394,1398,218,57,57,1458,526,159,941,282,155,482,1254,1375,804,650,645,1446,1974,1448,120,159,277,1238,661,650,1265,1238,1499,601,1282,982,1022,814,1142,963,483,202,214,1259,585,532,650,1069,374,226,328,962,1169,286,286,374,605,991,683,7,482,524,1155,563,612,1324,716,546,962,665,612,286,1446,1325,1169,235,1349,1367,1621,1320,400,535,1238,1375,1286,224,1306,318,992,1052,581,1087,1212,137,224,295,768,921,710,1011,581,930,1468,441,449,963,583,247,651,224,17,1440,318,580,1328,881,44,381,381,870,1460,999,295,22,1454,1447,1332,553,1343,1448,1080,905,306,375,524,502,395,430,541,968,1453,1386,1448,1064,637,1367,1060,1360,1230,1346,1433,1229,343,950,1453,799,623,1433,1422,637,1483,468,1233,186,406,544,1342,882,932,402,804,905,393,44,678,858,635,1011,1002,483,1327,1266,171,197,182,363,1360,441,1087,344,1483,342,1240,48,1421,1365,1240,1157,383,1438,1262,1370,672,499,129,2644,1238,474,1312,1433,502,1422,83,611,329,315,648,363,1039,544,1855,1342,483,1475,306,1367,493,441,618,876,629,629,670,650,1621,2645,400,1306,224,992,2644,295,678,1011,441,381,1938,670,2279,1447,1343,449,1440,629,1332,950,1080,2646,1448,502,1453,968,672,502,1433,343,637,804,44,468,402,1233,635,186,1483,197,342,483,1240,1087,876,1370,1262,2044,672,1433,648,1283,381,188,188,2647,1504,60,596,596,188,1310,33,60,60,60,1310,60,31,188,60,60,137,1006,825,825,121,186,186,1340,994,1567,58,1078,1364,640,218,119,353,929,1364,1364,923,1316,2238,1819,700,920,1381,1234,1201,1476,1350,218,1379,511,423,1320,317,2648,218,291,1824,2272,218,511,1371,1236,632,146,135,801,1177,1448,2238,517,486,137,224,307,246,2649,1460,257,1399,1158,1090,1216,966,514,469,267,1362,728,52,1870,771,1341,505,1473,645,1316,1210,1117,2650,1158,866,448,801,283,517,1308,1316,82,1359,1274,959,1196,1458,959,248,248,1199,514,1170,270,1342,2272,2173,397,1255,1080,143,1124,1398,975,333,632,1137,469,628,383,598,48,216,532,52,463,1680,672,1360,1453,511,1367,185,585,1235,1170,1343,218,1371,682,54,840,1365,231,1129,283,2651,1324,171,146,1168,1367,1078,1438,1360,1379,295,60,1819,295,1262,465,959,235,218,1236,774,2652,380,1332,157,1986,1316,1142,248,146,1371,1124,2641,931,695,1332,394,1423,18,1080,88,148,1124,1395,1432,283,248,665,146,213,648,1506,987,929,1308,1367,1341,295,640,526,1080,57,1331,700,1282,1131,1824,1419,1165,307,736,58,58,593,627,1370,1332,682,628,1343,1186,244,922,1355,1052,418,1365,706,1005,956,1257,583,341,549,70,319,1289,2653,1283,1071,35,1370,209,479,2212,35,1252,526,1360,418,1145,958,57,885,75,1063,44,1221,247,1492,1338,648,580,399,1457,1077,569,1367,350,430,945,1448,483,1857,448,1289,499,1258,399,838,1956,487,577,1444,605,1379,1323,142,270,1257,687,343,1253,342,361,427,605,487,372,537,1351,78,1260,44,629,1377,85,1275,920,886,950,404,1453,1150,1492,905,598,799,136,1360,85,1270,226,524,1355,938,1340,1724,1324,715,1020,44,295,1087,530,1366,1379,446,585,1270,1270,1118,1154,744,1257,1006,161,1483,1492,1338,1252,1270,1377,428,1355,56,712,129,341,641,1345,1466,452,938,1322,1423,704,938,1303,244,57,1315,57,1407,1236,1444,712,1055,648,908,226,723,1338,103,577,332,723,1492,583,633,1390,120,1359,482,1325,120,1670,651,460,1257,332,1390,629,1361,1716,959,1480,876,1456,1342,353,1365,520,1324,1504,710,1441,1304,270,1438,328,1370,345,1379,1147,1172,927,1216,1667,417,381,1121,1747,155,1386,745,612,186,155,1362,1190,1447,1265,497,142,1347,1199,502,715,572,1125,620,916,1504,414,1325,2124,2045,1236,672,602,1322,1158,873,1421,183,1150,1504,1475,201,588,1168,1266,271,450,1303,1290,1151,959,183,1379,910,553,1118,728,968,156,213,1379,1358,16,1413,414,1154,497,895,138,1367,627,1395,610,1340,497,83,502,1257,1150,1747,825,1343,628,526,1325,1343,808,1005,1314,155,1325,651,728,728,2045,1286,502,2124,728,1325,60,1587,1080,1362,1080,1080,1972,2654,1337,2655,1970,509,509,959,1342,641,232,1427,261,910,959,21,343,1438,1446,21,676,343,771,255,1055,940,568,173,1485,317,455,455,420,356,921,706,996,248,1112,1260,1343,115,1077,1200,1257,842,929,959,493,888,1336,509,23,319,137,1289,269,1375,929,341,885,768,1283,291,532,283,378,1346,930,32,1023,990,128,823,328,1378,1342,947,885,404,442,1345,535,255,1343,1450,1259,352,996,479,1147,549,1370,670,295,947,285,1483,800,1199,1080,741,381,553,651,75,1112,383,78,885,1367,1112,1510,82,1360,1173,488,1438,1079,1080,1446,526,119,1077,1077,1309,1427,41,430,57,1073,1642,1078,78,1460,148,1381,907,1190,930,342,1417,657,929,342,629,1375,885,1326,33,77,505,378,1090,1442,760,1259,1259,248,486,761,1151,461,1342,1110,583,539,43,493,1190,1289,442,501,147,308,684,75,1080,756,486,1345,535,1453,153,1272,1190,753,620,482,1367,1304,990,1508,1448,956,1055,148,1267,1289,968,885,1183,1457,1273,744,574,1332,1265,1266,1297,535,111,611,483,887,1005,729,1340,1344,395,11,264,1361,1039,552,930,674,1110,5,255,420,1015,1080,561,959,1274,1433,1300,1394,1168,216,385,444,1450,1193,687,715,605,1230,1283,1377,44,1255,1124,1367,120,263,78,420,487,442,202,332,119,1229,1448,1110,1306,455,1366,1177,583,1480,690,1289,948,1072,418,690,1336,159,1409,523,1268,1183,706,918,627,864,1015,142,361,628,1161,74,1460,1441,1198,657,1504,526,125,1371,493,1777,255,1158,124,678,406,651,885,672,627,934,913,529,741,1504,882,12,885,1268,1317,1473,620,553,563,1142,588,272,790,179,1483,1442,46,814,1490,1657,1150,263,921,930,1475,482,1367,510,393,710,123,1174,1257,634,1158,384,216,1090,1438,672,724,672,454,64,347,598,559,319,1256,772,1230,1390,451,237,1265,889,477,637,312,719,541,1349,245,634,404,1267,274,1199,1236,1488,344,1002,483,295,535,138,1168,1411,452,1327,277,1262,1365,561,1073,465,1237,344,1427,111,945,1077,441,585,1174,756,938,938,446,1193,191,461,1338,741,493,275,493,1114,585,450,1306,286,505,1438,58,1450,1483,1297,1336,716,860,5,507,933,715,1136,218,37,1262,632,1136,542,656,1332,1338,830,1245,998,1154,1136,934,629,1109,179,78,862,217,54,1033,356,1483,1252,1350,467,1177,656,541,1133,1044,1118,1230,428,1441,1441,605,1343,1370,1155,814,1258,486,362,695,528,1117,1497,1136,1264,332,1240,766,671,1136,849,1496,1204,1297,394,1309,634,1085,20,703,753,682,57,1238,656,246,1029,1489,1341,672,18,561,934,205,920,124,1153,704,1355,121,332,514,63,1090,667,1300,191,661,1390,493,101,627,1264,1257,546,1100,52,1426,933,141,672,455,483,916,471,936,907,648,1039,1300,1029,141,2332,1110,656,782,465,898,1131,316,939,967,1327,1153,1273,532,499,1506,640,945,610,280,1284,1387,482,492,1450,244,1317,1351,278,532,830,1387,917,1183,316,141,262,1055,540,491,327,1131,671,528,471,1367,632,1480,938,493,1480,618,57,1183,461,1338,285,406,2656,526,329,218,44,1343,8,1488,1316,255,1460,437,1475,482,814,430,480,950,70,1349,587,818,1301,493,493,931,113,1448,740,627,618,482,1501,583,1057,882,596,272,1109,186,688,63,41,671,1269,578,661,295,670,1283,493,603,78,491,2233,120,1270,493,1257,394,98,814,619,202,963,864,682,2289,674,2258,814,532,1155,1458,601,509,347,1342,1438,1717,1427,907,1981,1200,929,173,2125,1023,316,255,719,842,1327,115,1289,352,85,1147,526,283,128,1450,768,44,1777,32,930,2233,946,535,1252,378,549,319,479,2657,823,1535,1367,930,1442,930,383,77,553,909,82,78,1375,2289,761,1151,488,1707,1360,1183,78,651,1109,2088,711,41,1080,1309,2156,57,799,2066,285,1289,552,611,307,706,1317,1361,142,487,1336,782,1448,2656,1190,687,255,1344,541,1411,1457,2201,1719,264,1265,455,968,1044,408,1710,365,744,2274,1039,501,1340,121,153,1153,11,1345,1005,1460,610,2658,2199,598,1161,634,561,1274,1136,628,1409,159,404,361,291,1300,216,690,332,1283,657,540,1890,1015,1167,605,948,1916,0,934,1434,840,216,710,46,921,237,556,588,123,1257,384,682,179,1473,510,1448,1265,529,477,874,2158,263,493,2311,724,1483,945,1349,541,934,1110,295,111,938,1450,277,1118,465,461,5,274,716,667,1483,327,715,753,1441,78,363,814,1155,528,814,766,1986,1780,37,1343,486,70,998,63,2159,704,682,52,1355,514,1698,1496,1309,656,1609,532,642,916,671,1731,601,917,202,2091,244,480,618,461,1480,632,1343,113,63,596,120,1270,2659,124,520,19,257,962,641,728,2659,719,696,1097,1054,480,719,1323,57,420,57,1052,540,549,49,442,1018,1030,1266,2660,491,742,1261,1105,270,1411,784,1419,881,1263,651,60,57,1018,8,392,363,1786,311,161,444,399,742,1038,589,607,686,1186,1047,524,1375,1375,121,965,650,123,1051,589,1322,1241,1323,295,473,1367,1241,444,38,444,965,1333,1241,32,12,1236,1344,592,974,601,271,968,332,1173,814,1142,541,430,974,176,392,270,922,584,1154,392,894,545,1260,471,121,2661,231,461,1142,1241,1139,719,1097,1054,1323,420,57,1243,176,1018,1236,1266,442,1888,742,1936,2662,1411,784,399,1241,1142,742,686,541,311,363,430,589,518,1375,1956,1726,1367,1322,123,1260,1241,473,1323,1742,121,601,965,471,592,974,430,1173,1677,2104,1826,461,344,344,615,280,1286,929,1255,352,1448,1344,1327,269,295,1073,32,874,561,1378,1011,1447,886,513,320,1327,1344,1441,504,141,295,962,247,1497,1512,1708,652,1516,1216,1216,1557,75,344,485,2545,662,137,1289,287,667,753,1896,1087,287,1268,306,400,1364,350,1054,537,941,1289,183,344,57,1262,1054,1073,1448,915,269,1372,1441,159,1268,234,814,1378,1267,874,327,1492,1343,280,678,1344,523,179,1624,886,651,2162,1268,2545,1073,1267,1344,1459,1327,994,1262,151,1325,1344,1090,1700,1365,2663,1235,1235,814,280,1129,11,465,504,1129,1346,352,1324,468,363,1378,1255,1950,8,1260,1154,485,1425,234,18,1260,1346,1023,986,648,1208,244,501,1131,1129,1530,1267,329,800,306,482,234,1258,1346,1448,468,662,1257,629,629,629,1473,2664,1445,1133,1924,2038,1343,2665,1325,947,929,1294,2367,483,384,461,1448,380,1924,188,1355,523,159,449,1382,684,1365,155,253,1128,420,1250,1099,394,159,1382,1355,482,1257,155,658,627,2038,1311,1382,483,483,2249,1365,1312,767,340,1039,761,1241,328,865,1377,629,148,1326,651,618,10,39,1255,252,231,452,1255,255,1301,485,761,137,1274,1413,1186,1441,876,715,651,920,1453,480,1047,324,442,1419,1344,799,1460,1212,648,651,267,1349,1255,651,1099,651,1262,442,1344,700,700,1378,658,725,70,1255,1238,592,1345,1258,1343,7,228,1166,2116,1367,2666,661,224,483,865,1327,24,885,1236,332,1260,485,698,723,723,618,629,480,7,327,224,661,651,8,332,629,40,431,431,431,76,950,950,1915,1172,1438,514,141,953,514,514,252,514,1172,324,324,552,2017,733,733,41,1338,1174,471,1338,1787,261,112,930,992,246,274,1255,992,947,511,1310,823,23,1346,2111,768,753,1497,218,33,1343,1334,1158,44,504,442,870,2229,75,1344,363,1158,505,1280,9,9,433,375,927,218,939,1185,702,1352,1128,1177,2096,1309,565,51,1142,1058,75,592,523,597,1282,1002,35,305,958,958,430,941,761,523,491,58,245,491,1240,1157,378,1142,44,1280,862,363,57,592,1153,665,401,939,1310,482,491,495,122,218,452,1267,929,272,1222,491,601,1236,992,342,717,970,363,1237,941,418,126,592,1003,1379,1067,1237,363,29,1157,378,1379,359,57,1237,1131,272,1222,272,1236,992,342,418,1237,1379,1157,520,520,1265,1282,1265,482,1344,1382,1379,1061,615,882,465,1349,1379,882,9,1344,1272,452,1338,262,952,585,1273,1107,417,1301,1341,1301,294,1117,586,921,423,1367,1228,31,283,246,226,864,2073,652,135,513,620,430,938,295,291,1237,1280,592,1071,1341,1341,413,279,1366,904,620,343,487,2176,471,585,487,681,1187,141,1351,1376,450,420,2667,1208,1208,901,600,1208,1483,1483,179,263,600,1150,420,929,553,207,1308,946,1341,1025,1168,291,295,1323,1243,544,1356,1466,1323,1267,1286,1342,1372,1033,250,250,496,584,620,610,895,1226,702,201,1332,428,939,384,1316,1324,471,122,1005,723,70,1267,901,670,602,814,968,1338,262,952,417,1273,1107,1301,1301,1228,384,2668,921,31,1117,423,1367,670,652,2073,2667,1906,487,1187,2669,1351,517,1937,1341,901,471,1208,420,1351,1351,553,1168,1356,295,1466,1323,291,814,701,250,1033,428,620,1612,1005,901,968,1759,63,385,342,1261,1421,63,1023,22,1145,645,385,1309,1060,526,63,1110,1237,1327,1332,1118,541,1118,315,656,1258,658,893,893,656,63,63,63,640,63,1039,332,1297,332,1327,1421,63,1023,526,1110,1118,1332,656,893,63,63,324,315,324,324,2144,1078,93,482,263,1112,1215,1419,1132,1272,1212,992,1367,530,148,1346,318,318,1132,1346,1195,1341,1512,57,506,1157,40,188,1184,1112,40,1080,287,415,992,1174,501,487,75,1332,1190,188,1272,1328,530,307,1325,616,1257,93,77,789,295,85,537,270,1124,1365,1080,1332,474,406,1080,493,1475,75,1268,85,1338,1150,324,1423,1238,393,295,835,1379,450,465,1184,471,491,446,8,2160,328,1238,383,111,1117,1154,78,359,1260,587,658,1118,1158,1338,1240,751,1154,702,57,1367,1297,510,1029,587,1469,1055,148,228,1343,482,985,141,1355,1157,1238,1272,1419,328,415,723,1325,751,120,1272,682,1390,778,1078,263,482,1215,2160,1212,1260,992,1367,148,318,2241,493,1080,40,1112,287,1341,307,985,1272,430,2670,501,1257,75,77,270,295,1365,789,537,406,474,1268,393,1604,587,383,1118,120,1338,751,78,1986,1297,510,702,1029,1469,2039,1084,585,405,1270,329,78,800,57,78,1199,417,324,491,9,8,344,1265,1376,1283,329,900,1283,695,1367,333,420,246,1216,1433,146,1315,1460,1445,876,1286,996,1023,1378,1241,1446,228,686,1360,78,40,532,1216,1343,962,1241,962,516,909,1427,1369,874,1456,526,1460,831,523,885,1393,489,651,1440,1145,344,1328,1079,57,1216,1216,1112,656,696,890,1158,1448,998,1165,267,1314,967,1069,516,307,415,78,1457,1344,684,1453,228,415,656,524,1116,946,706,605,420,1199,1306,1210,537,294,143,1288,6,672,1473,1453,1067,890,588,1158,598,1488,556,921,921,1325,324,1035,1325,363,1333,1255,892,191,892,930,324,465,930,477,1365,1168,968,1262,1488,585,1002,1110,385,1216,399,1118,929,142,1448,656,882,1165,1258,102,272,1325,1370,1492,1035,1055,921,1448,1099,672,1258,682,1423,753,20,93,63,191,962,57,1448,1367,1301,455,77,1124,1274,648,1422,281,656,1216,109,252,499,1183,1325,682,1419,512,1343,1338,1215,900,1456,1325,661,686,1165,1270,485,1099,524,1258,460,627,63,695,1367,2671,2222,146,962,996,78,686,1393,1079,1112,344,523,1492,1460,1216,831,489,1343,1681,1274,696,1344,499,455,684,415,890,63,627,143,1325,1288,946,537,921,921,556,1473,1488,1124,93,892,1448,585,1035,1255,20,656,929,1055,1572,682,191,121,1456,485,1099,1270,2672,1237,1237,1237,1365,355,1343,13,1194,507,41,44,187,1169,247,569,413,938,446,575,363,1209,372,890,57,553,928,1365,1130,665,78,1274,1546,78,187,578,72,751,640,665,1274,272,1392,1130,2306,72,578,670,359,359,941,550,940,610,1037,63,588,588,246,321,1297,978,710,228,588,72,2673,1177,885,1483,1749,978,1170,41,57,710,342,306,998,962,374,1297,592,485,882,1461,1060,1134,1170,629,72,1488,246,882,1114,1442,968,1488,1107,492,1217,450,596,518,921,632,1440,72,882,696,1028,702,702,1042,1301,645,929,929,778,461,592,818,72,645,982,270,270,784,444,1260,270,419,270,270,518,126,1367,1367,1788,1325,343,947,399,1488,658,399,1056,1367,400,717,485,921,349,585,474,22,2674,717,1301,1324,1274,636,1367,1208,920,1233,636,1238,353,145,893,57,751,484,1301,156,1324,1419,1365,232,990,505,21,442,1215,255,484,1304,291,295,1044,423,1212,753,507,1071,32,1370,886,671,1241,450,670,1381,320,329,768,1360,892,751,430,1262,962,418,885,651,1216,77,959,905,342,57,189,1238,523,253,24,1360,402,885,803,1288,1145,41,344,1412,526,381,1078,265,225,1142,505,1087,448,1272,306,12,1236,1430,1457,1347,645,684,1190,1169,1343,76,1237,499,569,399,1265,363,469,611,342,552,1297,285,535,1168,732,1060,1412,1134,946,1365,789,1433,402,372,1121,1253,959,1052,915,463,1177,628,1044,1044,552,723,142,627,1493,1257,1357,1069,1483,921,1442,1272,789,183,485,253,945,384,1455,265,598,327,799,1343,588,672,521,123,512,678,651,1492,627,724,1488,1448,803,451,1365,247,1077,1069,463,1168,446,1288,1457,596,1488,1402,58,58,463,968,1402,1235,1235,1365,182,1002,257,465,634,1324,958,958,492,54,295,1327,344,1025,354,1261,1217,450,1080,1020,1306,1240,932,428,1252,320,1035,656,695,1078,1077,392,1483,1165,1141,1332,291,1356,930,921,1240,1020,1070,632,18,615,468,742,485,1177,962,491,1069,702,1258,959,65,485,485,1458,495,1297,1466,1355,1423,546,1312,66,1367,962,1273,77,501,1315,1124,465,1506,1055,1055,315,401,648,1315,735,1259,1407,1270,331,392,448,577,1402,1035,1419,354,401,1352,628,124,618,1089,8,1005,485,1304,452,1089,629,645,629,740,1438,1272,295,670,394,627,1265,1365,232,484,1360,1212,671,320,21,430,1537,905,803,670,1360,1412,344,1538,41,448,886,1145,1814,505,552,1237,306,1190,1402,448,577,485,499,2203,1412,1272,1315,372,627,632,491,628,1078,1177,402,1044,463,535,485,1357,2099,651,921,1492,324,588,123,384,799,803,253,512,1069,1077,1483,331,257,54,124,1168,1240,1235,1025,295,958,344,465,968,495,58,1035,18,921,1141,392,1332,866,491,615,65,1312,66,702,742,1355,1238,1258,401,1055,648,1506,452,354,628,1419,393,2675,1342,342,629,518,83,1325,343,253,482,335,1007,353,1077,335,744,40,1369,1445,452,947,341,31,1241,491,1077,1367,340,1331,532,532,887,460,1441,40,1325,1317,1444,319,335,1241,849,224,362,1005,651,740,342,1216,741,629,59,1448,362,1369,188,963,1274,145,228,523,1333,381,1450,1367,1326,1367,1317,1183,1367,1173,1349,278,1252,306,385,8,1445,1325,437,1274,487,645,1457,1327,1168,442,684,1005,756,362,990,499,1297,1453,145,684,437,803,294,674,487,485,1199,460,1217,84,1253,528,1367,78,537,537,54,1257,537,524,1198,512,1168,291,291,581,450,1254,1069,600,503,864,672,886,1087,342,9,1480,882,947,929,42,253,672,974,491,524,1201,1317,1369,921,627,1338,1473,563,287,588,1073,921,512,1343,1343,512,1483,598,265,920,183,371,523,532,1343,1506,482,1090,1349,148,735,1002,9,958,1168,450,1168,295,504,710,342,182,1199,1324,430,1370,889,588,715,450,1349,173,1255,524,265,97,321,431,1349,1370,1265,1245,1077,431,159,1154,1262,1020,656,483,382,491,1133,420,188,188,1343,1334,1325,482,629,1173,568,1167,1167,1343,1199,695,1274,359,1173,137,483,1204,1435,18,1333,1340,849,148,1448,658,1355,686,57,1099,1480,1365,1253,532,1345,1168,1110,499,1343,455,57,1349,1124,465,316,499,640,1325,359,521,1266,1266,1199,699,1506,1367,1349,672,1204,1266,1311,1343,888,1644,682,1257,1341,482,1399,1365,1419,1338,1327,888,818,452,728,1349,723,246,1375,1367,619,84,670,431,661,1099,1375,1349,394,963,627,674,2675,518,482,1369,744,1077,849,1317,97,40,1441,381,265,670,1317,1274,1326,651,145,963,1369,1311,1216,1110,1448,306,385,1297,437,1453,362,674,442,1253,78,627,528,1800,291,1367,1266,1168,1254,974,735,9,588,42,882,929,183,371,1369,1124,450,430,295,1800,958,188,715,1255,1343,1334,1245,656,483,1343,491,431,1340,1349,1435,658,640,499,521,1367,672,461,1419,1349,452,723,394,1099,671,509,651,492,1266,214,492,492,1323,214,672,1359,198,484,2676,198,870,1460,1930,1459,491,728,728,1366,656,137,535,648,799,921,672,521,1930,512,2295,1270,1266,486,728,629,1067,1423,648,512,921,651,1323,198,700,700,430,130,341,733,733,341,872,965,962,756,341,1268,598,962,733,149,332,341,962,733,2677,714,2678,468,728,1492,837,329,465,465,509,395,1172,1195,442,456,583,442,1089,627,803,145,432,432,1343,930,227,1260,1211,753,753,930,224,161,1361,251,1340,1262,1324,962,716,1324,951,1375,627,230,272,95,1322,1323,1324,227,12,1440,224,95,1340,715,1421,1324,272,670,1447,330,342,343,2021,2679,1370,201,965,129,1219,1457,1445,723,18,66,1216,57,1370,129,1216,262,730,772,262,1483,383,383,265,329,1158,1369,886,123,359,923,1369,1153,1164,1089,1089,329,327,1241,22,253,1216,1450,963,1073,1078,1362,1078,1186,1253,524,512,474,524,1324,1198,1110,1257,1268,1324,618,55,1334,959,417,1255,1255,445,1282,295,1410,1300,1247,1410,827,780,2680,1415,485,1483,482,482,2681,482,147,139,139,430,1318,1345,1282,38,1399,1282,116,145,1324,525,1177,651,651,158,968,1282,559,814,521,1333,186,1378,1366,160,1342,799,1399,1492,964,1378,1324,850,295,1250,1343,187,1492,250,160,511,1320,147,139,430,1345,525,651,38,1177,1483,1944,1366,186,964,1250,661,866,374,866,1324,661,662,374,543,218,651,218,661,374,543,1811,958,66,1811,1375,178,430,532,1280,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1054,F,F,F,F,F,F,F,F,F,358,1676,700,F,F,F,F,362,226,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,20,1173,800]),
+PinyinRangeTable::new(0x20000..=0x2D016, &[429,920,F,944,F,415,F,F,F,958,100,F,F,1080,F,F,F,F,F,1110,532,F,F,F,F,F,F,1367,F,563,F,F,F,F,1483,F,F,F,1289,F,F,F,F,F,F,F,F,F,F,757,F,F,F,F,F,482,1338,535,F,F,930,672,F,F,F,270,F,F,F,F,F,F,F,158,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,F,F,F,F,F,F,945,F,F,F,F,348,F,F,F,F,F,F,F,921,F,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,460,F,1343,1511,505,F,F,F,F,F,F,F,F,1492,F,F,1485,F,F,F,F,F,F,F,F,1381,F,509,F,F,860,F,278,192,F,1072,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,465,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1346,F,402,F,F,F,F,F,F,F,F,F,518,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1069,F,F,F,F,F,F,F,F,F,F,F,F,F,F,521,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,267,F,F,532,505,F,254,F,532,179,F,F,F,651,F,F,F,1185,F,F,F,F,F,F,F,F,370,F,F,F,F,F,F,F,F,F,F,1320,F,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,1236,F,F,505,F,F,F,F,F,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,F,259,F,F,921,F,F,F,F,F,F,F,F,F,1258,F,F,F,F,F,F,F,38,F,F,F,F,1282,F,F,F,F,F,F,F,1425,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,1304,F,F,F,F,F,F,F,F,259,F,226,F,F,F,1237,F,F,F,387,F,F,F,F,F,318,F,F,F,F,753,F,F,1257,1324,F,F,799,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,227,F,F,F,1157,F,F,374,F,F,F,F,573,161,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,965,F,753,F,1077,F,354,F,1073,F,F,F,F,F,F,F,F,F,1215,F,F,F,1082,F,F,F,F,813,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1379,387,F,F,F,F,F,F,1274,610,F,F,228,1134,F,F,F,707,1341,F,1161,F,57,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,684,F,F,F,F,F,F,F,F,F,F,1259,473,218,F,F,526,F,272,F,F,11,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,717,F,F,978,F,6,1367,F,F,493,F,923,F,F,F,F,F,F,F,F,F,F,742,F,F,F,1456,1448,1451,783,76,F,1469,1090,1316,532,931,F,F,387,F,1198,F,F,F,1352,1448,F,F,F,F,F,F,585,F,F,F,F,F,F,F,139,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,634,1317,F,F,F,F,407,736,1065,1366,F,1112,1119,F,1448,F,F,942,F,344,F,F,524,44,F,57,F,F,1136,F,F,931,745,121,F,F,1035,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,376,956,F,F,F,2050,F,F,F,1119,1119,1332,F,F,F,F,F,F,F,F,F,154,F,F,1096,1432,629,352,1128,1419,F,1472,F,F,F,F,F,F,F,F,344,F,F,F,F,F,482,268,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,471,F,F,492,1324,1448,F,F,F,F,F,F,F,F,F,F,F,716,1334,245,1341,F,F,F,67,F,F,963,1343,F,1329,F,F,F,1411,F,F,F,1047,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,598,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,532,F,920,F,F,1365,F,F,1385,1023,1039,F,F,283,F,F,F,506,553,F,F,1373,F,F,F,492,1332,F,F,F,F,F,F,F,F,F,F,1265,F,1277,938,F,F,F,1367,963,F,F,F,F,F,1265,684,F,396,F,F,141,176,1341,F,1443,F,1490,280,F,1417,F,F,F,F,F,F,F,F,F,F,F,F,F,F,326,1341,723,F,F,F,F,F,F,58,505,1090,640,56,1122,F,F,F,248,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,44,1243,F,F,F,F,723,F,121,F,F,F,F,F,F,F,F,F,F,F,234,F,F,F,F,901,492,650,401,920,441,F,F,F,483,1282,1442,121,F,F,F,F,F,F,F,F,F,1332,121,F,F,F,F,F,F,250,153,788,788,F,F,1418,58,F,121,F,F,F,F,F,F,F,F,1274,F,F,F,F,201,1283,513,929,F,950,F,F,F,F,383,F,F,F,F,F,F,F,F,F,F,1255,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1371,75,F,F,F,F,1228,F,F,F,F,F,F,F,F,F,F,F,F,F,809,F,F,F,F,634,F,F,986,F,F,F,F,F,318,246,F,F,F,F,F,F,F,F,F,F,468,1343,1266,F,F,F,F,F,F,F,F,F,601,344,F,1296,F,F,636,1157,483,F,F,F,507,1411,1077,F,F,F,F,F,921,59,F,602,649,F,F,F,F,F,F,F,1448,57,1076,F,F,F,F,1076,F,947,F,F,63,1257,F,F,F,F,F,530,F,482,F,1257,948,F,F,415,F,F,F,678,F,F,F,F,F,F,F,F,F,F,1375,F,F,F,F,F,F,F,632,F,F,F,F,F,F,31,F,F,437,F,F,F,920,F,F,F,929,1444,711,F,F,F,F,192,F,F,768,F,F,F,F,F,1187,F,1488,F,F,F,F,F,F,F,F,516,1429,F,F,F,F,F,F,F,F,804,142,F,F,928,F,F,1367,501,F,F,F,F,F,1430,F,F,F,F,F,246,518,F,F,F,1130,F,F,1331,F,F,F,1231,F,F,640,F,1190,F,725,F,F,F,F,1361,F,F,F,F,F,F,F,1110,F,F,F,F,F,F,F,667,F,F,F,1344,F,F,F,F,F,152,F,F,F,F,F,354,518,F,F,F,F,F,F,F,F,F,949,517,F,1280,F,F,F,F,F,F,F,F,431,F,F,F,1157,F,959,1280,514,804,514,F,F,483,F,F,F,F,F,F,F,F,1174,F,207,257,F,952,F,F,F,F,F,F,F,906,905,F,257,667,F,F,F,F,F,F,F,F,F,633,418,865,1154,F,F,F,1341,1307,1137,656,1100,1072,F,85,1118,F,F,947,1071,F,F,F,827,1187,495,F,F,F,F,F,F,F,F,1257,1448,F,F,F,F,F,F,600,629,629,F,F,430,502,F,1323,F,1087,F,F,1079,F,F,F,F,F,F,1440,F,1359,F,F,F,F,F,F,1137,1253,F,F,F,F,F,124,192,F,F,F,526,F,F,F,F,F,F,F,1087,F,F,F,F,F,521,1236,F,F,F,F,F,F,F,F,480,F,F,F,F,F,504,F,F,F,F,F,F,F,1397,F,F,F,F,F,F,F,F,F,851,F,F,F,F,F,387,F,F,F,F,F,F,426,628,1448,1268,F,F,82,126,F,F,F,F,F,F,F,F,1376,430,F,F,F,F,F,F,F,1157,F,F,F,63,F,F,F,F,253,F,308,518,F,F,F,F,248,1343,588,F,1433,F,592,1453,526,F,F,F,1055,1047,253,31,482,F,F,1450,F,F,1341,F,566,1252,F,F,F,362,21,F,F,378,F,F,1266,385,657,147,389,170,F,627,201,1091,F,F,F,F,F,F,56,F,F,F,75,629,F,F,520,1176,286,F,1322,967,F,F,645,F,556,F,367,1438,F,329,F,F,F,F,F,F,F,F,1341,F,521,1304,501,F,F,688,520,F,156,F,F,1268,F,577,280,F,F,F,F,681,1257,949,85,F,F,F,F,F,F,F,F,F,F,928,F,F,F,F,F,F,F,F,F,F,F,F,885,1317,49,406,385,F,F,F,524,F,F,925,F,532,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,454,499,F,F,F,F,F,F,928,F,1411,925,F,1433,111,1351,F,F,1322,F,150,F,F,F,147,F,1227,F,1118,F,F,F,F,F,544,1370,F,F,F,F,F,F,F,F,F,155,F,1136,1198,F,F,1433,1167,F,1252,F,217,629,111,F,F,F,F,F,F,F,F,F,F,998,376,974,F,627,F,F,F,F,1157,F,F,F,F,F,629,F,F,728,F,148,F,F,403,665,176,1136,501,511,F,F,F,311,1415,F,F,F,F,F,F,F,511,207,F,F,110,1507,F,1430,F,F,897,1421,1257,1334,343,150,F,F,F,F,F,207,F,F,F,385,F,F,F,F,F,F,F,F,F,F,F,482,F,F,1039,1419,653,1039,1338,F,F,F,F,F,523,F,F,F,F,F,1198,F,F,F,1005,1399,461,F,F,F,F,1267,F,929,1430,F,F,F,F,F,F,94,F,F,592,629,1002,F,F,F,272,F,F,645,F,F,F,F,1349,629,F,F,272,F,651,F,F,F,F,F,F,F,1227,F,F,F,F,257,F,F,F,518,F,629,569,561,F,1440,F,F,F,F,F,F,431,F,57,F,885,F,F,F,F,F,422,F,F,F,F,F,F,1480,279,F,1343,F,F,F,F,F,F,F,556,1343,753,F,F,94,F,372,556,1080,F,F,F,F,F,F,F,651,49,F,F,277,F,F,F,F,528,783,1485,F,F,1490,F,F,F,F,F,F,F,1154,F,F,1262,420,F,F,F,F,690,930,F,F,F,F,F,753,849,426,F,F,F,1411,530,192,F,F,F,F,629,1411,1361,252,532,44,F,F,F,1333,897,F,F,F,F,F,F,511,541,1039,1328,511,F,F,F,556,F,F,F,F,F,F,F,F,F,F,119,F,F,804,F,1230,688,F,F,F,F,F,1377,1331,38,F,F,535,1307,F,1453,F,F,F,F,F,F,F,587,335,F,F,F,F,F,F,F,F,963,1063,1133,F,F,F,271,F,576,872,F,F,F,F,F,F,41,F,F,F,344,521,978,F,F,F,523,F,F,F,F,F,956,F,F,1453,456,40,F,F,F,F,F,1341,F,1341,1341,F,F,F,F,712,F,F,F,F,F,F,F,F,1011,F,F,F,F,188,F,F,418,F,192,F,F,F,1260,F,F,F,F,968,1177,F,255,F,418,F,F,F,F,F,F,F,F,1337,F,F,F,F,F,290,1236,F,97,255,F,F,F,295,248,F,1126,968,F,F,295,849,1307,F,F,F,1254,F,F,F,1343,F,756,F,F,F,F,F,F,F,449,F,F,F,418,F,F,F,F,F,F,F,F,F,F,1078,F,F,F,1023,F,F,F,F,57,F,418,514,1260,F,949,214,190,F,31,F,F,F,F,278,F,F,F,F,1260,F,F,F,F,F,F,F,F,F,F,1445,676,F,449,483,389,F,F,F,F,F,865,F,F,F,F,F,F,F,F,F,F,F,F,F,1457,F,F,F,F,56,F,1365,F,F,F,F,F,F,F,F,F,F,922,F,F,430,160,F,F,1063,148,78,F,F,F,994,1360,F,F,F,771,F,F,F,F,471,1177,32,F,F,F,F,1301,F,F,F,1360,148,F,F,F,F,F,F,F,F,F,437,F,F,F,1226,F,F,1282,F,F,F,532,F,1128,950,1469,F,F,F,F,F,F,485,F,F,57,F,1257,F,F,F,F,F,483,F,537,F,F,640,1359,F,F,F,F,F,F,524,F,F,F,F,1375,F,F,37,F,F,886,F,F,1400,F,F,1343,247,F,F,945,F,1136,F,190,F,1460,1375,F,F,F,F,F,499,F,F,F,F,1078,F,F,1341,1262,F,F,F,F,F,F,F,1370,F,F,F,F,405,F,556,F,F,207,1343,F,F,F,F,F,F,F,F,F,629,F,251,F,F,1257,F,F,F,F,57,F,F,59,715,629,F,F,1119,F,F,F,F,F,F,F,F,656,401,556,F,F,F,F,1341,F,1259,1345,F,F,F,F,556,F,F,F,1067,F,F,F,1249,F,F,F,F,F,F,888,F,F,F,F,F,F,F,1375,442,F,F,629,344,F,F,F,F,F,F,F,532,1265,F,F,F,F,250,F,F,629,F,F,F,F,F,F,1198,F,F,F,F,491,F,F,28,248,1424,F,F,F,F,F,1367,F,F,F,F,280,F,F,F,F,93,1199,F,F,F,F,F,F,F,1149,483,921,1055,803,F,F,F,F,F,F,F,F,F,F,F,394,F,56,F,F,1288,F,F,F,F,F,F,F,1440,F,F,F,F,F,1021,F,753,F,342,F,F,F,F,1157,37,F,F,F,F,F,F,65,F,1257,F,505,F,F,F,F,F,F,F,511,F,F,F,F,F,F,F,929,F,F,F,F,F,F,F,F,F,1112,513,F,147,F,F,F,F,F,F,F,F,F,F,F,513,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,F,F,F,F,F,F,F,F,F,1410,627,F,F,F,1479,F,F,F,F,60,F,F,F,F,F,1212,F,F,F,57,F,F,327,F,F,F,237,F,1458,F,F,F,F,523,F,F,F,F,F,F,F,1342,F,F,1320,F,F,148,387,1447,F,F,F,F,F,F,994,F,F,1359,F,78,F,484,902,F,F,1349,1326,708,F,F,F,F,F,F,F,663,2033,1024,170,F,188,1254,992,F,F,F,224,483,F,1342,F,978,F,F,480,385,F,1433,888,F,F,1381,33,505,F,F,445,F,F,1268,468,F,F,F,F,F,F,F,F,1410,222,362,F,888,F,892,1078,636,1375,452,60,F,F,994,F,994,F,F,F,F,F,F,F,F,F,F,1340,1445,F,509,1245,128,F,958,F,1459,F,1411,909,12,F,430,F,158,1323,F,F,F,1080,452,295,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1078,F,F,F,F,1215,224,1226,907,999,523,F,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1366,268,F,400,1085,F,F,1134,801,1453,663,75,1508,1338,979,F,651,1023,F,F,619,295,F,1452,484,F,F,295,F,F,F,1512,F,F,768,1378,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,1503,1090,264,314,991,F,35,418,442,1340,F,1340,F,F,553,1343,469,1442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,514,F,F,363,F,F,828,945,F,257,F,485,1343,1341,F,342,1109,1109,1357,559,455,444,F,F,F,430,F,F,429,931,928,F,1112,F,35,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,512,556,F,F,F,F,F,5,665,F,1198,F,F,175,F,F,1117,142,F,1235,F,F,832,F,520,F,F,70,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1277,1073,447,F,F,1460,F,392,482,F,485,F,1257,F,1067,851,450,1142,1275,F,1398,F,F,80,923,1219,1215,235,F,768,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1415,F,F,1320,F,F,1254,1439,238,429,F,13,886,1039,330,385,F,F,F,909,F,F,F,1309,417,356,21,1488,725,F,480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,250,1257,F,F,220,780,F,F,F,253,684,556,F,F,F,F,1343,532,430,F,483,F,F,431,814,1017,930,224,1060,556,1458,F,1077,688,486,890,448,482,1142,155,1248,514,907,1415,1283,F,F,60,F,F,1304,F,F,F,F,381,507,F,F,1266,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,291,F,78,893,F,F,F,1383,F,860,1198,F,1349,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1272,F,F,840,362,78,1282,F,F,F,1438,1365,801,F,F,F,F,F,1316,1222,F,16,420,441,226,F,838,F,102,483,793,1357,1274,F,164,1334,F,363,1154,41,121,F,1304,F,F,413,F,155,F,492,1509,F,F,F,1238,217,885,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,F,1257,F,880,655,2030,738,607,1210,31,F,F,362,F,572,F,F,486,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,F,1496,684,F,F,F,F,F,F,627,F,430,751,F,1104,1069,548,148,651,683,F,F,1324,1430,164,383,948,F,1150,331,1199,F,F,F,F,651,F,F,606,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,601,1390,1255,F,F,F,F,627,0,690,1447,155,497,F,491,F,F,F,F,678,1341,F,1056,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,815,F,F,F,471,F,1410,F,F,419,F,1346,57,9,1261,799,F,F,F,245,492,862,F,F,1367,169,1381,F,112,F,1433,1039,F,877,381,1433,F,F,F,F,F,627,268,F,155,F,1503,910,F,F,1064,661,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1090,F,511,651,F,F,546,596,F,1301,511,F,170,F,F,F,1375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,699,1282,518,485,F,1375,F,F,F,491,F,418,F,1023,471,941,F,1039,1503,F,F,671,456,158,1054,1250,483,1480,1266,1340,405,587,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1453,F,F,672,F,77,1078,1352,569,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,1280,F,F,1338,295,688,420,1338,F,F,F,F,684,186,318,1446,1352,1242,1219,8,1365,F,F,454,F,645,512,1382,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1392,280,F,485,F,F,F,1248,F,483,1257,F,1422,1204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1365,F,F,F,F,645,F,F,1448,1077,F,611,600,1237,870,146,1351,270,F,270,F,41,945,1090,F,1446,F,F,F,F,F,645,F,882,F,1432,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,849,F,F,1282,483,600,1350,108,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,614,F,F,F,F,F,F,680,F,F,F,661,1260,F,F,F,650,F,F,399,F,F,F,F,F,F,F,F,F,1288,F,F,F,627,187,F,F,F,F,F,F,F,F,F,F,F,F,F,F,952,F,F,F,F,F,F,F,F,F,491,235,492,953,1282,1352,F,F,F,F,F,F,F,F,409,F,1436,1064,728,461,F,F,F,F,F,F,F,F,F,F,F,F,201,1005,1022,480,1340,F,245,F,676,F,1343,F,F,F,F,F,F,80,866,1150,291,1392,192,F,F,F,F,F,F,F,F,F,F,F,1415,F,1259,707,F,596,1379,F,F,F,F,F,F,F,295,F,F,F,257,F,F,F,F,F,F,F,392,F,F,F,463,F,F,F,1080,492,F,F,1419,483,463,F,F,F,F,F,F,F,F,F,1230,683,F,F,F,F,F,F,F,F,270,F,F,F,F,F,634,F,F,F,F,F,F,F,F,F,F,F,F,F,814,775,521,1375,F,F,1331,174,F,F,F,95,628,283,775,775,F,F,F,F,F,996,F,F,F,F,1375,F,F,1360,F,1344,F,405,F,F,F,231,F,F,F,F,F,F,F,F,1438,728,257,F,F,1438,F,F,F,573,F,418,1117,430,482,1433,F,F,F,75,F,1236,1193,F,1199,F,F,356,665,968,476,1468,974,F,442,F,231,430,1147,389,F,1367,F,1320,F,F,1227,975,F,F,F,F,532,849,F,F,967,1446,F,F,651,1252,1286,218,F,1369,1372,F,F,F,753,F,1360,F,F,F,F,1254,F,1423,F,F,1306,F,F,986,402,1367,F,F,F,F,F,1262,59,1360,F,1344,F,1307,1360,618,F,F,F,F,F,1187,F,F,1438,F,F,F,F,1386,346,594,F,F,974,F,F,F,F,524,F,182,217,1212,5,F,F,F,1485,F,F,465,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,41,146,F,F,996,F,F,F,F,F,F,670,F,F,505,1080,F,1499,F,F,F,F,F,F,1343,F,F,331,331,F,F,F,753,F,F,F,1090,F,F,F,F,F,F,F,F,F,F,F,F,F,18,F,887,905,907,487,1454,F,958,F,F,F,1361,1150,F,F,999,F,730,F,F,F,F,F,F,F,F,1343,F,999,F,F,645,956,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,469,485,F,F,F,F,361,F,F,F,F,F,F,F,1360,111,237,1344,F,1367,44,F,78,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,938,F,F,F,F,F,113,F,1283,F,146,F,F,F,F,1398,585,F,327,F,F,1139,391,F,F,284,F,F,F,F,399,F,1446,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,783,F,F,450,F,1158,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,814,1418,461,F,274,F,923,104,F,F,179,F,F,F,217,F,F,F,1448,373,F,F,F,1247,F,F,F,F,F,F,F,F,F,F,F,F,F,274,F,F,F,146,F,F,F,12,592,F,1250,F,F,F,1349,F,891,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1411,1462,F,1124,F,F,F,F,801,F,F,F,F,F,F,1458,120,F,F,52,799,1446,471,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1264,F,1448,1257,F,F,496,F,F,F,F,F,F,F,F,F,F,278,341,F,F,499,129,29,F,F,F,F,F,645,F,F,F,F,F,F,18,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1395,161,F,F,1217,F,F,426,546,1345,F,1268,F,F,F,F,F,F,1765,66,F,584,F,F,F,945,1023,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,217,1337,F,F,F,1424,F,635,F,279,F,F,F,F,610,1314,F,F,F,F,F,F,F,F,F,1448,F,569,F,F,F,1131,1248,F,F,F,569,F,F,F,F,F,F,F,F,F,F,F,492,F,F,F,F,F,F,496,F,F,F,F,1475,F,1100,1365,F,F,1023,F,1367,602,F,F,F,F,F,1367,F,931,526,F,645,F,F,1088,1268,F,F,352,F,F,F,F,F,F,F,F,F,F,F,1145,F,F,F,F,1174,F,F,F,F,F,F,F,725,F,246,F,731,F,F,F,F,F,F,F,F,F,F,468,F,F,F,F,F,F,F,F,F,F,287,F,F,F,645,F,F,F,F,600,1345,602,F,499,F,480,F,F,F,F,F,F,F,F,F,F,404,F,F,1422,F,F,F,F,F,F,729,F,F,584,F,F,F,F,F,F,287,F,F,F,F,F,F,F,1345,F,F,F,F,F,F,F,620,F,F,F,F,F,F,F,F,F,F,F,F,F,376,F,F,F,F,F,1187,1332,F,1233,F,F,F,F,505,F,F,F,F,1297,F,1090,F,F,F,F,F,F,1238,F,F,1367,F,F,F,F,F,F,F,F,F,F,F,F,1419,F,F,F,F,F,F,F,13,F,F,F,F,F,1033,155,F,575,F,F,F,525,415,F,F,F,F,F,F,F,F,F,F,732,F,F,F,F,424,F,F,155,F,F,F,651,F,1488,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,588,F,F,1450,F,1427,F,258,379,1377,226,2052,F,F,F,F,F,F,75,F,978,119,F,F,F,999,1344,119,F,F,1448,F,F,391,838,1069,F,F,1124,F,F,1249,147,F,F,F,F,F,F,740,1446,F,F,920,F,F,378,F,F,F,F,666,F,1483,F,F,F,F,230,F,1267,F,1003,F,F,F,F,F,883,F,F,F,F,F,F,1257,F,F,F,573,F,401,183,507,F,F,F,F,F,F,F,F,F,F,F,507,1257,F,569,F,381,1413,318,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,461,F,809,1260,F,F,F,F,F,F,F,F,F,F,198,F,402,F,F,F,1257,F,F,F,F,F,926,F,F,705,F,F,1433,F,F,530,F,F,F,66,F,F,F,F,F,F,F,F,F,F,F,66,F,F,F,F,968,F,F,F,F,F,1260,F,F,501,F,968,1447,1172,540,F,F,F,F,1029,F,F,1483,F,F,F,F,F,F,F,505,F,F,F,F,F,F,67,F,270,1502,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,57,F,F,F,576,F,1325,1236,F,460,F,428,F,F,F,F,374,F,F,723,F,F,619,F,248,F,75,460,F,F,1219,532,F,F,F,F,F,F,F,148,F,F,F,F,21,520,F,F,F,F,F,F,F,F,248,F,1425,F,220,1078,428,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,F,F,F,F,F,F,F,57,887,1333,F,F,245,96,901,1375,942,885,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1217,1282,F,F,F,F,F,F,1338,F,F,318,385,452,1006,F,F,F,F,F,979,340,464,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1005,F,F,F,F,F,F,F,711,F,F,278,471,1260,1297,F,F,979,1340,F,F,1433,F,485,361,1362,F,915,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,198,1433,809,F,945,F,F,112,F,809,1128,F,F,F,F,F,112,142,1332,274,1231,F,805,728,F,F,F,F,F,829,1260,F,1331,F,119,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,740,562,F,201,F,1075,864,452,F,556,1268,F,446,956,1488,F,F,342,772,F,801,F,571,F,F,F,F,F,F,F,F,F,F,F,F,F,F,791,F,F,F,F,F,F,F,F,F,F,F,F,F,F,362,F,F,F,446,F,5,F,1077,F,F,F,F,F,F,F,F,1297,191,499,F,1411,1274,634,964,F,F,1054,1282,F,376,740,116,F,302,F,F,F,270,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,1177,1077,F,F,F,1032,F,F,392,F,F,F,428,1448,1329,1189,57,F,751,F,342,F,F,F,F,F,F,935,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,1118,F,F,809,530,F,1329,F,464,F,F,F,49,751,129,F,F,F,687,1060,84,1407,1110,F,1504,1373,1387,675,F,F,F,F,F,F,F,F,F,F,F,F,F,963,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,736,F,F,F,F,F,1469,F,F,F,F,F,F,F,231,F,1369,F,F,F,F,F,F,F,525,F,F,470,923,F,1379,F,704,F,752,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,893,F,511,F,F,F,F,F,1331,F,F,F,F,F,F,148,801,1118,F,F,F,F,F,1090,F,F,893,F,F,F,F,F,F,F,F,420,F,F,F,F,F,F,F,F,F,F,F,1331,F,788,F,F,F,F,F,F,F,1080,F,1369,F,92,505,F,F,F,F,F,F,F,F,F,1282,F,F,F,1323,1274,F,F,F,F,F,F,F,F,F,F,F,F,1282,629,F,318,F,F,F,F,F,F,F,F,1460,F,768,F,1468,F,F,584,F,F,F,682,F,F,F,F,F,F,F,F,925,F,F,F,F,F,F,F,F,F,F,1227,F,F,F,F,F,F,1089,F,144,F,1343,F,F,F,F,427,F,502,F,F,471,F,1277,188,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,F,F,F,F,F,800,F,800,1169,F,F,F,F,F,F,F,F,F,F,F,F,526,F,746,F,F,F,F,627,F,1452,F,1304,F,956,342,F,72,F,F,F,485,F,F,921,1260,F,F,F,F,F,244,306,F,F,F,F,F,1088,1190,2208,F,886,F,F,F,F,F,F,F,F,227,F,483,F,F,F,F,F,F,F,F,1277,F,F,F,192,F,F,F,70,999,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,733,F,F,F,F,733,F,1087,1275,40,1222,F,872,F,F,F,F,F,F,F,F,F,351,F,449,437,F,1458,389,F,F,F,401,F,F,F,F,F,F,F,F,F,F,F,224,F,F,70,466,F,F,112,F,F,F,1264,524,F,1333,F,F,F,F,F,F,F,F,F,F,F,F,F,F,330,1398,F,F,F,332,F,F,F,F,F,F,523,1367,F,F,F,F,F,473,F,F,F,F,F,F,F,F,505,1296,F,772,F,F,F,F,F,829,F,1075,F,1367,F,F,461,372,1229,F,1216,938,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1347,F,486,F,F,F,F,F,F,1136,F,505,1257,1246,F,F,F,706,F,F,F,F,F,F,F,F,F,F,F,F,1327,F,1332,F,F,F,F,706,849,F,F,10,F,F,F,667,F,F,F,F,F,F,F,F,F,F,F,295,1485,F,F,F,F,295,F,12,F,F,F,F,480,F,109,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1296,485,F,1510,F,921,F,F,F,F,1442,F,F,F,F,F,482,920,529,818,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1039,F,F,F,F,F,431,999,948,F,F,F,F,523,F,F,629,F,F,F,F,F,1078,F,F,801,1266,341,F,F,1006,F,F,F,1296,401,F,485,F,724,341,F,1027,1367,502,725,1989,934,F,2019,F,F,1341,F,661,417,801,612,1043,F,F,649,F,1367,F,F,F,F,F,F,838,F,F,F,F,F,1255,F,F,F,59,F,F,59,1306,F,491,F,F,59,F,F,F,F,F,F,F,F,F,237,F,F,F,F,1466,F,F,F,999,F,F,F,F,1098,F,F,F,F,F,F,F,486,F,F,470,F,F,1419,F,F,F,29,645,F,1279,F,F,F,F,F,F,F,492,1084,F,F,F,F,552,F,F,F,392,676,F,F,F,F,F,829,125,F,F,F,F,F,F,F,F,F,F,F,F,F,F,635,F,F,F,F,F,F,F,F,F,F,772,1006,F,F,F,1448,F,F,F,F,F,F,F,101,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,601,F,F,F,119,F,1231,F,F,629,F,F,1255,870,1362,F,353,F,F,F,9,1297,1103,1014,F,F,F,F,F,32,1360,F,F,F,F,F,F,F,F,478,F,F,468,F,F,1512,1274,F,731,F,F,F,F,349,1371,F,80,131,1209,F,80,F,F,F,F,F,349,F,1176,766,F,F,F,F,450,814,F,F,F,F,469,666,F,1168,F,941,939,1451,F,F,F,245,F,F,F,648,F,F,968,1466,F,618,F,1280,F,F,990,F,F,229,F,271,804,F,F,F,1079,F,F,1266,F,F,F,F,F,F,1446,F,F,F,8,187,916,F,1079,F,F,F,962,1089,250,F,1276,1103,F,461,F,F,F,1341,528,F,F,1447,F,F,F,F,F,F,F,1430,F,F,F,F,F,F,1304,F,F,F,F,F,F,F,F,F,F,F,663,F,1460,F,1136,F,F,F,257,F,F,963,F,556,449,523,F,952,F,F,F,F,F,F,F,F,F,F,F,F,74,F,F,F,F,F,F,F,1170,F,532,F,F,959,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,1379,F,F,717,885,F,963,F,F,F,F,F,F,F,F,F,F,F,730,F,F,1170,F,F,542,F,56,F,962,F,F,F,F,F,F,F,F,1176,F,161,F,F,F,F,F,F,524,F,1257,F,F,F,650,F,F,F,F,F,F,F,F,F,F,F,F,F,F,147,482,F,F,F,F,F,670,F,F,F,629,F,F,F,F,F,532,F,F,F,F,F,F,1457,672,F,F,F,F,F,F,F,814,F,F,F,F,F,968,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1320,F,295,F,452,F,F,F,F,F,F,F,F,F,F,F,F,F,F,706,F,F,F,F,F,F,F,F,1255,F,F,111,F,F,F,F,946,505,442,F,226,303,1400,452,16,507,344,1358,F,332,F,F,F,F,F,F,F,F,F,F,F,F,F,761,F,F,F,F,F,F,F,F,F,1039,192,F,F,F,546,F,F,F,F,F,F,1334,8,38,F,909,F,1079,318,F,524,886,F,1238,569,943,353,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,583,1131,49,486,1320,F,F,F,545,814,F,1289,F,1260,F,650,286,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,280,F,495,1366,672,303,F,F,383,F,1237,132,463,67,F,F,420,1208,768,922,F,1194,1369,1232,F,1253,361,F,F,561,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,1274,F,400,1320,1131,1116,F,1480,F,F,1198,1267,F,F,F,F,1400,629,F,F,1460,F,505,F,F,1170,F,F,1280,956,F,1320,523,F,F,1345,1446,F,544,1483,F,553,F,814,935,1229,1399,F,523,F,1486,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1320,F,F,648,F,921,F,F,F,F,469,F,F,F,F,923,F,1327,1131,F,922,399,F,F,F,F,F,F,F,F,949,290,F,1512,F,F,1400,923,483,F,1216,257,F,471,710,F,1303,F,F,446,1324,1271,191,450,F,F,12,F,75,F,F,F,F,F,F,F,F,286,F,F,F,F,F,F,F,F,1459,257,1359,922,1078,1314,1359,543,940,F,F,934,878,F,F,968,F,1350,F,F,F,F,F,1044,F,F,F,1157,F,444,887,1332,F,F,F,F,1199,116,F,1264,921,F,956,F,F,511,F,F,F,F,F,F,F,F,F,F,1438,F,F,F,1457,F,1257,F,1245,1451,F,F,F,1131,F,F,553,592,548,F,F,129,57,753,1460,420,1366,1341,694,F,F,923,403,704,640,648,1496,619,452,176,923,618,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,145,F,908,257,F,F,F,F,F,619,F,F,F,1342,F,F,F,F,252,F,F,281,359,449,F,1274,347,881,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1071,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,280,129,1346,577,569,F,1504,383,F,F,1379,1448,F,F,485,141,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,1503,10,425,F,F,909,F,246,1338,F,F,F,782,F,F,F,F,F,F,F,F,F,505,37,602,98,F,57,F,F,F,F,1421,923,F,F,F,F,F,782,F,F,688,F,583,F,751,F,619,870,F,F,F,F,F,629,109,F,231,619,F,F,295,F,52,532,F,F,F,F,F,F,F,F,1307,814,F,415,F,F,F,F,F,1267,F,492,728,814,F,F,F,F,F,F,F,F,98,1116,1407,1343,F,F,F,151,F,98,F,F,F,F,619,838,629,F,F,627,682,F,F,F,1155,F,F,814,814,F,482,F,619,F,780,F,F,648,651,F,F,1266,1367,F,1384,969,645,F,F,F,F,F,1367,464,F,F,F,F,F,F,F,F,F,783,F,1316,F,524,480,F,1343,F,F,F,F,F,F,F,F,1257,1039,501,1355,F,F,F,F,F,F,F,1077,512,1230,1337,518,F,F,375,F,F,F,F,468,F,F,F,F,F,F,F,F,307,F,F,F,F,F,F,F,F,F,F,420,F,F,F,F,F,F,342,F,F,F,342,1480,482,F,F,F,F,F,F,F,F,F,F,F,F,35,F,F,921,1079,F,254,876,1267,1028,F,F,F,F,124,1373,F,374,F,1252,F,328,F,F,121,F,790,532,F,1429,F,930,F,F,19,F,F,F,F,1233,1450,F,464,F,85,1459,57,128,1442,342,566,F,1510,1309,F,344,F,F,F,1333,F,F,77,F,44,1280,1080,1341,F,442,207,F,1343,1466,F,F,F,F,148,F,F,F,F,F,F,907,F,F,F,1345,F,F,F,F,F,F,F,F,1372,F,F,F,F,516,F,F,712,932,F,1343,F,F,F,1253,F,F,F,F,F,F,F,F,F,F,F,F,42,480,192,563,F,F,F,F,1142,F,420,932,F,F,F,1446,F,F,F,F,F,1039,F,F,929,406,F,F,402,F,F,491,1450,732,400,1080,756,295,23,596,F,F,F,1456,F,483,F,F,F,F,F,1397,F,F,F,1410,1343,F,379,F,F,399,1349,1050,430,37,753,723,F,F,1255,224,F,F,517,F,F,F,F,420,F,F,1189,566,627,1448,471,1389,F,254,201,F,F,F,F,F,F,F,F,F,1448,F,574,F,1273,456,640,207,938,501,F,1301,308,F,1215,1150,1448,F,F,F,F,F,F,783,712,248,109,F,F,501,632,F,1044,228,F,F,1131,632,407,F,F,64,F,190,252,688,800,1324,601,F,F,352,159,F,F,F,57,1496,471,F,599,1266,331,431,F,F,F,F,F,F,F,F,1334,1421,F,788,F,683,F,1370,F,792,F,F,F,F,F,F,991,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,363,F,492,905,F,F,F,69,F,F,493,F,F,76,F,F,F,F,F,730,452,F,F,F,254,F,1359,1331,49,F,137,482,F,1331,F,F,F,F,F,F,F,F,F,392,1325,F,F,F,F,F,F,F,F,F,F,F,F,147,F,1047,1324,1343,1343,135,F,F,420,464,F,F,F,F,1104,1131,990,1150,1447,F,318,334,F,F,F,F,1150,F,728,886,85,768,1173,22,1343,F,F,F,F,F,F,F,F,F,1324,F,1176,F,1332,1071,553,1190,F,1308,F,F,F,F,F,F,F,1362,F,29,F,F,F,1262,687,590,1392,959,F,F,201,1502,666,F,1262,F,F,F,F,F,F,F,F,F,F,1069,F,F,916,F,512,934,1343,F,814,278,F,505,1131,1422,195,F,F,49,392,1066,F,511,248,F,F,F,F,F,F,F,F,F,F,226,F,771,F,828,F,483,1323,F,830,F,274,1238,889,F,F,F,450,F,489,1338,536,601,596,1344,F,F,F,1208,F,F,F,F,F,F,F,783,F,F,F,F,1497,F,F,F,F,696,1110,1448,F,F,468,1475,F,471,F,F,F,F,F,159,F,135,F,F,1297,601,F,191,1072,753,1340,1332,1259,1503,76,F,F,F,F,F,F,F,F,F,F,1365,F,672,F,F,F,F,1208,1237,F,329,1071,F,F,F,F,F,F,F,F,640,F,F,F,F,F,F,1089,227,529,1365,1286,1332,1121,F,F,F,F,F,F,F,F,F,478,F,929,F,F,F,F,F,694,F,F,541,F,F,F,671,F,1359,F,F,F,F,F,F,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,1253,F,1346,1257,F,F,1415,1282,F,F,F,963,F,F,F,627,F,F,F,F,929,F,F,F,F,F,F,651,674,F,F,F,F,119,F,F,F,F,F,F,F,F,F,F,F,1444,F,1323,F,F,F,F,F,F,F,F,F,1347,585,F,F,F,962,F,342,F,1367,F,F,F,F,F,921,F,F,F,F,923,485,1369,F,F,F,F,F,361,530,F,921,F,351,F,970,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,492,F,1112,F,F,1343,929,F,F,F,F,F,F,F,F,F,F,629,F,F,1392,1343,F,90,1343,362,F,257,F,1445,1343,F,1384,224,F,1124,F,F,F,F,F,505,139,963,F,420,1266,F,F,F,F,F,F,968,505,F,F,F,530,F,F,F,F,228,F,F,509,F,F,F,F,F,F,74,450,F,F,F,532,F,1365,F,F,F,F,F,F,F,628,935,1103,569,F,1440,F,F,F,F,342,1069,F,F,F,179,F,F,1190,F,1343,F,1327,F,F,1216,1453,483,F,F,F,F,F,1316,F,1071,1306,F,F,F,F,F,656,1369,450,1444,F,F,881,F,F,F,532,F,F,F,F,F,F,F,F,F,F,1448,889,1372,F,491,F,F,F,866,F,F,F,1469,F,1266,F,49,F,191,F,753,F,F,F,405,F,F,F,142,938,F,F,F,F,F,F,57,F,F,936,F,1453,F,F,F,F,F,F,316,66,F,F,F,F,F,F,F,F,F,F,78,2106,F,F,F,F,F,262,968,521,1332,F,F,F,F,F,F,F,F,F,F,1262,F,F,1397,F,F,F,F,F,F,226,F,1254,1216,F,449,F,F,F,F,1257,F,F,F,F,598,F,324,F,F,F,F,F,F,F,F,F,F,450,F,F,F,F,F,F,F,F,F,F,F,F,1265,F,F,1054,F,F,F,327,F,F,216,F,342,F,161,F,F,F,F,F,F,F,F,264,603,F,F,F,F,F,F,F,F,F,F,1259,F,F,F,F,F,63,1367,1131,1259,F,F,F,F,913,F,1054,F,F,F,F,502,F,1343,F,F,1228,484,F,1228,1210,F,F,F,16,F,1172,146,F,F,F,978,F,1023,1345,885,189,1190,1346,F,F,363,1176,1442,1456,F,1341,575,1114,F,F,F,248,F,F,F,F,1280,F,1274,397,1217,332,1253,F,F,1300,F,F,F,F,F,F,F,F,F,1360,F,651,1325,F,F,265,923,1158,418,F,146,1114,F,F,F,F,F,969,F,F,420,F,F,F,F,F,F,F,F,F,F,1003,923,540,1365,111,144,1367,F,76,F,191,1457,F,1367,F,F,F,F,532,658,1035,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1096,F,F,F,F,F,1372,F,F,1423,F,F,1096,F,160,1423,1029,1265,F,205,724,246,F,F,F,1448,20,F,F,F,F,F,F,1297,F,890,F,502,579,1023,1268,1413,252,F,F,F,F,F,F,F,1341,F,F,471,1055,F,F,F,F,F,F,F,F,151,1341,1282,1448,1179,F,904,1266,F,F,1265,1124,F,203,F,F,F,F,F,1116,F,F,F,432,F,1268,F,1360,F,1367,F,F,1145,F,532,780,F,250,F,1343,57,F,F,F,F,F,F,1301,1343,1007,F,374,F,F,1343,F,F,F,F,1448,1283,F,485,F,1264,F,F,F,1427,786,1282,F,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,343,F,1067,F,1370,317,F,341,1255,1257,443,F,F,F,485,126,F,F,F,F,753,876,F,F,759,959,712,F,218,F,1262,1069,1161,442,57,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,800,F,939,F,F,F,F,F,F,F,1011,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,497,111,729,F,F,1343,F,1134,F,1255,1306,F,F,1258,F,1342,F,F,F,F,F,F,F,F,782,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,545,F,F,663,687,1471,F,F,F,F,F,F,1448,F,1291,F,372,511,1268,485,216,F,610,330,526,F,F,F,F,737,1262,F,F,F,F,1124,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,452,568,F,1136,801,F,F,F,F,F,F,F,F,F,F,F,1164,1460,F,F,F,F,F,218,F,959,1320,F,1266,F,F,790,F,F,F,1447,67,F,F,F,152,601,265,975,1273,F,1275,1229,1007,1234,801,F,26,1320,F,F,F,F,F,1110,1346,F,1367,F,F,F,F,F,627,480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,37,F,F,F,F,F,F,F,F,F,F,F,1257,F,518,F,F,1282,929,840,1290,285,484,1254,759,1325,923,766,148,446,F,1037,F,782,F,F,143,143,1949,F,489,1199,441,272,F,F,1262,1452,480,151,218,F,F,712,1334,F,F,F,F,F,528,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1080,F,F,1345,F,F,F,383,1255,F,F,F,407,1170,F,441,F,F,F,F,F,F,F,F,F,F,F,988,F,1341,F,1213,F,F,F,956,415,F,923,F,F,480,1170,885,F,F,372,F,1282,F,730,361,1139,1272,F,1087,F,F,342,F,F,F,F,F,1466,658,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1360,F,F,F,F,143,278,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,627,1329,627,F,F,671,760,1131,8,F,F,568,F,1433,8,1165,F,688,1208,54,F,F,F,F,F,F,F,F,471,461,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,592,F,1283,F,F,F,1037,F,F,F,F,F,F,F,F,F,1090,974,21,1210,F,F,F,344,66,F,1155,F,1273,F,1110,78,F,698,231,F,401,432,1257,231,1343,F,54,F,381,207,1039,F,F,F,363,1367,767,F,629,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1430,F,F,F,482,1011,F,F,F,153,F,F,F,F,F,F,F,F,505,F,F,F,F,F,F,F,F,F,126,1433,F,F,F,F,2153,1355,F,F,923,1480,F,F,542,F,1338,F,923,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1296,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1340,156,F,F,F,1205,8,900,F,F,645,731,8,F,752,1238,1352,800,F,F,F,78,F,658,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1015,F,F,F,F,F,F,F,686,112,F,F,161,F,1037,627,F,1114,F,629,F,F,F,F,1260,F,1322,213,F,656,F,F,F,F,F,F,F,723,F,1422,F,F,F,F,F,F,F,F,F,1472,F,F,736,F,629,F,525,F,F,F,1282,1282,662,661,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1165,1460,F,F,F,F,F,F,F,120,1267,F,F,1350,876,F,F,F,F,F,F,F,1280,F,502,F,F,F,150,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,429,F,F,F,F,F,F,F,F,F,1213,F,F,F,F,F,F,F,443,F,F,702,F,509,F,963,269,959,1384,F,1073,1384,F,F,1342,F,F,F,F,456,F,F,F,F,543,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,801,1110,F,1249,F,F,F,94,F,491,F,740,1061,F,999,353,F,F,F,F,935,F,1088,1480,F,F,F,F,F,F,1077,F,1170,F,F,F,F,1411,1419,F,F,F,F,F,331,740,F,1400,F,F,F,1448,929,420,363,F,F,F,F,F,F,94,F,405,499,F,1355,18,F,F,F,F,F,F,1411,F,1260,F,F,F,1301,1254,F,F,F,F,F,F,F,F,F,F,F,F,532,1869,F,148,F,1229,F,740,1402,F,F,F,F,505,1078,F,1257,F,295,F,F,F,452,452,F,629,F,F,161,F,F,1340,711,1301,1450,F,1343,F,F,F,F,F,F,640,F,F,F,F,491,492,524,F,F,1460,F,F,F,1254,F,F,F,F,F,F,556,555,629,56,363,F,1301,1044,651,553,F,F,F,F,F,78,58,1097,921,1055,F,F,F,482,F,940,F,F,F,F,F,1343,532,1424,F,1286,F,F,F,F,1215,415,1264,F,1216,1341,F,F,F,F,F,F,201,F,F,F,495,774,F,F,883,F,505,1310,450,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1361,832,1338,F,F,1347,F,564,F,F,F,F,F,F,F,F,F,1274,1270,F,F,F,F,782,F,1425,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,505,F,F,832,1055,F,F,F,F,F,F,F,F,F,F,F,F,F,487,F,F,F,F,1455,999,F,F,672,2113,833,F,78,1433,F,948,F,187,1496,F,1249,1254,F,F,812,F,F,F,1265,442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1188,510,F,F,F,F,505,431,1198,1433,900,511,776,F,F,F,F,283,F,1257,1282,F,1260,610,276,485,111,154,F,356,F,F,F,F,F,1271,234,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,1274,1283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1366,1266,627,931,F,F,716,F,938,1320,F,925,957,F,37,1442,F,F,F,F,F,F,F,F,F,1400,1098,F,F,1037,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,672,F,F,F,1280,F,343,1418,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1400,F,277,F,244,1367,F,688,F,1230,1311,501,1374,1448,1237,F,363,525,F,1324,216,712,F,F,F,F,F,F,F,342,5,F,F,1306,F,356,9,F,F,F,F,483,F,F,F,F,F,886,1447,F,F,838,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,864,F,1341,F,F,505,F,1483,F,489,1225,F,F,489,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,1136,1136,483,1116,F,1167,885,908,F,F,F,F,F,730,F,F,F,F,F,1338,F,949,511,F,F,531,F,F,1369,1010,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,33,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,70,F,F,F,1238,1398,945,1118,671,F,F,F,257,166,57,1458,693,327,897,1347,1309,F,20,1480,1496,F,F,56,F,F,F,F,F,607,F,1170,F,F,F,F,F,1178,491,F,F,F,F,1191,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,284,267,F,F,F,59,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,329,F,F,F,547,1448,1415,57,579,F,33,531,962,F,920,618,1280,1153,F,F,F,F,F,F,F,F,1118,F,44,F,F,F,F,F,F,F,F,1329,492,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1398,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1465,F,F,316,F,1065,F,956,F,910,F,1183,1044,1382,F,F,F,809,391,206,F,F,F,F,F,F,F,F,F,F,F,F,F,941,F,257,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,900,188,F,37,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1347,F,F,F,1267,F,F,1342,736,276,1456,F,563,F,F,F,F,F,F,F,F,1423,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,656,F,1447,121,272,1370,F,F,1137,505,629,375,F,F,F,F,F,F,F,F,F,F,35,F,F,F,F,F,F,F,F,F,F,405,640,1071,F,F,F,F,F,F,F,F,F,809,F,204,1237,F,1215,F,F,1121,F,661,F,F,F,F,F,1274,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,952,F,F,F,F,F,F,F,F,F,1257,F,1365,F,1444,1282,115,331,F,405,F,513,603,1265,F,F,651,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,619,F,F,F,537,1277,F,F,F,F,F,F,F,F,1382,F,F,F,F,F,F,F,392,945,684,1334,674,1142,F,F,F,F,F,F,F,684,F,F,F,F,F,F,F,F,F,F,F,F,23,122,F,1480,F,F,F,F,F,F,F,F,F,1178,F,F,F,1227,651,F,F,F,1367,923,F,921,F,F,F,F,485,78,F,1077,343,F,399,F,F,251,F,425,F,351,F,921,F,F,F,F,F,F,F,142,471,F,F,1262,1078,1448,921,F,415,F,F,501,629,F,641,F,938,F,F,F,1023,F,920,1077,F,F,505,F,F,F,F,F,F,44,58,21,535,885,F,F,227,F,F,1154,586,569,F,567,F,F,F,F,F,1077,1077,482,41,F,F,F,555,580,F,F,F,F,742,F,F,640,295,363,F,F,F,1233,285,F,F,928,455,F,443,F,F,881,F,502,F,F,F,F,962,1486,1456,580,F,1044,F,485,1235,915,1311,F,1060,F,F,F,F,F,605,1447,1187,F,F,220,F,F,F,F,F,F,F,F,F,F,F,F,F,1327,511,1447,F,F,1480,F,1382,120,F,F,F,F,712,F,F,F,563,1453,449,881,F,F,F,521,F,F,F,F,F,F,F,F,F,F,186,742,F,F,F,F,1277,F,273,1236,F,94,1365,274,540,888,F,F,142,F,F,F,184,F,1062,1324,577,F,1373,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,1442,F,556,921,1447,672,F,F,F,F,885,840,871,F,F,F,F,F,F,326,F,F,F,F,1241,F,F,723,F,F,F,F,F,1054,F,F,F,1296,F,287,65,F,F,F,F,F,F,F,F,F,F,1359,F,704,F,641,F,F,1280,676,938,244,F,142,142,F,F,F,F,F,186,F,F,F,F,F,F,F,F,F,F,104,F,F,F,F,F,F,F,618,1421,628,632,976,F,F,F,F,F,F,F,F,137,F,142,381,F,1490,155,173,F,F,F,F,F,F,F,620,1109,688,F,F,F,F,342,F,629,F,1030,F,F,F,1028,F,F,F,1023,F,F,F,814,F,F,1499,628,F,F,F,1089,F,F,342,F,F,F,F,F,F,F,F,F,F,57,F,F,F,235,F,F,F,F,1077,F,F,F,355,1152,F,F,F,F,704,F,F,627,F,F,57,F,F,F,862,F,1359,F,F,F,F,518,F,404,640,F,F,1250,928,269,F,645,F,501,F,645,F,F,F,F,F,1176,F,F,404,F,865,938,F,246,1379,F,F,F,F,F,F,F,615,F,F,F,1110,1283,F,F,F,F,1283,1273,683,F,F,F,F,F,49,1176,F,F,F,F,F,F,1277,F,268,F,F,F,F,231,1186,1469,F,F,F,F,F,849,F,1250,F,F,F,F,F,F,1283,1011,F,F,1480,231,F,F,F,207,F,F,F,1480,F,F,F,F,F,192,121,F,F,F,F,1328,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,1324,F,1441,F,F,F,F,F,F,F,839,1325,F,F,F,F,F,F,321,F,F,F,1324,1365,F,F,1168,344,47,F,1324,F,468,F,F,F,F,F,466,F,F,401,1325,F,450,63,F,F,F,F,F,F,1131,F,F,F,F,F,F,1486,485,294,485,586,F,637,F,F,F,480,F,1236,1479,F,F,F,1187,F,F,F,1385,1362,F,F,F,F,992,F,F,F,733,F,F,F,F,F,F,F,F,F,F,F,F,768,F,F,1198,F,226,F,532,F,F,1301,245,F,F,F,F,F,F,F,F,F,F,1273,F,F,F,F,F,F,1296,F,F,1361,387,F,F,1257,F,F,F,F,F,F,F,F,F,431,F,F,F,F,262,F,F,670,F,1302,F,1456,1268,464,111,1079,355,839,12,F,F,F,1279,F,F,F,F,F,F,F,428,F,F,F,F,F,F,F,F,F,F,946,372,1052,342,F,F,F,F,F,F,F,1400,F,F,F,F,F,F,F,F,F,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,251,1069,F,1497,F,F,F,F,F,F,F,F,66,F,F,F,179,1435,224,F,F,1249,956,F,F,F,648,F,473,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,101,F,F,F,F,761,F,F,257,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,1169,F,F,F,1199,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,373,F,F,F,F,F,F,F,F,F,F,F,F,F,146,197,1169,F,F,F,F,F,F,F,F,F,F,F,480,920,1035,1034,1308,16,772,F,1327,1087,1044,F,F,F,F,1187,F,F,F,F,F,F,F,1320,466,F,F,F,70,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,852,101,F,18,F,712,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,723,F,1172,F,F,F,F,F,F,1034,1304,545,F,F,F,F,F,F,F,606,F,F,F,F,F,F,F,F,F,F,F,F,F,F,66,192,F,F,1266,F,F,F,F,F,F,F,F,F,1211,F,F,F,F,1367,228,1352,F,1427,F,918,F,F,F,F,F,F,F,F,471,F,F,F,F,F,8,752,F,F,512,601,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,895,F,78,956,F,F,57,F,F,F,F,F,1355,F,F,F,F,F,629,F,F,F,F,F,F,F,814,F,237,F,F,F,460,F,F,F,1375,F,F,182,F,629,F,1423,651,183,F,F,F,F,F,F,104,1315,F,F,F,525,472,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1189,F,F,F,F,F,F,F,818,526,F,F,F,F,F,F,F,114,F,F,F,F,F,F,1395,F,F,F,F,1367,F,F,F,559,F,F,F,F,F,F,583,326,F,F,F,F,F,F,F,1379,931,F,F,F,968,F,F,F,910,F,875,F,F,F,F,F,F,F,F,F,373,1343,684,F,F,F,F,F,F,F,F,F,F,578,F,1308,805,F,F,F,F,F,450,524,F,F,F,F,F,F,F,F,F,F,F,F,F,1338,F,F,F,F,1257,F,F,1375,1155,903,282,44,F,F,641,F,F,F,F,F,F,F,1357,F,F,F,F,F,F,F,F,F,F,F,F,F,1317,F,F,501,F,F,590,F,1441,1090,F,F,1078,F,F,F,1360,860,1275,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,920,430,F,F,564,F,F,F,F,F,F,F,1338,148,F,F,F,F,F,F,551,1375,F,F,1221,804,F,188,F,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,522,F,F,F,F,F,F,1326,627,F,222,151,F,F,F,F,F,1341,F,F,F,F,420,F,F,F,F,1340,F,153,452,1462,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,956,287,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1190,1265,F,F,F,F,F,342,F,F,252,1258,1279,1441,941,1198,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,420,583,1134,F,1085,1177,F,F,F,1438,F,F,790,F,931,1345,F,636,1047,1486,886,359,F,F,511,1360,F,1055,F,730,852,F,449,F,F,F,1362,F,724,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1447,F,F,56,F,F,F,1069,923,1265,862,548,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1097,886,F,1384,1459,F,1118,517,F,F,120,316,1275,1346,446,712,1199,353,485,F,F,F,1341,F,1367,516,872,F,1274,F,379,F,F,378,1133,1267,1468,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,155,F,F,F,F,F,938,1168,1377,F,F,1052,645,F,1447,F,F,F,857,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,524,598,F,F,F,1485,F,963,383,532,1446,16,949,886,1502,F,930,F,214,F,483,1168,1005,F,414,1315,F,44,1446,F,283,F,F,F,F,F,F,F,F,F,230,F,F,F,F,994,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,353,F,F,358,1142,F,1218,1329,569,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,491,801,1069,37,1096,268,F,929,418,925,354,F,F,183,112,57,1340,341,294,F,610,426,627,F,F,F,F,1161,1069,F,1345,F,F,491,F,F,F,112,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,197,F,F,1341,F,F,F,1154,F,F,530,F,F,F,F,F,F,F,F,F,F,F,F,F,148,379,F,F,505,1433,450,706,F,F,F,1492,1112,F,F,327,1483,1483,F,505,1110,F,182,872,F,F,F,1336,245,F,618,1301,1005,F,854,530,1260,1338,9,F,1343,491,F,F,1114,1249,F,1039,1447,54,1469,F,F,F,497,428,F,148,283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,78,483,164,F,684,F,F,1014,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,450,F,F,F,F,F,228,F,F,419,974,1044,1421,1399,171,920,257,F,1413,1196,F,F,187,1023,F,682,F,F,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,683,947,F,F,F,956,530,F,F,F,8,492,F,F,F,F,F,F,F,F,F,F,F,1170,1241,F,F,938,F,F,F,858,474,F,F,8,F,1109,632,280,F,1142,510,57,1324,361,894,1367,1067,F,F,491,F,450,F,F,645,F,60,1124,499,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1475,F,F,F,F,417,F,F,F,F,F,F,F,F,F,283,F,F,F,F,F,F,F,F,1281,723,341,672,1152,F,F,656,1265,1033,F,F,197,1470,F,136,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,634,F,F,F,627,F,F,F,F,F,F,F,F,F,F,F,884,1217,F,F,1218,F,640,1277,181,458,809,929,629,F,F,869,1177,656,1253,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1351,F,1411,F,F,F,F,F,F,F,F,F,1365,F,1267,1307,1097,1257,F,F,715,1040,634,F,518,612,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1274,1492,F,F,F,F,F,F,F,F,F,F,656,F,1430,F,1433,F,619,F,F,F,F,F,F,F,F,F,F,F,F,276,F,F,F,F,F,F,F,F,F,492,1097,1510,945,F,611,F,F,F,F,F,F,F,F,F,F,F,F,1367,1343,800,F,F,107,F,F,F,F,F,F,1325,F,1011,F,F,F,F,F,F,1323,257,731,F,F,F,F,F,F,618,F,1227,F,F,F,F,F,F,767,F,F,F,F,F,1323,F,F,F,619,F,F,1044,449,F,F,1257,1257,F,1361,417,F,412,F,1219,1304,885,1149,1257,1257,70,946,1257,1365,1260,F,190,932,1261,F,F,1220,295,1361,1291,799,418,57,1073,F,F,1419,252,1366,F,851,F,400,1233,929,1341,F,F,1496,F,929,263,561,F,161,1340,F,F,417,579,F,F,F,F,F,F,F,F,F,F,F,F,252,1260,1483,651,1486,F,1367,473,F,1111,544,F,F,F,F,F,F,F,12,F,1361,483,476,925,446,446,F,252,F,F,F,F,F,1279,F,F,F,1067,1047,F,F,F,1280,1332,220,F,1282,145,1361,429,1047,F,F,F,F,1145,F,1458,F,7,F,F,F,974,1399,F,593,667,169,F,1361,F,F,F,F,F,F,F,1170,F,1077,F,F,F,F,F,F,F,F,1277,1260,F,F,F,F,F,480,148,1343,F,F,F,F,1088,1375,120,295,1257,1257,1351,1496,1381,F,F,1381,F,F,F,F,F,F,F,1142,1230,F,F,F,1286,F,F,1234,343,F,F,F,F,671,F,F,F,F,F,F,F,F,F,F,F,F,F,492,F,F,1323,F,57,559,394,F,F,1483,F,F,F,F,F,586,1455,1448,F,F,1199,F,F,F,1142,F,161,141,143,1460,F,220,F,F,F,F,F,F,F,F,57,F,488,F,F,1343,F,F,1375,356,F,F,F,F,F,353,F,F,F,F,F,938,F,F,F,159,159,57,F,F,F,401,F,F,383,75,1347,1475,383,F,629,F,F,F,F,F,295,223,F,F,F,F,F,94,F,F,F,F,F,1170,274,1343,F,F,F,257,F,823,F,1313,786,401,551,F,F,172,F,F,F,1411,1360,F,29,1078,252,853,959,F,F,F,1313,F,753,553,1361,501,78,F,F,F,F,1299,F,F,F,F,F,729,684,F,1313,F,287,F,308,1052,F,587,784,732,629,676,F,257,F,928,620,F,712,F,436,135,1448,F,383,213,F,F,F,F,1255,1160,F,F,1257,1331,1237,F,1496,696,1366,883,1343,949,1375,534,497,1304,49,F,F,F,F,683,1473,F,F,F,F,F,F,274,1273,F,F,471,F,F,F,383,551,F,F,1288,474,58,F,F,F,556,551,F,F,214,F,F,F,F,F,F,672,F,1504,1395,501,394,F,F,F,F,1322,306,F,951,F,F,244,1112,1131,642,F,F,F,F,1055,F,57,1238,1338,F,1418,F,1336,255,8,F,F,497,F,F,1121,F,458,1367,F,F,983,F,F,250,1499,31,F,947,F,F,486,F,886,F,F,1194,F,F,F,155,F,F,F,F,400,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,1313,F,252,F,F,60,1385,1190,F,F,F,F,F,F,1054,F,384,974,F,F,F,F,F,F,383,F,F,F,F,F,F,F,450,576,F,F,380,F,1124,F,155,F,561,F,271,F,F,F,F,1343,F,F,235,934,F,F,F,F,F,661,F,627,F,629,950,F,1235,F,756,F,F,F,F,923,F,496,1280,F,F,F,F,224,F,665,F,F,F,F,F,F,394,F,F,F,874,F,F,886,F,530,F,42,532,530,1080,F,F,F,F,1281,F,F,1013,514,910,1028,F,F,F,F,482,F,F,F,F,F,F,F,F,328,44,507,1021,F,885,F,F,F,F,F,248,710,25,25,1177,651,1073,1440,885,1255,F,1400,41,F,F,F,F,F,F,687,F,F,F,F,F,F,F,F,F,F,F,F,F,F,425,269,342,799,F,F,F,363,F,F,1005,1267,F,F,57,F,F,F,F,F,F,F,F,F,710,F,F,F,999,959,F,F,F,78,F,425,783,1323,F,F,F,F,F,F,F,870,1128,F,1218,F,962,627,237,F,505,505,402,491,57,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1030,35,183,F,F,F,772,36,F,F,998,486,1118,F,F,F,237,F,F,F,F,F,F,F,F,F,F,F,F,F,1265,1419,712,F,F,1483,F,485,921,F,F,F,F,1007,1134,999,1255,998,998,F,F,F,F,F,F,F,F,F,1142,F,1118,F,F,F,F,F,F,F,627,F,F,206,1488,719,1259,F,707,814,F,1128,F,874,F,F,57,248,F,F,963,939,F,328,1124,F,F,F,F,F,F,1301,F,F,F,999,482,F,F,963,645,F,F,F,F,F,F,F,F,1037,F,F,590,F,207,1338,76,F,505,F,963,963,F,F,F,723,978,F,70,129,F,F,272,F,F,F,F,F,F,F,F,F,982,1265,F,F,1158,963,814,F,F,1087,671,F,590,F,F,F,F,F,741,742,F,F,F,228,F,F,1347,F,F,1277,F,F,F,485,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,328,1452,F,F,F,383,F,F,F,F,F,112,F,656,F,F,84,F,F,F,853,1112,232,1440,F,F,1052,F,168,F,520,F,F,F,F,F,556,146,F,F,F,F,F,452,629,1044,F,F,860,1236,1254,F,F,F,1350,F,F,F,F,1044,245,F,F,226,F,F,F,F,F,F,F,F,F,F,F,1198,430,909,F,1447,823,801,F,F,F,F,F,999,391,F,1446,F,F,483,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,318,F,F,505,414,F,F,F,1422,F,1260,F,F,1483,F,F,1258,896,F,F,46,F,492,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,493,F,F,1382,F,F,F,F,F,F,F,48,712,F,F,1398,1472,F,581,F,F,55,F,860,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,1152,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1213,683,F,F,1149,F,F,F,F,F,F,F,F,F,F,F,F,F,F,10,F,F,F,F,F,418,1458,F,287,287,355,F,F,F,F,F,F,F,F,F,957,F,1233,F,753,1435,1242,1472,F,504,872,F,F,F,F,F,F,F,1124,F,F,F,F,526,F,F,920,96,F,1204,1044,F,1216,F,F,456,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,741,1450,F,F,F,1109,F,F,F,1343,1233,18,F,F,F,F,F,F,F,F,F,F,1123,F,F,F,F,F,F,F,400,1217,470,F,F,1304,1389,F,1485,60,F,218,1344,969,F,F,458,766,1382,F,1168,F,F,F,F,F,1341,1149,1065,1109,F,1289,F,F,1361,F,F,329,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,556,F,F,F,342,F,F,F,F,F,F,742,F,F,F,F,F,F,F,888,F,483,941,1451,355,1369,146,F,F,F,932,F,1510,F,1280,710,F,450,F,888,1316,F,1262,1168,F,F,768,164,F,F,F,F,F,F,F,F,F,1254,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,464,1313,1160,F,941,F,F,499,F,F,F,F,F,F,F,F,230,29,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,231,568,F,523,1044,512,F,F,751,828,F,1109,F,1090,1470,342,F,F,1392,1280,607,1189,F,F,F,F,F,F,1433,F,F,96,F,1375,F,F,1456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1149,F,F,F,1323,672,1324,F,F,F,F,F,1399,1096,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,404,1458,F,1005,1005,F,F,544,485,359,F,F,F,1282,F,F,852,491,F,F,F,1446,1411,F,443,F,579,F,78,F,F,1039,12,493,F,1165,F,F,1114,F,725,1345,1149,404,F,F,1010,1238,F,F,1112,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,923,F,1424,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,266,342,1071,1124,1343,634,F,430,F,1438,F,1399,F,206,206,F,F,F,F,F,F,F,F,F,335,628,568,F,F,F,1277,1361,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,426,F,F,F,F,F,420,559,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1367,F,F,F,F,F,462,1134,F,596,F,270,493,907,59,F,F,F,F,1313,F,60,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,505,29,F,804,1268,1039,F,F,455,163,F,F,852,645,246,92,F,1411,F,686,F,F,F,480,F,F,629,1351,F,1237,57,405,F,888,F,F,63,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,442,F,F,F,650,295,F,1346,603,F,1334,F,F,1309,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,603,651,1257,441,F,501,1480,F,F,F,1446,F,F,78,1164,11,F,F,1315,619,1392,470,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1260,442,F,318,492,192,1382,F,86,F,1359,F,F,280,861,F,F,F,F,F,F,F,F,1142,F,864,F,F,F,315,1257,F,F,F,F,F,F,F,F,F,1334,682,F,F,58,F,511,629,F,F,F,F,F,F,F,F,F,F,F,F,1325,270,F,F,704,F,374,983,96,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,F,F,F,F,F,F,F,F,F,F,F,F,F,383,1098,F,F,F,F,F,1323,57,F,F,F,F,F,F,63,142,587,F,479,F,F,F,F,F,F,F,F,148,F,1250,F,197,1448,F,F,F,F,F,F,1103,F,F,388,915,1304,1110,F,1254,F,F,F,F,F,F,F,F,F,F,F,F,341,F,1080,F,F,471,464,853,F,F,F,F,F,F,1459,F,1341,F,F,F,F,629,1054,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,741,F,362,F,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,302,311,F,F,1304,1341,F,F,F,F,F,F,F,F,F,1350,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,146,F,F,F,F,1341,F,F,F,F,F,F,F,F,F,F,F,246,470,430,F,1412,F,F,F,F,F,F,F,F,F,F,F,F,1377,1265,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1266,612,1063,1080,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,66,520,1248,501,342,F,1270,542,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,783,F,480,483,596,F,F,F,F,338,1054,642,740,135,F,F,751,F,F,F,F,F,F,F,F,F,F,F,F,665,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,287,F,F,783,F,482,F,F,1460,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1124,287,F,F,F,517,F,F,1385,470,1351,450,650,1246,420,F,F,F,774,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1260,F,355,F,F,F,F,431,482,1272,1044,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1210,F,1427,1090,F,1361,491,F,F,F,F,F,1398,F,F,F,989,F,F,F,1423,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1020,F,F,F,F,F,F,1322,F,F,F,F,F,F,207,F,F,F,F,F,F,F,F,F,F,F,483,1056,F,F,F,F,F,F,F,F,F,F,295,610,1151,F,1460,F,F,F,F,F,649,F,1407,F,529,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1071,480,F,F,F,587,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,1456,F,F,F,17,F,1480,F,F,F,F,1288,F,740,449,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1150,F,F,F,F,57,F,F,F,F,F,F,F,F,F,262,F,F,F,F,F,542,F,63,F,F,F,F,480,645,204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,989,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,1314,F,641,F,F,F,F,F,F,F,F,F,F,F,1044,F,F,1080,F,F,F,1282,F,F,F,F,F,F,F,F,1274,F,F,F,1336,602,1343,F,F,F,F,F,633,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,78,100,F,F,F,F,F,F,F,1334,F,F,F,F,F,F,F,F,634,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,1257,1448,F,F,F,F,1257,F,F,1375,F,F,F,F,F,F,1267,F,1481,F,F,F,F,F,F,F,F,1424,F,F,F,F,F,1497,F,766,F,F,F,F,F,F,235,645,766,F,F,F,F,F,F,F,F,870,F,526,F,F,F,F,F,F,F,F,676,F,683,F,F,1092,1058,F,F,683,F,328,F,F,F,41,F,F,F,F,629,F,F,1296,F,F,F,F,F,F,F,F,F,F,229,F,F,F,F,F,F,F,F,F,144,F,F,F,1424,F,F,1119,F,F,1070,F,F,F,F,F,F,F,364,F,F,F,F,F,1364,F,468,135,F,502,1460,1087,F,F,1275,F,F,F,818,F,F,F,F,F,F,495,F,495,F,F,F,F,F,F,F,255,F,F,F,F,F,935,959,F,332,F,F,F,F,F,1422,553,F,F,F,F,F,F,F,F,F,F,257,1399,F,F,395,1039,331,F,F,496,F,1323,1448,F,F,F,629,F,F,F,651,F,F,F,1341,F,964,862,378,F,488,430,F,884,F,526,F,135,F,F,645,1080,910,1273,F,888,683,201,F,1366,F,F,F,565,1282,F,F,F,F,1229,1324,874,F,F,F,142,F,F,F,F,1168,135,57,634,488,F,1186,F,F,F,1167,F,F,F,F,F,257,F,1090,627,686,2202,F,205,F,F,F,77,1208,916,F,650,331,F,78,122,F,F,229,1146,235,F,F,629,F,1318,1318,1419,F,F,1341,F,920,F,F,F,F,F,F,F,452,F,1185,F,567,F,F,183,1360,331,F,838,1175,511,886,137,888,F,507,400,F,F,F,F,F,F,F,F,F,F,1472,F,F,450,156,1090,1157,886,999,999,F,447,881,F,F,F,F,F,F,29,F,1262,F,F,F,948,800,F,1157,965,F,F,1280,F,1430,454,1283,F,1082,F,F,1199,F,635,57,158,F,1288,F,1283,341,F,F,F,F,507,F,F,F,343,F,F,1161,1067,F,F,128,178,F,F,F,F,F,F,978,447,49,F,91,F,F,F,F,759,F,F,F,1301,257,F,F,121,1365,1452,F,F,F,627,1082,F,F,F,F,F,272,F,709,465,F,1158,F,274,1168,1073,715,F,F,F,1438,947,888,1154,97,1332,F,1300,35,383,F,F,F,85,F,F,F,F,F,F,380,78,F,F,F,1243,F,F,485,F,F,F,F,F,593,F,F,205,742,200,849,1355,F,F,F,F,710,556,705,262,460,286,495,1124,F,F,F,F,109,1142,F,465,532,1315,F,F,1296,F,730,976,610,F,F,1448,1237,F,F,F,F,1039,F,F,F,1392,F,F,F,F,F,11,1238,F,1852,F,F,1422,F,1349,362,471,F,968,F,F,F,F,F,645,524,24,618,F,702,651,F,F,629,484,F,F,F,469,1286,1080,1433,F,F,77,F,F,F,111,F,F,F,111,512,21,44,F,F,1325,F,452,F,1365,F,57,171,F,484,F,F,F,761,F,710,1450,F,1338,268,1337,F,F,F,996,1344,F,428,F,768,1184,344,760,1385,F,450,F,136,1216,F,F,161,342,F,F,F,F,F,F,F,41,F,F,F,F,247,91,672,909,218,1338,F,1342,F,F,F,1271,54,1457,F,1341,F,688,F,580,F,F,1448,F,F,F,F,1220,245,1090,645,1397,1448,782,F,F,F,F,F,F,F,F,F,116,F,F,1274,1394,F,F,1367,270,114,1280,1327,F,1267,40,F,F,F,F,F,F,F,F,2265,F,959,F,450,1386,532,F,417,F,F,F,F,F,F,F,F,12,1398,F,F,1047,F,1268,147,1324,F,12,F,F,F,1433,532,F,F,629,F,615,F,F,91,F,672,F,486,F,F,1264,1277,1322,1301,F,283,1350,468,1168,828,1259,F,F,1199,F,F,F,F,F,F,F,F,F,F,F,F,1224,136,F,F,F,441,F,1168,1306,1382,F,F,F,F,F,F,363,F,F,F,665,116,862,483,F,1142,F,F,1257,F,F,1274,F,1035,486,1124,464,F,213,F,1142,1094,F,F,F,F,F,F,F,F,F,342,629,F,1067,F,1154,F,F,F,F,F,F,250,F,F,F,57,F,380,201,F,929,F,F,618,1124,F,F,1490,425,F,F,F,F,F,F,F,148,101,F,F,1250,1274,645,2225,F,F,F,F,F,F,57,F,463,F,1257,145,1301,782,1323,F,1282,1411,F,1131,F,1260,49,978,1109,31,401,540,136,F,F,1304,F,F,F,F,F,F,F,295,629,1257,463,1124,F,125,F,F,F,F,F,672,1323,F,F,229,227,1326,F,1417,526,F,285,1035,598,1124,F,F,F,F,F,F,1399,F,F,F,57,F,F,1347,F,425,F,645,F,F,426,1327,F,F,F,F,F,1109,1523,956,F,F,619,1280,F,1080,F,F,F,F,F,F,671,F,974,632,F,F,F,F,F,1277,F,1349,F,F,1280,F,F,F,F,F,F,651,1359,F,F,F,F,230,602,1274,F,F,F,F,F,F,F,1343,F,F,F,1252,F,1343,1215,84,F,1286,F,F,1110,509,F,21,312,F,753,1020,F,F,F,F,F,F,F,F,F,F,F,F,F,220,485,F,F,F,F,1124,956,21,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1173,1360,F,1216,F,1226,1362,F,265,F,F,1259,564,F,F,F,956,278,287,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1257,946,1124,F,F,656,F,1228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,132,1457,F,712,F,F,F,F,F,F,968,F,F,F,F,F,1364,F,1343,728,F,F,598,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,799,F,31,F,F,F,265,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,651,1365,191,248,1448,F,F,F,1011,F,F,493,F,F,F,F,F,1230,F,1909,F,866,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,672,963,F,1259,218,F,F,F,F,452,683,F,615,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,375,F,F,F,F,653,F,F,F,F,F,F,610,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1397,428,1273,F,F,428,629,F,F,252,363,F,F,F,F,F,F,F,F,F,F,461,F,F,F,F,F,F,295,F,1262,F,F,F,F,491,921,1262,1361,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1442,F,F,F,F,F,F,F,F,1469,F,F,F,122,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,782,F,F,F,F,F,F,F,485,1173,F,F,F,F,1324,F,F,F,427,1284,651,F,31,51,F,F,F,F,378,651,F,F,592,928,502,F,302,1332,271,F,F,F,F,F,479,273,873,F,F,F,F,F,F,F,F,1370,F,F,665,1289,F,F,632,1332,1257,1332,F,1257,F,F,670,F,1325,F,F,968,F,F,F,F,982,1222,1496,318,1343,274,1131,F,885,F,418,F,1304,F,375,F,248,F,768,F,F,F,F,F,F,287,1219,F,F,814,F,F,F,F,F,255,464,F,F,1168,318,F,F,F,1253,14,F,905,F,F,F,F,418,F,356,627,F,281,344,768,F,105,F,F,F,F,504,F,953,F,1349,1271,F,F,F,F,450,F,F,1124,F,F,F,F,F,F,362,295,1304,F,F,F,F,F,F,F,F,1257,F,F,F,546,405,504,171,618,437,1506,F,F,F,F,899,F,F,242,1257,618,F,1055,F,F,F,F,F,F,F,F,F,F,670,F,280,537,F,F,122,F,1280,1219,1433,F,1466,F,F,F,658,618,F,F,F,224,353,F,F,F,F,F,F,1080,F,F,1324,F,F,F,F,353,F,F,F,1323,F,F,F,F,F,1128,F,F,F,1450,F,1080,F,F,F,F,1076,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,465,1347,F,F,724,F,F,F,F,F,F,982,F,F,1271,F,F,44,F,F,F,171,F,F,F,F,916,F,F,F,F,F,F,F,553,593,F,F,969,F,424,148,706,F,F,F,F,1413,F,F,F,331,F,131,F,F,F,F,F,F,513,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,F,768,768,1190,F,F,F,978,1497,885,1361,F,1087,F,F,F,F,F,F,F,F,F,F,645,1082,1205,F,360,1061,1216,F,774,F,F,F,1217,374,255,F,F,F,F,F,F,F,F,F,F,724,35,F,1280,1112,1187,401,F,F,342,401,F,F,F,F,F,F,F,F,F,F,401,F,F,F,F,F,F,F,1459,F,598,F,679,1174,979,F,F,265,F,F,F,F,F,F,F,F,F,F,F,F,F,530,1323,F,F,1010,227,F,F,712,F,F,F,F,F,674,F,1304,F,1257,F,F,F,F,F,F,F,694,920,F,114,F,F,1056,420,905,F,F,F,482,F,F,F,F,629,F,1367,31,F,1164,F,F,F,F,155,F,F,155,F,F,F,920,1257,44,F,F,F,1338,F,F,396,F,1460,F,F,F,618,618,111,F,F,F,F,F,F,F,F,F,F,396,F,F,F,F,F,F,F,F,F,F,F,F,257,F,F,F,F,F,1319,F,F,F,F,814,1087,F,1448,F,F,F,1448,F,F,1448,887,F,518,518,1343,1362,F,518,F,F,F,F,463,F,274,F,F,F,F,F,F,F,F,F,1158,945,947,1286,119,485,F,949,F,F,F,F,F,F,F,274,1445,F,F,851,F,1255,F,1241,F,F,F,F,F,57,F,F,42,F,760,511,1158,640,F,F,F,F,101,1411,F,F,F,F,F,147,1317,585,1347,F,F,F,F,F,F,F,F,661,928,F,422,1058,415,111,F,501,611,F,F,F,F,F,F,1257,F,F,78,F,F,F,F,F,F,F,1447,F,1214,342,F,449,F,814,1343,1472,F,F,F,112,F,F,F,1125,F,F,1379,F,F,F,F,F,F,274,F,1257,173,1289,501,1069,F,F,F,F,F,F,F,F,F,F,F,1231,42,325,493,968,1343,265,1304,768,483,F,F,1440,921,278,1345,F,521,886,1286,678,91,653,66,235,237,F,F,F,F,F,597,F,1257,526,F,1275,F,512,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1226,F,783,1270,974,945,1198,1303,471,F,F,F,741,1237,F,1360,1208,224,F,F,F,555,768,F,344,1367,1447,F,417,5,344,F,F,F,F,F,F,F,F,F,1326,F,F,1078,F,120,F,F,F,148,1379,1094,F,1124,1033,F,295,1443,6,1136,85,F,976,1343,1324,F,768,1254,F,F,F,F,F,F,F,F,F,F,629,629,F,1257,532,1077,F,1319,F,F,F,F,F,F,F,F,F,F,F,F,137,1350,57,135,F,F,1410,1217,452,1165,1352,56,818,634,1286,1366,F,F,F,F,F,F,F,44,F,751,278,F,234,921,F,F,F,F,F,F,F,1094,F,F,1274,1451,1475,F,60,F,1237,1257,F,242,F,1279,861,814,F,F,67,1067,327,742,923,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1055,1136,F,F,F,F,F,F,F,F,F,F,F,483,F,F,227,530,672,F,20,F,F,F,1343,1089,1131,1238,1228,160,F,F,F,F,1250,F,F,F,F,F,F,F,F,F,57,F,1346,478,F,F,542,818,F,F,F,F,8,F,629,F,1415,F,F,F,F,F,F,F,F,F,F,672,F,F,F,F,60,862,F,F,F,F,F,F,F,401,1121,723,1267,663,F,923,F,F,F,F,F,122,1343,423,F,633,394,F,1237,F,F,F,F,532,618,674,629,F,886,F,F,F,F,F,462,F,F,F,F,F,F,F,F,F,F,F,399,F,F,F,F,524,F,F,242,F,F,F,327,F,F,F,F,F,F,1445,F,717,F,463,F,F,F,853,56,F,907,F,F,F,F,F,F,306,F,463,F,F,F,F,F,F,F,F,F,F,F,F,F,126,F,684,340,F,F,F,F,F,F,F,F,155,F,1496,774,1276,F,F,F,F,F,29,672,F,684,F,F,805,1399,F,F,F,1460,450,F,F,468,1155,155,F,F,F,F,F,F,465,268,F,F,F,F,F,F,F,F,737,F,78,F,F,248,F,243,915,F,1114,155,F,F,F,F,1334,724,661,F,F,F,F,632,F,F,67,F,F,F,F,687,F,F,F,F,1039,1510,F,F,F,210,651,1443,887,39,F,F,974,F,F,885,776,885,79,44,310,F,742,753,1222,1427,1448,198,F,F,F,F,1315,483,401,F,142,F,F,F,420,1277,974,F,186,F,343,F,F,F,F,F,948,672,974,251,929,F,F,F,F,125,1142,42,F,F,274,51,448,F,F,F,F,1412,1412,F,F,F,F,974,694,418,F,F,656,672,F,1483,F,887,1456,F,1395,F,823,F,F,471,F,F,1311,F,596,F,F,F,F,F,828,1324,979,783,F,596,396,272,F,F,670,F,F,F,492,1282,923,F,F,1273,F,F,406,505,708,F,F,1261,584,F,F,F,F,F,F,1358,F,414,730,1334,F,F,1240,F,F,F,F,F,F,F,F,F,629,530,1252,939,F,F,F,F,F,F,F,F,F,F,F,255,161,F,F,1134,F,F,150,F,F,967,1067,F,F,F,F,F,F,F,1323,724,526,F,F,F,F,F,F,F,1199,F,F,F,F,F,F,825,751,F,F,F,F,F,331,F,F,F,F,F,F,F,F,18,404,450,94,281,414,501,F,F,F,F,F,F,F,381,F,F,F,F,509,F,F,1327,F,F,F,F,F,F,F,114,F,F,F,F,F,F,F,F,F,F,F,468,F,F,F,F,F,F,F,963,553,F,F,F,F,F,F,F,F,F,950,1343,F,F,541,501,F,F,154,84,370,499,1445,F,F,1243,F,70,F,F,F,1296,318,F,F,1341,173,1334,F,F,1326,274,1324,F,723,F,F,F,F,F,145,761,499,F,843,F,405,1313,F,F,342,1310,341,876,753,1257,1250,1054,F,F,1257,923,733,F,F,F,F,F,F,F,F,227,F,156,F,F,F,F,F,F,F,F,327,739,F,1313,1304,1110,525,711,41,F,1341,385,801,F,1341,F,1512,F,F,832,F,F,F,F,F,F,F,F,F,F,F,F,252,318,1343,1080,F,F,198,1440,F,F,F,F,F,1080,501,448,306,F,F,F,F,620,1313,373,F,1082,F,528,F,F,F,F,F,F,F,505,1236,F,1084,514,F,1302,153,F,F,F,F,F,F,F,F,F,495,758,F,F,1367,F,F,532,F,F,F,F,1188,F,F,1274,F,268,F,405,706,1231,1304,1234,1134,530,1375,F,418,F,1069,F,1280,656,1016,F,F,F,F,F,F,F,57,F,F,784,F,F,F,1230,521,972,F,F,F,F,F,F,F,F,F,F,F,801,F,728,1134,F,936,F,F,420,1480,728,1304,F,606,505,263,126,1448,324,487,537,480,920,523,1476,F,252,501,1317,F,1421,F,F,F,F,F,F,F,F,F,F,1445,F,700,449,1282,1078,399,F,F,F,F,F,F,F,F,F,F,F,1304,F,F,483,F,177,F,712,1010,1301,463,1047,525,F,F,F,583,F,446,392,385,F,728,257,57,636,596,1054,672,1260,F,F,1119,F,F,F,F,F,F,F,F,F,F,F,F,849,F,623,F,F,F,F,F,F,F,F,569,399,F,F,1257,862,1039,534,444,394,526,F,772,455,363,629,380,1170,F,696,1165,218,F,F,F,920,1367,501,740,372,725,1238,F,1168,921,F,F,137,268,F,862,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,420,F,F,F,730,694,672,920,561,F,257,923,499,546,938,730,1052,F,F,F,F,F,F,491,627,556,1304,F,F,F,F,F,F,702,335,122,470,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,1237,394,483,1508,480,1280,F,F,F,F,1131,F,1011,F,1161,F,F,F,1444,588,1272,731,F,1260,F,F,F,F,F,F,F,F,F,F,1021,F,F,F,F,F,F,F,F,F,F,F,F,295,740,1459,1492,724,F,1257,F,1154,F,489,124,483,F,F,F,F,F,F,F,F,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,431,112,923,731,1440,569,1338,1453,F,491,F,864,F,F,468,F,744,658,F,F,F,F,F,F,F,1104,F,700,627,F,F,1109,1341,F,F,F,F,629,F,F,F,1279,1161,1297,F,F,1309,629,723,1236,723,F,F,F,F,F,F,F,F,1334,601,651,1349,1349,629,492,399,F,F,F,392,1282,F,F,1067,F,1502,F,F,F,F,F,F,F,545,618,F,F,F,F,F,60,F,F,1089,843,1304,F,F,F,428,F,F,F,F,400,F,1418,605,202,1448,333,946,F,1399,768,823,1343,F,F,191,1077,492,1488,1324,1349,F,F,F,1011,F,998,1260,F,392,542,F,1255,F,947,191,F,1399,1282,F,1367,1390,174,628,628,1304,728,1304,1011,F,F,401,999,F,F,710,F,947,204,204,204,F,F,F,F,F,F,F,F,F,1252,F,312,22,F,F,F,F,F,F,928,1448,1179,F,F,F,F,F,F,F,F,F,1448,1446,F,463,155,F,1448,F,F,F,1351,F,F,F,1255,42,F,442,1071,F,532,587,F,F,1342,1320,F,F,54,F,F,F,575,929,F,F,1427,F,541,1056,F,F,12,1433,1448,F,F,F,F,F,F,1448,F,501,F,F,F,F,F,F,1110,916,851,F,F,F,F,F,F,F,1480,F,F,F,F,F,F,1349,F,460,F,1320,F,F,1078,853,917,F,F,F,706,115,F,F,F,F,F,F,F,F,F,1377,F,F,383,F,F,F,F,F,F,F,F,F,F,F,F,227,F,782,F,1433,F,450,F,F,F,F,F,561,F,257,1185,F,F,391,F,F,955,1079,488,20,767,902,487,F,F,F,F,F,F,F,F,F,F,F,F,1435,85,1249,F,113,F,F,F,F,782,1935,F,F,F,272,391,956,F,998,1342,278,619,F,1453,573,290,1265,252,840,295,1355,1255,561,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,1447,1315,F,F,1444,F,F,1327,F,480,483,783,F,F,F,F,1320,672,F,F,341,1029,161,1237,F,343,561,1112,549,F,1343,456,F,F,F,F,F,F,F,1366,F,F,F,F,629,F,F,649,273,295,F,F,F,936,272,F,F,505,186,1268,F,F,F,360,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,235,F,F,F,441,F,F,F,F,F,F,F,F,F,F,1488,F,F,923,1216,441,887,373,F,814,F,F,563,F,F,1447,F,F,F,F,F,F,1276,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1067,1365,495,F,F,F,F,F,922,138,1033,F,1136,930,471,F,1055,295,F,F,F,F,F,F,F,F,958,F,556,F,F,1245,1483,483,F,F,F,F,219,F,216,F,F,F,666,546,592,246,942,F,753,F,F,406,441,129,432,F,F,F,F,F,F,F,F,101,1433,F,F,402,F,F,F,F,F,F,F,F,F,1301,882,532,F,F,354,1110,F,1131,974,F,1253,1323,884,1276,861,F,F,F,F,F,F,F,F,F,F,F,F,F,596,F,F,F,F,F,F,F,F,F,52,1440,483,F,F,510,632,559,F,1454,F,F,F,F,F,F,F,1398,F,615,920,76,F,F,F,F,F,F,F,F,1346,900,F,F,F,F,1119,688,F,246,272,641,1480,F,F,F,F,F,F,F,F,125,F,F,F,F,F,F,F,139,1142,F,F,F,F,F,974,235,F,F,983,F,910,F,F,F,F,F,F,1450,1279,F,F,495,963,619,88,972,F,F,F,F,F,F,1273,620,F,F,F,603,F,F,601,F,595,F,595,F,F,1367,F,F,F,F,F,502,947,482,F,F,354,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,1445,F,F,64,1073,521,F,F,F,F,429,342,F,523,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1511,1341,F,F,F,F,1268,1341,F,1112,F,181,F,F,753,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1419,F,1315,F,F,1005,F,480,F,F,F,1060,F,F,F,1085,F,F,F,F,F,F,F,F,F,F,F,F,F,1362,1367,F,F,537,F,F,F,F,F,F,1483,672,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,147,588,F,F,F,F,1478,F,F,F,F,F,446,F,F,1303,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1488,1352,F,F,1457,F,F,658,F,F,F,F,F,F,F,F,F,F,F,833,F,F,F,F,F,F,57,F,148,F,1497,333,672,917,F,F,F,F,F,1469,F,1433,1077,1366,672,635,F,F,F,532,642,49,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,392,F,F,F,F,F,F,F,19,F,F,401,742,F,1324,601,F,F,78,F,F,1390,1361,F,F,F,F,F,F,F,F,F,F,1343,800,F,F,F,F,F,800,406,537,F,1077,F,1267,F,929,974,585,F,F,F,F,F,F,F,F,F,1065,F,480,F,1230,F,F,F,F,F,327,327,F,1367,F,F,F,1445,388,F,505,706,430,F,1361,F,F,F,F,F,F,274,1110,F,629,F,F,505,823,24,1365,F,F,F,F,F,1445,F,F,F,F,F,F,F,F,F,430,553,F,F,F,F,274,486,F,136,F,181,430,1417,F,F,F,F,717,F,F,430,1485,1458,F,F,1216,F,F,F,F,F,1508,F,1005,287,497,F,F,F,F,F,F,F,F,F,F,437,F,49,753,F,F,F,F,1496,F,F,66,F,572,487,F,F,F,F,1479,F,1297,F,F,F,F,F,F,430,F,938,F,F,F,F,F,F,F,326,1073,F,F,F,1475,579,1400,1265,F,57,1343,F,126,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,F,F,F,F,F,F,F,F,F,F,F,1229,F,F,F,F,F,F,1252,569,1249,1288,553,F,518,275,463,F,F,1448,104,1002,483,F,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,512,1329,F,F,F,F,1489,F,F,F,F,F,F,F,95,F,F,F,F,F,F,F,1110,629,383,126,F,326,656,F,505,1376,F,F,1448,F,F,155,66,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,682,491,F,174,1100,F,688,537,502,F,1170,1410,F,F,1343,F,191,789,486,F,F,F,F,F,485,F,F,F,F,F,F,F,F,8,F,F,F,F,F,F,F,F,F,492,F,F,48,F,316,1847,1508,252,359,360,610,F,1480,F,F,F,F,F,F,F,F,F,F,452,F,F,1208,F,F,F,57,524,F,F,F,F,F,F,455,F,F,F,142,F,F,F,577,229,363,1280,F,505,F,93,F,F,F,F,F,F,F,F,1496,F,916,F,F,1089,84,F,F,F,F,F,F,F,F,F,F,818,1324,1456,F,723,F,59,F,1273,F,F,F,F,672,627,F,F,F,485,F,740,620,F,1448,1359,59,F,F,F,F,F,F,F,761,980,980,F,F,F,F,F,F,F,809,F,F,F,F,F,F,F,968,1433,F,F,F,F,620,F,F,F,F,F,F,230,532,F,F,F,651,F,651,1323,F,F,F,1333,1441,920,8,F,831,707,F,F,F,F,F,F,F,544,F,518,1324,733,F,1345,1228,1334,1219,886,1131,F,F,F,F,F,F,F,F,564,F,F,442,F,745,651,1343,1069,F,1512,F,F,F,F,F,F,F,F,F,F,F,1198,F,1358,F,1222,400,444,F,F,F,1080,F,1296,F,0,F,F,F,F,F,F,F,142,F,561,1343,1329,1186,270,112,658,F,F,F,959,1308,1069,578,1192,F,931,F,157,F,F,F,F,1242,F,663,11,F,F,F,F,F,F,544,F,1333,F,342,F,F,F,52,602,928,252,F,F,502,399,F,1296,F,F,556,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,1251,F,F,F,F,F,F,F,F,F,F,375,F,F,F,851,553,F,F,F,569,F,F,F,F,F,1173,380,695,F,658,F,F,F,1238,1242,F,F,F,F,F,F,376,F,1199,818,F,F,730,F,F,F,605,931,702,1433,F,456,1355,511,F,717,F,F,342,F,F,F,963,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,658,344,228,F,375,F,207,F,F,1290,F,F,F,F,F,F,1198,1085,F,F,F,F,F,F,F,F,F,F,F,F,956,F,F,F,F,F,F,F,F,998,F,F,F,F,F,F,F,629,F,F,F,482,1218,F,F,F,F,F,F,F,F,F,F,F,1190,F,F,F,F,F,1150,F,651,F,1343,1011,F,855,F,F,F,88,F,F,F,1375,974,1460,415,F,F,F,F,F,F,F,F,F,F,F,F,311,415,F,F,F,F,F,F,F,F,F,F,F,F,F,82,904,645,F,F,F,F,F,F,F,586,342,1174,1250,F,523,F,F,F,F,F,F,F,F,1438,F,342,F,F,F,F,F,F,F,661,F,F,F,1260,1173,F,F,F,485,F,F,F,1334,F,201,F,F,868,945,F,F,F,F,F,661,484,F,F,F,F,F,1190,1341,F,123,F,F,F,F,F,374,F,F,267,F,F,F,F,F,F,F,1270,F,F,1187,F,1466,1342,1343,1485,922,F,F,113,F,F,F,F,F,F,F,F,F,283,F,F,150,672,283,F,319,1080,1170,482,958,1103,137,F,F,467,1081,F,F,1377,F,F,F,F,F,661,F,703,378,F,F,F,1265,753,F,1071,F,907,1334,962,979,F,F,F,526,F,F,1346,27,814,F,154,F,F,F,F,F,F,F,F,F,998,172,814,629,495,551,104,153,1461,1485,1327,F,F,F,F,F,F,F,1242,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,485,F,F,F,688,959,283,39,119,F,78,F,145,1435,708,F,485,737,1372,F,1253,1448,904,F,F,150,F,F,F,F,F,728,325,213,723,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1345,707,251,253,F,930,F,F,424,1446,524,805,F,F,F,728,383,F,F,F,F,F,1461,814,1480,F,1338,194,F,1301,F,1260,F,F,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1590,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,510,F,F,525,231,274,F,1336,514,556,684,1237,1198,1360,860,F,886,263,F,1237,135,493,F,1110,1480,1120,F,1011,F,1365,F,F,F,F,295,F,F,571,F,1460,F,F,F,F,F,1262,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,342,1158,1257,154,1370,686,104,1055,656,F,1260,482,1342,1150,F,450,F,F,213,364,F,1080,1035,444,1268,F,F,1262,F,F,F,F,761,1136,F,1418,F,341,1039,831,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,947,953,F,F,F,F,F,F,F,F,F,F,F,F,471,1100,227,849,753,929,148,858,530,F,F,129,645,74,568,228,155,1189,228,703,452,640,1266,F,101,672,173,1253,702,F,F,F,1485,F,274,F,F,1101,344,526,1456,F,255,1234,174,929,1210,F,632,63,627,F,F,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,344,207,271,F,1390,661,1315,956,482,931,F,492,1060,287,1087,85,1301,266,F,978,F,1327,1014,650,492,248,329,252,1504,F,819,F,F,F,F,1127,1174,12,F,F,104,263,1069,274,1168,501,1504,1424,492,228,227,1116,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1421,1186,1448,F,F,1360,858,F,F,F,F,F,F,F,F,F,F,628,F,F,930,F,1131,525,8,363,526,1212,57,928,78,471,F,493,F,F,378,1127,F,F,F,F,188,937,F,F,F,1323,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,252,F,F,740,F,F,F,F,F,F,F,F,F,910,652,505,1460,383,F,F,275,1430,F,1062,948,728,F,905,192,154,F,F,1023,F,F,F,F,F,F,1174,F,F,F,F,F,F,F,F,F,F,F,F,F,F,656,686,671,1492,F,F,F,688,462,F,1177,1208,936,650,42,870,1419,F,629,F,F,F,1168,450,F,F,F,F,F,645,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,470,962,1308,F,514,257,1129,F,1238,F,1323,1322,33,F,496,800,629,450,923,1450,F,F,F,F,F,F,F,F,57,F,F,1365,257,650,629,1480,485,523,F,332,F,1367,F,F,F,F,F,F,F,F,F,645,1382,930,504,392,F,1480,F,F,344,F,F,F,F,F,F,F,1039,F,F,201,F,F,F,F,F,F,470,F,F,F,F,231,661,1343,F,F,F,F,F,F,F,F,F,F,F,F,1022,1375,F,246,F,F,F,F,F,F,354,1387,1055,1367,79,F,263,316,F,1367,F,1069,F,F,F,F,F,374,F,740,1212,F,F,645,F,F,F,F,F,F,F,F,1410,873,F,728,F,744,318,F,768,1112,1341,486,1460,F,F,F,F,F,31,1367,F,909,F,F,F,460,96,499,F,F,F,1150,F,F,F,F,F,F,F,F,1448,F,729,551,F,F,F,F,1331,280,969,85,161,940,656,78,F,546,331,F,F,F,F,F,F,F,F,F,235,F,F,F,270,363,F,F,F,F,F,F,F,F,F,F,F,F,F,F,651,1258,F,801,1453,1453,F,F,F,F,154,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,802,482,F,962,F,F,F,F,F,F,F,F,F,F,F,F,540,F,F,1268,F,430,F,648,F,1483,F,F,F,851,201,F,F,F,F,112,F,F,F,F,F,1452,83,F,F,F,F,F,154,1260,1023,1266,1039,733,F,316,1445,F,207,F,F,F,F,F,1264,F,F,F,F,F,F,F,F,F,840,627,1496,F,205,1399,F,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,F,1479,207,915,F,316,1150,F,F,F,F,F,F,F,F,F,1483,1497,1453,998,648,1150,F,F,F,F,F,F,F,1080,F,F,F,206,1483,341,F,F,F,F,1277,F,F,F,F,F,F,332,F,F,F,F,F,F,1268,493,F,331,F,F,F,F,629,753,F,F,F,F,F,F,1359,F,F,F,F,F,480,F,962,F,F,F,F,808,F,F,F,728,F,F,923,F,F,430,F,634,F,F,F,F,F,F,1512,F,F,651,F,F,1458,F,809,F,F,484,994,505,354,F,F,1343,F,F,1454,F,1255,F,F,F,F,372,201,F,740,F,F,F,1315,F,1445,1275,F,F,342,450,F,F,F,245,F,532,255,F,F,F,F,F,F,F,F,F,1084,F,F,1233,F,F,F,F,F,F,768,245,1080,188,1087,F,1222,135,316,F,381,1369,F,F,392,F,F,F,F,F,F,F,F,945,F,1421,224,1064,F,F,F,F,F,F,F,1453,1272,744,1486,464,728,F,1260,1448,860,286,F,F,F,190,756,F,131,F,1343,378,F,F,F,F,F,F,F,F,F,F,F,F,512,F,F,F,F,F,F,F,F,F,F,F,1407,904,1338,505,F,885,F,F,1044,1472,517,F,F,656,1366,F,523,F,F,F,F,840,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,712,F,F,F,F,F,F,F,F,F,F,F,136,F,1469,805,565,504,456,F,F,1283,1510,1325,532,F,F,F,F,449,1456,1067,F,1324,F,1282,257,F,F,136,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,492,485,F,F,186,442,F,F,F,F,F,F,F,F,218,F,F,F,540,1288,471,492,1456,1412,344,148,51,840,F,F,F,485,930,F,1230,850,57,1109,F,F,F,F,F,F,F,F,512,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,326,F,F,627,F,F,629,886,F,F,F,F,F,F,1131,656,430,475,1151,1109,1448,78,F,F,F,1260,F,F,F,908,977,F,761,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,F,224,F,F,F,F,F,F,F,922,254,814,1100,F,1060,589,1131,F,268,257,F,F,F,F,F,F,F,374,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1468,405,F,F,F,F,F,F,F,F,F,F,F,1301,963,F,F,1315,F,F,499,1433,F,252,1032,49,F,1136,931,F,F,F,1301,F,1315,F,F,753,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,596,1459,1456,F,629,F,226,524,F,1379,121,682,F,F,1039,F,632,F,1500,F,F,600,1100,945,F,F,F,F,F,F,F,F,F,F,268,F,F,F,F,F,1255,723,F,485,F,F,145,F,800,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1332,F,F,596,F,F,688,F,1131,341,F,F,619,1237,F,F,F,F,F,F,F,F,191,F,F,F,F,F,629,F,901,F,535,525,F,596,F,F,F,F,F,F,F,F,F,F,F,485,F,F,740,F,1334,58,F,F,F,192,1110,F,F,1110,F,F,430,F,F,F,F,F,F,F,F,F,F,780,F,257,F,F,135,1379,F,1299,1087,F,121,741,632,1344,1288,1235,383,1194,1139,326,217,814,201,1511,505,1309,78,509,1346,1301,F,1365,1296,F,923,42,1289,375,F,F,1503,F,F,F,504,F,540,F,F,1289,42,1087,1367,F,1455,1421,F,F,F,F,F,F,1450,F,F,F,112,F,179,658,F,F,F,F,1128,F,1459,F,F,F,F,F,F,F,F,60,F,F,F,1286,F,1320,F,F,651,F,F,F,F,1320,F,F,F,F,1185,F,F,F,F,F,F,F,F,F,F,F,F,246,F,F,F,F,F,F,F,886,452,107,F,F,F,F,F,F,1172,757,529,F,757,F,526,657,F,652,F,F,F,657,452,F,F,F,F,F,F,F,F,F,F,F,F,F,342,450,F,F,295,374,381,F,F,F,F,388,F,F,F,F,F,F,F,690,F,316,688,723,342,656,F,F,F,F,F,1280,381,F,1268,78,F,485,F,F,F,F,F,F,F,967,672,F,F,F,F,F,F,F,F,F,1109,F,F,757,1367,420,F,F,F,F,F,1375,228,F,F,F,F,1365,491,F,F,356,F,F,F,F,F,F,F,F,F,F,F,101,1072,657,F,F,F,F,499,F,F,1124,1124,1452,F,F,F,F,F,F,642,F,1308,672,F,485,F,F,1323,F,F,F,F,672,F,742,1168,F,F,F,463,F,F,1343,1151,F,1254,F,F,F,482,F,F,F,F,F,F,272,588,F,535,F,F,F,F,1077,776,910,F,F,1087,970,F,F,F,F,F,F,992,F,F,329,F,F,1142,1212,F,1327,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,284,187,F,383,329,F,F,1002,F,F,F,359,1271,F,1271,447,F,1157,1055,1327,1486,F,1370,F,F,F,F,F,F,F,1122,F,F,173,1271,F,31,F,703,F,343,595,628,F,505,1359,F,F,F,F,1367,F,148,F,173,1343,1052,F,F,F,483,1322,F,F,1255,183,706,F,F,F,F,342,486,380,382,487,F,F,F,1266,F,511,1486,665,F,F,380,F,F,F,990,F,1052,F,F,532,1190,1361,F,F,F,F,F,F,F,F,F,F,F,F,491,272,F,450,F,F,F,1035,1367,F,F,F,F,F,700,F,1445,1322,359,F,458,F,F,F,F,F,968,F,F,1328,F,1503,F,F,F,1274,1343,1324,442,1365,F,F,F,148,F,146,F,F,423,1039,853,1142,328,145,F,F,442,1313,F,F,F,F,F,F,F,F,1447,F,F,F,F,963,F,F,F,F,1257,342,F,1087,415,F,910,F,189,F,F,F,F,F,F,F,118,F,F,441,F,F,F,F,871,1069,1274,F,F,1306,189,1186,910,F,F,F,F,F,F,1142,111,F,F,1496,480,1304,1325,118,F,1216,F,F,F,F,F,F,1266,1306,446,462,363,152,57,441,441,146,F,112,F,F,F,F,F,F,F,F,F,F,F,1412,F,1416,1142,F,F,910,1142,F,1360,342,187,218,1141,F,656,F,F,187,F,441,F,420,593,F,1077,F,F,F,F,1190,471,430,897,1367,F,F,F,F,F,F,F,F,F,F,1265,419,F,910,F,F,F,F,F,F,596,480,F,F,F,F,F,F,F,F,1147,F,F,F,611,F,1090,F,F,F,235,251,F,F,F,F,F,F,F,F,F,F,F,F,1296,F,F,1234,F,134,772,F,532,F,F,306,306,841,843,F,F,F,F,F,F,1468,F,F,F,F,840,F,645,619,F,21,F,F,F,F,141,F,F,399,968,365,F,375,1063,F,F,F,F,598,1442,1343,402,1235,679,F,F,F,1078,1349,1075,1199,57,F,1399,1452,F,999,921,344,104,F,F,F,F,627,702,F,632,63,F,F,F,175,1343,F,F,F,860,F,F,F,F,1343,577,F,F,63,F,148,963,753,1433,1047,1047,F,F,F,F,F,F,1331,374,772,F,F,1282,F,F,1175,F,F,F,F,F,F,1336,F,F,1044,F,F,F,F,F,1037,F,F,250,1304,F,F,F,F,F,963,F,F,F,F,F,F,441,1076,1188,F,F,F,F,F,F,F,F,F,F,F,F,F,288,F,F,F,F,640,F,444,628,F,1272,F,F,1072,F,F,341,F,F,F,F,F,F,F,1324,1233,920,286,F,456,929,F,1282,F,F,F,F,F,F,F,F,F,F,F,F,190,1073,F,F,F,308,F,1288,F,1210,1325,F,645,F,F,728,F,F,F,F,F,F,F,F,F,F,F,1490,F,1483,F,450,1350,632,217,1173,1175,F,F,F,F,F,F,F,F,F,998,F,8,F,F,8,1433,404,672,1427,728,640,1433,F,F,F,964,191,F,F,1185,F,1150,1421,450,F,897,F,217,998,F,F,F,F,F,783,F,F,F,F,778,229,501,F,F,F,F,F,526,307,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,404,1226,F,F,F,F,814,F,509,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,887,148,F,F,F,F,F,F,F,887,1343,271,1221,1314,F,923,1055,F,F,1301,F,F,429,864,F,873,F,1293,F,147,1149,1504,1500,923,271,F,F,F,F,F,F,F,F,F,F,F,F,F,1103,F,F,767,1257,F,F,F,F,F,F,F,F,F,130,1343,F,F,1442,F,F,524,224,F,1028,F,F,1460,1230,383,F,1028,33,489,700,F,F,F,F,F,F,F,F,F,F,F,F,1218,F,923,F,F,F,F,1470,1216,F,F,905,F,F,F,F,F,F,F,F,F,881,580,1341,F,1282,1373,433,F,446,1442,184,1080,1221,F,1280,F,F,F,F,F,F,F,F,F,F,F,F,373,F,F,F,F,F,F,F,F,F,F,F,F,295,F,F,F,F,F,F,F,F,F,570,768,F,F,523,1309,962,135,687,430,1076,776,F,430,112,1322,372,814,F,405,1323,393,F,F,1448,613,F,F,F,F,F,F,273,923,962,532,F,F,F,F,F,F,F,F,F,F,F,F,F,F,332,F,1304,1210,F,418,569,F,F,1069,1448,F,868,1442,629,1229,F,317,1286,F,F,1320,F,F,F,F,523,1072,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,707,F,1213,1480,1257,1347,512,1212,F,F,373,485,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1468,F,F,1181,F,1445,F,483,F,F,F,1350,1238,F,463,1186,120,F,F,585,928,33,111,1217,775,504,F,1322,F,1199,F,1242,F,191,F,F,1304,1347,F,F,52,F,F,F,F,1992,F,F,F,F,1384,217,F,F,F,814,525,446,F,F,F,373,F,F,F,F,F,F,F,F,446,543,374,F,470,1282,F,F,1260,418,728,F,1246,476,1035,1286,1433,480,F,374,F,F,1027,509,1219,F,F,F,279,145,F,F,F,F,F,F,F,F,F,F,1257,F,F,F,F,728,1392,1033,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1212,1448,1242,F,F,1345,1213,F,150,1399,F,1274,751,201,F,F,59,1297,F,F,1341,F,F,F,F,F,F,466,F,1410,1134,474,526,F,F,F,F,F,F,F,F,F,F,F,F,F,201,F,F,482,1315,1133,109,F,1343,F,F,F,F,63,F,F,F,532,629,F,F,872,F,F,F,F,1381,1338,F,57,1435,1435,F,521,1433,F,F,1090,F,F,F,1257,F,F,F,F,F,F,F,F,F,F,F,F,1303,771,1266,402,1238,F,F,483,1023,F,F,266,838,274,1442,569,F,F,744,F,F,F,F,F,F,F,F,F,F,F,39,471,F,F,1488,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1030,F,1164,1341,F,1367,F,F,F,1334,819,F,155,476,F,280,F,923,1351,76,818,465,F,F,F,F,F,F,F,F,1351,F,F,F,39,F,F,F,397,619,1507,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,492,F,F,F,723,F,1277,F,F,F,F,F,1286,F,627,F,F,F,F,F,F,F,F,940,F,F,F,F,1237,768,F,865,F,618,F,F,682,F,F,F,674,F,371,F,674,F,F,963,F,F,F,682,F,778,F,F,682,1375,F,F,F,F,F,1104,F,F,730,1232,104,491,1233,F,F,F,F,F,486,F,F,F,F,461,F,F,F,634,1486,27,1084,F,F,1229,F,F,F,1087,F,F,F,F,399,1257,F,1005,1334,F,F,359,F,F,F,F,F,F,1375,F,F,1355,1222,78,F,F,F,F,1286,F,F,888,78,F,F,415,1418,1250,F,1338,57,415,F,F,F,F,F,F,F,F,F,F,F,148,F,F,1448,F,799,F,F,F,1253,7,F,F,F,F,F,7,1366,148,F,F,514,1448,1448,1448,524,F,F,F,F,418,F,F,F,904,F,F,1333,F,F,F,F,F,1360,904,F,753,F,F,F,F,F,1512,910,F,1311,581,1343,910,F,F,F,F,F,F,F,F,1475,F,F,F,F,F,F,F,799,960,197,F,F,F,F,F,F,1333,F,F,F,F,329,F,F,F,1262,F,495,111,F,F,1277,111,F,F,F,F,F,142,207,F,F,F,956,F,1367,F,1365,F,F,F,1243,F,111,1366,F,F,F,F,F,F,1510,234,F,F,530,234,1349,F,334,F,F,F,1247,F,F,511,923,F,949,F,592,F,1149,1265,F,1172,F,592,F,1175,F,450,1457,1419,1142,F,1172,1142,1142,455,1324,1184,F,1184,1142,F,F,F,F,F,F,458,F,487,923,F,1142,F,F,1149,456,F,F,1469,454,F,F,601,F,F,F,F,F,F,F,F,F,1506,1343,342,1255,F,342,F,261,1142,F,F,F,F,F,F,F,131,F,F,996,969,F,362,F,F,F,F,342,248,253,1357,F,489,F,F,661,F,F,1357,886,F,478,956,F,316,1253,1190,423,F,F,1149,F,F,F,F,F,436,F,F,F,F,F,1176,F,F,F,1453,F,F,29,1282,232,F,509,F,F,F,F,F,449,42,F,263,F,F,F,838,1238,1365,F,1288,342,1268,923,1198,F,F,483,F,1352,F,244,1235,1257,F,858,F,1074,1361,F,6,493,F,378,1020,F,F,F,376,F,F,1047,1154,F,F,F,F,F,F,672,18,F,923,1297,F,222,F,F,F,311,1238,F,283,640,315,465,532,1142,1508,985,93,1165,F,F,456,1301,F,1419,F,F,F,354,F,F,882,93,1279,218,F,F,F,485,F,F,F,F,628,F,F,862,F,F,F,661,629,1258,1165,F,F,651,F,F,F,F,628,978,651,F,F,F,402,F,F,F,F,F,907,753,857,F,F,24,F,F,F,F,F,F,F,921,F,F,1323,F,F,F,F,F,1222,15,F,746,742,1316,723,F,F,390,F,F,499,F,F,F,F,351,F,F,F,F,F,90,1255,1433,991,566,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1429,1450,959,404,374,915,452,732,F,F,1172,F,F,F,F,1233,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1458,218,1296,766,F,F,528,F,F,804,F,F,F,F,F,F,452,1044,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,F,1139,F,1110,F,F,F,1343,F,F,F,F,F,F,F,956,1448,687,1005,F,921,1366,1453,1327,1267,756,155,468,518,521,895,F,F,F,F,502,F,389,F,753,F,F,F,F,F,F,F,F,F,F,1257,916,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,485,F,F,F,F,F,F,1242,44,1342,342,1110,528,485,F,801,F,48,F,F,F,F,1304,F,F,948,78,F,F,1232,1435,F,1250,1061,1398,1328,F,F,1117,814,F,F,F,F,57,F,F,F,F,F,F,F,199,934,F,F,F,F,F,1277,1445,1065,F,F,1448,881,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,255,F,F,F,F,F,F,1250,F,1447,57,F,329,F,F,F,F,F,2036,F,F,F,35,F,F,F,F,959,800,78,283,F,1079,1301,124,1301,1336,728,F,F,F,1283,1480,344,F,F,887,1313,F,1367,1266,1367,1365,F,523,1139,563,F,F,1442,723,356,F,F,F,F,F,761,1259,57,F,344,F,F,F,F,F,1277,F,F,F,518,F,F,379,F,F,F,F,F,F,F,F,F,F,F,F,146,518,518,F,F,1044,F,324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,342,F,F,F,1230,1301,77,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,428,F,1280,890,1366,F,1173,886,F,1079,576,482,F,F,1410,772,757,F,342,274,F,F,1075,112,F,146,400,741,1153,29,934,F,1480,1238,1315,F,736,1384,1360,F,1362,F,1052,430,687,1446,F,F,F,514,1438,F,F,723,1360,F,1250,22,F,F,530,1005,197,1445,F,F,F,F,F,F,F,F,450,1326,F,537,1065,568,F,F,930,F,F,723,F,F,F,F,F,1177,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,F,F,F,F,F,F,F,F,F,F,F,F,F,F,146,F,1293,F,476,F,F,246,605,F,1395,104,1136,1498,1128,F,1262,F,1282,F,F,505,1360,F,380,372,F,F,F,537,466,483,912,1252,F,1343,F,F,771,F,999,774,F,905,1055,253,483,454,280,564,1142,F,444,F,1087,F,F,F,437,330,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,803,F,F,F,159,F,F,F,F,F,F,F,F,937,F,F,F,F,F,F,F,F,F,F,F,1260,450,1117,1250,F,415,1005,723,F,1029,F,1253,F,1360,F,1149,1069,F,F,F,922,F,405,928,1265,F,F,F,F,1128,672,F,F,920,253,F,F,921,487,1360,1258,129,F,F,F,F,F,730,667,56,F,F,F,F,F,F,874,F,F,F,1438,1069,121,344,F,F,F,F,962,1110,F,F,1502,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,886,F,F,197,F,F,F,F,F,359,272,F,341,392,1036,1119,492,913,F,94,52,758,1427,1275,F,524,1087,492,627,F,173,612,F,431,450,381,1424,505,1273,F,271,418,487,1273,483,1089,607,482,1052,F,F,1157,1483,1098,F,483,161,485,1069,650,640,F,1029,F,11,1011,F,1168,228,F,461,F,1023,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1013,1252,526,461,623,672,F,F,1149,1407,F,F,F,930,F,F,F,1257,F,F,F,F,F,F,F,F,F,189,1065,F,F,F,F,1023,F,F,712,963,F,78,354,F,945,530,229,124,1327,430,F,482,F,75,F,715,F,F,F,281,19,512,672,733,252,431,F,491,F,F,455,378,F,F,672,342,470,F,1402,F,511,1110,975,F,F,F,F,F,228,F,1230,59,F,F,F,F,487,F,F,F,F,F,F,227,518,1266,78,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1262,F,63,F,F,F,910,F,F,1036,44,1047,1237,F,97,672,F,F,F,F,F,F,F,F,F,F,228,F,383,1381,36,355,F,F,128,485,643,F,956,493,672,275,1125,1332,1346,F,1142,1332,512,159,342,1370,1062,F,76,231,1080,F,F,670,945,682,910,F,723,505,F,F,482,F,F,672,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,126,740,723,492,F,F,91,F,1124,F,F,F,F,F,F,F,431,1023,F,1483,561,372,1110,F,F,F,F,F,1168,1422,1282,1102,147,1359,671,725,645,1112,F,F,1257,316,341,1071,1168,118,1375,F,341,493,248,F,F,1435,1280,226,F,F,F,1446,F,F,F,1304,F,F,F,F,814,318,723,742,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,665,272,F,1422,493,420,228,1040,493,1150,501,908,F,905,F,1469,F,640,1486,F,1480,F,452,F,F,F,F,1260,F,723,526,740,1266,F,587,723,491,F,F,F,828,F,248,1035,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,161,1446,930,687,F,1480,F,F,F,1512,418,1130,380,F,156,485,1343,1365,F,F,F,F,F,F,F,828,800,1020,F,F,F,648,F,F,818,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,939,1332,344,1099,587,963,266,1089,F,F,F,F,F,627,524,1014,F,F,F,1411,F,F,F,1274,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,1078,250,629,244,1390,F,682,94,F,F,17,F,F,492,F,255,F,F,1350,F,F,F,F,F,F,F,F,F,F,1343,230,828,F,1375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,628,627,452,F,1362,F,F,F,F,F,F,F,780,F,F,F,F,F,F,F,139,F,F,F,F,F,F,332,66,F,F,F,F,703,355,480,F,198,F,1361,F,F,1362,F,F,F,1301,F,F,F,F,1304,451,670,F,1262,1343,F,F,F,F,F,F,F,451,452,1485,F,F,F,F,F,F,374,1207,1252,651,381,1450,F,F,F,F,F,F,F,670,F,F,F,1498,F,F,F,1190,1261,430,F,F,F,F,1375,F,F,F,F,F,774,78,449,923,1088,934,1453,1334,381,F,F,F,31,544,F,F,F,F,F,F,F,430,485,450,1323,F,F,F,F,182,262,958,446,F,F,428,F,F,1498,F,1266,F,F,1264,1260,F,F,1039,F,F,F,363,1260,F,F,F,363,F,F,687,F,363,556,F,1085,1460,F,1165,1320,801,F,F,F,F,F,F,F,684,1128,F,F,121,F,F,1255,F,1364,F,F,F,F,F,F,F,F,F,1397,F,1343,1257,442,968,1233,147,1260,1174,1378,F,1340,483,468,339,F,343,F,F,483,1307,F,F,1147,F,274,F,F,F,1370,F,F,F,248,F,F,1459,1144,F,999,1311,1367,318,43,F,964,F,85,486,1411,F,832,1065,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,400,390,F,224,F,F,F,F,F,F,F,350,F,F,190,F,1324,1114,1080,F,572,1447,1190,963,295,F,1289,1005,1365,F,F,1343,1343,1304,340,363,F,F,F,F,430,1344,F,444,F,286,F,F,F,F,F,F,F,F,F,F,F,1289,316,F,F,F,F,F,F,F,F,F,F,920,1044,F,274,248,627,1343,1258,372,1190,552,444,590,814,146,1168,F,1190,F,F,F,F,F,627,768,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1419,43,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1177,F,1381,295,1085,563,882,344,672,1282,1282,1297,672,1174,1142,190,962,F,344,1445,F,1282,1493,327,741,1288,F,F,1190,921,F,893,F,1131,307,F,F,F,451,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1114,F,66,261,32,1077,1282,1275,326,F,F,F,F,F,F,F,F,F,F,F,F,F,F,172,1096,1331,532,1075,F,1359,318,F,F,585,248,F,710,505,F,1323,F,F,1235,F,F,1032,505,1365,1238,295,968,517,333,661,257,890,F,634,450,688,F,F,F,F,F,F,F,F,252,F,F,F,207,756,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1232,528,553,1323,501,F,F,F,F,F,F,F,374,F,998,1132,1055,F,F,146,F,921,1136,F,1338,1397,972,1421,21,1496,1136,1433,1260,F,160,501,1504,362,1255,F,F,690,483,F,F,1280,1280,F,F,269,F,F,F,F,958,F,F,F,F,F,905,F,656,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,505,F,471,F,F,F,1047,F,F,F,F,F,F,F,F,F,F,F,F,1446,8,1304,57,F,1335,801,1458,F,1124,F,1280,1367,962,F,F,1496,1445,1423,690,1237,150,730,F,482,F,1124,1337,1258,1204,632,1307,F,1255,F,F,F,F,F,710,F,F,F,F,F,F,F,F,F,F,F,F,442,F,F,690,272,192,120,672,1124,F,F,F,F,F,F,F,F,F,F,F,690,F,F,F,F,F,1450,627,327,F,513,587,1343,455,207,F,1367,51,1211,1089,224,1252,190,820,231,1496,418,F,886,173,F,F,274,854,F,F,1457,F,1280,1433,945,1306,F,1037,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,F,344,F,F,F,F,F,629,F,F,F,F,F,291,F,1335,1089,F,1039,F,920,407,1039,F,344,710,F,618,1419,F,F,F,F,F,F,F,F,F,F,118,F,F,F,F,1238,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,618,F,1402,1349,8,1279,F,57,F,F,F,F,F,F,120,F,F,886,192,645,921,F,485,512,265,325,1341,1204,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,724,94,1318,F,F,F,F,F,F,F,1328,F,1186,F,F,F,1446,F,1282,688,F,629,F,710,F,F,1262,F,F,1120,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1121,1313,F,F,F,F,F,629,1370,F,F,1421,F,1142,1307,1238,1338,866,710,1168,901,F,274,959,1342,F,F,F,F,1216,118,F,F,511,F,F,F,F,291,F,F,F,F,F,F,120,1349,651,F,1267,F,920,F,1375,690,1350,963,F,F,F,326,1483,F,F,F,F,F,950,F,F,F,818,1238,1099,F,344,F,F,753,753,1216,118,1394,F,F,F,F,F,627,627,F,1262,529,F,774,730,F,F,465,F,1099,F,1303,F,F,326,F,1282,F,F,F,F,1142,1357,F,1421,F,F,F,F,F,F,F,935,778,F,650,F,F,F,674,1267,342,F,651,F,F,F,1035,F,471,F,F,F,F,F,1186,F,951,F,464,F,12,F,F,F,F,F,F,703,F,801,F,F,F,405,851,F,F,1273,F,509,F,F,F,F,1442,F,831,F,F,1030,452,F,1496,470,F,F,482,F,F,F,1338,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1289,F,F,596,1367,532,F,F,F,F,F,1090,1442,F,1357,F,362,F,493,1286,F,F,468,F,F,1096,F,F,150,423,F,F,F,F,641,F,F,F,F,495,F,374,F,1480,F,F,F,F,F,922,F,F,F,F,929,F,269,907,F,F,452,F,F,823,F,923,253,253,F,629,F,F,1293,F,F,F,F,F,F,F,F,F,F,F,F,F,F,766,F,1442,596,1448,F,294,77,907,1301,1358,188,629,F,F,870,F,F,1300,F,F,F,F,F,F,F,F,F,F,F,918,F,133,923,F,F,1343,F,1168,286,661,F,493,F,F,F,F,F,1422,1372,F,F,F,F,F,F,F,1365,F,371,F,448,F,F,F,922,F,761,463,663,1260,291,606,327,1229,F,209,F,882,F,F,F,216,1245,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,360,F,F,F,207,F,F,923,627,945,932,563,51,F,1085,F,F,F,F,F,F,F,1235,F,F,F,F,F,F,F,F,F,F,F,F,1052,F,F,F,F,F,F,F,F,F,F,1483,F,F,1170,929,272,F,F,1199,F,F,1235,F,F,F,450,1288,F,1052,1447,F,F,F,F,147,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1456,1245,146,1136,1282,F,556,F,F,1051,1077,1085,F,507,F,F,F,360,687,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,1447,F,F,F,702,F,1096,556,F,254,1340,F,1124,174,F,F,F,F,F,F,F,F,F,207,1218,F,F,1282,F,F,F,F,F,F,F,1307,F,F,F,F,431,532,F,F,1170,327,F,1447,1080,1208,F,F,150,F,1170,1422,437,F,963,1236,F,281,41,F,F,F,640,F,F,F,F,F,F,F,1110,F,F,F,F,F,64,1282,67,F,193,F,F,F,526,430,F,F,F,587,1355,F,F,F,F,F,F,F,1090,F,F,F,F,F,F,F,F,F,F,F,F,814,F,1365,1480,723,450,F,F,645,F,F,F,F,F,F,F,504,1294,F,F,F,F,1324,F,F,F,F,F,505,596,1090,505,618,F,F,1496,F,1080,F,F,F,F,F,1236,271,1124,F,F,F,F,F,F,F,F,1280,982,F,F,F,F,F,F,F,684,F,F,F,F,929,F,F,F,F,F,F,780,651,F,F,485,F,F,F,746,F,F,383,F,F,F,F,1307,F,F,F,1301,F,F,F,F,78,F,F,F,F,F,F,F,F,F,F,1235,F,F,F,F,F,569,F,F,F,1229,F,114,F,712,556,F,F,190,F,F,F,1268,753,F,F,F,F,F,F,473,122,1077,1440,295,728,F,1077,962,1087,F,187,1324,F,F,449,920,1448,464,F,F,F,F,F,F,F,1447,F,1361,F,F,F,F,F,361,1333,912,F,F,F,F,F,F,F,F,1341,144,485,F,7,F,267,F,1131,F,521,F,F,F,F,F,F,923,632,1308,F,641,F,F,F,F,1379,1308,196,889,F,585,F,1168,462,226,401,136,F,1057,485,F,F,F,F,634,543,1076,F,268,1360,921,F,1276,F,F,F,F,F,1343,665,F,F,174,F,F,F,F,612,359,F,F,F,1407,F,1236,F,F,F,491,F,F,F,F,1349,316,629,929,F,F,F,1334,F,F,F,584,1236,F,974,F,F,1276,974,F,F,449,F,F,F,F,284,161,F,F,1069,F,F,1480,291,485,F,F,1150,F,853,F,F,F,F,F,F,F,507,941,F,F,F,F,930,526,F,F,959,1216,F,F,F,840,1112,F,F,F,1341,F,383,476,854,1483,F,499,F,F,1259,1062,F,1341,1448,F,F,F,F,F,F,F,680,F,1453,532,1150,840,526,450,F,1448,F,F,F,F,54,F,F,F,F,F,F,F,F,F,148,1306,483,387,524,1250,1216,F,959,1235,275,F,1085,F,1440,786,F,F,1260,1433,1448,F,766,F,F,F,F,491,F,F,F,F,F,1332,405,F,F,247,F,480,512,F,F,F,F,F,F,532,1375,F,F,F,F,F,F,483,F,1124,F,491,F,588,1250,583,63,532,F,F,57,F,120,F,1483,627,F,F,F,337,931,1324,F,1152,753,F,F,F,568,1257,F,F,F,F,F,F,F,F,F,452,452,F,342,F,F,1329,407,F,990,1347,332,537,F,1377,F,F,1316,F,1260,F,F,F,F,F,F,F,F,F,F,F,F,1261,F,F,423,F,F,F,F,F,F,F,F,452,F,F,449,918,315,F,F,F,F,486,F,F,1341,F,F,F,F,F,1215,766,F,F,F,F,F,F,F,F,F,1345,1347,F,F,F,F,F,F,F,F,F,485,1234,1080,278,287,F,1216,1219,629,F,F,F,989,F,F,190,1304,1453,1486,F,F,F,F,F,F,1233,1319,F,485,130,F,F,F,F,F,F,F,F,483,F,F,F,F,F,F,F,F,F,F,F,1054,1199,F,67,1260,885,1413,F,F,471,F,1134,F,431,F,1373,F,1252,F,651,F,1413,455,F,F,F,F,F,F,F,F,F,F,F,120,F,F,F,F,F,F,F,295,F,137,F,F,F,F,F,1131,F,1174,F,F,F,F,F,F,1448,1170,17,1480,1486,553,F,1039,1173,672,F,F,F,F,1053,1412,F,F,F,150,F,1325,F,F,F,F,F,F,F,F,F,F,F,F,760,449,F,F,F,F,F,F,145,F,F,1124,F,F,F,F,F,783,F,F,483,285,448,F,194,1410,1345,F,1276,F,60,52,596,F,145,F,928,F,9,1077,F,F,148,F,F,F,F,F,F,F,F,833,F,485,F,F,F,F,F,F,F,F,F,F,F,851,F,1261,F,F,118,F,6,F,F,1076,430,F,483,145,1260,1442,F,F,1139,F,696,F,F,885,F,F,1301,931,F,F,F,F,F,F,F,F,F,1264,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1367,F,F,F,F,F,F,505,1264,671,F,945,F,114,F,F,F,1329,485,1046,667,F,482,1448,1234,F,57,9,1340,9,F,F,F,F,627,F,F,F,F,F,F,F,F,F,F,F,F,1265,F,F,F,F,521,1151,F,428,431,F,F,1410,1421,1343,1260,F,1260,313,1323,F,F,760,F,F,F,F,F,381,F,F,F,F,F,F,F,F,1377,F,F,F,F,F,1452,F,121,175,471,1382,403,492,1318,F,F,F,1273,431,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,F,F,F,731,818,F,723,F,645,1456,915,1144,F,F,F,1350,1165,405,F,F,F,F,935,F,688,1023,645,146,1281,F,F,405,41,684,530,F,F,F,295,F,F,F,F,F,F,F,F,430,F,717,F,F,1282,901,F,417,139,1055,471,F,F,F,F,F,F,F,1349,F,492,F,F,F,F,9,F,F,F,1142,1340,1208,F,F,F,656,F,1510,F,627,F,901,1313,F,791,270,F,F,602,F,F,F,F,F,1419,532,1438,483,929,F,417,329,F,417,442,430,446,F,F,F,1422,155,1147,932,F,1067,1349,F,F,946,F,F,480,F,1260,431,1260,1261,425,612,F,629,F,F,141,F,F,F,537,1257,419,F,F,F,F,F,270,F,268,1227,F,F,268,1386,530,F,666,161,F,1442,F,F,F,921,545,480,598,F,F,F,F,F,F,F,350,F,1085,F,265,F,F,F,F,665,1203,F,F,1365,1255,F,1173,F,F,F,F,F,F,F,405,F,F,F,F,F,1150,921,F,F,F,F,F,F,645,629,F,1314,F,F,F,F,373,1185,420,161,F,1212,F,1294,1360,753,147,F,451,271,F,760,F,768,F,651,F,F,F,8,1265,F,F,F,544,1112,1028,F,F,F,1343,F,F,F,F,1343,1277,F,1445,270,F,F,F,F,700,F,F,F,678,532,F,F,F,934,651,F,F,F,F,F,F,890,197,287,1366,F,F,F,1479,F,1260,458,744,1154,F,F,F,915,F,730,702,F,389,F,929,F,648,742,1237,109,F,452,1129,F,F,526,1047,723,F,F,F,F,F,F,F,F,F,1236,1257,653,F,F,57,1238,F,F,F,629,1433,F,1356,450,1228,21,491,F,F,F,F,1511,1421,77,958,1326,F,F,265,963,F,F,F,F,886,1417,F,F,1052,380,65,1341,342,F,1286,1080,1189,F,F,261,F,F,1198,1274,1253,874,468,F,F,F,F,598,F,F,F,1112,206,1047,1455,1430,1236,598,57,F,F,266,F,F,783,1279,986,1206,1238,1360,715,1370,1452,F,F,F,F,F,F,F,1118,F,382,1063,F,1429,886,F,F,1189,F,145,882,120,1355,1100,F,1254,F,886,463,F,342,F,65,F,F,F,F,782,F,65,1238,1355,F,783,391,F,F,F,F,F,629,F,1286,1323,910,874,F,F,F,F,1136,F,992,1054,F,F,F,F,1136,F,F,F,F,F,226,F,720,F,F,F,F,F,F,F,F,1084,F,F,F,F,380,F,417,1080,1328,F,383,F,F,F,F,F,F,F,F,F,F,F,F,553,F,F,523,F,860,104,38,1293,90,F,F,649,8,F,F,F,730,599,F,F,F,1274,F,1065,F,F,F,F,F,F,F,478,801,F,F,F,F,F,F,F,1444,F,650,1411,F,F,1377,F,F,1304,F,F,F,F,F,F,142,1249,1257,F,F,44,F,F,1056,F,F,F,1367,730,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,276,F,F,114,318,1399,144,F,1186,F,F,F,F,F,F,F,F,F,F,1341,F,F,F,F,F,1331,F,F,569,F,329,1280,144,F,F,F,F,F,F,F,F,587,F,F,F,70,F,665,F,F,F,1343,730,1282,F,F,F,F,F,F,F,F,F,399,F,682,F,F,1055,F,F,F,F,F,F,F,524,271,F,F,1265,F,F,1447,F,F,72,F,F,F,F,F,F,F,F,F,F,F,F,1447,1469,1311,634,1131,F,F,F,F,F,F,F,F,F,F,F,F,603,526,731,1316,1422,403,F,F,F,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,1238,969,118,F,F,F,F,F,F,F,F,994,F,1375,F,1483,F,F,F,684,401,F,142,F,523,1174,1230,F,F,F,1445,F,F,775,F,F,F,F,417,F,F,F,F,1257,648,F,F,1322,1304,F,F,F,F,452,355,1304,F,1260,F,F,F,207,F,F,1260,450,F,F,F,F,F,F,1322,F,F,F,F,F,F,F,F,1343,146,532,F,1496,F,F,F,F,F,F,F,F,F,502,1343,F,1151,148,22,1196,1488,959,F,F,148,1259,F,F,F,F,F,F,F,801,F,198,F,1254,F,161,1121,1356,525,22,F,189,248,863,148,F,960,F,1323,F,F,F,F,F,F,F,F,F,1417,F,F,F,F,1268,52,580,923,1453,524,945,753,1370,F,401,1502,F,F,F,F,F,F,F,F,F,945,F,F,F,F,F,F,F,F,450,959,413,344,607,1047,1257,82,1080,1357,395,F,814,F,F,447,F,F,F,F,F,F,F,F,F,F,730,F,F,F,295,1266,1378,1304,948,265,623,923,601,342,922,152,F,F,201,F,F,753,42,F,F,F,235,F,F,F,505,153,148,1367,205,1124,1170,1090,1411,342,F,135,337,446,1411,F,F,F,F,F,F,F,F,F,F,F,F,F,505,1411,1419,F,F,1324,413,1254,455,250,1332,1118,929,483,1296,923,535,F,413,F,F,F,F,F,F,F,1324,505,205,F,1204,1423,893,672,1445,161,730,934,F,634,F,F,F,F,F,F,629,F,F,F,291,1124,532,F,F,524,1150,640,1028,267,F,1382,1446,F,F,F,1309,651,F,F,242,F,F,1419,1306,948,502,888,F,F,419,F,F,F,F,F,1365,405,F,1315,F,F,1315,120,505,524,1324,272,F,444,1268,1315,F,F,F,F,651,505,1343,963,353,332,F,532,962,F,F,F,F,521,F,485,484,F,F,F,F,F,F,1258,865,F,583,572,F,572,1413,F,F,24,F,F,138,452,833,291,1293,282,1073,1228,328,F,F,1257,1483,F,452,F,F,F,F,67,F,1218,32,363,F,553,F,F,F,F,F,F,1475,342,753,487,1216,1367,F,760,532,524,385,909,F,F,800,F,F,F,1221,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,156,580,415,F,1271,1257,F,210,1189,1020,F,285,135,F,F,F,620,1483,F,1443,1511,F,F,546,1386,F,1369,956,F,F,F,311,1315,F,485,F,111,F,F,F,F,F,F,F,1087,1309,1280,1167,420,1265,1052,1214,423,589,107,268,838,1325,142,915,923,1375,341,F,F,F,F,1187,F,F,F,F,F,F,F,1249,1073,1217,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1151,F,1319,1448,672,1324,523,F,F,237,F,161,1497,295,1446,882,F,66,F,247,F,F,F,F,F,F,F,F,598,F,1338,F,F,F,F,F,F,F,F,F,426,862,1152,546,1301,1494,483,1255,F,F,173,F,F,910,1324,1218,F,272,F,890,148,476,904,F,191,1412,F,F,F,F,1227,F,F,F,F,1225,F,F,F,295,1238,26,F,495,F,F,F,F,F,F,F,F,F,F,F,112,F,161,575,1165,1492,629,1142,1023,F,F,862,862,F,F,F,1037,938,F,F,F,F,F,F,F,F,1496,F,1448,1324,F,505,792,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,674,962,F,244,635,121,945,667,257,205,F,F,484,F,F,129,1098,1496,F,546,F,F,934,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1094,1367,1423,619,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,908,F,F,F,F,1433,1277,F,1151,207,601,F,1301,1090,1412,94,F,F,56,884,F,F,F,F,142,F,F,F,F,F,939,482,F,F,F,F,F,1415,F,601,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1174,1023,509,1460,287,F,114,530,1154,52,F,316,645,1402,1131,F,F,F,F,F,F,F,F,F,1039,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,1208,F,950,F,186,F,F,F,1142,76,1242,F,F,909,F,F,F,F,F,F,F,751,86,F,F,583,F,214,986,41,600,F,F,F,F,F,F,804,627,F,F,F,F,F,F,501,670,629,661,401,F,F,F,121,F,F,F,F,F,F,1265,F,122,F,1282,1422,F,F,F,F,F,1099,F,F,F,F,F,F,F,F,F,F,F,729,674,684,F,F,250,F,F,F,F,F,F,F,257,F,1227,1375,674,F,674,F,F,F,F,623,F,1225,260,791,1062,1282,886,F,F,F,F,F,F,F,F,F,F,710,F,1346,F,78,F,1460,F,F,150,F,F,F,F,F,F,F,760,1216,F,1191,1336,F,F,F,F,F,F,467,F,992,F,1338,F,F,F,F,F,1215,F,F,F,F,F,F,F,F,F,F,1499,1367,F,F,0,F,1453,1227,F,F,F,286,1452,408,465,733,F,F,F,182,945,374,1186,715,F,F,F,1156,F,F,998,F,F,998,921,405,F,F,F,1273,1173,F,F,F,F,F,F,1274,F,F,1419,207,F,F,F,F,F,F,F,F,F,F,601,F,F,F,1069,F,619,629,F,119,814,674,F,1185,471,F,F,F,F,374,F,F,F,F,F,F,F,F,923,1365,F,1283,F,F,F,F,1375,21,224,482,1309,F,F,532,823,F,F,F,F,F,F,F,274,483,F,F,F,F,F,F,853,375,48,F,561,1329,657,799,1413,1347,804,872,F,374,85,430,999,401,F,F,F,57,1257,524,474,57,F,1176,1443,F,441,1343,190,F,76,F,F,F,F,374,F,F,311,F,F,1327,1303,F,F,441,F,F,1394,116,442,F,1173,F,F,F,1445,1288,1302,F,F,1441,F,F,1229,F,F,F,537,F,F,F,F,1250,F,F,672,F,1442,999,142,342,F,295,1157,1154,F,528,131,1142,247,F,1488,F,F,561,1207,F,561,F,F,F,F,F,F,F,F,F,F,F,F,F,F,999,1376,430,1489,191,958,F,F,F,761,285,1303,561,1268,F,F,F,F,F,F,F,F,272,544,F,1349,F,F,F,1483,F,F,F,F,465,F,882,F,629,F,78,363,524,553,F,450,1332,1154,F,956,999,657,471,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,1154,1447,546,F,F,F,F,F,F,1329,F,1155,441,F,F,F,635,F,101,F,F,F,771,1489,F,244,F,499,882,F,395,306,493,502,838,1397,F,F,F,F,882,229,F,963,632,761,602,F,F,F,F,329,F,F,F,474,F,F,F,580,F,1346,1098,493,F,F,F,F,F,F,F,684,F,672,F,F,F,F,F,363,983,F,F,901,F,661,F,F,F,1440,1268,F,F,650,632,1052,78,629,F,F,F,F,F,F,1280,363,742,632,F,F,532,1453,F,F,F,F,553,F,257,F,1433,F,1087,482,661,395,1397,1268,929,F,1069,F,F,1346,507,F,F,1069,1069,1022,F,F,F,F,1260,F,F,F,F,F,572,F,963,F,363,33,F,57,929,F,F,F,F,F,F,70,33,F,1512,888,F,480,F,F,F,F,F,F,F,33,F,F,F,F,F,F,F,F,F,F,F,825,F,137,F,881,F,F,343,1199,F,F,F,F,F,F,F,F,F,887,910,F,F,147,F,F,1313,923,1255,F,F,1448,248,191,1360,F,F,F,F,F,F,F,F,F,F,F,F,F,191,F,F,248,1480,F,1493,192,F,F,864,1324,923,999,487,F,1448,959,1375,F,1080,F,F,F,426,F,F,F,F,F,F,F,1215,F,F,67,F,545,F,F,F,186,F,189,F,1346,1080,415,1011,F,1327,145,F,187,F,F,374,728,F,484,F,F,F,F,F,F,F,370,1398,F,F,F,51,F,F,F,F,F,1285,592,F,257,F,F,1186,F,F,F,F,F,F,F,F,F,F,F,F,1104,F,F,F,224,F,F,F,F,F,F,F,628,F,1357,499,F,F,1140,964,1345,1369,505,F,929,1331,1320,F,F,950,F,F,F,F,F,F,F,874,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,486,F,1196,F,1167,F,F,F,F,F,283,121,486,148,491,1090,F,F,F,F,F,F,F,F,1142,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,1370,F,F,449,F,645,F,F,F,1399,F,161,F,F,F,961,49,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,461,573,1073,F,505,F,1233,F,F,F,449,F,F,F,F,F,F,1399,1389,1329,F,147,521,F,F,F,F,F,F,F,F,F,F,640,1364,F,F,F,F,59,F,581,F,F,F,F,F,F,F,F,F,157,1318,1480,F,945,1268,F,1369,1254,501,1273,1047,F,1448,F,F,153,F,58,1235,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,235,F,F,F,F,F,F,F,F,F,1367,1208,F,F,131,F,F,F,471,931,F,1237,F,F,F,F,F,F,F,1360,F,F,F,F,F,F,F,F,F,F,F,248,F,220,F,F,1360,521,1208,1389,F,F,471,F,1047,F,F,480,F,F,F,F,F,F,F,1332,F,F,F,F,1268,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,F,F,F,248,F,521,F,F,F,471,F,552,1360,F,F,629,F,F,F,171,F,146,F,480,F,1360,F,1375,F,F,F,F,F,F,F,F,F,1142,1390,F,F,F,814,1460,F,F,F,F,F,F,F,F,F,F,F,F,1267,F,F,F,F,F,F,F,1078,F,567,922,1200,316,209,F,F,1212,111,90,1273,876,513,921,1062,823,768,F,947,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,57,F,F,F,F,F,57,38,60,1483,768,1238,426,F,F,F,F,F,F,F,510,F,1444,F,F,F,943,F,F,F,F,F,F,428,1190,1397,1076,210,464,1005,1386,803,F,F,F,F,F,F,F,1265,F,F,F,F,F,F,F,F,F,968,485,1345,628,706,1063,420,216,537,485,85,663,340,1360,577,F,F,F,F,F,1273,F,F,F,F,1377,F,947,469,916,F,F,F,F,F,627,874,1087,523,1341,1442,151,F,1258,F,451,1002,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,463,941,1445,1350,1259,938,485,1442,465,F,1365,1492,715,F,F,F,1075,F,F,F,F,F,F,F,F,F,F,F,F,968,F,F,F,F,F,F,495,430,F,1190,430,1240,1343,866,F,F,1245,930,629,1341,177,1304,1237,F,F,F,F,F,362,F,1366,F,F,1418,353,929,546,627,1069,394,F,894,F,F,627,F,451,F,F,1199,1107,F,452,627,F,F,667,F,F,F,231,F,1512,1052,F,1067,F,333,526,1190,501,939,359,1483,465,1052,F,F,1150,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1215,F,653,F,142,1247,1510,1367,F,1458,F,976,1259,963,F,363,F,F,F,F,F,F,920,1301,F,F,F,F,352,974,155,723,F,F,F,F,F,F,1069,963,F,F,938,94,F,F,629,F,1230,618,1288,605,F,F,1080,1442,316,F,F,F,F,F,1448,F,F,F,F,1345,F,629,F,F,F,F,751,1237,F,1349,982,F,F,F,F,F,967,F,F,F,F,683,F,F,F,F,F,F,F,F,F,F,F,F,224,F,1347,56,362,F,1243,1324,732,F,357,959,1445,F,F,F,F,F,F,F,F,F,F,F,F,381,1190,F,651,1168,188,1341,318,907,57,F,41,F,F,F,F,F,F,F,F,881,F,1125,F,F,1114,1236,1275,F,F,F,F,F,428,1324,F,F,F,F,F,F,F,F,1341,1395,1351,775,F,F,F,F,F,1381,F,1174,1257,502,1323,F,F,F,F,F,F,F,788,1151,1325,1174,1448,154,1158,F,F,F,F,1413,F,F,F,F,F,F,732,F,F,1254,1346,1325,611,F,F,F,F,907,F,476,414,759,191,F,F,572,154,F,1361,F,F,F,1480,F,F,1118,F,F,F,F,F,F,1347,F,F,1504,1032,658,420,1238,723,450,629,F,730,F,35,492,F,F,F,F,F,F,F,F,F,F,974,F,F,F,723,F,759,443,452,728,1051,F,F,1056,131,F,1480,F,1445,805,F,F,F,F,F,485,F,F,553,1442,F,F,F,F,F,226,641,1421,375,610,454,169,F,492,587,F,F,F,F,1064,F,F,F,F,F,F,138,1151,F,450,723,872,1421,124,F,354,F,F,1343,F,1131,F,F,F,1304,485,603,F,F,F,1341,F,F,730,F,740,F,F,F,203,F,F,602,F,F,1322,F,F,728,F,F,F,1357,98,492,F,F,1118,F,F,F,F,F,F,1323,F,F,530,F,F,F,295,F,F,331,F,331,F,F,F,F,F,F,397,698,F,644,F,F,F,F,150,F,627,F,F,F,F,F,1446,F,F,1282,F,F,155,F,483,F,F,F,885,F,F,F,F,505,F,F,F,F,1455,F,F,F,F,F,1293,F,F,F,583,F,F,F,F,F,F,F,513,F,452,F,F,930,F,F,F,F,107,F,F,921,1229,710,F,269,F,F,F,F,F,F,F,F,F,567,F,224,F,782,F,442,F,F,F,F,F,F,F,599,286,929,F,1345,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,667,468,F,F,F,344,710,F,1453,F,F,1356,F,F,F,F,F,F,F,F,F,F,F,610,483,1343,656,191,F,775,F,F,F,F,F,F,F,F,F,F,F,F,274,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1211,1273,F,F,F,60,175,1255,F,523,F,F,F,F,F,F,F,F,F,F,F,1279,885,1480,1015,F,1037,1486,F,1444,F,F,1496,962,F,148,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,77,F,F,F,F,F,F,F,970,929,1317,131,430,1006,F,F,F,523,1255,F,F,F,F,F,F,F,F,F,F,148,583,F,197,1012,592,146,1496,499,F,1365,1199,723,217,1109,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,332,379,265,113,712,121,58,1367,F,F,1228,1496,F,1483,F,170,1229,1219,F,967,F,F,1229,F,1264,F,F,F,F,F,F,1352,493,F,F,1237,1168,1035,F,921,1044,1367,483,270,121,1204,F,F,656,F,1475,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1012,F,F,1325,383,F,629,111,F,F,F,248,656,1421,907,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,667,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,632,F,F,F,683,F,F,F,F,F,F,F,287,F,F,532,629,601,F,1012,381,120,1301,F,F,1447,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1313,77,141,F,1460,432,F,F,31,F,F,F,F,1259,F,F,F,F,257,F,F,1421,405,F,F,63,596,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,509,F,F,F,F,F,F,F,351,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,725,F,1367,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1259,F,893,1110,F,F,F,F,F,F,244,F,F,F,185,246,482,120,F,F,F,1480,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,F,F,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1194,F,F,F,F,F,F,F,F,F,F,627,F,F,F,F,932,F,F,F,185,F,F,F,F,F,F,F,F,1142,F,255,F,492,F,F,F,F,F,F,F,1447,532,F,751,F,682,F,F,F,F,F,40,F,F,F,F,F,F,1500,F,F,F,F,F,F,F,1432,F,F,1365,F,F,40,F,F,695,1260,452,1343,291,381,1199,1438,F,959,1124,637,962,651,394,605,1193,217,667,465,1085,499,1506,351,1236,F,F,588,277,1114,921,1328,F,F,F,1080,F,351,F,F,235,1333,F,F,F,930,F,1060,124,748,F,751,F,F,F,783,F,F,191,F,814,1427,107,F,F,1114,814,190,F,F,537,F,1060,F,1458,286,12,54,F,F,1170,F,887,1262,959,1075,F,F,F,1153,F,F,F,702,889,F,1170,998,F,F,F,F,F,F,F,191,F,F,482,333,1255,502,610,1407,882,95,F,825,F,121,F,F,F,F,F,F,F,F,702,401,810,150,122,F,F,F,780,F,F,1261,518,484,1441,F,F,F,F,1187,F,F,719,1375,F,1450,1212,1015,1282,1257,F,1187,823,F,1233,491,F,328,F,F,F,F,F,F,F,F,F,F,F,F,F,F,60,F,F,F,F,1341,F,F,257,482,354,F,F,491,516,F,F,F,F,F,540,F,F,F,974,F,774,756,1304,1116,1072,580,974,1236,F,F,F,257,774,F,1020,374,270,F,804,F,F,128,430,1325,F,F,F,F,F,1199,84,F,450,1357,F,1079,161,F,F,F,F,F,F,F,F,1274,719,628,1168,F,491,F,F,F,1447,385,393,F,923,F,324,1366,1433,1237,294,119,1257,F,383,F,F,F,F,F,F,974,471,F,1280,1349,F,1142,1224,342,507,888,F,F,F,1075,1365,573,F,888,1280,844,1268,493,1304,F,57,F,F,F,774,F,635,F,890,F,F,F,514,F,F,F,1141,1325,8,F,F,1274,934,1254,1154,F,537,F,F,F,F,F,592,F,F,F,F,F,F,607,F,793,F,F,270,1088,F,501,814,F,1365,F,F,F,F,F,104,F,501,F,456,1241,1335,291,395,454,499,F,F,F,F,F,F,F,620,F,1056,1358,F,242,392,822,F,1131,1273,F,1023,123,F,F,F,F,F,F,1018,F,1376,F,328,493,1304,F,F,F,1260,1088,F,F,F,F,F,F,F,F,F,F,F,1280,629,F,F,1194,F,F,729,120,477,F,F,1468,1375,F,F,F,F,F,F,F,F,601,F,1323,231,1273,1375,1187,49,1030,1268,257,888,890,F,1141,F,499,1335,F,1375,F,994,940,921,253,921,F,F,420,1370,1360,483,352,412,1080,F,962,F,F,F,F,F,F,F,F,F,1243,F,F,1441,907,1323,381,524,1175,F,F,F,F,F,295,F,F,1317,650,57,F,F,F,1485,442,F,286,F,280,1309,F,1054,F,1054,1332,979,F,F,F,F,F,F,F,1216,F,F,74,1304,1211,142,F,270,1343,F,F,F,135,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,529,482,F,1430,49,F,1174,F,F,F,F,881,F,F,F,F,344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1217,F,1266,801,661,F,F,1480,F,1442,1106,1488,332,277,888,1324,1119,959,295,930,F,931,F,86,1316,F,F,F,F,F,F,1475,F,F,711,501,F,F,F,F,F,1421,886,1257,1325,327,814,F,1448,F,1136,F,1343,F,619,1304,F,1343,F,F,1235,F,F,482,136,257,F,F,F,F,F,F,F,F,F,F,F,1370,F,1258,F,656,1136,F,F,F,F,F,F,F,F,49,1264,1325,F,205,F,546,F,F,950,665,54,F,F,F,F,F,F,F,F,F,F,F,1422,204,1253,1301,136,426,532,F,139,112,121,1446,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,363,137,1338,F,F,F,F,160,963,1282,F,1422,559,F,532,F,F,F,F,F,F,F,F,F,F,F,963,F,723,1338,1492,916,F,1080,F,F,F,F,1089,120,F,F,272,F,404,672,1322,F,F,F,F,809,70,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,801,460,930,F,F,F,F,F,F,1264,F,F,651,F,F,F,F,632,F,1343,F,629,1112,F,F,224,F,F,1238,F,F,190,F,F,F,520,442,F,1365,F,585,F,F,F,423,362,322,F,F,585,F,F,399,147,F,F,520,F,F,1128,F,F,257,F,F,F,F,F,F,F,1130,F,F,F,947,F,F,F,399,F,F,F,F,F,F,1473,F,F,1179,F,F,1375,F,F,F,F,F,1503,F,F,F,F,F,F,F,1253,206,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,F,1104,F,265,F,F,F,F,F,F,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,152,F,F,F,F,F,F,F,F,F,F,F,1016,F,F,F,F,F,F,F,F,F,F,483,F,F,F,F,F,253,F,97,F,568,F,F,1236,F,F,F,94,F,F,694,852,F,F,F,F,F,F,1029,F,F,F,1236,F,F,F,F,F,1029,F,F,509,F,F,F,F,F,F,F,F,F,F,F,F,1236,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,627,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,F,F,F,1376,F,F,141,F,F,1052,F,F,F,F,F,F,F,449,1051,1212,F,340,F,949,1301,F,F,F,170,344,F,F,F,1343,265,342,342,1399,918,F,651,F,F,F,1051,872,F,F,F,1345,684,456,1347,52,1364,1067,F,1282,160,F,F,F,F,F,F,F,F,1067,252,F,F,F,1343,F,135,372,661,905,1378,1325,753,F,1128,F,F,F,F,F,F,F,514,F,1117,866,F,1318,1039,286,F,F,175,1282,F,1204,374,1309,F,593,F,651,F,224,1411,F,F,F,F,F,F,F,F,F,F,1344,1114,F,1366,1216,1216,F,F,24,979,78,224,F,1411,446,F,F,F,470,F,F,F,F,F,F,670,F,F,F,F,653,1005,F,F,F,F,F,F,F,F,F,228,723,1264,1246,418,1483,1441,1039,214,629,F,250,632,380,F,F,F,882,F,F,F,1349,F,448,F,280,1255,F,F,F,F,F,F,F,896,431,F,661,753,326,687,1399,78,252,707,F,1472,672,865,280,85,F,F,F,F,136,704,F,F,F,F,F,F,F,F,1257,F,F,F,F,F,F,11,1450,F,776,1218,430,F,F,280,1227,1450,107,629,1099,F,F,F,107,F,1110,F,280,F,473,F,F,F,F,F,F,F,491,825,228,344,480,471,188,F,1357,1023,1186,F,F,F,F,F,F,F,658,F,F,1125,651,702,252,F,F,F,869,F,651,F,F,629,F,828,F,F,F,645,1054,F,327,F,F,F,F,1054,F,F,651,1422,F,70,627,F,F,1110,982,491,1480,F,F,651,651,725,F,1099,F,F,F,F,651,F,F,476,F,F,F,F,F,F,651,491,963,F,F,F,825,514,136,F,F,F,F,F,1438,953,F,953,295,F,F,1039,F,F,F,F,F,44,F,324,F,F,327,325,F,F,F,F,319,571,F,F,F,F,1382,471,F,325,F,F,F,280,F,F,F,F,853,823,868,228,226,8,F,1174,130,19,717,775,F,F,80,1367,1265,700,F,F,904,F,278,F,235,F,F,1291,801,417,161,1092,703,F,F,F,F,F,1230,1343,255,1322,F,1250,1127,F,11,601,775,F,960,733,839,94,95,F,F,F,603,1174,1338,F,804,F,1092,F,F,F,F,188,F,492,F,F,355,F,F,F,493,405,F,1419,F,683,F,F,482,401,F,F,F,487,484,F,F,1309,F,332,F,F,F,57,921,F,F,1370,16,245,F,F,295,329,F,F,526,800,1216,F,1069,342,1262,963,910,1229,651,696,1456,41,F,1367,F,F,F,F,51,700,F,486,F,1328,F,574,502,F,75,F,F,F,684,400,287,1448,F,F,F,1441,295,1457,22,F,F,F,1441,332,270,804,85,280,1044,1039,57,F,F,F,1448,1433,85,F,F,F,532,1316,F,F,F,1260,F,1480,29,1332,156,1142,929,F,784,1367,295,491,1343,1274,F,814,F,F,74,F,F,F,F,406,1280,255,F,F,523,1136,257,342,732,1080,1309,1168,1367,F,F,1280,342,1448,800,1309,1327,F,334,1490,1456,1306,F,F,F,1457,F,597,F,1352,361,592,F,291,1236,715,F,F,F,457,156,F,1136,1142,1136,1142,1313,F,375,488,F,78,1142,1371,F,F,F,F,F,F,1142,F,F,F,F,179,F,F,1293,430,1134,F,F,F,F,753,151,1128,1399,672,1423,684,1304,491,1052,F,1304,F,F,F,F,F,F,F,F,F,F,F,496,F,F,F,41,698,F,1190,1260,F,F,998,F,1074,1456,F,491,344,244,F,F,1355,523,F,1343,35,F,1039,1131,F,285,1282,F,F,F,461,F,F,F,1006,800,1456,401,F,684,F,F,F,F,F,F,F,1445,1304,F,1445,F,532,523,F,F,1370,670,F,F,78,F,F,998,1282,F,F,F,F,F,1259,682,F,F,F,363,F,F,1499,420,F,499,1022,947,975,870,1375,135,342,873,F,F,717,F,F,1157,F,558,1260,F,F,F,F,F,287,F,1343,F,F,1131,F,1262,528,F,1236,F,1343,F,1367,F,29,1216,1142,870,F,F,F,75,F,F,1379,1379,277,1011,1236,F,F,F,F,F,1236,401,F,218,1262,F,F,476,529,1128,F,1131,F,F,665,29,1367,1444,401,F,584,359,226,F,F,F,1267,1416,1039,F,553,84,78,F,F,1131,F,1367,84,518,518,F,530,532,F,768,1416,1157,1237,1262,1282,F,F,F,1023,482,F,F,1282,F,F,280,1485,F,F,F,F,F,F,F,F,F,F,1371,949,342,882,870,F,1347,F,441,1496,F,374,267,429,1250,F,865,F,F,1124,544,814,426,335,295,1338,F,F,1186,267,1433,1032,F,F,F,753,1124,F,615,F,917,291,1480,1338,F,F,F,1270,397,991,651,F,F,20,F,F,115,F,285,956,569,1301,461,1331,1441,1187,51,F,F,14,F,543,F,569,874,1362,19,719,753,F,F,F,F,F,343,950,596,269,1151,F,F,931,1334,1238,450,753,429,1309,F,57,907,F,245,F,1440,F,1077,544,104,F,F,1301,1440,F,1459,F,F,F,F,471,147,F,F,443,828,814,1325,F,152,343,395,920,F,369,1187,F,F,F,1151,931,F,F,521,1301,922,F,1441,F,F,F,959,F,294,F,F,471,444,952,F,132,F,F,344,F,441,1257,1253,706,F,F,1167,F,F,441,F,F,F,F,F,F,F,F,F,F,78,F,948,369,F,F,342,586,F,F,F,F,F,67,514,544,399,F,360,1301,12,1375,1255,1341,512,F,672,968,1208,F,485,F,F,F,F,F,F,F,F,F,F,F,517,532,897,588,F,F,1226,471,283,1371,505,F,401,360,910,719,1469,424,F,F,F,F,F,F,F,F,1356,959,F,620,14,887,1245,F,F,949,F,948,740,268,728,1419,F,952,1341,F,F,F,F,F,F,F,31,F,F,528,F,1399,1304,601,694,694,849,42,F,913,1304,F,F,F,20,F,F,F,F,F,443,F,F,443,1421,F,1041,360,F,908,642,F,F,F,F,1226,1306,F,F,F,F,F,585,F,F,295,420,1039,F,F,228,F,F,F,F,F,1306,F,295,352,F,232,F,724,1340,819,F,901,F,F,F,97,F,F,F,F,1372,F,295,814,F,F,1346,F,F,938,F,441,651,F,119,1351,F,F,F,F,392,F,809,1301,1150,511,F,F,F,882,F,640,F,F,44,F,F,1284,1212,128,353,F,449,1233,F,F,342,876,F,782,1315,1313,F,F,657,651,1313,962,426,1341,420,F,342,22,1341,F,78,F,F,F,441,629,F,F,F,1023,1257,F,F,F,1080,893,456,1341,78,78,789,959,F,F,1237,135,1360,F,1238,470,1023,F,F,F,444,1118,420,870,F,320,F,656,1456,886,F,629,F,F,179,1257,1442,F,52,1442,1131,1324,F,F,F,F,950,1255,636,F,1430,635,F,F,F,504,F,441,1359,F,596,448,F,1372,442,1338,F,1351,1308,1360,F,F,F,F,968,F,F,F,1154,1136,F,629,1118,629,F,F,1367,F,F,1343,F,F,F,F,F,1297,18,1204,1124,1096,F,1367,F,332,F,F,F,1124,1208,1367,1442,1442,F,1158,F,F,F,F,656,F,142,1129,1035,F,F,F,F,F,F,383,332,645,893,F,F,F,629,F,661,158,1274,441,1282,1067,F,F,661,445,1307,332,F,22,78,1158,1124,1430,63,1118,1208,1136,1274,437,1035,F,324,F,F,F,F,824,707,F,F,F,F,461,1445,F,F,F,1343,F,1367,F,F,1341,1373,146,F,F,F,F,F,F,F,F,F,F,F,1346,824,999,F,F,F,768,F,F,F,F,F,F,F,1173,F,21,F,F,F,F,307,1442,295,913,482,799,F,517,487,F,F,353,F,F,F,651,F,1504,F,F,44,F,F,F,F,F,F,1087,1342,857,F,F,F,783,1080,F,703,1080,F,1168,F,F,F,F,F,F,374,F,F,F,F,620,40,1369,1509,605,1297,F,F,1386,144,491,712,487,1367,F,F,1367,1341,F,F,F,705,1386,F,1475,1168,F,F,1260,524,1390,672,1158,F,F,1475,651,F,526,F,F,482,529,F,F,1483,F,1373,265,F,F,F,F,779,F,F,F,151,F,F,F,F,F,F,F,F,16,F,F,F,371,F,77,263,1237,F,F,F,F,968,553,F,F,888,544,342,1357,F,1204,1195,1362,1331,F,1335,F,F,1325,F,F,F,F,F,F,F,F,F,F,1266,F,1168,F,1131,F,F,F,188,F,F,F,1303,1255,93,1367,F,F,121,1262,F,552,97,111,960,F,F,217,F,1124,F,F,454,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1252,1369,F,F,F,F,497,1272,1415,1029,751,F,1057,101,1128,175,728,1458,151,485,151,F,F,F,F,F,F,F,F,F,F,F,F,F,632,F,F,F,F,415,F,F,F,F,F,281,1272,141,1057,629,465,F,F,244,F,F,635,F,F,F,F,F,F,1381,F,F,F,480,648,F,F,272,420,1355,1372,407,651,F,633,F,20,229,1343,825,1055,F,1286,F,F,218,1367,93,1250,112,78,F,492,F,F,F,F,F,F,723,1237,751,F,F,F,F,1104,505,1109,480,F,F,186,F,661,458,F,1215,F,F,1365,F,F,122,1355,480,F,602,F,F,F,F,768,21,353,1342,487,F,218,263,1316,991,529,1204,1303,1117,F,101,141,F,262,F,F,F,F,F,F,F,F,413,F,F,F,F,1254,F,F,F,F,F,F,922,F,482,F,F,F,F,F,585,1236,F,F,F,F,F,F,1084,342,F,1204,F,67,F,1150,422,897,F,F,F,1365,1150,F,F,F,F,F,F,F,1270,F,1297,F,F,F,F,1246,415,882,F,F,F,F,F,F,F,1150,F,67,1270,F,F,1342,F,F,894,461,760,21,F,318,F,F,F,261,F,F,F,F,F,F,F,328,F,F,507,F,F,F,1135,F,F,F,F,F,1230,362,F,F,328,F,1216,F,1241,385,284,F,1433,189,1333,F,33,85,753,F,909,F,F,363,F,F,656,F,F,979,F,F,F,F,F,F,353,F,450,756,F,F,1297,464,342,469,F,963,505,1215,1365,753,1453,521,F,1088,580,956,645,344,F,F,F,F,F,F,F,F,F,F,1304,F,F,F,F,F,F,F,F,F,F,F,650,F,814,F,885,F,344,85,1343,F,F,78,F,291,F,F,F,F,F,1433,F,629,F,F,1201,218,F,672,1322,265,945,1229,745,1502,344,962,46,19,934,F,F,F,F,975,F,F,F,F,F,F,974,455,1268,590,F,F,F,207,F,F,1341,F,F,F,145,1490,783,142,275,1356,1434,F,1152,1327,1280,1306,F,277,1092,1266,1266,F,F,291,F,F,F,F,F,F,F,F,593,F,F,F,F,F,1238,1359,1365,F,F,1167,F,509,F,1154,921,F,250,1157,688,1422,1240,485,17,852,928,F,F,F,1077,1141,F,F,753,F,F,F,1360,F,F,F,F,F,F,F,1411,F,F,1332,F,F,F,F,F,F,F,150,627,1365,121,1340,F,F,148,F,627,F,F,F,F,F,F,F,F,1199,F,1496,F,F,1266,F,F,F,F,1260,F,67,418,921,1032,F,324,F,1055,F,F,F,F,F,F,F,460,F,F,F,F,F,F,F,37,1365,1365,F,483,F,F,F,F,F,F,F,F,F,F,F,F,576,1488,F,F,F,F,F,F,1268,723,F,F,F,F,F,F,F,F,629,1448,316,645,92,272,395,1296,627,923,F,F,532,1215,F,526,1274,F,F,F,F,963,F,F,F,1468,F,F,F,F,532,F,F,F,F,507,F,1456,1268,661,1328,979,1343,645,77,474,485,267,1453,967,505,F,F,F,F,524,F,46,F,F,54,363,184,F,930,1118,1238,142,665,1365,593,930,250,1141,1422,F,316,645,1185,482,929,450,F,F,1365,923,1365,1219,F,24,921,1022,938,1320,1267,F,F,F,F,F,F,187,318,F,589,402,972,295,956,F,F,696,569,1333,F,F,972,158,488,F,1459,F,278,1220,F,783,F,F,F,1323,1190,F,F,F,F,F,1289,402,904,F,F,F,1366,431,F,1480,F,F,1064,1366,F,F,485,F,934,1104,186,1496,623,799,F,1219,1411,F,228,F,F,F,F,F,F,274,60,504,928,430,153,1323,F,1325,F,F,F,1115,1165,1333,F,550,F,1473,401,6,415,F,F,F,F,1136,1304,63,F,335,962,707,F,405,F,F,F,F,F,57,532,175,F,F,916,F,F,F,1343,F,F,929,1343,295,651,F,57,F,F,F,F,F,480,752,F,F,1314,F,F,1325,F,F,F,629,F,1150,F,F,F,674,F,542,712,1274,F,F,F,F,7,F,F,1141,F,F,717,F,404,F,F,F,360,782,426,F,F,F,F,F,F,F,F,F,F,972,F,F,F,F,101,1037,F,F,F,F,886,F,F,F,F,F,F,1279,1274,524,F,F,F,F,142,783,F,F,F,F,F,790,F,F,F,F,F,F,F,F,F,F,F,F,760,F,1060,F,250,F,F,651,F,1440,1333,F,344,930,956,524,76,710,1413,1144,F,F,F,150,F,F,F,F,F,F,1417,F,1077,1358,F,956,235,1170,1474,F,1347,F,783,77,580,1447,286,191,40,F,F,F,F,F,F,F,F,F,627,F,F,524,1241,645,F,F,1249,1079,809,706,518,F,F,F,F,1297,F,F,F,F,1249,F,235,F,1257,12,218,1489,420,179,54,F,267,F,1424,F,F,F,1317,F,F,246,477,F,F,F,741,F,F,344,F,40,556,710,989,1488,928,1261,1118,1297,768,F,F,F,702,F,F,F,F,1410,120,1067,1249,F,F,F,F,6,37,425,F,1035,1136,1170,1320,F,76,998,F,F,F,F,F,F,F,F,F,F,1044,1246,F,F,18,F,1470,F,896,1128,1340,1118,268,1118,682,F,F,F,F,F,F,F,F,F,327,1508,F,784,242,1446,213,640,484,77,192,142,84,F,1028,1390,F,F,F,F,F,502,F,F,1334,671,F,96,F,F,F,F,F,F,F,F,F,800,F,F,F,F,F,F,F,505,916,1472,1390,F,F,F,F,F,F,629,F,F,596,F,F,150,F,F,1422,F,F,F,F,F,F,F,F,F,60,1245,F,F,F,F,444,F,F,F,900,F,1039,F,F,F,F,800,328,1303,F,1079,F,526,F,F,F,532,F,1367,F,404,404,F,450,F,F,629,1280,306,1370,413,F,F,514,F,556,F,1488,327,F,881,371,F,491,799,F,1266,629,130,F,306,371,1367,450,327,18,F,F,F,307,F,F,F,F,556,572,78,F,F,1338,502,F,F,F,F,F,F,F,130,371,1007,F,1375,F,648,F,F,F,F,1367,1375,1415,1274,F,F,740,F,F,F,400,518,F,1218,F,F,1258,1237,1480,1238,585,F,F,717,F,428,422,319,822,1362,456,F,F,607,F,F,F,F,F,F,F,F,F,F,F,F,F,1458,400,57,488,1177,F,688,564,1503,651,921,F,1458,F,F,F,F,383,1498,F,1326,1121,F,585,F,123,F,1332,F,F,1367,F,F,F,F,F,F,1087,600,1343,268,F,F,F,1253,1351,342,1469,343,F,1124,628,1334,1210,F,F,F,F,F,F,F,401,F,F,F,688,1325,921,607,1458,F,400,449,F,F,F,F,F,F,512,F,F,147,F,F,524,1411,F,735,F,F,F,F,F,F,F,1457,353,1293,483,F,F,F,1051,717,1379,F,F,F,1085,F,F,688,1362,497,838,F,F,F,F,526,1362,F,F,1343,1165,1236,134,650,384,F,629,642,F,F,499,1327,63,921,F,F,F,1343,F,F,70,723,114,F,353,F,F,F,963,246,618,F,F,F,F,651,F,F,F,460,963,F,682,F,585,F,F,F,959,1366,456,F,F,F,620,F,992,1276,1112,F,F,274,66,F,F,F,F,822,F,431,873,F,327,761,F,F,341,F,F,450,1232,1044,F,499,1254,F,F,F,F,F,F,F,344,F,F,F,F,F,F,F,75,1460,F,1458,145,F,1071,449,82,F,F,F,F,979,F,F,F,F,F,F,F,761,F,629,F,F,486,F,F,696,F,F,723,756,1453,1267,470,391,521,F,761,F,1007,F,1253,F,1005,F,1413,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,839,1280,F,497,F,F,F,F,F,F,628,1087,1343,246,951,524,F,F,1448,F,605,85,581,1343,F,78,F,F,F,F,F,F,F,F,F,F,F,F,148,F,F,F,F,F,497,F,1250,1316,F,F,F,F,1211,706,F,320,1480,F,928,F,1141,921,F,884,66,331,1201,456,F,F,295,F,F,F,295,F,F,263,F,1005,F,F,F,F,F,295,F,F,F,F,F,F,F,1325,1112,F,F,F,F,F,1350,799,799,1341,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,728,F,F,F,F,1336,907,197,F,1238,F,415,1349,F,1186,1448,324,1360,F,F,585,12,24,F,420,F,F,F,F,F,F,F,F,F,F,F,F,774,772,F,F,512,F,F,1235,F,F,F,F,F,F,F,F,F,F,F,161,F,1136,1157,921,1154,1237,354,F,363,F,420,F,768,363,F,F,F,F,1442,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1141,F,F,F,1110,F,801,1033,F,F,F,F,F,F,F,F,F,F,F,F,1280,F,F,F,1496,1365,801,920,F,F,1069,F,F,F,F,F,F,82,F,F,F,F,F,F,F,F,F,F,F,F,F,F,588,627,F,385,F,F,F,F,F,F,F,1324,85,493,F,1253,107,648,1469,F,468,F,1190,1412,F,432,F,F,406,F,F,F,F,F,F,F,F,F,513,F,F,F,257,F,1350,F,F,F,F,1448,F,F,F,F,F,1237,F,485,999,F,F,20,229,682,1338,1235,F,F,F,F,F,935,F,F,F,F,F,F,363,485,F,F,F,F,F,F,F,F,F,F,F,1494,F,1341,F,F,1412,F,645,F,F,F,F,F,F,1338,F,F,F,F,F,F,F,1419,F,F,F,155,63,F,F,F,F,1304,1359,F,F,F,F,F,1282,1236,629,F,F,F,F,F,F,F,F,F,F,F,78,492,120,588,F,F,951,F,F,F,F,F,1099,1257,963,F,F,F,F,F,F,682,F,F,230,803,628,F,24,F,295,341,344,475,1413,9,F,F,959,155,732,1316,1201,799,453,1087,F,1301,1452,546,F,F,F,F,F,1274,1274,190,148,F,253,1343,F,261,F,F,420,1228,F,1342,41,1343,F,F,F,F,1316,F,F,F,F,1271,F,F,F,F,F,F,55,F,F,505,362,1400,F,1441,450,1257,1283,1274,344,1452,F,712,1283,934,F,F,F,329,31,460,F,F,F,F,F,F,499,F,41,1317,1332,F,F,F,F,F,1260,F,526,F,965,1375,1145,1195,753,1411,963,F,341,F,963,148,F,1360,F,F,F,F,F,F,F,F,F,F,1168,F,F,1219,F,F,1216,F,159,F,362,F,F,F,F,F,F,1369,362,963,F,F,F,F,526,F,F,257,1341,1077,1343,F,400,497,F,1114,956,F,295,464,469,1315,F,F,524,F,1416,148,611,F,921,1297,F,468,1190,F,F,F,F,F,F,F,F,F,F,F,F,344,F,F,1315,505,729,1367,F,F,F,F,F,F,F,2284,499,1448,142,F,505,1274,137,627,1375,F,1448,610,1250,963,F,1231,F,1340,1343,605,F,1194,9,532,1325,F,F,F,526,F,1441,F,1448,707,F,F,F,1300,F,F,175,158,F,F,F,F,F,934,324,124,F,731,1124,19,F,343,F,F,F,1238,1445,741,123,1323,1367,F,344,1142,484,F,327,F,F,450,523,F,1366,F,F,F,F,F,921,715,F,F,66,406,F,F,F,746,F,1229,1229,F,F,F,F,F,F,F,F,F,F,F,F,512,1367,1266,F,F,182,483,F,1270,878,344,F,F,F,656,F,1025,1310,1494,F,505,F,F,1419,F,1365,1365,715,736,712,285,344,F,F,F,F,F,F,F,493,F,F,F,F,F,F,F,F,F,F,735,F,17,F,F,F,F,556,F,F,F,F,F,F,F,F,446,F,F,F,380,F,1257,F,998,362,862,1370,1264,F,F,1044,885,F,951,1355,963,F,376,F,363,1265,F,1124,F,F,F,31,921,448,F,F,F,1257,F,1252,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,920,452,399,F,246,1056,700,742,485,1258,1265,483,124,568,150,F,F,F,F,F,F,1423,895,1124,690,627,725,150,1172,F,651,F,148,F,F,F,F,F,F,F,F,150,F,F,148,F,F,F,809,F,1356,F,F,F,F,F,F,F,F,F,F,F,F,F,F,730,F,1087,F,1260,F,295,1483,F,F,505,482,445,1076,629,F,920,F,1453,1110,963,F,F,F,F,F,F,F,F,1280,F,F,F,F,F,F,F,F,F,F,F,1110,F,F,F,1301,F,F,F,F,344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,825,1320,656,488,399,585,148,96,159,F,404,F,227,F,F,F,493,F,229,448,F,568,F,161,929,8,F,888,F,F,F,F,F,F,1316,512,725,F,70,601,383,155,F,F,1355,386,1365,1456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,92,F,656,84,684,505,1438,740,396,F,487,F,596,F,F,F,F,F,F,1085,F,F,404,F,F,725,930,600,F,430,1204,F,F,F,F,F,468,F,F,F,F,F,F,441,F,F,F,687,F,F,487,F,F,F,F,F,399,F,F,1340,460,F,F,F,F,F,F,682,F,F,F,532,F,F,F,394,F,F,968,809,F,F,702,F,F,1379,1241,148,148,1445,F,188,1472,455,505,963,1198,741,715,1365,18,31,F,885,1438,671,148,1194,F,504,F,1419,F,F,F,F,F,509,671,F,F,493,1152,123,F,190,F,F,1224,197,545,F,60,F,F,F,F,F,F,1240,F,F,929,F,F,F,355,F,F,471,F,354,F,485,355,F,457,F,F,F,1112,F,F,341,F,F,F,F,886,F,86,F,F,F,F,48,F,F,F,F,F,2141,F,F,461,F,399,F,F,851,F,F,F,F,F,F,F,F,870,F,1351,1187,1277,F,1460,F,1365,F,F,F,F,F,493,F,F,964,1229,588,1473,F,1367,406,905,1503,1496,F,1457,836,1457,F,F,F,F,893,728,F,F,F,F,57,1124,F,F,F,916,728,F,F,F,F,F,F,F,F,1338,1366,F,1367,F,1459,F,F,651,F,F,F,F,F,F,830,F,F,F,651,F,641,F,1215,F,56,768,963,F,886,269,814,1212,F,482,F,651,F,F,F,572,1124,F,F,F,1195,F,F,F,F,F,770,1399,F,F,1191,363,278,F,F,505,F,1173,1179,146,962,F,1044,F,78,627,F,684,F,640,1090,243,F,145,740,F,1158,474,F,812,F,537,452,F,672,1338,F,753,131,F,F,F,1137,F,553,344,F,130,F,F,1136,F,F,958,F,F,F,1124,F,1379,F,1136,569,78,F,666,753,F,633,1309,1136,702,57,F,F,F,F,F,F,F,1170,F,632,1150,1055,F,963,272,461,1037,F,F,F,583,F,F,F,814,F,814,682,1510,1343,1268,130,1184,F,F,F,F,F,F,1109,F,729,F,F,728,F,F,F,1229,F,48,934,F,752,F,F,656,1250,F,716,F,1194,F,F,760,F,715,F,F,F,F,1512,F,1212,549,1212,F,F,F,135,1444,F,150,1172,F,1448,120,120,F,F,950,1211,470,974,1419,491,120,F,465,F,468,146,F,465,437,1378,F,1203,58,F,465,1378,F,F,F,F,753,374,F,374,F,401,F,120,F,974,1015,583,896,F,F,1006,F,F,F,823,452,510,801,41,F,800,F,57,450,627,F,F,1457,767,F,969,334,56,627,67,803,266,F,F,632,801,632,694,1433,F,F,F,486,1341,F,662,F,1343,F,F,F,F,224,274,F,F,F,F,1342,F,1147,422,1090,F,F,F,F,1228,F,1124,1333,308,F,1441,F,F,F,F,F,F,F,270,491,F,F,F,865,468,F,114,1052,670,F,1367,F,1325,1229,941,681,1367,F,F,1199,1238,F,1214,F,F,475,46,945,F,509,598,F,1447,1365,F,190,F,F,F,F,F,F,1338,257,114,252,702,F,244,1235,804,620,74,1252,F,1440,F,F,1002,1226,730,507,F,446,F,1418,1006,1483,862,F,753,F,730,F,920,753,F,F,1445,31,F,740,F,672,F,920,150,F,627,1343,F,F,244,213,F,280,696,1324,F,1409,1324,280,915,F,F,1375,F,F,F,480,700,492,825,947,F,947,F,F,1338,F,1145,F,F,F,F,491,F,F,112,F,228,1165,629,F,F,807,120,1392,F,F,F,F,1367,F,1504,58,F,F,160,F,F,F,F,F,F,F,978,F,978,1326,79,F,F,F,F,201,F,F,F,F,F,F,F,F,F,F,728,F,555,F,201,F,F,F,F,F,F,1258,F,694,F,1077,250,F,F,1077,F,F,262,516,F,1370,353,F,F,F,471,482,F,882,F,242,F,52,F,F,865,1142,F,1369,359,1369,F,F,F,F,F,486,F,F,F,563,F,F,267,F,F,1266,923,F,1032,F,F,F,1347,F,F,661,F,F,1164,661,F,F,992,F,F,1347,905,915,1370,999,F,319,F,F,F,422,728,450,1483,F,651,516,999,F,F,F,905,395,307,F,F,F,F,F,F,F,F,201,537,F,F,F,F,F,F,F,1299,F,306,1170,F,1327,F,8,450,1258,F,450,F,1110,628,F,1343,383,F,1154,F,F,F,F,F,F,974,1488,627,F,502,F,F,316,916,1110,F,505,670,629,120,F,1334,F,F,F,F,468,F,F,F,445,250,961,532,F,888,F,F,F,584,F,F,1259,1167,F,F,1304,F,F,F,F,F,59,F,F,429,632,F,F,1124,642,F,F,F,F,509,F,F,F,F,629,120,F,F,921,921,F,F,1483,F,1483,F,921,F,921,1483,F,1415,1415,856,F,523,F,F,1324,F,F,423,768,F,F,F,F,F,F,1324,F,1422,1079,1446,F,F,F,F,1410,F,F,F,F,F,F,999,1410,F,1343,744,1318,F,1448,F,592,1262,F,890,1142,F,1342,F,F,1297,1415,F,286,295,F,F,F,1345,F,295,1125,9,214,F,1216,F,1216,1262,F,F,186,F,1125,F,F,F,F,F,F,F,485,931,1496,1415,1378,1422,F,1341,F,F,F,F,F,1318,1373,F,F,F,F,F,430,928,F,F,F,111,F,F,F,F,850,F,F,F,F,450,F,1325,F,945,78,934,507,F,F,F,F,F,F,F,F,F,F,F,801,F,F,121,948,F,1395,F,F,1346,1282,F,921,493,F,F,1301,F,1409,F,295,F,F,F,1495,1342,F,F,F,F,1446,629,F,F,629,1345,F,F,632,F,120,F,532,F,1382,F,F,F,F,F,F,1415,890,F,661,F,661,F,F,F,F,661,F,F,F,F,661,F,F,661,F,707,F,F,1433,F,F,F,F,F,F,355,378,978,201,499,F,F,F,F,F,F,79,F,1460,958,1326,F,F,F,F,1277,F,469,962,F,F,F,F,651,F,1345,F,F,F,888,F,F,F,632,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,285,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1870,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,27,F,F,F,F,F,F,1419,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,674,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,665,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,642,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1115,F,F,F,F,F,F,F,F,F,F,F,F,531,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,962,F,F,F,F,F,1064,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1355,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,832,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,191,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1268,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,327,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1124,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,568,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1323,145,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,1314,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1237,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,928,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,375,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,682,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,F,F,1341,F,F,782,1190,F,F,F,F,F,F,F,F,F,F,F,1266,1259,F,1276,F,F,F,F,F,F,F,F,F,F,1306,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1925,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1375,F,577,F,F,651,F,F,F,F,F,F,799,F,F,1584,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,723,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,418,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,341,F,191,F,F,F,F,F,482,F,F,F,F,F,F,1306,F,F,482,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,316,532,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,814,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1342,343,F,F,F,1376,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1423,F,F,F,F,F,85,1124,F,F,F,F,F,F,465,F,F,F,F,F,1419,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1241,532,F,F,F,F,F,420,6,F,1158,672,1168,F,1370,F,1258,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1304,442,F,F,F,F,F,F,F,F,342,F,469,1077,189,F,F,915,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,645,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1445,F,F,532,F,F,F,818,F,F,F,F,F,F,F,F,F,F,581,F,F,F,F,F,F,F,F,148,F,1168,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1085,456,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,719,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,635,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1131,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,442,F,F,218,F,F,F,F,F,F,F,F,F,586,F,F,F,1307,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,799,F,F,F,F,F,F,F,F,667,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,228,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1740,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2058,F,F,679,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,610,F,F,F,F,F,F,1055,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,497,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1289,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,257,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1283,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,566,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,255,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,230,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1237,1268,F,F,F,F,F,F,F,F,F,F,F,F,1056,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,486,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1448,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,783,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1230,F,F,F,1559,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,405,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,2056,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,629,F,F,F,F,F,231,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,414,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1452,F,F,F,F,F,F,F,F,F,F,F,F,156,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,974,F,F,F,F,F,F,F,F,F,F,F,372,F,F,601,1286,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1794,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,227,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,1185,F,461,932,1622,F,1477,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,F,F,F,F,F,F,753,1270,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,811,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,704,F,F,636,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,901,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,265,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1301,F,F,F,F,1459,F,F,493,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,434,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,1080,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,471,F,F,F,F,F,F,F,1469,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,921,F,F,F,F,F,F,F,F,F,F,F,1360,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1315,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,825,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1343,F,F,F,678,125,F,F,509,F,F,F,F,F,F,1090,1070,F,670,F,F,F,1427,F,760,F,F,F,F,F,F,F,F,274,F,F,F,442,F,F,F,F,F,F,F,F,F,F,F,183,77,F,F,F,F,F,F,F,F,446,F,F,F,F,1245,F,F,1865,F,F,898,F,F,F,1259,F,F,432,F,648,F,F,F,1131,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1344,F,F,F,F,F,F,F,F,F,F,F,F,F,1772,482,F,F,F,F,F,F,F,F,F,F,F,F,F,1208,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,761,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,246,F,F,F,F,F,F,F,F,1237,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,141,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,121,F,F,885,516,F,F,F,F,F,F,1069,F,1199,F,F,324,477,F,F,F,F,F,F,648,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,523,F,1216,F,1236,1430,F,F,F,F,F,F,F,F,F,F,F,F,F,F,596,634,F,F,F,F,F,F,F,485,F,F,F,F,485,1259,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1582,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1324,F,1375,F,F,F,F,F,F,F,F,1265,F,F,1480,F,F,F,316,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,1282,F,F,F,F,F,F,F,F,F,F,F,1342,F,F,F,F,F,F,F,F,F,F,160,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,F,627]),
+PinyinRangeTable::new(0x3007..=0x3007, &[1984]),
+PinyinRangeTable::new(0xE815..=0xE864, &[1338,2247,F,402,F,2280,2280,F,1904,F,1224,419,419,F,F,1456,1456,1084,356,576,1116,1116,332,376,356,1864,1142,491,302,1276,F,1992,1589,1458,1635,1233,F,2207,1384,1536,1536,F,1654,956,1864,1864,529,1283,1325,951,951,F,1055,2224,907,1055,1479,1055,532,169,1444,169,1444,1480,1350,1365,1347,182,958,1365,1165,1077,499,645,512,524,1167,888,1324,674]),
 PinyinRangeTable::new(0xFA18..=0xFA18, &[628]),
 PinyinRangeTable::new(0x2F835..=0x2F835, &[468]),
 PinyinRangeTable::new(0x30EDD..=0x30EDE, &[62,6])
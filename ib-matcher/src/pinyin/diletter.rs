@@ -0,0 +1,261 @@
+use std::{collections::HashMap, fmt};
+
+use bon::bon;
+
+use super::{data, notation, Pinyin, PinyinString};
+
+/// A custom 双拼 (diletter) scheme, used by [`PinyinNotation::DiletterCustom`](super::PinyinNotation::DiletterCustom).
+///
+/// A pinyin with an initial is mapped to the key of its initial followed by the key of its final. Single-letter initials are mapped to themselves by default.
+///
+/// A pinyin without an initial (零声母) is mapped by:
+/// 1. `zero_initials`, e.g. "ang" → "ah" in 小鹤双拼.
+/// 2. `zero_initial_key` followed by the key of its final, e.g. "ang" → "oh" in 微软双拼.
+/// 3. The pinyin itself if it has exactly 2 letters, e.g. "er" → "er".
+///
+/// ## Example
+/// ```
+/// use ib_matcher::{
+///     matcher::{IbMatcher, PinyinMatchConfig},
+///     pinyin::{DiletterScheme, PinyinData, PinyinNotation},
+/// };
+///
+/// // 搜狗双拼
+/// let scheme = DiletterScheme::builder()
+///     .initials(&[("zh", 'v'), ("ch", 'i'), ("sh", 'u')])
+///     .finals(&[
+///         ("i", 'i'), ("u", 'u'), ("v", 'y'),
+///         ("e", 'e'), ("ie", 'x'), ("o", 'o'), ("uo", 'o'), ("ue", 't'), ("ve", 'v'),
+///         ("a", 'a'), ("ia", 'w'), ("ua", 'w'),
+///         ("ei", 'z'), ("ui", 'v'),
+///         ("ai", 'l'), ("uai", 'y'),
+///         ("ou", 'b'), ("iu", 'q'),
+///         ("ao", 'k'), ("iao", 'c'),
+///         ("in", 'n'), ("un", 'p'), ("vn", 'p'),
+///         ("en", 'f'),
+///         ("an", 'j'), ("ian", 'm'), ("uan", 'r'), ("van", 'r'),
+///         ("ing", ';'),
+///         ("ong", 's'), ("iong", 's'),
+///         ("eng", 'g'),
+///         ("ang", 'h'), ("iang", 'd'), ("uang", 'd'),
+///         ("er", 'r'),
+///     ])
+///     .zero_initial_key('o')
+///     .build()
+///     .unwrap();
+///
+/// let mut data = PinyinData::new(PinyinNotation::empty());
+/// data.init_diletter_scheme(&scheme);
+/// let matcher = IbMatcher::builder("pnyn")
+///     .pinyin(PinyinMatchConfig::builder(PinyinNotation::DiletterCustom).data(&data).build())
+///     .build();
+/// assert!(matcher.is_match("拼音"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiletterScheme {
+    initials: HashMap<String, char>,
+    finals: HashMap<String, char>,
+    zero_initials: HashMap<String, PinyinString>,
+    zero_initial_key: Option<char>,
+}
+
+#[bon]
+impl DiletterScheme {
+    /// ## Arguments
+    /// - `initials`: At least "zh", "ch" and "sh" must be mapped.
+    ///
+    ///   See [initials](https://en.wikipedia.org/wiki/Pinyin#Initials) for details.
+    ///
+    /// - `finals`: All finals must be mapped, in their [`PinyinNotation::Ascii`](super::PinyinNotation::Ascii) spellings (e.g. "iu", "ui", "un", "ve").
+    ///
+    ///   See [finals](https://en.wikipedia.org/wiki/Pinyin#Finals) for details.
+    ///
+    /// - `zero_initials`: Pinyins without an initial and their 2 keys.
+    /// - `zero_initial_key`: The key before the final of a pinyin without an initial, if not in `zero_initials`.
+    ///
+    /// All keys must be ASCII graphic characters and not upper case.
+    #[builder]
+    pub fn new(
+        initials: &[(&str, char)],
+        finals: &[(&str, char)],
+        zero_initials: Option<&[(&str, &str)]>,
+        zero_initial_key: Option<char>,
+    ) -> Result<Self, DiletterSchemeError> {
+        let check_key = |key: char| {
+            if key.is_ascii_graphic() && !key.is_ascii_uppercase() {
+                Ok(key)
+            } else {
+                Err(DiletterSchemeError::InvalidKey(key.to_string()))
+            }
+        };
+
+        let map = |keys: &[(&str, char)]| {
+            keys.iter()
+                .map(|&(s, key)| Ok((s.to_owned(), check_key(key)?)))
+                .collect::<Result<HashMap<_, _>, _>>()
+        };
+        let initials = map(initials)?;
+        let finals = map(finals)?;
+        let zero_initials = zero_initials
+            .unwrap_or_default()
+            .iter()
+            .map(|&(s, keys)| {
+                if keys.chars().count() != 2 || keys.chars().any(|key| check_key(key).is_err()) {
+                    return Err(DiletterSchemeError::InvalidKey(keys.to_owned()));
+                }
+                Ok((s.to_owned(), keys.into()))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let zero_initial_key = zero_initial_key.map(check_key).transpose()?;
+
+        let scheme = Self {
+            initials,
+            finals,
+            zero_initials,
+            zero_initial_key,
+        };
+        for unicode in data::PINYINS {
+            let ascii = notation::unicode_to_ascii(unicode);
+            if scheme.ascii_to_diletter(&ascii).is_none() {
+                return Err(DiletterSchemeError::Unmapped(ascii.to_string()));
+            }
+        }
+        Ok(scheme)
+    }
+}
+
+impl DiletterScheme {
+    /// `None` if the initial or final of the pinyin is not mapped.
+    pub(super) fn ascii_to_diletter(&self, ascii: &str) -> Option<PinyinString> {
+        let ascii = notation::diletter_syllabic(ascii);
+
+        let mut diletter = PinyinString::new();
+        match Pinyin::split_initial_final(ascii) {
+            ("", final_) => {
+                if let Some(&keys) = self.zero_initials.get(final_) {
+                    return Some(keys);
+                }
+                match self.zero_initial_key {
+                    Some(key) => diletter.extend([key, *self.finals.get(final_)?]),
+                    None if final_.len() == 2 => return Some(final_.into()),
+                    None => return None,
+                }
+            }
+            (initial, final_) => {
+                let initial = match self.initials.get(initial) {
+                    Some(&key) => key,
+                    None if initial.len() == 1 => initial.chars().next().unwrap(),
+                    None => return None,
+                };
+                diletter.extend([initial, *self.finals.get(final_)?]);
+            }
+        }
+        Some(diletter)
+    }
+}
+
+/// An error that occurred when building a [`DiletterScheme`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiletterSchemeError {
+    /// The key is not an ASCII graphic character, is upper case, or a zero-initial mapping is not 2 keys.
+    InvalidKey(String),
+    /// The pinyin cannot be mapped with the given initials and finals.
+    Unmapped(String),
+}
+
+impl fmt::Display for DiletterSchemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(f, "invalid diletter key: {key:?}"),
+            Self::Unmapped(pinyin) => write!(f, "unmapped pinyin: {pinyin:?}"),
+        }
+    }
+}
+
+impl std::error::Error for DiletterSchemeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pinyin::{PinyinData, PinyinNotation};
+
+    #[rustfmt::skip]
+    fn xiaohe() -> Result<DiletterScheme, DiletterSchemeError> {
+        DiletterScheme::builder()
+            .initials(&[("zh", 'v'), ("ch", 'i'), ("sh", 'u')])
+            .finals(&[
+                ("i", 'i'), ("u", 'u'), ("v", 'v'),
+                ("e", 'e'), ("ie", 'p'), ("o", 'o'), ("uo", 'o'), ("ue", 't'), ("ve", 't'),
+                ("a", 'a'), ("ia", 'x'), ("ua", 'x'),
+                ("ei", 'w'), ("ui", 'v'),
+                ("ai", 'd'), ("uai", 'k'),
+                ("ou", 'z'), ("iu", 'q'),
+                ("ao", 'c'), ("iao", 'n'),
+                ("in", 'b'), ("un", 'y'), ("vn", 'y'),
+                ("en", 'f'),
+                ("an", 'j'), ("ian", 'm'), ("uan", 'r'), ("van", 'r'),
+                ("ing", 'k'),
+                ("ong", 's'), ("iong", 's'),
+                ("eng", 'g'),
+                ("ang", 'h'), ("iang", 'l'), ("uang", 'l'),
+            ])
+            .zero_initials(&[
+                ("e", "ee"), ("o", "oo"), ("a", "aa"),
+                ("ei", "ei"), ("ai", "ai"), ("ou", "ou"), ("ao", "ao"),
+                ("en", "en"), ("an", "an"), ("eng", "eg"), ("ang", "ah"),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn builtin() {
+        let scheme = xiaohe().unwrap();
+        let map = notation::ascii_map_fn(PinyinNotation::DiletterXiaohe);
+        for unicode in data::PINYINS {
+            let ascii = notation::unicode_to_ascii(unicode);
+            assert_eq!(
+                scheme.ascii_to_diletter(&ascii),
+                Some(map(&ascii)),
+                "{ascii}"
+            );
+        }
+    }
+
+    #[test]
+    fn init_diletter_scheme() {
+        #[cfg_attr(feature = "inmut-data", allow(unused_mut))]
+        let mut data = PinyinData::new(PinyinNotation::empty());
+        data.init_diletter_scheme(&xiaohe().unwrap());
+        data.init_diletter_scheme(&xiaohe().unwrap());
+        assert!(data
+            .inited_notations()
+            .contains(PinyinNotation::DiletterCustom));
+    }
+
+    #[test]
+    #[should_panic = "a different diletter scheme is already inited"]
+    fn init_diletter_scheme_different() {
+        #[cfg_attr(feature = "inmut-data", allow(unused_mut))]
+        let mut data = PinyinData::new(PinyinNotation::empty());
+        data.init_diletter_scheme(&xiaohe().unwrap());
+        data.init_diletter_scheme(&DiletterScheme {
+            zero_initial_key: Some('o'),
+            ..xiaohe().unwrap()
+        });
+    }
+
+    #[test]
+    fn error() {
+        let e = DiletterScheme::builder()
+            .initials(&[("zh", 'v'), ("ch", 'i'), ("sh", 'u')])
+            .finals(&[("a", 'a')])
+            .build();
+        assert!(matches!(e, Err(DiletterSchemeError::Unmapped(_))));
+
+        let e = DiletterScheme::builder()
+            .initials(&[("zh", 'V')])
+            .finals(&[])
+            .build();
+        assert_eq!(e.unwrap_err(), DiletterSchemeError::InvalidKey("V".into()));
+    }
+}
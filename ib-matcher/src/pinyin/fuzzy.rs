@@ -0,0 +1,141 @@
+use super::{Pinyin, PinyinString};
+
+bitflags::bitflags! {
+    /// 模糊音, i.e. treating similar initials/finals as the same, mainly for speakers of southern Chinese dialects.
+    ///
+    /// Fuzzy variants of a pinyin are generated from its [`PinyinNotation::Ascii`](super::PinyinNotation::Ascii) and then converted to other notations. [`PinyinNotation::Unicode`](super::PinyinNotation::Unicode) is not supported.
+    ///
+    /// e.g. With [`FuzzyPinyin::ZZh`], "zong" can match "中" (zhong).
+    #[doc(alias = "模糊音")]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct FuzzyPinyin: u32 {
+        /// z ↔ zh
+        const ZZh = 0x1;
+        /// c ↔ ch
+        const CCh = 0x2;
+        /// s ↔ sh
+        const SSh = 0x4;
+        /// n ↔ l
+        const NL = 0x8;
+        /// f ↔ h
+        const FH = 0x10;
+        /// r ↔ l
+        const RL = 0x20;
+
+        /// an ↔ ang
+        const AnAng = 0x100;
+        /// en ↔ eng
+        const EnEng = 0x200;
+        /// in ↔ ing
+        const InIng = 0x400;
+        /// ian ↔ iang
+        const IanIang = 0x800;
+    }
+}
+
+impl FuzzyPinyin {
+    /// Calls `f` with each fuzzy variant of the given [`PinyinNotation::Ascii`](super::PinyinNotation::Ascii) pinyin, excluding the pinyin itself, until `f` returns `Some`.
+    ///
+    /// Variants are not guaranteed to be valid pinyins, e.g. "hong" with [`FuzzyPinyin::FH`] gives "fong".
+    pub fn variants_and_try_for_each<T>(
+        &self,
+        ascii: &str,
+        mut f: impl FnMut(&str) -> Option<T>,
+    ) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // Syllabic consonants
+        if matches!(ascii, "m" | "n" | "ng" | "hm" | "hng") {
+            return None;
+        }
+
+        let (initial, final_) = Pinyin::split_initial_final(ascii);
+        let initials = [
+            Some(initial),
+            match initial {
+                "z" if self.contains(Self::ZZh) => Some("zh"),
+                "zh" if self.contains(Self::ZZh) => Some("z"),
+                "c" if self.contains(Self::CCh) => Some("ch"),
+                "ch" if self.contains(Self::CCh) => Some("c"),
+                "s" if self.contains(Self::SSh) => Some("sh"),
+                "sh" if self.contains(Self::SSh) => Some("s"),
+                "n" if self.contains(Self::NL) => Some("l"),
+                "l" if self.contains(Self::NL) => Some("n"),
+                "f" if self.contains(Self::FH) => Some("h"),
+                "h" if self.contains(Self::FH) => Some("f"),
+                "r" if self.contains(Self::RL) => Some("l"),
+                _ => None,
+            },
+            match initial {
+                "l" if self.contains(Self::RL) => Some("r"),
+                _ => None,
+            },
+        ];
+        let finals = [
+            Some(final_),
+            match final_ {
+                "an" if self.contains(Self::AnAng) => Some("ang"),
+                "ang" if self.contains(Self::AnAng) => Some("an"),
+                "en" if self.contains(Self::EnEng) => Some("eng"),
+                "eng" if self.contains(Self::EnEng) => Some("en"),
+                "in" if self.contains(Self::InIng) => Some("ing"),
+                "ing" if self.contains(Self::InIng) => Some("in"),
+                "ian" if self.contains(Self::IanIang) => Some("iang"),
+                "iang" if self.contains(Self::IanIang) => Some("ian"),
+                _ => None,
+            },
+        ];
+
+        for (i, initial) in initials.into_iter().enumerate() {
+            let Some(initial) = initial else { continue };
+            for (j, final_) in finals.into_iter().enumerate() {
+                let Some(final_) = final_ else { continue };
+                if i == 0 && j == 0 {
+                    continue;
+                }
+                let mut variant = PinyinString::new();
+                if variant.try_push_str(initial).is_err() || variant.try_push_str(final_).is_err() {
+                    continue;
+                }
+                if let Some(v) = f(&variant) {
+                    return Some(v);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variants(fuzzy: FuzzyPinyin, ascii: &str) -> Vec<String> {
+        let mut variants = Vec::new();
+        fuzzy.variants_and_try_for_each(ascii, |variant| {
+            variants.push(variant.to_owned());
+            None::<()>
+        });
+        variants
+    }
+
+    #[test]
+    fn fuzzy_variants() {
+        assert!(variants(FuzzyPinyin::empty(), "zhong").is_empty());
+        assert_eq!(variants(FuzzyPinyin::ZZh, "zhong"), ["zong"]);
+        assert_eq!(variants(FuzzyPinyin::ZZh, "zong"), ["zhong"]);
+        assert!(variants(FuzzyPinyin::CCh, "zhong").is_empty());
+        assert_eq!(
+            variants(FuzzyPinyin::NL | FuzzyPinyin::AnAng, "nan"),
+            ["nang", "lan", "lang"]
+        );
+        assert_eq!(
+            variants(FuzzyPinyin::NL | FuzzyPinyin::RL, "lu"),
+            ["nu", "ru"]
+        );
+        assert_eq!(variants(FuzzyPinyin::IanIang, "xian"), ["xiang"]);
+        assert_eq!(variants(FuzzyPinyin::AnAng, "ang"), ["an"]);
+        assert!(variants(FuzzyPinyin::all(), "ng").is_empty());
+    }
+}
@@ -1,9 +1,19 @@
 //! [Pinyin](https://en.wikipedia.org/wiki/Pinyin)
 
-use std::ops::RangeInclusive;
+use std::{borrow::Cow, collections::HashMap, ops::RangeInclusive, sync::OnceLock};
 
+mod alphabet;
+mod collate;
+mod convert;
 mod data;
+mod diletter;
+mod fuzzy;
 mod notation;
+mod reverse;
+mod segment;
+mod umlaut;
+#[cfg(feature = "pinyin-words")]
+mod words;
 
 pub(super) type PinyinCombination = [u16; data::PINYIN_COMBINATION_LEN];
 
@@ -21,20 +31,34 @@ impl PinyinRangeTable {
     }
 }
 
+pub use alphabet::AlphabetIndex;
+pub use collate::{CollateHanzi, CollateTone, PinyinCollator};
+pub use convert::NonHanzi;
+pub use diletter::{DiletterScheme, DiletterSchemeError};
+pub use fuzzy::FuzzyPinyin;
 use itertools::Itertools;
-pub use notation::PinyinNotation;
+pub(crate) use notation::{is_combining_mark, UNICODE_DECOMPOSED_MAX_LEN};
+pub use notation::{PinyinNotation, PinyinNotationError};
+pub use segment::PinyinSyllable;
+pub use umlaut::UmlautSpelling;
+#[cfg(feature = "pinyin-words")]
+pub use words::{PinyinWords, PinyinWordsError};
 
-type PinyinString = arraystring::ArrayString<arraystring::typenum::U7>;
+pub(crate) type PinyinString = arraystring::ArrayString<arraystring::typenum::U7>;
+/// Zhuyin symbols are 3 bytes each.
+type ZhuyinString = arraystring::ArrayString<arraystring::typenum::U11>;
 
 #[cfg(not(feature = "inmut-data"))]
-type OptionalPinyinStringArray = Option<Box<[PinyinString]>>;
+type OptionalStringArray<S> = Option<Box<[S]>>;
 #[cfg(feature = "inmut-data")]
-type OptionalPinyinStringArray = std::sync::OnceLock<Box<[PinyinString]>>;
+type OptionalStringArray<S> = std::sync::OnceLock<Box<[S]>>;
+type OptionalPinyinStringArray = OptionalStringArray<PinyinString>;
 
 /// ## Memory usage
 /// Per pinyin notation: 8 * 1514 ≈ 11.8 KiB.
 /// - `Unicode` does not require extra memory.
-/// - `AsciiFirstLetter` uses the same storage as `Ascii`.
+/// - `AsciiFirstLetter` and `AsciiInitial` use the same storage as `Ascii`.
+/// - `Zhuyin` and `ZhuyinTone`: 12 * 1514 ≈ 17.7 KiB.
 ///
 /// ## Others
 /// TODO: Optionally generate pinyin notation data at build time.
@@ -62,6 +86,16 @@ pub struct PinyinData {
     diletter_thunisoft: OptionalPinyinStringArray,
     diletter_xiaohe: OptionalPinyinStringArray,
     diletter_zrm: OptionalPinyinStringArray,
+    #[cfg(not(feature = "inmut-data"))]
+    diletter_scheme: Option<DiletterScheme>,
+    #[cfg(feature = "inmut-data")]
+    diletter_scheme: std::sync::OnceLock<DiletterScheme>,
+    diletter_custom: OptionalPinyinStringArray,
+    zhuyin: OptionalStringArray<ZhuyinString>,
+    zhuyin_tone: OptionalStringArray<ZhuyinString>,
+    zhuyin_daqian: OptionalPinyinStringArray,
+
+    analyze_cache: crate::matcher::analyze::PinyinAnalyzeCache,
 }
 
 impl PinyinData {
@@ -78,6 +112,12 @@ impl PinyinData {
             diletter_thunisoft: Default::default(),
             diletter_xiaohe: Default::default(),
             diletter_zrm: Default::default(),
+            diletter_scheme: Default::default(),
+            diletter_custom: Default::default(),
+            zhuyin: Default::default(),
+            zhuyin_tone: Default::default(),
+            zhuyin_daqian: Default::default(),
+            analyze_cache: Default::default(),
         };
 
         pinyin_data.init_notations(notations);
@@ -90,21 +130,28 @@ impl PinyinData {
             PinyinNotation::Ascii => &self.ascii,
             PinyinNotation::AsciiTone => &self.ascii_tone,
             PinyinNotation::AsciiFirstLetter => unreachable!(),
+            PinyinNotation::AsciiInitial => unreachable!(),
             PinyinNotation::DiletterAbc => &self.diletter_abc,
             PinyinNotation::DiletterJiajia => &self.diletter_jiajia,
             PinyinNotation::DiletterMicrosoft => &self.diletter_microsoft,
             PinyinNotation::DiletterThunisoft => &self.diletter_thunisoft,
             PinyinNotation::DiletterXiaohe => &self.diletter_xiaohe,
             PinyinNotation::DiletterZrm => &self.diletter_zrm,
+            PinyinNotation::DiletterCustom => &self.diletter_custom,
+            PinyinNotation::Zhuyin => unreachable!(),
+            PinyinNotation::ZhuyinTone => unreachable!(),
+            PinyinNotation::ZhuyinDaqian => &self.zhuyin_daqian,
             _ => unreachable!(),
         }
     }
 
+    /// [`PinyinNotation::DiletterCustom`] can only be inited by [`PinyinData::init_diletter_scheme()`] and is ignored here.
     #[cfg(not(feature = "inmut-data"))]
     pub fn init_notations(&mut self, notations: PinyinNotation) {
         Self::init_notations_inner(self, notations)
     }
 
+    /// [`PinyinNotation::DiletterCustom`] can only be inited by [`PinyinData::init_diletter_scheme()`] and is ignored here.
     #[cfg(feature = "inmut-data")]
     pub fn init_notations(&self, notations: PinyinNotation) {
         Self::init_notations_inner(self, notations)
@@ -116,10 +163,13 @@ impl PinyinData {
         #[cfg(feature = "inmut-data")] this: &Self,
         notations: PinyinNotation,
     ) {
+        let notations = notations - PinyinNotation::DiletterCustom;
         for notation in notations.iter() {
             match notation {
                 PinyinNotation::Unicode => (),
-                PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter => {
+                PinyinNotation::Ascii
+                | PinyinNotation::AsciiFirstLetter
+                | PinyinNotation::AsciiInitial => {
                     let init = || {
                         data::PINYINS
                             .iter()
@@ -145,6 +195,32 @@ impl PinyinData {
                     #[cfg(feature = "inmut-data")]
                     this.ascii_tone.get_or_init(init);
                 }
+                PinyinNotation::Zhuyin => {
+                    let init = || {
+                        data::PINYINS
+                            .iter()
+                            .map(|py| notation::ascii_to_zhuyin(&notation::unicode_to_ascii(py)))
+                            .collect::<Vec<_>>()
+                            .into_boxed_slice()
+                    };
+                    #[cfg(not(feature = "inmut-data"))]
+                    this.zhuyin.get_or_insert_with(init);
+                    #[cfg(feature = "inmut-data")]
+                    this.zhuyin.get_or_init(init);
+                }
+                PinyinNotation::ZhuyinTone => {
+                    let init = || {
+                        data::PINYINS
+                            .iter()
+                            .map(|py| notation::unicode_to_zhuyin_tone(py))
+                            .collect::<Vec<_>>()
+                            .into_boxed_slice()
+                    };
+                    #[cfg(not(feature = "inmut-data"))]
+                    this.zhuyin_tone.get_or_insert_with(init);
+                    #[cfg(feature = "inmut-data")]
+                    this.zhuyin_tone.get_or_init(init);
+                }
                 _ => {
                     this.init_notations(PinyinNotation::Ascii);
 
@@ -169,6 +245,7 @@ impl PinyinData {
                         PinyinNotation::DiletterThunisoft => &mut this.diletter_thunisoft,
                         PinyinNotation::DiletterXiaohe => &mut this.diletter_xiaohe,
                         PinyinNotation::DiletterZrm => &mut this.diletter_zrm,
+                        PinyinNotation::ZhuyinDaqian => &mut this.zhuyin_daqian,
                         _ => unreachable!(),
                     }
                     .get_or_insert_with(init);
@@ -183,11 +260,75 @@ impl PinyinData {
         this.inited_notations.bitor_assign(notations);
     }
 
+    /// Init [`PinyinNotation::DiletterCustom`] with the given scheme.
+    ///
+    /// If the same scheme is already inited, does nothing.
+    ///
+    /// ## Panics
+    /// If a different scheme is already inited, as the inited notations may be in use.
+    #[cfg(not(feature = "inmut-data"))]
+    pub fn init_diletter_scheme(&mut self, scheme: &DiletterScheme) {
+        if let Some(inited) = &self.diletter_scheme {
+            assert!(
+                inited == scheme,
+                "a different diletter scheme is already inited"
+            );
+            return;
+        }
+        self.init_notations(PinyinNotation::Ascii);
+        let ascii = self.ascii.as_ref().unwrap();
+        self.diletter_custom = Some(Self::diletter_custom(ascii, scheme));
+        self.diletter_scheme = Some(scheme.clone());
+        self.inited_notations |= PinyinNotation::DiletterCustom;
+    }
+
+    /// Init [`PinyinNotation::DiletterCustom`] with the given scheme.
+    ///
+    /// If the same scheme is already inited, does nothing.
+    ///
+    /// ## Panics
+    /// If a different scheme is already inited, as the inited notations may be in use.
+    #[cfg(feature = "inmut-data")]
+    pub fn init_diletter_scheme(&self, scheme: &DiletterScheme) {
+        self.init_notations(PinyinNotation::Ascii);
+        let ascii = self.ascii.get().unwrap();
+        let inited = self.diletter_scheme.get_or_init(|| scheme.clone());
+        assert!(
+            inited == scheme,
+            "a different diletter scheme is already inited"
+        );
+        self.diletter_custom
+            .get_or_init(|| Self::diletter_custom(ascii, scheme));
+        self.inited_notations
+            .bitor_assign(PinyinNotation::DiletterCustom);
+    }
+
+    fn diletter_custom(ascii: &[PinyinString], scheme: &DiletterScheme) -> Box<[PinyinString]> {
+        ascii
+            .iter()
+            // Checked by `DiletterScheme::new()`
+            .map(|py| scheme.ascii_to_diletter(py).unwrap())
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    }
+
+    fn diletter_scheme(&self) -> Option<&DiletterScheme> {
+        #[cfg(not(feature = "inmut-data"))]
+        let scheme = self.diletter_scheme.as_ref();
+        #[cfg(feature = "inmut-data")]
+        let scheme = self.diletter_scheme.get();
+        scheme
+    }
+
+    pub(crate) fn analyze_cache(&self) -> &crate::matcher::analyze::PinyinAnalyzeCache {
+        &self.analyze_cache
+    }
+
     pub fn inited_notations(&self) -> PinyinNotation {
         self.inited_notations.clone().into()
     }
 
-    fn get_pinyin_index(c: char) -> Option<u16> {
+    pub(crate) fn get_pinyin_index(c: char) -> Option<u16> {
         if PinyinRangeTable::MAX_RANGE.contains(&(c as u32)) {
             for range in &data::PINYIN_RANGE_TABLES {
                 if range.range.contains(&(c as u32)) {
@@ -215,6 +356,8 @@ impl PinyinData {
         (0..data::PINYINS.len() as u16).map(|i| self.pinyin(i))
     }
 
+    /// The readings of a char (多音字 may have multiple readings), the primary reading first, see [`PinyinData::get_primary_pinyin()`]. Other readings are in no particular order.
+    ///
     /// Prefer [`PinyinData::get_pinyins_and_for_each`] and [`PinyinData::get_pinyins_and_try_for_each`] if applicable.
     ///
     /// ## Performance
@@ -232,7 +375,23 @@ impl PinyinData {
         }
     }
 
-    pub fn get_pinyins_and_for_each(&self, c: char, mut f: impl FnMut(Pinyin)) {
+    /// The primary reading of a char, e.g. "xíng" for "行" (háng/xíng).
+    ///
+    /// Primary readings are inferred from where the [CLDR](https://cldr.unicode.org/) pinyin collation data places each char, not from frequency data. So they are not always the most common readings, e.g. "de" for "地" and "zhǎng" for "长". Contextual readings of words are not considered, see `PinyinWords` (`pinyin-words` feature) for that.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::pinyin::{PinyinData, PinyinNotation};
+    ///
+    /// let data = PinyinData::new(PinyinNotation::Ascii);
+    /// let pinyin = data.get_primary_pinyin('行').unwrap();
+    /// assert_eq!(pinyin.notation(PinyinNotation::Unicode), Some("xíng"));
+    /// ```
+    pub fn get_primary_pinyin(&self, c: char) -> Option<Pinyin<'_>> {
+        self.get_pinyins_and_try_for_each(c, Some)
+    }
+
+    pub fn get_pinyins_and_for_each<'a>(&'a self, c: char, mut f: impl FnMut(Pinyin<'a>)) {
         if let Some(i) = Self::get_pinyin_index(c) {
             if i < data::PINYINS.len() as u16 {
                 f(self.pinyin(i));
@@ -243,17 +402,31 @@ impl PinyinData {
         }
     }
 
-    pub fn get_pinyins_and_try_for_each<T>(
-        &self,
+    pub fn get_pinyins_and_try_for_each<'a, T>(
+        &'a self,
         c: char,
-        mut f: impl FnMut(Pinyin) -> Option<T>,
+        f: impl FnMut(Pinyin<'a>) -> Option<T>,
     ) -> Option<T> {
-        if let Some(i) = Self::get_pinyin_index(c) {
+        self.get_pinyins_by_index_and_try_for_each(Self::get_pinyin_index(c), usize::MAX, f)
+    }
+
+    /// [`PinyinData::get_pinyins_and_try_for_each`] with the result of `get_pinyin_index()`, which may be cached.
+    ///
+    /// ## Arguments
+    /// - `max`: Only the first `max` readings are passed to `f`.
+    #[inline]
+    pub(crate) fn get_pinyins_by_index_and_try_for_each<'a, T>(
+        &'a self,
+        index: Option<u16>,
+        max: usize,
+        mut f: impl FnMut(Pinyin<'a>) -> Option<T>,
+    ) -> Option<T> {
+        if let Some(i) = index {
             if i < data::PINYINS.len() as u16 {
                 f(self.pinyin(i))
             } else {
                 let i = i - data::PINYINS.len() as u16;
-                for &i in Self::pinyin_combination(i) {
+                for &i in Self::pinyin_combination(i).take(max) {
                     if let Some(v) = f(self.pinyin(i)) {
                         return Some(v);
                     }
@@ -321,29 +494,217 @@ impl<'a> Pinyin<'a> {
 
         let i = self.index as usize;
 
-        let get = |pinyins: &'a OptionalPinyinStringArray| {
+        fn get<S: core::ops::Deref<Target = str>>(
+            pinyins: &OptionalStringArray<S>,
+            i: usize,
+        ) -> Option<&str> {
             #[cfg(not(feature = "inmut-data"))]
-            let notation = pinyins.as_ref().map(|pinyins| pinyins[i].as_str());
+            let notation = pinyins.as_ref().map(|pinyins| &*pinyins[i]);
             #[cfg(feature = "inmut-data")]
-            let notation = pinyins.get().map(|pinyins| pinyins[i].as_str());
+            let notation = pinyins.get().map(|pinyins| &*pinyins[i]);
             notation
-        };
+        }
 
         match notation {
             PinyinNotation::Unicode => Some(data::PINYINS[i]),
             PinyinNotation::AsciiFirstLetter => {
-                get(&self.data.ascii).map(|ascii| unsafe { ascii.get_unchecked(..1) })
+                get(&self.data.ascii, i).map(|ascii| unsafe { ascii.get_unchecked(..1) })
             }
-            _ => get(self.data.notation(notation)),
+            PinyinNotation::AsciiInitial => get(&self.data.ascii, i).map(Self::split_initial),
+            PinyinNotation::Zhuyin => get(&self.data.zhuyin, i),
+            PinyinNotation::ZhuyinTone => get(&self.data.zhuyin_tone, i),
+            _ => get(self.data.notation(notation), i),
         }
     }
 
+    /// Calls `f` with each fuzzy variant of the pinyin in the given notation, excluding the pinyin itself, until `f` returns `Some`.
+    ///
+    /// Require `PinyinNotation::Ascii` and the given notation. [`PinyinNotation::Unicode`] is not supported and never calls `f`.
+    ///
+    /// ## Performance
+    /// Variants of diletter notations are converted on the fly, which is much slower than [`Pinyin::notation`].
+    pub fn fuzzy_notation_and_try_for_each<T>(
+        &self,
+        notation: PinyinNotation,
+        fuzzy: FuzzyPinyin,
+        mut f: impl FnMut(&str) -> Option<T>,
+    ) -> Option<T> {
+        debug_assert_eq!(notation.bits().count_ones(), 1);
+        if fuzzy.is_empty() {
+            return None;
+        }
+
+        let ascii = self.notation(PinyinNotation::Ascii)?;
+        match notation {
+            PinyinNotation::Unicode => None,
+            PinyinNotation::Ascii => fuzzy.variants_and_try_for_each(ascii, f),
+            PinyinNotation::AsciiFirstLetter => {
+                // At most 3 initials
+                let mut tried = [ascii.as_bytes()[0]; 3];
+                let mut tried_len = 1;
+                fuzzy.variants_and_try_for_each(ascii, |variant| {
+                    let c = variant.as_bytes()[0];
+                    if tried[..tried_len].contains(&c) {
+                        return None;
+                    }
+                    tried[tried_len] = c;
+                    tried_len += 1;
+                    f(&variant[..1])
+                })
+            }
+            PinyinNotation::AsciiInitial => {
+                // At most 3 initials
+                let mut tried = [PinyinString::from_str_truncate(Self::split_initial(ascii)); 3];
+                let mut tried_len = 1;
+                fuzzy.variants_and_try_for_each(ascii, |variant| {
+                    let initial = Self::split_initial(variant);
+                    if tried[..tried_len]
+                        .iter()
+                        .any(|tried| tried.as_str() == initial)
+                    {
+                        return None;
+                    }
+                    tried[tried_len] = PinyinString::from_str_truncate(initial);
+                    tried_len += 1;
+                    f(initial)
+                })
+            }
+            PinyinNotation::AsciiTone => {
+                let tone = self.notation(PinyinNotation::AsciiTone)?.chars().last()?;
+                fuzzy.variants_and_try_for_each(ascii, |variant| {
+                    let mut variant = PinyinString::try_from_str(variant).ok()?;
+                    variant.try_push(tone).ok()?;
+                    f(&variant)
+                })
+            }
+            PinyinNotation::DiletterCustom => {
+                let scheme = self.data.diletter_scheme()?;
+                fuzzy.variants_and_try_for_each(ascii, |variant| {
+                    f(&scheme.ascii_to_diletter(variant)?)
+                })
+            }
+            PinyinNotation::Zhuyin => fuzzy
+                .variants_and_try_for_each(ascii, |variant| f(&notation::ascii_to_zhuyin(variant))),
+            PinyinNotation::ZhuyinTone => {
+                let mark = notation::zhuyin_tone_mark(self.notation(PinyinNotation::Unicode)?);
+                fuzzy.variants_and_try_for_each(ascii, |variant| {
+                    let mut variant = notation::ascii_to_zhuyin(variant);
+                    variant.extend(mark);
+                    f(&variant)
+                })
+            }
+            _ => {
+                let map = notation::ascii_map_fn(notation);
+                fuzzy.variants_and_try_for_each(ascii, |variant| f(&map(variant)))
+            }
+        }
+    }
+
+    /// Calls `f` with each spelling of "ü" of the pinyin in the given notation, excluding the pinyin itself, until `f` returns `Some`, e.g. "nyu" for "女" (nǚ) with [`UmlautSpelling::Yu`].
+    ///
+    /// Only [`PinyinNotation::Ascii`] and [`PinyinNotation::AsciiTone`] are supported, other notations never call `f`.
+    pub fn umlaut_notation_and_try_for_each<T>(
+        &self,
+        notation: PinyinNotation,
+        spellings: UmlautSpelling,
+        mut f: impl FnMut(&str) -> Option<T>,
+    ) -> Option<T> {
+        debug_assert_eq!(notation.bits().count_ones(), 1);
+
+        let unicode = data::PINYINS[self.index as usize];
+        if spellings.is_empty() || !unicode.contains(['ü', 'ǘ', 'ǚ', 'ǜ']) {
+            return None;
+        }
+        let to_ascii = match notation {
+            PinyinNotation::Ascii => notation::unicode_to_ascii_with_umlaut,
+            PinyinNotation::AsciiTone => notation::unicode_to_ascii_tone_with_umlaut,
+            _ => return None,
+        };
+        spellings
+            .iter()
+            .find_map(|spelling| f(&to_ascii(unicode, spelling.as_str())))
+    }
+
+    /// Calls `f` with each variant of the pinyin in [`PinyinNotation::Unicode`], excluding the pinyin itself, until `f` returns `Some`:
+    /// - If `toneless`, the pinyin without the tone mark, e.g. "lü" for "lǜ".
+    /// - If `decomposed`, the pinyin (and the toneless one) with combining marks (NFD), e.g. "lu\u{308}\u{300}" for "lǜ".
+    pub(crate) fn unicode_variants_and_try_for_each<T>(
+        &self,
+        toneless: bool,
+        decomposed: bool,
+        mut f: impl FnMut(&str) -> Option<T>,
+    ) -> Option<T> {
+        let unicode = data::PINYINS[self.index as usize];
+        let toneless = Some(notation::unicode_toneless(unicode))
+            .filter(|variant| toneless && variant.as_str() != unicode);
+        if let Some(toneless) = &toneless {
+            if let Some(r) = f(toneless) {
+                return Some(r);
+            }
+        }
+        if decomposed {
+            for variant in [Some(unicode), toneless.as_deref()].into_iter().flatten() {
+                let decomposed = notation::unicode_decompose(variant);
+                if decomposed.as_str() != variant {
+                    if let Some(r) = f(&decomposed) {
+                        return Some(r);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The tone, 1~4, or 5 for the neutral tone (轻声).
+    pub fn tone(&self) -> u8 {
+        notation::unicode_tone(data::PINYINS[self.index as usize])
+    }
+
+    /// [`PinyinNotation::Ascii`], converted on the fly if it is not inited.
+    pub(crate) fn to_ascii(&self) -> Cow<'a, str> {
+        match self.notation(PinyinNotation::Ascii) {
+            Some(ascii) => Cow::Borrowed(ascii),
+            None => Cow::Owned(
+                notation::unicode_to_ascii(data::PINYINS[self.index as usize]).to_string(),
+            ),
+        }
+    }
+
+    /// Equal for the same pinyins, or for pinyins that only differ in tones if not `tone`, e.g. "yīn" and "yín".
+    pub(crate) fn homophone_key(&self, tone: bool) -> u16 {
+        if tone {
+            return self.index;
+        }
+        // The index of the first pinyin with the same toneless form
+        static TONELESS_INDEXES: OnceLock<Box<[u16]>> = OnceLock::new();
+        TONELESS_INDEXES.get_or_init(|| {
+            let mut indexes = HashMap::new();
+            data::PINYINS
+                .iter()
+                .enumerate()
+                .map(|(i, unicode)| {
+                    *indexes
+                        .entry(notation::unicode_toneless(unicode))
+                        .or_insert(i as u16)
+                })
+                .collect()
+        })[self.index as usize]
+    }
+
     /// Require `PinyinNotation::Ascii`.
     pub fn initial_final(&self) -> Option<(&str, &str)> {
         self.notation(PinyinNotation::Ascii)
             .map(Self::split_initial_final)
     }
 
+    /// The initial of [`PinyinNotation::Ascii`], or the first letter if there is no initial.
+    fn split_initial(ascii: &str) -> &str {
+        match Self::split_initial_final(ascii) {
+            ("", _) => &ascii[..1],
+            (initial, _) => initial,
+        }
+    }
+
     fn split_initial_final(ascii: &str) -> (&str, &str) {
         debug_assert!(ascii.is_ascii());
 
@@ -421,9 +782,95 @@ mod tests {
         for pinyin in data.get_pinyins('中') {
             println!("{:?}", pinyin);
 
-            for notation in PinyinNotation::all().iter() {
+            for notation in (PinyinNotation::all() - PinyinNotation::DiletterCustom).iter() {
                 assert!(pinyin.notation(notation).is_some_and(|py| !py.is_empty()));
             }
         }
     }
+
+    #[test]
+    fn umlaut_notation() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let variants = |c, notation, spellings| {
+            let mut variants = Vec::new();
+            data.get_pinyins_and_for_each(c, |pinyin| {
+                pinyin.umlaut_notation_and_try_for_each(notation, spellings, |variant| {
+                    variants.push(variant.to_owned());
+                    None::<()>
+                });
+            });
+            variants
+        };
+
+        assert_eq!(
+            variants('女', PinyinNotation::Ascii, UmlautSpelling::all()),
+            ["nu", "nyu", "nu:"]
+        );
+        assert_eq!(
+            variants('略', PinyinNotation::AsciiTone, UmlautSpelling::U),
+            ["lue4"]
+        );
+        assert!(variants('女', PinyinNotation::Ascii, UmlautSpelling::empty()).is_empty());
+        assert!(variants('女', PinyinNotation::DiletterXiaohe, UmlautSpelling::all()).is_empty());
+        assert!(variants('居', PinyinNotation::Ascii, UmlautSpelling::all()).is_empty());
+    }
+
+    #[test]
+    fn unicode_variants() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let variants = |c, toneless, decomposed| {
+            let mut variants = Vec::new();
+            data.get_pinyins_and_for_each(c, |pinyin| {
+                pinyin.unicode_variants_and_try_for_each(toneless, decomposed, |variant| {
+                    variants.push(variant.to_owned());
+                    None::<()>
+                });
+            });
+            variants
+        };
+
+        assert_eq!(variants('绿', true, false), ["lü", "lu"]);
+        assert_eq!(
+            variants('绿', true, true),
+            ["lü", "lu\u{308}\u{300}", "lu\u{308}", "lu", "lu\u{300}"]
+        );
+        assert_eq!(
+            variants('绿', false, true),
+            ["lu\u{308}\u{300}", "lu\u{300}"]
+        );
+        assert!(variants('的', false, false).is_empty());
+    }
+
+    #[test]
+    fn fuzzy_notation() {
+        let data = PinyinData::new(PinyinNotation::all());
+        let fuzzy = FuzzyPinyin::ZZh | FuzzyPinyin::NL | FuzzyPinyin::EnEng;
+        let variants = |c, notation| {
+            let mut variants = Vec::new();
+            data.get_pinyins_and_for_each(c, |pinyin| {
+                pinyin.fuzzy_notation_and_try_for_each(notation, fuzzy, |variant| {
+                    variants.push(variant.to_owned());
+                    None::<()>
+                });
+            });
+            variants
+        };
+
+        assert_eq!(variants('中', PinyinNotation::Ascii), ["zong", "zong"]);
+        assert_eq!(
+            variants('中', PinyinNotation::AsciiTone),
+            ["zong1", "zong4"]
+        );
+        assert!(variants('中', PinyinNotation::AsciiFirstLetter).is_empty());
+        assert!(variants('中', PinyinNotation::Unicode).is_empty());
+        // zhong: vs, zong: zs
+        assert_eq!(variants('中', PinyinNotation::DiletterXiaohe), ["zs", "zs"]);
+        assert_eq!(variants('能', PinyinNotation::AsciiFirstLetter), ["l"]);
+        assert_eq!(variants('中', PinyinNotation::AsciiInitial), ["z", "z"]);
+        assert_eq!(variants('能', PinyinNotation::AsciiInitial), ["l"]);
+        assert_eq!(
+            variants('能', PinyinNotation::Ascii),
+            ["nen", "leng", "len"]
+        );
+    }
 }
@@ -1,8 +1,13 @@
-use super::{Pinyin, PinyinString};
+use std::fmt;
+
+use bon::bon;
+
+use super::{Pinyin, PinyinData, PinyinString, ZhuyinString};
 
 bitflags::bitflags! {
     /// - All pinyin notations are in lower case (`py.to_lowercase() == py`).
-    /// - All pinyin notations are no more than 7 characters long (`py.len() <= 7`).
+    /// - All pinyin notations are no more than 7 characters long (`py.chars().count() <= 7`).
+    /// - All pinyin notations except [`PinyinNotation::Zhuyin`] and [`PinyinNotation::ZhuyinTone`] are no more than 7 bytes long (`py.len() <= 7`).
     ///
     /// ## Others
     /// TODO: doc alias does not work
@@ -35,6 +40,16 @@ bitflags::bitflags! {
         #[doc(alias = "简拼")]
         const AsciiFirstLetter = 0x1;
 
+        /// 声母
+        ///
+        /// The initial, or the first letter if there is no initial, so "zh", "ch" and "sh" are single units unlike [`PinyinNotation::AsciiFirstLetter`].
+        ///
+        /// e.g. "p", "y", "zh" (中), "a" (安)
+        ///
+        /// See [声母](https://zh.wikipedia.org/wiki/声母) for details.
+        #[doc(alias = "声母")]
+        const AsciiInitial = 0x4000;
+
         /// 智能 ABC 双拼
         ///
         /// See [智能ABC输入法](https://zh.wikipedia.org/wiki/智能ABC输入法#双拼方案) for details.
@@ -70,6 +85,38 @@ bitflags::bitflags! {
         /// See [自然码](https://zh.wikipedia.org/zh-cn/自然码) for details.
         #[doc(alias = "自然码双拼")]
         const DiletterZrm = 0x200;
+
+        /// 自定义双拼
+        ///
+        /// Requires [`PinyinData::init_diletter_scheme()`](super::PinyinData::init_diletter_scheme) with a [`DiletterScheme`](super::DiletterScheme).
+        #[doc(alias = "自定义双拼")]
+        const DiletterCustom = 0x2000;
+
+        /// 注音符号
+        ///
+        /// e.g. "ㄆㄧㄣ", "ㄧㄣ"
+        ///
+        /// See [Bopomofo](https://en.wikipedia.org/wiki/Bopomofo) for details.
+        #[doc(alias("注音", "注音符号", "Bopomofo"))]
+        const Zhuyin = 0x400;
+
+        /// 带声调注音符号
+        ///
+        /// Tone marks are appended to the end, i.e. the order they are typed: "ˊ", "ˇ", "ˋ" and "˙" for tone 2 to 5, and none for tone 1.
+        ///
+        /// e.g. "ㄆㄧㄣ", "ㄓㄨㄥˋ"
+        #[doc(alias = "带声调注音符号")]
+        const ZhuyinTone = 0x800;
+
+        /// 大千注音键盘
+        ///
+        /// The keys of [`PinyinNotation::Zhuyin`] on the standard (大千) Zhuyin keyboard layout, without tones.
+        ///
+        /// e.g. "qup" (ㄆㄧㄣ), "up" (ㄧㄣ)
+        ///
+        /// See [Zhuyin keyboard layouts](https://en.wikipedia.org/wiki/Bopomofo#Keyboard_layouts) for details.
+        #[doc(alias("大千", "大千注音"))]
+        const ZhuyinDaqian = 0x1000;
     }
 }
 
@@ -81,19 +128,29 @@ impl PinyinNotation {
                 | PinyinNotation::DiletterMicrosoft
                 | PinyinNotation::DiletterThunisoft
                 | PinyinNotation::DiletterXiaohe
-                | PinyinNotation::DiletterZrm,
+                | PinyinNotation::DiletterZrm
+                | PinyinNotation::DiletterCustom,
         )
     }
 
-    /// `None` if no notation is set.
+    /// The max length in bytes. `None` if no notation is set.
     pub fn max_len(&self) -> Option<usize> {
+        if self.contains(PinyinNotation::ZhuyinTone) {
+            return Some(11);
+        }
+        if self.contains(PinyinNotation::Zhuyin) {
+            return Some(9);
+        }
         if self.intersects(PinyinNotation::Unicode | PinyinNotation::AsciiTone) {
             return Some(7);
         }
         if self.contains(PinyinNotation::Ascii) {
             return Some(6);
         }
-        if self.contains_diletter() {
+        if self.contains(PinyinNotation::ZhuyinDaqian) {
+            return Some(3);
+        }
+        if self.contains_diletter() || self.contains(PinyinNotation::AsciiInitial) {
             return Some(2);
         }
         if self.contains(PinyinNotation::AsciiFirstLetter) {
@@ -139,6 +196,11 @@ mod atomic {
 }
 
 pub(super) fn unicode_to_ascii(unicode: &str) -> PinyinString {
+    unicode_to_ascii_with_umlaut(unicode, "v")
+}
+
+/// [`unicode_to_ascii()`] with "ü" spelled as `umlaut`, e.g. "lyu" with "yu".
+pub(super) fn unicode_to_ascii_with_umlaut(unicode: &str, umlaut: &str) -> PinyinString {
     let mut ascii = PinyinString::new();
     let mut chars = unicode.chars();
     while let Some(c) = chars.next() {
@@ -152,6 +214,7 @@ pub(super) fn unicode_to_ascii(unicode: &str) -> PinyinString {
                     }
                 }
             }
+            'ü' | 'ǘ' | 'ǚ' | 'ǜ' => ascii.push_str(umlaut),
             _ => ascii.extend([match c {
                 'ā' | 'á' | 'ǎ' | 'à' => b'a',
                 'ē' | 'é' | 'ě' | 'è' | 'ế' | 'ề' => b'e',
@@ -164,7 +227,6 @@ pub(super) fn unicode_to_ascii(unicode: &str) -> PinyinString {
                 'ī' | 'í' | 'ǐ' | 'ì' => b'i',
                 'ō' | 'ó' | 'ǒ' | 'ò' => b'o',
                 'ū' | 'ú' | 'ǔ' | 'ù' => b'u',
-                'ü' | 'ǘ' | 'ǚ' | 'ǜ' => b'v',
                 'ń' | 'ň' | 'ǹ' => b'n',
                 'ḿ' => b'm',
                 // "m̀" begins with 'm'
@@ -177,7 +239,12 @@ pub(super) fn unicode_to_ascii(unicode: &str) -> PinyinString {
 }
 
 pub(super) fn unicode_to_ascii_tone(unicode: &str) -> PinyinString {
-    let mut ascii = unicode_to_ascii(unicode);
+    unicode_to_ascii_tone_with_umlaut(unicode, "v")
+}
+
+/// [`unicode_to_ascii_tone()`] with "ü" spelled as `umlaut`, e.g. "lyu3" with "yu".
+pub(super) fn unicode_to_ascii_tone_with_umlaut(unicode: &str, umlaut: &str) -> PinyinString {
+    let mut ascii = unicode_to_ascii_with_umlaut(unicode, umlaut);
     let tone = match unicode_tone(unicode) {
         1 => '1',
         2 => '2',
@@ -190,7 +257,27 @@ pub(super) fn unicode_to_ascii_tone(unicode: &str) -> PinyinString {
     ascii
 }
 
-fn unicode_tone(unicode: &str) -> u8 {
+pub(super) fn unicode_to_zhuyin_tone(unicode: &str) -> ZhuyinString {
+    let mut zhuyin = ascii_to_zhuyin(&unicode_to_ascii(unicode));
+    if let Some(mark) = zhuyin_tone_mark(unicode) {
+        zhuyin.extend([mark]);
+    }
+    zhuyin
+}
+
+/// `None` for tone 1.
+pub(super) fn zhuyin_tone_mark(unicode: &str) -> Option<char> {
+    match unicode_tone(unicode) {
+        1 => None,
+        2 => Some('ˊ'),
+        3 => Some('ˇ'),
+        4 => Some('ˋ'),
+        5 => Some('˙'),
+        _ => unreachable!(),
+    }
+}
+
+pub(super) fn unicode_tone(unicode: &str) -> u8 {
     for c in unicode.chars() {
         match c {
             'ā' | 'ē' | 'ī' | 'ō' | 'ū' |
@@ -209,6 +296,62 @@ fn unicode_tone(unicode: &str) -> u8 {
     5
 }
 
+/// The max length in bytes of [`unicode_decompose()`], e.g. "zhua\u{300}ng".
+pub(crate) const UNICODE_DECOMPOSED_MAX_LEN: usize = 8;
+
+/// See [`unicode_decompose()`].
+type UnicodeDecomposedString = arraystring::ArrayString<arraystring::typenum::U8>;
+const _: () = assert!(
+    <arraystring::typenum::U8 as arraystring::typenum::Unsigned>::USIZE
+        == UNICODE_DECOMPOSED_MAX_LEN
+);
+
+/// Tone marks as combining chars, for tone 1 to 4.
+const COMBINING_TONE_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30C}', '\u{300}'];
+
+/// Whether the char is a combining diacritical mark, e.g. a tone mark typed by macOS IMEs.
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{36F}')
+}
+
+/// Remove the tone mark, e.g. "lǜ" → "lü", "ê̄" → "ê".
+pub(super) fn unicode_toneless(unicode: &str) -> PinyinString {
+    let mut toneless = PinyinString::new();
+    for c in unicode.chars() {
+        let c = match c {
+            c if COMBINING_TONE_MARKS.contains(&c) => continue,
+            'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' => 'ü',
+            'ế' | 'ề' => 'ê',
+            c => unicode_letter_tone(c).map_or(c, |(letter, _)| letter as char),
+        };
+        unsafe { toneless.push_unchecked(c) }
+    }
+    toneless
+}
+
+/// Decompose letters with diacritics into base letters and combining marks (NFD), e.g. "lǜ" → "lu\u{308}\u{300}".
+pub(super) fn unicode_decompose(unicode: &str) -> UnicodeDecomposedString {
+    let mut decomposed = UnicodeDecomposedString::new();
+    for c in unicode.chars() {
+        match c {
+            'ü' => decomposed.extend(['u', '\u{308}']),
+            'ê' => decomposed.extend(['e', '\u{302}']),
+            'ế' => decomposed.extend(['e', '\u{302}', '\u{301}']),
+            'ề' => decomposed.extend(['e', '\u{302}', '\u{300}']),
+            c => match unicode_letter_tone(c) {
+                Some((b'v', tone)) => {
+                    decomposed.extend(['u', '\u{308}', COMBINING_TONE_MARKS[tone as usize - 1]])
+                }
+                Some((letter, tone)) => {
+                    decomposed.extend([letter as char, COMBINING_TONE_MARKS[tone as usize - 1]])
+                }
+                None => decomposed.extend([c]),
+            },
+        }
+    }
+    decomposed
+}
+
 pub(super) fn ascii_map_fn(notation: PinyinNotation) -> fn(&str) -> PinyinString {
     match notation {
         PinyinNotation::DiletterAbc => ascii_to_diletter_abc,
@@ -217,10 +360,123 @@ pub(super) fn ascii_map_fn(notation: PinyinNotation) -> fn(&str) -> PinyinString
         PinyinNotation::DiletterThunisoft => ascii_to_diletter_thunisoft,
         PinyinNotation::DiletterXiaohe => ascii_to_diletter_xiaohe,
         PinyinNotation::DiletterZrm => ascii_to_diletter_zrm,
+        PinyinNotation::ZhuyinDaqian => ascii_to_zhuyin_daqian,
         _ => unreachable!(),
     }
 }
 
+/// Also accepts invalid combinations of valid initials and finals, e.g. fuzzy variants.
+///
+/// See [Bopomofo](https://en.wikipedia.org/wiki/Bopomofo#Pinyin_and_Bopomofo) for details.
+pub(super) fn ascii_to_zhuyin(ascii: &str) -> ZhuyinString {
+    let mut zhuyin = ZhuyinString::new();
+    match ascii {
+        // 呒呣嘸
+        "m" => zhuyin.extend(['ㄇ']),
+        // 唔嗯 㕶 𠮾
+        "n" => zhuyin.extend(['ㄋ']),
+        "ng" => zhuyin.extend(['ㄫ']),
+        // 噷
+        "hm" => zhuyin.extend(['ㄏ', 'ㄇ']),
+        // 哼
+        "hng" => zhuyin.extend(['ㄏ', 'ㄫ']),
+        _ => {
+            let (initial, final_) = Pinyin::split_initial_final(ascii);
+            if let Some(c) = zhuyin_initial(initial) {
+                zhuyin.extend([c]);
+            }
+
+            let (medial, rest) = match (initial, final_) {
+                // 整体认读: zhi, chi, shi, ri, zi, ci, si
+                ("zh" | "ch" | "sh" | "r" | "z" | "c" | "s", "i") => (None, ""),
+                ("y", "i" | "in" | "ing") => (Some('ㄧ'), &final_[1..]),
+                ("y", "ong") => (Some('ㄩ'), "ng"),
+                ("y" | "j" | "q" | "x", f) if f.starts_with('u') => (Some('ㄩ'), &f[1..]),
+                ("y", f) => (Some('ㄧ'), f),
+                ("w", "u") => (Some('ㄨ'), ""),
+                ("w", "ong") => (Some('ㄨ'), "ng"),
+                ("w", f) => (Some('ㄨ'), f),
+                (_, "iong") => (Some('ㄩ'), "ng"),
+                (_, "ong") => (Some('ㄨ'), "ng"),
+                // Abbreviated finals: iou, uei, uen
+                (_, "iu") => (Some('ㄧ'), "ou"),
+                (_, "ui") => (Some('ㄨ'), "ei"),
+                (_, "un") => (Some('ㄨ'), "n"),
+                (_, f) => match f.as_bytes()[0] {
+                    b'i' => (Some('ㄧ'), &f[1..]),
+                    b'u' => (Some('ㄨ'), &f[1..]),
+                    b'v' => (Some('ㄩ'), &f[1..]),
+                    _ => (None, f),
+                },
+            };
+            if let Some(c) = medial {
+                zhuyin.extend([c]);
+            }
+
+            if let Some(c) = match rest {
+                "" => None,
+                "a" => Some('ㄚ'),
+                "o" => Some('ㄛ'),
+                // ê
+                "e" if medial.is_some() => Some('ㄝ'),
+                "e" => Some('ㄜ'),
+                "ai" => Some('ㄞ'),
+                "ei" => Some('ㄟ'),
+                "ao" => Some('ㄠ'),
+                "ou" => Some('ㄡ'),
+                "an" => Some('ㄢ'),
+                "en" | "n" => Some('ㄣ'),
+                "ang" => Some('ㄤ'),
+                "eng" | "ng" => Some('ㄥ'),
+                "er" => Some('ㄦ'),
+                _ => unreachable!(),
+            } {
+                zhuyin.extend([c]);
+            }
+        }
+    }
+    zhuyin
+}
+
+#[rustfmt::skip]
+fn zhuyin_initial(initial: &str) -> Option<char> {
+    Some(match initial {
+        "b" => 'ㄅ', "p" => 'ㄆ', "m" => 'ㄇ', "f" => 'ㄈ',
+        "d" => 'ㄉ', "t" => 'ㄊ', "n" => 'ㄋ', "l" => 'ㄌ',
+        "g" => 'ㄍ', "k" => 'ㄎ', "h" => 'ㄏ',
+        "j" => 'ㄐ', "q" => 'ㄑ', "x" => 'ㄒ',
+        "zh" => 'ㄓ', "ch" => 'ㄔ', "sh" => 'ㄕ', "r" => 'ㄖ',
+        "z" => 'ㄗ', "c" => 'ㄘ', "s" => 'ㄙ',
+        // "", "y", "w"
+        _ => return None,
+    })
+}
+
+#[rustfmt::skip]
+fn ascii_to_zhuyin_daqian(ascii: &str) -> PinyinString {
+    ascii_to_zhuyin(ascii)
+        .chars()
+        .map(|c| match c {
+            'ㄅ' => '1', 'ㄆ' => 'q', 'ㄇ' => 'a', 'ㄈ' => 'z',
+            'ㄉ' => '2', 'ㄊ' => 'w', 'ㄋ' => 's', 'ㄌ' => 'x',
+            'ㄍ' => 'e', 'ㄎ' => 'd', 'ㄏ' => 'c',
+            'ㄐ' => 'r', 'ㄑ' => 'f', 'ㄒ' => 'v',
+            'ㄓ' => '5', 'ㄔ' => 't', 'ㄕ' => 'g', 'ㄖ' => 'b',
+            'ㄗ' => 'y', 'ㄘ' => 'h', 'ㄙ' => 'n',
+            'ㄧ' => 'u', 'ㄨ' => 'j', 'ㄩ' => 'm',
+            'ㄚ' => '8', 'ㄛ' => 'i', 'ㄜ' => 'k', 'ㄝ' => ',',
+            'ㄞ' => '9', 'ㄟ' => 'o', 'ㄠ' => 'l', 'ㄡ' => '.',
+            'ㄢ' => '0', 'ㄣ' => 'p', 'ㄤ' => ';', 'ㄥ' => '/',
+            'ㄦ' => '-',
+            // ㄫ is not on the layout, typed as ㄥ
+            'ㄫ' => '/',
+            _ => unreachable!(),
+        })
+        .collect::<String>()
+        .as_str()
+        .into()
+}
+
 /// ## Arguments
 /// - `map_initial`
 ///
@@ -235,17 +491,7 @@ fn ascii_to_diletter<'a>(
     map_initial: impl Fn(&str) -> Option<&str>,
     map_final: impl Fn(&str) -> Option<&str>,
 ) -> PinyinString {
-    let ascii = match ascii {
-        // 噷
-        "hm" => "hen",
-        // 哼
-        "hng" => "heng",
-        // 呒呣嘸
-        "m" => "mu",
-        // 唔嗯 㕶 𠮾
-        "n" | "ng" => "en",
-        _ => ascii,
-    };
+    let ascii = diletter_syllabic(ascii);
 
     if let Some(py) = map_pinyin(ascii) {
         return py.into();
@@ -266,6 +512,21 @@ fn ascii_to_diletter<'a>(
     }
 }
 
+/// Syllabic consonants are typed as their similar pinyins in diletter schemes.
+pub(super) fn diletter_syllabic(ascii: &str) -> &str {
+    match ascii {
+        // 噷
+        "hm" => "hen",
+        // 哼
+        "hng" => "heng",
+        // 呒呣嘸
+        "m" => "mu",
+        // 唔嗯 㕶 𠮾
+        "n" | "ng" => "en",
+        _ => ascii,
+    }
+}
+
 #[rustfmt::skip]
 fn ascii_to_diletter_abc(ascii: &str) -> PinyinString {
     ascii_to_diletter(
@@ -518,6 +779,316 @@ fn ascii_to_diletter_zrm(ascii: &str) -> PinyinString {
     )
 }
 
+#[bon]
+impl PinyinData {
+    /// Convert pinyin text from one notation to another, e.g. "pin1yin1" in [`PinyinNotation::AsciiTone`] to "pīnyīn" in [`PinyinNotation::Unicode`] or "pbyb" in [`PinyinNotation::DiletterXiaohe`].
+    ///
+    /// `from`, `to` and [`PinyinNotation::Ascii`] must be inited.
+    ///
+    /// The text is split into words by whitespace, and each word is split into pinyins with the fewest syllables, e.g. "xian" is "xiān" (先) instead of "xī'ān" (西安). Apostrophes can be used as explicit syllable boundaries, see [`PinyinData::segment()`].
+    ///
+    /// Spellings of the source notation are normalized:
+    /// - Letters are case-insensitive.
+    /// - "ü" can be written as "v", "u:" or "ü", or "yu" after "l" and "n" (e.g. "lyu" for "lǚ" as in passports). "jv" is also accepted for "ju".
+    /// - [`PinyinNotation::Unicode`]: Tone marks can be on any letter of a pinyin and can be combining characters, e.g. "piń" for "pín". Pinyins without tone marks are in the neutral tone (轻声).
+    /// - [`PinyinNotation::AsciiTone`]: Every pinyin must have a tone digit (`1..=5`).
+    ///
+    /// ## Arguments
+    /// - `separator`: Put between pinyins of a word. By default, pinyins are not separated, except that an apostrophe is put before pinyins starting with "a", "o" or "e" in [`PinyinNotation::Unicode`] and [`PinyinNotation::Ascii`], e.g. "xī'ān".
+    ///
+    /// ## Returns
+    /// Words are separated by a space.
+    ///
+    /// Tones must be known to convert to [`PinyinNotation::Unicode`], [`PinyinNotation::AsciiTone`] and [`PinyinNotation::ZhuyinTone`], otherwise [`PinyinNotationError::MissingTone`] is returned. [`PinyinNotation::AsciiFirstLetter`] can't be converted from.
+    ///
+    /// `from`, `to` and [`PinyinNotation::Ascii`] must be inited, otherwise [`PinyinNotationError::NotInited`] is returned. With the `inmut-data` feature, they are inited on demand (except [`PinyinNotation::DiletterCustom`]).
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::pinyin::{PinyinData, PinyinNotation};
+    ///
+    /// let data = PinyinData::new(
+    ///     PinyinNotation::Ascii | PinyinNotation::AsciiTone | PinyinNotation::DiletterXiaohe,
+    /// );
+    /// let convert = |s, from, to| data.convert_notation(s, from, to).call().unwrap();
+    /// assert_eq!(convert("pin1yin1", PinyinNotation::AsciiTone, PinyinNotation::Unicode), "pīnyīn");
+    /// assert_eq!(convert("lu:4se4", PinyinNotation::AsciiTone, PinyinNotation::Unicode), "lǜsè");
+    /// assert_eq!(convert("xī'ān", PinyinNotation::Unicode, PinyinNotation::AsciiTone), "xi1an1");
+    /// assert_eq!(convert("pin1yin1", PinyinNotation::AsciiTone, PinyinNotation::DiletterXiaohe), "pbyb");
+    /// assert_eq!(convert("pbyb", PinyinNotation::DiletterXiaohe, PinyinNotation::Ascii), "pinyin");
+    /// ```
+    #[builder]
+    pub fn convert_notation(
+        &self,
+        #[builder(start_fn)] pinyin: &str,
+        #[builder(start_fn)] from: PinyinNotation,
+        #[builder(start_fn)] to: PinyinNotation,
+        separator: Option<&str>,
+    ) -> Result<String, PinyinNotationError> {
+        for notation in [from, to] {
+            if notation.bits().count_ones() != 1 {
+                return Err(PinyinNotationError::NotSingle(notation));
+            }
+        }
+        let required = from | to | PinyinNotation::Ascii;
+        #[cfg(feature = "inmut-data")]
+        self.init_notations(required);
+        let not_inited = required - self.inited_notations();
+        if !not_inited.is_empty() {
+            return Err(PinyinNotationError::NotInited(not_inited));
+        }
+
+        let mut converted = String::with_capacity(pinyin.len() * 2);
+        for (i, word) in pinyin.split_whitespace().enumerate() {
+            if i != 0 {
+                converted.push(' ');
+            }
+            let pinyins = match from {
+                PinyinNotation::Unicode | PinyinNotation::Ascii | PinyinNotation::AsciiTone => {
+                    self.parse_romanized(word, from)?
+                }
+                PinyinNotation::AsciiFirstLetter | PinyinNotation::AsciiInitial => {
+                    return Err(PinyinNotationError::Unsupported(from))
+                }
+                _ => self.parse_by_table(word, from)?,
+            };
+            for (j, (py, tone)) in pinyins.into_iter().enumerate() {
+                let ascii = py.notation(PinyinNotation::Ascii).unwrap();
+                let py = match to {
+                    PinyinNotation::Unicode
+                    | PinyinNotation::AsciiTone
+                    | PinyinNotation::ZhuyinTone => {
+                        let tone =
+                            tone.ok_or_else(|| PinyinNotationError::MissingTone(ascii.to_owned()))?;
+                        self.pinyin_by_ascii(ascii, Some(tone)).unwrap()
+                    }
+                    _ => py,
+                };
+                if j != 0 {
+                    match separator {
+                        Some(separator) => converted.push_str(separator),
+                        None if matches!(to, PinyinNotation::Unicode | PinyinNotation::Ascii)
+                            && ascii.starts_with(['a', 'o', 'e']) =>
+                        {
+                            converted.push('\'')
+                        }
+                        None => (),
+                    }
+                }
+                let py = py.notation(to).unwrap();
+                converted.push_str(py);
+            }
+        }
+        Ok(converted)
+    }
+
+    /// Split a word of [`PinyinNotation::Unicode`], [`PinyinNotation::Ascii`] or [`PinyinNotation::AsciiTone`] into pinyins and their tones.
+    fn parse_romanized(
+        &self,
+        word: &str,
+        from: PinyinNotation,
+    ) -> Result<Vec<(Pinyin<'_>, Option<u8>)>, PinyinNotationError> {
+        let invalid = || PinyinNotationError::InvalidPinyin(word.to_owned());
+
+        // Lower case ASCII with "ü" as "v", and the tone mark on each byte for Unicode
+        let mut ascii = Vec::with_capacity(word.len());
+        let mut marks: Vec<Option<u8>> = Vec::with_capacity(word.len());
+        for c in word.chars().flat_map(char::to_lowercase) {
+            let (c, mark) = match c {
+                'a'..='z' | '\'' => (c as u8, None),
+                '0'..='9' if from == PinyinNotation::AsciiTone => (c as u8, None),
+                ':' if ascii.last() == Some(&b'u') => {
+                    ascii.pop();
+                    (b'v', marks.pop().flatten())
+                }
+                'ü' => (b'v', None),
+                'ê' => (b'e', None),
+                _ if from != PinyinNotation::Unicode => return Err(invalid()),
+                '\u{304}' | '\u{301}' | '\u{30C}' | '\u{300}' => {
+                    let tone = match c {
+                        '\u{304}' => 1,
+                        '\u{301}' => 2,
+                        '\u{30C}' => 3,
+                        _ => 4,
+                    };
+                    match marks.last_mut() {
+                        Some(mark @ None) => *mark = Some(tone),
+                        _ => return Err(invalid()),
+                    }
+                    continue;
+                }
+                c => {
+                    let (c, tone) = unicode_letter_tone(c).ok_or_else(invalid)?;
+                    (c, Some(tone))
+                }
+            };
+            ascii.push(c);
+            marks.push(mark);
+        }
+
+        // "lyu", "nyu" and "jv", "qv", "xv", "yv"
+        let mut i = 0;
+        while i + 1 < ascii.len() {
+            let is_word_start = i == 0 || matches!(ascii[i - 1], b'\'' | b'0'..=b'9');
+            match ascii[i] {
+                b'l' | b'n'
+                    if ascii[i + 1..].starts_with(b"yu") && (ascii[i] == b'l' || is_word_start) =>
+                {
+                    ascii[i + 1] = b'v';
+                    ascii.remove(i + 2);
+                    let mark = marks.remove(i + 2);
+                    marks[i + 1] = marks[i + 1].or(mark);
+                }
+                b'j' | b'q' | b'x' | b'y' if ascii[i + 1] == b'v' => ascii[i + 1] = b'u',
+                _ => (),
+            }
+            i += 1;
+        }
+        let ascii = String::from_utf8(ascii).unwrap();
+
+        self.segment(&ascii)
+            .call()
+            .into_iter()
+            .find_map(|syllables| {
+                syllables
+                    .into_iter()
+                    .map(|syllable| {
+                        let tone = match from {
+                            PinyinNotation::Unicode => {
+                                let mut marks = marks[syllable.range].iter().flatten();
+                                let tone = marks.next().copied().unwrap_or(5);
+                                if marks.next().is_some() {
+                                    return None;
+                                }
+                                Some(tone)
+                            }
+                            PinyinNotation::AsciiTone => Some(syllable.tone?),
+                            _ => None,
+                        };
+                        Some((self.pinyin_by_ascii(syllable.pinyin, tone)?, tone))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(invalid)
+    }
+
+    /// Split a word of notations that are looked up from the pinyin data, e.g. diletter and Zhuyin, into pinyins and their tones.
+    fn parse_by_table(
+        &self,
+        word: &str,
+        from: PinyinNotation,
+    ) -> Result<Vec<(Pinyin<'_>, Option<u8>)>, PinyinNotationError> {
+        let invalid = || PinyinNotationError::InvalidPinyin(word.to_owned());
+        let word = word.to_lowercase();
+
+        let mut pinyins = Vec::new();
+        for part in word.split('\'').filter(|part| !part.is_empty()) {
+            // The fewest pinyins and the start of the last pinyin that end at each byte
+            let mut splits: Vec<Option<(usize, usize)>> = vec![None; part.len() + 1];
+            splits[0] = Some((0, 0));
+            for start in 0..part.len() {
+                let Some((count, _)) = splits[start] else {
+                    continue;
+                };
+                for py in self.match_pinyin(from, &part[start..]) {
+                    let end = start + py.len();
+                    if splits[end].map_or(true, |(end_count, _)| count + 1 < end_count) {
+                        splits[end] = Some((count + 1, start));
+                    }
+                }
+            }
+            if splits[part.len()].is_none() {
+                return Err(invalid());
+            }
+
+            let start = pinyins.len();
+            let mut end = part.len();
+            while end != 0 {
+                let (_, py_start) = splits[end].unwrap();
+                let py = self.pinyin_by_notation(from, &part[py_start..end]).unwrap();
+                let tone = (from == PinyinNotation::ZhuyinTone).then(|| py.tone());
+                pinyins.push((py, tone));
+                end = py_start;
+            }
+            pinyins[start..].reverse();
+        }
+        if pinyins.is_empty() {
+            return Err(invalid());
+        }
+        Ok(pinyins)
+    }
+
+    /// A pinyin with the ASCII spelling and the tone, or any tone if `None`. "ê" is only returned if there is no "e".
+    fn pinyin_by_ascii(&self, ascii: &str, tone: Option<u8>) -> Option<Pinyin<'_>> {
+        self.iter()
+            .filter(|py| {
+                py.notation(PinyinNotation::Ascii) == Some(ascii)
+                    && tone.map_or(true, |tone| py.tone() == tone)
+            })
+            .min_by_key(|py| super::data::PINYINS[py.index as usize].starts_with('ê'))
+    }
+
+    /// A pinyin in the notation. Syllabic consonants are only returned if there is no other pinyin, e.g. "en" instead of "n" for diletter "en".
+    fn pinyin_by_notation(&self, notation: PinyinNotation, s: &str) -> Option<Pinyin<'_>> {
+        self.iter()
+            .filter(|py| py.notation(notation) == Some(s))
+            .min_by_key(|py| {
+                let ascii = py.notation(PinyinNotation::Ascii).unwrap();
+                diletter_syllabic(ascii) != ascii
+            })
+    }
+}
+
+/// The ASCII letter and the tone of a letter with a tone mark, e.g. 'ǚ' → ('v', 3).
+#[rustfmt::skip]
+fn unicode_letter_tone(c: char) -> Option<(u8, u8)> {
+    Some(match c {
+        'ā' => (b'a', 1), 'á' => (b'a', 2), 'ǎ' => (b'a', 3), 'à' => (b'a', 4),
+        'ē' => (b'e', 1), 'é' => (b'e', 2), 'ě' => (b'e', 3), 'è' => (b'e', 4),
+        'ế' => (b'e', 2), 'ề' => (b'e', 4),
+        'ī' => (b'i', 1), 'í' => (b'i', 2), 'ǐ' => (b'i', 3), 'ì' => (b'i', 4),
+        'ō' => (b'o', 1), 'ó' => (b'o', 2), 'ǒ' => (b'o', 3), 'ò' => (b'o', 4),
+        'ū' => (b'u', 1), 'ú' => (b'u', 2), 'ǔ' => (b'u', 3), 'ù' => (b'u', 4),
+        'ǖ' => (b'v', 1), 'ǘ' => (b'v', 2), 'ǚ' => (b'v', 3), 'ǜ' => (b'v', 4),
+        'ḿ' => (b'm', 2),
+        'ń' => (b'n', 2), 'ň' => (b'n', 3), 'ǹ' => (b'n', 4),
+        _ => return None,
+    })
+}
+
+/// An error that occurred when converting pinyin between notations, see [`PinyinData::convert_notation()`] and [`PinyinData::to_pinyin()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PinyinNotationError {
+    /// The word can't be split into valid pinyins of the source notation.
+    InvalidPinyin(String),
+    /// The target notation requires a tone, but the pinyin has no tone in the source notation.
+    MissingTone(String),
+    /// Converting from the notation is not supported.
+    Unsupported(PinyinNotation),
+    /// `from` or `to` is not exactly one notation.
+    NotSingle(PinyinNotation),
+    /// The notations are required but not inited, see [`PinyinData::init_notations()`].
+    ///
+    /// With the `inmut-data` feature, notations are inited on demand, except [`PinyinNotation::DiletterCustom`], which requires [`PinyinData::init_diletter_scheme()`].
+    NotInited(PinyinNotation),
+}
+
+impl fmt::Display for PinyinNotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPinyin(word) => write!(f, "invalid pinyin: {word:?}"),
+            Self::MissingTone(pinyin) => write!(f, "missing tone: {pinyin:?}"),
+            Self::Unsupported(notation) => {
+                write!(f, "converting from {notation:?} is not supported")
+            }
+            Self::NotSingle(notation) => write!(f, "not a single notation: {notation:?}"),
+            Self::NotInited(notations) => write!(f, "notations not inited: {notations:?}"),
+        }
+    }
+}
+
+impl std::error::Error for PinyinNotationError {}
+
 #[cfg(test)]
 mod tests {
     use super::{super::data, *};
@@ -544,6 +1115,10 @@ mod tests {
             assert_eq!(ascii_to_diletter_thunisoft(&ascii).len(), 2);
             assert_eq!(ascii_to_diletter_xiaohe(&ascii).len(), 2);
             assert_eq!(ascii_to_diletter_zrm(&ascii).len(), 2);
+
+            assert!(unicode_to_zhuyin_tone(unicode).len() <= 11);
+            assert!(ascii_to_zhuyin(&ascii).len() <= 9);
+            assert!(ascii_to_zhuyin_daqian(&ascii).len() <= 3);
         }
     }
 
@@ -557,6 +1132,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unicode_decompose_() {
+        assert_eq!(unicode_toneless("lǜ").as_str(), "lü");
+        assert_eq!(unicode_toneless("ê̄").as_str(), "ê");
+        assert_eq!(unicode_toneless("m̀").as_str(), "m");
+        assert_eq!(unicode_decompose("lǜ").as_str(), "lu\u{308}\u{300}");
+        assert_eq!(unicode_decompose("ế").as_str(), "e\u{302}\u{301}");
+        assert_eq!(unicode_decompose("ê̄").as_str(), "e\u{302}\u{304}");
+        assert_eq!(unicode_decompose("pīn").as_str(), "pi\u{304}n");
+
+        for unicode in data::PINYINS {
+            let decomposed = unicode_decompose(unicode);
+            assert!(
+                decomposed.as_str().len() <= UNICODE_DECOMPOSED_MAX_LEN,
+                "{unicode}"
+            );
+            assert!(unicode_toneless(unicode).as_str().len() <= unicode.len());
+            assert_eq!(
+                unicode_toneless(&decomposed).as_str(),
+                unicode_decompose(&unicode_toneless(unicode)).as_str()
+            );
+        }
+        assert!(
+            data::PINYINS
+                .iter()
+                .any(|unicode| unicode_decompose(unicode).as_str().len()
+                    == UNICODE_DECOMPOSED_MAX_LEN)
+        );
+    }
+
     #[test]
     fn ascii_to_diletter_microsoft_() {
         assert_eq!(&ascii_to_diletter_microsoft("pin"), "pn");
@@ -568,4 +1173,188 @@ mod tests {
         assert_eq!(&ascii_to_diletter_xiaohe("pin"), "pb");
         assert_eq!(&ascii_to_diletter_xiaohe("yin"), "yb");
     }
+
+    #[test]
+    fn ascii_to_zhuyin_() {
+        for (ascii, zhuyin) in [
+            ("pin", "ㄆㄧㄣ"),
+            ("yin", "ㄧㄣ"),
+            ("zhong", "ㄓㄨㄥ"),
+            ("zhi", "ㄓ"),
+            ("si", "ㄙ"),
+            ("ju", "ㄐㄩ"),
+            ("xue", "ㄒㄩㄝ"),
+            ("lv", "ㄌㄩ"),
+            ("nve", "ㄋㄩㄝ"),
+            ("yong", "ㄩㄥ"),
+            ("xiong", "ㄒㄩㄥ"),
+            ("yuan", "ㄩㄢ"),
+            ("ye", "ㄧㄝ"),
+            ("you", "ㄧㄡ"),
+            ("liu", "ㄌㄧㄡ"),
+            ("gui", "ㄍㄨㄟ"),
+            ("lun", "ㄌㄨㄣ"),
+            ("wu", "ㄨ"),
+            ("weng", "ㄨㄥ"),
+            ("e", "ㄜ"),
+            ("er", "ㄦ"),
+            ("hng", "ㄏㄫ"),
+        ] {
+            assert_eq!(&ascii_to_zhuyin(ascii), zhuyin, "{ascii}");
+        }
+        assert_eq!(&unicode_to_zhuyin_tone("zhōng"), "ㄓㄨㄥ");
+        assert_eq!(&unicode_to_zhuyin_tone("zhòng"), "ㄓㄨㄥˋ");
+        assert_eq!(&unicode_to_zhuyin_tone("de"), "ㄉㄜ˙");
+        assert_eq!(&ascii_to_zhuyin_daqian("pin"), "qup");
+        assert_eq!(&ascii_to_zhuyin_daqian("zhuang"), "5j;");
+    }
+
+    #[test]
+    fn convert_notation() {
+        let data = PinyinData::new(PinyinNotation::all());
+        let convert = |s: &str, from, to| data.convert_notation(s, from, to).call();
+        use PinyinNotation as N;
+
+        // Round trips of all pinyins, and some pinyins for slower notations
+        for py in data.iter() {
+            let unicode = py.notation(N::Unicode).unwrap();
+            let ascii_tone = py.notation(N::AsciiTone).unwrap();
+            // "ê" is normalized to "e"
+            let expected = unicode
+                .replace("ê̄", "ē")
+                .replace('ế', "é")
+                .replace("ê̌", "ě")
+                .replace('ề', "è");
+            assert_eq!(
+                convert(unicode, N::Unicode, N::AsciiTone).unwrap(),
+                ascii_tone
+            );
+            assert_eq!(
+                convert(ascii_tone, N::AsciiTone, N::Unicode).unwrap(),
+                expected
+            );
+            let zhuyin_tone = py.notation(N::ZhuyinTone).unwrap();
+            if py.index % 8 != 0 {
+                continue;
+            }
+            // Zhuyin is ambiguous for e.g. "weng" and "wong"
+            let converted = convert(zhuyin_tone, N::ZhuyinTone, N::AsciiTone).unwrap();
+            assert_eq!(
+                convert(&converted, N::AsciiTone, N::ZhuyinTone).unwrap(),
+                zhuyin_tone
+            );
+            for notation in [N::Ascii, N::DiletterXiaohe, N::DiletterZrm, N::Zhuyin] {
+                let s = py.notation(notation).unwrap();
+                let ascii = convert(s, notation, N::Ascii).unwrap();
+                assert_eq!(convert(&ascii, N::Ascii, notation).unwrap(), s, "{unicode}");
+            }
+        }
+
+        assert_eq!(
+            convert("pin1yin1", N::AsciiTone, N::Unicode).unwrap(),
+            "pīnyīn"
+        );
+        assert_eq!(
+            convert("pin1yin1", N::AsciiTone, N::Unicode)
+                .unwrap()
+                .chars()
+                .count(),
+            6
+        );
+        assert_eq!(
+            convert("Pin1 Yin1", N::AsciiTone, N::Unicode).unwrap(),
+            "pīn yīn"
+        );
+        assert_eq!(
+            convert("xi1an1", N::AsciiTone, N::Unicode).unwrap(),
+            "xī'ān"
+        );
+        assert_eq!(convert("xi1an1", N::AsciiTone, N::Ascii).unwrap(), "xi'an");
+        assert_eq!(
+            data.convert_notation("xi1an1", N::AsciiTone, N::Ascii)
+                .separator(" ")
+                .call()
+                .unwrap(),
+            "xi an"
+        );
+        assert_eq!(convert("xian", N::Ascii, N::Zhuyin).unwrap(), "ㄒㄧㄢ");
+        assert_eq!(convert("xi'an", N::Ascii, N::Zhuyin).unwrap(), "ㄒㄧㄢ");
+        assert_eq!(
+            convert("xīān", N::Unicode, N::ZhuyinTone).unwrap(),
+            "ㄒㄧㄢ"
+        );
+
+        // Tone mark placement
+        assert_eq!(convert("gui4", N::AsciiTone, N::Unicode).unwrap(), "guì");
+        assert_eq!(convert("liu2", N::AsciiTone, N::Unicode).unwrap(), "liú");
+        assert_eq!(convert("lüè", N::Unicode, N::AsciiTone).unwrap(), "lve4");
+        assert_eq!(convert("guǐ", N::Unicode, N::AsciiTone).unwrap(), "gui3");
+        assert_eq!(convert("gǔi", N::Unicode, N::Unicode).unwrap(), "guǐ");
+        assert_eq!(convert("piń", N::Unicode, N::Unicode).unwrap(), "pín");
+        assert_eq!(
+            convert("pi\u{301}n", N::Unicode, N::Unicode).unwrap(),
+            "pín"
+        );
+
+        // ü
+        for s in ["lv3", "lu:3", "lü3", "lyu3", "LYU3"] {
+            assert_eq!(convert(s, N::AsciiTone, N::Unicode).unwrap(), "lǚ", "{s}");
+        }
+        assert_eq!(convert("nyu3", N::AsciiTone, N::Unicode).unwrap(), "nǚ");
+        assert_eq!(convert("jv1", N::AsciiTone, N::Unicode).unwrap(), "jū");
+        assert_eq!(convert("lyǔ", N::Unicode, N::Ascii).unwrap(), "lv");
+        assert_eq!(convert("anyu", N::Ascii, N::Ascii).unwrap(), "anyu");
+
+        assert_eq!(
+            convert("pinyin", N::Ascii, N::Unicode),
+            Err(PinyinNotationError::MissingTone("pin".into()))
+        );
+        assert_eq!(
+            convert("pyin1", N::AsciiTone, N::Unicode),
+            Err(PinyinNotationError::InvalidPinyin("pyin1".into()))
+        );
+        assert_eq!(
+            convert("pinyin1", N::AsciiTone, N::Unicode),
+            Err(PinyinNotationError::InvalidPinyin("pinyin1".into()))
+        );
+        assert_eq!(
+            convert("xi5", N::AsciiTone, N::Unicode),
+            Err(PinyinNotationError::InvalidPinyin("xi5".into()))
+        );
+        assert_eq!(
+            convert("xīx", N::Unicode, N::Ascii),
+            Err(PinyinNotationError::InvalidPinyin("xīx".into()))
+        );
+        assert_eq!(
+            convert("py", N::AsciiFirstLetter, N::Ascii),
+            Err(PinyinNotationError::Unsupported(N::AsciiFirstLetter))
+        );
+        assert!(convert("pb!", N::DiletterXiaohe, N::Ascii).is_err());
+        assert_eq!(convert("", N::Ascii, N::Unicode).unwrap(), "");
+        assert_eq!(
+            convert("pin1", N::AsciiTone | N::Ascii, N::Unicode),
+            Err(PinyinNotationError::NotSingle(N::AsciiTone | N::Ascii))
+        );
+        assert_eq!(
+            convert("pin1", N::AsciiTone, N::empty()),
+            Err(PinyinNotationError::NotSingle(N::empty()))
+        );
+
+        let data = PinyinData::new(N::Ascii | N::AsciiTone);
+        let convert = |s: &str, from, to| data.convert_notation(s, from, to).call();
+        #[cfg(feature = "inmut-data")]
+        assert_eq!(
+            convert("pin1", N::AsciiTone, N::DiletterXiaohe).unwrap(),
+            "pb"
+        );
+        #[cfg(not(feature = "inmut-data"))]
+        assert_eq!(
+            convert("pin1", N::AsciiTone, N::DiletterXiaohe),
+            Err(PinyinNotationError::NotInited(N::DiletterXiaohe))
+        );
+        assert_eq!(
+            convert("pb", N::DiletterCustom, N::Ascii),
+            Err(PinyinNotationError::NotInited(N::DiletterCustom))
+        );
+    }
 }
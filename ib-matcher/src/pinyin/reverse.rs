@@ -0,0 +1,188 @@
+use std::sync::OnceLock;
+
+use bon::bon;
+
+use super::{data, Pinyin, PinyinData, PinyinNotation};
+
+/// Chars of each pinyin in `data::PINYINS`, built on first use.
+///
+/// Chars whose primary reading is the pinyin come first, then the others, each in code point order.
+fn reverse_index() -> &'static [Box<[char]>] {
+    static INDEX: OnceLock<Box<[Box<[char]>]>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut primary = vec![Vec::new(); data::PINYINS.len()];
+        let mut others = vec![Vec::new(); data::PINYINS.len()];
+        for range in &data::PINYIN_RANGE_TABLES {
+            for (c, &index) in range.range.clone().zip(range.table) {
+                let Some(c) = char::from_u32(c) else {
+                    continue;
+                };
+                match index {
+                    u16::MAX => (),
+                    i if i < data::PINYINS.len() as u16 => primary[i as usize].push(c),
+                    i => {
+                        let i = i - data::PINYINS.len() as u16;
+                        for (j, &i) in PinyinData::pinyin_combination(i).enumerate() {
+                            if j == 0 {
+                                primary[i as usize].push(c);
+                            } else {
+                                others[i as usize].push(c);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        primary
+            .into_iter()
+            .zip(others)
+            .map(|(mut chars, others)| {
+                chars.extend(others);
+                chars.into_boxed_slice()
+            })
+            .collect()
+    })
+}
+
+impl<'a> Pinyin<'a> {
+    /// The chars that have this reading, e.g. "中" and "钟" for "zhōng".
+    ///
+    /// Chars whose primary reading (see [`PinyinData::get_primary_pinyin()`]) is this come first, then the others, each in code point order.
+    ///
+    /// ## Performance
+    /// The reverse index of all pinyins is built on the first call of this or [`PinyinData::get_chars()`].
+    pub fn chars(&self) -> &'static [char] {
+        &reverse_index()[self.index as usize]
+    }
+}
+
+#[bon]
+impl PinyinData {
+    /// The chars that have a reading matching `pinyin` in the given notation, e.g. "shi" in [`PinyinNotation::Ascii`], or "ui" in [`PinyinNotation::DiletterXiaohe`].
+    ///
+    /// `notation` must be inited.
+    ///
+    /// ## Arguments
+    /// - `tone`: Only readings with this tone, 1~4, or 5 for the neutral tone (轻声).
+    /// - `prefix`: Also match readings starting with `pinyin`, e.g. "sh" for "shi" and "shang".
+    ///
+    /// ## Returns
+    /// Chars of each matched reading in the order of [`Pinyin::chars()`], and chars of earlier readings come first. Every char is only returned once.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::pinyin::{PinyinData, PinyinNotation};
+    ///
+    /// let data = PinyinData::new(PinyinNotation::Ascii);
+    /// let chars = data.get_chars("shi", PinyinNotation::Ascii).tone(4).call();
+    /// assert!(chars.contains(&'是'));
+    /// assert!(!chars.contains(&'时'));
+    /// ```
+    #[builder]
+    pub fn get_chars(
+        &self,
+        #[builder(start_fn)] pinyin: &str,
+        #[builder(start_fn)] notation: PinyinNotation,
+        tone: Option<u8>,
+        #[builder(default = false)] prefix: bool,
+    ) -> Vec<char> {
+        debug_assert_eq!(notation.bits().count_ones(), 1);
+        debug_assert!(self.inited_notations().contains(notation));
+
+        let mut chars = Vec::new();
+        for py in self.iter() {
+            if tone.is_some_and(|tone| py.tone() != tone) {
+                continue;
+            }
+            let Some(s) = py.notation(notation) else {
+                continue;
+            };
+            if !(s == pinyin || prefix && s.starts_with(pinyin)) {
+                continue;
+            }
+            for &c in py.chars() {
+                if !chars.contains(&c) {
+                    chars.push(c);
+                }
+            }
+        }
+        chars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chars() {
+        let data = PinyinData::new(PinyinNotation::empty());
+
+        let zhong = data.get_primary_pinyin('中').unwrap();
+        assert_eq!(zhong.notation(PinyinNotation::Unicode), Some("zhōng"));
+        let chars = zhong.chars();
+        assert!(chars.contains(&'中'));
+        assert!(chars.contains(&'钟'));
+        assert!(!chars.contains(&'重'));
+
+        // 行 (xíng, háng)
+        let hang = data
+            .get_pinyins('行')
+            .find(|py| py.notation(PinyinNotation::Unicode) == Some("háng"))
+            .unwrap();
+        let chars = hang.chars();
+        let i = chars.iter().position(|&c| c == '行').unwrap();
+        let j = chars.iter().position(|&c| c == '航').unwrap();
+        assert!(j < i);
+
+        // Every reading of every char
+        let mut count = 0;
+        for py in data.iter() {
+            for &c in py.chars() {
+                assert!(data.get_pinyins(c).any(|p| p.index == py.index));
+                count += 1;
+            }
+        }
+        assert!(count > 40000);
+    }
+
+    #[test]
+    fn get_chars() {
+        let data = PinyinData::new(
+            PinyinNotation::Ascii | PinyinNotation::AsciiTone | PinyinNotation::DiletterXiaohe,
+        );
+
+        let chars = data.get_chars("shi", PinyinNotation::Ascii).call();
+        assert!(chars.contains(&'是'));
+        assert!(chars.contains(&'时'));
+        assert_eq!(
+            chars.len(),
+            chars.iter().collect::<std::collections::HashSet<_>>().len()
+        );
+
+        let chars = data.get_chars("shi2", PinyinNotation::AsciiTone).call();
+        assert!(chars.contains(&'时'));
+        assert!(!chars.contains(&'是'));
+        assert_eq!(
+            data.get_chars("shi", PinyinNotation::Ascii).tone(2).call(),
+            chars
+        );
+
+        let chars = data.get_chars("ui", PinyinNotation::DiletterXiaohe).call();
+        assert!(chars.contains(&'是'));
+
+        let chars = data.get_chars("sh", PinyinNotation::Ascii).call();
+        assert!(chars.is_empty());
+        let chars = data
+            .get_chars("sh", PinyinNotation::Ascii)
+            .prefix(true)
+            .call();
+        assert!(chars.contains(&'是'));
+        assert!(chars.contains(&'上'));
+        assert!(!chars.contains(&'死'));
+
+        let chars = data.get_chars("zhōng", PinyinNotation::Unicode).call();
+        assert!(chars.contains(&'中'));
+        assert!(!chars.contains(&'肿'));
+    }
+}
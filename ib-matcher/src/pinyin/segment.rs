@@ -0,0 +1,276 @@
+use std::{cmp::Reverse, ops::Range};
+
+use bon::bon;
+
+use super::{PinyinData, PinyinNotation};
+
+/// A syllable of a segmentation, see [`PinyinData::segment()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PinyinSyllable<'a> {
+    /// The syllable in [`PinyinNotation::Ascii`], e.g. "lv" for "lu:3".
+    ///
+    /// If `partial`, the whole pinyin that the input is a prefix of.
+    pub pinyin: &'a str,
+    /// The tone digit in the input, 1~5. "0" is read as 5, the neutral tone.
+    pub tone: Option<u8>,
+    /// The byte range in the input, including the tone digit but not the apostrophe.
+    pub range: Range<usize>,
+    /// Whether only a prefix of `pinyin` is in the input. Only the last syllable can be partial.
+    pub partial: bool,
+}
+
+#[bon]
+impl PinyinData {
+    /// Split ASCII pinyin input into syllables in every valid way, e.g. "xian" into "xian" and "xi'an".
+    ///
+    /// Requires [`PinyinNotation::Ascii`].
+    ///
+    /// The input is case-insensitive, and can contain:
+    /// - Apostrophes (`'`) as explicit syllable boundaries, e.g. "xi'an". Only one is allowed between two syllables, and an apostrophe can't be at the start.
+    /// - A tone digit (`0..=5`) after a syllable, e.g. "xi1an1". "0" is the same as "5", the neutral tone, e.g. "de0". Syllables can't have tones that no hanzi has.
+    /// - "ü" written as "v", "u:" or "ü", e.g. "lv", "lu:" and "lü".
+    ///
+    /// Syllables without vowels ("n", "ng", "m", "hm", "hng") are only split out if they are delimited by apostrophes or tone digits, e.g. "xia'n" but not "xian".
+    ///
+    /// ## Arguments
+    /// - `partial`: Allow the last syllable to be a prefix of a pinyin, e.g. "pinyi" as "pin yin". The last syllable can't be partial if it's followed by a tone digit or an apostrophe.
+    ///
+    /// ## Returns
+    /// Segmentations with fewer syllables first, or empty if the input can't be segmented.
+    ///
+    /// The number of segmentations can grow exponentially with the input length.
+    ///
+    /// ## Example
+    /// ```
+    /// use ib_matcher::pinyin::{PinyinData, PinyinNotation};
+    ///
+    /// let data = PinyinData::new(PinyinNotation::Ascii);
+    /// let segmentations: Vec<Vec<&str>> = data
+    ///     .segment("xian")
+    ///     .call()
+    ///     .into_iter()
+    ///     .map(|syllables| syllables.into_iter().map(|s| s.pinyin).collect())
+    ///     .collect();
+    /// assert_eq!(segmentations, [vec!["xian"], vec!["xi", "an"]]);
+    ///
+    /// assert_eq!(data.segment("xi'an").call().len(), 1);
+    /// ```
+    #[builder]
+    pub fn segment(
+        &self,
+        #[builder(start_fn)] input: &str,
+        #[builder(default = false)] partial: bool,
+    ) -> Vec<Vec<PinyinSyllable<'_>>> {
+        debug_assert!(self.inited_notations().contains(PinyinNotation::Ascii));
+
+        // The normalized input and the input offset of each byte of it
+        let mut normalized = String::with_capacity(input.len());
+        let mut offsets = Vec::with_capacity(input.len() + 1);
+        let mut chars = input.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let c = match c {
+                'u' | 'U' if chars.next_if(|&(_, c)| c == ':').is_some() => 'v',
+                'ü' | 'Ü' => 'v',
+                c if c.is_ascii() => c.to_ascii_lowercase(),
+                _ => return Vec::new(),
+            };
+            normalized.push(c);
+            offsets.push(i);
+        }
+        offsets.push(input.len());
+
+        let mut segmentations = Vec::new();
+        self.sub_segment(
+            &normalized,
+            0,
+            partial,
+            &offsets,
+            &mut Vec::new(),
+            &mut segmentations,
+        );
+        segmentations.sort_by_key(Vec::len);
+        segmentations
+    }
+
+    /// ## Arguments
+    /// - `input`: The normalized input.
+    /// - `start`: The start of the remaining input.
+    /// - `offsets`: The original offset of each byte of `input`.
+    /// - `syllables`: The syllables before `start`.
+    fn sub_segment<'a>(
+        &'a self,
+        input: &str,
+        start: usize,
+        partial: bool,
+        offsets: &[usize],
+        syllables: &mut Vec<PinyinSyllable<'a>>,
+        segmentations: &mut Vec<Vec<PinyinSyllable<'a>>>,
+    ) {
+        let rest = &input[start..];
+        if rest.is_empty() {
+            if !syllables.is_empty() {
+                segmentations.push(syllables.clone());
+            }
+            return;
+        }
+
+        // Syllables never span apostrophes or tone digits
+        let chunk = &rest[..rest
+            .find(|c: char| c == '\'' || c.is_ascii_digit())
+            .unwrap_or(rest.len())];
+        if chunk.is_empty() {
+            return;
+        }
+        let partial = partial && chunk.len() == rest.len();
+        let is_chunk_start =
+            start == 0 || matches!(input.as_bytes()[start - 1], b'\'' | b'0'..=b'9');
+
+        let mut matches: Vec<(&str, bool)> = self
+            .match_pinyin_partial(PinyinNotation::Ascii, chunk, partial)
+            .collect();
+        matches.sort_unstable_by_key(|&(pinyin, partial)| (partial, Reverse(pinyin.len()), pinyin));
+        matches.dedup();
+
+        for (pinyin, is_partial) in matches {
+            // Syllables without vowels must be delimited explicitly
+            let has_vowel = pinyin.contains(['a', 'e', 'i', 'o', 'u', 'v']);
+            if !(has_vowel || is_chunk_start && pinyin.len() == chunk.len()) {
+                continue;
+            }
+
+            let mut end = start
+                + if is_partial {
+                    chunk.len()
+                } else {
+                    pinyin.len()
+                };
+            let mut tone = None;
+            if let Some(digit) = input[end..].bytes().next().filter(u8::is_ascii_digit) {
+                let digit = match digit - b'0' {
+                    // The neutral tone, the same as `IbMatcher`
+                    0 => 5,
+                    digit => digit,
+                };
+                if !self.iter().any(|py| {
+                    py.notation(PinyinNotation::Ascii) == Some(pinyin) && py.tone() == digit
+                }) {
+                    continue;
+                }
+                tone = Some(digit);
+                end += 1;
+            }
+
+            syllables.push(PinyinSyllable {
+                pinyin,
+                tone,
+                range: offsets[start]..offsets[end],
+                partial: is_partial,
+            });
+            if input[end..].starts_with('\'') {
+                end += 1;
+            }
+            self.sub_segment(input, end, partial, offsets, syllables, segmentations);
+            syllables.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(data: &PinyinData, input: &str, partial: bool) -> Vec<String> {
+        data.segment(input)
+            .partial(partial)
+            .call()
+            .into_iter()
+            .map(|syllables| {
+                syllables
+                    .iter()
+                    .map(|s| {
+                        let mut py = s.pinyin.to_owned();
+                        if let Some(tone) = s.tone {
+                            py.push(char::from(b'0' + tone));
+                        }
+                        if s.partial {
+                            py.push('~');
+                        }
+                        py
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn segment_ascii() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(segment(&data, "xian", false), ["xian", "xi an"]);
+        assert_eq!(segment(&data, "XiAn", false), ["xian", "xi an"]);
+        assert_eq!(segment(&data, "xi'an", false), ["xi an"]);
+        assert_eq!(segment(&data, "xian'", false), ["xian", "xi an"]);
+        assert_eq!(segment(&data, "'xian", false), Vec::<String>::new());
+        assert_eq!(segment(&data, "xi''an", false), Vec::<String>::new());
+        assert_eq!(
+            segment(&data, "pinyinsousuo", false),
+            ["pin yin sou suo", "pin yin sou su o"]
+        );
+        assert_eq!(segment(&data, "vv", false), Vec::<String>::new());
+        assert_eq!(segment(&data, "n", false), ["n"]);
+        assert_eq!(segment(&data, "xi'a'n", false), ["xi a n"]);
+        assert_eq!(segment(&data, "xia2n2", false), ["xia2 n2", "xi a2 n2"]);
+        assert_eq!(segment(&data, "pinyin搜索", false), Vec::<String>::new());
+        assert_eq!(segment(&data, "", false), Vec::<String>::new());
+    }
+
+    #[test]
+    fn segment_tone() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(segment(&data, "xian1", false), ["xian1", "xi an1"]);
+        assert_eq!(segment(&data, "xi1an1", false), ["xi1 an1"]);
+        assert_eq!(segment(&data, "xi1'an1", false), ["xi1 an1"]);
+        // No hanzi is read as "xi5"
+        assert_eq!(segment(&data, "xi5an", false), Vec::<String>::new());
+        // "0" is the neutral tone, the same as "5"
+        assert_eq!(segment(&data, "de0", false), ["de5"]);
+        assert_eq!(segment(&data, "hao3de0", false), ["hao3 de5", "ha o3 de5"]);
+        assert_eq!(
+            segment(&data, "xi0an", false),
+            segment(&data, "xi5an", false)
+        );
+
+        let syllables = data.segment("xi1an1").call().remove(0);
+        assert_eq!(syllables[0].range, 0..3);
+        assert_eq!(syllables[1].range, 3..6);
+    }
+
+    #[test]
+    fn segment_v() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(segment(&data, "lv", false), ["lv"]);
+        assert_eq!(segment(&data, "lu:", false), ["lv"]);
+        assert_eq!(segment(&data, "lü", false), ["lv"]);
+        assert_eq!(segment(&data, "nu:3er", false), ["nv3 er"]);
+
+        let syllables = data.segment("nu:3er").call().remove(0);
+        assert_eq!(syllables[0].range, 0..4);
+        assert_eq!(syllables[1].range, 4..6);
+        let syllables = data.segment("lü'an").call().remove(0);
+        assert_eq!(syllables[0].range, 0..3);
+        assert_eq!(syllables[1].range, 4..6);
+    }
+
+    #[test]
+    fn segment_partial() {
+        let data = PinyinData::new(PinyinNotation::Ascii);
+        assert_eq!(segment(&data, "pinyi", false), ["pin yi"]);
+        let segmentations = segment(&data, "pinyi", true);
+        assert!(segmentations.contains(&"pin yin~".to_owned()));
+        assert!(segmentations.contains(&"pin yi".to_owned()));
+        assert!(!segmentations.contains(&"pin yi~".to_owned()));
+        assert!(segment(&data, "pinyi'", true) == ["pin yi"]);
+        assert_eq!(segment(&data, "zh", false), Vec::<String>::new());
+        assert!(segment(&data, "zh", true).contains(&"zhong~".to_owned()));
+    }
+}
@@ -0,0 +1,30 @@
+bitflags::bitflags! {
+    /// Spellings of "ü" in ASCII pinyin, e.g. for "女" (nǚ) and "略" (lüè), in addition to "v" of [`PinyinNotation::Ascii`](super::PinyinNotation::Ascii) ("nv", "lve").
+    ///
+    /// Only "ü" after "l" and "n" is affected, since "ü" after "j", "q", "x" and "y" is already spelled as "u", e.g. "ju".
+    ///
+    /// Only [`PinyinNotation::Ascii`](super::PinyinNotation::Ascii) and [`PinyinNotation::AsciiTone`](super::PinyinNotation::AsciiTone) are supported.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct UmlautSpelling: u32 {
+        /// e.g. "nu", "lue"
+        ///
+        /// Makes e.g. "nu" ambiguous with "努" (nǔ).
+        const U = 0x1;
+        /// e.g. "nyu", "lyue", as in Chinese passports.
+        const Yu = 0x2;
+        /// e.g. "nu:", "lu:e", as in CC-CEDICT.
+        const UColon = 0x4;
+    }
+}
+
+impl UmlautSpelling {
+    /// How "ü" is spelled, for a single flag.
+    pub(super) fn as_str(&self) -> &'static str {
+        match *self {
+            Self::U => "u",
+            Self::Yu => "yu",
+            Self::UColon => "u:",
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::{collections::HashMap, fmt, sync::OnceLock};
+
+use super::{data, Pinyin, PinyinData};
+
+mod builtin;
+
+/// A dictionary of words (词典) and their pinyins, for polyphone (多音字) disambiguation.
+///
+/// Pinyin data is per char, so a polyphone like "重" in "重庆" (chóng qìng) also has the reading "zhòng". With a dictionary, the readings of chars in known words can be decided by their context, see:
+/// - [`PinyinData::to_pinyin()`] with `words`.
+/// - [`PinyinMatchConfigBuilder::words()`](crate::matcher::PinyinMatchConfigBuilder::words) for strict readings in matching.
+///
+/// Words are segmented by forward maximum matching, i.e. the longest known word at each position.
+///
+/// ## Example
+/// ```
+/// use ib_matcher::pinyin::{PinyinData, PinyinNotation, PinyinWords};
+///
+/// let data = PinyinData::new(PinyinNotation::Ascii);
+/// let mut words = PinyinWords::new();
+/// assert_eq!(
+///     data.to_pinyin("重庆", PinyinNotation::Unicode).words(&words).call().unwrap(),
+///     "chóng qìng"
+/// );
+///
+/// words.insert("重要", "zhòng yào").unwrap();
+/// let pinyins: Vec<_> = words.get(&data, "重要").unwrap().map(|py| py.notation(PinyinNotation::Ascii).unwrap()).collect();
+/// assert_eq!(pinyins, ["zhong", "yao"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PinyinWords {
+    /// Word → indexes into `data::PINYINS`.
+    words: HashMap<Box<str>, Box<[u16]>>,
+    /// The max number of chars of words.
+    max_chars: usize,
+}
+
+impl PinyinWords {
+    /// The built-in dictionary of common words with polyphones.
+    ///
+    /// The built-in dictionary is small (hundreds of words) and only covers common cases. More words can be added by [`PinyinWords::insert()`].
+    pub fn new() -> Self {
+        let mut words = Self::empty();
+        for &(word, pinyins) in builtin::WORDS {
+            words.insert(word, pinyins).unwrap();
+        }
+        words
+    }
+
+    /// An empty dictionary.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Add a word, or replace the pinyins of an existing word.
+    ///
+    /// ## Arguments
+    /// - `pinyins`: The pinyins of each char in [`PinyinNotation::Unicode`](super::PinyinNotation::Unicode), separated by spaces, e.g. "chóng qìng". Each pinyin must be in the pinyin data.
+    pub fn insert(&mut self, word: &str, pinyins: &str) -> Result<(), PinyinWordsError> {
+        let indexes = pinyins
+            .split_whitespace()
+            .map(|py| {
+                unicode_index(py).ok_or_else(|| PinyinWordsError::UnknownPinyin(py.to_owned()))
+            })
+            .collect::<Result<Box<[u16]>, _>>()?;
+        let chars = word.chars().count();
+        if chars == 0 || chars != indexes.len() {
+            return Err(PinyinWordsError::LenMismatch(word.to_owned()));
+        }
+        self.max_chars = self.max_chars.max(chars);
+        self.words.insert(word.into(), indexes);
+        Ok(())
+    }
+
+    /// The number of words.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// The pinyins of each char of the word, if the word is known.
+    pub fn get<'w, 'a: 'w>(
+        &'w self,
+        data: &'a PinyinData,
+        word: &str,
+    ) -> Option<impl Iterator<Item = Pinyin<'a>> + 'w> {
+        self.words
+            .get(word)
+            .map(move |indexes| indexes.iter().map(move |&i| data.pinyin(i)))
+    }
+
+    /// The max number of chars of words.
+    pub(crate) fn max_chars(&self) -> usize {
+        self.max_chars
+    }
+
+    /// Segments `chars` by forward maximum matching, and calls `f` with the char index and the pinyin index of each char in known words.
+    pub(crate) fn segment_and_for_each(&self, chars: &[char], mut f: impl FnMut(usize, u16)) {
+        // Encoded once, so that candidate words are slices of it
+        let s: String = chars.iter().collect();
+        let offsets: Vec<usize> = s.char_indices().map(|(i, _)| i).chain([s.len()]).collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let mut len = self.max_chars.min(chars.len() - i);
+            while len > 0 {
+                if let Some(indexes) = self.words.get(&s[offsets[i]..offsets[i + len]]) {
+                    for (j, &index) in indexes.iter().enumerate() {
+                        f(i + j, index);
+                    }
+                    break;
+                }
+                len -= 1;
+            }
+            i += len.max(1);
+        }
+    }
+}
+
+/// The index of a [`PinyinNotation::Unicode`](super::PinyinNotation::Unicode) pinyin in `data::PINYINS`.
+fn unicode_index(unicode: &str) -> Option<u16> {
+    static INDEXES: OnceLock<HashMap<&'static str, u16>> = OnceLock::new();
+    INDEXES
+        .get_or_init(|| {
+            let mut indexes = HashMap::with_capacity(data::PINYINS.len());
+            for (i, &py) in data::PINYINS.iter().enumerate() {
+                indexes.entry(py).or_insert(i as u16);
+            }
+            indexes
+        })
+        .get(unicode)
+        .copied()
+}
+
+/// An error that occurred when adding a word to [`PinyinWords`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PinyinWordsError {
+    /// The pinyin is not a known [`PinyinNotation::Unicode`](super::PinyinNotation::Unicode) pinyin.
+    UnknownPinyin(String),
+    /// The number of pinyins does not match the number of chars of the word.
+    LenMismatch(String),
+}
+
+impl fmt::Display for PinyinWordsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPinyin(pinyin) => write!(f, "unknown pinyin: {pinyin:?}"),
+            Self::LenMismatch(word) => {
+                write!(f, "the number of pinyins does not match the word: {word:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PinyinWordsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::PinyinNotation, *};
+
+    #[test]
+    fn builtin() {
+        let data = PinyinData::new(PinyinNotation::empty());
+        let words = PinyinWords::new();
+        assert_eq!(words.len(), builtin::WORDS.len());
+
+        // Every reading should be a reading of the char
+        let mut invalid = Vec::new();
+        for &(word, _) in builtin::WORDS {
+            for (c, pinyin) in word.chars().zip(words.get(&data, word).unwrap()) {
+                let pinyin = pinyin.notation(PinyinNotation::Unicode).unwrap();
+                let mut found = false;
+                data.get_pinyins_and_for_each(c, |py| {
+                    found |= py.notation(PinyinNotation::Unicode) == Some(pinyin)
+                });
+                if !found {
+                    invalid.push(format!("{word}: {c} {pinyin}"));
+                }
+            }
+        }
+        assert_eq!(invalid, Vec::<String>::new());
+    }
+
+    #[test]
+    fn segment() {
+        let mut words = PinyinWords::empty();
+        words.insert("重庆", "chóng qìng").unwrap();
+        words.insert("重庆市", "chóng qìng shì").unwrap();
+        words.insert("行长", "háng zhǎng").unwrap();
+        assert_eq!(
+            words.insert("行", "háng zhǎng"),
+            Err(PinyinWordsError::LenMismatch("行".into()))
+        );
+        assert_eq!(
+            words.insert("行", "hang2"),
+            Err(PinyinWordsError::UnknownPinyin("hang2".into()))
+        );
+
+        let segment = |s: &str| {
+            let chars: Vec<char> = s.chars().collect();
+            let mut v = Vec::new();
+            words.segment_and_for_each(&chars, |i, index| {
+                v.push((i, data::PINYINS[index as usize]))
+            });
+            v
+        };
+        assert_eq!(
+            segment("重庆市行长"),
+            [
+                (0, "chóng"),
+                (1, "qìng"),
+                (2, "shì"),
+                (3, "háng"),
+                (4, "zhǎng")
+            ]
+        );
+        assert_eq!(segment("重庆行"), [(0, "chóng"), (1, "qìng")]);
+        assert_eq!(segment("很重要"), []);
+    }
+}
@@ -0,0 +1,274 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+/// Common words with polyphones (多音字), and their pinyins in [`PinyinNotation::Unicode`](crate::pinyin::PinyinNotation::Unicode).
+///
+/// Neutral tones (轻声) are written without tone marks, e.g. "jué de" (觉得).
+pub(super) const WORDS: &[(&str, &str)] = &[
+// 长
+("长城", "cháng chéng"), ("长江", "cháng jiāng"), ("长度", "cháng dù"), ("长期", "cháng qī"), ("长沙", "cháng shā"), ("长春", "cháng chūn"), ("长安", "cháng ān"),
+("长大", "zhǎng dà"), ("校长", "xiào zhǎng"), ("市长", "shì zhǎng"), ("部长", "bù zhǎng"), ("家长", "jiā zhǎng"), ("成长", "chéng zhǎng"), ("增长", "zēng zhǎng"), ("生长", "shēng zhǎng"), ("董事长", "dǒng shì zhǎng"), ("行长", "háng zhǎng"), ("班长", "bān zhǎng"), ("长老", "zhǎng lǎo"),
+// 重
+("重庆", "chóng qìng"), ("重复", "chóng fù"), ("重新", "chóng xīn"), ("重叠", "chóng dié"), ("重阳", "chóng yáng"), ("重建", "chóng jiàn"), ("重启", "chóng qǐ"), ("重播", "chóng bō"), ("重写", "chóng xiě"), ("重命名", "chóng mìng míng"), ("重装", "chóng zhuāng"), ("重置", "chóng zhì"), ("重试", "chóng shì"), ("重做", "chóng zuò"),
+("重要", "zhòng yào"), ("重量", "zhòng liàng"), ("重点", "zhòng diǎn"), ("重视", "zhòng shì"), ("严重", "yán zhòng"), ("体重", "tǐ zhòng"), ("尊重", "zūn zhòng"), ("重大", "zhòng dà"),
+// 行
+("银行", "yín háng"), ("行业", "háng yè"), ("行情", "háng qíng"), ("行列", "háng liè"), ("同行", "tóng háng"), ("外行", "wài háng"), ("内行", "nèi háng"), ("排行", "pái háng"), ("商行", "shāng háng"),
+("行动", "xíng dòng"), ("行人", "xíng rén"), ("行为", "xíng wéi"), ("行李", "xíng lǐ"), ("旅行", "lǚ xíng"), ("进行", "jìn xíng"), ("运行", "yùn xíng"), ("执行", "zhí xíng"), ("自行车", "zì xíng chē"), ("可行", "kě xíng"), ("流行", "liú xíng"), ("行政", "xíng zhèng"), ("发行", "fā xíng"), ("命令行", "mìng lìng háng"), ("行号", "háng hào"),
+// 乐
+("音乐", "yīn yuè"), ("乐器", "yuè qì"), ("乐队", "yuè duì"), ("乐曲", "yuè qǔ"), ("乐谱", "yuè pǔ"), ("声乐", "shēng yuè"),
+("快乐", "kuài lè"), ("欢乐", "huān lè"), ("乐观", "lè guān"), ("娱乐", "yú lè"), ("可乐", "kě lè"), ("俱乐部", "jù lè bù"),
+// 还
+("还是", "hái shì"), ("还有", "hái yǒu"), ("还要", "hái yào"), ("还没", "hái méi"),
+("还原", "huán yuán"), ("归还", "guī huán"), ("偿还", "cháng huán"), ("还款", "huán kuǎn"), ("还钱", "huán qián"),
+// 得
+("得到", "dé dào"), ("获得", "huò dé"), ("取得", "qǔ dé"), ("得分", "dé fēn"), ("心得", "xīn dé"),
+("觉得", "jué de"), ("记得", "jì de"), ("懂得", "dǒng de"), ("值得", "zhí de"), ("晓得", "xiǎo de"),
+// 的
+("目的", "mù dì"), ("的确", "dí què"), ("的士", "dī shì"),
+// 地
+("地图", "dì tú"), ("地址", "dì zhǐ"), ("地方", "dì fāng"), ("地球", "dì qiú"), ("土地", "tǔ dì"),
+// 了
+("了解", "liǎo jiě"), ("了不起", "liǎo bù qǐ"), ("除了", "chú le"),
+// 好
+("爱好", "ài hào"), ("好奇", "hào qí"), ("好学", "hào xué"), ("好客", "hào kè"),
+// 为
+("为了", "wèi le"), ("因为", "yīn wèi"), ("为什么", "wèi shén me"),
+("成为", "chéng wéi"), ("认为", "rèn wéi"), ("作为", "zuò wéi"), ("以为", "yǐ wéi"),
+// 中
+("中国", "zhōng guó"), ("中文", "zhōng wén"), ("中心", "zhōng xīn"), ("中间", "zhōng jiān"),
+("中奖", "zhòng jiǎng"), ("中毒", "zhòng dú"), ("打中", "dǎ zhòng"), ("命中", "mìng zhòng"), ("中标", "zhòng biāo"), ("中暑", "zhòng shǔ"),
+// 传
+("传说", "chuán shuō"), ("传统", "chuán tǒng"), ("传输", "chuán shū"), ("传播", "chuán bō"), ("上传", "shàng chuán"), ("传送", "chuán sòng"),
+("传记", "zhuàn jì"), ("自传", "zì zhuàn"), ("水浒传", "shuǐ hǔ zhuàn"),
+// 调
+("调整", "tiáo zhěng"), ("调节", "tiáo jié"), ("空调", "kōng tiáo"), ("调试", "tiáo shì"), ("调和", "tiáo hé"), ("调皮", "tiáo pí"),
+("调查", "diào chá"), ("调用", "diào yòng"), ("调度", "diào dù"), ("声调", "shēng diào"), ("单调", "dān diào"), ("调动", "diào dòng"), ("回调", "huí diào"),
+// 数
+("数据", "shù jù"), ("数字", "shù zì"), ("数学", "shù xué"), ("数量", "shù liàng"), ("数组", "shù zǔ"), ("参数", "cān shù"), ("函数", "hán shù"), ("数据库", "shù jù kù"),
+("数一数", "shǔ yī shǔ"), ("数不清", "shǔ bù qīng"),
+// 系
+("系统", "xì tǒng"), ("关系", "guān xì"), ("联系", "lián xì"), ("系列", "xì liè"),
+("系鞋带", "jì xié dài"),
+// 处
+("处理", "chǔ lǐ"), ("处理器", "chǔ lǐ qì"), ("处于", "chǔ yú"), ("相处", "xiāng chǔ"), ("处罚", "chǔ fá"),
+("到处", "dào chù"), ("好处", "hǎo chù"), ("处长", "chù zhǎng"), ("办事处", "bàn shì chù"),
+// 会
+("会计", "kuài jì"), ("会议", "huì yì"), ("机会", "jī huì"), ("社会", "shè huì"),
+// 将
+("将来", "jiāng lái"), ("将军", "jiāng jūn"), ("大将", "dà jiàng"),
+// 差
+("差别", "chā bié"), ("差异", "chā yì"), ("误差", "wù chā"), ("差距", "chā jù"),
+("出差", "chū chāi"), ("差事", "chāi shì"),
+("差不多", "chà bù duō"), ("很差", "hěn chà"),
+("参差", "cēn cī"),
+// 参
+("参加", "cān jiā"), ("参考", "cān kǎo"), ("人参", "rén shēn"),
+// 朝
+("朝代", "cháo dài"), ("朝鲜", "cháo xiǎn"), ("唐朝", "táng cháo"), ("朝阳", "zhāo yáng"), ("朝气", "zhāo qì"),
+// 曾
+("曾经", "céng jīng"), ("未曾", "wèi céng"),
+// 藏
+("西藏", "xī zàng"), ("宝藏", "bǎo zàng"), ("收藏", "shōu cáng"), ("隐藏", "yǐn cáng"), ("躲藏", "duǒ cáng"),
+// 只
+("只是", "zhǐ shì"), ("只有", "zhǐ yǒu"), ("只要", "zhǐ yào"), ("一只", "yì zhī"),
+// 便
+("方便", "fāng biàn"), ("便利", "biàn lì"), ("顺便", "shùn biàn"), ("随便", "suí biàn"),
+("便宜", "pián yí"),
+// 着
+("着急", "zháo jí"), ("睡着", "shuì zháo"), ("着火", "zháo huǒ"),
+("着陆", "zhuó lù"), ("着手", "zhuó shǒu"), ("着重", "zhuó zhòng"), ("执着", "zhí zhuó"),
+// 看
+("看守", "kān shǒu"), ("看门", "kān mén"),
+// 教
+("教学", "jiào xué"), ("教育", "jiào yù"), ("教授", "jiào shòu"), ("宗教", "zōng jiào"), ("教程", "jiào chéng"),
+// 相
+("相信", "xiāng xìn"), ("相同", "xiāng tóng"), ("互相", "hù xiāng"),
+("照相", "zhào xiàng"), ("相机", "xiàng jī"), ("首相", "shǒu xiàng"), ("相册", "xiàng cè"), ("真相", "zhēn xiàng"),
+// 血
+("血液", "xuè yè"), ("流血", "liú xuè"),
+// 薄
+("薄荷", "bò hé"), ("单薄", "dān bó"),
+// 角
+("角色", "jué sè"), ("主角", "zhǔ jué"), ("配角", "pèi jué"), ("角度", "jiǎo dù"), ("三角", "sān jiǎo"),
+// 省
+("反省", "fǎn xǐng"), ("省份", "shěng fèn"), ("节省", "jié shěng"),
+// 给
+("给予", "jǐ yǔ"), ("供给", "gōng jǐ"), ("补给", "bǔ jǐ"),
+// 更
+("更新", "gēng xīn"), ("更改", "gēng gǎi"), ("更换", "gēng huàn"), ("变更", "biàn gēng"), ("更加", "gèng jiā"),
+// 发
+("头发", "tóu fà"), ("理发", "lǐ fà"), ("发现", "fā xiàn"), ("开发", "kāi fā"), ("发送", "fā sòng"), ("发布", "fā bù"),
+// 干
+("干净", "gān jìng"), ("干燥", "gān zào"), ("饼干", "bǐng gān"), ("干部", "gàn bù"), ("干活", "gàn huó"), ("树干", "shù gàn"),
+// 难
+("困难", "kùn nán"), ("难过", "nán guò"), ("灾难", "zāi nàn"), ("难民", "nàn mín"),
+// 结
+("结果", "jié guǒ"), ("结构", "jié gòu"), ("结束", "jié shù"), ("结实", "jiē shí"),
+// 应
+("应该", "yīng gāi"), ("应当", "yīng dāng"), ("应用", "yìng yòng"), ("响应", "xiǎng yìng"), ("反应", "fǎn yìng"), ("适应", "shì yìng"), ("应用程序", "yìng yòng chéng xù"),
+// 空
+("空间", "kōng jiān"), ("天空", "tiān kōng"), ("空白", "kòng bái"), ("空闲", "kòng xián"), ("有空", "yǒu kòng"),
+// 种
+("种类", "zhǒng lèi"), ("种子", "zhǒng zi"), ("各种", "gè zhǒng"), ("种植", "zhòng zhí"), ("种地", "zhòng dì"),
+// 量
+("质量", "zhì liàng"), ("变量", "biàn liàng"), ("常量", "cháng liàng"), ("测量", "cè liáng"), ("商量", "shāng liáng"),
+// 分
+("分析", "fēn xī"), ("分钟", "fēn zhōng"), ("部分", "bù fèn"), ("成分", "chéng fèn"), ("身份", "shēn fèn"),
+// 创
+("创造", "chuàng zào"), ("创建", "chuàng jiàn"), ("创新", "chuàng xīn"), ("创伤", "chuāng shāng"),
+// 载
+("下载", "xià zài"), ("加载", "jiā zài"), ("卸载", "xiè zài"), ("记载", "jì zǎi"), ("转载", "zhuǎn zǎi"),
+// 露
+("露出", "lòu chū"), ("暴露", "bào lù"), ("露水", "lù shuǐ"),
+// 率
+("效率", "xiào lǜ"), ("概率", "gài lǜ"), ("频率", "pín lǜ"), ("分辨率", "fēn biàn lǜ"), ("率领", "shuài lǐng"),
+// 区
+("区别", "qū bié"), ("地区", "dì qū"), ("区域", "qū yù"),
+// 单
+("单于", "chán yú"), ("单位", "dān wèi"), ("菜单", "cài dān"),
+// 任
+("任务", "rèn wù"),
+// 大
+("大夫", "dài fū"), ("大王", "dài wáng"),
+// 模
+("模样", "mú yàng"), ("模板", "mú bǎn"), ("模具", "mú jù"), ("模式", "mó shì"), ("模型", "mó xíng"), ("模块", "mó kuài"), ("模拟", "mó nǐ"),
+// 似
+("似的", "shì de"), ("相似", "xiāng sì"), ("似乎", "sì hū"),
+// 属
+("属于", "shǔ yú"), ("属性", "shǔ xìng"),
+// 解
+("解决", "jiě jué"), ("解释", "jiě shì"), ("解析", "jiě xī"), ("解压", "jiě yā"),
+// 降
+("降低", "jiàng dī"), ("下降", "xià jiàng"), ("投降", "tóu xiáng"),
+// 强
+("强大", "qiáng dà"), ("勉强", "miǎn qiǎng"), ("倔强", "jué jiàng"),
+// 宿
+("宿舍", "sù shè"), ("星宿", "xīng xiù"),
+// 石
+("石头", "shí tou"), ("一石", "yí dàn"),
+// 降
+// 区块
+("区块链", "qū kuài liàn"),
+// 剥
+("剥削", "bō xuē"),
+// 广
+("广州", "guǎng zhōu"), ("广告", "guǎng gào"),
+// 厦
+("厦门", "xià mén"), ("大厦", "dà shà"),
+// 蚌
+("蚌埠", "bèng bù"),
+// 六
+("六安", "lù ān"),
+// 番
+("番禺", "pān yú"),
+// 尉
+("尉迟", "yù chí"),
+// 会稽
+("会稽", "kuài jī"),
+// 单县
+("单县", "shàn xiàn"),
+// 曲
+("歌曲", "gē qǔ"), ("曲线", "qū xiàn"), ("弯曲", "wān qū"),
+// 假
+("假期", "jià qī"), ("放假", "fàng jià"), ("请假", "qǐng jià"), ("假如", "jiǎ rú"), ("假设", "jiǎ shè"),
+// 供
+("提供", "tí gōng"), ("供应", "gōng yìng"), ("口供", "kǒu gòng"), ("供奉", "gòng fèng"),
+// 冲
+("冲突", "chōng tū"), ("冲击", "chōng jī"), ("冲刺", "chōng cì"),
+// 背
+("背景", "bèi jǐng"), ("背包", "bēi bāo"),
+// 都
+("首都", "shǒu dū"), ("成都", "chéng dū"), ("都市", "dū shì"), ("都是", "dōu shì"),
+// 降
+// 盛
+("盛开", "shèng kāi"), ("盛饭", "chéng fàn"),
+// 折
+("折扣", "zhé kòu"), ("打折", "dǎ zhé"), ("折腾", "zhē téng"), ("折本", "shé běn"),
+// 落
+("落后", "luò hòu"), ("降落", "jiàng luò"), ("落下", "là xià"),
+// 卡
+("卡片", "kǎ piàn"), ("关卡", "guān qiǎ"),
+// 称
+("称呼", "chēng hū"), ("名称", "míng chēng"), ("对称", "duì chèn"), ("相称", "xiāng chèn"),
+// 间
+("时间", "shí jiān"), ("房间", "fáng jiān"), ("间隔", "jiàn gé"), ("间接", "jiàn jiē"),
+// 没
+("没有", "méi yǒu"), ("淹没", "yān mò"), ("没收", "mò shōu"),
+// 似
+// 识
+("知识", "zhī shí"), ("认识", "rèn shí"), ("标识", "biāo zhì"),
+// 缝
+("缝纫", "féng rèn"), ("裂缝", "liè fèng"),
+// 兴
+("高兴", "gāo xìng"), ("兴趣", "xìng qù"), ("兴奋", "xīng fèn"),
+// 倒
+("倒霉", "dǎo méi"), ("倒闭", "dǎo bì"), ("倒计时", "dào jì shí"), ("倒序", "dào xù"),
+// 弹
+("弹窗", "tán chuāng"), ("弹性", "tán xìng"), ("子弹", "zǐ dàn"), ("导弹", "dǎo dàn"),
+// 转
+("转换", "zhuǎn huàn"), ("转发", "zhuǎn fā"), ("旋转", "xuán zhuàn"), ("转圈", "zhuàn quān"),
+// 压
+("压缩", "yā suō"), ("压根", "yà gēn"),
+// 恶
+("恶心", "ě xīn"), ("可恶", "kě wù"), ("厌恶", "yàn wù"), ("恶劣", "è liè"),
+// 降
+// 正
+("正月", "zhēng yuè"), ("正常", "zhèng cháng"),
+// 几
+("几乎", "jī hū"), ("茶几", "chá jī"), ("几个", "jǐ gè"),
+// 和
+("和平", "hé píng"), ("暖和", "nuǎn huo"), ("附和", "fù hè"), ("和面", "huó miàn"),
+// 提
+("提防", "dī fáng"),
+// 说
+("说服", "shuō fú"), ("游说", "yóu shuì"),
+// 奇
+("奇数", "jī shù"), ("奇怪", "qí guài"),
+// 车
+("汽车", "qì chē"), ("象棋车", "xiàng qí jū"),
+// 喝
+("喝彩", "hè cǎi"), ("喝水", "hē shuǐ"),
+// 尽
+("尽量", "jǐn liàng"), ("尽管", "jǐn guǎn"), ("尽力", "jìn lì"),
+// 缩
+("缩写", "suō xiě"), ("缩小", "suō xiǎo"),
+// 待
+("等待", "děng dài"), ("待会", "dāi huì"),
+// 挣
+("挣钱", "zhèng qián"), ("挣扎", "zhēng zhá"),
+// 着
+// 扎
+("扎实", "zhā shí"), ("包扎", "bāo zā"),
+// 仔
+("仔细", "zǐ xì"), ("牛仔", "niú zǎi"),
+// 吐
+("吐槽", "tǔ cáo"), ("呕吐", "ǒu tù"),
+// 散
+("散步", "sàn bù"), ("分散", "fēn sàn"), ("散文", "sǎn wén"), ("松散", "sōng sǎn"),
+// 塞
+("塞车", "sāi chē"), ("边塞", "biān sài"), ("堵塞", "dǔ sè"), ("阻塞", "zǔ sè"),
+// 圈
+("圆圈", "yuán quān"), ("猪圈", "zhū juàn"),
+// 参
+// 晃
+("摇晃", "yáo huàng"), ("晃眼", "huǎng yǎn"),
+// 劲
+("使劲", "shǐ jìn"), ("强劲", "qiáng jìng"),
+// 校
+("学校", "xué xiào"), ("校对", "jiào duì"), ("校验", "jiào yàn"), ("校准", "jiào zhǔn"),
+// 拓
+("拓展", "tuò zhǎn"), ("拓扑", "tuò pū"), ("拓片", "tà piàn"),
+// 据
+("根据", "gēn jù"),
+// 栅
+("栅栏", "zhà lán"), ("栅格", "shān gé"),
+// 血
+("血压", "xuè yā"),
+// 覆
+("覆盖", "fù gài"),
+// 传
+// 识别
+("识别", "shí bié"),
+// 宁
+("宁可", "nìng kě"), ("宁愿", "nìng yuàn"), ("安宁", "ān níng"), ("南宁", "nán níng"),
+// 给
+];
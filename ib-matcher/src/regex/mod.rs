@@ -0,0 +1,661 @@
+//! Pinyin/romaji-aware regular expressions.
+//!
+//! Literals in the pattern are matched by [`IbMatcher`], so they can also match hanzi by pinyin and Japanese by romaji, while the rest of the pattern follows the syntax of the [`regex`](https://docs.rs/regex/latest/regex/#syntax) crate.
+//!
+//! ## Example
+//! ```
+//! use ib_matcher::{matcher::PinyinMatchConfig, pinyin::PinyinNotation, regex::Regex};
+//!
+//! let pinyin = PinyinMatchConfig::notations(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter);
+//! let re = Regex::builder(r"^py.*\.txt$").pinyin(&pinyin).build().unwrap();
+//! assert!(re.is_match("拼音笔记.txt"));
+//! assert!(re.is_match("python.txt"));
+//! assert!(!re.is_match("拼音笔记.md"));
+//! ```
+//!
+//! ## Limitations
+//! - The engine is a backtracking one, but like [`regex_automata::nfa::thompson::backtrack`](https://docs.rs/regex-automata/latest/regex_automata/nfa/thompson/backtrack/index.html), each (state, position) pair is visited at most once per search. So a search takes at most `O(states * haystack.len())` time, and also memory for the visited set, which is capped by [`RegexBuilder::visited_capacity`].
+//! - Capture groups are matched as non-capturing groups.
+//! - Case insensitivity is controlled by [`RegexBuilder::plain`] for the whole pattern. Inline flags like `(?i)` turn literals into classes, which are only matched as plain chars.
+//! - Only UTF-8 haystacks are supported at the moment.
+use std::{collections::HashMap, fmt, iter};
+
+use bon::bon;
+use regex_syntax::{
+    hir::{Class, Hir, HirKind, Look},
+    ParserBuilder,
+};
+
+#[cfg(feature = "pinyin")]
+use crate::matcher::PinyinMatchConfig;
+#[cfg(feature = "romaji")]
+use crate::matcher::RomajiMatchConfig;
+use crate::matcher::{input::Input, IbMatcher, Match, PlainMatchConfig};
+
+/// A compiled pinyin/romaji-aware regular expression, see [module](self) for details.
+///
+/// API follows [`regex::Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html).
+pub struct Regex<'a> {
+    literals: Box<[IbMatcher<'a>]>,
+    states: Box<[State]>,
+    start: StateId,
+    /// Can only match at the start of the haystack.
+    anchored: bool,
+    visited_capacity: usize,
+}
+
+type StateId = usize;
+
+/// A state of the Thompson NFA.
+enum State {
+    /// Matches `Regex::literals[literal]`.
+    Literal {
+        literal: usize,
+        next: StateId,
+    },
+    Class {
+        class: Class,
+        next: StateId,
+    },
+    Look {
+        look: Look,
+        next: StateId,
+    },
+    /// Tries `first`, then `second`.
+    Split {
+        first: StateId,
+        second: StateId,
+    },
+    Match,
+}
+
+#[bon]
+impl<'a> Regex<'a> {
+    /// The maximum number of NFA states, e.g. counted repetitions are compiled by copying.
+    pub const STATES_LIMIT: usize = 10_000;
+
+    /// The default of [`RegexBuilder::visited_capacity`], 16 MiB.
+    pub const DEFAULT_VISITED_CAPACITY: usize = 16 << 20;
+
+    #[builder]
+    pub fn new(
+        #[builder(start_fn)] pattern: &str,
+
+        /// `None` means not to match literals as plain characters, i.e. match them only as pinyin/romaji.
+        ///
+        /// Also applies to classes, e.g. `[a-z]` is case insensitive by default.
+        #[builder(required, default = Some(PlainMatchConfig::builder().build()))]
+        plain: Option<PlainMatchConfig>,
+        /// See [`IbMatcherBuilder::mix_lang`](crate::matcher::IbMatcherBuilder::mix_lang).
+        #[builder(default = false)]
+        mix_lang: bool,
+        /// Shared by all literals in the pattern.
+        #[cfg(feature = "pinyin")]
+        pinyin: Option<&'a PinyinMatchConfig<'a>>,
+        /// Shared by all literals in the pattern.
+        #[cfg(feature = "romaji")]
+        romaji: Option<&'a RomajiMatchConfig<'a>>,
+        /// The max size in bytes of the visited set of a search, which takes `states * (haystack.len() + 1)` bits.
+        ///
+        /// Searches that need more fail with [`MatchError`], see [`Regex::try_find()`]. E.g. with the default [`Regex::DEFAULT_VISITED_CAPACITY`], a regex of 100 states can search haystacks up to about 1.3 MB.
+        #[builder(default = Regex::DEFAULT_VISITED_CAPACITY)]
+        visited_capacity: usize,
+    ) -> Result<Self, BuildError> {
+        let hir = ParserBuilder::new()
+            .utf8(true)
+            .build()
+            .parse(pattern)
+            .map_err(|e| BuildError(BuildErrorKind::Syntax(Box::new(e))))?;
+
+        let mut compiler = Compiler {
+            plain,
+            mix_lang,
+            #[cfg(feature = "pinyin")]
+            pinyin,
+            #[cfg(feature = "romaji")]
+            romaji,
+            literals: Vec::new(),
+            literal_ids: HashMap::new(),
+            states: vec![State::Match],
+        };
+        let start = compiler.compile(&hir, 0)?;
+        Ok(Self {
+            literals: compiler.literals.into(),
+            states: compiler.states.into(),
+            start,
+            anchored: hir.properties().look_set_prefix().contains(Look::Start),
+            visited_capacity,
+        })
+    }
+
+    /// Returns true if and only if there is a match for the regex anywhere in the haystack given.
+    ///
+    /// ## Panics
+    /// If the search exceeds [`RegexBuilder::visited_capacity`], see [`Regex::try_is_match()`].
+    pub fn is_match<'h>(&self, input: impl Into<Input<'h, str>>) -> bool {
+        self.find(input).is_some()
+    }
+
+    /// Like [`Regex::is_match()`], but returns an error instead of panicking if the search exceeds [`RegexBuilder::visited_capacity`].
+    pub fn try_is_match<'h>(&self, input: impl Into<Input<'h, str>>) -> Result<bool, MatchError> {
+        self.try_find(input).map(|m| m.is_some())
+    }
+
+    /// Searches for the leftmost-first match of the regex in the haystack given, like [`regex::Regex::find`](https://docs.rs/regex/latest/regex/struct.Regex.html#method.find).
+    ///
+    /// [`Input::range`] is treated as the whole haystack, e.g. `^` matches at the start of the range unless [`InputBuilder::no_start`](crate::matcher::input::InputBuilder::no_start).
+    ///
+    /// ## Panics
+    /// If the search exceeds [`RegexBuilder::visited_capacity`], see [`Regex::try_find()`].
+    pub fn find<'h>(&self, input: impl Into<Input<'h, str>>) -> Option<Match> {
+        self.try_find(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Regex::find()`], but returns an error instead of panicking if the search exceeds [`RegexBuilder::visited_capacity`].
+    pub fn try_find<'h>(
+        &self,
+        input: impl Into<Input<'h, str>>,
+    ) -> Result<Option<Match>, MatchError> {
+        let input = input.into();
+        let haystack = input.span();
+        let visited_len = self
+            .states
+            .len()
+            .checked_mul(haystack.len() + 1)
+            .map(|bits| bits.div_ceil(64))
+            .filter(|&words| words <= self.visited_capacity / 8)
+            .ok_or(MatchError(MatchErrorKind::HaystackTooLong {
+                len: haystack.len(),
+                visited_capacity: self.visited_capacity,
+            }))?;
+        let mut exec = Exec {
+            regex: self,
+            haystack,
+            no_start: input.no_start,
+            no_end: input.no_end,
+            visited: vec![0; visited_len],
+            stack: Vec::new(),
+            lens: Vec::new(),
+        };
+        let offset = input.range.start;
+
+        // The visited set is shared by all starts: a state visited from an earlier start has failed, and would fail again
+        let starts = haystack
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(iter::once(haystack.len()));
+        for start in starts {
+            if let Some(end) = exec.backtrack(start) {
+                return Ok(Some(Match {
+                    start: offset + start,
+                    end: offset + end,
+                    is_pattern_partial: false,
+                    char_spans: None,
+                }));
+            }
+            if self.anchored {
+                break;
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// An error that occurred when building a [`Regex`].
+#[derive(Debug, Clone)]
+pub struct BuildError(BuildErrorKind);
+
+#[derive(Debug, Clone)]
+enum BuildErrorKind {
+    Syntax(Box<regex_syntax::Error>),
+    TooManyStates,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            BuildErrorKind::Syntax(e) => e.fmt(f),
+            BuildErrorKind::TooManyStates => write!(
+                f,
+                "compiled regex exceeds the limit of {} states",
+                Regex::STATES_LIMIT
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// An error that occurred when searching with a [`Regex`], see [`Regex::try_find()`].
+#[derive(Debug, Clone)]
+pub struct MatchError(MatchErrorKind);
+
+#[derive(Debug, Clone)]
+enum MatchErrorKind {
+    HaystackTooLong { len: usize, visited_capacity: usize },
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            MatchErrorKind::HaystackTooLong {
+                len,
+                visited_capacity,
+            } => write!(
+                f,
+                "haystack of {len} bytes exceeds the visited capacity of {visited_capacity} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatchError {}
+
+/// Compiles [`Hir`] into a Thompson NFA, backwards from the `next` state of each node.
+struct Compiler<'a> {
+    plain: Option<PlainMatchConfig>,
+    mix_lang: bool,
+    #[cfg(feature = "pinyin")]
+    pinyin: Option<&'a PinyinMatchConfig<'a>>,
+    #[cfg(feature = "romaji")]
+    romaji: Option<&'a RomajiMatchConfig<'a>>,
+
+    literals: Vec<IbMatcher<'a>>,
+    /// Counted repetitions compile the same literal multiple times.
+    literal_ids: HashMap<Box<[u8]>, usize>,
+    states: Vec<State>,
+}
+
+impl<'a> Compiler<'a> {
+    fn push(&mut self, state: State) -> Result<StateId, BuildError> {
+        if self.states.len() >= Regex::STATES_LIMIT {
+            return Err(BuildError(BuildErrorKind::TooManyStates));
+        }
+        self.states.push(state);
+        Ok(self.states.len() - 1)
+    }
+
+    fn literal(&mut self, literal: &[u8]) -> usize {
+        if let Some(&id) = self.literal_ids.get(literal) {
+            return id;
+        }
+        // UTF-8 is guaranteed by the parser
+        let pattern = std::str::from_utf8(literal).unwrap();
+        let matcher = IbMatcher::builder(pattern)
+            .plain(self.plain.clone())
+            .mix_lang(self.mix_lang);
+        #[cfg(feature = "pinyin")]
+        let matcher = matcher.maybe_pinyin(self.pinyin.map(|c| c.shallow_clone()));
+        #[cfg(feature = "romaji")]
+        let matcher = matcher.maybe_romaji(self.romaji.map(|c| c.shallow_clone()));
+        self.literals.push(matcher.build());
+        self.literal_ids
+            .insert(literal.into(), self.literals.len() - 1);
+        self.literals.len() - 1
+    }
+
+    /// Returns the start state of `hir`, which continues with `next` on match.
+    fn compile(&mut self, hir: &Hir, next: StateId) -> Result<StateId, BuildError> {
+        match hir.kind() {
+            HirKind::Empty => Ok(next),
+            HirKind::Literal(literal) => {
+                let literal = self.literal(&literal.0);
+                self.push(State::Literal { literal, next })
+            }
+            HirKind::Class(class) => {
+                let mut class = class.clone();
+                if self
+                    .plain
+                    .as_ref()
+                    .is_some_and(|plain| plain.case_insensitive)
+                {
+                    class.case_fold_simple();
+                }
+                self.push(State::Class { class, next })
+            }
+            HirKind::Look(look) => self.push(State::Look { look: *look, next }),
+            HirKind::Repetition(repetition) => {
+                let split = |sub, next| match repetition.greedy {
+                    true => State::Split {
+                        first: sub,
+                        second: next,
+                    },
+                    false => State::Split {
+                        first: next,
+                        second: sub,
+                    },
+                };
+                let mut start = match repetition.max {
+                    None => {
+                        // Empty iterations are cut by the visited set
+                        let lp = self.push(State::Match)?;
+                        let sub = self.compile(&repetition.sub, lp)?;
+                        self.states[lp] = split(sub, next);
+                        lp
+                    }
+                    Some(max) => {
+                        let mut start = next;
+                        for _ in repetition.min..max {
+                            let sub = self.compile(&repetition.sub, start)?;
+                            start = self.push(split(sub, next))?;
+                        }
+                        start
+                    }
+                };
+                for _ in 0..repetition.min {
+                    start = self.compile(&repetition.sub, start)?;
+                }
+                Ok(start)
+            }
+            HirKind::Capture(capture) => self.compile(&capture.sub, next),
+            HirKind::Concat(hirs) => hirs
+                .iter()
+                .rev()
+                .try_fold(next, |next, hir| self.compile(hir, next)),
+            HirKind::Alternation(hirs) => {
+                let (last, rest) = hirs.split_last().unwrap();
+                let mut start = self.compile(last, next)?;
+                for hir in rest.iter().rev() {
+                    let first = self.compile(hir, next)?;
+                    start = self.push(State::Split {
+                        first,
+                        second: start,
+                    })?;
+                }
+                Ok(start)
+            }
+        }
+    }
+}
+
+/// Backtracking executor with an explicit stack and a visited set.
+struct Exec<'r, 'a, 'h> {
+    regex: &'r Regex<'a>,
+    haystack: &'h str,
+    no_start: bool,
+    no_end: bool,
+    /// Bitset of visited `(state, pos)`.
+    visited: Vec<u64>,
+    /// Alternatives to backtrack to, the last one first.
+    stack: Vec<(StateId, usize)>,
+    /// Buffer of literal match lengths.
+    lens: Vec<usize>,
+}
+
+impl Exec<'_, '_, '_> {
+    /// Returns whether `(state, pos)` was not visited before, and marks it as visited.
+    fn visit(&mut self, state: StateId, pos: usize) -> bool {
+        let i = state * (self.haystack.len() + 1) + pos;
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        let unvisited = self.visited[word] & bit == 0;
+        self.visited[word] |= bit;
+        unvisited
+    }
+
+    /// Returns the end of the first match starting at `start`, in preference order.
+    fn backtrack(&mut self, start: usize) -> Option<usize> {
+        self.stack.push((self.regex.start, start));
+        while let Some((mut state, mut pos)) = self.stack.pop() {
+            loop {
+                if !self.visit(state, pos) {
+                    break;
+                }
+                match &self.regex.states[state] {
+                    State::Match => {
+                        self.stack.clear();
+                        return Some(pos);
+                    }
+                    &State::Split { first, second } => {
+                        self.stack.push((second, pos));
+                        state = first;
+                    }
+                    State::Class { class, next } => match self.haystack[pos..].chars().next() {
+                        Some(c) if class_contains(class, c) => {
+                            pos += c.len_utf8();
+                            state = *next;
+                        }
+                        _ => break,
+                    },
+                    &State::Look { look, next } => {
+                        if !self.look(look, pos) {
+                            break;
+                        }
+                        state = next;
+                    }
+                    &State::Literal { literal, next } => {
+                        let lens = &mut self.lens;
+                        lens.clear();
+                        self.regex.literals[literal].test_each(&self.haystack[pos..], |len| {
+                            // Different paths through the literal can end at the same length
+                            if !lens.contains(&len) {
+                                lens.push(len);
+                            }
+                            false
+                        });
+                        let Some((&len, rest)) = lens.split_first() else {
+                            break;
+                        };
+                        self.stack
+                            .extend(rest.iter().rev().map(|&len| (next, pos + len)));
+                        pos += len;
+                        state = next;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn look(&self, look: Look, pos: usize) -> bool {
+        let before = self.haystack[..pos].chars().next_back();
+        let after = self.haystack[pos..].chars().next();
+        let is_word_ascii =
+            |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+        // An approximation of Unicode word chars
+        let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        match look {
+            Look::Start => before.is_none() && !self.no_start,
+            Look::End => after.is_none() && !self.no_end,
+            Look::StartLF => before.map_or(!self.no_start, |c| c == '\n'),
+            Look::EndLF => after.map_or(!self.no_end, |c| c == '\n'),
+            Look::StartCRLF => match before {
+                None => !self.no_start,
+                Some('\n') => true,
+                Some('\r') => after != Some('\n'),
+                Some(_) => false,
+            },
+            Look::EndCRLF => match after {
+                None => !self.no_end,
+                Some('\r') => true,
+                Some('\n') => before != Some('\r'),
+                Some(_) => false,
+            },
+            Look::WordAscii => is_word_ascii(before) != is_word_ascii(after),
+            Look::WordAsciiNegate => is_word_ascii(before) == is_word_ascii(after),
+            Look::WordUnicode => is_word(before) != is_word(after),
+            Look::WordUnicodeNegate => is_word(before) == is_word(after),
+            Look::WordStartAscii => !is_word_ascii(before) && is_word_ascii(after),
+            Look::WordEndAscii => is_word_ascii(before) && !is_word_ascii(after),
+            Look::WordStartUnicode => !is_word(before) && is_word(after),
+            Look::WordEndUnicode => is_word(before) && !is_word(after),
+            Look::WordStartHalfAscii => !is_word_ascii(before),
+            Look::WordEndHalfAscii => !is_word_ascii(after),
+            Look::WordStartHalfUnicode => !is_word(before),
+            Look::WordEndHalfUnicode => !is_word(after),
+        }
+    }
+}
+
+fn class_contains(class: &Class, c: char) -> bool {
+    match class {
+        Class::Unicode(class) => class
+            .ranges()
+            .binary_search_by(|range| {
+                if range.end() < c {
+                    std::cmp::Ordering::Less
+                } else if range.start() > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok(),
+        // Only ASCII byte classes are allowed in UTF-8 mode
+        Class::Bytes(class) => {
+            c.is_ascii()
+                && class
+                    .ranges()
+                    .iter()
+                    .any(|range| (range.start()..=range.end()).contains(&(c as u8)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        matcher::{PinyinMatchConfig, RomajiMatchConfig},
+        pinyin::PinyinNotation,
+    };
+
+    use super::*;
+
+    fn find(re: &Regex, haystack: &str) -> Option<(usize, usize)> {
+        re.find(haystack).map(|m| (m.start(), m.end()))
+    }
+
+    #[test]
+    fn regex() {
+        let pinyin =
+            PinyinMatchConfig::notations(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter);
+        let re = Regex::builder(r"^py.*\.txt$")
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert!(re.is_match("拼音笔记.txt"));
+        assert!(re.is_match("PY.txt"));
+        assert!(!re.is_match("拼音笔记.txt.md"));
+        assert!(!re.is_match("a拼音.txt"));
+
+        let re = Regex::builder(r"(pin|sou)yin\d+")
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert_eq!(find(&re, "a拼音12b"), Some((1, 9)));
+        assert_eq!(find(&re, "搜音3"), Some((0, 7)));
+        assert_eq!(find(&re, "拼音"), None);
+
+        let re = Regex::builder(r"^(pinyin|p)yin$")
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert!(re.is_match("拼音"));
+        assert!(re.is_match("pyin"));
+
+        // Backtracking into a literal: "ta" can match "他" or "他啊"
+        let re = Regex::builder("^ta$").pinyin(&pinyin).build().unwrap();
+        assert!(re.is_match("他啊"));
+        let re = Regex::builder("^ta啊$").pinyin(&pinyin).build().unwrap();
+        assert!(re.is_match("他啊"));
+
+        let re = Regex::builder(r"\bsou(suo)?\b")
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert_eq!(find(&re, "a sou b"), Some((2, 5)));
+        assert_eq!(find(&re, "拼音 搜索"), Some((7, 13)));
+        assert_eq!(find(&re, "asou"), None);
+
+        let re = Regex::builder("[a-c]+x").build().unwrap();
+        assert_eq!(find(&re, "zABcx"), Some((1, 5)));
+        let re = Regex::builder("[a-c]+x").plain(None).build().unwrap();
+        assert_eq!(find(&re, "zABcx"), None);
+
+        let re = Regex::builder(r"a{2,}?").build().unwrap();
+        assert_eq!(find(&re, "aaaa"), Some((0, 2)));
+        let re = Regex::builder(r"(a*)*b").build().unwrap();
+        assert_eq!(find(&re, "aab"), Some((0, 3)));
+        let re = Regex::builder("").build().unwrap();
+        assert_eq!(find(&re, "拼音"), Some((0, 0)));
+
+        assert!(Regex::builder("(").build().is_err());
+        assert!(Regex::builder("(a{100}){100}").build().is_err());
+    }
+
+    #[test]
+    fn regex_long() {
+        let pinyin = PinyinMatchConfig::notations(PinyinNotation::Ascii);
+        let haystack = "a".repeat(120_000);
+        let re = Regex::builder(".*z").build().unwrap();
+        assert!(!re.is_match(haystack.as_str()));
+        let re = Regex::builder("(a|aa)*b").build().unwrap();
+        assert!(!re.is_match(haystack.as_str()));
+
+        let haystack = "拼".repeat(40_000) + "音";
+        let re = Regex::builder("^(pin)*yin$")
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert_eq!(find(&re, &haystack), Some((0, haystack.len())));
+        let re = Regex::builder("pinyin").pinyin(&pinyin).build().unwrap();
+        assert_eq!(
+            find(&re, &haystack),
+            Some((haystack.len() - 6, haystack.len()))
+        );
+    }
+
+    #[test]
+    fn regex_visited_capacity() {
+        let re = Regex::builder("a+b").visited_capacity(64).build().unwrap();
+        assert_eq!(re.try_find("aab").unwrap().map(|m| m.range()), Some(0..3));
+        assert!(re.try_find("a".repeat(1000).as_str()).is_err());
+        assert!(re.try_is_match("a".repeat(1000).as_str()).is_err());
+        assert!(Regex::builder("a+b")
+            .build()
+            .unwrap()
+            .try_is_match("a".repeat(1000).as_str())
+            .is_ok());
+    }
+
+    #[test]
+    fn regex_polyphone() {
+        // Each "a" matches "啊" by both notations, which must not multiply the paths
+        let pinyin =
+            PinyinMatchConfig::notations(PinyinNotation::Ascii | PinyinNotation::AsciiFirstLetter);
+        let haystack = "啊".repeat(40);
+        let re = Regex::builder(&"a".repeat(40))
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert_eq!(find(&re, &haystack), Some((0, haystack.len())));
+        let re = Regex::builder(&("a".repeat(40) + "b"))
+            .pinyin(&pinyin)
+            .build()
+            .unwrap();
+        assert_eq!(find(&re, &haystack), None);
+    }
+
+    #[test]
+    fn regex_input() {
+        let pinyin = PinyinMatchConfig::notations(PinyinNotation::Ascii);
+        let re = Regex::builder("^pinyin$").pinyin(&pinyin).build().unwrap();
+        let haystack = "a拼音b";
+        assert_eq!(
+            re.find(Input::builder(haystack).range(1..7).build())
+                .map(|m| m.range()),
+            Some(1..7)
+        );
+        assert!(!re.is_match(Input::builder(haystack).range(1..7).no_start(true).build()));
+        assert!(!re.is_match(Input::builder(haystack).range(1..7).no_end(true).build()));
+    }
+
+    #[test]
+    fn regex_romaji() {
+        let romaji = RomajiMatchConfig::default();
+        let re = Regex::builder(r"^konosubarashii.*sekai")
+            .romaji(&romaji)
+            .build()
+            .unwrap();
+        assert!(re.is_match("この素晴らしい世界に祝福を"));
+    }
+}
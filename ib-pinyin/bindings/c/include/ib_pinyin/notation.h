@@ -28,6 +28,13 @@ typedef uint32_t PinyinNotation;
 /// See [简拼](https://zh.wikipedia.org/wiki/简拼) for details.
 #define PINYIN_NOTATION_ASCII_FIRST_LETTER 0x1
 
+/// 声母
+///
+/// The initial, or the first letter if there is no initial, so "zh", "ch" and "sh" are single units.
+///
+/// e.g. "p", "y", "zh" (中)
+#define PINYIN_NOTATION_ASCII_INITIAL 0x4000
+
 /// 智能 ABC 双拼
 ///
 /// See [智能ABC输入法](https://zh.wikipedia.org/wiki/智能ABC输入法#双拼方案) for details.
@@ -56,4 +63,23 @@ typedef uint32_t PinyinNotation;
 /// 自然码双拼
 ///
 /// See [自然码](https://zh.wikipedia.org/zh-cn/自然码) for details.
-#define PINYIN_NOTATION_DiletterZrm 0x200
\ No newline at end of file
+#define PINYIN_NOTATION_DiletterZrm 0x200
+
+/// 注音符号
+///
+/// e.g. "ㄆㄧㄣ", "ㄧㄣ"
+///
+/// See [Bopomofo](https://en.wikipedia.org/wiki/Bopomofo) for details.
+#define PINYIN_NOTATION_Zhuyin 0x400
+
+/// 带声调注音符号
+///
+/// Tone marks are appended to the end: "ˊ", "ˇ", "ˋ" and "˙" for tone 2 to 5, and none for tone 1.
+///
+/// e.g. "ㄆㄧㄣ", "ㄓㄨㄥˋ"
+#define PINYIN_NOTATION_ZhuyinTone 0x800
+
+/// 大千注音键盘
+///
+/// e.g. "qup" (ㄆㄧㄣ), "up" (ㄧㄣ)
+#define PINYIN_NOTATION_ZhuyinDaqian 0x1000
\ No newline at end of file
@@ -15,11 +15,7 @@ mod ffi {
 
     impl ib_pinyin {
         pub fn is_match_u8(pattern: &str, haystack: &str, pinyin_notations: u32) -> bool {
-            minimal::is_pinyin_match(
-                pattern,
-                haystack,
-                PinyinNotation::from_bits_truncate(pinyin_notations),
-            )
+            minimal::is_pinyin_match(pattern, haystack, Self::notations(pinyin_notations))
         }
 
         /// TODO: Lossy decoding?
@@ -44,7 +40,7 @@ mod ffi {
             minimal::is_pinyin_match_u16(
                 unsafe { U16Str::from_ptr(pattern as *const u16, pattern_len) },
                 unsafe { U16Str::from_ptr(haystack as *const u16, haystack_len) },
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             )
         }
 
@@ -52,7 +48,7 @@ mod ffi {
             minimal::is_pinyin_match_u16(
                 unsafe { U16CStr::from_ptr_str(pattern as *const u16) }.as_ustr(),
                 unsafe { U16CStr::from_ptr_str(haystack as *const u16) }.as_ustr(),
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             )
         }
 
@@ -66,7 +62,7 @@ mod ffi {
             minimal::is_pinyin_match_u32(
                 unsafe { U32Str::from_ptr(pattern as *const u32, pattern_len) },
                 unsafe { U32Str::from_ptr(haystack as *const u32, haystack_len) },
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             )
         }
 
@@ -74,10 +70,15 @@ mod ffi {
             minimal::is_pinyin_match_u32(
                 unsafe { U32CStr::from_ptr_str(pattern as *const u32) }.as_ustr(),
                 unsafe { U32CStr::from_ptr_str(haystack as *const u32) }.as_ustr(),
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             )
         }
 
+        /// `DiletterCustom` is masked out, since `PinyinData::init_diletter_scheme()` is not exposed to C yet.
+        fn notations(pinyin_notations: u32) -> PinyinNotation {
+            PinyinNotation::from_bits_truncate(pinyin_notations) - PinyinNotation::DiletterCustom
+        }
+
         fn match_to_u64(m: Option<Match>) -> u64 {
             if let Some(m) = m {
                 m.start() as u64 | (m.end() as u64) << 32
@@ -93,7 +94,7 @@ mod ffi {
             Self::match_to_u64(minimal::find_pinyin_match(
                 pattern,
                 haystack,
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             ))
         }
 
@@ -118,7 +119,7 @@ mod ffi {
             Self::match_to_u64(minimal::find_pinyin_match_u16(
                 unsafe { U16Str::from_ptr(pattern as *const u16, pattern_len) },
                 unsafe { U16Str::from_ptr(haystack as *const u16, haystack_len) },
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             ))
         }
 
@@ -126,7 +127,7 @@ mod ffi {
             Self::match_to_u64(minimal::find_pinyin_match_u16(
                 unsafe { U16CStr::from_ptr_str(pattern as *const u16) }.as_ustr(),
                 unsafe { U16CStr::from_ptr_str(haystack as *const u16) }.as_ustr(),
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             ))
         }
 
@@ -140,7 +141,7 @@ mod ffi {
             Self::match_to_u64(minimal::find_pinyin_match_u32(
                 unsafe { U32Str::from_ptr(pattern as *const u32, pattern_len) },
                 unsafe { U32Str::from_ptr(haystack as *const u32, haystack_len) },
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             ))
         }
 
@@ -148,7 +149,7 @@ mod ffi {
             Self::match_to_u64(minimal::find_pinyin_match_u32(
                 unsafe { U32CStr::from_ptr_str(pattern as *const u32) }.as_ustr(),
                 unsafe { U32CStr::from_ptr_str(haystack as *const u32) }.as_ustr(),
-                PinyinNotation::from_bits_truncate(pinyin_notations),
+                Self::notations(pinyin_notations),
             ))
         }
     }
@@ -1,3 +1,7 @@
+import bisect
+import sys
+import unicodedata
+
 pinyins = []
 pinyin_combinations = []
 pinyin_tables = []
@@ -16,6 +20,91 @@ def read_pinyin_tables(f):
         rng = line[:-1].split(',')
         pinyin_tables.append((range(int(rng[0], 16), int(rng[1], 16) + 1), f.readline()[:-1]))
 
+def order_by_primary(cldr_path):
+    '''Move the primary reading of each polyphone to the front of its combination.
+
+    The primary readings are inferred from CLDR's pinyin collation data (e.g. `Unicode/Collate/CJK/Pinyin.pm` in Perl), which sorts chars by their primary readings: the primary reading of a char is the reading of its nearest monophonic chars, or the reading whose monophonic chars are the nearest to it.
+
+    This is not frequency data, so the primary reading is not always the most common one, e.g. "de" for 地.
+    '''
+    global pinyin_combinations
+
+    weights = {}
+    with open(cldr_path, encoding='utf-8') as f:
+        for line in f:
+            if line.startswith('__DATA__'):
+                break
+        weight = 0
+        for line in f:
+            if line.startswith('__END__'):
+                break
+            for c in line.split():
+                if '-' not in c:
+                    weights[int(c, 16)] = weight
+                weight += 1
+
+    n = len(pinyins)
+    def readings(v):
+        return [v] if v < n else [int(i) for i in pinyin_combinations[v - n].split(',')]
+
+    monophonic = [[] for _ in range(n)]
+    for rng, table in pinyin_tables:
+        for c, v in zip(rng, map(int, table.split(','))):
+            if v < n and c in weights:
+                monophonic[v].append(weights[c])
+    for ws in monophonic:
+        ws.sort()
+
+    def distance(weight, pinyin):
+        ws = monophonic[pinyin]
+        i = bisect.bisect_left(ws, weight)
+        return min((abs(ws[j] - weight) for j in (i - 1, i) if 0 <= j < len(ws)), default=float('inf'))
+
+    # Readings of the nearest monophonic chars before and after each weight
+    monophonic_weights = sorted((w, pinyin) for pinyin, ws in enumerate(monophonic) for w in ws)
+    def neighbors(weight):
+        i = bisect.bisect_left(monophonic_weights, (weight, -1))
+        return [monophonic_weights[j][1] for j in (i - 1, i) if 0 <= j < len(monophonic_weights)]
+
+    def syllable(pinyin):
+        # Remove tone marks but keep ü
+        return ''.join(c for c in unicodedata.normalize('NFD', pinyins[pinyin][0]) if c not in '\u0300\u0301\u0304\u030C')
+
+    def score(weight, pinyin, rs):
+        near = neighbors(weight)
+        if pinyin in near:
+            rank = 0
+        elif syllable(pinyin) in map(syllable, near):
+            rank = 1
+        else:
+            rank = 2
+        return (rank, distance(weight, pinyin), rs.index(pinyin))
+
+    combinations = {comb: i for i, comb in enumerate(pinyin_combinations)}
+    new_tables = []
+    for rng, table in pinyin_tables:
+        values = []
+        for c, v in zip(rng, map(int, table.split(','))):
+            if v != 65535 and v >= n and c in weights:
+                rs = readings(v)
+                primary = min(rs, key=lambda r: score(weights[c], r, rs))
+                comb = ','.join(str(r) for r in [primary] + [r for r in rs if r != primary])
+                if comb not in combinations:
+                    combinations[comb] = len(pinyin_combinations)
+                    pinyin_combinations.append(comb)
+                v = n + combinations[comb]
+            values.append(v)
+        new_tables.append((rng, values))
+
+    # Remove unused combinations
+    used = sorted({v - n for _, values in new_tables for v in values if v != 65535 and v >= n})
+    new_indexes = {old: new for new, old in enumerate(used)}
+    pinyin_combinations = [pinyin_combinations[i] for i in used]
+    pinyin_tables[:] = [
+        (rng, ','.join(str(v if v == 65535 or v < n else n + new_indexes[v - n]) for v in values))
+        for rng, values in new_tables
+    ]
+
 def output():
     pinyin_code = f'pub(super) const PINYINS: [&\'static str; {len(pinyins)}] = [\n'
     pinyin_code += ','.join(f'"{pinyin[0]}"' for pinyin in pinyins)
@@ -43,12 +132,16 @@ const F: u16 = u16::MAX;
 {range_code}
 '''
 
-with open('data/pinyin_compact.txt', encoding='utf-8') as f:
-    if f.readline() == 'pinyins:\n':
-        read_pinyins(f)
-    if f.readline() == 'pinyin_combinations:\n':
-        read_pinyin_combinations(f)
-    if f.readline() == 'pinyin_tables:\n':
-        read_pinyin_tables(f)
+if __name__ == '__main__':
+    with open('data/pinyin_compact.txt', encoding='utf-8') as f:
+        if f.readline() == 'pinyins:\n':
+            read_pinyins(f)
+        if f.readline() == 'pinyin_combinations:\n':
+            read_pinyin_combinations(f)
+        if f.readline() == 'pinyin_tables:\n':
+            read_pinyin_tables(f)
+    # e.g. /usr/share/perl/5.36.0/Unicode/Collate/CJK/Pinyin.pm
+    if len(sys.argv) > 1:
+        order_by_primary(sys.argv[1])
     with open('src/pinyin/data.rs', 'w', encoding='utf-8') as f:
         f.write(output())
\ No newline at end of file
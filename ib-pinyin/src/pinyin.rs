@@ -1 +1 @@
-pub use ib_matcher::pinyin::{Pinyin, PinyinData, PinyinNotation};
+pub use ib_matcher::pinyin::{NonHanzi, Pinyin, PinyinData, PinyinNotation};